@@ -0,0 +1,128 @@
+//! Enemy model registry. `models/enemies.ron` maps archetype ids (the server's
+//! `enemy_type`) to their VAT model, placement and clip names; the loader
+//! pulls in every referenced asset so the registry only becomes a resource
+//! once all of them are ready.
+
+use super::*;
+use bevy::asset::{AssetLoader, LoadContext, io::Reader};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+pub const ENEMY_MODELS: &str = "models/enemies.ron";
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_asset::<EnemyModels>()
+        .register_asset_loader(EnemyModelsLoader)
+        .load_gameplay_resource_from_path::<EnemyModels>(ENEMY_MODELS);
+}
+
+#[derive(Asset, Resource, Clone, TypePath)]
+pub struct EnemyModels {
+    fallback: String,
+    archetypes: HashMap<String, EnemyModel>,
+}
+
+impl EnemyModels {
+    /// Id and model for `archetype`, or the fallback archetype's for ids the
+    /// registry doesn't know.
+    pub fn resolve(&self, archetype: &str) -> Option<(&String, &EnemyModel)> {
+        self.archetypes
+            .get_key_value(archetype)
+            .or_else(|| self.archetypes.get_key_value(&self.fallback))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &EnemyModel)> {
+        self.archetypes.iter()
+    }
+}
+
+#[derive(Clone)]
+pub struct EnemyModel {
+    pub scene: Handle<Gltf>,
+    pub vat_texture: Handle<Image>,
+    pub remap_info: Handle<RemapInfo>,
+    pub scale: f32,
+    /// Vertical offset of the model under the entity origin.
+    pub offset_y: f32,
+    pub clips: EnemyClips,
+}
+
+/// VAT clip names per behavior. Hit and death clips are optional — without
+/// them the enemy doesn't flinch, or keeps its last clip as it dissolves.
+#[derive(Deserialize, Clone, Debug)]
+pub struct EnemyClips {
+    pub idle: String,
+    pub chase: String,
+    pub attack: String,
+    #[serde(default)]
+    pub hit: Option<String>,
+    #[serde(default)]
+    pub knockback: Option<String>,
+    #[serde(default)]
+    pub death: Option<String>,
+}
+
+// ── Loader ──────────────────────────────────────────────────────────
+
+#[derive(Deserialize)]
+struct EnemyModelsFile {
+    fallback: String,
+    archetypes: HashMap<String, EnemyModelDef>,
+}
+
+#[derive(Deserialize)]
+struct EnemyModelDef {
+    scene: String,
+    vat_texture: String,
+    remap_info: String,
+    #[serde(default = "default_scale")]
+    scale: f32,
+    #[serde(default)]
+    offset_y: f32,
+    clips: EnemyClips,
+}
+
+fn default_scale() -> f32 {
+    1.0
+}
+
+#[derive(TypePath)]
+struct EnemyModelsLoader;
+
+impl AssetLoader for EnemyModelsLoader {
+    type Asset = EnemyModels;
+    type Settings = ();
+    type Error = RonLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let file = ::ron::de::from_bytes::<EnemyModelsFile>(&bytes)?;
+
+        let archetypes = file
+            .archetypes
+            .into_iter()
+            .map(|(id, def)| {
+                let model = EnemyModel {
+                    scene: load_context.load(def.scene),
+                    vat_texture: load_context.load(def.vat_texture),
+                    remap_info: load_context.load(def.remap_info),
+                    scale: def.scale,
+                    offset_y: def.offset_y,
+                    clips: def.clips,
+                };
+                (id, model)
+            })
+            .collect();
+
+        Ok(EnemyModels {
+            fallback: file.fallback,
+            archetypes,
+        })
+    }
+}
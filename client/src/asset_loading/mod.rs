@@ -5,8 +5,10 @@ use bevy_open_vat::prelude::RemapInfo;
 use bevy_seedling::sample::AudioSample;
 use bevy_shuffle_bag::ShuffleBag;
 
+mod enemy_models;
 mod ron;
 mod tracking;
+pub use enemy_models::*;
 pub use ron::*;
 pub use tracking::*;
 
@@ -15,8 +17,9 @@ pub fn plugin(app: &mut App) {
     app.add_plugins(tracking::plugin)
         .add_plugins(RonAssetPlugin::<Config>::default())
         .load_resource_from_path::<Config>("config.ron")
+        .add_plugins(enemy_models::plugin)
         .load_resource::<Fonts>()
-        .load_resource::<Models>()
+        .load_gameplay_resource::<Models>()
         .load_resource::<AudioSources>();
 }
 
@@ -52,12 +55,6 @@ pub struct Models {
     pub player: Handle<Gltf>,
     #[dependency]
     pub scene: Handle<Gltf>,
-    #[dependency]
-    pub enemy_scene: Handle<Gltf>,
-    #[dependency]
-    pub enemy_vat_texture: Handle<Image>,
-    #[dependency]
-    pub enemy_remap_info: Handle<RemapInfo>,
 }
 
 const PLAYER_MODEL: &str = "models/player.glb";
@@ -73,9 +70,6 @@ impl FromWorld for Models {
                 });
             }),
             scene: assets.load("models/scene.glb"),
-            enemy_scene: assets.load("models/zombie_vat/zombie.glb"),
-            enemy_vat_texture: assets.load("models/zombie_vat/zombie_vat.exr"),
-            enemy_remap_info: assets.load("models/zombie_vat/zombie-remap_info.json"),
         }
     }
 }
@@ -98,6 +92,12 @@ pub struct AudioSources {
     pub explore: ShuffleBag<Handle<AudioSample>>,
     #[dependency]
     pub combat: ShuffleBag<Handle<AudioSample>>,
+
+    // ambience
+    #[dependency]
+    pub wind: Handle<AudioSample>,
+    #[dependency]
+    pub cave_drips: Handle<AudioSample>,
 }
 
 impl AudioSources {
@@ -113,6 +113,8 @@ impl AudioSources {
     ];
     pub const PUNCHES: &[&'static str] = &["audio/sfx/punch.wav"];
     pub const GAMEPLAY: &'static str = "audio/music/embrace-the-fight.ogg";
+    pub const WIND: &'static str = "audio/ambience/wind.wav";
+    pub const CAVE_DRIPS: &'static str = "audio/ambience/cave-drips.wav";
 }
 
 impl FromWorld for AudioSources {
@@ -132,6 +134,8 @@ impl FromWorld for AudioSources {
             explore: ShuffleBag::try_new(vec![gameplay], &mut rng).unwrap(),
             hover: a.load(Self::BTN_HOVER),
             press: a.load(Self::BTN_PRESS),
+            wind: a.load(Self::WIND),
+            cave_drips: a.load(Self::CAVE_DRIPS),
         }
     }
 }
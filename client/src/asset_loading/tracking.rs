@@ -17,58 +17,102 @@ pub trait LoadResource {
         &mut self,
         path: impl Into<String>,
     ) -> &mut Self;
+    /// Like [`LoadResource::load_resource`], but only gameplay needs it: the
+    /// title screen doesn't wait for it, so it can stream in while connecting.
+    fn load_gameplay_resource<T: Resource + Asset + Clone + FromWorld>(&mut self) -> &mut Self;
+    /// Like [`LoadResource::load_resource_from_path`], but only gameplay needs it.
+    fn load_gameplay_resource_from_path<T: Resource + Asset + Clone>(
+        &mut self,
+        path: impl Into<String>,
+    ) -> &mut Self;
 }
 
 impl LoadResource for App {
     fn load_resource<T: Resource + Asset + Clone + FromWorld>(&mut self) -> &mut Self {
-        self.init_asset::<T>();
-        let world = self.world_mut();
-        let value = T::from_world(world);
-        let assets = world.resource::<AssetServer>();
-        let handle = assets.add(value);
-        let mut handles = world.resource_mut::<ResourceHandles>();
-        handles.push_handle(handle);
-        self
+        add_resource::<T>(self, LoadPriority::Critical)
     }
 
     fn load_resource_from_path<T: Resource + Asset + Clone>(
         &mut self,
         path: impl Into<String>,
     ) -> &mut Self {
-        self.init_asset::<T>();
-        let _handle = {
-            let world = self.world_mut();
-            let assets = world.resource::<AssetServer>();
-            let handle: Handle<T> = assets.load::<T>(path.into());
-            let src_handle = handle.clone();
-            let mut handles = world.resource_mut::<ResourceHandles>();
-            handles.push_handle(handle);
-            src_handle
-        };
-
-        // If we are running in dev mode on desktop where hot-reloading of assets is possible
-        // then watch the asset and update the resource if it changes.
-        #[cfg(feature = "dev")]
-        self.add_systems(
-            Update,
-            move |mut commands: Commands,
-                  mut events: MessageReader<AssetEvent<T>>,
-                  assets: Res<Assets<T>>| {
-                for event in events.read() {
-                    match event {
-                        AssetEvent::Modified { id } if *id == _handle.id() => {
-                            if let Some(asset) = assets.get(&_handle) {
-                                commands.insert_resource(asset.clone());
-                            }
+        load_resource_from_path::<T>(self, path.into(), LoadPriority::Critical)
+    }
+
+    fn load_gameplay_resource<T: Resource + Asset + Clone + FromWorld>(&mut self) -> &mut Self {
+        add_resource::<T>(self, LoadPriority::Gameplay)
+    }
+
+    fn load_gameplay_resource_from_path<T: Resource + Asset + Clone>(
+        &mut self,
+        path: impl Into<String>,
+    ) -> &mut Self {
+        load_resource_from_path::<T>(self, path.into(), LoadPriority::Gameplay)
+    }
+}
+
+fn add_resource<T: Resource + Asset + Clone + FromWorld>(
+    app: &mut App,
+    priority: LoadPriority,
+) -> &mut App {
+    app.init_asset::<T>();
+    let world = app.world_mut();
+    let value = T::from_world(world);
+    let assets = world.resource::<AssetServer>();
+    let handle = assets.add(value);
+    let mut handles = world.resource_mut::<ResourceHandles>();
+    handles.push_handle(handle, priority);
+    app
+}
+
+fn load_resource_from_path<T: Resource + Asset + Clone>(
+    app: &mut App,
+    path: String,
+    priority: LoadPriority,
+) -> &mut App {
+    app.init_asset::<T>();
+    let _handle = {
+        let world = app.world_mut();
+        let assets = world.resource::<AssetServer>();
+        let handle: Handle<T> = assets.load::<T>(path);
+        let src_handle = handle.clone();
+        let mut handles = world.resource_mut::<ResourceHandles>();
+        handles.push_handle(handle, priority);
+        src_handle
+    };
+
+    // If we are running in dev mode on desktop where hot-reloading of assets is possible
+    // then watch the asset and update the resource if it changes.
+    #[cfg(feature = "dev")]
+    app.add_systems(
+        Update,
+        move |mut commands: Commands,
+              mut events: MessageReader<AssetEvent<T>>,
+              assets: Res<Assets<T>>| {
+            for event in events.read() {
+                match event {
+                    AssetEvent::Modified { id } if *id == _handle.id() => {
+                        if let Some(asset) = assets.get(&_handle) {
+                            commands.insert_resource(asset.clone());
                         }
-                        _ => {}
                     }
+                    _ => {}
                 }
-            },
-        );
+            }
+        },
+    );
 
-        self
-    }
+    app
+}
+
+/// Whether the title screen waits for an asset, or only gameplay does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoadPriority {
+    /// Needed before the title screen: config, fonts, UI audio.
+    Critical,
+    /// Only needed in gameplay, so it streams in while the player picks a
+    /// mode and the server handshake runs.
+    Gameplay,
 }
 
 /// A function that inserts a loaded resource.
@@ -78,7 +122,7 @@ type InsertLoadedResource = fn(&mut World, &UntypedHandle);
 pub struct ResourceHandles {
     // Use a queue for waiting assets so they can be cycled through and moved to
     // `finished` one at a time.
-    waiting: VecDeque<(UntypedHandle, InsertLoadedResource)>,
+    waiting: VecDeque<(UntypedHandle, InsertLoadedResource, LoadPriority)>,
     finished: Vec<UntypedHandle>,
 }
 
@@ -88,15 +132,35 @@ impl ResourceHandles {
         self.waiting.is_empty()
     }
 
+    /// Returns true once everything the title screen needs is available.
+    pub fn is_critical_done(&self) -> bool {
+        self.waiting
+            .iter()
+            .all(|(_, _, priority)| *priority != LoadPriority::Critical)
+    }
+
+    /// How many assets are still loading.
+    pub fn pending(&self) -> usize {
+        self.waiting.len()
+    }
+
     /// Adds an asset handle to the list of pending assets to be tracked and converted to resources
     /// on load.
-    pub fn push_handle<T: Asset + Resource + Clone>(&mut self, handle: Handle<T>) {
-        self.waiting.push_back((handle.untyped(), |world, handle| {
-            let assets = world.resource::<Assets<T>>();
-            if let Some(value) = assets.get(handle.id().typed::<T>()) {
-                world.insert_resource(value.clone());
-            }
-        }));
+    pub fn push_handle<T: Asset + Resource + Clone>(
+        &mut self,
+        handle: Handle<T>,
+        priority: LoadPriority,
+    ) {
+        self.waiting.push_back((
+            handle.untyped(),
+            |world, handle| {
+                let assets = world.resource::<Assets<T>>();
+                if let Some(value) = assets.get(handle.id().typed::<T>()) {
+                    world.insert_resource(value.clone());
+                }
+            },
+            priority,
+        ));
     }
 }
 
@@ -104,12 +168,14 @@ fn load_resource_assets(world: &mut World) {
     world.resource_scope(|world, mut resource_handles: Mut<ResourceHandles>| {
         world.resource_scope(|world, assets: Mut<AssetServer>| {
             for _ in 0..resource_handles.waiting.len() {
-                let (handle, insert_fn) = resource_handles.waiting.pop_front().unwrap();
+                let (handle, insert_fn, priority) = resource_handles.waiting.pop_front().unwrap();
                 if assets.is_loaded_with_dependencies(&handle) {
                     insert_fn(world, &handle);
                     resource_handles.finished.push(handle);
                 } else {
-                    resource_handles.waiting.push_back((handle, insert_fn));
+                    resource_handles
+                        .waiting
+                        .push_back((handle, insert_fn, priority));
                 }
             }
         });
@@ -0,0 +1,213 @@
+//! Web boot checks. Runs before the app is built so a browser that can't run
+//! the game gets an explanation on the page instead of a blank canvas.
+//!
+//! Browser APIs are probed reflectively — some of them (WebGPU, the
+//! cross-origin isolation flag) are missing from web-sys's stable bindings.
+
+use js_sys::{Function, Promise, Reflect};
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Document, Element, Window};
+
+const OVERLAY_ID: &str = "boot-check";
+
+struct Issue {
+    title: &'static str,
+    fix: String,
+}
+
+/// Check the page and report anything that will break the game. The server
+/// check is asynchronous and reports on its own when the ping fails.
+pub fn run(server_uri: &str) {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+
+    let mut issues = Vec::new();
+    check_graphics(&window, &mut issues);
+    check_isolation(&window, &mut issues);
+    for issue in &issues {
+        report(&window, issue);
+    }
+
+    check_server(&window, server_uri);
+}
+
+fn check_graphics(window: &Window, issues: &mut Vec<Issue>) {
+    // The `web` feature renders through WebGPU; without it bevy uses WebGL2
+    #[cfg(feature = "web")]
+    if !has_property(&window.navigator(), "gpu") {
+        issues.push(Issue {
+            title: "WebGPU is not available",
+            fix: "Use a recent Chrome or Edge. In Firefox or Safari, turn WebGPU on in the \
+                  browser's experimental features."
+                .into(),
+        });
+    }
+
+    #[cfg(not(feature = "web"))]
+    if !supports_webgl2(window) {
+        issues.push(Issue {
+            title: "WebGL2 is not available",
+            fix: "Enable hardware acceleration in the browser settings, or update your graphics \
+                  drivers."
+                .into(),
+        });
+    }
+}
+
+#[cfg(not(feature = "web"))]
+fn supports_webgl2(window: &Window) -> bool {
+    let Some(canvas) = window
+        .document()
+        .and_then(|d| d.create_element("canvas").ok())
+    else {
+        return false;
+    };
+    call_method(&canvas, "getContext", &"webgl2".into())
+        .is_some_and(|ctx| !ctx.is_null() && !ctx.is_undefined())
+}
+
+/// The web audio backend runs in an AudioWorklet that shares memory with the
+/// main thread, which browsers only allow on cross-origin isolated pages.
+fn check_isolation(window: &Window, issues: &mut Vec<Issue>) {
+    let isolated = Reflect::get(window, &"crossOriginIsolated".into())
+        .ok()
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    if !isolated {
+        issues.push(Issue {
+            title: "Page is not cross-origin isolated — audio is disabled",
+            fix: "Serve the game with the headers `Cross-Origin-Opener-Policy: same-origin` and \
+                  `Cross-Origin-Embedder-Policy: require-corp`."
+                .into(),
+        });
+    }
+
+    if !has_property(&js_sys::global(), "SharedArrayBuffer") {
+        issues.push(Issue {
+            title: "SharedArrayBuffer is unavailable",
+            fix: "Open the game over HTTPS (or localhost) with the cross-origin isolation \
+                  headers above."
+                .into(),
+        });
+    }
+}
+
+/// Ping SpacetimeDB's HTTP API on the same host as the websocket URI.
+fn check_server(window: &Window, server_uri: &str) {
+    let ping_url = format!(
+        "{}/v1/ping",
+        server_uri
+            .replacen("wss://", "https://", 1)
+            .replacen("ws://", "http://", 1)
+            .trim_end_matches('/')
+    );
+    let Some(promise) = call_method(window, "fetch", &ping_url.as_str().into())
+        .and_then(|p| p.dyn_into::<Promise>().ok())
+    else {
+        return;
+    };
+
+    let unreachable = {
+        let uri = server_uri.to_string();
+        move || {
+            if let Some(window) = web_sys::window() {
+                report(
+                    &window,
+                    &Issue {
+                        title: "Can't reach the game server",
+                        fix: format!(
+                            "Tried {uri}. Check that SpacetimeDB is running, or add \
+                             ?stdb=wss://your-host to the page URL to use another server."
+                        ),
+                    },
+                );
+            }
+        }
+    };
+    let on_error = unreachable.clone();
+
+    let on_response = Closure::once_into_js(move |response: JsValue| {
+        let ok = Reflect::get(&response, &"ok".into())
+            .ok()
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        if !ok {
+            unreachable();
+        }
+    });
+    let on_error = Closure::once_into_js(move |_: JsValue| on_error());
+    let _ = promise.then2(on_response.unchecked_ref(), on_error.unchecked_ref());
+}
+
+// ── Overlay ─────────────────────────────────────────────────────────
+
+/// Append `issue` to the overlay, creating the overlay on first use.
+fn report(window: &Window, issue: &Issue) {
+    let Some(document) = window.document() else {
+        return;
+    };
+    let Some(overlay) = overlay(&document) else {
+        return;
+    };
+
+    let Ok(entry) = document.create_element("div") else {
+        return;
+    };
+    let _ = entry.set_attribute("style", "margin-top: 12px;");
+    // Text nodes only — the server URI comes from the page URL
+    if let Ok(title) = document.create_element("strong") {
+        title.set_text_content(Some(issue.title));
+        let _ = entry.append_child(&title);
+    }
+    if let Ok(fix) = document.create_element("div") {
+        fix.set_text_content(Some(&issue.fix));
+        let _ = fix.set_attribute("style", "opacity: 0.8;");
+        let _ = entry.append_child(&fix);
+    }
+    // Keep the dismiss button last
+    let _ = overlay.insert_before(&entry, overlay.last_child().as_ref());
+}
+
+fn overlay(document: &Document) -> Option<Element> {
+    if let Some(existing) = document.get_element_by_id(OVERLAY_ID) {
+        return Some(existing);
+    }
+
+    let overlay = document.create_element("div").ok()?;
+    overlay.set_id(OVERLAY_ID);
+    let _ = overlay.set_attribute(
+        "style",
+        "position: fixed; top: 16px; left: 50%; transform: translateX(-50%); \
+         max-width: 560px; padding: 16px 20px; z-index: 1000; \
+         background: rgba(10, 10, 10, 0.92); color: #e5e5e5; \
+         border: 1px solid #7f1d1d; border-radius: 4px; \
+         font: 14px/1.4 sans-serif;",
+    );
+
+    let header = document.create_element("div").ok()?;
+    header.set_text_content(Some("This browser can't run the game properly"));
+    let _ = header.set_attribute("style", "font-weight: bold; color: #f87171;");
+    let _ = overlay.append_child(&header);
+
+    let dismiss = document.create_element("button").ok()?;
+    dismiss.set_text_content(Some("Continue anyway"));
+    let _ = dismiss.set_attribute("onclick", "this.parentElement.remove()");
+    let _ = dismiss.set_attribute("style", "float: right; margin-top: 12px;");
+    let _ = overlay.append_child(&dismiss);
+
+    document.body()?.append_child(&overlay).ok()?;
+    Some(overlay)
+}
+
+// ── Reflection helpers ──────────────────────────────────────────────
+
+fn has_property(target: &JsValue, name: &str) -> bool {
+    Reflect::get(target, &name.into()).is_ok_and(|v| !v.is_undefined() && !v.is_null())
+}
+
+fn call_method(target: &JsValue, name: &str, arg: &JsValue) -> Option<JsValue> {
+    let method = Reflect::get(target, &name.into()).ok()?;
+    method.dyn_ref::<Function>()?.call1(target, arg).ok()
+}
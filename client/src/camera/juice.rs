@@ -3,14 +3,14 @@ use bevy::prelude::*;
 use bevy::transform::TransformSystems;
 use bevy_third_person_camera::CameraSyncSet;
 
-use crate::models::{Config, Player, SceneCamera, Screen};
+use crate::models::{Config, Player, SceneCamera, Screen, Settings};
 use crate::player::control::{AirborneTracker, JumpCharge, LandingStun, Sprinting};
 
 /// Tracks dynamic FOV state for smooth interpolation.
 #[derive(Resource)]
 pub struct DynamicFov {
     current: f32, // radians
-    base: f32,    // radians (from config)
+    base: f32,    // radians (from settings)
 }
 
 impl Default for DynamicFov {
@@ -38,6 +38,7 @@ const FALL_MAX_VELOCITY: f32 = 25.0;
 fn dynamic_fov(
     time: Res<Time>,
     cfg: Res<Config>,
+    settings: Res<Settings>,
     mut fov_state: ResMut<DynamicFov>,
     player: Query<
         (
@@ -59,10 +60,12 @@ fn dynamic_fov(
         return;
     };
 
-    // Lazily sync base from config
-    let config_fov = cfg.player.fov.to_radians();
-    if (fov_state.base - config_fov).abs() > 0.001 {
-        fov_state.base = config_fov;
+    // Follow the FOV setting live: shift the current value with the base, so
+    // the change shows even while paused and virtual time stands still
+    let settings_fov = settings.fov.to_radians();
+    if (fov_state.base - settings_fov).abs() > 0.001 {
+        fov_state.current += settings_fov - fov_state.base;
+        fov_state.base = settings_fov;
     }
 
     let speed = controller.basis_memory.running_velocity.length();
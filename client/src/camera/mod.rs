@@ -8,13 +8,21 @@ use bevy::{
 
 mod assist;
 mod juice;
+mod observer;
 mod third_person;
 
+pub use observer::{ObserverMode, ObserverRig, ObserverView};
+
 pub fn plugin(app: &mut App) {
     app.insert_resource(DefaultOpaqueRendererMethod::deferred())
         .add_systems(Startup, spawn_camera);
 
-    app.add_plugins((third_person::plugin, assist::plugin, juice::plugin));
+    app.add_plugins((
+        third_person::plugin,
+        observer::plugin,
+        assist::plugin,
+        juice::plugin,
+    ));
 }
 
 pub fn spawn_camera(mut commands: Commands) {
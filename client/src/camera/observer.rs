@@ -0,0 +1,361 @@
+//! Observer camera: with no character of our own, the camera follows the
+//! action, a chosen player, or flies free.
+//!
+//! - Following the action keeps a slow orbit around the densest cluster of
+//!   recent combat events.
+//! - Spectate next/prev pick a player to follow instead.
+//! - The spectate key switches to the free camera and back.
+
+use bevy::prelude::*;
+use bevy::window::{CursorGrabMode, CursorOptions, PrimaryWindow};
+use bevy_enhanced_input::prelude::*;
+use spacetimedb_sdk::{Identity, Table};
+use std::collections::VecDeque;
+
+use crate::models::{
+    GameplayCleanup, Navigate, Observing, Pan, SceneCamera, Screen, Settings, Spectate,
+    SpectateNext, SpectatePrev, SpectatorCtx, is_observing,
+};
+use crate::networking::generated::combat_event_table::CombatEventTableAccess;
+use crate::networking::generated::player_table::PlayerTableAccess;
+use crate::networking::{ServerId, SpacetimeDbConnection};
+use crate::player::RemotePlayer;
+
+/// How long a combat event counts towards where the action is.
+const ACTION_MEMORY_SECS: f32 = 4.0;
+/// Events within this distance of each other count as one fight.
+const ACTION_RADIUS: f32 = 10.0;
+/// Oldest events are dropped past this many, so a brawl can't grow the
+/// hotspot search without bound.
+const MAX_REMEMBERED_EVENTS: usize = 256;
+const FOLLOW_DISTANCE: f32 = 18.0;
+const FOLLOW_PITCH: f32 = -0.6;
+/// How quickly the follow camera catches up with its focus.
+const FOLLOW_RATE: f32 = 2.0;
+/// Radians per second of the slow orbit while following.
+const ORBIT_SPEED: f32 = 0.08;
+const FLY_SPEED: f32 = 20.0;
+const LOOK_SENSITIVITY: f32 = 0.02;
+
+pub fn plugin(app: &mut App) {
+    app.init_resource::<ObserverView>()
+        .add_systems(
+            OnEnter(Screen::Gameplay),
+            spawn_observer_rig.run_if(is_observing),
+        )
+        .add_systems(
+            OnExit(Screen::Gameplay),
+            release_observer_camera.before(GameplayCleanup),
+        )
+        .add_systems(
+            Update,
+            (
+                track_action,
+                grab_cursor,
+                look,
+                follow.after(track_action).after(look),
+                fly.after(look),
+            )
+                .run_if(in_state(Screen::Gameplay).and(is_observing)),
+        )
+        .add_observer(toggle_free_camera)
+        .add_observer(follow_next_player)
+        .add_observer(follow_prev_player);
+}
+
+/// Holds the observer's input contexts in place of a player entity.
+#[derive(Component)]
+pub struct ObserverRig;
+
+/// What the observer camera is pointed at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ObserverMode {
+    /// The densest cluster of recent combat events.
+    #[default]
+    Action,
+    Player(Identity),
+    FreeCamera,
+}
+
+#[derive(Resource, Debug, Default)]
+pub struct ObserverView {
+    pub mode: ObserverMode,
+    /// Smoothed point the follow modes look at.
+    focus: Vec3,
+    yaw: f32,
+    pitch: f32,
+    /// Recent combat event positions with when they arrived, oldest first.
+    events: VecDeque<(Vec3, f32)>,
+    last_event_id: u64,
+}
+
+fn spawn_observer_rig(
+    settings: Res<Settings>,
+    mut view: ResMut<ObserverView>,
+    mut camera: Query<Entity, With<SceneCamera>>,
+    mut commands: Commands,
+) {
+    *view = ObserverView {
+        pitch: FOLLOW_PITCH,
+        ..default()
+    };
+    commands.spawn((
+        Name::new("ObserverRig"),
+        ObserverRig,
+        DespawnOnExit(Screen::Gameplay),
+    ));
+    if let Ok(camera) = camera.single_mut() {
+        commands
+            .entity(camera)
+            .insert(Projection::from(PerspectiveProjection {
+                fov: settings.fov.to_radians(),
+                ..default()
+            }));
+    }
+}
+
+/// Hand the cursor back and point the camera at nothing, as the third person
+/// camera does on exit. [`Observing`] may already be gone by the time the
+/// screen exits, so this runs for every session.
+fn release_observer_camera(
+    mut windows: Query<&mut CursorOptions, With<PrimaryWindow>>,
+    mut camera: Query<&mut Transform, With<SceneCamera>>,
+) {
+    if let Ok(mut cursor) = windows.single_mut() {
+        cursor.grab_mode = CursorGrabMode::None;
+        cursor.visible = true;
+    }
+    if let Ok(mut transform) = camera.single_mut() {
+        *transform = Transform::from_xyz(0.0, 50.0, 0.0).looking_at(Vec3::ZERO, Vec3::Y);
+    }
+}
+
+/// Lock the cursor while the rig has the camera controls, free it for menus.
+fn grab_cursor(
+    rig: Query<Has<SpectatorCtx>, With<ObserverRig>>,
+    mut windows: Query<&mut CursorOptions, With<PrimaryWindow>>,
+) {
+    let locked = rig.single().unwrap_or(false);
+    let Ok(mut cursor) = windows.single_mut() else {
+        return;
+    };
+    let grab_mode = if locked {
+        CursorGrabMode::Locked
+    } else {
+        CursorGrabMode::None
+    };
+    if cursor.grab_mode != grab_mode {
+        cursor.grab_mode = grab_mode;
+        cursor.visible = !locked;
+    }
+}
+
+/// Remember where new combat events happened.
+fn track_action(
+    conn: Option<Res<SpacetimeDbConnection>>,
+    time: Res<Time>,
+    mut view: ResMut<ObserverView>,
+) {
+    let now = time.elapsed_secs();
+    if let Some(conn) = conn {
+        let last_id = view.last_event_id;
+        let mut fresh: Vec<_> = conn
+            .conn
+            .db
+            .combat_event()
+            .iter()
+            .filter(|e| e.id > last_id)
+            .collect();
+        fresh.sort_by_key(|e| e.id);
+        for event in fresh {
+            view.last_event_id = event.id;
+            view.events
+                .push_back((Vec3::new(event.x, event.y, event.z), now));
+        }
+    }
+    while view
+        .events
+        .front()
+        .is_some_and(|(_, at)| now - at > ACTION_MEMORY_SECS)
+        || view.events.len() > MAX_REMEMBERED_EVENTS
+    {
+        view.events.pop_front();
+    }
+}
+
+/// Centre of the densest cluster of remembered events, weighting newer ones
+/// more.
+fn action_hotspot(events: &VecDeque<(Vec3, f32)>, now: f32) -> Option<Vec3> {
+    let weight = |at: f32| 1.0 - (now - at) / ACTION_MEMORY_SECS;
+    let cluster = |center: Vec3| {
+        events
+            .iter()
+            .filter(move |(pos, _)| pos.distance(center) <= ACTION_RADIUS)
+    };
+    let (center, _) = events
+        .iter()
+        .map(|(pos, _)| {
+            let score: f32 = cluster(*pos).map(|(_, at)| weight(*at)).sum();
+            (*pos, score)
+        })
+        .max_by(|a, b| a.1.total_cmp(&b.1))?;
+
+    let (sum, total) = cluster(center).fold((Vec3::ZERO, 0.0), |(sum, total), (pos, at)| {
+        let w = weight(*at).max(0.01);
+        (sum + *pos * w, total + w)
+    });
+    Some(sum / total)
+}
+
+fn look(
+    time: Res<Time>,
+    pan: Query<&Action<Pan>, With<ActionOf<SpectatorCtx>>>,
+    mut view: ResMut<ObserverView>,
+) {
+    let Ok(pan) = pan.single() else {
+        return;
+    };
+    let delta = **pan * LOOK_SENSITIVITY;
+    view.yaw += delta.x;
+    view.pitch = (view.pitch + delta.y).clamp(-1.5, 1.5);
+    if view.mode != ObserverMode::FreeCamera {
+        view.yaw += ORBIT_SPEED * time.delta_secs();
+        view.pitch = view.pitch.min(-0.1);
+    }
+}
+
+/// Orbit the followed player, or the action when there is no player to
+/// follow. With no fighting going on, watch the middle of the players.
+fn follow(
+    time: Res<Time>,
+    mut view: ResMut<ObserverView>,
+    players: Query<(&ServerId, &GlobalTransform), With<RemotePlayer>>,
+    mut camera: Query<&mut Transform, With<SceneCamera>>,
+) {
+    let target = match view.mode {
+        ObserverMode::FreeCamera => return,
+        ObserverMode::Player(identity) => players
+            .iter()
+            .find(|(id, _)| **id == ServerId::Player(identity))
+            .map(|(_, transform)| transform.translation()),
+        ObserverMode::Action => None,
+    };
+    let target = target
+        .or_else(|| action_hotspot(&view.events, time.elapsed_secs()))
+        .or_else(|| {
+            let count = players.iter().len();
+            (count > 0).then(|| {
+                players
+                    .iter()
+                    .map(|(_, transform)| transform.translation())
+                    .sum::<Vec3>()
+                    / count as f32
+            })
+        });
+    if let Some(target) = target {
+        let blend = 1.0 - (-FOLLOW_RATE * time.delta_secs()).exp();
+        view.focus = view.focus.lerp(target, blend);
+    }
+
+    let Ok(mut transform) = camera.single_mut() else {
+        return;
+    };
+    let rotation = Quat::from_euler(EulerRot::YXZ, view.yaw, view.pitch, 0.0);
+    transform.translation = view.focus + rotation * Vec3::Z * FOLLOW_DISTANCE;
+    transform.rotation = rotation;
+}
+
+fn fly(
+    time: Res<Time>,
+    view: Res<ObserverView>,
+    navigate: Query<&Action<Navigate>, With<ActionOf<SpectatorCtx>>>,
+    mut camera: Query<&mut Transform, With<SceneCamera>>,
+) {
+    if view.mode != ObserverMode::FreeCamera {
+        return;
+    }
+    let Ok(mut transform) = camera.single_mut() else {
+        return;
+    };
+    transform.rotation = Quat::from_euler(EulerRot::YXZ, view.yaw, view.pitch, 0.0);
+    let input = navigate.single().map_or(Vec2::ZERO, |a| **a);
+    let motion = transform.forward() * input.y + transform.right() * input.x;
+    transform.translation += motion * FLY_SPEED * time.delta_secs();
+}
+
+fn toggle_free_camera(
+    _: On<Start<Spectate>>,
+    observing: Option<Res<Observing>>,
+    mut view: ResMut<ObserverView>,
+    camera: Query<&Transform, With<SceneCamera>>,
+) {
+    if observing.is_none() {
+        return;
+    }
+    view.mode = if view.mode == ObserverMode::FreeCamera {
+        ObserverMode::Action
+    } else {
+        // Take over from wherever the follow camera is looking
+        if let Ok(transform) = camera.single() {
+            let (yaw, pitch, _) = transform.rotation.to_euler(EulerRot::YXZ);
+            view.yaw = yaw;
+            view.pitch = pitch;
+        }
+        ObserverMode::FreeCamera
+    };
+}
+
+/// Online players in a stable order, for cycling through them.
+fn player_order(conn: &SpacetimeDbConnection) -> Vec<Identity> {
+    let mut players: Vec<_> = conn
+        .conn
+        .db
+        .player()
+        .iter()
+        .filter(|p| p.online)
+        .map(|p| p.identity)
+        .collect();
+    players.sort_by_key(|id| id.to_hex().to_string());
+    players
+}
+
+/// Step through the players by `step`. Stepping past either end goes back
+/// to following the action.
+fn cycle_followed(view: &mut ObserverView, conn: &SpacetimeDbConnection, step: isize) {
+    let players = player_order(conn);
+    let current = match view.mode {
+        ObserverMode::Player(identity) => players.iter().position(|p| *p == identity),
+        _ => None,
+    };
+    let next = match current {
+        Some(i) => i as isize + step,
+        None if step > 0 => 0,
+        None => players.len() as isize - 1,
+    };
+    view.mode = usize::try_from(next)
+        .ok()
+        .and_then(|i| players.get(i))
+        .map_or(ObserverMode::Action, |p| ObserverMode::Player(*p));
+}
+
+fn follow_next_player(
+    _: On<Start<SpectateNext>>,
+    observing: Option<Res<Observing>>,
+    conn: Option<Res<SpacetimeDbConnection>>,
+    mut view: ResMut<ObserverView>,
+) {
+    if let (Some(_), Some(conn)) = (observing, conn) {
+        cycle_followed(&mut view, &conn, 1);
+    }
+}
+
+fn follow_prev_player(
+    _: On<Start<SpectatePrev>>,
+    observing: Option<Res<Observing>>,
+    conn: Option<Res<SpacetimeDbConnection>>,
+    mut view: ResMut<ObserverView>,
+) {
+    if let (Some(_), Some(conn)) = (observing, conn) {
+        cycle_followed(&mut view, &conn, -1);
+    }
+}
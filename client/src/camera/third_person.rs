@@ -3,13 +3,17 @@ use crate::ui::Modals;
 use bevy_third_person_camera::*;
 
 pub fn plugin(app: &mut App) {
-    app.add_systems(OnEnter(Screen::Gameplay), add_tpv_cam)
-        .add_systems(OnExit(Screen::Gameplay), rm_tpv_cam.before(GameplayCleanup))
-        .add_observer(toggle_cam_cursor);
+    app.add_systems(
+        OnEnter(Screen::Gameplay),
+        add_tpv_cam.run_if(not(is_observing)),
+    )
+    .add_systems(OnExit(Screen::Gameplay), rm_tpv_cam.before(GameplayCleanup))
+    .add_observer(toggle_cam_cursor);
 }
 
 fn add_tpv_cam(
     cfg: Res<Config>,
+    settings: Res<Settings>,
     mut commands: Commands,
     mut camera: Query<(Entity, &mut Transform), With<SceneCamera>>,
     mut tpv_cam: Query<Entity, With<ThirdPersonCamera>>,
@@ -38,7 +42,7 @@ fn add_tpv_cam(
             ..default()
         },
         Projection::from(PerspectiveProjection {
-            fov: cfg.player.fov.to_radians(),
+            fov: settings.fov.to_radians(),
             ..Default::default()
         }),
     ));
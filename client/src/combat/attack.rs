@@ -1,9 +1,10 @@
 use super::*;
+use crate::networking::ServerId;
 use crate::player::ControlScheme;
 use crate::player::control::{GroundPoundImpact, GroundPoundState, InputBuffer, LandingImpact};
 use crate::rules::{
     OnCritHitRules, OnHitRules, OnKillRules, OnPreHitRules, OnTakeDamageRules, OnTickRules, Stat,
-    Stats,
+    Stats, StatusEffects, Stunned, grant_statuses, play_cues, report_traces, spawn_requested,
 };
 use avian3d::prelude::LinearVelocity;
 use bevy_enhanced_input::prelude::Fire;
@@ -12,13 +13,15 @@ use wasm_fantasia_shared::combat::{
     CombatInput, HitTarget, defaults, ground_pound, landing_aoe, resolve_combat,
 };
 use wasm_fantasia_shared::presets::EntityRules;
+use wasm_fantasia_shared::rng::attack_seed;
 
 /// Visual constants for attack effects
 pub const VFX_RANGE: f32 = 2.0;
 pub const VFX_ARC_DEGREES: f32 = 120.0;
 
 pub fn plugin(app: &mut App) {
-    app.add_observer(handle_attack)
+    app.init_resource::<AttackSequence>()
+        .add_observer(handle_attack)
         .add_observer(on_attack_hit)
         .add_observer(on_ground_pound_hit)
         .add_observer(on_landing_aoe_hit)
@@ -38,15 +41,19 @@ fn handle_attack(
             &TnuaController<ControlScheme>,
             &LinearVelocity,
             Has<GroundPoundState>,
+            Has<Stunned>,
         ),
         With<PlayerCombatant>,
     >,
 ) {
-    let Ok((mut attack_state, controller, velocity, already_pounding)) =
+    let Ok((mut attack_state, controller, velocity, already_pounding, stunned)) =
         query.get_mut(on.context)
     else {
         return;
     };
+    if stunned {
+        return;
+    }
 
     // Airborne attack → ground pound (slam straight down)
     let grounded = controller.basis_memory.standing_on_entity().is_some();
@@ -84,19 +91,48 @@ fn process_buffered_attack(
     }
 }
 
+/// Numbers the player's swings. The server seeds a swing's rolls from the
+/// player's identity and its number, as [`on_attack_hit`] does, so predicted
+/// crits match. Networking restarts it for every connection and sets `seed`
+/// once the identity is known; offline it stays 0.
+#[derive(Resource, Debug)]
+pub struct AttackSequence {
+    pub next: u32,
+    pub seed: u64,
+}
+
+impl Default for AttackSequence {
+    fn default() -> Self {
+        Self { next: 1, seed: 0 }
+    }
+}
+
 /// Tick attack state timers and trigger hits based on time (not animation events).
+/// The wielded weapon scales the pace on top of the attack speed stat.
 fn tick_attack_state(
     time: Res<Time>,
-    mut query: Query<(Entity, &mut AttackState, Option<&Stats>)>,
+    mut query: Query<(
+        Entity,
+        &mut AttackState,
+        Option<&Stats>,
+        Option<&StatusEffects>,
+        Option<&EquippedWeapon>,
+        Has<PlayerCombatant>,
+    )>,
+    mut sequence: ResMut<AttackSequence>,
     mut commands: Commands,
 ) {
-    for (entity, mut state, stats) in query.iter_mut() {
-        let speed_mult = stats
+    for (entity, mut state, stats, statuses, weapon, is_player) in query.iter_mut() {
+        let weapon_speed = weapon.map_or(1.0, |w| w.0.stats().speed);
+        let haste = statuses.map_or(1.0, StatusEffects::haste);
+        let speed_mult = (stats
             .map(|s| {
                 let speed = s.get(&Stat::AttackSpeed);
                 if speed == 0.0 { 1.0 } else { speed }
             })
             .unwrap_or(1.0)
+            * weapon_speed
+            * haste)
             .max(0.1);
 
         let scaled_delta = time.delta().mul_f32(speed_mult);
@@ -113,7 +149,16 @@ fn tick_attack_state(
                 *elapsed += dt;
 
                 if *elapsed >= *hit_time {
-                    commands.trigger(AttackIntent { attacker: entity });
+                    let seq = if is_player {
+                        sequence.next += 1;
+                        sequence.next - 1
+                    } else {
+                        0
+                    };
+                    commands.trigger(AttackIntent {
+                        attacker: entity,
+                        seq,
+                    });
                     let remaining_duration = *total_duration - *hit_time;
                     let overshoot = *elapsed - *hit_time;
                     state.phase = AttackPhase::Recovery {
@@ -139,10 +184,42 @@ fn tick_attack_state(
     }
 }
 
+/// What the attack observers read off each enemy in reach.
+type AttackTarget = (
+    Entity,
+    &'static Transform,
+    &'static Health,
+    Option<&'static Shield>,
+    Option<&'static Stats>,
+    Option<&'static ServerId>,
+);
+
+fn hit_target(targets: &Query<AttackTarget, With<Enemy>>, entity: Entity, pos: Vec3) -> HitTarget {
+    let target = targets.get(entity).ok();
+    HitTarget {
+        id: entity.to_bits(),
+        // The server salts its rolls with the enemy's id
+        seed: match target.and_then(|(.., id)| id) {
+            Some(&ServerId::Enemy(id)) => id,
+            _ => entity.to_bits(),
+        },
+        pos: Vec2::new(pos.x, pos.z),
+        health: target.map_or(0.0, |(_, _, h, ..)| h.current),
+        max_health: target.map_or(0.0, |(_, _, h, ..)| h.max),
+        shield: target
+            .and_then(|(_, _, _, s, ..)| s)
+            .map_or(0.0, |s| s.current),
+        armor: target
+            .and_then(|(_, _, _, _, stats, _)| stats)
+            .map_or(0.0, |s| s.get(&Stat::Armor)),
+    }
+}
+
 /// Observer: triggered when attack hit time is reached.
 /// Calls [`resolve_combat`] and fires [`DamageDealt`] per hit.
 fn on_attack_hit(
     trigger: On<AttackIntent>,
+    sequence: Res<AttackSequence>,
     mut attackers: Query<
         (
             &mut AttackState,
@@ -157,7 +234,7 @@ fn on_attack_hit(
         ),
         With<PlayerCombatant>,
     >,
-    targets: Query<(Entity, &Transform, &Health), With<Enemy>>,
+    targets: Query<AttackTarget, With<Enemy>>,
     mut commands: Commands,
 ) {
     let attacker_entity = trigger.event().attacker;
@@ -194,6 +271,8 @@ fn on_attack_hit(
         on_kill: on_kill.map(|r| r.0.clone()).unwrap_or_default(),
         on_take_damage: on_take_damage.map(|r| r.0.clone()).unwrap_or_default(),
         on_tick: on_tick.map(|r| r.0.clone()).unwrap_or_default(),
+        // Dodge, block, threshold and timer rules don't run during a swing
+        ..Default::default()
     };
 
     let attacker_pos = transform.translation;
@@ -203,16 +282,12 @@ fn on_attack_hit(
     let vertical_reach = defaults::ATTACK_VERTICAL_REACH;
     let target_list: Vec<(Entity, Vec3)> = targets
         .iter()
-        .filter(|(_, tf, _)| (tf.translation.y - attacker_pos.y).abs() <= vertical_reach)
-        .map(|(e, tf, _)| (e, tf.translation))
+        .filter(|(_, tf, ..)| (tf.translation.y - attacker_pos.y).abs() <= vertical_reach)
+        .map(|(e, tf, ..)| (e, tf.translation))
         .collect();
     let hit_targets: Vec<HitTarget> = target_list
         .iter()
-        .map(|&(e, pos)| HitTarget {
-            id: e.to_bits(),
-            pos: Vec2::new(pos.x, pos.z),
-            health: targets.get(e).map(|(_, _, h)| h.current).unwrap_or(0.0),
-        })
+        .map(|&(e, pos)| hit_target(&targets, e, pos))
         .collect();
     let forward = transform.forward().as_vec3();
     let forward_xz = Vec2::new(forward.x, forward.z).normalize_or_zero();
@@ -226,9 +301,11 @@ fn on_attack_hit(
         half_arc_cos,
         attacker_stats: &attacker_stats,
         rules: &rules,
-        rng_seed: rand::random(),
+        rng_seed: attack_seed(sequence.seed, trigger.event().seq),
         targets: &hit_targets,
     });
+    grant_statuses(&mut commands, attacker_entity, output.statuses);
+    report_traces(&mut commands, attacker_entity, output.traces);
 
     // Write back modified stats (stacking etc.)
     if output.hit_any {
@@ -271,6 +348,13 @@ fn on_attack_hit(
             is_crit: hit.is_crit,
             feedback: hit.feedback.clone(),
         });
+        spawn_requested(
+            &mut commands,
+            attacker_entity,
+            Some(target_pos),
+            hit.spawns.clone(),
+        );
+        play_cues(&mut commands, attacker_entity, hit.cues.clone());
 
         if hit.is_crit {
             any_crit = true;
@@ -299,7 +383,7 @@ fn on_ground_pound_hit(
         ),
         With<PlayerCombatant>,
     >,
-    targets: Query<(Entity, &Transform, &Health), With<Enemy>>,
+    targets: Query<AttackTarget, With<Enemy>>,
     mut commands: Commands,
 ) {
     let Ok((
@@ -327,20 +411,22 @@ fn on_ground_pound_hit(
         on_kill: on_kill.map(|r| r.0.clone()).unwrap_or_default(),
         on_take_damage: on_take_damage.map(|r| r.0.clone()).unwrap_or_default(),
         on_tick: on_tick.map(|r| r.0.clone()).unwrap_or_default(),
+        // Dodge, block, threshold and timer rules don't run during a swing
+        ..Default::default()
     };
 
     // Gather enemies within AOE radius (XZ distance + vertical reach)
     let vertical_reach = defaults::ATTACK_VERTICAL_REACH;
     let target_list: Vec<(Entity, Vec3)> = targets
         .iter()
-        .filter(|(_, tf, _)| {
+        .filter(|(_, tf, ..)| {
             let dx = tf.translation.x - impact_pos.x;
             let dz = tf.translation.z - impact_pos.z;
             let xz_dist = (dx * dx + dz * dz).sqrt();
             let vert_ok = (tf.translation.y - impact_pos.y).abs() <= vertical_reach;
             xz_dist <= ground_pound::RADIUS && vert_ok
         })
-        .map(|(e, tf, _)| (e, tf.translation))
+        .map(|(e, tf, ..)| (e, tf.translation))
         .collect();
 
     if target_list.is_empty() {
@@ -350,11 +436,7 @@ fn on_ground_pound_hit(
     // Use resolve_combat for damage/crit — full-circle arc (half_arc_cos = -1.0)
     let hit_targets: Vec<HitTarget> = target_list
         .iter()
-        .map(|&(e, pos)| HitTarget {
-            id: e.to_bits(),
-            pos: Vec2::new(pos.x, pos.z),
-            health: targets.get(e).map(|(_, _, h)| h.current).unwrap_or(0.0),
-        })
+        .map(|&(e, pos)| hit_target(&targets, e, pos))
         .collect();
 
     let origin_xz = Vec2::new(impact_pos.x, impact_pos.z);
@@ -370,6 +452,8 @@ fn on_ground_pound_hit(
         rng_seed: rand::random(),
         targets: &hit_targets,
     });
+    grant_statuses(&mut commands, attacker_entity, output.statuses);
+    report_traces(&mut commands, attacker_entity, output.traces);
 
     // Fire DamageDealt per hit with outward radial knockback
     for hit in &output.hits {
@@ -400,6 +484,7 @@ fn on_ground_pound_hit(
             is_crit: hit.is_crit,
             feedback: hit.feedback.clone(),
         });
+        play_cues(&mut commands, attacker_entity, hit.cues.clone());
     }
 }
 
@@ -423,7 +508,7 @@ fn on_landing_aoe_hit(
         ),
         With<PlayerCombatant>,
     >,
-    targets: Query<(Entity, &Transform, &Health), With<Enemy>>,
+    targets: Query<AttackTarget, With<Enemy>>,
     mut commands: Commands,
 ) {
     let event = trigger.event();
@@ -468,20 +553,22 @@ fn on_landing_aoe_hit(
         on_kill: on_kill.map(|r| r.0.clone()).unwrap_or_default(),
         on_take_damage: on_take_damage.map(|r| r.0.clone()).unwrap_or_default(),
         on_tick: on_tick.map(|r| r.0.clone()).unwrap_or_default(),
+        // Dodge, block, threshold and timer rules don't run during a swing
+        ..Default::default()
     };
 
     // Gather enemies within AOE radius
     let vertical_reach = defaults::ATTACK_VERTICAL_REACH * 2.0; // generous vertical reach for slam
     let target_list: Vec<(Entity, Vec3)> = targets
         .iter()
-        .filter(|(_, tf, _)| {
+        .filter(|(_, tf, ..)| {
             let dx = tf.translation.x - impact_pos.x;
             let dz = tf.translation.z - impact_pos.z;
             let xz_dist = (dx * dx + dz * dz).sqrt();
             let vert_ok = (tf.translation.y - impact_pos.y).abs() <= vertical_reach;
             xz_dist <= radius && vert_ok
         })
-        .map(|(e, tf, _)| (e, tf.translation))
+        .map(|(e, tf, ..)| (e, tf.translation))
         .collect();
 
     if target_list.is_empty() {
@@ -490,11 +577,7 @@ fn on_landing_aoe_hit(
 
     let hit_targets: Vec<HitTarget> = target_list
         .iter()
-        .map(|&(e, pos)| HitTarget {
-            id: e.to_bits(),
-            pos: Vec2::new(pos.x, pos.z),
-            health: targets.get(e).map(|(_, _, h)| h.current).unwrap_or(0.0),
-        })
+        .map(|&(e, pos)| hit_target(&targets, e, pos))
         .collect();
 
     let origin_xz = Vec2::new(impact_pos.x, impact_pos.z);
@@ -510,6 +593,8 @@ fn on_landing_aoe_hit(
         rng_seed: rand::random(),
         targets: &hit_targets,
     });
+    grant_statuses(&mut commands, attacker_entity, output.statuses);
+    report_traces(&mut commands, attacker_entity, output.traces);
 
     for hit in &output.hits {
         let Some(&(target_entity, target_pos)) = target_list
@@ -539,5 +624,6 @@ fn on_landing_aoe_hit(
             is_crit: hit.is_crit,
             feedback: hit.feedback.clone(),
         });
+        play_cues(&mut commands, attacker_entity, hit.cues.clone());
     }
 }
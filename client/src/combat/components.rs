@@ -1,9 +1,11 @@
 use bevy::prelude::*;
 
 pub use wasm_fantasia_shared::combat::{attack_timing, hit_timing};
+use wasm_fantasia_shared::variation::EnemyVariation;
 
 pub fn plugin(app: &mut App) {
     app.register_type::<Health>()
+        .register_type::<Shield>()
         .register_type::<AttackState>()
         .register_type::<EnemyBehavior>();
 }
@@ -41,6 +43,77 @@ impl Default for Health {
     }
 }
 
+/// Energy shield that absorbs damage before [`Health`]. Recharges once
+/// `defaults::SHIELD_REGEN_DELAY` seconds pass without a hit.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component)]
+pub struct Shield {
+    pub current: f32,
+    pub max: f32,
+    pub since_hit: f32,
+}
+
+impl Shield {
+    pub fn new(max: f32) -> Self {
+        Self {
+            current: max,
+            max,
+            since_hit: 0.0,
+        }
+    }
+
+    pub fn fraction(&self) -> f32 {
+        if self.max > 0.0 {
+            self.current / self.max
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Damage this client dealt to a server-owned enemy that the server hasn't
+/// confirmed yet, oldest hit first. The reconciler shows the server's health
+/// and shield minus these; networking drops a hit once its `combat_event`
+/// arrives, or as rejected once the server has moved past it.
+#[derive(Component, Debug, Clone, Default)]
+pub struct PredictedDamage {
+    pub hits: std::collections::VecDeque<PredictedHit>,
+}
+
+/// One unconfirmed hit: what it took off health and shield locally.
+#[derive(Debug, Clone, Copy)]
+pub struct PredictedHit {
+    pub health: f32,
+    pub shield: f32,
+    /// Seconds since the hit landed locally.
+    pub age: f32,
+    /// [`InputSequence`](crate::networking::InputSequence) number of the
+    /// position update after it; the server has ruled on the hit once it
+    /// acknowledges a later one.
+    pub seq: u32,
+}
+
+impl PredictedDamage {
+    pub fn push(&mut self, health: f32, shield: f32, seq: u32) {
+        self.hits.push_back(PredictedHit {
+            health,
+            shield,
+            age: 0.0,
+            seq,
+        });
+    }
+
+    /// Health taken by all unconfirmed hits.
+    pub fn health(&self) -> f32 {
+        self.hits.iter().map(|h| h.health).sum()
+    }
+
+    /// Shield taken by all unconfirmed hits.
+    pub fn shield(&self) -> f32 {
+        self.hits.iter().map(|h| h.shield).sum()
+    }
+}
+
 /// Phase of an attack's lifecycle. Ready → Windup → Recovery → Ready.
 #[derive(Reflect, Debug, Clone, Default, PartialEq)]
 pub enum AttackPhase {
@@ -164,6 +237,18 @@ pub struct PlayerCombatant;
 #[reflect(Component)]
 pub struct Enemy;
 
+/// Which [`EnemyModels`](crate::asset_loading::EnemyModels) entry an enemy
+/// is drawn with. Enemies without one use the registry's fallback.
+#[derive(Component, Reflect, Debug, Clone, Default)]
+#[reflect(Component)]
+pub struct EnemyArchetype(pub String);
+
+/// Per-enemy tint, scale and animation offset, derived from the server id so
+/// every client draws a given enemy the same way. Enemies without one use
+/// the archetype's look unchanged.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct EnemyLook(pub EnemyVariation);
+
 /// Current behavior state for enemy AI and animation.
 #[derive(Component, Default, Clone, Copy, PartialEq, Eq, Reflect, Debug)]
 #[reflect(Component)]
@@ -2,6 +2,7 @@ use super::*;
 use crate::player::ControlScheme;
 use bevy_tnua::builtins::TnuaBuiltinKnockback;
 use bevy_tnua::prelude::{TnuaController, TnuaUserControlsSystems};
+use wasm_fantasia_shared::combat::{absorb_damage, regen_shield};
 
 /// Scale applied to the knockback vector before passing it to Tnua as a shove.
 /// The knockback value from `defaults::KNOCKBACK` is already in m/s (6.0), so
@@ -14,8 +15,10 @@ pub fn plugin(app: &mut App) {
         .add_observer(on_death)
         .add_systems(
             Update,
-            apply_pending_knockback
-                .after(TnuaUserControlsSystems)
+            (
+                apply_pending_knockback.after(TnuaUserControlsSystems),
+                tick_shield_regen,
+            )
                 .run_if(in_state(Screen::Gameplay)),
         );
 }
@@ -23,24 +26,66 @@ pub fn plugin(app: &mut App) {
 /// Observer: apply damage and knockback when [`DamageDealt`] is triggered.
 ///
 /// Server-owned entities: health is server-authoritative (reconciler syncs it).
+/// Enemies take the damage locally as a [`PredictedDamage`] hit until the
+/// server confirms or drops it.
 /// Knockback is always applied client-side for immediate visual feedback — the
 /// server also applies it via KnockbackImpulse, and reconciler corrects drift.
 fn on_damage(
     on: On<DamageDealt>,
-    mut targets: Query<&mut Health>,
+    mut targets: Query<(
+        &mut Health,
+        Option<&mut Shield>,
+        Option<&mut PredictedDamage>,
+    )>,
     server_entities: Query<(), With<crate::networking::ServerId>>,
+    inputs: Res<crate::networking::InputSequence>,
     mut commands: Commands,
 ) {
     let event = on.event();
 
-    let Ok(mut health) = targets.get_mut(event.target) else {
+    let Ok((mut health, shield, predicted)) = targets.get_mut(event.target) else {
         return;
     };
 
     let is_server_owned = server_entities.get(event.target).is_ok();
 
-    let died = if is_server_owned {
+    let died = if let Some(mut shield) = shield {
+        let absorbed = absorb_damage(shield.current, health.current, event.damage);
+        if absorbed.shield_broke {
+            commands.trigger(ShieldBroken {
+                entity: event.target,
+            });
+        }
+        if absorbed.blocked {
+            commands.trigger(Blocked {
+                entity: event.target,
+            });
+        }
+        shield.since_hit = 0.0;
+        if is_server_owned {
+            // Break VFX is predicted; the reconciler syncs the real values
+            if let Some(mut predicted) = predicted {
+                predicted.push(
+                    health.current - absorbed.health,
+                    shield.current - absorbed.shield,
+                    inputs.upcoming(),
+                );
+                shield.current = absorbed.shield;
+                health.current = absorbed.health;
+            }
+            false
+        } else {
+            shield.current = absorbed.shield;
+            health.current = absorbed.health;
+            health.is_dead()
+        }
+    } else if is_server_owned {
         // Server handles health — but apply knockback locally for responsive feel
+        if let Some(mut predicted) = predicted {
+            let lost = event.damage.min(health.current);
+            predicted.push(lost, 0.0, inputs.upcoming());
+            health.current -= lost;
+        }
         false
     } else {
         health.take_damage(event.damage)
@@ -90,6 +135,20 @@ fn apply_pending_knockback(
     }
 }
 
+/// Recharge client-owned shields. Server-owned shields regen in `game_tick`.
+fn tick_shield_regen(
+    time: Res<Time>,
+    mut shields: Query<&mut Shield, Without<crate::networking::ServerId>>,
+) {
+    let dt = time.delta_secs();
+    for mut shield in &mut shields {
+        shield.since_hit += dt;
+        if shield.current < shield.max {
+            shield.current = regen_shield(shield.current, shield.max, shield.since_hit, dt);
+        }
+    }
+}
+
 /// Observer: handle entity death.
 /// Server-owned entities are handled by the reconciler, not despawned locally.
 fn on_death(
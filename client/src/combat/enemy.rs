@@ -1,13 +1,17 @@
+use super::reaction::Flinch;
 use super::*;
-use crate::asset_loading::Models;
+use crate::asset_loading::{EnemyModel, EnemyModels};
 use crate::models::{ClearEnemies, SpawnEnemy};
+use crate::networking::{ActiveServer, GameServer};
 use avian3d::prelude::{Collider, RigidBody, Sensor};
 use bevy::pbr::ExtendedMaterial;
 use bevy::render::storage::ShaderStorageBuffer;
-use bevy_open_vat::data::VatInstanceData;
 use bevy::scene::SceneInstanceReady;
 use bevy_enhanced_input::prelude::Start;
+use bevy_open_vat::data::VatInstanceData;
 use bevy_open_vat::prelude::*;
+use std::collections::HashMap;
+use wasm_fantasia_shared::variation::TINT_VARIANTS;
 
 /// bevy_open_vat's material type with StandardMaterial base.
 type VatMaterial = ExtendedMaterial<StandardMaterial, OpenVatExtension>;
@@ -16,6 +20,7 @@ pub fn plugin(app: &mut App) {
     app.add_observer(spawn_enemy_in_front)
         .add_observer(clear_all_enemies)
         .add_observer(on_enemy_added)
+        .add_observer(play_death_clip)
         .add_systems(
             Update,
             (
@@ -32,32 +37,74 @@ pub fn plugin(app: &mut App) {
 // VAT resources — shared across all enemy instances
 // =============================================================================
 
-/// Shared VAT rendering resources for all enemy instances, created once on
-/// first gameplay frame when all assets are loaded.
+/// Shared VAT rendering resources, one material per archetype and tint
+/// variant, created once on first gameplay frame when all assets are loaded.
 #[derive(Resource)]
 struct VatEnemyState {
-    material: Handle<VatMaterial>,
+    materials: HashMap<String, Vec<Handle<VatMaterial>>>,
 }
 
+/// Base colors enemies are drawn with, indexed by
+/// [`EnemyVariation::tint`](wasm_fantasia_shared::variation::EnemyVariation::tint).
+/// All close to the original red so archetypes still read as one family.
+const ENEMY_TINTS: [Color; TINT_VARIANTS as usize] = [
+    Color::srgb(0.816, 0.125, 0.125),
+    Color::srgb(0.69, 0.09, 0.16),
+    Color::srgb(0.86, 0.27, 0.12),
+    Color::srgb(0.62, 0.13, 0.24),
+];
+
 /// Links an enemy entity to the child mesh entity that holds the
 /// `VatAnimationController`, so `animate_enemies` can update the clip.
 #[derive(Component)]
 pub(super) struct VatMeshLink(pub Entity);
 
+/// Links an enemy entity to its model's scene root. An enemy that already
+/// has one (reused from the networking entity pool) keeps its model and is
+/// only restyled for its new look.
+#[derive(Component)]
+struct VatSceneLink(Entity);
+
 fn initialize_vat_enemy_resources(
-    models: Res<Models>,
+    models: Res<EnemyModels>,
     images: Res<Assets<Image>>,
     remap_infos: Res<Assets<RemapInfo>>,
     mut vat_materials: ResMut<Assets<VatMaterial>>,
     mut buffers: ResMut<Assets<ShaderStorageBuffer>>,
     mut commands: Commands,
 ) {
-    let Some(remap_info) = remap_infos.get(&models.enemy_remap_info) else {
-        return;
-    };
-    let Some(image) = images.get(&models.enemy_vat_texture) else {
-        return;
-    };
+    let mut materials = HashMap::new();
+    for (archetype, model) in models.iter() {
+        let mut variants = Vec::with_capacity(ENEMY_TINTS.len());
+        for tint in ENEMY_TINTS {
+            let Some(material) = create_vat_material(
+                model,
+                tint,
+                &images,
+                &remap_infos,
+                &mut vat_materials,
+                &mut buffers,
+            ) else {
+                return;
+            };
+            variants.push(material);
+        }
+        materials.insert(archetype.clone(), variants);
+    }
+
+    commands.insert_resource(VatEnemyState { materials });
+}
+
+fn create_vat_material(
+    model: &EnemyModel,
+    base_color: Color,
+    images: &Assets<Image>,
+    remap_infos: &Assets<RemapInfo>,
+    vat_materials: &mut Assets<VatMaterial>,
+    buffers: &mut Assets<ShaderStorageBuffer>,
+) -> Option<Handle<VatMaterial>> {
+    let remap_info = remap_infos.get(&model.remap_info)?;
+    let image = images.get(&model.vat_texture)?;
 
     let y_resolution = image.texture_descriptor.size.height as f32;
 
@@ -67,9 +114,9 @@ fn initialize_vat_enemy_resources(
     buffer.set_data(vec![VatInstanceData::default()]);
     let buffer = buffers.add(buffer);
 
-    let material = vat_materials.add(ExtendedMaterial {
+    Some(vat_materials.add(ExtendedMaterial {
         base: StandardMaterial {
-            base_color: Color::srgb(0.816, 0.125, 0.125),
+            base_color,
             double_sided: true,
             cull_mode: None,
             // Force forward rendering. The project uses deferred rendering by
@@ -84,63 +131,54 @@ fn initialize_vat_enemy_resources(
             ..default()
         },
         extension: OpenVatExtension {
-            vat_texture: models.enemy_vat_texture.clone(),
+            vat_texture: model.vat_texture.clone(),
             min_pos: remap_info.os_remap.min.into(),
             frame_count: remap_info.os_remap.frames,
             max_pos: remap_info.os_remap.max.into(),
             y_resolution,
             instance: buffer,
         },
-    });
-
-    commands.insert_resource(VatEnemyState { material });
+    }))
 }
 
 // =============================================================================
 // Spawn trigger (E key / server request)
 // =============================================================================
 
-/// Spawn a pack of enemies via server reducer.
-/// All game modes go through SpacetimeDB when connected.
+/// Spawn a pack of enemies around the player, through whichever
+/// [`GameServer`] the session has.
 fn spawn_enemy_in_front(
     _on: On<Start<SpawnEnemy>>,
     player: Query<&Transform, With<Player>>,
-    conn: Option<Res<crate::networking::SpacetimeDbConnection>>,
+    server: ActiveServer,
+    mut commands: Commands,
 ) {
     let Ok(player_transform) = player.single() else {
         return;
     };
+    let Some(server) = server.get() else {
+        warn!("No server connection — cannot spawn enemies");
+        return;
+    };
 
     let forward = player_transform.forward();
-    let pos = player_transform.translation;
-
-    if let Some(conn) = conn {
-        use spacetimedb_sdk::DbContext;
-        if conn.conn.is_active() {
-            crate::networking::combat::server_spawn_enemies(&conn, pos, forward.as_vec3());
-            debug!("Requested enemies from server");
-            return;
-        }
-    }
-
-    warn!("No server connection — cannot spawn enemies");
+    server.spawn_enemies(
+        &mut commands,
+        player_transform.translation,
+        forward.as_vec3(),
+    );
+    debug!("Requested enemies from server");
 }
 
-/// Delete all enemies in the current world via server reducer.
-fn clear_all_enemies(
-    _on: On<Start<ClearEnemies>>,
-    conn: Option<Res<crate::networking::SpacetimeDbConnection>>,
-) {
-    if let Some(conn) = conn {
-        use spacetimedb_sdk::DbContext;
-        if conn.conn.is_active() {
-            crate::networking::combat::server_clear_enemies(&conn);
-            info!("Requested enemy clear from server");
-            return;
-        }
-    }
+/// Delete all enemies in the current world.
+fn clear_all_enemies(_on: On<Start<ClearEnemies>>, server: ActiveServer, mut commands: Commands) {
+    let Some(server) = server.get() else {
+        warn!("No server connection — cannot clear enemies");
+        return;
+    };
 
-    warn!("No server connection — cannot clear enemies");
+    server.clear_enemies(&mut commands);
+    info!("Requested enemy clear from server");
 }
 
 // =============================================================================
@@ -149,11 +187,19 @@ fn clear_all_enemies(
 
 fn on_enemy_added(
     on: On<Add, Enemy>,
-    models: Res<Models>,
+    models: Res<EnemyModels>,
+    archetypes: Query<&EnemyArchetype>,
+    looks: Query<&EnemyLook>,
+    scenes: Query<&VatSceneLink>,
+    vat_state: Option<Res<VatEnemyState>>,
+    children_q: Query<&Children>,
+    mesh_entities: Query<Entity, With<Mesh3d>>,
     gltf_assets: Res<Assets<Gltf>>,
     mut commands: Commands,
 ) {
     let entity = on.entity;
+    let archetype = archetypes.get(entity).map_or("", |a| a.0.as_str());
+    let scale = looks.get(entity).map_or(1.0, |look| look.0.scale);
 
     // Remove capsule mesh if present (reconciler may have added it)
     commands
@@ -172,21 +218,54 @@ fn on_enemy_added(
         Sensor,
     ));
 
-    let Some(gltf) = gltf_assets.get(&models.enemy_scene) else {
+    let Some((id, model)) = models.resolve(archetype) else {
+        warn!("No enemy model registered for archetype {archetype:?}");
+        return;
+    };
+    if let Ok(scene) = scenes.get(entity) {
+        let look = looks.get(entity).ok().map(|look| look.0);
+        commands
+            .entity(scene.0)
+            .insert(model_transform(model, scale));
+        let tint = look.map_or(0, |look| look.tint as usize);
+        let material = vat_state.as_ref().and_then(|state| {
+            let variants = state.materials.get(id)?;
+            variants.get(tint).or(variants.first())
+        });
+        // Not ready means the scene hasn't loaded yet, and
+        // `prepare_enemy_vat_scene` will style it with the new look
+        if let Some(material) = material {
+            apply_vat_to_descendants(
+                scene.0,
+                &children_q,
+                &mesh_entities,
+                &mut commands,
+                material,
+                model,
+                look.map_or(0.0, |look| look.animation_offset),
+                entity,
+            );
+        }
+        return;
+    }
+    let Some(gltf) = gltf_assets.get(&model.scene) else {
         warn!("Enemy VAT GLB not loaded when enemy spawned");
         return;
     };
 
     let scene = SceneRoot(gltf.scenes[0].clone());
-    commands.entity(entity).with_children(|parent| {
-        let mut child = parent.spawn((
-            Transform::from_xyz(0.0, -0.85, 0.0)
-                .with_scale(Vec3::splat(1.25))
-                .with_rotation(Quat::from_rotation_y(std::f32::consts::PI)),
-            scene,
-        ));
-        child.observe(prepare_enemy_vat_scene);
-    });
+    let scene = commands
+        .spawn((model_transform(model, scale), scene, ChildOf(entity)))
+        .observe(prepare_enemy_vat_scene)
+        .id();
+    commands.entity(entity).insert(VatSceneLink(scene));
+}
+
+/// Where an enemy's model scene sits under it, at the look's `scale`.
+fn model_transform(model: &EnemyModel, scale: f32) -> Transform {
+    Transform::from_xyz(0.0, model.offset_y, 0.0)
+        .with_scale(Vec3::splat(model.scale * scale))
+        .with_rotation(Quat::from_rotation_y(std::f32::consts::PI))
 }
 
 // =============================================================================
@@ -196,7 +275,9 @@ fn on_enemy_added(
 fn prepare_enemy_vat_scene(
     on: On<SceneInstanceReady>,
     vat_state: Option<Res<VatEnemyState>>,
-    models: Res<Models>,
+    models: Res<EnemyModels>,
+    archetypes: Query<&EnemyArchetype>,
+    looks: Query<&EnemyLook>,
     children_q: Query<&Children>,
     mesh_entities: Query<Entity, With<Mesh3d>>,
     parents: Query<&ChildOf>,
@@ -216,14 +297,29 @@ fn prepare_enemy_vat_scene(
         scene_entity
     };
 
+    let archetype = archetypes.get(enemy_entity).map_or("", |a| a.0.as_str());
+    let Some((id, model)) = models.resolve(archetype) else {
+        return;
+    };
+    let look = looks.get(enemy_entity).ok().map(|look| look.0);
+    let tint = look.map_or(0, |look| look.tint as usize);
+    let Some(material) = vat_state
+        .materials
+        .get(id)
+        .and_then(|variants| variants.get(tint).or(variants.first()))
+    else {
+        return;
+    };
+
     // Find mesh entities in the scene subtree and apply VAT material + controller
     apply_vat_to_descendants(
         scene_entity,
         &children_q,
         &mesh_entities,
         &mut commands,
-        vat_state,
-        &models,
+        material,
+        model,
+        look.map_or(0.0, |look| look.animation_offset),
         enemy_entity,
     );
 }
@@ -233,8 +329,9 @@ fn apply_vat_to_descendants(
     children_q: &Query<&Children>,
     mesh_entities: &Query<Entity, With<Mesh3d>>,
     commands: &mut Commands,
-    vat_state: &VatEnemyState,
-    models: &Models,
+    material: &Handle<VatMaterial>,
+    model: &EnemyModel,
+    animation_offset: f32,
     enemy_entity: Entity,
 ) {
     if mesh_entities.get(entity).is_ok() {
@@ -242,14 +339,14 @@ fn apply_vat_to_descendants(
             .entity(entity)
             .remove::<MeshMaterial3d<StandardMaterial>>()
             .insert((
-                MeshMaterial3d(vat_state.material.clone()),
+                MeshMaterial3d(material.clone()),
                 VatAnimationController {
-                    remap_info: models.enemy_remap_info.clone(),
-                    current_clip: "Zombie_Idle_Loop".to_string(),
+                    remap_info: model.remap_info.clone(),
+                    current_clip: model.clips.idle.clone(),
                     speed: 1.0,
                     is_playing: true,
                     start_time: 0.0,
-                    offset: 0.0,
+                    offset: animation_offset,
                 },
             ));
 
@@ -263,8 +360,9 @@ fn apply_vat_to_descendants(
                 children_q,
                 mesh_entities,
                 commands,
-                vat_state,
-                models,
+                material,
+                model,
+                animation_offset,
                 enemy_entity,
             );
         }
@@ -272,28 +370,67 @@ fn apply_vat_to_descendants(
 }
 
 // =============================================================================
-// Animation driver — maps EnemyBehavior to VAT clip names
+// Animation driver — maps EnemyBehavior to the archetype's VAT clips
 // =============================================================================
 
 fn animate_enemies(
-    enemies: Query<(&EnemyBehavior, &VatMeshLink), Changed<EnemyBehavior>>,
+    models: Res<EnemyModels>,
+    enemies: Query<
+        (&EnemyBehavior, &VatMeshLink, Option<&EnemyArchetype>),
+        (Changed<EnemyBehavior>, Without<Flinch>),
+    >,
     mut controllers: Query<&mut VatAnimationController>,
     time: Res<Time>,
 ) {
-    for (behavior, vat_link) in &enemies {
+    for (behavior, vat_link, archetype) in &enemies {
         let Ok(mut controller) = controllers.get_mut(vat_link.0) else {
             continue;
         };
+        let archetype = archetype.map_or("", |a| a.0.as_str());
+        let Some((_, model)) = models.resolve(archetype) else {
+            continue;
+        };
 
         let clip_name = match behavior {
-            EnemyBehavior::Idle => "Zombie_Idle_Loop",
-            EnemyBehavior::Chase => "Zombie_Walk_Fwd_Loop",
-            EnemyBehavior::Attack => "Zombie_Scratch",
+            EnemyBehavior::Idle => &model.clips.idle,
+            EnemyBehavior::Chase => &model.clips.chase,
+            EnemyBehavior::Attack => &model.clips.attack,
         };
 
-        if controller.current_clip != clip_name {
-            controller.current_clip = clip_name.to_string();
+        if controller.current_clip != *clip_name {
+            controller.current_clip = clip_name.clone();
             controller.start_time = time.elapsed_secs();
         }
     }
 }
+
+/// An enemy stops being one when it dies (or its server row goes away); its
+/// model plays the death clip, if the archetype has one, while it leaves.
+fn play_death_clip(
+    on: On<Remove, Enemy>,
+    models: Res<EnemyModels>,
+    enemies: Query<(&VatMeshLink, Option<&EnemyArchetype>, Has<Flinch>)>,
+    mut controllers: Query<&mut VatAnimationController>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    let Ok((vat_link, archetype, flinching)) = enemies.get(on.entity) else {
+        return;
+    };
+    let Some((_, model)) = models.resolve(archetype.map_or("", |a| a.0.as_str())) else {
+        return;
+    };
+    let Some(clip) = &model.clips.death else {
+        return;
+    };
+    let Ok(mut controller) = controllers.get_mut(vat_link.0) else {
+        return;
+    };
+
+    controller.current_clip = clip.clone();
+    controller.start_time = time.elapsed_secs();
+    // Ending a flinch would hand the clip back to the behavior
+    if flinching {
+        commands.entity(on.entity).try_remove::<Flinch>();
+    }
+}
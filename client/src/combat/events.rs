@@ -3,6 +3,8 @@
 //! Attack chain:  [`AttackIntent`] → [`DamageDealt`] → [`HitLanded`]
 //! Death chain:   [`DamageDealt`] → [`Died`] (cross-domain — any source can kill)
 //! Crit chain:    [`HitLanded`] → [`CritHit`], [`Died`] → [`CritKill`]
+//! Shield chain:  [`DamageDealt`] → [`ShieldBroken`], [`Blocked`]
+//! Flinch chain:  [`HitLanded`] → [`HitReacted`] (remote hits arrive via combat events)
 //!
 //! Convention: intents use noun form (hasn't happened yet), mutations/feedback
 //! use past tense (it happened). The tense tells you the event's role.
@@ -11,7 +13,7 @@
 //! [`CritKill`]: crate::rules::triggers::CritKill
 
 use bevy::prelude::*;
-use wasm_fantasia_shared::combat::HitFeedback;
+use wasm_fantasia_shared::combat::{HitFeedback, HitReaction};
 
 // ── Intent ──────────────────────────────────────────────────────────
 
@@ -20,6 +22,9 @@ use wasm_fantasia_shared::combat::HitFeedback;
 #[derive(Event, Clone, Debug)]
 pub struct AttackIntent {
     pub attacker: Entity,
+    /// The player's swing number from [`AttackSequence`](super::AttackSequence),
+    /// sent to the server with `attack_hit`; 0 for anyone else.
+    pub seq: u32,
 }
 
 // ── Mutations ───────────────────────────────────────────────────────
@@ -57,3 +62,35 @@ pub struct HitLanded {
     pub is_crit: bool,
     pub feedback: HitFeedback,
 }
+
+/// Feedback: a hit took the target's shield from up to empty.
+/// Triggered by the [`DamageDealt`] observer.
+#[derive(Event, Debug, Clone)]
+pub struct ShieldBroken {
+    pub entity: Entity,
+}
+
+/// Feedback: the target's shield soaked a whole hit.
+/// Triggered by the [`DamageDealt`] observer.
+#[derive(Event, Debug, Clone)]
+pub struct Blocked {
+    pub entity: Entity,
+}
+
+/// Feedback: `entity` flinches from a hit.
+/// Triggered by [`HitLanded`] for local hits and by the reconciler for hits
+/// other players landed.
+#[derive(Event, Debug, Clone)]
+pub struct HitReacted {
+    pub entity: Entity,
+    pub reaction: HitReaction,
+}
+
+/// Feedback: the server awarded the local player XP for a kill at `position`.
+/// `shared` when a nearby party member landed the kill.
+#[derive(Event, Debug, Clone)]
+pub struct XpGained {
+    pub amount: u32,
+    pub shared: bool,
+    pub position: Vec3,
+}
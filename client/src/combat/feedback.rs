@@ -1,13 +1,10 @@
-use std::time::Duration;
-
-use bevy::input::gamepad::{GamepadRumbleIntensity, GamepadRumbleRequest};
 use bevy::prelude::*;
 use bevy::transform::TransformSystems;
 
-use crate::combat::HitLanded;
+use crate::combat::{HitLanded, Rumble};
 use crate::models::{Player, SceneCamera, Session};
 use crate::player::control::{JumpLaunched, LandingImpact};
-use crate::rules::{Stat, Stats};
+use crate::rules::{FeedbackCue, RuleCue, Stat, Stats};
 
 pub fn plugin(app: &mut App) {
     app.insert_resource(HitStop::default())
@@ -15,6 +12,7 @@ pub fn plugin(app: &mut App) {
         .add_observer(on_hit_stop)
         .add_observer(on_screen_shake)
         .add_observer(on_rumble)
+        .add_observer(on_rule_cue)
         .add_observer(on_jump_shake)
         .add_observer(on_jump_rumble)
         .add_observer(on_landing_shake)
@@ -37,6 +35,13 @@ pub struct HitStop {
 
 impl HitStop {
     pub const MAX_DURATION: f32 = 0.12;
+
+    /// Hold the game nearly still for `duration`, or longer if a stop is
+    /// already running, up to [`Self::MAX_DURATION`].
+    fn freeze(&mut self, duration: f32, time: &mut Time<Virtual>) {
+        self.remaining = self.remaining.max(duration).min(Self::MAX_DURATION);
+        time.set_relative_speed(0.05);
+    }
 }
 
 fn on_hit_stop(
@@ -69,8 +74,7 @@ fn on_hit_stop(
     };
 
     let adjusted = (duration * (1.0 - speed_reduction)).max(0.01);
-    hit_stop.remaining = hit_stop.remaining.max(adjusted).min(HitStop::MAX_DURATION);
-    time.set_relative_speed(0.05);
+    hit_stop.freeze(adjusted, &mut time);
 }
 
 fn tick_hit_stop(
@@ -102,6 +106,12 @@ impl ScreenShake {
     pub const MAX_TRANSLATION: f32 = 0.25;
     pub const NOISE_SPEED: f32 = 25.0;
     pub const EXPONENT: f32 = 2.0;
+
+    /// Add hit trauma, with diminishing returns as it builds up.
+    fn add_hit_trauma(&mut self, intensity: f32) {
+        let diminish = 1.0 - self.trauma * 0.7;
+        self.trauma = (self.trauma + intensity * diminish).min(0.7);
+    }
 }
 
 fn on_screen_shake(
@@ -112,9 +122,7 @@ fn on_screen_shake(
     if local_check.get(on.event().source).is_err() {
         return;
     }
-    let intensity = on.event().feedback.shake_intensity;
-    let diminish = 1.0 - shake.trauma * 0.7;
-    shake.trauma = (shake.trauma + intensity * diminish).min(0.7);
+    shake.add_hit_trauma(on.event().feedback.shake_intensity);
 }
 
 fn apply_camera_shake(
@@ -145,10 +153,11 @@ fn apply_camera_shake(
 
 // ── Gamepad Rumble ──────────────────────────────────────────────────
 
+/// Rumble strength and length come from the `RumbleIntensity` / `RumbleDuration`
+/// action vars, resolved into [`HitFeedback`](wasm_fantasia_shared::combat::HitFeedback).
 fn on_rumble(
     on: On<HitLanded>,
-    gamepads: Query<Entity, With<Gamepad>>,
-    mut rumble: MessageWriter<GamepadRumbleRequest>,
+    mut commands: Commands,
     local_check: Query<(), With<crate::combat::PlayerCombatant>>,
 ) {
     if local_check.get(on.event().source).is_err() {
@@ -156,20 +165,43 @@ fn on_rumble(
     }
 
     let feedback = &on.event().feedback;
+    commands.trigger(Rumble {
+        strong: feedback.rumble_strong,
+        weak: feedback.rumble_weak,
+        duration_ms: feedback.rumble_duration,
+    });
+}
+
+// ── Rule Cues ───────────────────────────────────────────────────────
 
-    if feedback.rumble_strong <= 0.0 && feedback.rumble_weak <= 0.0 {
+/// Play the hit-stop, shake and rumble cues the local player's rules ask
+/// for, on top of the hit's own. Sounds are `sound.rs`'s.
+fn on_rule_cue(
+    on: On<RuleCue>,
+    mut hit_stop: ResMut<HitStop>,
+    mut time: ResMut<Time<Virtual>>,
+    mut shake: ResMut<ScreenShake>,
+    mut commands: Commands,
+    local_check: Query<(), With<crate::combat::PlayerCombatant>>,
+) {
+    let event = on.event();
+    if local_check.get(event.source).is_err() {
         return;
     }
-
-    for gamepad in gamepads.iter() {
-        rumble.write(GamepadRumbleRequest::Add {
-            gamepad,
-            duration: Duration::from_millis(feedback.rumble_duration as u64),
-            intensity: GamepadRumbleIntensity {
-                strong_motor: feedback.rumble_strong,
-                weak_motor: feedback.rumble_weak,
-            },
-        });
+    match event.cue {
+        FeedbackCue::HitStop { duration } if duration > 0.0 => {
+            hit_stop.freeze(duration, &mut time);
+        }
+        FeedbackCue::Shake { intensity } => shake.add_hit_trauma(intensity.max(0.0)),
+        FeedbackCue::Rumble {
+            intensity,
+            duration,
+        } => commands.trigger(Rumble {
+            strong: intensity,
+            weak: intensity * 0.6,
+            duration_ms: duration,
+        }),
+        _ => {}
     }
 }
 
@@ -183,28 +215,15 @@ fn on_jump_shake(on: On<JumpLaunched>, mut shake: ResMut<ScreenShake>) {
     shake.trauma = (shake.trauma + trauma).min(0.7);
 }
 
-fn on_jump_rumble(
-    on: On<JumpLaunched>,
-    gamepads: Query<Entity, With<Gamepad>>,
-    mut rumble: MessageWriter<GamepadRumbleRequest>,
-) {
+fn on_jump_rumble(on: On<JumpLaunched>, mut commands: Commands) {
     let event = on.event();
     let t = (event.charge_time / crate::player::control::MAX_CHARGE_TIME).clamp(0.0, 1.0);
 
-    let strong = 0.2 + 0.6 * t;
-    let weak = 0.1 + 0.4 * t;
-    let duration_ms = 80 + (70.0 * t) as u64; // 80ms tap, 150ms full
-
-    for gamepad in gamepads.iter() {
-        rumble.write(GamepadRumbleRequest::Add {
-            gamepad,
-            duration: Duration::from_millis(duration_ms),
-            intensity: GamepadRumbleIntensity {
-                strong_motor: strong,
-                weak_motor: weak,
-            },
-        });
-    }
+    commands.trigger(Rumble {
+        strong: 0.2 + 0.6 * t,
+        weak: 0.1 + 0.4 * t,
+        duration_ms: 80.0 + 70.0 * t, // 80ms tap, 150ms full
+    });
 }
 
 // ── Landing Impact Feedback ─────────────────────────────────────────
@@ -220,26 +239,13 @@ fn on_landing_shake(on: On<LandingImpact>, mut shake: ResMut<ScreenShake>) {
     shake.trauma = (shake.trauma + trauma).min(1.0);
 }
 
-fn on_landing_rumble(
-    on: On<LandingImpact>,
-    gamepads: Query<Entity, With<Gamepad>>,
-    mut rumble: MessageWriter<GamepadRumbleRequest>,
-) {
+fn on_landing_rumble(on: On<LandingImpact>, mut commands: Commands) {
     let event = on.event();
     let t = ((event.velocity_y - 3.0) / (LANDING_MAX_VELOCITY - 3.0)).clamp(0.0, 1.0);
 
-    let strong = 0.4 + 0.5 * t;
-    let weak = 0.2 + 0.4 * t;
-    let duration_ms = 120 + (130.0 * t) as u64;
-
-    for gamepad in gamepads.iter() {
-        rumble.write(GamepadRumbleRequest::Add {
-            gamepad,
-            duration: Duration::from_millis(duration_ms),
-            intensity: GamepadRumbleIntensity {
-                strong_motor: strong,
-                weak_motor: weak,
-            },
-        });
-    }
+    commands.trigger(Rumble {
+        strong: 0.4 + 0.5 * t,
+        weak: 0.2 + 0.4 * t,
+        duration_ms: 120.0 + 130.0 * t,
+    });
 }
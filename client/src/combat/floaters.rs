@@ -2,10 +2,13 @@ use bevy::camera::primitives::Aabb;
 use bevy::prelude::*;
 use bevy::transform::TransformSystems;
 
-use crate::combat::components::{Enemy, Health};
-use crate::combat::{DamageDealt, Died, HitLanded};
+use crate::combat::components::{Enemy, Health, PredictedDamage, Shield};
+use crate::combat::{DamageDealt, Died, HitLanded, XpGained};
 use crate::models::SceneCamera;
-use crate::ui::colors::{GRASS_GREEN, NEUTRAL450, NEUTRAL850, RED, SAND_YELLOW};
+use crate::ui::colors::{
+    ACID_GREEN, GRASS_GREEN, NEUTRAL300, NEUTRAL450, NEUTRAL850, RED, SAND_YELLOW, SHIELD_BLUE,
+};
+use crate::ui::tween::{TrailingFill, approach};
 
 /// Cached mesh height above the entity origin, computed once from descendant AABBs.
 #[derive(Component)]
@@ -13,6 +16,7 @@ pub struct MeshHeight(pub f32);
 
 pub fn plugin(app: &mut App) {
     app.add_observer(on_damage_number)
+        .add_observer(on_xp_number)
         .add_observer(on_enemy_damaged)
         .add_observer(on_enemy_death)
         .add_systems(Startup, setup_glyph_cache)
@@ -21,6 +25,7 @@ pub fn plugin(app: &mut App) {
             (
                 compute_mesh_heights,
                 tick_damage_numbers,
+                tick_damage_counts,
                 tick_enemy_health_bars,
             )
                 .after(TransformSystems::Propagate),
@@ -60,11 +65,20 @@ pub struct GlyphCache;
 #[derive(Component)]
 pub struct DamageNumber {
     pub timer: f32,
-    pub is_crit: bool,
+    pub color: Color,
     pub world_pos: Vec3,
     pub offset: Vec2,
 }
 
+/// Running total of a damage floater. Hits on the same target while the
+/// floater is still fresh add to it, and the number counts up to the total.
+#[derive(Component)]
+pub struct DamageCount {
+    pub target: Entity,
+    pub total: f32,
+    pub shown: f32,
+}
+
 pub const DAMAGE_COLOR: Color = crate::ui::colors::NEUTRAL10;
 pub const CRIT_COLOR: Color = Color::oklcha(0.905, 0.182, 98.111, 1.0);
 
@@ -72,6 +86,11 @@ const DISPLAY_DURATION: f32 = 0.8;
 const POP_DURATION: f32 = 0.15;
 const HOLD_END: f32 = 0.4;
 const RISE_PIXELS: f32 = 80.0;
+/// Hits taking at least this fraction of the target's max health count up
+/// from zero instead of appearing at their full value.
+const BURST_FRACTION: f32 = 0.25;
+/// How fast a counting floater closes on its total.
+const COUNT_RATE: f32 = 12.0;
 
 fn setup_glyph_cache(mut commands: Commands) {
     for size in [20.0, 28.0] {
@@ -95,20 +114,37 @@ fn setup_glyph_cache(mut commands: Commands) {
 
 fn on_damage_number(
     on: On<HitLanded>,
-    targets: Query<(&Transform, Option<&MeshHeight>)>,
+    targets: Query<(&Transform, Option<&MeshHeight>, Option<&Health>)>,
+    mut counting: Query<(&mut DamageNumber, &mut DamageCount, &mut TextColor)>,
     fonts: Option<Res<crate::asset_loading::Fonts>>,
     mut commands: Commands,
 ) {
     let event = on.event();
 
-    let Ok((target_transform, mesh_height)) = targets.get(event.target) else {
+    let Ok((target_transform, mesh_height, health)) = targets.get(event.target) else {
         return;
     };
 
     let world_pos = target_transform.translation + Vec3::Y * mesh_height.map_or(2.0, |h| h.0);
-    let damage = event.damage as i32;
     let is_crit = event.is_crit;
 
+    // Fold follow-up hits into a floater that's still fresh
+    if let Some((mut number, mut count, mut color)) = counting
+        .iter_mut()
+        .find(|(n, c, _)| c.target == event.target && n.timer < HOLD_END * DISPLAY_DURATION)
+    {
+        count.total += event.damage;
+        number.timer = number.timer.min(POP_DURATION);
+        number.world_pos = world_pos;
+        if is_crit {
+            number.color = CRIT_COLOR;
+            color.0 = CRIT_COLOR;
+        }
+        return;
+    }
+
+    let burst = health.is_some_and(|h| event.damage >= h.max * BURST_FRACTION);
+
     let mut rng = rand::rng();
     let offset = Vec2::new(
         rand::Rng::random_range(&mut rng, -40.0..40.0),
@@ -128,11 +164,20 @@ fn on_damage_number(
     commands.spawn((
         DamageNumber {
             timer: 0.0,
-            is_crit,
+            color: if is_crit { CRIT_COLOR } else { DAMAGE_COLOR },
             world_pos,
             offset,
         },
-        Text::new(format!("{}", damage)),
+        DamageCount {
+            target: event.target,
+            total: event.damage,
+            shown: if burst { 0.0 } else { event.damage },
+        },
+        Text::new(if burst {
+            "0".to_string()
+        } else {
+            format!("{}", event.damage as i32)
+        }),
         text_font,
         TextColor(if is_crit { CRIT_COLOR } else { DAMAGE_COLOR }),
         Node {
@@ -146,6 +191,45 @@ fn on_damage_number(
     ));
 }
 
+/// XP floaters ride the same animation as damage numbers, lifted above them.
+fn on_xp_number(
+    on: On<XpGained>,
+    fonts: Option<Res<crate::asset_loading::Fonts>>,
+    mut commands: Commands,
+) {
+    let event = on.event();
+
+    let label = if event.shared {
+        format!("+{} XP shared", event.amount)
+    } else {
+        format!("+{} XP", event.amount)
+    };
+    let mut text_font = TextFont::from_font_size(18.0);
+    if let Some(fonts) = fonts {
+        text_font.font = fonts.regular.clone();
+    }
+
+    commands.spawn((
+        DamageNumber {
+            timer: 0.0,
+            color: ACID_GREEN,
+            world_pos: event.position + Vec3::Y * 2.5,
+            offset: Vec2::new(0.0, -24.0),
+        },
+        Text::new(label),
+        text_font,
+        TextColor(ACID_GREEN),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(-9999.0),
+            top: Val::Px(-9999.0),
+            ..default()
+        },
+        GlobalZIndex(100),
+        Pickable::IGNORE,
+    ));
+}
+
 fn tick_damage_numbers(
     time: Res<Time>,
     mut commands: Commands,
@@ -198,12 +282,21 @@ fn tick_damage_numbers(
             1.0 - fade_t * fade_t
         };
 
-        let base_color = if dmg.is_crit {
-            CRIT_COLOR
-        } else {
-            DAMAGE_COLOR
-        };
-        color.0 = base_color.with_alpha(alpha);
+        color.0 = dmg.color.with_alpha(alpha);
+    }
+}
+
+fn tick_damage_counts(time: Res<Time>, mut counts: Query<(&mut DamageCount, &mut Text)>) {
+    let dt = time.delta_secs();
+    for (mut count, mut text) in &mut counts {
+        if count.shown == count.total {
+            continue;
+        }
+        count.shown = approach(count.shown, count.total, COUNT_RATE, dt);
+        let label = format!("{}", count.shown as i32);
+        if text.0 != label {
+            text.0 = label;
+        }
     }
 }
 
@@ -227,14 +320,28 @@ fn health_color(fraction: f32) -> Color {
 pub struct EnemyHealthBar {
     pub target: Entity,
     pub visible_timer: f32,
+    pub fill: TrailingFill,
 }
 
 #[derive(Component)]
 pub struct HealthBarFill;
 
+/// Segment behind the health fill showing recently lost health.
+#[derive(Component)]
+pub struct HealthBarTrail;
+
+/// Translucent segment between the health fill and the health the server has
+/// confirmed: damage this client predicted but the server hasn't counted yet.
+#[derive(Component)]
+pub struct HealthBarUnconfirmed;
+
+/// Shield strip overlaid on the top of the health fill.
+#[derive(Component)]
+pub struct ShieldBarFill;
+
 fn on_enemy_damaged(
     on: On<DamageDealt>,
-    enemies: Query<&GlobalTransform, With<Enemy>>,
+    enemies: Query<&Health, With<Enemy>>,
     mut health_bars: Query<&mut EnemyHealthBar>,
     mut commands: Commands,
 ) {
@@ -247,7 +354,7 @@ fn on_enemy_damaged(
         }
     }
 
-    let Ok(_enemy_tf) = enemies.get(event.target) else {
+    let Ok(health) = enemies.get(event.target) else {
         return;
     };
 
@@ -256,6 +363,7 @@ fn on_enemy_damaged(
             EnemyHealthBar {
                 target: event.target,
                 visible_timer: VISIBILITY_DURATION,
+                fill: TrailingFill::new(health.fraction()),
             },
             Node {
                 position_type: PositionType::Absolute,
@@ -273,6 +381,28 @@ fn on_enemy_damaged(
             Pickable::IGNORE,
         ))
         .with_children(|parent| {
+            parent.spawn((
+                HealthBarTrail,
+                Node {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    border_radius: BorderRadius::all(Val::Px(2.0)),
+                    ..default()
+                },
+                BackgroundColor(NEUTRAL300),
+            ));
+            parent.spawn((
+                HealthBarUnconfirmed,
+                Node {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(0.0),
+                    height: Val::Percent(100.0),
+                    border_radius: BorderRadius::all(Val::Px(2.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::NONE),
+            ));
             parent.spawn((
                 HealthBarFill,
                 Node {
@@ -283,6 +413,17 @@ fn on_enemy_damaged(
                 },
                 BackgroundColor(GRASS_GREEN),
             ));
+            parent.spawn((
+                ShieldBarFill,
+                Node {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(0.0),
+                    height: Val::Percent(40.0),
+                    border_radius: BorderRadius::all(Val::Px(2.0)),
+                    ..default()
+                },
+                BackgroundColor(SHIELD_BLUE),
+            ));
         });
 }
 
@@ -305,7 +446,16 @@ fn tick_enemy_health_bars(
     time: Res<Time>,
     mut commands: Commands,
     camera: Query<(&Camera, &GlobalTransform), With<SceneCamera>>,
-    enemies: Query<(&GlobalTransform, &Health, Option<&MeshHeight>), With<Enemy>>,
+    enemies: Query<
+        (
+            &GlobalTransform,
+            &Health,
+            Option<&Shield>,
+            Option<&PredictedDamage>,
+            Option<&MeshHeight>,
+        ),
+        With<Enemy>,
+    >,
     mut health_bars: Query<(
         Entity,
         &mut EnemyHealthBar,
@@ -314,8 +464,22 @@ fn tick_enemy_health_bars(
         &Children,
     )>,
     mut fills: Query<
-        (&mut Node, &mut BackgroundColor),
-        (With<HealthBarFill>, Without<EnemyHealthBar>),
+        (
+            &mut Node,
+            &mut BackgroundColor,
+            Has<ShieldBarFill>,
+            Has<HealthBarTrail>,
+            Has<HealthBarUnconfirmed>,
+        ),
+        (
+            Or<(
+                With<HealthBarFill>,
+                With<ShieldBarFill>,
+                With<HealthBarTrail>,
+                With<HealthBarUnconfirmed>,
+            )>,
+            Without<EnemyHealthBar>,
+        ),
     >,
 ) {
     let delta = time.delta_secs();
@@ -332,7 +496,7 @@ fn tick_enemy_health_bars(
             continue;
         }
 
-        let Ok((enemy_tf, health, mesh_height)) = enemies.get(bar.target) else {
+        let Ok((enemy_tf, health, shield, predicted, mesh_height)) = enemies.get(bar.target) else {
             commands.entity(entity).despawn();
             continue;
         };
@@ -354,11 +518,29 @@ fn tick_enemy_health_bars(
         };
         bg.0 = NEUTRAL850.with_alpha(0.7 * alpha);
 
-        let fraction = health.fraction();
+        bar.fill.tick(health.fraction(), delta);
+        let fraction = bar.fill.fill;
+        let shield_fraction = shield.map_or(0.0, Shield::fraction);
+        let confirmed_fraction = predicted.map_or(fraction, |p| {
+            ((health.current + p.health()) / health.max).clamp(fraction, 1.0)
+        });
         for child in children.iter() {
-            if let Ok((mut fill_node, mut fill_bg)) = fills.get_mut(child) {
-                fill_node.width = Val::Percent(fraction * 100.0);
-                fill_bg.0 = health_color(fraction).with_alpha(alpha);
+            if let Ok((mut fill_node, mut fill_bg, is_shield, is_trail, is_unconfirmed)) =
+                fills.get_mut(child)
+            {
+                if is_shield {
+                    fill_node.width = Val::Percent(shield_fraction * 100.0);
+                    fill_bg.0 = SHIELD_BLUE.with_alpha(alpha);
+                } else if is_trail {
+                    fill_node.width = Val::Percent(bar.fill.trail * 100.0);
+                    fill_bg.0 = NEUTRAL300.with_alpha(0.8 * alpha);
+                } else if is_unconfirmed {
+                    fill_node.width = Val::Percent(confirmed_fraction * 100.0);
+                    fill_bg.0 = health_color(fraction).with_alpha(0.35 * alpha);
+                } else {
+                    fill_node.width = Val::Percent(fraction * 100.0);
+                    fill_bg.0 = health_color(fraction).with_alpha(alpha);
+                }
             }
         }
     }
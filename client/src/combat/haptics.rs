@@ -0,0 +1,91 @@
+//! Controller haptics. Every rumble in the game goes through [`Rumble`] so the
+//! settings intensity applies uniformly. Native builds use gilrs force
+//! feedback; gilrs can't rumble in the browser, so web builds call the
+//! Gamepad haptics API directly.
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Duration;
+
+#[cfg(not(target_arch = "wasm32"))]
+use bevy::input::gamepad::{GamepadRumbleIntensity, GamepadRumbleRequest};
+use bevy::prelude::*;
+
+use crate::models::Settings;
+
+pub fn plugin(app: &mut App) {
+    app.add_observer(on_rumble);
+}
+
+/// Request: rumble every connected gamepad. Motor strengths are 0–1 and get
+/// scaled by [`Settings::rumble`].
+#[derive(Event, Debug, Clone)]
+pub struct Rumble {
+    pub strong: f32,
+    pub weak: f32,
+    pub duration_ms: f32,
+}
+
+fn on_rumble(
+    on: On<Rumble>,
+    settings: Res<Settings>,
+    #[cfg(not(target_arch = "wasm32"))] gamepads: Query<Entity, With<Gamepad>>,
+    #[cfg(not(target_arch = "wasm32"))] mut requests: MessageWriter<GamepadRumbleRequest>,
+) {
+    let event = on.event();
+    let strong = (event.strong * settings.rumble).clamp(0.0, 1.0);
+    let weak = (event.weak * settings.rumble).clamp(0.0, 1.0);
+    if (strong <= 0.0 && weak <= 0.0) || event.duration_ms <= 0.0 {
+        return;
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    web::play(strong, weak, event.duration_ms);
+
+    #[cfg(not(target_arch = "wasm32"))]
+    for gamepad in gamepads.iter() {
+        requests.write(GamepadRumbleRequest::Add {
+            gamepad,
+            duration: Duration::from_millis(event.duration_ms as u64),
+            intensity: GamepadRumbleIntensity {
+                strong_motor: strong,
+                weak_motor: weak,
+            },
+        });
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod web {
+    use js_sys::{Function, Object, Reflect};
+    use wasm_bindgen::{JsCast, JsValue};
+
+    /// `gamepad.vibrationActuator.playEffect("dual-rumble", ...)` on every pad.
+    /// Looked up reflectively — the API is still missing from some browsers
+    /// and from web-sys's stable bindings.
+    pub fn play(strong: f32, weak: f32, duration_ms: f32) {
+        let Some(navigator) = web_sys::window().map(|w| w.navigator()) else {
+            return;
+        };
+        let Ok(pads) = navigator.get_gamepads() else {
+            return;
+        };
+
+        let params = Object::new();
+        let _ = Reflect::set(&params, &"duration".into(), &duration_ms.into());
+        let _ = Reflect::set(&params, &"strongMagnitude".into(), &strong.into());
+        let _ = Reflect::set(&params, &"weakMagnitude".into(), &weak.into());
+
+        for pad in pads.iter().filter(|p| !p.is_null()) {
+            let Ok(actuator) = Reflect::get(&pad, &"vibrationActuator".into()) else {
+                continue;
+            };
+            let Ok(play_effect) = Reflect::get(&actuator, &"playEffect".into()) else {
+                continue;
+            };
+            let Some(play_effect) = play_effect.dyn_ref::<Function>() else {
+                continue;
+            };
+            let _ = play_effect.call2(&actuator, &JsValue::from_str("dual-rumble"), &params);
+        }
+    }
+}
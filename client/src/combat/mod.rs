@@ -8,15 +8,18 @@ mod enemy;
 pub mod events;
 mod feedback;
 mod floaters;
+mod haptics;
+mod reaction;
 mod sound;
 mod targeting;
 mod vfx;
 
-pub use attack::{VFX_ARC_DEGREES, VFX_RANGE};
+pub use attack::{AttackSequence, VFX_ARC_DEGREES, VFX_RANGE};
 pub use components::*;
 pub use events::*;
 pub use feedback::*;
 pub use floaters::*;
+pub use haptics::Rumble;
 pub use targeting::LockedTarget;
 
 pub fn plugin(app: &mut App) {
@@ -27,6 +30,8 @@ pub fn plugin(app: &mut App) {
         enemy::plugin,
         feedback::plugin,
         floaters::plugin,
+        haptics::plugin,
+        reaction::plugin,
         vfx::plugin,
         targeting::plugin,
         sound::plugin,
@@ -0,0 +1,91 @@
+//! Hit reactions — how hard a hit rocks its target.
+//!
+//! Skeletal characters layer the flinch clip themselves (see
+//! `player::hit_reaction`). VAT enemies can't layer clips, so light hits
+//! leave their animation alone and heavier ones swap the clip briefly.
+
+use super::enemy::VatMeshLink;
+use super::*;
+use crate::asset_loading::EnemyModels;
+use bevy_open_vat::prelude::VatAnimationController;
+use wasm_fantasia_shared::combat::HitReaction;
+
+/// How long the hit clips hold before the behavior clip resumes.
+const MEDIUM_SECS: f32 = 0.45;
+const HEAVY_SECS: f32 = 0.9;
+
+pub fn plugin(app: &mut App) {
+    app.add_observer(on_hit_landed)
+        .add_observer(on_enemy_reacted)
+        .add_systems(
+            Update,
+            tick_enemy_flinches.run_if(in_state(Screen::Gameplay)),
+        );
+}
+
+/// An enemy playing a flinch clip instead of its behavior clip.
+#[derive(Component)]
+pub(super) struct Flinch {
+    remaining: f32,
+}
+
+fn on_hit_landed(on: On<HitLanded>, targets: Query<&Health>, mut commands: Commands) {
+    let event = on.event();
+    let Ok(health) = targets.get(event.target) else {
+        return;
+    };
+    commands.trigger(HitReacted {
+        entity: event.target,
+        reaction: HitReaction::from_damage(event.damage, health.max),
+    });
+}
+
+fn on_enemy_reacted(
+    on: On<HitReacted>,
+    models: Res<EnemyModels>,
+    enemies: Query<(&VatMeshLink, Option<&EnemyArchetype>), With<Enemy>>,
+    mut controllers: Query<&mut VatAnimationController>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    let event = on.event();
+    let Ok((vat_link, archetype)) = enemies.get(event.entity) else {
+        return;
+    };
+    let Some((_, model)) = models.resolve(archetype.map_or("", |a| a.0.as_str())) else {
+        return;
+    };
+    let (clip, secs) = match event.reaction {
+        HitReaction::Light => return,
+        HitReaction::Medium => (&model.clips.hit, MEDIUM_SECS),
+        HitReaction::Heavy => (&model.clips.knockback, HEAVY_SECS),
+    };
+    let Some(clip) = clip else {
+        return;
+    };
+    let Ok(mut controller) = controllers.get_mut(vat_link.0) else {
+        return;
+    };
+
+    controller.current_clip = clip.clone();
+    controller.start_time = time.elapsed_secs();
+    commands
+        .entity(event.entity)
+        .insert(Flinch { remaining: secs });
+}
+
+/// Hand the clip back to the behavior once the flinch is over.
+fn tick_enemy_flinches(
+    time: Res<Time>,
+    mut flinching: Query<(Entity, &mut Flinch, &mut EnemyBehavior)>,
+    mut commands: Commands,
+) {
+    for (entity, mut flinch, mut behavior) in &mut flinching {
+        flinch.remaining -= time.delta_secs();
+        if flinch.remaining <= 0.0 {
+            commands.entity(entity).remove::<Flinch>();
+            // Force animate_enemies to pick the behavior clip again
+            behavior.set_changed();
+        }
+    }
+}
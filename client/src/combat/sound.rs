@@ -1,16 +1,20 @@
 use crate::asset_loading::AudioSources;
 use crate::combat::HitLanded;
-use crate::models::{Session, Settings};
+use crate::models::{EquippedWeapon, Session, Settings};
+use crate::player::moveset;
+use crate::rules::{FeedbackCue, RuleCue};
 use bevy::prelude::*;
 use bevy_seedling::prelude::*;
 use rand::Rng;
 
 pub fn plugin(app: &mut App) {
-    app.add_observer(punch_sound);
+    app.add_observer(punch_sound).add_observer(cue_sound);
 }
 
+/// Hit sound, pitched and weighted by the attacker's weapon.
 fn punch_sound(
-    _on: On<HitLanded>,
+    on: On<HitLanded>,
+    weapons: Query<&EquippedWeapon>,
     state: Res<Session>,
     settings: Res<Settings>,
     time: Res<Time>,
@@ -30,6 +34,12 @@ fn punch_sound(
     }
     *last_play = now;
 
+    let weapon = weapons
+        .get(on.event().source)
+        .map(|w| w.0)
+        .unwrap_or_default();
+    let moveset = moveset(weapon);
+
     let mut rng = rand::rng();
     let handle = sources.punches.pick(&mut rng);
 
@@ -38,10 +48,42 @@ fn punch_sound(
         return;
     };
     let vol_variation = rng.random_range(0.85..1.15);
-    let volume = Volume::Linear(base_vol * vol_variation);
+    let volume = Volume::Linear(base_vol * vol_variation * moveset.sound_volume);
+    let pitch = moveset.sound_pitch;
 
     cmds.spawn((
         SamplePlayer::new(handle.clone()).with_volume(volume),
-        RandomPitch::new(0.08), // ±8% pitch variation
+        RandomPitch(pitch * 0.92..pitch * 1.08), // ±8% around the weapon's pitch
     ));
 }
+
+/// Sound cues from rules (`Feedback::Sound`), by the name the rule gives.
+fn cue_sound(
+    on: On<RuleCue>,
+    state: Res<Session>,
+    settings: Res<Settings>,
+    mut cmds: Commands,
+    mut sources: ResMut<AudioSources>,
+) {
+    let FeedbackCue::Sound { name, volume } = &on.event().cue else {
+        return;
+    };
+    if state.muted || *volume <= 0.0 {
+        return;
+    }
+    let mut rng = rand::rng();
+    let handle = match name.as_str() {
+        "punch" => sources.punches.pick(&mut rng).clone(),
+        "step" => sources.steps.pick(&mut rng).clone(),
+        "hover" => sources.hover.clone(),
+        "press" => sources.press.clone(),
+        _ => {
+            warn_once!("Rules asked for a sound this client doesn't have: '{name}'");
+            return;
+        }
+    };
+    let Volume::Linear(base_vol) = settings.sfx() else {
+        return;
+    };
+    cmds.spawn(SamplePlayer::new(handle).with_volume(Volume::Linear(base_vol * volume)));
+}
@@ -3,9 +3,16 @@ use bevy::prelude::*;
 use bevy_open_vat::prelude::OpenVatExtension;
 
 use super::enemy::VatMeshLink;
-use crate::combat::{AttackIntent, HitLanded, MeshHeight, VFX_ARC_DEGREES, VFX_RANGE};
-use crate::models::Session;
-use crate::player::control::{Footstep, GroundPoundImpact, JumpLaunched, LandingImpact};
+use crate::combat::{
+    AttackIntent, HitLanded, MeshHeight, ShieldBroken, VFX_ARC_DEGREES, VFX_RANGE,
+};
+use crate::models::{EquippedWeapon, Session};
+use crate::player::control::{GroundPoundImpact, JumpLaunched, LandingImpact};
+use crate::player::moveset;
+use crate::rules::{Auras, RuleSpawned, Stunned};
+use std::collections::HashMap;
+use wasm_fantasia_shared::spawns::{Shockwave, SpawnKind};
+use wasm_fantasia_shared::weapons::Weapon;
 
 type VatMaterial = ExtendedMaterial<StandardMaterial, OpenVatExtension>;
 
@@ -29,9 +36,11 @@ pub fn plugin(app: &mut App) {
     app.add_observer(on_launch_shockwave)
         .add_observer(on_landing_vfx)
         .add_observer(on_ground_pound_vfx)
-        .add_observer(on_footstep_dust)
+        .add_observer(on_shield_break_vfx)
+        .add_observer(on_rule_shockwave_vfx)
+        .add_observer(on_stun_vfx)
         .add_systems(Startup, setup_shockwave_assets)
-        .add_systems(Update, tick_shockwave_vfx);
+        .add_systems(Update, (pulse_aura_vfx, tick_shockwave_vfx));
 }
 
 // ── Hit Flash ───────────────────────────────────────────────────────
@@ -114,10 +123,11 @@ fn tick_hit_flash(
 
 // ── Arc Slash (Phantom Fist) ────────────────────────────────────────
 
+/// Trail mesh plus one material per weapon, tinted by its moveset.
 #[derive(Resource)]
 pub struct ArcSlashAssets {
     pub mesh: Handle<Mesh>,
-    pub material: Handle<StandardMaterial>,
+    pub materials: HashMap<Weapon, Handle<StandardMaterial>>,
 }
 
 #[derive(Component)]
@@ -126,6 +136,8 @@ pub struct ArcSlash {
     pub timer: f32,
     pub duration: f32,
     pub start_scale: Vec3,
+    /// Grows out to the weapon's reach.
+    pub end_scale: Vec3,
 }
 
 fn setup_phantom_fist_assets(
@@ -136,17 +148,27 @@ fn setup_phantom_fist_assets(
     let arc_mesh = create_arc_mesh(VFX_RANGE, VFX_ARC_DEGREES.to_radians(), 0.6, 16);
     let mesh = meshes.add(arc_mesh);
 
-    let material = materials.add(StandardMaterial {
-        base_color: Color::srgba(0.7, 0.85, 1.0, 0.4),
-        emissive: LinearRgba::new(2.0, 3.0, 5.0, 1.0),
-        alpha_mode: AlphaMode::Blend,
-        unlit: true,
-        cull_mode: None,
-        double_sided: true,
-        ..default()
+    let by_weapon = [Weapon::Fists, Weapon::Sword, Weapon::Hammer]
+        .into_iter()
+        .map(|weapon| {
+            let moveset = moveset(weapon);
+            let material = materials.add(StandardMaterial {
+                base_color: moveset.trail_color,
+                emissive: moveset.trail_emissive,
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                cull_mode: None,
+                double_sided: true,
+                ..default()
+            });
+            (weapon, material)
+        })
+        .collect();
+
+    commands.insert_resource(ArcSlashAssets {
+        mesh,
+        materials: by_weapon,
     });
-
-    commands.insert_resource(ArcSlashAssets { mesh, material });
 }
 
 fn create_arc_mesh(radius: f32, arc_angle: f32, height: f32, segments: u32) -> Mesh {
@@ -193,7 +215,7 @@ fn create_arc_mesh(radius: f32, arc_angle: f32, height: f32, segments: u32) -> M
 
 fn on_phantom_fist(
     on: On<AttackIntent>,
-    transforms: Query<&Transform>,
+    attackers: Query<(&Transform, Option<&EquippedWeapon>)>,
     assets: Option<Res<ArcSlashAssets>>,
     mut commands: Commands,
 ) {
@@ -201,20 +223,26 @@ fn on_phantom_fist(
         return;
     };
 
-    let Ok(tf) = transforms.get(on.event().attacker) else {
+    let Ok((tf, weapon)) = attackers.get(on.event().attacker) else {
+        return;
+    };
+    let weapon = weapon.map(|w| w.0).unwrap_or_default();
+    let Some(material) = assets.materials.get(&weapon) else {
         return;
     };
 
     let pos = tf.translation + Vec3::Y * 0.8;
+    let reach = 1.0 + weapon.stats().range_bonus / VFX_RANGE;
 
     commands.spawn((
         ArcSlash {
             timer: 0.0,
             duration: 0.15,
             start_scale: Vec3::new(0.3, 1.0, 0.3),
+            end_scale: Vec3::new(reach, 1.0, reach),
         },
         Mesh3d(assets.mesh.clone()),
-        MeshMaterial3d(assets.material.clone()),
+        MeshMaterial3d(material.clone()),
         Transform::from_translation(pos)
             .with_rotation(tf.rotation)
             .with_scale(Vec3::new(0.3, 1.0, 0.3)),
@@ -236,7 +264,7 @@ fn tick_phantom_fist(
         }
 
         let eased = 1.0 - (1.0 - t).powi(3);
-        let scale = slash.start_scale.lerp(Vec3::ONE, eased);
+        let scale = slash.start_scale.lerp(slash.end_scale, eased);
         transform.scale = scale;
     }
 }
@@ -473,6 +501,7 @@ fn tick_impact_vfx(
 struct ShockwaveAssets {
     ring_mesh: Handle<Mesh>,
     ring_material: Handle<StandardMaterial>,
+    aura_material: Handle<StandardMaterial>,
     dust_mesh: Handle<Mesh>,
     dust_material: Handle<StandardMaterial>,
 }
@@ -511,6 +540,16 @@ fn setup_shockwave_assets(
         ..default()
     });
 
+    // Fainter and warmer, so aura pulses read apart from shockwaves
+    let aura_material = materials.add(StandardMaterial {
+        base_color: crate::ui::colors::SAND_YELLOW.with_alpha(0.35),
+        emissive: LinearRgba::new(3.0, 2.2, 0.8, 1.0),
+        alpha_mode: AlphaMode::Add,
+        unlit: true,
+        cull_mode: None,
+        ..default()
+    });
+
     // Chunky dust puffs — large enough to read at game camera distance
     let dust_mesh = meshes.add(Sphere::new(0.2));
     let dust_material = materials.add(StandardMaterial {
@@ -524,6 +563,7 @@ fn setup_shockwave_assets(
     commands.insert_resource(ShockwaveAssets {
         ring_mesh,
         ring_material,
+        aura_material,
         dust_mesh,
         dust_material,
     });
@@ -750,39 +790,141 @@ fn on_ground_pound_vfx(
     }
 }
 
-// ── Footstep Dust ───────────────────────────────────────────────────
+// ── Rule Shockwave VFX ──────────────────────────────────────────────
 
-fn on_footstep_dust(
-    on: On<Footstep>,
+/// Ring sized to the shockwave's reach where a rule set one off.
+fn on_rule_shockwave_vfx(
+    on: On<RuleSpawned>,
     assets: Option<Res<ShockwaveAssets>>,
+    sources: Query<&GlobalTransform>,
     mut commands: Commands,
 ) {
     let Some(assets) = assets else {
         return;
     };
-
     let event = on.event();
-    let pos = event.position - Vec3::Y * 0.8;
-    let mut rng = rand::rng();
+    if event.request.kind() != Some(SpawnKind::Shockwave) {
+        return;
+    }
+    let Some(position) = event
+        .position
+        .or_else(|| sources.get(event.source).ok().map(|t| t.translation()))
+    else {
+        return;
+    };
+    let wave = Shockwave::from_request(&event.request);
 
-    for _ in 0..5 {
-        let angle = rand::Rng::random_range(&mut rng, 0.0..std::f32::consts::TAU);
-        let vert = rand::Rng::random_range(&mut rng, 0.1..0.3);
-        let dir = Vec3::new(angle.cos(), vert, angle.sin()).normalize();
-        let speed = rand::Rng::random_range(&mut rng, 1.5..3.0);
-        let duration = rand::Rng::random_range(&mut rng, 0.2..0.35);
+    commands.spawn((
+        ShockwaveRing {
+            timer: 0.0,
+            duration: 0.35,
+            max_scale: wave.radius,
+        },
+        Mesh3d(assets.ring_mesh.clone()),
+        MeshMaterial3d(assets.ring_material.clone()),
+        Transform::from_translation(position - Vec3::Y * 0.8)
+            .with_rotation(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2))
+            .with_scale(Vec3::splat(0.1)),
+    ));
+}
 
-        commands.spawn((
-            ShockwaveDust {
-                timer: 0.0,
-                duration,
-                direction: dir,
-                speed,
-                start_pos: pos,
-            },
-            Mesh3d(assets.dust_mesh.clone()),
-            MeshMaterial3d(assets.dust_material.clone()),
-            Transform::from_translation(pos).with_scale(Vec3::splat(0.5)),
-        ));
+// ── Shield Break VFX ────────────────────────────────────────────────
+
+/// Quick ring burst at chest height when a shield pops.
+fn on_shield_break_vfx(
+    on: On<ShieldBroken>,
+    assets: Option<Res<ShockwaveAssets>>,
+    targets: Query<(&GlobalTransform, Option<&MeshHeight>)>,
+    mut commands: Commands,
+) {
+    let Some(assets) = assets else {
+        return;
+    };
+    let Ok((target_tf, mesh_height)) = targets.get(on.event().entity) else {
+        return;
+    };
+
+    let pos = target_tf.translation() + Vec3::Y * mesh_height.map_or(1.0, |h| h.0 * 0.5);
+
+    commands.spawn((
+        ShockwaveRing {
+            timer: 0.0,
+            duration: 0.25,
+            max_scale: 2.0,
+        },
+        Mesh3d(assets.ring_mesh.clone()),
+        MeshMaterial3d(assets.ring_material.clone()),
+        Transform::from_translation(pos)
+            .with_rotation(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2))
+            .with_scale(Vec3::splat(0.1)),
+    ));
+}
+
+// ── Aura VFX ────────────────────────────────────────────────────────
+
+/// A ring sweeping out to an aura's reach each time it pulses. Paced by
+/// the local clock, so it only roughly lines up with the server's pulses.
+fn pulse_aura_vfx(
+    time: Res<Time>,
+    assets: Option<Res<ShockwaveAssets>>,
+    owners: Query<(&Auras, &GlobalTransform)>,
+    mut commands: Commands,
+) {
+    let Some(assets) = assets else {
+        return;
+    };
+    let now = time.elapsed().as_micros() as i64;
+    let before = now - time.delta().as_micros() as i64;
+
+    for (auras, owner_tf) in owners.iter() {
+        for aura in &auras.0 {
+            if aura.fires_between(before, now) == 0 {
+                continue;
+            }
+            commands.spawn((
+                ShockwaveRing {
+                    timer: 0.0,
+                    duration: 0.8,
+                    max_scale: aura.radius,
+                },
+                Mesh3d(assets.ring_mesh.clone()),
+                MeshMaterial3d(assets.aura_material.clone()),
+                Transform::from_translation(owner_tf.translation() - Vec3::Y * 0.8)
+                    .with_rotation(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2))
+                    .with_scale(Vec3::splat(0.1)),
+            ));
+        }
     }
 }
+
+// ── Stun VFX ────────────────────────────────────────────────────────
+
+/// Slow ring over the head when a stun takes hold.
+fn on_stun_vfx(
+    on: On<Add, Stunned>,
+    assets: Option<Res<ShockwaveAssets>>,
+    targets: Query<(&GlobalTransform, Option<&MeshHeight>)>,
+    mut commands: Commands,
+) {
+    let Some(assets) = assets else {
+        return;
+    };
+    let Ok((target_tf, mesh_height)) = targets.get(on.entity) else {
+        return;
+    };
+
+    let pos = target_tf.translation() + Vec3::Y * mesh_height.map_or(2.0, |h| h.0);
+
+    commands.spawn((
+        ShockwaveRing {
+            timer: 0.0,
+            duration: 0.6,
+            max_scale: 0.8,
+        },
+        Mesh3d(assets.ring_mesh.clone()),
+        MeshMaterial3d(assets.ring_material.clone()),
+        Transform::from_translation(pos)
+            .with_rotation(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2))
+            .with_scale(Vec3::splat(0.1)),
+    ));
+}
@@ -6,10 +6,15 @@ use crate::asset_loading::Fonts;
 use crate::combat::{DamageDealt, Died, Enemy, Health, PlayerCombatant};
 use crate::models::{Player as LocalPlayer, Screen, Session};
 use crate::networking::ServerDiagnostics;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::networking::local_server::{LocalServer, LocalServerState};
 use crate::rules::{Stat, Stats};
-use crate::ui::{colors, size};
+use crate::ui::{StatTooltip, colors, size};
 
 const MAX_ENTRIES: usize = 10;
+/// Trailing lines of the singleplayer server's output shown in the panel.
+#[cfg(not(target_arch = "wasm32"))]
+const MAX_SERVER_LOG_LINES: usize = 6;
 
 // ── Log entries ──────────────────────────────────────────────────────
 
@@ -259,6 +264,40 @@ fn spawn_body(commands: &mut Commands, panel: Entity, text: impl Into<String>) {
     ));
 }
 
+/// Offensive stats of `entity`, each hoverable for its breakdown.
+fn spawn_stat_row(commands: &mut Commands, panel: Entity, entity: Entity, stats: &Stats) {
+    const STATS: [(Stat, &str); 4] = [
+        (Stat::AttackDamage, "Dmg"),
+        (Stat::CritChance, "Crit"),
+        (Stat::CritMultiplier, "CritX"),
+        (Stat::AttackSpeed, "Spd"),
+    ];
+
+    let row = commands
+        .spawn((
+            DebugText,
+            ChildOf(panel),
+            Node {
+                column_gap: Val::Px(10.0),
+                ..default()
+            },
+        ))
+        .id();
+    for (stat, label) in STATS {
+        let value = stats.get(&stat);
+        commands.spawn((
+            ChildOf(row),
+            StatTooltip::of(entity, stat),
+            Text::new(format!("{label} {value:.2}")),
+            TextFont {
+                font_size: 12.0,
+                ..default()
+            },
+            TextColor(colors::NEUTRAL400),
+        ));
+    }
+}
+
 // ── Render ───────────────────────────────────────────────────────────
 
 fn update_overlay(
@@ -269,7 +308,9 @@ fn update_overlay(
     existing: Query<Entity, With<DebugText>>,
     mut commands: Commands,
     server_diag: Res<ServerDiagnostics>,
-    player_query: Query<(&Health, Option<&Stats>), With<PlayerCombatant>>,
+    player_query: Query<(Entity, &Health, Option<&Stats>), With<PlayerCombatant>>,
+    #[cfg(not(target_arch = "wasm32"))] local_server: Option<Res<LocalServer>>,
+    #[cfg(not(target_arch = "wasm32"))] local_server_state: Option<Res<LocalServerState>>,
 ) {
     log.frame = log.frame.wrapping_add(1);
 
@@ -300,7 +341,7 @@ fn update_overlay(
     };
 
     // ── Player stats ────────────────────────────────────────
-    if let Ok((health, stats)) = player_query.single() {
+    if let Ok((player, health, stats)) = player_query.single() {
         let stacks = stats
             .map(|s| s.get(&Stat::Custom("Stacks".into())) as u32)
             .unwrap_or(0);
@@ -314,6 +355,9 @@ fn update_overlay(
                 health.current, health.max, stacks,
             ),
         );
+        if let Some(stats) = stats {
+            spawn_stat_row(&mut commands, panel_entity, player, stats);
+        }
     }
 
     // ── Server sections ─────────────────────────────────────
@@ -394,6 +438,30 @@ fn update_overlay(
         }
     }
 
+    // ── Local server ────────────────────────────────────────
+    #[cfg(not(target_arch = "wasm32"))]
+    if let (Some(server), Some(state)) = (local_server, local_server_state) {
+        let title = match state.as_ref() {
+            LocalServerState::Crashed { retry, .. } => format!(
+                "Local Server  crashed, restart in {:.0}s",
+                retry.remaining_secs().ceil()
+            ),
+            state => format!("Local Server  {}  :{}", state.label(), server.port),
+        };
+        spawn_title(&mut commands, panel_entity, &fonts, title);
+
+        let mut body = String::new();
+        for line in server.log_tail(MAX_SERVER_LOG_LINES) {
+            let _ = writeln!(body, "{}", line.text);
+        }
+        if let LocalServerState::Failed(err) = state.as_ref() {
+            let _ = writeln!(body, "{err}");
+        }
+        if !body.is_empty() {
+            spawn_body(&mut commands, panel_entity, body.trim_end());
+        }
+    }
+
     // ── Event log ───────────────────────────────────────────
     if !log.entries.is_empty() {
         spawn_title(&mut commands, panel_entity, &fonts, "Combat Log");
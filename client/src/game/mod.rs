@@ -4,6 +4,10 @@ pub mod combat_debug;
 #[cfg(feature = "dev")]
 mod dev_tools;
 mod music;
+#[cfg(feature = "dev")]
+mod rule_editor;
+#[cfg(feature = "dev")]
+mod rule_trace;
 
 pub fn plugin(app: &mut App) {
     app.add_plugins((
@@ -17,6 +21,10 @@ pub fn plugin(app: &mut App) {
         combat_debug::plugin,
         #[cfg(feature = "dev")]
         dev_tools::plugin,
+        #[cfg(feature = "dev")]
+        rule_editor::plugin,
+        #[cfg(feature = "dev")]
+        rule_trace::plugin,
         screens::plugin,
     ));
 }
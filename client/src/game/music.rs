@@ -1,9 +1,12 @@
-//! An abstraction for changing music of the game depending on some triggers
+//! An abstraction for changing music of the game depending on some triggers.
+//! Authored [`Mood`] and [`Ambience`] zones switch the soundtrack and the
+//! ambience bed as the player walks through them.
 
 use crate::*;
 use avian3d::prelude::Collisions;
 use bevy::time::common_conditions::on_timer;
 use bevy_seedling::prelude::*;
+use std::collections::HashMap;
 use std::time::Duration;
 
 pub fn plugin(app: &mut App) {
@@ -18,14 +21,20 @@ pub fn plugin(app: &mut App) {
             .run_if(in_state(Screen::Gameplay))
             .run_if(on_timer(Duration::from_millis(200))),
     )
-    .add_observer(change_mood);
+    .init_resource::<AmbiencePlaybacks>()
+    .add_observer(change_mood)
+    .add_observer(change_ambience);
 }
 
 fn start_soundtrack(
     settings: Res<Settings>,
     mut commands: Commands,
     mut sources: ResMut<AudioSources>,
+    mut state: ResMut<Session>,
 ) {
+    state.current_mood = Mood::default();
+    state.current_ambience = None;
+
     let mut rng = rand::rng();
     let handle = sources.explore.pick(&mut rng);
 
@@ -46,46 +55,64 @@ fn start_soundtrack(
     commands.insert_resource(mp);
 }
 
-fn stop_soundtrack(mut commands: Commands, music_pb: Res<MusicPlaybacks>) {
-    for (_, e) in music_pb.iter() {
+fn stop_soundtrack(
+    mut commands: Commands,
+    music_pb: Res<MusicPlaybacks>,
+    mut ambience_pb: ResMut<AmbiencePlaybacks>,
+) {
+    for e in music_pb.values().chain(ambience_pb.values()) {
         commands.entity(*e).insert(FadeOut);
     }
+    ambience_pb.clear();
 }
 
+/// Pick the highest-priority mood and ambience among the zones the player is
+/// standing in, falling back to the scene's [`SceneAudio`] outside of them.
 fn trigger_mood_change(
     collisions: Collisions,
-    state: ResMut<Session>,
-    zones: Query<(Entity, &Mood)>,
+    state: Res<Session>,
+    mood_zones: Query<(Entity, &Mood, Option<&ZonePriority>)>,
+    ambience_zones: Query<(Entity, &Ambience, Option<&ZonePriority>)>,
+    scene_audio: Query<&SceneAudio>,
     mut commands: Commands,
     mut player: Query<Entity, With<Player>>,
 ) {
     let Ok(player) = player.single_mut() else {
         return;
     };
-    for (e, zone) in zones.iter() {
-        if collisions.contains(player, e) {
-            info!("colliding with zone: {:?}", zone);
-            match zone {
-                Mood::Combat => {
-                    if state.current_mood != Mood::Combat {
-                        debug!("Trigger changing mood from:{:?}", state.current_mood);
-                        commands.trigger(ChangeMood {
-                            mood: Mood::Combat,
-                            entity: player,
-                        });
-                    }
-                }
-                Mood::Exploration => {
-                    if state.current_mood != Mood::Exploration {
-                        debug!("Trigger changing mood from:{:?}", state.current_mood);
-                        commands.trigger(ChangeMood {
-                            mood: Mood::Exploration,
-                            entity: player,
-                        })
-                    }
-                }
-            }
-        }
+    let fallback = scene_audio.iter().next().copied().unwrap_or_default();
+
+    let mood = mood_zones
+        .iter()
+        .filter(|(e, ..)| collisions.contains(player, *e))
+        .max_by_key(|(.., priority)| priority.copied().unwrap_or_default().0)
+        .map_or(fallback.mood, |(_, mood, _)| *mood);
+    if mood != state.current_mood {
+        debug!(
+            "Trigger changing mood from:{:?} to:{:?}",
+            state.current_mood, mood
+        );
+        commands.trigger(ChangeMood {
+            mood,
+            entity: player,
+        });
+    }
+
+    let ambience = ambience_zones
+        .iter()
+        .filter(|(e, ..)| collisions.contains(player, *e))
+        .max_by_key(|(.., priority)| priority.copied().unwrap_or_default().0)
+        .map(|(_, ambience, _)| *ambience)
+        .or(fallback.ambience);
+    if ambience != state.current_ambience {
+        debug!(
+            "Trigger changing ambience from:{:?} to:{:?}",
+            state.current_ambience, ambience
+        );
+        commands.trigger(ChangeAmbience {
+            ambience,
+            entity: player,
+        });
     }
 }
 
@@ -134,3 +161,51 @@ fn change_mood(
         on.mood,
     ));
 }
+
+/// Looping ambience beds, kept around (paused) once spawned so re-entering a
+/// zone resumes the same bed.
+#[derive(Resource, Default, Deref, DerefMut)]
+struct AmbiencePlaybacks(HashMap<Ambience, Entity>);
+
+fn change_ambience(
+    on: On<ChangeAmbience>,
+    settings: Res<Settings>,
+    sources: Res<AudioSources>,
+    mut ambience_pb: ResMut<AmbiencePlaybacks>,
+    mut state: ResMut<Session>,
+    mut commands: Commands,
+) {
+    for (ambience, bed) in ambience_pb.iter() {
+        if Some(*ambience) != on.ambience {
+            commands.entity(*bed).insert(FadeOut);
+        }
+    }
+    state.current_ambience = on.ambience;
+
+    let Some(ambience) = on.ambience else {
+        return;
+    };
+    if let Some(bed) = ambience_pb.get(&ambience) {
+        commands.entity(*bed).insert(FadeIn);
+        return;
+    }
+
+    let handle = match ambience {
+        Ambience::Wind => sources.wind.clone(),
+        Ambience::CaveDrips => sources.cave_drips.clone(),
+    };
+    let bed = commands
+        .spawn((
+            MusicPool,
+            SamplePlayer::new(handle)
+                .with_volume(settings.music())
+                .looping(),
+            sample_effects![VolumeNode {
+                volume: Volume::SILENT,
+                ..default()
+            }],
+            FadeIn,
+        ))
+        .id();
+    ambience_pb.insert(ambience, bed);
+}
@@ -0,0 +1,237 @@
+//! Rule editor: F8 opens a window listing the local player's rules by
+//! trigger as RON, the same shape a [`RulePreset`](crate::rules::RulePreset)
+//! file takes. Edit any of them and apply to swap the player's rule
+//! components in place, no restart needed. Online the server still runs
+//! its own copy, so edits only change what the client predicts and plays
+//! offline. Dev builds only.
+
+use bevy::ecs::query::QueryData;
+use bevy::prelude::*;
+use bevy_inspector_egui::bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+use ron::ser::PrettyConfig;
+use serde::Serialize;
+
+use crate::models::{Player as LocalPlayer, Screen};
+use crate::rules::{
+    Auras, OnBlockRules, OnCritHitRules, OnDodgeRules, OnHealthThresholdRules, OnHitRules,
+    OnKillRules, OnPreHitRules, OnTakeDamageRules, OnTickRules, OnTimerRules,
+};
+
+/// The rule lists the editor shows, in the order it shows them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Trigger {
+    PreHit,
+    OnHit,
+    OnCritHit,
+    OnKill,
+    OnTakeDamage,
+    OnTick,
+    OnDodge,
+    OnBlock,
+    OnHealthThreshold,
+    OnTimer,
+    Auras,
+}
+
+impl Trigger {
+    const ALL: [Self; 11] = [
+        Self::PreHit,
+        Self::OnHit,
+        Self::OnCritHit,
+        Self::OnKill,
+        Self::OnTakeDamage,
+        Self::OnTick,
+        Self::OnDodge,
+        Self::OnBlock,
+        Self::OnHealthThreshold,
+        Self::OnTimer,
+        Self::Auras,
+    ];
+
+    /// Named like the matching [`RulePreset`](crate::rules::RulePreset)
+    /// field, so a draft can be pasted into a preset file as is.
+    fn label(self) -> &'static str {
+        match self {
+            Self::PreHit => "on_pre_hit",
+            Self::OnHit => "on_hit",
+            Self::OnCritHit => "on_crit_hit",
+            Self::OnKill => "on_kill",
+            Self::OnTakeDamage => "on_take_damage",
+            Self::OnTick => "on_tick",
+            Self::OnDodge => "on_dodge",
+            Self::OnBlock => "on_block",
+            Self::OnHealthThreshold => "on_health_threshold",
+            Self::OnTimer => "on_timer",
+            Self::Auras => "auras",
+        }
+    }
+}
+
+/// The rule components the editor reads, each missing when the entity has
+/// no rules for that trigger.
+#[derive(QueryData)]
+struct RuleComponents {
+    pre_hit: Option<&'static OnPreHitRules>,
+    on_hit: Option<&'static OnHitRules>,
+    on_crit_hit: Option<&'static OnCritHitRules>,
+    on_kill: Option<&'static OnKillRules>,
+    on_take_damage: Option<&'static OnTakeDamageRules>,
+    on_tick: Option<&'static OnTickRules>,
+    on_dodge: Option<&'static OnDodgeRules>,
+    on_block: Option<&'static OnBlockRules>,
+    on_health_threshold: Option<&'static OnHealthThresholdRules>,
+    on_timer: Option<&'static OnTimerRules>,
+    auras: Option<&'static Auras>,
+}
+
+impl RuleComponentsItem<'_, '_> {
+    /// The rules for `trigger` as RON.
+    fn draft(&self, trigger: Trigger) -> String {
+        match trigger {
+            Trigger::PreHit => to_ron(self.pre_hit.map(|c| &c.0)),
+            Trigger::OnHit => to_ron(self.on_hit.map(|c| &c.0)),
+            Trigger::OnCritHit => to_ron(self.on_crit_hit.map(|c| &c.0)),
+            Trigger::OnKill => to_ron(self.on_kill.map(|c| &c.0)),
+            Trigger::OnTakeDamage => to_ron(self.on_take_damage.map(|c| &c.0)),
+            Trigger::OnTick => to_ron(self.on_tick.map(|c| &c.0)),
+            Trigger::OnDodge => to_ron(self.on_dodge.map(|c| &c.0)),
+            Trigger::OnBlock => to_ron(self.on_block.map(|c| &c.0)),
+            Trigger::OnHealthThreshold => to_ron(self.on_health_threshold.map(|c| &c.0)),
+            Trigger::OnTimer => to_ron(self.on_timer.map(|c| &c.0)),
+            Trigger::Auras => to_ron(self.auras.map(|c| &c.0)),
+        }
+    }
+}
+
+/// A missing list reads as an empty one.
+fn to_ron<T: Serialize>(rules: Option<&Vec<T>>) -> String {
+    let empty = Vec::new();
+    ron::ser::to_string_pretty(rules.unwrap_or(&empty), PrettyConfig::default())
+        .unwrap_or_else(|e| format!("// failed to serialize: {e}"))
+}
+
+/// Parse `text` as the rules for `trigger` and put them on the entity.
+fn apply(
+    trigger: Trigger,
+    text: &str,
+    entity: &mut EntityCommands,
+) -> Result<(), ron::error::SpannedError> {
+    match trigger {
+        Trigger::PreHit => entity.insert(OnPreHitRules(ron::from_str(text)?)),
+        Trigger::OnHit => entity.insert(OnHitRules(ron::from_str(text)?)),
+        Trigger::OnCritHit => entity.insert(OnCritHitRules(ron::from_str(text)?)),
+        Trigger::OnKill => entity.insert(OnKillRules(ron::from_str(text)?)),
+        Trigger::OnTakeDamage => entity.insert(OnTakeDamageRules(ron::from_str(text)?)),
+        Trigger::OnTick => entity.insert(OnTickRules(ron::from_str(text)?)),
+        Trigger::OnDodge => entity.insert(OnDodgeRules(ron::from_str(text)?)),
+        Trigger::OnBlock => entity.insert(OnBlockRules(ron::from_str(text)?)),
+        Trigger::OnHealthThreshold => entity.insert(OnHealthThresholdRules(ron::from_str(text)?)),
+        Trigger::OnTimer => entity.insert(OnTimerRules(ron::from_str(text)?)),
+        Trigger::Auras => entity.insert(Auras(ron::from_str(text)?)),
+    };
+    Ok(())
+}
+
+struct Draft {
+    trigger: Trigger,
+    text: String,
+    /// Why the last apply couldn't parse this draft.
+    error: Option<String>,
+}
+
+#[derive(Resource, Default)]
+struct RuleEditor {
+    open: bool,
+    /// The player the drafts were read from. Drafts are read again when the
+    /// player respawns.
+    loaded_for: Option<Entity>,
+    drafts: Vec<Draft>,
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<RuleEditor>()
+        .add_systems(Update, toggle_editor.run_if(in_state(Screen::Gameplay)))
+        .add_systems(
+            EguiPrimaryContextPass,
+            editor_window.run_if(in_state(Screen::Gameplay)),
+        );
+}
+
+fn toggle_editor(input: Res<ButtonInput<KeyCode>>, mut editor: ResMut<RuleEditor>) {
+    if input.just_pressed(KeyCode::F8) {
+        editor.open = !editor.open;
+    }
+}
+
+fn editor_window(
+    mut contexts: EguiContexts,
+    mut editor: ResMut<RuleEditor>,
+    player: Query<(Entity, RuleComponents), With<LocalPlayer>>,
+    mut commands: Commands,
+) -> Result {
+    if !editor.open {
+        return Ok(());
+    }
+    let Ok((entity, rules)) = player.single() else {
+        return Ok(());
+    };
+    if editor.loaded_for != Some(entity) {
+        load(&mut editor, entity, &rules);
+    }
+
+    let ctx = contexts.ctx_mut()?;
+    let mut open = true;
+    let mut reload = false;
+    egui::Window::new("Rule Editor")
+        .open(&mut open)
+        .default_width(480.0)
+        .default_height(560.0)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                if ui.button("Apply").clicked() {
+                    let mut target = commands.entity(entity);
+                    for draft in &mut editor.drafts {
+                        draft.error = apply(draft.trigger, &draft.text, &mut target)
+                            .err()
+                            .map(|e| e.to_string());
+                    }
+                }
+                if ui.button("Reload").clicked() {
+                    reload = true;
+                }
+            });
+            ui.separator();
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                for draft in &mut editor.drafts {
+                    ui.collapsing(draft.trigger.label(), |ui| {
+                        if let Some(error) = &draft.error {
+                            ui.colored_label(egui::Color32::LIGHT_RED, error);
+                        }
+                        ui.add(
+                            egui::TextEdit::multiline(&mut draft.text)
+                                .code_editor()
+                                .desired_width(f32::INFINITY),
+                        );
+                    });
+                }
+            });
+        });
+    editor.open = open;
+    if reload {
+        load(&mut editor, entity, &rules);
+    }
+    Ok(())
+}
+
+/// Read fresh drafts from the player's rule components.
+fn load(editor: &mut RuleEditor, entity: Entity, rules: &RuleComponentsItem) {
+    editor.loaded_for = Some(entity);
+    editor.drafts = Trigger::ALL
+        .into_iter()
+        .map(|trigger| Draft {
+            trigger,
+            text: rules.draft(trigger),
+            error: None,
+        })
+        .collect();
+}
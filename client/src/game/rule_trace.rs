@@ -0,0 +1,171 @@
+//! Rule trace overlay: F6 turns rule tracing on and shows the last few
+//! passes (which rules fired, their conditions, what they changed); F7 dumps
+//! the passes kept so far to the log. Dev builds only.
+
+use bevy::prelude::*;
+use std::collections::VecDeque;
+use std::fmt::Write;
+
+use crate::asset_loading::Fonts;
+use crate::models::Screen;
+use crate::rules::{RulesTraced, TriggerTrace, set_tracing, tracing};
+use crate::ui::{colors, size};
+
+const MAX_TRACES: usize = 8;
+
+struct TraceEntry {
+    source: String,
+    trace: TriggerTrace,
+}
+
+#[derive(Component)]
+struct TracePanel;
+
+#[derive(Component)]
+struct TraceText;
+
+#[derive(Resource, Default)]
+struct TraceLog {
+    entries: VecDeque<TraceEntry>,
+    dirty: bool,
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<TraceLog>()
+        .add_systems(OnEnter(Screen::Gameplay), spawn_panel)
+        .add_observer(observe_traces)
+        .add_systems(Update, (toggle_tracing, dump_traces, update_panel).chain());
+}
+
+fn spawn_panel(mut commands: Commands) {
+    commands.spawn((
+        TracePanel,
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(10.0),
+            bottom: Val::Px(10.0),
+            max_height: Val::Vh(60.0),
+            max_width: Val::Px(420.0),
+            padding: UiRect::axes(Val::Px(16.0), Val::Px(12.0)),
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(8.0),
+            overflow: Overflow::clip_y(),
+            border_radius: BorderRadius::all(size::BORDER_RADIUS),
+            ..default()
+        },
+        BackgroundColor(colors::NEUTRAL920.with_alpha(0.92)),
+        Visibility::Hidden,
+    ));
+}
+
+fn toggle_tracing(
+    input: Res<ButtonInput<KeyCode>>,
+    mut log: ResMut<TraceLog>,
+    mut panel: Query<&mut Visibility, With<TracePanel>>,
+) {
+    if !input.just_pressed(KeyCode::F6) {
+        return;
+    }
+    let enabled = !tracing();
+    set_tracing(enabled);
+    log.dirty = true;
+    info!("Rule tracing {}", if enabled { "on" } else { "off" });
+
+    if let Ok(mut vis) = panel.single_mut() {
+        *vis = if enabled {
+            Visibility::Visible
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
+fn dump_traces(input: Res<ButtonInput<KeyCode>>, log: Res<TraceLog>) {
+    if !input.just_pressed(KeyCode::F7) {
+        return;
+    }
+    if log.entries.is_empty() {
+        info!("No rule traces kept (F6 turns tracing on)");
+        return;
+    }
+    let mut dump = String::new();
+    for entry in &log.entries {
+        let _ = write!(dump, "[{}] {}", entry.source, entry.trace);
+    }
+    info!(
+        "Last {} rule passes:\n{}",
+        log.entries.len(),
+        dump.trim_end()
+    );
+}
+
+fn observe_traces(on: On<RulesTraced>, mut log: ResMut<TraceLog>, names: Query<&Name>) {
+    let event = on.event();
+    let source = names
+        .get(event.source)
+        .map(|n| n.as_str().to_string())
+        .unwrap_or_else(|_| format!("#{}", event.source.index()));
+    for trace in &event.traces {
+        if log.entries.len() >= MAX_TRACES {
+            log.entries.pop_front();
+        }
+        log.entries.push_back(TraceEntry {
+            source: source.clone(),
+            trace: trace.clone(),
+        });
+    }
+    log.dirty = true;
+}
+
+fn update_panel(
+    mut log: ResMut<TraceLog>,
+    fonts: Option<Res<Fonts>>,
+    panel: Query<Entity, With<TracePanel>>,
+    existing: Query<Entity, With<TraceText>>,
+    mut commands: Commands,
+) {
+    let Some(fonts) = fonts else {
+        return;
+    };
+    if !log.dirty || !tracing() {
+        return;
+    }
+    log.dirty = false;
+
+    for e in &existing {
+        commands.entity(e).despawn();
+    }
+    let Ok(panel_entity) = panel.single() else {
+        return;
+    };
+
+    commands.spawn((
+        TraceText,
+        ChildOf(panel_entity),
+        Text::new("Rule Trace  (F7 dumps to log)"),
+        TextFont {
+            font: fonts.semibold.clone(),
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(colors::NEUTRAL300),
+    ));
+
+    let mut body = String::new();
+    for entry in log.entries.iter().rev() {
+        let _ = write!(body, "[{}] {}", entry.source, entry.trace);
+    }
+    if body.is_empty() {
+        body.push_str("No rule passes yet");
+    }
+    commands.spawn((
+        TraceText,
+        ChildOf(panel_entity),
+        Text::new(body.trim_end()),
+        TextFont {
+            font_size: 12.0,
+            ..default()
+        },
+        TextColor(colors::NEUTRAL500),
+    ));
+}
@@ -7,6 +7,8 @@ use bevy_fix_cursor_unlock_web::prelude::*;
 
 pub mod asset_loading;
 pub mod audio;
+#[cfg(target_arch = "wasm32")]
+mod boot_check;
 pub mod camera;
 pub mod combat;
 pub mod game;
@@ -18,6 +20,8 @@ pub mod rule_presets;
 pub mod rules;
 pub mod scene;
 pub mod screens;
+#[cfg(feature = "smoke_test")]
+mod smoke_test;
 pub mod ui;
 pub mod venom_voice;
 
@@ -26,13 +30,21 @@ use audio::*;
 use models::*;
 use ui::*;
 
-fn main() {
+fn main() -> AppExit {
+    #[cfg(target_arch = "wasm32")]
+    boot_check::run(&networking::SpacetimeDbConfig::default().uri);
+
+    #[cfg(feature = "smoke_test")]
+    let smoke_script = smoke_test::script_from_args();
+
     let mut app = App::new();
 
     let window = WindowPlugin {
         primary_window: Some(Window {
             title: "WASM Fantasia".to_string(),
             fit_canvas_to_parent: true,
+            #[cfg(feature = "smoke_test")]
+            visible: smoke_script.is_none(),
             ..default()
         }),
         ..default()
@@ -66,6 +78,11 @@ fn main() {
     app.add_plugins(networking::NetworkingPlugin);
     app.add_plugins(venom_voice::plugin);
 
+    #[cfg(feature = "smoke_test")]
+    if let Some(script) = smoke_script {
+        app.add_plugins(smoke_test::SmokeTestPlugin { script });
+    }
+
     // override default font
     load_internal_binary_asset!(
         app,
@@ -73,5 +90,5 @@ fn main() {
         "../assets/fonts/ChakraPetch-SemiBold.ttf",
         |bytes: &[u8], _path: String| { Font::try_from_bytes(bytes.to_vec()).unwrap() }
     );
-    app.run();
+    app.run()
 }
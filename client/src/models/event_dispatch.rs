@@ -31,6 +31,11 @@ pub struct ChangeMood {
     pub entity: Entity,
     pub mood: Mood,
 }
+#[derive(EntityEvent)]
+pub struct ChangeAmbience {
+    pub entity: Entity,
+    pub ambience: Option<Ambience>,
+}
 /// Event triggered on a UI entity when the [`Interaction`] component on the same entity changes to
 /// [`Interaction::Pressed`]. Observe this event to detect e.g. button presses.
 #[derive(Event)]
@@ -8,10 +8,15 @@ pub fn plugin(app: &mut App) {
     app.add_plugins(EnhancedInputPlugin)
         .add_input_context::<PlayerCtx>()
         .add_input_context::<ModalCtx>()
+        .add_input_context::<SpectatorCtx>()
+        .init_resource::<InputStack>()
         .add_systems(Update, (log_gamepad_events, log_gamepad_status))
-        .add_observer(rm_player_ctx)
+        .add_observer(rm_ctx::<PlayerCtx>)
+        .add_observer(rm_ctx::<ModalCtx>)
+        .add_observer(rm_ctx::<SpectatorCtx>)
         .add_observer(add_modal_ctx)
         .add_observer(add_player_ctx)
+        .add_observer(add_spectator_ctx)
         .add_observer(log_navigate)
         .add_observer(log_jump)
         .add_observer(log_sprint)
@@ -101,7 +106,59 @@ fn log_venom_speak(_on: On<Start<VenomSpeak>>) {
     debug!("VenomSpeak");
 }
 
-markers!(GlobalCtx, PlayerCtx, ModalCtx);
+markers!(GlobalCtx, PlayerCtx, ModalCtx, SpectatorCtx);
+
+// ============================================================================
+// INPUT LAYERS
+// Only one gameplay-side context is live at a time. Layers are pushed/popped
+// as menus open and close; `sync_gameplay_lock` maps the top of the stack
+// onto the matching context component on the player entity. Spectating isn't
+// a pushed layer: it's the base layer while observing without a character.
+// ============================================================================
+
+/// Which input context owns the keyboard/gamepad right now.
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InputLayer {
+    /// Regular play — [`PlayerCtx`].
+    #[default]
+    Gameplay,
+    /// Modal stack is non-empty — [`ModalCtx`].
+    Menu,
+    /// Observing without a character — [`SpectatorCtx`].
+    Spectator,
+}
+
+/// Stack of active input layers. [`InputLayer::Gameplay`] is the implicit base
+/// and is never stored.
+#[derive(Resource, Reflect, Debug, Clone, Default)]
+#[reflect(Resource)]
+pub struct InputStack(Vec<InputLayer>);
+
+impl InputStack {
+    /// Push a layer on top. Pushing the layer that's already on top is a no-op,
+    /// so double-fired observers can't stack duplicates.
+    pub fn push(&mut self, layer: InputLayer) {
+        if layer == InputLayer::Gameplay || self.top() == layer {
+            return;
+        }
+        self.0.push(layer);
+    }
+
+    /// Remove the topmost occurrence of `layer`, wherever it sits in the stack.
+    pub fn pop(&mut self, layer: InputLayer) {
+        if let Some(i) = self.0.iter().rposition(|l| *l == layer) {
+            self.0.remove(i);
+        }
+    }
+
+    pub fn top(&self) -> InputLayer {
+        self.0.last().copied().unwrap_or_default()
+    }
+
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+}
 
 #[derive(InputAction)]
 #[action_output(Vec2)]
@@ -151,14 +208,46 @@ pub struct SpawnEnemy;
 #[action_output(bool)]
 pub struct ClearEnemies;
 
+#[derive(InputAction)]
+#[action_output(bool)]
+pub struct AbilitySlot1;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+pub struct AbilitySlot2;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+pub struct AbilitySlot3;
+
 #[derive(InputAction)]
 #[action_output(bool)]
 pub struct VenomSpeak;
 
+#[derive(InputAction)]
+#[action_output(bool)]
+pub struct Spectate;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+pub struct TogglePvp;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+pub struct CycleWeapon;
+
 #[derive(InputAction)]
 #[action_output(Vec2)]
 struct NavigateModal;
 
+#[derive(Debug, InputAction)]
+#[action_output(bool)]
+pub struct SpectateNext;
+
+#[derive(Debug, InputAction)]
+#[action_output(bool)]
+pub struct SpectatePrev;
+
 #[derive(Debug, InputAction)]
 #[action_output(bool)]
 pub struct Select;
@@ -235,17 +324,44 @@ pub fn add_player_ctx(add: On<Add, PlayerCtx>, mut commands: Commands) {
             Action::<ClearEnemies>::new(),
             bindings![KeyCode::KeyQ],
         ),
+        (
+            Action::<AbilitySlot1>::new(),
+            bindings![KeyCode::Digit1, GamepadButton::West],
+        ),
+        (
+            Action::<AbilitySlot2>::new(),
+            bindings![KeyCode::Digit2, GamepadButton::East],
+        ),
+        (
+            Action::<AbilitySlot3>::new(),
+            bindings![KeyCode::Digit3, GamepadButton::RightTrigger],
+        ),
         (
             Action::<VenomSpeak>::new(),
             bindings![KeyCode::KeyT],
         ),
+        (
+            Action::<TogglePvp>::new(),
+            ActionSettings {
+                require_reset: true,
+                ..Default::default()
+            },
+            bindings![KeyCode::KeyO],
+        ),
+        (
+            Action::<CycleWeapon>::new(),
+            ActionSettings {
+                require_reset: true,
+                ..Default::default()
+            },
+            bindings![KeyCode::KeyR, GamepadButton::DPadUp],
+        ),
     ]));
 }
 
-fn rm_player_ctx(rm: On<Remove, PlayerCtx>, mut commands: Commands) {
-    commands
-        .entity(rm.entity)
-        .despawn_related::<Actions<PlayerCtx>>();
+/// Context actions live as related entities — drop them together with the marker.
+fn rm_ctx<C: Component>(rm: On<Remove, C>, mut commands: Commands) {
+    commands.entity(rm.entity).despawn_related::<Actions<C>>();
 }
 
 fn add_modal_ctx(add: On<Add, ModalCtx>, mut commands: Commands) {
@@ -286,3 +402,49 @@ fn add_modal_ctx(add: On<Add, ModalCtx>, mut commands: Commands) {
         ]),
     ));
 }
+
+fn add_spectator_ctx(add: On<Add, SpectatorCtx>, mut commands: Commands) {
+    commands.entity(add.entity).insert(actions!(SpectatorCtx[
+        (
+            Action::<Pan>::new(),
+            ActionSettings {
+                require_reset: true,
+                ..Default::default()
+            },
+            Bindings::spawn((
+                Spawn((Binding::mouse_motion(),Scale::splat(0.1), Negate::all())),
+                Axial::right_stick().with((Scale::splat(2.0), Negate::x())) ,
+            )),
+        ),
+        (
+            Action::<Navigate>::new(),
+            DeadZone::default(),
+            Scale::splat(0.3),
+            Bindings::spawn(( Cardinal::wasd_keys(), Cardinal::arrows(), Axial::left_stick() )),
+        ),
+        (
+            Action::<SpectateNext>::new(),
+            bindings![MouseButton::Left, KeyCode::BracketRight, GamepadButton::RightTrigger],
+        ),
+        (
+            Action::<SpectatePrev>::new(),
+            bindings![MouseButton::Right, KeyCode::BracketLeft, GamepadButton::LeftTrigger],
+        ),
+        (
+            Action::<Spectate>::new(),
+            ActionSettings {
+                require_reset: true,
+                ..Default::default()
+            },
+            bindings![KeyCode::KeyV, GamepadButton::East],
+        ),
+        (
+            Action::<Escape>::new(),
+            ActionSettings {
+                require_reset: true,
+                ..Default::default()
+            },
+            bindings![KeyCode::Escape, GamepadButton::Start],
+        ),
+    ]));
+}
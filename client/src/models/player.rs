@@ -1,6 +1,7 @@
 use super::*;
 use crate::player::Animation;
 use std::collections::HashMap;
+use wasm_fantasia_shared::net::AnimationId;
 
 #[derive(Component, Reflect, Clone)]
 #[reflect(Component)]
@@ -28,6 +29,40 @@ impl Default for Player {
     }
 }
 
+/// The local player's level and lifetime XP. Online the server owns these
+/// (see `wasm_fantasia_shared::leveling`); offline play stays at level 1.
+#[derive(Component, Reflect, Clone, Copy, Debug, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct PlayerLevel {
+    pub level: u32,
+    pub xp: u32,
+}
+
+impl Default for PlayerLevel {
+    fn default() -> Self {
+        Self { level: 1, xp: 0 }
+    }
+}
+
+/// Items the local player has equipped, in the order they were acquired.
+/// Mirrors `equipped_item` rows; their bonuses are already in [`Stats`](crate::rules::Stats).
+#[derive(Component, Default, Clone, Debug)]
+pub struct Equipment(pub Vec<wasm_fantasia_shared::items::Item>);
+
+/// The weapon a player, local or remote, fights with. Mirrors `player.weapon`
+/// online; offline play stays on fists.
+#[derive(Component, Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EquippedWeapon(pub wasm_fantasia_shared::weapons::Weapon);
+
+/// Seconds until the dead local player may respawn, counted down from the
+/// server's `death` row. Present only while dead.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct RespawnCountdown(pub f32);
+
+/// Marks the local player as opted in to PvP, mirroring the server's `player.pvp`.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct PvpEnabled;
+
 #[derive(Component, Reflect, Default, Clone, Debug)]
 #[reflect(Component)]
 pub enum AnimationState {
@@ -49,46 +84,51 @@ pub enum AnimationState {
     KnockBack,
     Attack,
     GroundPound,
+    /// One-shot fidget played after standing idle for a while.
+    IdleVariation(Animation),
 }
 
 impl AnimationState {
-    /// Serialize to a server animation name for broadcast to other clients.
-    /// Speed parameters are dropped — remote players use default playback speeds.
-    pub fn server_name(&self) -> &'static str {
+    /// Wire id to broadcast to other clients. Speed parameters are
+    /// dropped — remote players use default playback speeds.
+    pub fn wire_id(&self) -> AnimationId {
         match self {
-            Self::StandIdle => "Idle",
-            Self::Run(_) | Self::Climb(_) => "Walk",
-            Self::Sprint(_) => "Run",
-            Self::Crouch(_) => "Crouch",
-            Self::CrouchIdle => "CrouchIdle",
-            Self::JumpStart => "JumpStart",
-            Self::JumpLoop | Self::WallJump => "Jump",
-            Self::JumpLand => "JumpLand",
-            Self::Fall | Self::WallSlide => "Fall",
-            Self::Roll => "Roll",
-            Self::LandingStun => "LandingStun",
-            Self::KnockBack => "KnockBack",
-            Self::Attack => "Idle", // Attacks handled by attack_sequence/attack_animation
-            Self::GroundPound => "Fall", // Diving pose for remote players
+            Self::StandIdle => AnimationId::Idle,
+            Self::Run(_) | Self::Climb(_) => AnimationId::Walk,
+            Self::Sprint(_) => AnimationId::Run,
+            Self::Crouch(_) => AnimationId::Crouch,
+            Self::CrouchIdle => AnimationId::CrouchIdle,
+            Self::JumpStart => AnimationId::JumpStart,
+            Self::JumpLoop | Self::WallJump => AnimationId::Jump,
+            Self::JumpLand => AnimationId::JumpLand,
+            Self::Fall | Self::WallSlide => AnimationId::Fall,
+            Self::Roll => AnimationId::Roll,
+            Self::LandingStun => AnimationId::LandingStun,
+            Self::KnockBack => AnimationId::KnockBack,
+            Self::Attack => AnimationId::Idle, // Attacks handled by attack_sequence/attack_animation
+            Self::GroundPound => AnimationId::Fall, // Diving pose for remote players
+            Self::IdleVariation(anim) => anim.wire_id(),
         }
     }
 
-    /// Deserialize from a server animation name. Speed-parameterized variants
-    /// get default display speeds since the exact value isn't transmitted.
-    pub fn from_server_name(name: &str) -> Self {
-        match name {
-            "Walk" => Self::Run(1.0),
-            "Run" => Self::Sprint(1.0),
-            "Crouch" => Self::Crouch(1.0),
-            "CrouchIdle" => Self::CrouchIdle,
-            "JumpStart" => Self::JumpStart,
-            "Jump" => Self::JumpLoop,
-            "JumpLand" => Self::JumpLand,
-            "Fall" => Self::Fall,
-            "Roll" => Self::Roll,
-            "LandingStun" => Self::LandingStun,
-            "KnockBack" => Self::KnockBack,
-            _ => Self::StandIdle,
+    /// From a wire id. Speed-parameterized variants get default display
+    /// speeds since the exact value isn't transmitted.
+    pub fn from_wire_id(id: AnimationId) -> Self {
+        match id {
+            AnimationId::Walk => Self::Run(1.0),
+            AnimationId::Run => Self::Sprint(1.0),
+            AnimationId::Crouch => Self::Crouch(1.0),
+            AnimationId::CrouchIdle => Self::CrouchIdle,
+            AnimationId::JumpStart => Self::JumpStart,
+            AnimationId::Jump => Self::JumpLoop,
+            AnimationId::JumpLand => Self::JumpLand,
+            AnimationId::Fall => Self::Fall,
+            AnimationId::Roll => Self::Roll,
+            AnimationId::LandingStun => Self::LandingStun,
+            AnimationId::KnockBack => Self::KnockBack,
+            other => Animation::from_wire_id(other)
+                .filter(|anim| Animation::IDLE_VARIATIONS.contains(anim))
+                .map_or(Self::StandIdle, Self::IdleVariation),
         }
     }
 
@@ -114,6 +154,7 @@ impl AnimationState {
             Self::KnockBack => (Animation::HitChest, 1.0, false),
             Self::Attack => (Animation::Idle, 1.0, true),
             Self::GroundPound => (Animation::NinjaJumpStart, 1.5, false),
+            Self::IdleVariation(anim) => (*anim, 1.0, false),
         }
     }
 }
@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
+use wasm_fantasia_shared::gameplay::gameplay;
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize, Reflect, Asset, Resource)]
 #[reflect(Resource)]
@@ -43,9 +44,15 @@ pub struct PlayerPreset {
     pub hitbox: HitboxPreset,
     pub zoom: (f32, f32),
     pub fov: f32,
+    /// From the shared `gameplay.ron`, not `config.ron`.
+    #[serde(skip, default = "shared_spawn_pos")]
     pub spawn_pos: (f32, f32, f32),
 }
 
+fn shared_spawn_pos() -> (f32, f32, f32) {
+    gameplay().spawn_pos
+}
+
 #[derive(Clone, Debug, Default, Serialize, Deserialize, Reflect)]
 pub struct HitboxPreset {
     pub radius: f32,
@@ -55,12 +62,28 @@ pub struct HitboxPreset {
 #[derive(Clone, Debug, Default, Serialize, Deserialize, Reflect)]
 pub struct MovementPreset {
     pub actions_in_air: u8,
+    /// Speeds come from the shared `gameplay.ron`, not `config.ron`.
+    #[serde(skip, default = "shared_speed")]
     pub speed: f32,
+    #[serde(skip, default = "shared_sprint_factor")]
     pub sprint_factor: f32,
+    #[serde(skip, default = "shared_crouch_factor")]
     pub crouch_factor: f32,
     pub idle_to_run_threshold: f32,
 }
 
+fn shared_speed() -> f32 {
+    gameplay().movement.speed
+}
+
+fn shared_sprint_factor() -> f32 {
+    gameplay().movement.sprint_factor
+}
+
+fn shared_crouch_factor() -> f32 {
+    gameplay().movement.crouch_factor
+}
+
 #[derive(Clone, Debug, Default, Serialize, Deserialize, Reflect)]
 pub struct SettingsPreset {
     pub min_volume: f32,
@@ -4,6 +4,8 @@ use serde::Deserialize;
 use std::{error::Error, fs};
 
 pub const SETTINGS_PATH: &str = "client/assets/settings.ron";
+/// Recent servers kept beyond this are forgotten.
+const MAX_RECENT_SERVERS: usize = 5;
 
 pub fn plugin(app: &mut App) {
     let settings = Settings::load();
@@ -20,13 +22,27 @@ fn auto_save_settings(settings: Res<Settings>) {
 
 #[derive(Resource, Reflect, Deserialize, Serialize, Debug, Clone)]
 #[reflect(Resource)]
+#[serde(default)]
 pub struct Settings {
     // audio
     pub sound: SoundPreset,
     // video
     pub fov: f32,
+    /// Color grading on the scene camera (F2 in game).
+    pub postfx: bool,
+    /// Multiplier on every UI size. Changing it asks for confirmation and
+    /// reverts on its own if that doesn't come.
+    pub ui_scale: f32,
+    /// Name tags over other players.
+    pub show_names: bool,
+    // controller
+    /// Gamepad rumble multiplier, 0 disables it.
+    pub rumble: f32,
     // keybindings
     pub input_map: InputSettings,
+    // servers
+    /// Servers connected to lately, most recent first.
+    pub recent_servers: Vec<RemoteServer>,
 }
 
 impl Settings {
@@ -41,6 +57,16 @@ impl Settings {
         Volume::Linear(self.sound.general * self.sound.sfx)
     }
 
+    /// Put `server` at the top of [`Self::recent_servers`] and save.
+    pub fn remember_server(&mut self, server: RemoteServer) {
+        self.recent_servers.retain(|s| *s != server);
+        self.recent_servers.insert(0, server);
+        self.recent_servers.truncate(MAX_RECENT_SERVERS);
+        if let Err(e) = self.save() {
+            error!("Failed to save recent servers: {e}");
+        }
+    }
+
     pub fn load() -> Self {
         match fs::read_to_string(SETTINGS_PATH) {
             Ok(content) => match ron::from_str(&content) {
@@ -72,7 +98,12 @@ impl Default for Settings {
         Self {
             sound: SoundPreset::default(),
             fov: 65.0, // wider for horde combat visibility
+            postfx: true,
+            ui_scale: 1.0,
+            show_names: true,
+            rumble: 1.0,
             input_map: InputSettings::default(),
+            recent_servers: Vec::new(),
         }
     }
 }
@@ -83,6 +114,7 @@ pub enum UiTab {
     #[default]
     Audio,
     Video,
+    Network,
 }
 
 #[derive(Resource, Default)]
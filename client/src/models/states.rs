@@ -1,9 +1,15 @@
 use super::*;
+use serde::Deserialize;
 
 pub fn plugin(app: &mut App) {
     app.init_resource::<Session>()
         .init_resource::<GameMode>()
-        .register_type::<Mood>();
+        .init_resource::<SelectedLoadout>()
+        .init_resource::<SelectedServer>()
+        .register_type::<Mood>()
+        .register_type::<Ambience>()
+        .register_type::<ZonePriority>()
+        .register_type::<SceneAudio>();
 }
 
 /// Entities that survive gameplay exit. Applied to all Startup entities
@@ -23,10 +29,25 @@ pub enum GameMode {
     Multiplayer,
 }
 
+/// The build picked on the title screen. Decides the local player's rules
+/// and is sent to the server on connect, which uses it for their attacks.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SelectedLoadout(pub wasm_fantasia_shared::presets::Loadout);
+
+/// The session is watched rather than played: it connects like multiplayer
+/// but has no character of its own. See `camera::observer` and `ui::broadcast`.
+/// Removed along with the [`ServerTarget`] when the session ends.
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct Observing;
+
 pub fn is_multiplayer_mode(mode: Res<GameMode>) -> bool {
     *mode == GameMode::Multiplayer
 }
 
+pub fn is_observing(observing: Option<Res<Observing>>) -> bool {
+    observing.is_some()
+}
+
 /// Describes where the SpacetimeDB instance lives.
 /// Inserted when the player picks a mode on the title screen;
 /// removed when returning to title.
@@ -34,8 +55,41 @@ pub fn is_multiplayer_mode(mode: Res<GameMode>) -> bool {
 pub enum ServerTarget {
     /// Native singleplayer — launch a local SpacetimeDB subprocess.
     Local { port: u16 },
-    /// Multiplayer (all platforms) or web solo — connect to a remote server.
-    Remote { uri: String },
+    /// Multiplayer (all platforms) or web solo — connect to a module on a
+    /// remote server.
+    Remote { uri: String, module: String },
+    /// Solo with no server at all — see `networking::offline`.
+    Offline,
+}
+
+/// A SpacetimeDB host and the module on it to play, e.g. a staging build
+/// published next to the live one.
+#[derive(Reflect, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RemoteServer {
+    pub uri: String,
+    pub module: String,
+}
+
+impl RemoteServer {
+    /// Short name for menus: host and module, without the scheme.
+    pub fn label(&self) -> String {
+        let host = self.uri.split_once("://").map_or(&*self.uri, |(_, h)| h);
+        format!("{host}/{}", self.module)
+    }
+}
+
+/// The server picked on the title screen for multiplayer sessions; `None`
+/// is the configured default (see `networking::SpacetimeDbConfig`).
+#[derive(Resource, Debug, Clone, Default)]
+pub struct SelectedServer(pub Option<RemoteServer>);
+
+/// Account details typed on the title screen, used on the next connect to
+/// sign in (or register the character). Kept in memory only.
+#[derive(Resource, Debug, Clone)]
+pub struct AccountLogin {
+    pub username: String,
+    pub password: String,
+    pub register: bool,
 }
 
 /// Runtime session flags — debug toggles, preferences, and transient state.
@@ -45,6 +99,7 @@ pub enum ServerTarget {
 pub struct Session {
     pub last_screen: Screen,
     pub current_mood: Mood,
+    pub current_ambience: Option<Ambience>,
 
     pub diagnostics: bool,
     pub debug_ui: bool,
@@ -58,6 +113,7 @@ impl Default for Session {
         Self {
             last_screen: Screen::Title,
             current_mood: Mood::Exploration,
+            current_ambience: None,
             diagnostics: false,
             debug_ui: false,    // Off by default
             screen_shake: true, // On by default
@@ -81,7 +137,7 @@ pub fn is_paused(session: Res<Session>) -> bool {
 /// The game's main screen states.
 /// See <https://bevy-cheatbook.github.io/programming/states.html>
 /// Or <https://github.com/bevyengine/bevy/blob/main/examples/ecs/state.rs>
-#[derive(States, Default, Clone, Eq, PartialEq, Debug, Hash, Reflect)]
+#[derive(States, Default, Clone, Eq, PartialEq, Debug, Hash, Reflect, serde::Deserialize)]
 pub enum Screen {
     #[cfg_attr(not(feature = "dev"), default)]
     Splash,
@@ -95,6 +151,8 @@ pub enum Screen {
     Connecting,
     // During this State the actual game logic is executed
     Gameplay,
+    // End-of-match results — keeps the connection alive for "Play Again"
+    Summary,
 }
 
 #[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
@@ -104,3 +162,26 @@ pub enum Mood {
     Exploration,
     Combat,
 }
+
+/// Looping background bed played under the music while inside a zone.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[reflect(Component)]
+pub enum Ambience {
+    Wind,
+    CaveDrips,
+}
+
+/// Put on a [`Mood`] or [`Ambience`] zone. Where zones overlap, the one with
+/// the highest priority wins.
+#[derive(Component, Reflect, Debug, Clone, Copy, Default)]
+#[reflect(Component)]
+pub struct ZonePriority(pub i32);
+
+/// What plays when the player isn't inside any zone. One per scene; without
+/// it the fallback is [`Mood::default`] and no ambience.
+#[derive(Component, Reflect, Debug, Clone, Copy, Default)]
+#[reflect(Component)]
+pub struct SceneAudio {
+    pub mood: Mood,
+    pub ambience: Option<Ambience>,
+}
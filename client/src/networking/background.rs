@@ -0,0 +1,131 @@
+//! Keeping a session healthy while the app is in the background.
+//!
+//! Browsers stop `requestAnimationFrame` in hidden tabs, so Bevy stops
+//! updating and nothing calls `frame_tick`: server messages pile up and the
+//! connection looks dead when the tab comes back. On wasm a JS interval keeps
+//! ticking the connection while the tab is hidden (browsers throttle it to
+//! about once a second, which is plenty to drain the queue).
+//!
+//! When frames resume after a long gap, the frame spanning it is skipped in
+//! virtual time: local physics doesn't lurch forward by a whole `max_delta`,
+//! and remote entities glide from where they were to where the server has
+//! them instead of snapping there in one frame.
+
+use bevy::prelude::*;
+use web_time::Instant;
+
+#[cfg(target_arch = "wasm32")]
+use std::cell::RefCell;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::{JsCast, closure::Closure};
+
+use super::SpacetimeDbConnection;
+
+/// A real-time gap between frames longer than this means the app was in the
+/// background (or stalled), and the frame spanning it is skipped.
+const BACKGROUND_GAP_SECS: f32 = 1.0;
+/// How often the hidden-tab interval asks to run. Browsers clamp it to about
+/// a second anyway.
+#[cfg(target_arch = "wasm32")]
+const BACKGROUND_TICK_MS: i32 = 250;
+
+/// Tracks frame gaps so the one spanning a background period can be skipped.
+#[derive(Resource, Default)]
+pub(super) struct BackgroundGap {
+    last_frame: Option<Instant>,
+    /// Virtual time was paused for this frame and needs resuming.
+    paused: bool,
+}
+
+/// Runs before the clocks update: after a long gap, pause virtual time so
+/// this frame advances it by zero instead of by the gap.
+pub(super) fn skip_background_gap(mut gap: ResMut<BackgroundGap>, mut time: ResMut<Time<Virtual>>) {
+    let now = Instant::now();
+    let away = gap
+        .last_frame
+        .replace(now)
+        .map(|last| now.duration_since(last).as_secs_f32())
+        .filter(|secs| *secs > BACKGROUND_GAP_SECS);
+    let Some(secs) = away else {
+        return;
+    };
+    if !time.is_paused() {
+        info!("Resumed after {secs:.1}s in the background — skipping the gap");
+        time.pause();
+        gap.paused = true;
+    }
+}
+
+/// Runs after the clocks update: undo [`skip_background_gap`]'s pause, so
+/// the next frame runs normally.
+pub(super) fn resume_after_gap(mut gap: ResMut<BackgroundGap>, mut time: ResMut<Time<Virtual>>) {
+    if std::mem::take(&mut gap.paused) {
+        time.unpause();
+    }
+}
+
+/// The interval ticking the connection while the tab is hidden. Dropping it
+/// stops the interval.
+#[cfg(target_arch = "wasm32")]
+struct BackgroundTicker {
+    interval_id: i32,
+    _tick: Closure<dyn FnMut()>,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Drop for BackgroundTicker {
+    fn drop(&mut self) {
+        if let Some(window) = web_sys::window() {
+            window.clear_interval_with_handle(self.interval_id);
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+thread_local! {
+    static TICKER: RefCell<Option<BackgroundTicker>> = const { RefCell::new(None) };
+}
+
+/// Start ticking a new connection while the tab is hidden. While it's
+/// visible, `handle_connection_events` does that every frame. Does nothing
+/// on native, where the frame loop keeps running when the window is hidden.
+pub(super) fn start_background_ticker(conn: Res<SpacetimeDbConnection>) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+        let conn = conn.conn.clone();
+        let tick = Closure::<dyn FnMut()>::new(move || {
+            let hidden = web_sys::window()
+                .and_then(|w| w.document())
+                .is_some_and(|d| d.hidden());
+            if !hidden || !conn.is_active() {
+                return;
+            }
+            if let Err(e) = conn.frame_tick() {
+                warn!("Background frame_tick error: {e:?}");
+            }
+        });
+        match window.set_interval_with_callback_and_timeout_and_arguments_0(
+            tick.as_ref().unchecked_ref(),
+            BACKGROUND_TICK_MS,
+        ) {
+            Ok(interval_id) => TICKER.with(|ticker| {
+                *ticker.borrow_mut() = Some(BackgroundTicker {
+                    interval_id,
+                    _tick: tick,
+                });
+            }),
+            Err(e) => warn!("Couldn't start background connection ticks: {e:?}"),
+        }
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    let _ = conn;
+}
+
+/// Stop the hidden-tab ticks once the connection is gone.
+pub(super) fn stop_background_ticker() {
+    #[cfg(target_arch = "wasm32")]
+    TICKER.with(|ticker| ticker.borrow_mut().take());
+}
@@ -0,0 +1,65 @@
+//! World chat: sends lines through the `send_chat` reducer and surfaces new
+//! `chat_message` rows as [`ChatReceived`] events.
+
+use bevy::prelude::*;
+use spacetimedb_sdk::{DbContext, Table};
+
+use super::SpacetimeDbConnection;
+use super::generated::chat_message_table::ChatMessageTableAccess;
+use super::generated::player_table::PlayerTableAccess;
+use super::generated::send_chat_reducer::send_chat;
+
+/// A chat message arrived in the current world, the local player's own
+/// included. Fired once per message, oldest first.
+#[derive(Event, Debug, Clone)]
+pub struct ChatReceived {
+    pub sender_name: String,
+    pub text: String,
+    pub is_local: bool,
+    /// Server time the message was sent, in microseconds since the epoch.
+    pub timestamp: i64,
+}
+
+/// Post a chat message to the current world. The server trims, truncates
+/// and rate-limits it.
+pub fn send_chat_message(conn: &SpacetimeDbConnection, text: &str) {
+    if let Err(e) = conn.reducers().send_chat(text.to_string()) {
+        warn!("Failed to send chat message: {:?}", e);
+    }
+}
+
+pub(super) fn process_chat_messages(
+    conn: Res<SpacetimeDbConnection>,
+    mut last_processed_id: Local<u64>,
+    mut commands: Commands,
+) {
+    let our_id = conn.conn.try_identity();
+
+    let mut new_messages: Vec<_> = conn
+        .conn
+        .db
+        .chat_message()
+        .iter()
+        .filter(|m| m.id > *last_processed_id)
+        .collect();
+    new_messages.sort_by_key(|m| m.id);
+
+    for message in new_messages {
+        *last_processed_id = message.id;
+        let sender_name = conn
+            .conn
+            .db
+            .player()
+            .identity()
+            .find(&message.sender)
+            .and_then(|p| p.name)
+            .unwrap_or_else(|| "Player".to_string());
+
+        commands.trigger(ChatReceived {
+            sender_name,
+            text: message.text,
+            is_local: our_id == Some(message.sender),
+            timestamp: message.timestamp,
+        });
+    }
+}
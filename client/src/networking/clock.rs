@@ -0,0 +1,82 @@
+//! Estimate of the server's clock. Every `ping` the server answers carries
+//! its time; half a round trip before we saw it, that is what the server
+//! clock read. An alpha-beta filter over those samples tracks both the
+//! offset from our clock and how fast it drifts.
+
+use bevy::prelude::*;
+use web_time::{Instant, SystemTime, UNIX_EPOCH};
+
+/// How much of each sample's error corrects the offset…
+const OFFSET_GAIN: f64 = 0.1;
+/// …and the drift.
+const DRIFT_GAIN: f64 = 0.01;
+
+/// Server time as the client best knows it. Server timestamps (µs since the
+/// Unix epoch, as in `combat_event.timestamp` or `death.respawn_at`) can be
+/// aged or counted down against it once [`Self::is_synced`].
+#[derive(Resource, Default, Debug)]
+pub struct ServerClock {
+    /// Server minus local time in µs, as of `synced_at`.
+    offset_micros: f64,
+    /// How fast the offset moves, in µs per second.
+    drift: f64,
+    synced_at: Option<Instant>,
+    pub samples: u32,
+}
+
+impl ServerClock {
+    pub fn is_synced(&self) -> bool {
+        self.synced_at.is_some()
+    }
+
+    /// Estimated server time now, in µs since the Unix epoch.
+    pub fn now_micros(&self) -> i64 {
+        let since_sync = self.synced_at.map_or(0.0, |t| t.elapsed().as_secs_f64());
+        (local_micros() + self.offset_micros + self.drift * since_sync) as i64
+    }
+
+    /// Seconds since the server time `micros`.
+    pub fn secs_since(&self, micros: i64) -> f32 {
+        (self.now_micros() - micros) as f32 / 1_000_000.0
+    }
+
+    /// Seconds until the server time `micros`, zero once it has passed.
+    pub fn secs_until(&self, micros: i64) -> f32 {
+        (-self.secs_since(micros)).max(0.0)
+    }
+
+    /// Fold in a `ping` answered at `server_micros`, `rtt_secs` after it
+    /// was sent.
+    pub(super) fn sample(&mut self, server_micros: i64, rtt_secs: f32) {
+        let local = local_micros() - rtt_secs as f64 * 500_000.0;
+        let offset = server_micros as f64 - local;
+        match self.synced_at {
+            None => {
+                self.offset_micros = offset;
+                self.drift = 0.0;
+            }
+            Some(synced_at) => {
+                let dt = synced_at.elapsed().as_secs_f64();
+                let predicted = self.offset_micros + self.drift * dt;
+                let error = offset - predicted;
+                self.offset_micros = predicted + OFFSET_GAIN * error;
+                if dt > 0.0 {
+                    self.drift += DRIFT_GAIN * error / dt;
+                }
+            }
+        }
+        self.synced_at = Some(Instant::now());
+        self.samples += 1;
+    }
+}
+
+fn local_micros() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0.0, |d| d.as_micros() as f64)
+}
+
+/// A new server means a new clock.
+pub(super) fn clear_server_clock(mut clock: ResMut<ServerClock>) {
+    *clock = ServerClock::default();
+}
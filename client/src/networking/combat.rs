@@ -1,50 +1,225 @@
-//! Outbound combat networking: attack relay, respawn, enemy spawn requests.
+//! Outbound combat networking: attack and ability relay, PvP opt-in, weapon
+//! swaps, respawn, enemy spawn requests.
 
 use super::SpacetimeDbConnection;
+use super::clock::ServerClock;
 use super::generated::attack_hit_reducer::attack_hit;
+use super::generated::cast_ability_reducer::cast_ability;
+use super::generated::death_table::DeathTableAccess;
+use super::generated::equip_item_reducer::equip_item;
+use super::generated::equipped_item_table::EquippedItemTableAccess;
 use super::generated::ground_pound_hit_reducer::ground_pound_hit;
+use super::generated::inventory_item_table::InventoryItemTableAccess;
 use super::generated::landing_aoe_hit_reducer::landing_aoe_hit;
+use super::generated::player_table::PlayerTableAccess;
 use super::generated::respawn_reducer::respawn;
+use super::generated::set_pvp_reducer::set_pvp;
 use super::generated::clear_enemies_reducer::clear_enemies;
 use super::generated::spawn_enemies_reducer::spawn_enemies;
-use crate::combat::{AttackIntent, Health, PlayerCombatant};
-use crate::models::Player as LocalPlayer;
+use super::generated::unequip_item_reducer::unequip_item;
+use super::generated::world_table::WorldTableAccess;
+use super::prediction::{InputSequence, PositionHistory};
+use crate::combat::{AttackIntent, AttackSequence, Health, PlayerCombatant};
+use crate::models::{
+    Config, CycleWeapon, Player as LocalPlayer, PvpEnabled, RespawnCountdown, TogglePvp,
+};
 use crate::player::control::{GroundPoundImpact, LandingImpact};
+use avian3d::prelude::LinearVelocity;
 use bevy::prelude::*;
+use bevy_enhanced_input::prelude::{InputAction, Start};
+use spacetimedb_sdk::{DbContext, Table};
+use std::collections::VecDeque;
+use wasm_fantasia_shared::abilities;
+use wasm_fantasia_shared::items::{Item, ItemSlot};
+use wasm_fantasia_shared::rng::seed_from_identity;
+
+/// Resend an unacknowledged attack intent this often.
+const ATTACK_RETRY_SECS: f32 = 0.15;
+/// Give up on an intent after this long: a swing resolved later than this
+/// would land well after its animation.
+const ATTACK_EXPIRY_SECS: f32 = 0.6;
+
+/// Attack intents sent with `attack_hit` that the server hasn't acknowledged
+/// yet. A reducer call lost on the way is resent until the player row's
+/// `attack_intent_seq` catches up or the intent expires; the server skips
+/// any copy it has already handled. Numbered by [`AttackSequence`], from 1
+/// on every connection, as the server's echo is.
+#[derive(Resource, Debug, Default)]
+pub struct AttackIntents {
+    /// Sequence number, age and time since last sent.
+    pending: VecDeque<(u32, f32, f32)>,
+}
+
+impl AttackIntents {
+    /// An intent is still waiting on the server.
+    pub fn in_flight(&self) -> bool {
+        !self.pending.is_empty()
+    }
+}
 
 /// Observer: when local player's attack connects, notify the server.
 pub fn send_attack_to_server(
     on: On<AttackIntent>,
     players: Query<(), With<PlayerCombatant>>,
     conn: Option<Res<SpacetimeDbConnection>>,
+    mut intents: ResMut<AttackIntents>,
 ) {
     let Some(conn) = conn else { return };
-    if players.get(on.event().attacker).is_ok() {
-        if let Err(e) = conn.conn.reducers.attack_hit() {
-            warn!("Failed to send attack_hit: {:?}", e);
-        }
+    if players.get(on.event().attacker).is_err() {
+        return;
+    }
+    let seq = on.event().seq;
+    intents.pending.push_back((seq, 0.0, 0.0));
+    if let Err(e) = conn.reducers().attack_hit(seq) {
+        warn!("Failed to send attack_hit: {:?}", e);
+    }
+}
+
+/// A new connection is a new session: the server numbers from the start too.
+pub fn reset_attack_intents(
+    mut intents: ResMut<AttackIntents>,
+    mut sequence: ResMut<AttackSequence>,
+) {
+    *intents = AttackIntents::default();
+    *sequence = AttackSequence::default();
+}
+
+/// Seed predicted swings from our identity once it's known, as the server
+/// seeds them.
+pub fn seed_attack_sequence(
+    conn: Res<SpacetimeDbConnection>,
+    mut sequence: ResMut<AttackSequence>,
+) {
+    if sequence.seed != 0 {
+        return;
+    }
+    if let Some(identity) = conn.conn.try_identity() {
+        sequence.seed = seed_from_identity(&identity.to_byte_array());
     }
 }
 
-/// Auto-respawn when local player dies (calls server respawn reducer).
+/// Drop intents the server has acknowledged or that are too old to matter,
+/// and resend the rest once they've waited [`ATTACK_RETRY_SECS`].
+pub fn resend_attack_intents(
+    time: Res<Time>,
+    conn: Res<SpacetimeDbConnection>,
+    mut intents: ResMut<AttackIntents>,
+) {
+    if intents.pending.is_empty() {
+        return;
+    }
+    let acked = conn
+        .conn
+        .try_identity()
+        .and_then(|id| conn.conn.db.player().identity().find(&id))
+        .map_or(0, |row| row.attack_intent_seq);
+    let dt = time.delta_secs();
+    intents.pending.retain_mut(|(seq, age, since_sent)| {
+        *age += dt;
+        *since_sent += dt;
+        if *seq <= acked {
+            return false;
+        }
+        if *age > ATTACK_EXPIRY_SECS {
+            debug!("attack_hit {seq} was never acknowledged; giving up");
+            return false;
+        }
+        if *since_sent >= ATTACK_RETRY_SECS {
+            *since_sent = 0.0;
+            if let Err(e) = conn.reducers().attack_hit(*seq) {
+                warn!("Failed to resend attack_hit: {:?}", e);
+            }
+        }
+        true
+    });
+}
+
+/// How often a dead player whose countdown has run out asks to respawn, in
+/// case a request landed a little before the server's timer did.
+const RESPAWN_RETRY_SECS: f32 = 0.5;
+
+/// Count down the local player's `death` row and ask the server to respawn
+/// once it runs out. The server refuses earlier requests.
 pub fn request_respawn_on_death(
+    time: Res<Time>,
     conn: Res<SpacetimeDbConnection>,
-    query: Query<&Health, With<LocalPlayer>>,
+    mut seen: Local<Option<(i64, f32)>>,
+    mut last_request: Local<f32>,
+    clock: Res<ServerClock>,
+    query: Query<(Entity, &Health), With<LocalPlayer>>,
+    mut commands: Commands,
 ) {
-    let Ok(health) = query.single() else {
+    let Ok((entity, health)) = query.single() else {
         return;
     };
+    let death = conn
+        .conn
+        .try_identity()
+        .and_then(|id| conn.conn.db.death().identity().find(&id));
+
+    if !health.is_dead() {
+        *seen = None;
+        commands.entity(entity).remove::<RespawnCountdown>();
+        return;
+    }
 
-    if health.is_dead() {
-        if let Err(e) = conn.conn.reducers.respawn() {
+    // Count down on the server clock once we know it, otherwise from when
+    // this client first saw the row
+    let now = time.elapsed_secs();
+    let remaining = match death {
+        Some(death) if clock.is_synced() => clock.secs_until(death.respawn_at),
+        Some(death) => {
+            if seen.is_none_or(|(died_at, _)| died_at != death.died_at) {
+                *seen = Some((death.died_at, now));
+            }
+            let seen_at = seen.map_or(now, |(_, at)| at);
+            let delay = (death.respawn_at - death.died_at) as f32 / 1_000_000.0;
+            (delay - (now - seen_at)).max(0.0)
+        }
+        None => 0.0,
+    };
+    commands.entity(entity).insert(RespawnCountdown(remaining));
+
+    if remaining <= 0.0 && now - *last_request >= RESPAWN_RETRY_SECS {
+        *last_request = now;
+        if let Err(e) = conn.reducers().respawn() {
             warn!("Failed to send respawn: {:?}", e);
         }
     }
 }
 
+/// Move the local player back to spawn once the server revives them, so a
+/// void-out doesn't resume the fall it died from. Uses the world's spawn
+/// point when the server has one, the configured one otherwise.
+pub fn snap_to_spawn_on_respawn(
+    cfg: Res<Config>,
+    conn: Res<SpacetimeDbConnection>,
+    mut was_dead: Local<bool>,
+    mut query: Query<(&Health, &mut Transform, &mut LinearVelocity), With<LocalPlayer>>,
+    inputs: Res<InputSequence>,
+    mut history: ResMut<PositionHistory>,
+) {
+    let Ok((health, mut transform, mut velocity)) = query.single_mut() else {
+        return;
+    };
+
+    if *was_dead && !health.is_dead() {
+        let world_spawn = conn
+            .conn
+            .try_identity()
+            .and_then(|id| conn.conn.db.player().identity().find(&id))
+            .and_then(|me| conn.conn.db.world().id().find(&me.world_id))
+            .map(|w| Vec3::new(w.spawn_x, w.spawn_y, w.spawn_z));
+        transform.translation = world_spawn.unwrap_or(Vec3::from(cfg.player.spawn_pos));
+        velocity.0 = Vec3::ZERO;
+        history.restart(&inputs);
+    }
+    *was_dead = health.is_dead();
+}
+
 /// Send clear_enemies request to server.
 pub fn server_clear_enemies(conn: &SpacetimeDbConnection) {
-    if let Err(e) = conn.conn.reducers.clear_enemies() {
+    if let Err(e) = conn.reducers().clear_enemies() {
         warn!("Failed to send clear_enemies: {:?}", e);
     }
 }
@@ -56,11 +231,10 @@ pub fn send_ground_pound_to_server(
 ) {
     let Some(conn) = conn else { return };
     let event = on.event();
-    if let Err(e) = conn.conn.reducers.ground_pound_hit(
-        event.position.x,
-        event.position.y,
-        event.position.z,
-    ) {
+    if let Err(e) =
+        conn.reducers()
+            .ground_pound_hit(event.position.x, event.position.y, event.position.z)
+    {
         warn!("Failed to send ground_pound_hit: {:?}", e);
     }
 }
@@ -75,7 +249,7 @@ pub fn send_landing_aoe_to_server(
     if event.velocity_y < wasm_fantasia_shared::combat::landing_aoe::MIN_VELOCITY {
         return;
     }
-    if let Err(e) = conn.conn.reducers.landing_aoe_hit(
+    if let Err(e) = conn.reducers().landing_aoe_hit(
         event.velocity_y,
         event.position.x,
         event.position.y,
@@ -85,11 +259,79 @@ pub fn send_landing_aoe_to_server(
     }
 }
 
+/// Observer: cast the ability in hotbar slot `SLOT` when its key goes down.
+/// The server checks cooldown and stack cost; hits come back as combat events.
+pub fn cast_hotbar_ability<A: InputAction, const SLOT: usize>(
+    _on: On<Start<A>>,
+    conn: Option<Res<SpacetimeDbConnection>>,
+) {
+    let Some(conn) = conn else { return };
+    let name = abilities::HOTBAR[SLOT];
+    if let Err(e) = conn.reducers().cast_ability(name.to_string()) {
+        warn!("Failed to send cast_ability({name}): {:?}", e);
+    }
+}
+
+/// Observer: flip the local player's PvP opt-in. The reconciler picks up the
+/// change from the player row.
+pub fn toggle_pvp(
+    _on: On<Start<TogglePvp>>,
+    player: Query<Has<PvpEnabled>, With<LocalPlayer>>,
+    conn: Option<Res<SpacetimeDbConnection>>,
+) {
+    let Some(conn) = conn else { return };
+    let Ok(enabled) = player.single() else {
+        return;
+    };
+    if let Err(e) = conn.reducers().set_pvp(!enabled) {
+        warn!("Failed to send set_pvp: {:?}", e);
+    }
+}
+
+/// Observer: equip the next weapon in the local player's inventory, going
+/// back to fists after the last one. The reconciler picks up the new weapon
+/// from the player row.
+pub fn cycle_weapon(_on: On<Start<CycleWeapon>>, conn: Option<Res<SpacetimeDbConnection>>) {
+    let Some(conn) = conn else { return };
+    let Some(me) = conn.conn.try_identity() else {
+        return;
+    };
+    let mut weapons: Vec<u64> = conn
+        .conn
+        .db
+        .inventory_item()
+        .iter()
+        .filter(|i| i.owner == me)
+        .filter(|i| Item::parse_str(&i.item_id).is_some_and(|item| item.slot() == ItemSlot::Weapon))
+        .map(|i| i.id)
+        .collect();
+    weapons.sort_unstable();
+    let wielded = conn
+        .conn
+        .db
+        .equipped_item()
+        .iter()
+        .find(|e| e.owner == me && e.slot == ItemSlot::Weapon.as_str())
+        .map(|e| e.inventory_id);
+
+    let next = match wielded {
+        Some(current) => weapons.iter().copied().find(|&id| id > current),
+        None => weapons.first().copied(),
+    };
+    let result = match (next, wielded) {
+        (Some(id), _) => conn.reducers().equip_item(id),
+        (None, Some(current)) => conn.reducers().unequip_item(current),
+        (None, None) => return,
+    };
+    if let Err(e) = result {
+        warn!("Failed to swap weapon: {:?}", e);
+    }
+}
+
 /// Send spawn_enemies request to server.
 pub fn server_spawn_enemies(conn: &SpacetimeDbConnection, pos: Vec3, forward: Vec3) {
     if let Err(e) = conn
-        .conn
-        .reducers
+        .reducers()
         .spawn_enemies(pos.x, pos.y, pos.z, forward.x, forward.z)
     {
         warn!("Failed to send spawn_enemies: {:?}", e);
@@ -1,19 +1,40 @@
 //! Connection lifecycle: connect, reconnect, handshake, disconnect, cleanup.
 
 use bevy::prelude::*;
-use spacetimedb_sdk::DbContext;
+use spacetimedb_sdk::{DbContext, SubscriptionHandle as _};
+use std::collections::HashSet;
+use wasm_fantasia_shared::gameplay;
+use wasm_fantasia_shared::presets::Loadout;
 use web_time::Instant;
 
+use super::generated::SubscriptionHandle;
 use super::generated::join_game_reducer::join_game;
 use super::generated::leave_game_reducer::leave_game;
-use super::{DbConnection, SpacetimeDbConfig, SpacetimeDbConnection, SpacetimeDbToken};
-use crate::models::{GameMode, Screen, ServerTarget};
+use super::generated::observe_reducer::observe;
+use super::generated::observer_table::ObserverTableAccess;
+use super::generated::player_table::PlayerTableAccess;
+use super::generated::register_account_reducer::register_account;
+use super::generated::server_info_table::ServerInfoTableAccess;
+use super::generated::set_loadout_reducer::set_loadout;
+use super::generated::sign_in_reducer::sign_in;
+use super::generated::world_table::WorldTableAccess;
+use super::reconcile::{ServerId, ServerSnapshot};
+use super::{
+    ConnectionQuality, DbConnection, SpacetimeDbConfig, SpacetimeDbConnection, SpacetimeDbToken,
+};
+use crate::models::{
+    AccountLogin, GameMode, Observing, RemoteServer, Screen, SelectedLoadout, ServerTarget,
+    Settings,
+};
 
 #[cfg(not(target_arch = "wasm32"))]
 use super::local_server;
 
 const HANDSHAKE_TIMEOUT_SECS: f32 = 5.0;
 const RECONNECT_INTERVAL_SECS: f32 = 2.0;
+/// How long a reconnected session waits for its world to come back before
+/// letting the reconciler loose on whatever the cache holds.
+const RECONNECT_SYNC_TIMEOUT_SECS: f32 = 10.0;
 
 // =============================================================================
 // Resources
@@ -36,15 +57,115 @@ impl Default for ReconnectTimer {
 #[derive(Resource)]
 pub(super) struct HandshakeStart(Instant);
 
+/// The connection dropped mid-game and is being replaced. Entities stay as
+/// they were: the reconcilers hold off (see [`is_synced`](super::is_synced))
+/// until [`finish_reconnect`] sees the world back in the new connection's
+/// cache, so its empty start doesn't despawn everything.
+#[derive(Resource, Debug)]
+pub struct Reconnecting {
+    /// The world we were in, rejoined instead of the mode's default so a
+    /// lobby match carries on.
+    world_id: Option<String>,
+}
+
 // =============================================================================
 // Systems
 // =============================================================================
 
+/// Tables of one world, filtered on their indexed `world_id` so other
+/// worlds' rows (every solo world in particular) never reach this client.
+/// Subscribed by [`follow_world`] for whichever world the client is in.
+fn world_queries(world_id: &str) -> Vec<String> {
+    let mut queries: Vec<String> = [
+        "player",
+        "enemy",
+        "combat_event",
+        "match_state",
+        "match_stats",
+        "leaderboard",
+        "chat_message",
+        "loot_drop",
+        "telegraph",
+    ]
+    .iter()
+    .map(|table| format!("SELECT * FROM {table} WHERE world_id = '{world_id}'"))
+    .collect();
+    queries.extend([
+        format!("SELECT * FROM world WHERE id = '{world_id}'"),
+        // Effects have no world of their own: take those on the world's players
+        format!(
+            "SELECT active_effect.* FROM active_effect \
+             JOIN player ON active_effect.owner = player.identity \
+             WHERE player.world_id = '{world_id}'"
+        ),
+    ]);
+    queries
+}
+
+/// World tables that only feed presentation (XP floaters, event
+/// announcements, aura rings), dropped while the connection is degraded.
+fn cosmetic_queries(world_id: &str) -> Vec<String> {
+    let mut queries: Vec<String> = ["xp_event", "world_event"]
+        .iter()
+        .map(|table| format!("SELECT * FROM {table} WHERE world_id = '{world_id}'"))
+        .collect();
+    // Loadouts of the world's players, for the aura rings they show
+    queries.push(format!(
+        "SELECT player_loadout.* FROM player_loadout \
+         JOIN player ON player_loadout.identity = player.identity \
+         WHERE player.world_id = '{world_id}'"
+    ));
+    queries
+}
+
+/// Everything this client needs wherever it is: its own rows, filtered to
+/// the sender, and the few tables that aren't tied to a world. Our own
+/// `player` and `observer` rows are how [`follow_world`] knows which world
+/// that is.
+fn session_queries() -> Vec<String> {
+    [
+        "SELECT * FROM player WHERE identity = :sender",
+        "SELECT * FROM observer WHERE identity = :sender",
+        "SELECT * FROM inventory_item WHERE owner = :sender",
+        "SELECT * FROM equipped_item WHERE owner = :sender",
+        "SELECT * FROM death WHERE identity = :sender",
+        "SELECT * FROM ping_result WHERE identity = :sender",
+        "SELECT * FROM trade_offer WHERE offerer = :sender",
+        "SELECT * FROM trade_offer WHERE recipient = :sender",
+        "SELECT trade_offer_item.* FROM trade_offer_item \
+         JOIN trade_offer ON trade_offer_item.offer_id = trade_offer.id \
+         WHERE trade_offer.offerer = :sender",
+        "SELECT trade_offer_item.* FROM trade_offer_item \
+         JOIN trade_offer ON trade_offer_item.offer_id = trade_offer.id \
+         WHERE trade_offer.recipient = :sender",
+        "SELECT * FROM server_info",
+        // Lobbies are open to everyone, wherever they are
+        "SELECT * FROM lobby",
+        "SELECT * FROM lobby_member",
+    ]
+    .iter()
+    .map(|query| query.to_string())
+    .collect()
+}
+
 macro_rules! connection_builder {
-    ($uri:expr, $module_name:expr, $token:expr, $is_solo:expr) => {{
+    (
+        $uri:expr,
+        $module_name:expr,
+        $token:expr,
+        $is_solo:expr,
+        $loadout:expr,
+        $observing:expr,
+        $rejoin:expr,
+        $account:expr
+    ) => {{
         let token_store = $token.clone();
         let stored = $token.lock().unwrap().clone();
         let is_solo = $is_solo;
+        let loadout: Loadout = $loadout;
+        let observing: bool = $observing;
+        let rejoin: Option<String> = $rejoin;
+        let account: Option<AccountLogin> = $account;
         DbConnection::builder()
             .with_uri($uri)
             .with_module_name($module_name)
@@ -53,24 +174,47 @@ macro_rules! connection_builder {
                 info!("Connected to SpacetimeDB with identity: {:?}", identity);
                 *token_store.lock().unwrap() = Some(token.to_string());
 
-                let world_id = if is_solo {
-                    identity.to_hex().to_string()
+                let world_id = rejoin.unwrap_or_else(|| {
+                    if is_solo {
+                        identity.to_hex().to_string()
+                    } else {
+                        "shared".to_string()
+                    }
+                });
+
+                if observing {
+                    if let Err(e) = conn.reducers.observe(world_id) {
+                        error!("Failed to call observe: {:?}", e);
+                    }
                 } else {
-                    "shared".to_string()
-                };
-
-                if let Err(e) = conn
-                    .reducers
-                    .join_game(Some("Player".to_string()), world_id.clone())
-                {
-                    error!("Failed to call join_game: {:?}", e);
+                    // Signing in brings the account's character to this
+                    // identity before join_game looks for one
+                    if let Some(account) = account.as_ref().filter(|a| !a.register) {
+                        let username = account.username.clone();
+                        if let Err(e) = conn.reducers.sign_in(username, account.password.clone()) {
+                            error!("Failed to call sign_in: {:?}", e);
+                        }
+                    }
+                    if let Err(e) = conn.reducers.set_loadout(loadout.as_str().to_string()) {
+                        error!("Failed to call set_loadout: {:?}", e);
+                    }
+                    if let Err(e) = conn
+                        .reducers
+                        .join_game(Some("Player".to_string()), world_id)
+                    {
+                        error!("Failed to call join_game: {:?}", e);
+                    }
+                    // …while registering needs the character join_game made
+                    if let Some(account) = account.filter(|a| a.register) {
+                        if let Err(e) = conn
+                            .reducers
+                            .register_account(account.username, account.password)
+                        {
+                            error!("Failed to call register_account: {:?}", e);
+                        }
+                    }
                 }
-                conn.subscription_builder().subscribe([
-                    format!("SELECT * FROM player WHERE world_id = '{world_id}'"),
-                    format!("SELECT * FROM enemy WHERE world_id = '{world_id}'"),
-                    format!("SELECT * FROM combat_event WHERE world_id = '{world_id}'"),
-                    "SELECT * FROM active_effect".to_string(),
-                ]);
+                conn.subscription_builder().subscribe(session_queries());
             })
             .on_connect_error(|_ctx, err| {
                 error!("Failed to connect to SpacetimeDB: {:?}", err);
@@ -87,12 +231,27 @@ pub fn try_connect(
     module_name: &str,
     token: &SpacetimeDbToken,
     is_solo: bool,
+    loadout: Loadout,
+    observing: bool,
+    rejoin: Option<String>,
+    account: Option<AccountLogin>,
 ) -> Option<SpacetimeDbConnection> {
     info!("Attempting SpacetimeDB connection to {uri}...");
-    match connection_builder!(uri, module_name, token.0, is_solo).build() {
+    match connection_builder!(
+        uri,
+        module_name,
+        token.0,
+        is_solo,
+        loadout,
+        observing,
+        rejoin,
+        account
+    )
+    .build()
+    {
         Ok(conn) => {
             info!("Connection initiated — waiting for handshake");
-            Some(SpacetimeDbConnection { conn })
+            Some(SpacetimeDbConnection::new(conn))
         }
         Err(e) => {
             warn!("SpacetimeDB connection failed: {e:?}");
@@ -101,6 +260,27 @@ pub fn try_connect(
     }
 }
 
+/// Compare the server's `gameplay.ron` checksum with ours once it arrives.
+/// A mismatch means client and server disagree on spawn points, speeds or
+/// timers, so it's logged loudly; play goes on.
+pub(super) fn check_gameplay_checksum(conn: Res<SpacetimeDbConnection>, mut checked: Local<bool>) {
+    if *checked {
+        return;
+    }
+    let Some(info) = conn.conn.db.server_info().id().find(&0) else {
+        return;
+    };
+    *checked = true;
+    let ours = gameplay::checksum();
+    if info.gameplay_checksum != ours {
+        error!(
+            "Server was built from a different gameplay.ron (checksum {:016x}, ours {ours:016x}); \
+             update the client or republish the module",
+            info.gameplay_checksum
+        );
+    }
+}
+
 pub(super) fn reset_reconnect_timer(mut timer: ResMut<ReconnectTimer>) {
     *timer = ReconnectTimer::default();
 }
@@ -138,6 +318,182 @@ pub(super) fn cleanup_connecting_exit(
     }
 }
 
+/// The subscriptions [`follow_world`] holds for the current world.
+pub(super) struct WorldSubscription {
+    world_id: String,
+    handle: SubscriptionHandle,
+    /// [`cosmetic_queries`], unless the connection is degraded.
+    cosmetic: Option<SubscriptionHandle>,
+}
+
+fn unsubscribe(handle: SubscriptionHandle) {
+    if let Err(e) = handle.unsubscribe() {
+        warn!("Failed to drop a world subscription: {e:?}");
+    }
+}
+
+/// Keep the world subscription on the world the client is in, which moves
+/// whenever `join_game` or `observe` takes it somewhere else (into a lobby's
+/// match, say). The new world's tables are asked for before the old ones are
+/// dropped; the reconciler despawns whatever leaves the cache. The cosmetic
+/// tables come and go with [`ConnectionQuality`].
+pub(super) fn follow_world(
+    conn: Res<SpacetimeDbConnection>,
+    quality: Res<ConnectionQuality>,
+    mut current: Local<Option<WorldSubscription>>,
+) {
+    // A handle from an earlier connection went away with it
+    if conn.is_added() {
+        *current = None;
+    }
+    let Some(identity) = conn.conn.try_identity() else {
+        return;
+    };
+    // Neither (not joined yet, or kicked): stay where we are
+    let Some(world_id) = current_world(&conn, identity) else {
+        return;
+    };
+    let subscribe_cosmetic = || {
+        (!quality.is_degraded()).then(|| {
+            conn.conn
+                .subscription_builder()
+                .subscribe(cosmetic_queries(&world_id))
+        })
+    };
+
+    match current.as_mut() {
+        Some(sub) if sub.world_id == world_id => {
+            if quality.is_degraded() {
+                if let Some(cosmetic) = sub.cosmetic.take() {
+                    info!("Dropping cosmetic tables while the connection is degraded");
+                    unsubscribe(cosmetic);
+                }
+            } else if sub.cosmetic.is_none() {
+                sub.cosmetic = subscribe_cosmetic();
+            }
+        }
+        _ => {
+            info!("Subscribing to world '{world_id}'");
+            let handle = conn
+                .conn
+                .subscription_builder()
+                .subscribe(world_queries(&world_id));
+            let cosmetic = subscribe_cosmetic();
+            let new = WorldSubscription {
+                world_id,
+                handle,
+                cosmetic,
+            };
+            if let Some(previous) = current.replace(new) {
+                unsubscribe(previous.handle);
+                if let Some(cosmetic) = previous.cosmetic {
+                    unsubscribe(cosmetic);
+                }
+            }
+        }
+    }
+}
+
+/// The world this connection is observing or playing in, by its own rows.
+fn current_world(
+    conn: &SpacetimeDbConnection,
+    identity: spacetimedb_sdk::Identity,
+) -> Option<String> {
+    let observing = conn
+        .conn
+        .db
+        .observer()
+        .identity()
+        .find(&identity)
+        .map(|o| o.world_id);
+    let playing = || {
+        conn.conn
+            .db
+            .player()
+            .identity()
+            .find(&identity)
+            .filter(|p| p.online)
+            .map(|p| p.world_id)
+    };
+    observing.or_else(playing)
+}
+
+/// End a mid-game reconnect once the new connection is back in its world
+/// and has that world's tables. The entities kept through the gap are
+/// rebound to the fresh cache: the reconciler matches them by [`ServerId`]
+/// again, duplicates of one row (and any of the local player) are dropped,
+/// and snapshots restart so nothing is extrapolated across the gap.
+pub(super) fn finish_reconnect(
+    conn: Res<SpacetimeDbConnection>,
+    reconnecting: Res<Reconnecting>,
+    time: Res<Time<Real>>,
+    mut waited: Local<f32>,
+    mut entities: Query<(Entity, &ServerId, &mut ServerSnapshot)>,
+    mut commands: Commands,
+) {
+    if reconnecting.is_added() {
+        *waited = 0.0;
+    }
+    let Some(identity) = conn.conn.try_identity() else {
+        return;
+    };
+    *waited += time.delta_secs();
+    let world_back = current_world(&conn, identity)
+        .filter(|world_id| reconnecting.world_id.as_ref().is_none_or(|w| w == world_id))
+        .is_some_and(|world_id| conn.conn.db.world().id().find(&world_id).is_some());
+    if !world_back {
+        if *waited < RECONNECT_SYNC_TIMEOUT_SECS {
+            return;
+        }
+        warn!("World didn't come back after reconnecting; resyncing from scratch");
+    }
+
+    let mut bound = HashSet::new();
+    for (entity, id, mut snapshot) in &mut entities {
+        let ours = *id == ServerId::Player(identity);
+        if ours || !bound.insert(id.clone()) {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        // The next reconcile writes the row's position, which restarts
+        // extrapolation from there
+        *snapshot = ServerSnapshot::default();
+    }
+    info!("Reconnected; rebound {} entities", bound.len());
+    commands.remove_resource::<Reconnecting>();
+}
+
+/// Leaving gameplay abandons a reconnect in progress.
+pub(super) fn abandon_reconnect(mut commands: Commands) {
+    commands.remove_resource::<Reconnecting>();
+}
+
+/// Go back to the title screen when an admin kicks us. The server can't drop
+/// the connection, so a kick shows up as our row going offline while we're
+/// still connected.
+pub(super) fn leave_when_kicked(
+    conn: Res<SpacetimeDbConnection>,
+    mut was_online: Local<bool>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    if conn.is_added() {
+        *was_online = false;
+    }
+    // No row (before the subscription lands) tells us nothing either way
+    let Some(me) = conn
+        .conn
+        .try_identity()
+        .and_then(|id| conn.conn.db.player().identity().find(&id))
+    else {
+        return;
+    };
+    if *was_online && !me.online {
+        warn!("Kicked from the session by an admin");
+        next_screen.set(Screen::Title);
+    }
+    *was_online = me.online;
+}
+
 pub(super) fn disconnect_from_spacetimedb(
     conn: Option<Res<SpacetimeDbConnection>>,
     mut commands: Commands,
@@ -166,12 +522,17 @@ pub(super) fn disconnect_from_spacetimedb(
 
 pub(super) fn remove_server_target(mut commands: Commands) {
     commands.remove_resource::<ServerTarget>();
+    commands.remove_resource::<Observing>();
 }
 
 pub(super) fn auto_connect(
     config: Res<SpacetimeDbConfig>,
     token: Res<SpacetimeDbToken>,
     mode: Res<GameMode>,
+    loadout: Res<SelectedLoadout>,
+    account: Option<Res<AccountLogin>>,
+    observing: Option<Res<Observing>>,
+    reconnecting: Option<Res<Reconnecting>>,
     mut timer: ResMut<ReconnectTimer>,
     time: Res<Time>,
     mut commands: Commands,
@@ -201,12 +562,22 @@ pub(super) fn auto_connect(
     }
 
     // Derive URI from ServerTarget — never from mutable config
-    let uri = match target.as_ref() {
-        ServerTarget::Local { port } => format!("ws://127.0.0.1:{port}"),
-        ServerTarget::Remote { uri } => uri.clone(),
+    let (uri, module) = match target.as_ref() {
+        ServerTarget::Local { port } => (format!("ws://127.0.0.1:{port}"), &config.module_name),
+        ServerTarget::Remote { uri, module } => (uri.clone(), module),
+        ServerTarget::Offline => return,
     };
     let is_solo = *mode != GameMode::Multiplayer;
-    if let Some(conn) = try_connect(&uri, &config.module_name, &token, is_solo) {
+    if let Some(conn) = try_connect(
+        &uri,
+        module,
+        &token,
+        is_solo,
+        loadout.0,
+        observing.is_some(),
+        reconnecting.and_then(|r| r.world_id.clone()),
+        account.as_deref().cloned(),
+    ) {
         commands.insert_resource(conn);
         commands.insert_resource(HandshakeStart(Instant::now()));
         info!("auto_connect: connection initiated");
@@ -218,18 +589,41 @@ pub(super) fn auto_connect(
 pub(super) fn reap_dead_connections(
     conn: Option<Res<SpacetimeDbConnection>>,
     start: Option<Res<HandshakeStart>>,
+    reconnecting: Option<Res<Reconnecting>>,
+    state: Res<State<Screen>>,
+    target: Option<Res<ServerTarget>>,
+    mut settings: ResMut<Settings>,
     mut commands: Commands,
 ) {
     let Some(conn) = conn else { return };
 
     if !conn.conn.is_active() {
         warn!("Connection lost — cleaning up for retry");
+        // A session that was under way reconnects in place. A failed retry
+        // keeps the world remembered by the first drop.
+        if *state.get() == Screen::Gameplay && reconnecting.is_none() {
+            if let Some(identity) = conn.conn.try_identity() {
+                let world_id = current_world(&conn, identity);
+                info!("Reconnecting to world {world_id:?} in the background");
+                commands.insert_resource(Reconnecting { world_id });
+            }
+        }
         commands.remove_resource::<SpacetimeDbConnection>();
         commands.remove_resource::<HandshakeStart>();
         return;
     }
 
     if conn.conn.try_identity().is_some() {
+        // First frame after the handshake: a remote server that answered
+        // goes to the top of the title screen's list
+        if start.is_some() {
+            if let Some(ServerTarget::Remote { uri, module }) = target.as_deref() {
+                settings.remember_server(RemoteServer {
+                    uri: uri.clone(),
+                    module: module.clone(),
+                });
+            }
+        }
         commands.remove_resource::<HandshakeStart>();
         return;
     }
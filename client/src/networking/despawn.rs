@@ -0,0 +1,251 @@
+//! Deferred removal of server-mirrored entities. When their row goes away,
+//! the reconcilers strip everything gameplay reads (server id, physics,
+//! health, targeting markers) at once, then let the visuals play an exit
+//! effect before the entity is despawned, or parked in the
+//! [`EntityPool`] for reuse.
+
+use avian3d::prelude::{Collider, RigidBody, Sensor};
+use bevy::prelude::*;
+
+use super::loot::LootPickup;
+use super::pool::{EntityPool, Pooled};
+use super::reconcile::ServerId;
+use crate::combat::{Combatant, Enemy, EnemyArchetype, Health, Shield};
+use crate::ui::colors::{NEUTRAL300, SAND_YELLOW};
+
+/// How an entity leaves the world.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ExitEffect {
+    /// Players who left: the model shrinks away in a puff of smoke.
+    Poof,
+    /// Enemies that died: the model slumps into the ground.
+    Dissolve,
+    /// Picked-up loot: a quick pop and a few rising sparks.
+    Sparkle,
+}
+
+impl ExitEffect {
+    fn duration_secs(self) -> f32 {
+        match self {
+            Self::Poof => 0.35,
+            Self::Dissolve => 0.8,
+            Self::Sparkle => 0.3,
+        }
+    }
+
+    fn particle_count(self) -> usize {
+        match self {
+            Self::Poof => 10,
+            Self::Dissolve => 6,
+            Self::Sparkle => 8,
+        }
+    }
+}
+
+/// On an entity playing its [`ExitEffect`]; despawned when it ends.
+#[derive(Component, Debug)]
+#[component(storage = "SparseSet")]
+pub(super) struct Despawning {
+    effect: ExitEffect,
+    timer: f32,
+    /// Scale when the effect started, so it animates from what was on screen.
+    start_scale: Vec3,
+}
+
+/// A puff, speck or spark thrown off by an exit effect.
+#[derive(Component)]
+#[component(storage = "SparseSet")]
+pub(super) struct ExitParticle {
+    velocity: Vec3,
+    timer: f32,
+    duration: f32,
+    size: f32,
+}
+
+/// Particle mesh and one material per effect.
+pub(super) struct ExitAssets {
+    mesh: Handle<Mesh>,
+    poof: Handle<StandardMaterial>,
+    dissolve: Handle<StandardMaterial>,
+    sparkle: Handle<StandardMaterial>,
+}
+
+/// Queue `entity` for removal with `effect`. Gameplay stops seeing it
+/// immediately; the entity itself goes once the effect has played.
+pub(super) fn despawn_with(commands: &mut Commands, entity: Entity, effect: ExitEffect) {
+    commands
+        .entity(entity)
+        .remove::<(
+            ServerId,
+            Enemy,
+            Combatant,
+            Health,
+            Shield,
+            LootPickup,
+            Collider,
+            RigidBody,
+            Sensor,
+        )>()
+        .insert(Despawning {
+            effect,
+            timer: 0.0,
+            start_scale: Vec3::ONE,
+        });
+}
+
+/// Give newly queued entities their starting scale and throw off their
+/// particles.
+pub(super) fn start_exit_effects(
+    mut queued: Query<(&mut Despawning, &Transform), Added<Despawning>>,
+    mut assets: Local<Option<ExitAssets>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut commands: Commands,
+) {
+    if queued.is_empty() {
+        return;
+    }
+    let assets = assets.get_or_insert_with(|| ExitAssets {
+        mesh: meshes.add(Sphere::new(0.5)),
+        poof: materials.add(StandardMaterial {
+            base_color: NEUTRAL300.with_alpha(0.6),
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            ..default()
+        }),
+        dissolve: materials.add(StandardMaterial {
+            base_color: Color::srgba(0.15, 0.05, 0.2, 0.7),
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            ..default()
+        }),
+        sparkle: materials.add(StandardMaterial {
+            base_color: SAND_YELLOW,
+            emissive: LinearRgba::from(SAND_YELLOW) * 6.0,
+            unlit: true,
+            ..default()
+        }),
+    });
+
+    let mut rng = rand::rng();
+    for (mut despawning, transform) in &mut queued {
+        despawning.start_scale = transform.scale;
+        let effect = despawning.effect;
+        let material = match effect {
+            ExitEffect::Poof => &assets.poof,
+            ExitEffect::Dissolve => &assets.dissolve,
+            ExitEffect::Sparkle => &assets.sparkle,
+        };
+        let count = effect.particle_count();
+        for i in 0..count {
+            let angle = (i as f32 / count as f32) * std::f32::consts::TAU;
+            let (velocity, size, origin) = match effect {
+                ExitEffect::Poof => (
+                    Vec3::new(
+                        angle.cos(),
+                        rand::Rng::random_range(&mut rng, 0.2..0.8),
+                        angle.sin(),
+                    ) * 1.5,
+                    0.35,
+                    Vec3::Y * 0.2,
+                ),
+                ExitEffect::Dissolve => (
+                    Vec3::new(
+                        angle.cos() * 0.4,
+                        rand::Rng::random_range(&mut rng, 0.8..1.6),
+                        angle.sin() * 0.4,
+                    ),
+                    0.2,
+                    Vec3::Y * 0.5,
+                ),
+                ExitEffect::Sparkle => (
+                    Vec3::new(
+                        angle.cos() * 0.8,
+                        rand::Rng::random_range(&mut rng, 1.5..2.5),
+                        angle.sin() * 0.8,
+                    ),
+                    0.06,
+                    Vec3::ZERO,
+                ),
+            };
+            commands.spawn((
+                Mesh3d(assets.mesh.clone()),
+                MeshMaterial3d(material.clone()),
+                Transform::from_translation(transform.translation + origin)
+                    .with_scale(Vec3::splat(size)),
+                ExitParticle {
+                    velocity,
+                    timer: 0.0,
+                    duration: effect.duration_secs() * rand::Rng::random_range(&mut rng, 1.0..1.6),
+                    size,
+                },
+            ));
+        }
+    }
+}
+
+/// Animate queued entities out and despawn them when their effect ends.
+/// [`Pooled`] ones are parked for reuse instead.
+pub(super) fn tick_despawning(
+    time: Res<Time>,
+    mut queued: Query<(
+        Entity,
+        &mut Despawning,
+        &mut Transform,
+        Option<&EnemyArchetype>,
+        Has<Pooled>,
+    )>,
+    mut pool: ResMut<EntityPool>,
+    mut commands: Commands,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut despawning, mut transform, archetype, pooled) in &mut queued {
+        despawning.timer += dt;
+        let t = despawning.timer / despawning.effect.duration_secs();
+        if t >= 1.0 {
+            match archetype.filter(|_| pooled) {
+                Some(archetype) => {
+                    commands.entity(entity).remove::<Despawning>();
+                    pool.park_enemy(&mut commands, entity, &archetype.0);
+                }
+                None => {
+                    commands.entity(entity).despawn();
+                }
+            }
+            continue;
+        }
+        let start = despawning.start_scale;
+        transform.scale = match despawning.effect {
+            // Quick swell, then gone
+            ExitEffect::Poof => {
+                start * (1.0 + 0.2 * (t * std::f32::consts::PI).sin()) * (1.0 - t * t)
+            }
+            // Flatten and spread as it sinks
+            ExitEffect::Dissolve => {
+                transform.translation.y -= 0.6 * dt;
+                start * Vec3::new(1.0 + 0.3 * t, 1.0 - t, 1.0 + 0.3 * t)
+            }
+            ExitEffect::Sparkle => start * (1.0 + 0.6 * t) * (1.0 - t).powi(2),
+        };
+    }
+}
+
+/// Drift exit particles outward and shrink them away.
+pub(super) fn tick_exit_particles(
+    time: Res<Time>,
+    mut particles: Query<(Entity, &mut ExitParticle, &mut Transform)>,
+    mut commands: Commands,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut particle, mut transform) in &mut particles {
+        particle.timer += dt;
+        let t = particle.timer / particle.duration;
+        if t >= 1.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        transform.translation += particle.velocity * dt;
+        particle.velocity *= 1.0 - (3.0 * dt).min(1.0);
+        transform.scale = Vec3::splat(particle.size * (1.0 + t) * (1.0 - t));
+    }
+}
@@ -9,7 +9,8 @@ use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 pub struct ActiveEffect {
     pub id: u64,
     pub owner: __sdk::Identity,
-    pub effect_type: String,
+    pub kind: u8,
+    pub name: String,
     pub magnitude: f32,
     pub duration: f32,
     pub timestamp: i64,
@@ -6,11 +6,15 @@ use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
 #[derive(__lib::ser::Serialize, __lib::de::Deserialize, Clone, PartialEq, Debug)]
 #[sats(crate = __lib)]
-pub(super) struct AttackHitArgs {}
+pub(super) struct AttackHitArgs {
+    pub intent_seq: u32,
+}
 
 impl From<AttackHitArgs> for super::Reducer {
     fn from(args: AttackHitArgs) -> Self {
-        Self::AttackHit
+        Self::AttackHit {
+            intent_seq: args.intent_seq,
+        }
     }
 }
 
@@ -30,7 +34,7 @@ pub trait attack_hit {
     /// This method returns immediately, and errors only if we are unable to send the request.
     /// The reducer will run asynchronously in the future,
     ///  and its status can be observed by listening for [`Self::on_attack_hit`] callbacks.
-    fn attack_hit(&self) -> __sdk::Result<()>;
+    fn attack_hit(&self, intent_seq: u32) -> __sdk::Result<()>;
     /// Register a callback to run whenever we are notified of an invocation of the reducer `attack_hit`.
     ///
     /// Callbacks should inspect the [`__sdk::ReducerEvent`] contained in the [`super::ReducerEventContext`]
@@ -40,7 +44,7 @@ pub trait attack_hit {
     /// to cancel the callback.
     fn on_attack_hit(
         &self,
-        callback: impl FnMut(&super::ReducerEventContext) + Send + 'static,
+        callback: impl FnMut(&super::ReducerEventContext, &u32) + Send + 'static,
     ) -> AttackHitCallbackId;
     /// Cancel a callback previously registered by [`Self::on_attack_hit`],
     /// causing it not to run in the future.
@@ -48,12 +52,13 @@ pub trait attack_hit {
 }
 
 impl attack_hit for super::RemoteReducers {
-    fn attack_hit(&self) -> __sdk::Result<()> {
-        self.imp.call_reducer("attack_hit", AttackHitArgs {})
+    fn attack_hit(&self, intent_seq: u32) -> __sdk::Result<()> {
+        self.imp
+            .call_reducer("attack_hit", AttackHitArgs { intent_seq })
     }
     fn on_attack_hit(
         &self,
-        mut callback: impl FnMut(&super::ReducerEventContext) + Send + 'static,
+        mut callback: impl FnMut(&super::ReducerEventContext, &u32) + Send + 'static,
     ) -> AttackHitCallbackId {
         AttackHitCallbackId(self.imp.on_reducer(
             "attack_hit",
@@ -62,7 +67,7 @@ impl attack_hit for super::RemoteReducers {
                 let super::ReducerEventContext {
                     event:
                         __sdk::ReducerEvent {
-                            reducer: super::Reducer::AttackHit {},
+                            reducer: super::Reducer::AttackHit { intent_seq },
                             ..
                         },
                     ..
@@ -70,7 +75,7 @@ impl attack_hit for super::RemoteReducers {
                 else {
                     unreachable!()
                 };
-                callback(ctx)
+                callback(ctx, intent_seq)
             }),
         ))
     }
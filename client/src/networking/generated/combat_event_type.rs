@@ -13,6 +13,13 @@ pub struct CombatEvent {
     pub z: f32,
     pub damage: f32,
     pub is_crit: bool,
+    pub attacker: __sdk::Identity,
+    pub target_id: u64,
+    pub target_player: Option<__sdk::Identity>,
+    pub knockback_x: f32,
+    pub knockback_y: f32,
+    pub knockback_z: f32,
+    pub reaction: String,
     pub world_id: String,
     pub timestamp: i64,
 }
@@ -17,13 +17,17 @@ pub struct Enemy {
     pub velocity_x: f32,
     pub velocity_y: f32,
     pub velocity_z: f32,
-    pub animation_state: String,
+    pub behavior: u8,
     pub health: f32,
     pub max_health: f32,
+    pub shield: f32,
+    pub max_shield: f32,
     pub attack_damage: f32,
     pub attack_range: f32,
     pub attack_speed: f32,
     pub last_attack_time: i64,
+    pub last_hit_time: i64,
+    pub last_hit_by: Option<__sdk::Identity>,
 }
 
 impl __sdk::InModule for Enemy {
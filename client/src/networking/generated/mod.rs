@@ -6,73 +6,335 @@
 #![allow(unused, clippy::all)]
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
+pub mod ability_table;
+pub mod ability_type;
+pub mod accept_trade_reducer;
 pub mod active_effect_table;
 pub mod active_effect_type;
+pub mod admin_add_effect_reducer;
+pub mod admin_grant_reducer;
+pub mod admin_kick_reducer;
+pub mod admin_revoke_reducer;
+pub mod admin_set_stat_reducer;
+pub mod admin_spawn_reducer;
+pub mod admin_table;
+pub mod admin_teleport_reducer;
+pub mod admin_type;
 pub mod attack_hit_reducer;
+pub mod boss_state_table;
+pub mod boss_state_type;
+pub mod cancel_trade_reducer;
+pub mod cancel_world_event_reducer;
+pub mod cast_ability_reducer;
+pub mod chat_message_table;
+pub mod chat_message_type;
 pub mod clear_enemies_reducer;
+pub mod close_world_reducer;
+pub mod collect_empty_worlds_reducer;
 pub mod combat_event_table;
 pub mod combat_event_type;
+pub mod combat_log_table;
+pub mod combat_log_type;
+pub mod create_lobby_reducer;
+pub mod create_world_reducer;
+pub mod death_table;
+pub mod death_type;
+pub mod delete_effect_script_reducer;
+pub mod delete_enemy_archetype_reducer;
+pub mod effect_script_table;
+pub mod effect_script_type;
+pub mod enemy_archetype_table;
+pub mod enemy_archetype_type;
+pub mod enemy_position_history_table;
+pub mod enemy_position_history_type;
 pub mod enemy_table;
 pub mod enemy_type;
+pub mod equip_item_reducer;
+pub mod equipped_item_table;
+pub mod equipped_item_type;
+pub mod export_world_reducer;
 pub mod game_tick_reducer;
 pub mod ground_pound_hit_reducer;
+pub mod import_world_reducer;
+pub mod inventory_item_table;
+pub mod inventory_item_type;
 pub mod join_game_reducer;
+pub mod join_lobby_reducer;
 pub mod knockback_impulse_table;
 pub mod knockback_impulse_type;
 pub mod landing_aoe_hit_reducer;
+pub mod leaderboard_table;
+pub mod leaderboard_type;
 pub mod leave_game_reducer;
+pub mod leave_lobby_reducer;
+pub mod lobby_member_table;
+pub mod lobby_member_type;
+pub mod lobby_table;
+pub mod lobby_type;
+pub mod loot_drop_table;
+pub mod loot_drop_type;
+pub mod match_state_table;
+pub mod match_state_type;
+pub mod match_stats_table;
+pub mod match_stats_type;
+pub mod observe_reducer;
+pub mod observer_table;
+pub mod observer_type;
+pub mod offer_trade_reducer;
+pub mod on_connect_reducer;
 pub mod on_disconnect_reducer;
 pub mod pause_world_reducer;
+pub mod pickup_loot_reducer;
+pub mod ping_reducer;
+pub mod ping_result_table;
+pub mod ping_result_type;
+pub mod play_again_reducer;
+pub mod player_input_reducer;
+pub mod player_loadout_table;
+pub mod player_loadout_type;
 pub mod player_table;
 pub mod player_type;
+pub mod rate_limit_table;
+pub mod rate_limit_type;
+pub mod register_account_reducer;
+pub mod report_rtt_reducer;
+pub mod reset_character_reducer;
+pub mod reset_config_reducer;
+pub mod reset_leaderboard_reducer;
 pub mod respawn_reducer;
 pub mod resume_world_reducer;
+pub mod schedule_world_event_reducer;
+pub mod send_chat_reducer;
+pub mod server_config_table;
+pub mod server_config_type;
+pub mod server_info_table;
+pub mod server_info_type;
+pub mod set_config_reducer;
+pub mod set_effect_script_reducer;
+pub mod set_loadout_reducer;
+pub mod set_pvp_reducer;
+pub mod sign_in_reducer;
+pub mod spawn_archetype_reducer;
+pub mod spawn_director_table;
+pub mod spawn_director_type;
 pub mod spawn_enemies_reducer;
+pub mod start_director_reducer;
+pub mod start_match_reducer;
+pub mod stop_director_reducer;
+pub mod store_world_snapshot_reducer;
+pub mod telegraph_table;
+pub mod telegraph_type;
+pub mod threat_table;
+pub mod threat_type;
 pub mod tick_schedule_table;
 pub mod tick_schedule_type;
+pub mod trade_offer_item_table;
+pub mod trade_offer_item_type;
+pub mod trade_offer_table;
+pub mod trade_offer_type;
+pub mod unequip_item_reducer;
 pub mod update_position_reducer;
+pub mod upsert_enemy_archetype_reducer;
+pub mod world_event_table;
+pub mod world_event_type;
+pub mod world_gc_schedule_table;
+pub mod world_gc_schedule_type;
 pub mod world_pause_table;
 pub mod world_pause_type;
-
+pub mod world_snapshot_table;
+pub mod world_snapshot_type;
+pub mod world_table;
+pub mod world_type;
+pub mod xp_event_table;
+pub mod xp_event_type;
+
+pub use ability_table::*;
+pub use ability_type::Ability;
+pub use accept_trade_reducer::{accept_trade, set_flags_for_accept_trade, AcceptTradeCallbackId};
 pub use active_effect_table::*;
 pub use active_effect_type::ActiveEffect;
+pub use admin_add_effect_reducer::{
+    admin_add_effect, set_flags_for_admin_add_effect, AdminAddEffectCallbackId,
+};
+pub use admin_grant_reducer::{admin_grant, set_flags_for_admin_grant, AdminGrantCallbackId};
+pub use admin_kick_reducer::{admin_kick, set_flags_for_admin_kick, AdminKickCallbackId};
+pub use admin_revoke_reducer::{admin_revoke, set_flags_for_admin_revoke, AdminRevokeCallbackId};
+pub use admin_set_stat_reducer::{
+    admin_set_stat, set_flags_for_admin_set_stat, AdminSetStatCallbackId,
+};
+pub use admin_spawn_reducer::{admin_spawn, set_flags_for_admin_spawn, AdminSpawnCallbackId};
+pub use admin_table::*;
+pub use admin_teleport_reducer::{
+    admin_teleport, set_flags_for_admin_teleport, AdminTeleportCallbackId,
+};
+pub use admin_type::Admin;
 pub use attack_hit_reducer::{attack_hit, set_flags_for_attack_hit, AttackHitCallbackId};
+pub use boss_state_table::*;
+pub use boss_state_type::BossState;
+pub use cancel_trade_reducer::{cancel_trade, set_flags_for_cancel_trade, CancelTradeCallbackId};
+pub use cancel_world_event_reducer::{
+    cancel_world_event, set_flags_for_cancel_world_event, CancelWorldEventCallbackId,
+};
+pub use cast_ability_reducer::{cast_ability, set_flags_for_cast_ability, CastAbilityCallbackId};
+pub use chat_message_table::*;
+pub use chat_message_type::ChatMessage;
 pub use clear_enemies_reducer::{
     clear_enemies, set_flags_for_clear_enemies, ClearEnemiesCallbackId,
 };
+pub use close_world_reducer::{close_world, set_flags_for_close_world, CloseWorldCallbackId};
+pub use collect_empty_worlds_reducer::{
+    collect_empty_worlds, set_flags_for_collect_empty_worlds, CollectEmptyWorldsCallbackId,
+};
 pub use combat_event_table::*;
 pub use combat_event_type::CombatEvent;
+pub use combat_log_table::*;
+pub use combat_log_type::CombatLog;
+pub use create_lobby_reducer::{create_lobby, set_flags_for_create_lobby, CreateLobbyCallbackId};
+pub use create_world_reducer::{create_world, set_flags_for_create_world, CreateWorldCallbackId};
+pub use death_table::*;
+pub use death_type::Death;
+pub use delete_effect_script_reducer::{
+    delete_effect_script, set_flags_for_delete_effect_script, DeleteEffectScriptCallbackId,
+};
+pub use delete_enemy_archetype_reducer::{
+    delete_enemy_archetype, set_flags_for_delete_enemy_archetype, DeleteEnemyArchetypeCallbackId,
+};
+pub use effect_script_table::*;
+pub use effect_script_type::EffectScript;
+pub use enemy_archetype_table::*;
+pub use enemy_archetype_type::EnemyArchetype;
+pub use enemy_position_history_table::*;
+pub use enemy_position_history_type::EnemyPositionHistory;
 pub use enemy_table::*;
 pub use enemy_type::Enemy;
+pub use equip_item_reducer::{equip_item, set_flags_for_equip_item, EquipItemCallbackId};
+pub use equipped_item_table::*;
+pub use equipped_item_type::EquippedItem;
+pub use export_world_reducer::{export_world, set_flags_for_export_world, ExportWorldCallbackId};
 pub use game_tick_reducer::{game_tick, set_flags_for_game_tick, GameTickCallbackId};
 pub use ground_pound_hit_reducer::{
     ground_pound_hit, set_flags_for_ground_pound_hit, GroundPoundHitCallbackId,
 };
+pub use import_world_reducer::{import_world, set_flags_for_import_world, ImportWorldCallbackId};
+pub use inventory_item_table::*;
+pub use inventory_item_type::InventoryItem;
 pub use join_game_reducer::{join_game, set_flags_for_join_game, JoinGameCallbackId};
+pub use join_lobby_reducer::{join_lobby, set_flags_for_join_lobby, JoinLobbyCallbackId};
 pub use knockback_impulse_table::*;
 pub use knockback_impulse_type::KnockbackImpulse;
 pub use landing_aoe_hit_reducer::{
     landing_aoe_hit, set_flags_for_landing_aoe_hit, LandingAoeHitCallbackId,
 };
+pub use leaderboard_table::*;
+pub use leaderboard_type::Leaderboard;
 pub use leave_game_reducer::{leave_game, set_flags_for_leave_game, LeaveGameCallbackId};
+pub use leave_lobby_reducer::{leave_lobby, set_flags_for_leave_lobby, LeaveLobbyCallbackId};
+pub use lobby_member_table::*;
+pub use lobby_member_type::LobbyMember;
+pub use lobby_table::*;
+pub use lobby_type::Lobby;
+pub use loot_drop_table::*;
+pub use loot_drop_type::LootDrop;
+pub use match_state_table::*;
+pub use match_state_type::MatchState;
+pub use match_stats_table::*;
+pub use match_stats_type::MatchStats;
+pub use observe_reducer::{observe, set_flags_for_observe, ObserveCallbackId};
+pub use observer_table::*;
+pub use observer_type::Observer;
+pub use offer_trade_reducer::{offer_trade, set_flags_for_offer_trade, OfferTradeCallbackId};
+pub use on_connect_reducer::{on_connect, set_flags_for_on_connect, OnConnectCallbackId};
 pub use on_disconnect_reducer::{
     on_disconnect, set_flags_for_on_disconnect, OnDisconnectCallbackId,
 };
 pub use pause_world_reducer::{pause_world, set_flags_for_pause_world, PauseWorldCallbackId};
+pub use pickup_loot_reducer::{pickup_loot, set_flags_for_pickup_loot, PickupLootCallbackId};
+pub use ping_reducer::{ping, set_flags_for_ping, PingCallbackId};
+pub use ping_result_table::*;
+pub use ping_result_type::PingResult;
+pub use play_again_reducer::{play_again, set_flags_for_play_again, PlayAgainCallbackId};
+pub use player_input_reducer::{player_input, set_flags_for_player_input, PlayerInputCallbackId};
+pub use player_loadout_table::*;
+pub use player_loadout_type::PlayerLoadout;
 pub use player_table::*;
 pub use player_type::Player;
+pub use rate_limit_table::*;
+pub use rate_limit_type::RateLimit;
+pub use register_account_reducer::{
+    register_account, set_flags_for_register_account, RegisterAccountCallbackId,
+};
+pub use report_rtt_reducer::{report_rtt, set_flags_for_report_rtt, ReportRttCallbackId};
+pub use reset_character_reducer::{
+    reset_character, set_flags_for_reset_character, ResetCharacterCallbackId,
+};
+pub use reset_config_reducer::{reset_config, set_flags_for_reset_config, ResetConfigCallbackId};
+pub use reset_leaderboard_reducer::{
+    reset_leaderboard, set_flags_for_reset_leaderboard, ResetLeaderboardCallbackId,
+};
 pub use respawn_reducer::{respawn, set_flags_for_respawn, RespawnCallbackId};
 pub use resume_world_reducer::{resume_world, set_flags_for_resume_world, ResumeWorldCallbackId};
+pub use schedule_world_event_reducer::{
+    schedule_world_event, set_flags_for_schedule_world_event, ScheduleWorldEventCallbackId,
+};
+pub use send_chat_reducer::{send_chat, set_flags_for_send_chat, SendChatCallbackId};
+pub use server_config_table::*;
+pub use server_config_type::ServerConfig;
+pub use server_info_table::*;
+pub use server_info_type::ServerInfo;
+pub use set_config_reducer::{set_config, set_flags_for_set_config, SetConfigCallbackId};
+pub use set_effect_script_reducer::{
+    set_effect_script, set_flags_for_set_effect_script, SetEffectScriptCallbackId,
+};
+pub use set_loadout_reducer::{set_flags_for_set_loadout, set_loadout, SetLoadoutCallbackId};
+pub use set_pvp_reducer::{set_flags_for_set_pvp, set_pvp, SetPvpCallbackId};
+pub use sign_in_reducer::{set_flags_for_sign_in, sign_in, SignInCallbackId};
+pub use spawn_archetype_reducer::{
+    set_flags_for_spawn_archetype, spawn_archetype, SpawnArchetypeCallbackId,
+};
+pub use spawn_director_table::*;
+pub use spawn_director_type::SpawnDirector;
 pub use spawn_enemies_reducer::{
     set_flags_for_spawn_enemies, spawn_enemies, SpawnEnemiesCallbackId,
 };
+pub use start_director_reducer::{
+    set_flags_for_start_director, start_director, StartDirectorCallbackId,
+};
+pub use start_match_reducer::{set_flags_for_start_match, start_match, StartMatchCallbackId};
+pub use stop_director_reducer::{
+    set_flags_for_stop_director, stop_director, StopDirectorCallbackId,
+};
+pub use store_world_snapshot_reducer::{
+    set_flags_for_store_world_snapshot, store_world_snapshot, StoreWorldSnapshotCallbackId,
+};
+pub use telegraph_table::*;
+pub use telegraph_type::Telegraph;
+pub use threat_table::*;
+pub use threat_type::Threat;
 pub use tick_schedule_table::*;
 pub use tick_schedule_type::TickSchedule;
+pub use trade_offer_item_table::*;
+pub use trade_offer_item_type::TradeOfferItem;
+pub use trade_offer_table::*;
+pub use trade_offer_type::TradeOffer;
+pub use unequip_item_reducer::{set_flags_for_unequip_item, unequip_item, UnequipItemCallbackId};
 pub use update_position_reducer::{
     set_flags_for_update_position, update_position, UpdatePositionCallbackId,
 };
+pub use upsert_enemy_archetype_reducer::{
+    set_flags_for_upsert_enemy_archetype, upsert_enemy_archetype, UpsertEnemyArchetypeCallbackId,
+};
+pub use world_event_table::*;
+pub use world_event_type::WorldEvent;
+pub use world_gc_schedule_table::*;
+pub use world_gc_schedule_type::WorldGcSchedule;
 pub use world_pause_table::*;
 pub use world_pause_type::WorldPause;
+pub use world_snapshot_table::*;
+pub use world_snapshot_type::WorldSnapshot;
+pub use world_table::*;
+pub use world_type::World;
+pub use xp_event_table::*;
+pub use xp_event_type::XpEvent;
 
 #[derive(Clone, PartialEq, Debug)]
 
@@ -82,8 +344,87 @@ pub use world_pause_type::WorldPause;
 /// to indicate which reducer caused the event.
 
 pub enum Reducer {
-    AttackHit,
+    AcceptTrade {
+        offer_id: u64,
+    },
+    AdminAddEffect {
+        target: __sdk::Identity,
+        effect_type: String,
+        magnitude: f32,
+        duration: f32,
+    },
+    AdminGrant {
+        identity: __sdk::Identity,
+    },
+    AdminKick {
+        target: __sdk::Identity,
+    },
+    AdminRevoke {
+        identity: __sdk::Identity,
+    },
+    AdminSetStat {
+        target: __sdk::Identity,
+        stat: String,
+        value: f32,
+    },
+    AdminSpawn {
+        world_id: String,
+        archetype: String,
+        count: u32,
+        x: f32,
+        y: f32,
+        z: f32,
+    },
+    AdminTeleport {
+        target: __sdk::Identity,
+        x: f32,
+        y: f32,
+        z: f32,
+    },
+    AttackHit {
+        intent_seq: u32,
+    },
+    CancelTrade {
+        offer_id: u64,
+    },
+    CancelWorldEvent {
+        id: u64,
+    },
+    CastAbility {
+        name: String,
+    },
     ClearEnemies,
+    CloseWorld {
+        world_id: String,
+    },
+    CollectEmptyWorlds {
+        args: WorldGcSchedule,
+    },
+    CreateLobby {
+        name: String,
+        max_players: u32,
+    },
+    CreateWorld {
+        world_id: String,
+        max_players: u32,
+        empty_lifetime_secs: u32,
+        spawn_x: f32,
+        spawn_y: f32,
+        spawn_z: f32,
+    },
+    DeleteEffectScript {
+        name: String,
+    },
+    DeleteEnemyArchetype {
+        name: String,
+    },
+    EquipItem {
+        inventory_id: u64,
+    },
+    ExportWorld {
+        world_id: String,
+        name: String,
+    },
     GameTick {
         args: TickSchedule,
     },
@@ -92,10 +433,17 @@ pub enum Reducer {
         y: f32,
         z: f32,
     },
+    ImportWorld {
+        name: String,
+        world_id: String,
+    },
     JoinGame {
         name: Option<String>,
         world_id: String,
     },
+    JoinLobby {
+        lobby_id: u64,
+    },
     LandingAoeHit {
         velocity_y: f32,
         x: f32,
@@ -103,10 +451,84 @@ pub enum Reducer {
         z: f32,
     },
     LeaveGame,
+    LeaveLobby,
+    Observe {
+        world_id: String,
+    },
+    OfferTrade {
+        recipient: __sdk::Identity,
+        offered: Vec<u64>,
+        requested: Vec<u64>,
+    },
+    OnConnect,
     OnDisconnect,
     PauseWorld,
+    PickupLoot {
+        loot_id: u64,
+    },
+    Ping {
+        nonce: u32,
+    },
+    PlayAgain,
+    PlayerInput {
+        velocity_x: f32,
+        velocity_z: f32,
+        jump_height: f32,
+        heading: u8,
+        animation: u8,
+        attack_sequence: u32,
+        attack_animation: u8,
+        input_seq: u32,
+    },
+    RegisterAccount {
+        username: String,
+        password: String,
+    },
+    ReportRtt {
+        rtt_ms: f32,
+    },
+    ResetCharacter,
+    ResetConfig {
+        key: String,
+    },
+    ResetLeaderboard {
+        world_id: String,
+    },
     Respawn,
     ResumeWorld,
+    ScheduleWorldEvent {
+        world_id: String,
+        kind: String,
+        delay_secs: u32,
+        duration_secs: u32,
+    },
+    SendChat {
+        text: String,
+    },
+    SetConfig {
+        key: String,
+        value: f32,
+    },
+    SetEffectScript {
+        name: String,
+        source: String,
+    },
+    SetLoadout {
+        loadout: String,
+    },
+    SetPvp {
+        enabled: bool,
+    },
+    SignIn {
+        username: String,
+        password: String,
+    },
+    SpawnArchetype {
+        archetype: String,
+        x: f32,
+        y: f32,
+        z: f32,
+    },
     SpawnEnemies {
         x: f32,
         y: f32,
@@ -114,14 +536,38 @@ pub enum Reducer {
         forward_x: f32,
         forward_z: f32,
     },
+    StartDirector,
+    StartMatch,
+    StopDirector,
+    StoreWorldSnapshot {
+        name: String,
+        json: String,
+    },
+    UnequipItem {
+        inventory_id: u64,
+    },
     UpdatePosition {
-        x: f32,
-        y: f32,
-        z: f32,
-        rotation_y: f32,
-        animation_state: String,
+        x: i16,
+        y: i16,
+        z: i16,
+        heading: u8,
+        animation: u8,
         attack_sequence: u32,
-        attack_animation: String,
+        attack_animation: u8,
+        input_seq: u32,
+    },
+    UpsertEnemyArchetype {
+        name: String,
+        max_health: f32,
+        max_shield: f32,
+        attack_damage: f32,
+        attack_range: f32,
+        attack_speed: f32,
+        attack_cooldown: f32,
+        walk_speed: f32,
+        mass: f32,
+        pack_size_min: u32,
+        pack_size_max: u32,
     },
 }
 
@@ -132,19 +578,67 @@ impl __sdk::InModule for Reducer {
 impl __sdk::Reducer for Reducer {
     fn reducer_name(&self) -> &'static str {
         match self {
-            Reducer::AttackHit => "attack_hit",
+            Reducer::AcceptTrade { .. } => "accept_trade",
+            Reducer::AdminAddEffect { .. } => "admin_add_effect",
+            Reducer::AdminGrant { .. } => "admin_grant",
+            Reducer::AdminKick { .. } => "admin_kick",
+            Reducer::AdminRevoke { .. } => "admin_revoke",
+            Reducer::AdminSetStat { .. } => "admin_set_stat",
+            Reducer::AdminSpawn { .. } => "admin_spawn",
+            Reducer::AdminTeleport { .. } => "admin_teleport",
+            Reducer::AttackHit { .. } => "attack_hit",
+            Reducer::CancelTrade { .. } => "cancel_trade",
+            Reducer::CancelWorldEvent { .. } => "cancel_world_event",
+            Reducer::CastAbility { .. } => "cast_ability",
             Reducer::ClearEnemies => "clear_enemies",
+            Reducer::CloseWorld { .. } => "close_world",
+            Reducer::CollectEmptyWorlds { .. } => "collect_empty_worlds",
+            Reducer::CreateLobby { .. } => "create_lobby",
+            Reducer::CreateWorld { .. } => "create_world",
+            Reducer::DeleteEffectScript { .. } => "delete_effect_script",
+            Reducer::DeleteEnemyArchetype { .. } => "delete_enemy_archetype",
+            Reducer::EquipItem { .. } => "equip_item",
+            Reducer::ExportWorld { .. } => "export_world",
             Reducer::GameTick { .. } => "game_tick",
             Reducer::GroundPoundHit { .. } => "ground_pound_hit",
+            Reducer::ImportWorld { .. } => "import_world",
             Reducer::JoinGame { .. } => "join_game",
+            Reducer::JoinLobby { .. } => "join_lobby",
             Reducer::LandingAoeHit { .. } => "landing_aoe_hit",
             Reducer::LeaveGame => "leave_game",
+            Reducer::LeaveLobby => "leave_lobby",
+            Reducer::Observe { .. } => "observe",
+            Reducer::OfferTrade { .. } => "offer_trade",
+            Reducer::OnConnect => "on_connect",
             Reducer::OnDisconnect => "on_disconnect",
             Reducer::PauseWorld => "pause_world",
+            Reducer::PickupLoot { .. } => "pickup_loot",
+            Reducer::Ping { .. } => "ping",
+            Reducer::PlayAgain => "play_again",
+            Reducer::PlayerInput { .. } => "player_input",
+            Reducer::RegisterAccount { .. } => "register_account",
+            Reducer::ReportRtt { .. } => "report_rtt",
+            Reducer::ResetCharacter => "reset_character",
+            Reducer::ResetConfig { .. } => "reset_config",
+            Reducer::ResetLeaderboard { .. } => "reset_leaderboard",
             Reducer::Respawn => "respawn",
             Reducer::ResumeWorld => "resume_world",
+            Reducer::ScheduleWorldEvent { .. } => "schedule_world_event",
+            Reducer::SendChat { .. } => "send_chat",
+            Reducer::SetConfig { .. } => "set_config",
+            Reducer::SetEffectScript { .. } => "set_effect_script",
+            Reducer::SetLoadout { .. } => "set_loadout",
+            Reducer::SetPvp { .. } => "set_pvp",
+            Reducer::SignIn { .. } => "sign_in",
+            Reducer::SpawnArchetype { .. } => "spawn_archetype",
             Reducer::SpawnEnemies { .. } => "spawn_enemies",
+            Reducer::StartDirector => "start_director",
+            Reducer::StartMatch => "start_match",
+            Reducer::StopDirector => "stop_director",
+            Reducer::StoreWorldSnapshot { .. } => "store_world_snapshot",
+            Reducer::UnequipItem { .. } => "unequip_item",
             Reducer::UpdatePosition { .. } => "update_position",
+            Reducer::UpsertEnemyArchetype { .. } => "upsert_enemy_archetype",
             _ => unreachable!(),
         }
     }
@@ -153,6 +647,53 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
     type Error = __sdk::Error;
     fn try_from(value: __ws::ReducerCallInfo<__ws::BsatnFormat>) -> __sdk::Result<Self> {
         match &value.reducer_name[..] {
+            "accept_trade" => Ok(
+                __sdk::parse_reducer_args::<accept_trade_reducer::AcceptTradeArgs>(
+                    "accept_trade",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "admin_add_effect" => Ok(__sdk::parse_reducer_args::<
+                admin_add_effect_reducer::AdminAddEffectArgs,
+            >("admin_add_effect", &value.args)?
+            .into()),
+            "admin_grant" => Ok(
+                __sdk::parse_reducer_args::<admin_grant_reducer::AdminGrantArgs>(
+                    "admin_grant",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "admin_kick" => Ok(
+                __sdk::parse_reducer_args::<admin_kick_reducer::AdminKickArgs>(
+                    "admin_kick",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "admin_revoke" => Ok(
+                __sdk::parse_reducer_args::<admin_revoke_reducer::AdminRevokeArgs>(
+                    "admin_revoke",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "admin_set_stat" => Ok(__sdk::parse_reducer_args::<
+                admin_set_stat_reducer::AdminSetStatArgs,
+            >("admin_set_stat", &value.args)?
+            .into()),
+            "admin_spawn" => Ok(
+                __sdk::parse_reducer_args::<admin_spawn_reducer::AdminSpawnArgs>(
+                    "admin_spawn",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "admin_teleport" => Ok(__sdk::parse_reducer_args::<
+                admin_teleport_reducer::AdminTeleportArgs,
+            >("admin_teleport", &value.args)?
+            .into()),
             "attack_hit" => Ok(
                 __sdk::parse_reducer_args::<attack_hit_reducer::AttackHitArgs>(
                     "attack_hit",
@@ -160,10 +701,75 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 )?
                 .into(),
             ),
+            "cancel_trade" => Ok(
+                __sdk::parse_reducer_args::<cancel_trade_reducer::CancelTradeArgs>(
+                    "cancel_trade",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "cancel_world_event" => Ok(__sdk::parse_reducer_args::<
+                cancel_world_event_reducer::CancelWorldEventArgs,
+            >("cancel_world_event", &value.args)?
+            .into()),
+            "cast_ability" => Ok(
+                __sdk::parse_reducer_args::<cast_ability_reducer::CastAbilityArgs>(
+                    "cast_ability",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "clear_enemies" => Ok(__sdk::parse_reducer_args::<
                 clear_enemies_reducer::ClearEnemiesArgs,
             >("clear_enemies", &value.args)?
             .into()),
+            "close_world" => Ok(
+                __sdk::parse_reducer_args::<close_world_reducer::CloseWorldArgs>(
+                    "close_world",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "collect_empty_worlds" => Ok(__sdk::parse_reducer_args::<
+                collect_empty_worlds_reducer::CollectEmptyWorldsArgs,
+            >("collect_empty_worlds", &value.args)?
+            .into()),
+            "create_lobby" => Ok(
+                __sdk::parse_reducer_args::<create_lobby_reducer::CreateLobbyArgs>(
+                    "create_lobby",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "create_world" => Ok(
+                __sdk::parse_reducer_args::<create_world_reducer::CreateWorldArgs>(
+                    "create_world",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "delete_effect_script" => Ok(__sdk::parse_reducer_args::<
+                delete_effect_script_reducer::DeleteEffectScriptArgs,
+            >("delete_effect_script", &value.args)?
+            .into()),
+            "delete_enemy_archetype" => Ok(__sdk::parse_reducer_args::<
+                delete_enemy_archetype_reducer::DeleteEnemyArchetypeArgs,
+            >("delete_enemy_archetype", &value.args)?
+            .into()),
+            "equip_item" => Ok(
+                __sdk::parse_reducer_args::<equip_item_reducer::EquipItemArgs>(
+                    "equip_item",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "export_world" => Ok(
+                __sdk::parse_reducer_args::<export_world_reducer::ExportWorldArgs>(
+                    "export_world",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "game_tick" => Ok(
                 __sdk::parse_reducer_args::<game_tick_reducer::GameTickArgs>(
                     "game_tick",
@@ -175,6 +781,13 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 ground_pound_hit_reducer::GroundPoundHitArgs,
             >("ground_pound_hit", &value.args)?
             .into()),
+            "import_world" => Ok(
+                __sdk::parse_reducer_args::<import_world_reducer::ImportWorldArgs>(
+                    "import_world",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "join_game" => Ok(
                 __sdk::parse_reducer_args::<join_game_reducer::JoinGameArgs>(
                     "join_game",
@@ -182,6 +795,13 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 )?
                 .into(),
             ),
+            "join_lobby" => Ok(
+                __sdk::parse_reducer_args::<join_lobby_reducer::JoinLobbyArgs>(
+                    "join_lobby",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "landing_aoe_hit" => Ok(__sdk::parse_reducer_args::<
                 landing_aoe_hit_reducer::LandingAoeHitArgs,
             >("landing_aoe_hit", &value.args)?
@@ -193,6 +813,32 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 )?
                 .into(),
             ),
+            "leave_lobby" => Ok(
+                __sdk::parse_reducer_args::<leave_lobby_reducer::LeaveLobbyArgs>(
+                    "leave_lobby",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "observe" => Ok(__sdk::parse_reducer_args::<observe_reducer::ObserveArgs>(
+                "observe",
+                &value.args,
+            )?
+            .into()),
+            "offer_trade" => Ok(
+                __sdk::parse_reducer_args::<offer_trade_reducer::OfferTradeArgs>(
+                    "offer_trade",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "on_connect" => Ok(
+                __sdk::parse_reducer_args::<on_connect_reducer::OnConnectArgs>(
+                    "on_connect",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "on_disconnect" => Ok(__sdk::parse_reducer_args::<
                 on_disconnect_reducer::OnDisconnectArgs,
             >("on_disconnect", &value.args)?
@@ -204,6 +850,56 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 )?
                 .into(),
             ),
+            "pickup_loot" => Ok(
+                __sdk::parse_reducer_args::<pickup_loot_reducer::PickupLootArgs>(
+                    "pickup_loot",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "ping" => Ok(
+                __sdk::parse_reducer_args::<ping_reducer::PingArgs>("ping", &value.args)?.into(),
+            ),
+            "play_again" => Ok(
+                __sdk::parse_reducer_args::<play_again_reducer::PlayAgainArgs>(
+                    "play_again",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "player_input" => Ok(
+                __sdk::parse_reducer_args::<player_input_reducer::PlayerInputArgs>(
+                    "player_input",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "register_account" => Ok(__sdk::parse_reducer_args::<
+                register_account_reducer::RegisterAccountArgs,
+            >("register_account", &value.args)?
+            .into()),
+            "report_rtt" => Ok(
+                __sdk::parse_reducer_args::<report_rtt_reducer::ReportRttArgs>(
+                    "report_rtt",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "reset_character" => Ok(__sdk::parse_reducer_args::<
+                reset_character_reducer::ResetCharacterArgs,
+            >("reset_character", &value.args)?
+            .into()),
+            "reset_config" => Ok(
+                __sdk::parse_reducer_args::<reset_config_reducer::ResetConfigArgs>(
+                    "reset_config",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "reset_leaderboard" => Ok(__sdk::parse_reducer_args::<
+                reset_leaderboard_reducer::ResetLeaderboardArgs,
+            >("reset_leaderboard", &value.args)?
+            .into()),
             "respawn" => Ok(__sdk::parse_reducer_args::<respawn_reducer::RespawnArgs>(
                 "respawn",
                 &value.args,
@@ -216,14 +912,87 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 )?
                 .into(),
             ),
+            "schedule_world_event" => Ok(__sdk::parse_reducer_args::<
+                schedule_world_event_reducer::ScheduleWorldEventArgs,
+            >("schedule_world_event", &value.args)?
+            .into()),
+            "send_chat" => Ok(
+                __sdk::parse_reducer_args::<send_chat_reducer::SendChatArgs>(
+                    "send_chat",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "set_config" => Ok(
+                __sdk::parse_reducer_args::<set_config_reducer::SetConfigArgs>(
+                    "set_config",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "set_effect_script" => Ok(__sdk::parse_reducer_args::<
+                set_effect_script_reducer::SetEffectScriptArgs,
+            >("set_effect_script", &value.args)?
+            .into()),
+            "set_loadout" => Ok(
+                __sdk::parse_reducer_args::<set_loadout_reducer::SetLoadoutArgs>(
+                    "set_loadout",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "set_pvp" => Ok(__sdk::parse_reducer_args::<set_pvp_reducer::SetPvpArgs>(
+                "set_pvp",
+                &value.args,
+            )?
+            .into()),
+            "sign_in" => Ok(__sdk::parse_reducer_args::<sign_in_reducer::SignInArgs>(
+                "sign_in",
+                &value.args,
+            )?
+            .into()),
+            "spawn_archetype" => Ok(__sdk::parse_reducer_args::<
+                spawn_archetype_reducer::SpawnArchetypeArgs,
+            >("spawn_archetype", &value.args)?
+            .into()),
             "spawn_enemies" => Ok(__sdk::parse_reducer_args::<
                 spawn_enemies_reducer::SpawnEnemiesArgs,
             >("spawn_enemies", &value.args)?
             .into()),
+            "start_director" => Ok(__sdk::parse_reducer_args::<
+                start_director_reducer::StartDirectorArgs,
+            >("start_director", &value.args)?
+            .into()),
+            "start_match" => Ok(
+                __sdk::parse_reducer_args::<start_match_reducer::StartMatchArgs>(
+                    "start_match",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "stop_director" => Ok(__sdk::parse_reducer_args::<
+                stop_director_reducer::StopDirectorArgs,
+            >("stop_director", &value.args)?
+            .into()),
+            "store_world_snapshot" => Ok(__sdk::parse_reducer_args::<
+                store_world_snapshot_reducer::StoreWorldSnapshotArgs,
+            >("store_world_snapshot", &value.args)?
+            .into()),
+            "unequip_item" => Ok(
+                __sdk::parse_reducer_args::<unequip_item_reducer::UnequipItemArgs>(
+                    "unequip_item",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "update_position" => Ok(__sdk::parse_reducer_args::<
                 update_position_reducer::UpdatePositionArgs,
             >("update_position", &value.args)?
             .into()),
+            "upsert_enemy_archetype" => Ok(__sdk::parse_reducer_args::<
+                upsert_enemy_archetype_reducer::UpsertEnemyArchetypeArgs,
+            >("upsert_enemy_archetype", &value.args)?
+            .into()),
             unknown => {
                 Err(
                     __sdk::InternalError::unknown_name("reducer", unknown, "ReducerCallInfo")
@@ -238,13 +1007,46 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct DbUpdate {
+    ability: __sdk::TableUpdate<Ability>,
     active_effect: __sdk::TableUpdate<ActiveEffect>,
+    admin: __sdk::TableUpdate<Admin>,
+    boss_state: __sdk::TableUpdate<BossState>,
+    chat_message: __sdk::TableUpdate<ChatMessage>,
     combat_event: __sdk::TableUpdate<CombatEvent>,
+    combat_log: __sdk::TableUpdate<CombatLog>,
+    death: __sdk::TableUpdate<Death>,
+    effect_script: __sdk::TableUpdate<EffectScript>,
     enemy: __sdk::TableUpdate<Enemy>,
+    enemy_archetype: __sdk::TableUpdate<EnemyArchetype>,
+    enemy_position_history: __sdk::TableUpdate<EnemyPositionHistory>,
+    equipped_item: __sdk::TableUpdate<EquippedItem>,
+    inventory_item: __sdk::TableUpdate<InventoryItem>,
     knockback_impulse: __sdk::TableUpdate<KnockbackImpulse>,
+    leaderboard: __sdk::TableUpdate<Leaderboard>,
+    lobby: __sdk::TableUpdate<Lobby>,
+    lobby_member: __sdk::TableUpdate<LobbyMember>,
+    loot_drop: __sdk::TableUpdate<LootDrop>,
+    match_state: __sdk::TableUpdate<MatchState>,
+    match_stats: __sdk::TableUpdate<MatchStats>,
+    observer: __sdk::TableUpdate<Observer>,
+    ping_result: __sdk::TableUpdate<PingResult>,
     player: __sdk::TableUpdate<Player>,
+    player_loadout: __sdk::TableUpdate<PlayerLoadout>,
+    rate_limit: __sdk::TableUpdate<RateLimit>,
+    server_config: __sdk::TableUpdate<ServerConfig>,
+    server_info: __sdk::TableUpdate<ServerInfo>,
+    spawn_director: __sdk::TableUpdate<SpawnDirector>,
+    telegraph: __sdk::TableUpdate<Telegraph>,
+    threat: __sdk::TableUpdate<Threat>,
     tick_schedule: __sdk::TableUpdate<TickSchedule>,
+    trade_offer: __sdk::TableUpdate<TradeOffer>,
+    trade_offer_item: __sdk::TableUpdate<TradeOfferItem>,
+    world: __sdk::TableUpdate<World>,
+    world_event: __sdk::TableUpdate<WorldEvent>,
+    world_gc_schedule: __sdk::TableUpdate<WorldGcSchedule>,
     world_pause: __sdk::TableUpdate<WorldPause>,
+    world_snapshot: __sdk::TableUpdate<WorldSnapshot>,
+    xp_event: __sdk::TableUpdate<XpEvent>,
 }
 
 impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
@@ -253,27 +1055,126 @@ impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
         let mut db_update = DbUpdate::default();
         for table_update in raw.tables {
             match &table_update.table_name[..] {
+                "ability" => db_update
+                    .ability
+                    .append(ability_table::parse_table_update(table_update)?),
                 "active_effect" => db_update
                     .active_effect
                     .append(active_effect_table::parse_table_update(table_update)?),
+                "admin" => db_update
+                    .admin
+                    .append(admin_table::parse_table_update(table_update)?),
+                "boss_state" => db_update
+                    .boss_state
+                    .append(boss_state_table::parse_table_update(table_update)?),
+                "chat_message" => db_update
+                    .chat_message
+                    .append(chat_message_table::parse_table_update(table_update)?),
                 "combat_event" => db_update
                     .combat_event
                     .append(combat_event_table::parse_table_update(table_update)?),
+                "combat_log" => db_update
+                    .combat_log
+                    .append(combat_log_table::parse_table_update(table_update)?),
+                "death" => db_update
+                    .death
+                    .append(death_table::parse_table_update(table_update)?),
+                "effect_script" => db_update
+                    .effect_script
+                    .append(effect_script_table::parse_table_update(table_update)?),
                 "enemy" => db_update
                     .enemy
                     .append(enemy_table::parse_table_update(table_update)?),
+                "enemy_archetype" => db_update
+                    .enemy_archetype
+                    .append(enemy_archetype_table::parse_table_update(table_update)?),
+                "enemy_position_history" => db_update.enemy_position_history.append(
+                    enemy_position_history_table::parse_table_update(table_update)?,
+                ),
+                "equipped_item" => db_update
+                    .equipped_item
+                    .append(equipped_item_table::parse_table_update(table_update)?),
+                "inventory_item" => db_update
+                    .inventory_item
+                    .append(inventory_item_table::parse_table_update(table_update)?),
                 "knockback_impulse" => db_update
                     .knockback_impulse
                     .append(knockback_impulse_table::parse_table_update(table_update)?),
+                "leaderboard" => db_update
+                    .leaderboard
+                    .append(leaderboard_table::parse_table_update(table_update)?),
+                "lobby" => db_update
+                    .lobby
+                    .append(lobby_table::parse_table_update(table_update)?),
+                "lobby_member" => db_update
+                    .lobby_member
+                    .append(lobby_member_table::parse_table_update(table_update)?),
+                "loot_drop" => db_update
+                    .loot_drop
+                    .append(loot_drop_table::parse_table_update(table_update)?),
+                "match_state" => db_update
+                    .match_state
+                    .append(match_state_table::parse_table_update(table_update)?),
+                "match_stats" => db_update
+                    .match_stats
+                    .append(match_stats_table::parse_table_update(table_update)?),
+                "observer" => db_update
+                    .observer
+                    .append(observer_table::parse_table_update(table_update)?),
+                "ping_result" => db_update
+                    .ping_result
+                    .append(ping_result_table::parse_table_update(table_update)?),
                 "player" => db_update
                     .player
                     .append(player_table::parse_table_update(table_update)?),
+                "player_loadout" => db_update
+                    .player_loadout
+                    .append(player_loadout_table::parse_table_update(table_update)?),
+                "rate_limit" => db_update
+                    .rate_limit
+                    .append(rate_limit_table::parse_table_update(table_update)?),
+                "server_config" => db_update
+                    .server_config
+                    .append(server_config_table::parse_table_update(table_update)?),
+                "server_info" => db_update
+                    .server_info
+                    .append(server_info_table::parse_table_update(table_update)?),
+                "spawn_director" => db_update
+                    .spawn_director
+                    .append(spawn_director_table::parse_table_update(table_update)?),
+                "telegraph" => db_update
+                    .telegraph
+                    .append(telegraph_table::parse_table_update(table_update)?),
+                "threat" => db_update
+                    .threat
+                    .append(threat_table::parse_table_update(table_update)?),
                 "tick_schedule" => db_update
                     .tick_schedule
                     .append(tick_schedule_table::parse_table_update(table_update)?),
+                "trade_offer" => db_update
+                    .trade_offer
+                    .append(trade_offer_table::parse_table_update(table_update)?),
+                "trade_offer_item" => db_update
+                    .trade_offer_item
+                    .append(trade_offer_item_table::parse_table_update(table_update)?),
+                "world" => db_update
+                    .world
+                    .append(world_table::parse_table_update(table_update)?),
+                "world_event" => db_update
+                    .world_event
+                    .append(world_event_table::parse_table_update(table_update)?),
+                "world_gc_schedule" => db_update
+                    .world_gc_schedule
+                    .append(world_gc_schedule_table::parse_table_update(table_update)?),
                 "world_pause" => db_update
                     .world_pause
                     .append(world_pause_table::parse_table_update(table_update)?),
+                "world_snapshot" => db_update
+                    .world_snapshot
+                    .append(world_snapshot_table::parse_table_update(table_update)?),
+                "xp_event" => db_update
+                    .xp_event
+                    .append(xp_event_table::parse_table_update(table_update)?),
 
                 unknown => {
                     return Err(__sdk::InternalError::unknown_name(
@@ -300,27 +1201,129 @@ impl __sdk::DbUpdate for DbUpdate {
     ) -> AppliedDiff<'_> {
         let mut diff = AppliedDiff::default();
 
+        diff.ability = cache
+            .apply_diff_to_table::<Ability>("ability", &self.ability)
+            .with_updates_by_pk(|row| &row.name);
         diff.active_effect = cache
             .apply_diff_to_table::<ActiveEffect>("active_effect", &self.active_effect)
             .with_updates_by_pk(|row| &row.id);
+        diff.admin = cache
+            .apply_diff_to_table::<Admin>("admin", &self.admin)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.boss_state = cache
+            .apply_diff_to_table::<BossState>("boss_state", &self.boss_state)
+            .with_updates_by_pk(|row| &row.enemy_id);
+        diff.chat_message = cache
+            .apply_diff_to_table::<ChatMessage>("chat_message", &self.chat_message)
+            .with_updates_by_pk(|row| &row.id);
         diff.combat_event = cache
             .apply_diff_to_table::<CombatEvent>("combat_event", &self.combat_event)
             .with_updates_by_pk(|row| &row.id);
+        diff.combat_log = cache
+            .apply_diff_to_table::<CombatLog>("combat_log", &self.combat_log)
+            .with_updates_by_pk(|row| &row.id);
+        diff.death = cache
+            .apply_diff_to_table::<Death>("death", &self.death)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.effect_script = cache
+            .apply_diff_to_table::<EffectScript>("effect_script", &self.effect_script)
+            .with_updates_by_pk(|row| &row.name);
         diff.enemy = cache
             .apply_diff_to_table::<Enemy>("enemy", &self.enemy)
             .with_updates_by_pk(|row| &row.id);
+        diff.enemy_archetype = cache
+            .apply_diff_to_table::<EnemyArchetype>("enemy_archetype", &self.enemy_archetype)
+            .with_updates_by_pk(|row| &row.name);
+        diff.enemy_position_history = cache
+            .apply_diff_to_table::<EnemyPositionHistory>(
+                "enemy_position_history",
+                &self.enemy_position_history,
+            )
+            .with_updates_by_pk(|row| &row.id);
+        diff.equipped_item = cache
+            .apply_diff_to_table::<EquippedItem>("equipped_item", &self.equipped_item)
+            .with_updates_by_pk(|row| &row.inventory_id);
+        diff.inventory_item = cache
+            .apply_diff_to_table::<InventoryItem>("inventory_item", &self.inventory_item)
+            .with_updates_by_pk(|row| &row.id);
         diff.knockback_impulse = cache
             .apply_diff_to_table::<KnockbackImpulse>("knockback_impulse", &self.knockback_impulse)
             .with_updates_by_pk(|row| &row.id);
+        diff.leaderboard = cache
+            .apply_diff_to_table::<Leaderboard>("leaderboard", &self.leaderboard)
+            .with_updates_by_pk(|row| &row.id);
+        diff.lobby = cache
+            .apply_diff_to_table::<Lobby>("lobby", &self.lobby)
+            .with_updates_by_pk(|row| &row.id);
+        diff.lobby_member = cache
+            .apply_diff_to_table::<LobbyMember>("lobby_member", &self.lobby_member)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.loot_drop = cache
+            .apply_diff_to_table::<LootDrop>("loot_drop", &self.loot_drop)
+            .with_updates_by_pk(|row| &row.id);
+        diff.match_state = cache
+            .apply_diff_to_table::<MatchState>("match_state", &self.match_state)
+            .with_updates_by_pk(|row| &row.world_id);
+        diff.match_stats = cache
+            .apply_diff_to_table::<MatchStats>("match_stats", &self.match_stats)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.observer = cache
+            .apply_diff_to_table::<Observer>("observer", &self.observer)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.ping_result = cache
+            .apply_diff_to_table::<PingResult>("ping_result", &self.ping_result)
+            .with_updates_by_pk(|row| &row.identity);
         diff.player = cache
             .apply_diff_to_table::<Player>("player", &self.player)
             .with_updates_by_pk(|row| &row.identity);
+        diff.player_loadout = cache
+            .apply_diff_to_table::<PlayerLoadout>("player_loadout", &self.player_loadout)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.rate_limit = cache
+            .apply_diff_to_table::<RateLimit>("rate_limit", &self.rate_limit)
+            .with_updates_by_pk(|row| &row.key);
+        diff.server_config = cache
+            .apply_diff_to_table::<ServerConfig>("server_config", &self.server_config)
+            .with_updates_by_pk(|row| &row.key);
+        diff.server_info = cache
+            .apply_diff_to_table::<ServerInfo>("server_info", &self.server_info)
+            .with_updates_by_pk(|row| &row.id);
+        diff.spawn_director = cache
+            .apply_diff_to_table::<SpawnDirector>("spawn_director", &self.spawn_director)
+            .with_updates_by_pk(|row| &row.world_id);
+        diff.telegraph = cache
+            .apply_diff_to_table::<Telegraph>("telegraph", &self.telegraph)
+            .with_updates_by_pk(|row| &row.id);
+        diff.threat = cache
+            .apply_diff_to_table::<Threat>("threat", &self.threat)
+            .with_updates_by_pk(|row| &row.id);
         diff.tick_schedule = cache
             .apply_diff_to_table::<TickSchedule>("tick_schedule", &self.tick_schedule)
             .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.trade_offer = cache
+            .apply_diff_to_table::<TradeOffer>("trade_offer", &self.trade_offer)
+            .with_updates_by_pk(|row| &row.id);
+        diff.trade_offer_item = cache
+            .apply_diff_to_table::<TradeOfferItem>("trade_offer_item", &self.trade_offer_item)
+            .with_updates_by_pk(|row| &row.id);
+        diff.world = cache
+            .apply_diff_to_table::<World>("world", &self.world)
+            .with_updates_by_pk(|row| &row.id);
+        diff.world_event = cache
+            .apply_diff_to_table::<WorldEvent>("world_event", &self.world_event)
+            .with_updates_by_pk(|row| &row.id);
+        diff.world_gc_schedule = cache
+            .apply_diff_to_table::<WorldGcSchedule>("world_gc_schedule", &self.world_gc_schedule)
+            .with_updates_by_pk(|row| &row.scheduled_id);
         diff.world_pause = cache
             .apply_diff_to_table::<WorldPause>("world_pause", &self.world_pause)
             .with_updates_by_pk(|row| &row.world_id);
+        diff.world_snapshot = cache
+            .apply_diff_to_table::<WorldSnapshot>("world_snapshot", &self.world_snapshot)
+            .with_updates_by_pk(|row| &row.name);
+        diff.xp_event = cache
+            .apply_diff_to_table::<XpEvent>("xp_event", &self.xp_event)
+            .with_updates_by_pk(|row| &row.id);
 
         diff
     }
@@ -330,13 +1333,46 @@ impl __sdk::DbUpdate for DbUpdate {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct AppliedDiff<'r> {
+    ability: __sdk::TableAppliedDiff<'r, Ability>,
     active_effect: __sdk::TableAppliedDiff<'r, ActiveEffect>,
+    admin: __sdk::TableAppliedDiff<'r, Admin>,
+    boss_state: __sdk::TableAppliedDiff<'r, BossState>,
+    chat_message: __sdk::TableAppliedDiff<'r, ChatMessage>,
     combat_event: __sdk::TableAppliedDiff<'r, CombatEvent>,
+    combat_log: __sdk::TableAppliedDiff<'r, CombatLog>,
+    death: __sdk::TableAppliedDiff<'r, Death>,
+    effect_script: __sdk::TableAppliedDiff<'r, EffectScript>,
     enemy: __sdk::TableAppliedDiff<'r, Enemy>,
+    enemy_archetype: __sdk::TableAppliedDiff<'r, EnemyArchetype>,
+    enemy_position_history: __sdk::TableAppliedDiff<'r, EnemyPositionHistory>,
+    equipped_item: __sdk::TableAppliedDiff<'r, EquippedItem>,
+    inventory_item: __sdk::TableAppliedDiff<'r, InventoryItem>,
     knockback_impulse: __sdk::TableAppliedDiff<'r, KnockbackImpulse>,
+    leaderboard: __sdk::TableAppliedDiff<'r, Leaderboard>,
+    lobby: __sdk::TableAppliedDiff<'r, Lobby>,
+    lobby_member: __sdk::TableAppliedDiff<'r, LobbyMember>,
+    loot_drop: __sdk::TableAppliedDiff<'r, LootDrop>,
+    match_state: __sdk::TableAppliedDiff<'r, MatchState>,
+    match_stats: __sdk::TableAppliedDiff<'r, MatchStats>,
+    observer: __sdk::TableAppliedDiff<'r, Observer>,
+    ping_result: __sdk::TableAppliedDiff<'r, PingResult>,
     player: __sdk::TableAppliedDiff<'r, Player>,
+    player_loadout: __sdk::TableAppliedDiff<'r, PlayerLoadout>,
+    rate_limit: __sdk::TableAppliedDiff<'r, RateLimit>,
+    server_config: __sdk::TableAppliedDiff<'r, ServerConfig>,
+    server_info: __sdk::TableAppliedDiff<'r, ServerInfo>,
+    spawn_director: __sdk::TableAppliedDiff<'r, SpawnDirector>,
+    telegraph: __sdk::TableAppliedDiff<'r, Telegraph>,
+    threat: __sdk::TableAppliedDiff<'r, Threat>,
     tick_schedule: __sdk::TableAppliedDiff<'r, TickSchedule>,
+    trade_offer: __sdk::TableAppliedDiff<'r, TradeOffer>,
+    trade_offer_item: __sdk::TableAppliedDiff<'r, TradeOfferItem>,
+    world: __sdk::TableAppliedDiff<'r, World>,
+    world_event: __sdk::TableAppliedDiff<'r, WorldEvent>,
+    world_gc_schedule: __sdk::TableAppliedDiff<'r, WorldGcSchedule>,
     world_pause: __sdk::TableAppliedDiff<'r, WorldPause>,
+    world_snapshot: __sdk::TableAppliedDiff<'r, WorldSnapshot>,
+    xp_event: __sdk::TableAppliedDiff<'r, XpEvent>,
     __unused: std::marker::PhantomData<&'r ()>,
 }
 
@@ -350,29 +1386,118 @@ impl<'r> __sdk::AppliedDiff<'r> for AppliedDiff<'r> {
         event: &EventContext,
         callbacks: &mut __sdk::DbCallbacks<RemoteModule>,
     ) {
+        callbacks.invoke_table_row_callbacks::<Ability>("ability", &self.ability, event);
         callbacks.invoke_table_row_callbacks::<ActiveEffect>(
             "active_effect",
             &self.active_effect,
             event,
         );
+        callbacks.invoke_table_row_callbacks::<Admin>("admin", &self.admin, event);
+        callbacks.invoke_table_row_callbacks::<BossState>("boss_state", &self.boss_state, event);
+        callbacks.invoke_table_row_callbacks::<ChatMessage>(
+            "chat_message",
+            &self.chat_message,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<CombatEvent>(
             "combat_event",
             &self.combat_event,
             event,
         );
+        callbacks.invoke_table_row_callbacks::<CombatLog>("combat_log", &self.combat_log, event);
+        callbacks.invoke_table_row_callbacks::<Death>("death", &self.death, event);
+        callbacks.invoke_table_row_callbacks::<EffectScript>(
+            "effect_script",
+            &self.effect_script,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<Enemy>("enemy", &self.enemy, event);
+        callbacks.invoke_table_row_callbacks::<EnemyArchetype>(
+            "enemy_archetype",
+            &self.enemy_archetype,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<EnemyPositionHistory>(
+            "enemy_position_history",
+            &self.enemy_position_history,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<EquippedItem>(
+            "equipped_item",
+            &self.equipped_item,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<InventoryItem>(
+            "inventory_item",
+            &self.inventory_item,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<KnockbackImpulse>(
             "knockback_impulse",
             &self.knockback_impulse,
             event,
         );
+        callbacks.invoke_table_row_callbacks::<Leaderboard>(
+            "leaderboard",
+            &self.leaderboard,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Lobby>("lobby", &self.lobby, event);
+        callbacks.invoke_table_row_callbacks::<LobbyMember>(
+            "lobby_member",
+            &self.lobby_member,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<LootDrop>("loot_drop", &self.loot_drop, event);
+        callbacks.invoke_table_row_callbacks::<MatchState>("match_state", &self.match_state, event);
+        callbacks.invoke_table_row_callbacks::<MatchStats>("match_stats", &self.match_stats, event);
+        callbacks.invoke_table_row_callbacks::<Observer>("observer", &self.observer, event);
+        callbacks.invoke_table_row_callbacks::<PingResult>("ping_result", &self.ping_result, event);
         callbacks.invoke_table_row_callbacks::<Player>("player", &self.player, event);
+        callbacks.invoke_table_row_callbacks::<PlayerLoadout>(
+            "player_loadout",
+            &self.player_loadout,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<RateLimit>("rate_limit", &self.rate_limit, event);
+        callbacks.invoke_table_row_callbacks::<ServerConfig>(
+            "server_config",
+            &self.server_config,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<ServerInfo>("server_info", &self.server_info, event);
+        callbacks.invoke_table_row_callbacks::<SpawnDirector>(
+            "spawn_director",
+            &self.spawn_director,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Telegraph>("telegraph", &self.telegraph, event);
+        callbacks.invoke_table_row_callbacks::<Threat>("threat", &self.threat, event);
         callbacks.invoke_table_row_callbacks::<TickSchedule>(
             "tick_schedule",
             &self.tick_schedule,
             event,
         );
+        callbacks.invoke_table_row_callbacks::<TradeOffer>("trade_offer", &self.trade_offer, event);
+        callbacks.invoke_table_row_callbacks::<TradeOfferItem>(
+            "trade_offer_item",
+            &self.trade_offer_item,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<World>("world", &self.world, event);
+        callbacks.invoke_table_row_callbacks::<WorldEvent>("world_event", &self.world_event, event);
+        callbacks.invoke_table_row_callbacks::<WorldGcSchedule>(
+            "world_gc_schedule",
+            &self.world_gc_schedule,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<WorldPause>("world_pause", &self.world_pause, event);
+        callbacks.invoke_table_row_callbacks::<WorldSnapshot>(
+            "world_snapshot",
+            &self.world_snapshot,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<XpEvent>("xp_event", &self.xp_event, event);
     }
 }
 
@@ -1094,12 +2219,45 @@ impl __sdk::SpacetimeModule for RemoteModule {
     type SubscriptionHandle = SubscriptionHandle;
 
     fn register_tables(client_cache: &mut __sdk::ClientCache<Self>) {
+        ability_table::register_table(client_cache);
         active_effect_table::register_table(client_cache);
+        admin_table::register_table(client_cache);
+        boss_state_table::register_table(client_cache);
+        chat_message_table::register_table(client_cache);
         combat_event_table::register_table(client_cache);
+        combat_log_table::register_table(client_cache);
+        death_table::register_table(client_cache);
+        effect_script_table::register_table(client_cache);
         enemy_table::register_table(client_cache);
+        enemy_archetype_table::register_table(client_cache);
+        enemy_position_history_table::register_table(client_cache);
+        equipped_item_table::register_table(client_cache);
+        inventory_item_table::register_table(client_cache);
         knockback_impulse_table::register_table(client_cache);
+        leaderboard_table::register_table(client_cache);
+        lobby_table::register_table(client_cache);
+        lobby_member_table::register_table(client_cache);
+        loot_drop_table::register_table(client_cache);
+        match_state_table::register_table(client_cache);
+        match_stats_table::register_table(client_cache);
+        observer_table::register_table(client_cache);
+        ping_result_table::register_table(client_cache);
         player_table::register_table(client_cache);
+        player_loadout_table::register_table(client_cache);
+        rate_limit_table::register_table(client_cache);
+        server_config_table::register_table(client_cache);
+        server_info_table::register_table(client_cache);
+        spawn_director_table::register_table(client_cache);
+        telegraph_table::register_table(client_cache);
+        threat_table::register_table(client_cache);
         tick_schedule_table::register_table(client_cache);
+        trade_offer_table::register_table(client_cache);
+        trade_offer_item_table::register_table(client_cache);
+        world_table::register_table(client_cache);
+        world_event_table::register_table(client_cache);
+        world_gc_schedule_table::register_table(client_cache);
         world_pause_table::register_table(client_cache);
+        world_snapshot_table::register_table(client_cache);
+        xp_event_table::register_table(client_cache);
     }
 }
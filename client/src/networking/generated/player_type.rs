@@ -16,11 +16,14 @@ pub struct Player {
     pub y: f32,
     pub z: f32,
     pub rotation_y: f32,
-    pub animation_state: String,
+    pub animation: u8,
     pub attack_sequence: u32,
-    pub attack_animation: String,
+    pub attack_animation: u8,
     pub health: f32,
     pub max_health: f32,
+    pub shield: f32,
+    pub max_shield: f32,
+    pub last_hit_time: i64,
     pub attack_damage: f32,
     pub crit_chance: f32,
     pub crit_multiplier: f32,
@@ -29,6 +32,13 @@ pub struct Player {
     pub knockback_force: f32,
     pub attack_speed: f32,
     pub last_attack_time: i64,
+    pub weapon: String,
+    pub pvp: bool,
+    pub xp: u32,
+    pub level: u32,
+    pub rtt_ms: f32,
+    pub input_seq: u32,
+    pub attack_intent_seq: u32,
 }
 
 impl __sdk::InModule for Player {
@@ -7,13 +7,14 @@ use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 #[derive(__lib::ser::Serialize, __lib::de::Deserialize, Clone, PartialEq, Debug)]
 #[sats(crate = __lib)]
 pub(super) struct UpdatePositionArgs {
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
-    pub rotation_y: f32,
-    pub animation_state: String,
+    pub x: i16,
+    pub y: i16,
+    pub z: i16,
+    pub heading: u8,
+    pub animation: u8,
     pub attack_sequence: u32,
-    pub attack_animation: String,
+    pub attack_animation: u8,
+    pub input_seq: u32,
 }
 
 impl From<UpdatePositionArgs> for super::Reducer {
@@ -22,10 +23,11 @@ impl From<UpdatePositionArgs> for super::Reducer {
             x: args.x,
             y: args.y,
             z: args.z,
-            rotation_y: args.rotation_y,
-            animation_state: args.animation_state,
+            heading: args.heading,
+            animation: args.animation,
             attack_sequence: args.attack_sequence,
             attack_animation: args.attack_animation,
+            input_seq: args.input_seq,
         }
     }
 }
@@ -48,13 +50,14 @@ pub trait update_position {
     ///  and its status can be observed by listening for [`Self::on_update_position`] callbacks.
     fn update_position(
         &self,
-        x: f32,
-        y: f32,
-        z: f32,
-        rotation_y: f32,
-        animation_state: String,
+        x: i16,
+        y: i16,
+        z: i16,
+        heading: u8,
+        animation: u8,
         attack_sequence: u32,
-        attack_animation: String,
+        attack_animation: u8,
+        input_seq: u32,
     ) -> __sdk::Result<()>;
     /// Register a callback to run whenever we are notified of an invocation of the reducer `update_position`.
     ///
@@ -65,7 +68,7 @@ pub trait update_position {
     /// to cancel the callback.
     fn on_update_position(
         &self,
-        callback: impl FnMut(&super::ReducerEventContext, &f32, &f32, &f32, &f32, &String, &u32, &String)
+        callback: impl FnMut(&super::ReducerEventContext, &i16, &i16, &i16, &u8, &u8, &u32, &u8, &u32)
             + Send
             + 'static,
     ) -> UpdatePositionCallbackId;
@@ -77,13 +80,14 @@ pub trait update_position {
 impl update_position for super::RemoteReducers {
     fn update_position(
         &self,
-        x: f32,
-        y: f32,
-        z: f32,
-        rotation_y: f32,
-        animation_state: String,
+        x: i16,
+        y: i16,
+        z: i16,
+        heading: u8,
+        animation: u8,
         attack_sequence: u32,
-        attack_animation: String,
+        attack_animation: u8,
+        input_seq: u32,
     ) -> __sdk::Result<()> {
         self.imp.call_reducer(
             "update_position",
@@ -91,16 +95,17 @@ impl update_position for super::RemoteReducers {
                 x,
                 y,
                 z,
-                rotation_y,
-                animation_state,
+                heading,
+                animation,
                 attack_sequence,
                 attack_animation,
+                input_seq,
             },
         )
     }
     fn on_update_position(
         &self,
-        mut callback: impl FnMut(&super::ReducerEventContext, &f32, &f32, &f32, &f32, &String, &u32, &String)
+        mut callback: impl FnMut(&super::ReducerEventContext, &i16, &i16, &i16, &u8, &u8, &u32, &u8, &u32)
             + Send
             + 'static,
     ) -> UpdatePositionCallbackId {
@@ -116,10 +121,11 @@ impl update_position for super::RemoteReducers {
                                     x,
                                     y,
                                     z,
-                                    rotation_y,
-                                    animation_state,
+                                    heading,
+                                    animation,
                                     attack_sequence,
                                     attack_animation,
+                                    input_seq,
                                 },
                             ..
                         },
@@ -133,10 +139,11 @@ impl update_position for super::RemoteReducers {
                     x,
                     y,
                     z,
-                    rotation_y,
-                    animation_state,
+                    heading,
+                    animation,
                     attack_sequence,
                     attack_animation,
+                    input_seq,
                 )
             }),
         ))
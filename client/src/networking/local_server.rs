@@ -1,13 +1,16 @@
 //! Local SpacetimeDB subprocess manager for native singleplayer.
 //!
 //! Starts a SpacetimeDB instance on localhost, deploys the game module,
-//! and exposes the connection URI. The subprocess is shut down when the
+//! and exposes the connection URI. The process handling itself lives in
+//! `wasm-fantasia-runner` (shared with the dedicated `server-runner`); this
+//! module drives it one step per frame. The subprocess is shut down when the
 //! [`LocalServer`] resource is removed or the app exits.
 
 use bevy::prelude::*;
-use std::net::TcpListener;
-use std::path::PathBuf;
-use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
+use wasm_fantasia_runner::{
+    LogLine, Module, Output, Publish, SpacetimeServer, StartOptions, Storage,
+};
 
 use crate::models::Screen;
 
@@ -18,10 +21,24 @@ use crate::models::Screen;
 /// Handle to the running SpacetimeDB subprocess.
 #[derive(Resource)]
 pub struct LocalServer {
-    process: Option<Child>,
+    server: Option<SpacetimeServer>,
     pub port: u16,
-    spacetime_binary: PathBuf,
-    data_dir: Option<PathBuf>,
+    /// Crashes since the server last stayed up for [`STABLE_SECS`].
+    crashes: u32,
+    ready_since: Option<Instant>,
+    /// A crash restart is in flight, so the state machine is driven here
+    /// rather than by the Connecting screen.
+    restarting: bool,
+}
+
+impl LocalServer {
+    /// Most recent captured output lines, oldest first.
+    pub fn log_tail(&self, n: usize) -> Vec<LogLine> {
+        self.server
+            .as_ref()
+            .map(|server| server.log().tail(n))
+            .unwrap_or_default()
+    }
 }
 
 /// Progress of the local server lifecycle.
@@ -29,19 +46,26 @@ pub struct LocalServer {
 pub enum LocalServerState {
     Starting,
     WaitingForReady,
-    Deploying(DeployProcess),
+    Deploying(Publish),
     Ready,
+    /// Exited unexpectedly; restarts when the timer finishes.
+    Crashed {
+        reason: String,
+        retry: Timer,
+    },
     Failed(String),
 }
 
-/// Owns the `spacetime publish` subprocess. Kills it on drop so cancelled
-/// deploys don't leave orphaned processes.
-pub struct DeployProcess(Child);
-
-impl Drop for DeployProcess {
-    fn drop(&mut self) {
-        let _ = self.0.kill();
-        let _ = self.0.wait();
+impl LocalServerState {
+    /// Short status for the diagnostics panel.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Starting | Self::WaitingForReady => "starting",
+            Self::Deploying(_) => "deploying",
+            Self::Ready => "ready",
+            Self::Crashed { .. } => "crashed",
+            Self::Failed(_) => "failed",
+        }
     }
 }
 
@@ -52,6 +76,7 @@ impl std::fmt::Debug for LocalServerState {
             Self::WaitingForReady => write!(f, "WaitingForReady"),
             Self::Deploying(_) => write!(f, "Deploying"),
             Self::Ready => write!(f, "Ready"),
+            Self::Crashed { reason, .. } => write!(f, "Crashed({reason})"),
             Self::Failed(e) => write!(f, "Failed({e})"),
         }
     }
@@ -61,8 +86,17 @@ impl std::fmt::Debug for LocalServerState {
 // Plugin
 // =============================================================================
 
+/// Restarts allowed before giving up, unless the server stays up for
+/// [`STABLE_SECS`] in between.
+const MAX_RESTARTS: u32 = 5;
+const STABLE_SECS: u64 = 60;
+/// First restart delay, doubled per consecutive crash.
+const RESTART_BACKOFF_SECS: f32 = 1.0;
+const MAX_RESTART_BACKOFF_SECS: f32 = 30.0;
+
 pub fn plugin(app: &mut App) {
-    app.add_systems(OnEnter(Screen::Loading), prewarm_local_server);
+    app.add_systems(OnEnter(Screen::Loading), prewarm_local_server)
+        .add_systems(Update, supervise_local_server);
 }
 
 /// Spawn the local SpacetimeDB process during loading so it has a head
@@ -79,61 +113,6 @@ fn prewarm_local_server(mut commands: Commands, existing: Option<Res<LocalServer
     commands.insert_resource(state);
 }
 
-// =============================================================================
-// Binary discovery
-// =============================================================================
-
-/// Find the `spacetime` CLI binary.
-///
-/// Search order:
-/// 1. Adjacent to the game executable (bundled distribution)
-/// 2. `SPACETIMEDB_PATH` environment variable
-/// 3. `~/.local/bin/spacetime` (default install location)
-/// 4. System PATH via `which`
-fn find_spacetime_binary() -> Option<PathBuf> {
-    if let Ok(exe) = std::env::current_exe() {
-        if let Some(dir) = exe.parent() {
-            let adjacent = dir.join("spacetime");
-            if adjacent.exists() {
-                return Some(adjacent);
-            }
-        }
-    }
-
-    if let Ok(path) = std::env::var("SPACETIMEDB_PATH") {
-        let p = PathBuf::from(path);
-        if p.exists() {
-            return Some(p);
-        }
-    }
-
-    if let Some(home) = home::home_dir() {
-        let default_path = home.join(".local/bin/spacetime");
-        if default_path.exists() {
-            return Some(default_path);
-        }
-    }
-
-    if let Ok(output) = Command::new("which").arg("spacetime").output() {
-        if output.status.success() {
-            let path = String::from_utf8_lossy(&output.stdout).trim().to_string();
-            if !path.is_empty() {
-                return Some(PathBuf::from(path));
-            }
-        }
-    }
-
-    None
-}
-
-/// Pick a random available port by binding to :0 and reading the assigned port.
-fn pick_available_port() -> Option<u16> {
-    TcpListener::bind("127.0.0.1:0")
-        .ok()
-        .and_then(|listener| listener.local_addr().ok())
-        .map(|addr| addr.port())
-}
-
 // =============================================================================
 // Lifecycle
 // =============================================================================
@@ -143,154 +122,70 @@ fn pick_available_port() -> Option<u16> {
 /// Returns `(LocalServer, LocalServerState)` to be inserted as resources.
 /// The caller should call [`advance`] each frame to drive the state machine.
 pub fn start() -> (LocalServer, LocalServerState) {
-    let Some(binary) = find_spacetime_binary() else {
-        return (
-            LocalServer {
-                process: None,
-                port: 0,
-                spacetime_binary: PathBuf::new(),
-                data_dir: None,
-            },
-            LocalServerState::Failed(
-                "SpacetimeDB CLI not found. Install from https://install.spacetimedb.com \
-                 or set SPACETIMEDB_PATH."
-                    .to_string(),
-            ),
-        );
+    let local = |server, port| LocalServer {
+        server,
+        port,
+        crashes: 0,
+        ready_since: None,
+        restarting: false,
     };
-
-    let Some(port) = pick_available_port() else {
-        return (
-            LocalServer {
-                process: None,
-                port: 0,
-                spacetime_binary: binary,
-                data_dir: None,
-            },
-            LocalServerState::Failed("Could not find an available port.".to_string()),
+    let failed = |port, reason: String| (local(None, port), LocalServerState::Failed(reason));
+
+    let Some(binary) = wasm_fantasia_runner::find_spacetime_binary() else {
+        return failed(
+            0,
+            "SpacetimeDB CLI not found. Install from https://install.spacetimedb.com \
+             or set SPACETIMEDB_PATH."
+                .to_string(),
         );
     };
 
-    // Use a unique temp data directory so the pid file doesn't conflict
-    // with any other running SpacetimeDB instance.
-    let data_dir = std::env::temp_dir().join(format!("spacetimedb-wf-{port}"));
-    let _ = std::fs::create_dir_all(&data_dir);
+    let Some(port) = wasm_fantasia_runner::pick_available_port() else {
+        return failed(0, "Could not find an available port.".to_string());
+    };
 
     let listen_addr = format!("127.0.0.1:{port}");
     info!("Starting local SpacetimeDB on {listen_addr}");
 
-    let data_dir_str = data_dir.to_string_lossy().to_string();
-    let result = Command::new(&binary)
-        .args([
-            "start",
-            "--listen-addr",
-            &listen_addr,
-            "--in-memory",
-            "--data-dir",
-            &data_dir_str,
-        ])
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .spawn();
-
-    match result {
-        Ok(child) => (
-            LocalServer {
-                process: Some(child),
-                port,
-                spacetime_binary: binary,
-                data_dir: Some(data_dir),
-            },
-            LocalServerState::Starting,
-        ),
-        Err(e) => (
-            LocalServer {
-                process: None,
-                port,
-                spacetime_binary: binary,
-                data_dir: Some(data_dir),
-            },
-            LocalServerState::Failed(format!("Failed to start SpacetimeDB: {e}")),
-        ),
-    }
-}
-
-/// Spawn the `spacetime publish` command as a non-blocking subprocess.
-fn spawn_deploy(server: &LocalServer) -> Result<DeployProcess, String> {
-    let listen_addr = format!("127.0.0.1:{}", server.port);
-
-    // Use pre-compiled WASM if adjacent to the executable, otherwise
-    // fall back to --project-path for dev workflow.
-    let bin_path = std::env::current_exe()
-        .ok()
-        .and_then(|exe| exe.parent().map(|d| d.join("wasm_fantasia_module.wasm")))
-        .filter(|p| p.exists());
-
-    let mut cmd = Command::new(&server.spacetime_binary);
-    cmd.args([
-        "publish",
-        "wasm-fantasia",
-        "--yes",
-        "--delete-data",
-        "-s",
-        &format!("http://{listen_addr}"),
-    ]);
-    if let Some(ref wasm_path) = bin_path {
-        cmd.args(["--bin-path", &wasm_path.to_string_lossy()]);
-    } else {
-        cmd.args(["--project-path", "server"]);
+    let options = StartOptions {
+        listen_addr,
+        storage: Storage::Temporary,
+        output: Output::Capture,
+    };
+    match SpacetimeServer::start(binary, options) {
+        Ok(server) => (local(Some(server), port), LocalServerState::Starting),
+        Err(e) => failed(port, e),
     }
-
-    cmd.stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .spawn()
-        .map(DeployProcess)
-        .map_err(|e| format!("Failed to run spacetime publish: {e}"))
 }
 
 /// Drive the local server state machine forward.
 ///
-/// Call each frame while state is not `Ready` or `Failed`.
-/// Returns `true` when the state changed.
-pub fn advance(server: &mut LocalServer, state: &mut LocalServerState) -> bool {
+/// Call each frame while state is not `Failed`; a `Ready` server is
+/// checked for crashes. Returns `true` when the state changed.
+pub fn advance(local: &mut LocalServer, state: &mut LocalServerState) -> bool {
+    let Some(server) = local.server.as_mut() else {
+        return false;
+    };
+
     match state {
         LocalServerState::Starting | LocalServerState::WaitingForReady => {
-            // Port occupied = server is listening
-            if TcpListener::bind(format!("127.0.0.1:{}", server.port)).is_err() {
-                info!("Local SpacetimeDB listening on port {}", server.port);
+            if server.is_listening() {
+                info!("Local SpacetimeDB listening on port {}", local.port);
                 info!(
                     "Deploying game module to local server at 127.0.0.1:{}",
-                    server.port
+                    local.port
                 );
-                match spawn_deploy(server) {
-                    Ok(child) => *state = LocalServerState::Deploying(child),
+                // Fresh in-memory database each session
+                match server.publish(&Module::locate(), true) {
+                    Ok(publish) => *state = LocalServerState::Deploying(publish),
                     Err(e) => *state = LocalServerState::Failed(e),
                 }
                 return true;
             }
 
-            // Check for premature exit
-            if let Some(ref mut child) = server.process {
-                if let Ok(Some(status)) = child.try_wait() {
-                    let stderr = child
-                        .stderr
-                        .as_mut()
-                        .and_then(|s| {
-                            use std::io::Read;
-                            let mut buf = String::new();
-                            s.read_to_string(&mut buf).ok().map(|_| buf)
-                        })
-                        .unwrap_or_default();
-                    let detail = if stderr.is_empty() {
-                        format!("exit status: {status}")
-                    } else {
-                        format!("exit status: {status}\n{stderr}")
-                    };
-                    *state = LocalServerState::Failed(format!(
-                        "SpacetimeDB exited prematurely: {detail}"
-                    ));
-                    return true;
-                }
+            if let Some(detail) = server.exit_status() {
+                *state = crashed(local, format!("SpacetimeDB exited prematurely: {detail}"));
+                return true;
             }
 
             if matches!(state, LocalServerState::Starting) {
@@ -301,39 +196,92 @@ pub fn advance(server: &mut LocalServer, state: &mut LocalServerState) -> bool {
             false
         }
 
-        LocalServerState::Deploying(deploy) => {
-            match deploy.0.try_wait() {
-                Ok(Some(status)) => {
-                    let mut stdout_buf = String::new();
-                    let mut stderr_buf = String::new();
-                    if let Some(ref mut out) = deploy.0.stdout {
-                        use std::io::Read;
-                        let _ = out.read_to_string(&mut stdout_buf);
-                    }
-                    if let Some(ref mut err) = deploy.0.stderr {
-                        use std::io::Read;
-                        let _ = err.read_to_string(&mut stderr_buf);
-                    }
-
-                    if status.success() {
-                        info!("Module deployed successfully: {stdout_buf}");
-                        *state = LocalServerState::Ready;
-                    } else {
-                        *state = LocalServerState::Failed(format!(
-                            "Module deploy failed:\nstdout: {stdout_buf}\nstderr: {stderr_buf}"
-                        ));
-                    }
-                    true
-                }
-                Ok(None) => false, // Still running
-                Err(e) => {
-                    *state = LocalServerState::Failed(format!("Error polling deploy process: {e}"));
-                    true
-                }
+        LocalServerState::Deploying(publish) => match publish.try_finish() {
+            Some(Ok(stdout)) => {
+                info!("Module deployed successfully: {stdout}");
+                *state = LocalServerState::Ready;
+                local.ready_since = Some(Instant::now());
+                local.restarting = false;
+                true
             }
+            Some(Err(e)) => {
+                *state = LocalServerState::Failed(e);
+                true
+            }
+            None => false, // Still running
+        },
+
+        LocalServerState::Ready => match server.exit_status() {
+            Some(detail) => {
+                *state = crashed(local, format!("SpacetimeDB crashed: {detail}"));
+                true
+            }
+            None => false,
+        },
+
+        LocalServerState::Crashed { .. } | LocalServerState::Failed(_) => false,
+    }
+}
+
+/// Schedule a restart with exponential backoff, or give up once the server
+/// keeps crashing.
+fn crashed(local: &mut LocalServer, reason: String) -> LocalServerState {
+    let was_stable = local
+        .ready_since
+        .take()
+        .is_some_and(|since| since.elapsed() >= Duration::from_secs(STABLE_SECS));
+    if was_stable {
+        local.crashes = 0;
+    }
+    local.crashes += 1;
+
+    if local.crashes > MAX_RESTARTS {
+        error!("Local SpacetimeDB crashed {MAX_RESTARTS} times in a row, giving up: {reason}");
+        return LocalServerState::Failed(reason);
+    }
+
+    let delay =
+        (RESTART_BACKOFF_SECS * 2f32.powi(local.crashes as i32 - 1)).min(MAX_RESTART_BACKOFF_SECS);
+    warn!("{reason}\nRestarting local SpacetimeDB in {delay:.0}s");
+    LocalServerState::Crashed {
+        reason,
+        retry: Timer::from_seconds(delay, TimerMode::Once),
+    }
+}
+
+/// Restart crashed servers and drive restarts through to `Ready`. Outside
+/// restarts the Connecting screen drives the state machine, so this only
+/// watches a ready server for crashes.
+fn supervise_local_server(
+    time: Res<Time>,
+    screen: Res<State<Screen>>,
+    local: Option<ResMut<LocalServer>>,
+    state: Option<ResMut<LocalServerState>>,
+) {
+    let (Some(mut local), Some(mut state)) = (local, state) else {
+        return;
+    };
+
+    if let LocalServerState::Crashed { retry, .. } = state.as_mut() {
+        if !retry.tick(time.delta()).just_finished() {
+            return;
         }
+        let port = local.port;
+        let Some(server) = local.server.as_mut() else {
+            return;
+        };
+        info!("Restarting local SpacetimeDB on port {port}");
+        *state = match server.restart() {
+            Ok(()) => LocalServerState::Starting,
+            Err(e) => LocalServerState::Failed(e),
+        };
+        local.restarting = true;
+        return;
+    }
 
-        LocalServerState::Ready | LocalServerState::Failed(_) => false,
+    let connecting = *screen.get() == Screen::Connecting;
+    if (local.restarting && !connecting) || matches!(*state, LocalServerState::Ready) {
+        advance(&mut local, &mut state);
     }
 }
 
@@ -346,25 +294,17 @@ pub fn connection_uri(server: &LocalServer) -> String {
 // Shutdown
 // =============================================================================
 
-fn shutdown(server: &mut LocalServer) {
-    if let Some(ref mut child) = server.process {
-        info!("Shutting down local SpacetimeDB (pid {})", child.id());
-        let _ = child.kill();
-        match child.wait() {
-            Ok(status) => info!("Local SpacetimeDB exited with status: {status}"),
-            Err(e) => warn!("Error waiting for SpacetimeDB to exit: {e}"),
-        }
-        server.process = None;
-    }
-    if let Some(ref dir) = server.data_dir {
-        let _ = std::fs::remove_dir_all(dir);
-    }
-}
-
 impl Drop for LocalServer {
     fn drop(&mut self) {
-        if self.process.is_some() {
-            shutdown(self);
+        let Some(mut server) = self.server.take() else {
+            return;
+        };
+        if let Some(pid) = server.pid() {
+            info!("Shutting down local SpacetimeDB (pid {pid})");
+        }
+        match server.shutdown() {
+            Some(status) => info!("Local SpacetimeDB exited with status: {status}"),
+            None => warn!("Local SpacetimeDB was already gone at shutdown"),
         }
     }
 }
@@ -0,0 +1,117 @@
+//! Loot drops: mirrors `loot_drop` rows as pickup entities and asks the
+//! server for them when the local player walks over one. The server checks
+//! range and applies the item; the row disappearing despawns the pickup with
+//! a sparkle.
+
+use bevy::prelude::*;
+use spacetimedb_sdk::Table;
+use std::collections::HashSet;
+use wasm_fantasia_shared::loot::{self, LootItem};
+
+use super::SpacetimeDbConnection;
+use super::despawn::{ExitEffect, despawn_with};
+use super::generated::loot_drop_table::LootDropTableAccess;
+use super::generated::pickup_loot_reducer::pickup_loot;
+use crate::combat::Health;
+use crate::models::{Player, Screen};
+use crate::ui::colors::HEALTH_RED;
+
+/// A loot drop lying in the world, keyed by its `loot_drop` row id.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct LootPickup {
+    pub id: u64,
+    /// `None` for item ids this client doesn't know.
+    pub item: Option<LootItem>,
+}
+
+/// Meshes and materials shared by every pickup.
+pub(super) struct LootAssets {
+    mesh: Handle<Mesh>,
+    material: Handle<StandardMaterial>,
+}
+
+/// Pickups float this far above where the enemy died.
+const HOVER_HEIGHT: f32 = 0.5;
+
+pub(super) fn reconcile_loot(
+    conn: Res<SpacetimeDbConnection>,
+    pickups: Query<(Entity, &LootPickup)>,
+    mut assets: Local<Option<LootAssets>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut commands: Commands,
+) {
+    let rows: Vec<_> = conn.conn.db.loot_drop().iter().collect();
+
+    let mut present = HashSet::new();
+    for (entity, pickup) in &pickups {
+        if rows.iter().any(|r| r.id == pickup.id) {
+            present.insert(pickup.id);
+        } else {
+            despawn_with(&mut commands, entity, ExitEffect::Sparkle);
+        }
+    }
+
+    let assets = assets.get_or_insert_with(|| LootAssets {
+        mesh: meshes.add(Sphere::new(0.2)),
+        material: materials.add(StandardMaterial {
+            base_color: HEALTH_RED,
+            emissive: LinearRgba::from(HEALTH_RED) * 4.0,
+            ..default()
+        }),
+    });
+
+    for row in rows.iter().filter(|r| !present.contains(&r.id)) {
+        let item = LootItem::parse_str(&row.item_id);
+        let scale = match item {
+            Some(LootItem::LargeHealthOrb) => 1.6,
+            _ => 1.0,
+        };
+        commands.spawn((
+            Name::new(format!("Loot {}", row.item_id)),
+            LootPickup { id: row.id, item },
+            Mesh3d(assets.mesh.clone()),
+            MeshMaterial3d(assets.material.clone()),
+            Transform::from_xyz(row.x, row.y + HOVER_HEIGHT, row.z).with_scale(Vec3::splat(scale)),
+            DespawnOnExit(Screen::Gameplay),
+        ));
+    }
+}
+
+/// Request pickups in range of the local player, once per drop.
+pub(super) fn collect_loot(
+    conn: Res<SpacetimeDbConnection>,
+    player: Query<(&GlobalTransform, &Health), With<Player>>,
+    pickups: Query<(&LootPickup, &GlobalTransform)>,
+    mut requested: Local<HashSet<u64>>,
+) {
+    requested.retain(|id| pickups.iter().any(|(p, _)| p.id == *id));
+
+    let Ok((player, health)) = player.single() else {
+        return;
+    };
+    if health.current <= 0.0 {
+        return;
+    }
+
+    for (pickup, transform) in &pickups {
+        let distance = transform.translation().distance(player.translation());
+        if distance > loot::PICKUP_RANGE + HOVER_HEIGHT || requested.contains(&pickup.id) {
+            continue;
+        }
+        requested.insert(pickup.id);
+        if let Err(e) = conn.reducers().pickup_loot(pickup.id) {
+            warn!("Failed to send pickup_loot: {:?}", e);
+        }
+    }
+}
+
+/// Gentle bob so drops read as pickups rather than debris.
+pub(super) fn bob_loot(time: Res<Time>, mut pickups: Query<(&mut Transform, &LootPickup)>) {
+    let t = time.elapsed_secs();
+    for (mut transform, pickup) in &mut pickups {
+        // Offset the phase per drop so neighbours don't bob in sync
+        let phase = (pickup.id % 16) as f32 * 0.4;
+        transform.translation.y += (t * 3.0 + phase).cos() * 0.15 * time.delta_secs();
+    }
+}
@@ -0,0 +1,124 @@
+//! Match lifecycle: detects when the server ends a match and snapshots the
+//! per-player results into [`MatchSummary`] for the summary screen.
+
+use bevy::prelude::*;
+use spacetimedb_sdk::{DbContext, Table};
+
+use super::SpacetimeDbConnection;
+use super::generated::match_state_table::MatchStateTableAccess;
+use super::generated::match_stats_table::MatchStatsTableAccess;
+use super::generated::play_again_reducer::play_again;
+use super::generated::player_table::PlayerTableAccess;
+use super::generated::xp_event_table::XpEventTableAccess;
+use crate::combat::XpGained;
+use crate::models::Screen;
+
+#[derive(Default, Clone)]
+pub struct PlayerMatchResult {
+    pub name: String,
+    pub is_you: bool,
+    pub kills: u32,
+    pub damage_dealt: f32,
+    pub crits: u32,
+    pub xp_gained: u32,
+    pub loot_earned: u32,
+}
+
+/// Results of the last finished match. Filled right before entering
+/// [`Screen::Summary`]; sorted with the local player first.
+#[derive(Resource, Default)]
+pub struct MatchSummary {
+    pub players: Vec<PlayerMatchResult>,
+    pub duration_secs: f32,
+    /// `ended_at` of the match this summary was built from. A match that
+    /// already produced a summary never triggers another one.
+    ended_at: i64,
+}
+
+pub(super) fn detect_match_end(
+    conn: Res<SpacetimeDbConnection>,
+    mut summary: ResMut<MatchSummary>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    let Some(our_id) = conn.conn.try_identity() else {
+        return;
+    };
+    let Some(me) = conn.conn.db.player().identity().find(&our_id) else {
+        return;
+    };
+    let Some(state) = conn.conn.db.match_state().world_id().find(&me.world_id) else {
+        return;
+    };
+    if state.active || state.ended_at == summary.ended_at {
+        return;
+    }
+
+    let mut players: Vec<PlayerMatchResult> = conn
+        .conn
+        .db
+        .match_stats()
+        .iter()
+        .filter(|s| s.world_id == state.world_id)
+        .map(|s| {
+            let name = conn
+                .conn
+                .db
+                .player()
+                .identity()
+                .find(&s.identity)
+                .and_then(|p| p.name)
+                .unwrap_or_else(|| "?".to_string());
+            PlayerMatchResult {
+                name,
+                is_you: s.identity == our_id,
+                kills: s.kills,
+                damage_dealt: s.damage_dealt,
+                crits: s.crits,
+                xp_gained: s.xp_gained,
+                loot_earned: s.loot_earned,
+            }
+        })
+        .collect();
+    players.sort_by(|a, b| b.is_you.cmp(&a.is_you).then(b.kills.cmp(&a.kills)));
+
+    *summary = MatchSummary {
+        players,
+        duration_secs: (state.ended_at - state.started_at) as f32 / 1_000_000.0,
+        ended_at: state.ended_at,
+    };
+    next_screen.set(Screen::Summary);
+}
+
+/// Turn XP awards addressed to the local player into [`XpGained`] floaters.
+pub(super) fn process_xp_events(
+    conn: Res<SpacetimeDbConnection>,
+    mut last_processed_id: Local<u64>,
+    mut commands: Commands,
+) {
+    let Some(our_id) = conn.conn.try_identity() else {
+        return;
+    };
+
+    for event in conn.conn.db.xp_event().iter() {
+        if event.id <= *last_processed_id {
+            continue;
+        }
+        *last_processed_id = (*last_processed_id).max(event.id);
+        if event.recipient != our_id {
+            continue;
+        }
+
+        commands.trigger(XpGained {
+            amount: event.amount,
+            shared: event.shared,
+            position: Vec3::new(event.x, event.y, event.z),
+        });
+    }
+}
+
+/// Ask the server to restart the match for the current world.
+pub fn request_play_again(conn: &SpacetimeDbConnection) {
+    if let Err(e) = conn.reducers().play_again() {
+        warn!("Failed to send play_again: {:?}", e);
+    }
+}
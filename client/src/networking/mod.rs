@@ -1,25 +1,58 @@
 //! SpacetimeDB multiplayer networking module
 
+use bevy::input::common_conditions::input_just_pressed;
 use bevy::prelude::*;
+use bevy::time::TimeSystems;
+use bevy::transform::TransformSystems;
+use wasm_fantasia_shared::gameplay::Relay;
 
-use crate::models::{GameplayCleanup, Screen, ServerTarget};
+use crate::models::{
+    AbilitySlot1, AbilitySlot2, AbilitySlot3, GameplayCleanup, RemoteServer, Screen, ServerTarget,
+};
 
+mod background;
+mod chat;
+mod clock;
 pub mod combat;
 mod connection;
+mod despawn;
 mod diagnostics;
 pub mod generated;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod local_server;
+mod loot;
+mod matches;
+mod name_tags;
+mod net_stats;
+pub mod offline;
+mod pool;
+mod prediction;
+mod quality;
 mod reconcile;
+mod replay;
+mod secondary;
+mod server;
 mod sync;
+mod telegraphs;
+mod world_events;
 
-pub use connection::{ReconnectTimer, try_connect};
+pub use chat::{ChatReceived, send_chat_message};
+pub use clock::ServerClock;
+pub use connection::{ReconnectTimer, Reconnecting, try_connect};
 pub use diagnostics::ServerDiagnostics;
 pub use generated::{DbConnection, Player, Reducer};
+pub use loot::LootPickup;
+pub use matches::{MatchSummary, PlayerMatchResult, request_play_again};
+pub use net_stats::NetStats;
+pub use prediction::InputSequence;
+pub use quality::ConnectionQuality;
 pub use reconcile::{
     CombatEventData, CombatStats, RemotePlayerState, ServerId, ServerSnapshot, WorldEntity,
 };
+pub use secondary::{ConnectionPurpose, ModuleConnection, SecondaryConnections};
+pub use server::{ActiveServer, GameServer};
 pub use sync::PingTracker;
+pub use world_events::WorldEventAnnounced;
 
 // =============================================================================
 // Resources
@@ -29,9 +62,28 @@ pub use sync::PingTracker;
 #[derive(Resource)]
 pub struct SpacetimeDbConnection {
     pub conn: DbConnection,
+    counters: std::sync::Arc<net_stats::NetCounters>,
+}
+
+impl SpacetimeDbConnection {
+    pub fn new(conn: DbConnection) -> Self {
+        Self {
+            conn,
+            counters: default(),
+        }
+    }
+
+    /// The module's reducers. Calls made through here are counted in
+    /// [`NetStats`].
+    pub fn reducers(&self) -> &generated::RemoteReducers {
+        self.counters.count_call();
+        &self.conn.reducers
+    }
 }
 
-/// SpacetimeDB configuration resource.
+/// SpacetimeDB configuration resource: the server multiplayer connects to
+/// unless another is picked on the title screen, and the module local
+/// servers publish.
 #[derive(Resource, Clone, Debug)]
 pub struct SpacetimeDbConfig {
     pub uri: String,
@@ -42,24 +94,76 @@ impl Default for SpacetimeDbConfig {
     fn default() -> Self {
         Self {
             uri: default_uri(),
-            module_name: "wasm-fantasia".to_string(),
+            module_name: default_module(),
+        }
+    }
+}
+
+impl SpacetimeDbConfig {
+    pub fn default_server(&self) -> RemoteServer {
+        RemoteServer {
+            uri: self.uri.clone(),
+            module: self.module_name.clone(),
+        }
+    }
+}
+
+/// Knobs trading smoothness for latency, adjustable while playing from the
+/// settings screen (and the inspector in dev builds). Not saved.
+#[derive(Resource, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+pub struct NetworkTuning {
+    /// How fast remote entities close on where they should be, per second.
+    /// Higher follows the server more tightly, lower hides jitter.
+    pub interpolation_speed: f32,
+    /// Position (or input) updates sent a second.
+    pub sync_rate_hz: f32,
+}
+
+impl NetworkTuning {
+    pub const INTERPOLATION_SPEED_RANGE: (f32, f32) = (2.0, 30.0);
+    /// The server's rate limit allows 30 a second.
+    pub const SYNC_RATE_RANGE: (f32, f32) = (5.0, 30.0);
+}
+
+impl Default for NetworkTuning {
+    fn default() -> Self {
+        Self {
+            interpolation_speed: 12.0,
+            sync_rate_hz: 20.0,
         }
     }
 }
 
+/// Page query parameter `name`, e.g. `stdb` from `?stdb=ws://...`.
+#[cfg(target_arch = "wasm32")]
+fn query_param(name: &str) -> Option<String> {
+    let search = web_sys::window()?.location().search().ok()?;
+    search
+        .trim_start_matches('?')
+        .split('&')
+        .find_map(|p| p.strip_prefix(name)?.strip_prefix('='))
+        .map(String::from)
+}
+
+/// `wasm-fantasia` unless the page asks for another with `?module=`, e.g.
+/// a staging build published alongside it.
+fn default_module() -> String {
+    #[cfg(target_arch = "wasm32")]
+    if let Some(module) = query_param("module") {
+        return module;
+    }
+    "wasm-fantasia".to_string()
+}
+
 /// On WASM, derive the SpacetimeDB URI from the page's location.
 fn default_uri() -> String {
     #[cfg(target_arch = "wasm32")]
     {
+        if let Some(uri) = query_param("stdb") {
+            return uri;
+        }
         if let Some(location) = web_sys::window().map(|w| w.location()) {
-            if let Some(uri) = location.search().ok().and_then(|s| {
-                s.trim_start_matches('?')
-                    .split('&')
-                    .find_map(|p| p.strip_prefix("stdb="))
-                    .map(String::from)
-            }) {
-                return uri;
-            }
             if let Some(host) = location.hostname().ok().filter(|h| !h.is_empty()) {
                 let scheme = match location.protocol().ok().as_deref() {
                     Some("https:") => "wss",
@@ -87,6 +191,15 @@ pub fn is_server_connected(conn: Option<Res<SpacetimeDbConnection>>) -> bool {
     conn.is_some()
 }
 
+/// Run condition: connected, and not waiting for a mid-game reconnect to get
+/// the world back. Systems that despawn what's missing from the cache use it.
+pub fn is_synced(
+    conn: Option<Res<SpacetimeDbConnection>>,
+    reconnecting: Option<Res<Reconnecting>>,
+) -> bool {
+    conn.is_some() && reconnecting.is_none()
+}
+
 pub const STALE_THRESHOLD_SECS: f32 = 3.0;
 
 // =============================================================================
@@ -99,14 +212,43 @@ impl Plugin for NetworkingPlugin {
     fn build(&self, app: &mut App) {
         #[cfg(not(target_arch = "wasm32"))]
         app.add_plugins(local_server::plugin);
+        app.add_plugins((offline::plugin, prediction::plugin, quality::plugin));
 
         app.init_resource::<SpacetimeDbConfig>()
             .init_resource::<SpacetimeDbToken>()
             .init_resource::<connection::ReconnectTimer>()
+            .init_resource::<NetworkTuning>()
+            .register_type::<NetworkTuning>()
             .init_resource::<sync::PositionSyncTimer>()
             .init_resource::<sync::PingTracker>()
+            .init_resource::<clock::ServerClock>()
             .init_resource::<reconcile::CombatEventTracker>()
+            .init_resource::<reconcile::RowChanges>()
+            .init_resource::<replay::ReplayRecorder>()
             .init_resource::<diagnostics::ServerDiagnostics>()
+            .init_resource::<net_stats::NetStats>()
+            .init_resource::<pool::EntityPool>()
+            .init_resource::<combat::AttackIntents>()
+            .init_resource::<SecondaryConnections>()
+            .init_resource::<matches::MatchSummary>()
+            .init_resource::<background::BackgroundGap>()
+            .add_systems(
+                First,
+                (
+                    background::skip_background_gap.before(TimeSystems),
+                    background::resume_after_gap.after(TimeSystems),
+                ),
+            )
+            .add_systems(
+                Update,
+                (
+                    background::stop_background_ticker
+                        .run_if(resource_removed::<SpacetimeDbConnection>),
+                    background::start_background_ticker
+                        .run_if(resource_added::<SpacetimeDbConnection>),
+                )
+                    .chain(),
+            )
             .add_systems(
                 OnEnter(Screen::Connecting),
                 connection::reset_reconnect_timer.run_if(resource_exists::<ServerTarget>),
@@ -116,39 +258,169 @@ impl Plugin for NetworkingPlugin {
                 OnExit(Screen::Connecting),
                 connection::cleanup_connecting_exit,
             )
+            .add_systems(
+                OnExit(Screen::Gameplay),
+                (connection::abandon_reconnect, pool::clear_entity_pool),
+            )
             .add_systems(
                 OnExit(Screen::Gameplay),
                 (
                     connection::disconnect_from_spacetimedb,
+                    secondary::disconnect_secondary_connections,
                     connection::remove_server_target,
                 )
-                    .run_if(is_server_connected)
+                    .run_if(is_server_connected.and(not(in_state(Screen::Summary))))
                     .before(GameplayCleanup),
+            )
+            // The summary keeps the connection so the party can play again;
+            // leaving it for anything but gameplay tears the session down.
+            .add_systems(
+                OnExit(Screen::Summary),
+                (
+                    connection::disconnect_from_spacetimedb,
+                    secondary::disconnect_secondary_connections,
+                    connection::remove_server_target,
+                )
+                    .run_if(is_server_connected.and(not(in_state(Screen::Gameplay)))),
             );
 
         app.add_observer(combat::send_attack_to_server)
             .add_observer(combat::send_ground_pound_to_server)
             .add_observer(combat::send_landing_aoe_to_server)
+            .add_observer(sync::queue_jump)
+            .add_observer(combat::cast_hotbar_ability::<AbilitySlot1, 0>)
+            .add_observer(combat::cast_hotbar_ability::<AbilitySlot2, 1>)
+            .add_observer(combat::cast_hotbar_ability::<AbilitySlot3, 2>)
+            .add_observer(combat::toggle_pvp)
+            .add_observer(combat::cycle_weapon)
             .add_systems(
             Update,
             (
-                connection::reap_dead_connections.run_if(resource_exists::<SpacetimeDbConnection>),
-                connection::handle_connection_events
+                (
+                    connection::reap_dead_connections
+                        .run_if(resource_exists::<SpacetimeDbConnection>),
+                    secondary::tick_secondary_connections,
+                ),
+                (connection::handle_connection_events, connection::follow_world)
+                    .chain()
+                    .run_if(resource_exists::<SpacetimeDbConnection>),
+                connection::finish_reconnect
+                    .after(connection::follow_world)
+                    .run_if(
+                        resource_exists::<SpacetimeDbConnection>
+                            .and(resource_exists::<connection::Reconnecting>),
+                    ),
+                connection::check_gameplay_checksum
+                    .run_if(resource_exists::<SpacetimeDbConnection>),
+                reconcile::watch_entity_tables
+                    .before(reconcile::reconcile)
+                    .run_if(resource_added::<SpacetimeDbConnection>),
+                reconcile::reconcile
+                    .after(connection::finish_reconnect)
+                    .run_if(is_synced.or(replay::is_replaying)),
+                (reconcile::process_combat_events, pool::expire_combat_events)
+                    .chain()
+                    .after(reconcile::reconcile)
+                    .run_if(resource_exists::<SpacetimeDbConnection>),
+                (reconcile::sync_equipped_weapons, reconcile::sync_remote_auras)
+                    .after(reconcile::reconcile)
+                    .run_if(resource_exists::<SpacetimeDbConnection>),
+                (reconcile::sync_control_effects, reconcile::sync_mana)
+                    .after(reconcile::reconcile)
+                    .run_if(resource_exists::<SpacetimeDbConnection>),
+                sync::interpolate_synced_entities.run_if(is_synced.or(replay::is_replaying)),
+                sync::restore_saved_position
+                    .before(reconcile::reconcile)
+                    .run_if(resource_exists::<SpacetimeDbConnection>),
+                (
+                    sync::apply_sync_rate.run_if(
+                        resource_changed::<NetworkTuning>
+                            .or(resource_changed::<ConnectionQuality>),
+                    ),
+                    sync::send_local_position.run_if(sync::relaying(Relay::Position)),
+                    sync::send_local_input.run_if(sync::relaying(Relay::Input)),
+                )
+                    .chain()
+                    .after(sync::restore_saved_position)
+                    .run_if(resource_exists::<SpacetimeDbConnection>),
+                (
+                    combat::reset_attack_intents.run_if(resource_added::<SpacetimeDbConnection>),
+                    combat::seed_attack_sequence,
+                    combat::resend_attack_intents,
+                    combat::request_respawn_on_death,
+                )
+                    .chain()
                     .run_if(resource_exists::<SpacetimeDbConnection>),
-                reconcile::reconcile.run_if(resource_exists::<SpacetimeDbConnection>),
-                sync::interpolate_synced_entities.run_if(resource_exists::<SpacetimeDbConnection>),
-                sync::send_local_position.run_if(resource_exists::<SpacetimeDbConnection>),
-                combat::request_respawn_on_death.run_if(resource_exists::<SpacetimeDbConnection>),
+                combat::snap_to_spawn_on_respawn.run_if(resource_exists::<SpacetimeDbConnection>),
                 sync::measure_ping.run_if(resource_exists::<SpacetimeDbConnection>),
                 diagnostics::update_server_diagnostics
                     .run_if(resource_exists::<SpacetimeDbConnection>),
+                matches::process_xp_events.run_if(resource_exists::<SpacetimeDbConnection>),
+                chat::process_chat_messages.run_if(resource_exists::<SpacetimeDbConnection>),
+                (loot::reconcile_loot, loot::collect_loot, loot::bob_loot)
+                    .chain()
+                    .run_if(is_synced),
+                (
+                    despawn::start_exit_effects,
+                    despawn::tick_despawning,
+                    despawn::tick_exit_particles,
+                )
+                    .chain()
+                    .after(reconcile::reconcile)
+                    .after(loot::reconcile_loot),
+                (telegraphs::reconcile_telegraphs, telegraphs::fill_telegraphs)
+                    .chain()
+                    .run_if(is_synced),
+                world_events::announce_world_events
+                    .run_if(resource_exists::<SpacetimeDbConnection>),
+                (matches::detect_match_end, connection::leave_when_kicked).run_if(
+                    resource_exists::<SpacetimeDbConnection>.and(in_state(Screen::Gameplay)),
+                ),
             ),
         );
 
+        if let Some(playback) =
+            replay::replay_from_args().and_then(|path| replay::ReplayPlayback::load(&path))
+        {
+            app.insert_resource(playback);
+        }
         app.add_systems(
+            OnEnter(Screen::Title),
+            replay::start_playback.run_if(replay::is_replaying),
+        )
+        .add_systems(
             Update,
-            diagnostics::clear_server_diagnostics
+            (
+                replay::watch_for_recording.run_if(resource_added::<SpacetimeDbConnection>),
+                replay::toggle_recording.run_if(
+                    input_just_pressed(KeyCode::F10).and(resource_exists::<SpacetimeDbConnection>),
+                ),
+                replay::finish_recording.run_if(resource_removed::<SpacetimeDbConnection>),
+                replay::feed_playback
+                    .before(reconcile::reconcile)
+                    .run_if(in_state(Screen::Gameplay).and(replay::is_replaying)),
+            ),
+        );
+
+        app.add_systems(
+            PostUpdate,
+            name_tags::tick_name_tags.after(TransformSystems::Propagate),
+        );
+        app.add_systems(
+            Update,
+            (
+                diagnostics::clear_server_diagnostics,
+                net_stats::clear_net_stats,
+                clock::clear_server_clock,
+            )
                 .run_if(not(resource_exists::<SpacetimeDbConnection>)),
         );
+        app.add_systems(
+            Update,
+            (
+                net_stats::count_inbound_rows.run_if(resource_added::<SpacetimeDbConnection>),
+                net_stats::update_net_stats.run_if(resource_exists::<SpacetimeDbConnection>),
+            ),
+        );
     }
 }
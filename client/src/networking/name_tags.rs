@@ -0,0 +1,95 @@
+//! Name tags over remote players. Each tag is a UI label spawned by the
+//! reconciler alongside its player and kept over their head on screen,
+//! fading out with distance. Settings can hide them all.
+
+use bevy::prelude::*;
+
+use super::reconcile::RemotePlayerState;
+use crate::models::{SceneCamera, Settings};
+use crate::ui::colors::NEUTRAL10;
+
+/// Above the player's origin, which sits at the middle of their capsule.
+const NAME_TAG_HEIGHT: f32 = 1.4;
+/// Tags are fully opaque up to this distance from the camera…
+const FADE_START: f32 = 12.0;
+/// …and gone from here on.
+const FADE_END: f32 = 30.0;
+
+/// Screen-space label following a remote player entity.
+#[derive(Component, Debug)]
+pub(super) struct NameTag {
+    target: Entity,
+}
+
+pub(super) fn name_tag(target: Entity, name: &str) -> impl Bundle {
+    (
+        Name::new("Name Tag"),
+        NameTag { target },
+        Text::new(name),
+        TextFont {
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(NEUTRAL10),
+        TextLayout::new_with_justify(Justify::Center),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(-9999.0),
+            top: Val::Px(-9999.0),
+            ..default()
+        },
+        GlobalZIndex(80),
+        Pickable::IGNORE,
+    )
+}
+
+/// Keep each tag over its player's head and faded by distance; despawn tags
+/// whose player is gone.
+pub(super) fn tick_name_tags(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    camera: Query<(&Camera, &GlobalTransform), With<SceneCamera>>,
+    players: Query<(&GlobalTransform, &RemotePlayerState, &InheritedVisibility)>,
+    mut tags: Query<(
+        Entity,
+        &NameTag,
+        &ComputedNode,
+        &mut Node,
+        &mut Text,
+        &mut TextColor,
+    )>,
+) {
+    let camera = camera.single().ok();
+    for (entity, tag, computed, mut node, mut text, mut color) in &mut tags {
+        let Ok((player_tf, state, visibility)) = players.get(tag.target) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        if text.0 != state.name {
+            text.0.clone_from(&state.name);
+        }
+
+        let world_pos = player_tf.translation() + Vec3::Y * NAME_TAG_HEIGHT;
+        let screen_pos = camera
+            .filter(|_| settings.show_names && visibility.get())
+            .and_then(|(cam, cam_tf)| {
+                let distance = cam_tf.translation().distance(world_pos);
+                let pos = cam.world_to_viewport(cam_tf, world_pos).ok()?;
+                Some((pos, distance))
+            })
+            .filter(|(_, distance)| *distance < FADE_END);
+        let Some((screen_pos, distance)) = screen_pos else {
+            node.left = Val::Px(-9999.0);
+            node.top = Val::Px(-9999.0);
+            continue;
+        };
+
+        // ComputedNode is in physical pixels, the viewport position isn't
+        let size = computed.size() * computed.inverse_scale_factor();
+        node.left = Val::Px(screen_pos.x - size.x / 2.0);
+        node.top = Val::Px(screen_pos.y - size.y);
+
+        let alpha = 1.0 - ((distance - FADE_START) / (FADE_END - FADE_START)).clamp(0.0, 1.0);
+        color.0 = NEUTRAL10.with_alpha(alpha);
+    }
+}
@@ -0,0 +1,128 @@
+//! Network statistics: reducer calls out, table rows in, and what the
+//! reconciler is tracking, summed into [`NetStats`] once a second for the dev
+//! overlay. Counting happens in SDK callbacks, off the ECS, so the raw
+//! counters are atomics shared with the connection.
+
+use bevy::prelude::*;
+use spacetimedb_sdk::TableWithPrimaryKey;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+
+use super::generated::active_effect_table::ActiveEffectTableAccess;
+use super::generated::chat_message_table::ChatMessageTableAccess;
+use super::generated::combat_event_table::CombatEventTableAccess;
+use super::generated::enemy_table::EnemyTableAccess;
+use super::generated::loot_drop_table::LootDropTableAccess;
+use super::generated::ping_result_table::PingResultTableAccess;
+use super::generated::player_table::PlayerTableAccess;
+use super::generated::telegraph_table::TelegraphTableAccess;
+use super::generated::xp_event_table::XpEventTableAccess;
+use super::reconcile::ServerId;
+use super::{PingTracker, SpacetimeDbConnection};
+
+/// How often [`NetStats`] is recomputed.
+const WINDOW_SECS: f32 = 1.0;
+
+// =============================================================================
+// Resources
+// =============================================================================
+
+/// Traffic over the last second. Read by the dev overlay.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct NetStats {
+    pub calls_per_sec: f32,
+    pub rows_per_sec: f32,
+    /// Rough inbound volume: each row counts as its in-memory size.
+    pub bytes_per_sec: f32,
+    pub rtt_ms: f32,
+    pub jitter_ms: f32,
+    pub players: usize,
+    pub enemies: usize,
+}
+
+/// Raw counts since the last window, bumped from SDK callbacks.
+#[derive(Debug, Default)]
+pub struct NetCounters {
+    calls: AtomicU32,
+    rows: AtomicU32,
+    bytes: AtomicU64,
+}
+
+impl NetCounters {
+    pub(super) fn count_call(&self) {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn count_row(&self, bytes: u64) {
+        self.rows.fetch_add(1, Ordering::Relaxed);
+        self.bytes.fetch_add(bytes, Ordering::Relaxed);
+    }
+}
+
+// =============================================================================
+// Systems
+// =============================================================================
+
+/// Count every insert, update and delete `table` receives.
+fn count_rows<T: TableWithPrimaryKey>(table: T, counters: &Arc<NetCounters>) {
+    let bytes = std::mem::size_of::<T::Row>() as u64;
+    let on_insert = counters.clone();
+    table.on_insert(move |_, _| on_insert.count_row(bytes));
+    let on_update = counters.clone();
+    table.on_update(move |_, _, _| on_update.count_row(bytes));
+    let on_delete = counters.clone();
+    table.on_delete(move |_, _| on_delete.count_row(bytes));
+}
+
+/// Hook the busy world tables on a new connection. Callbacks go away with
+/// the connection, so each one gets its own.
+pub(super) fn count_inbound_rows(conn: Res<SpacetimeDbConnection>) {
+    let db = &conn.conn.db;
+    let counters = &conn.counters;
+    count_rows(db.player(), counters);
+    count_rows(db.enemy(), counters);
+    count_rows(db.combat_event(), counters);
+    count_rows(db.active_effect(), counters);
+    count_rows(db.telegraph(), counters);
+    count_rows(db.loot_drop(), counters);
+    count_rows(db.xp_event(), counters);
+    count_rows(db.chat_message(), counters);
+    count_rows(db.ping_result(), counters);
+}
+
+pub(super) fn update_net_stats(
+    time: Res<Time<Real>>,
+    conn: Res<SpacetimeDbConnection>,
+    ping: Res<PingTracker>,
+    entities: Query<&ServerId>,
+    mut stats: ResMut<NetStats>,
+    mut elapsed: Local<f32>,
+) {
+    *elapsed += time.delta_secs();
+    if *elapsed < WINDOW_SECS {
+        return;
+    }
+    let window = std::mem::take(&mut *elapsed);
+    let counters = &conn.counters;
+    let calls = counters.calls.swap(0, Ordering::Relaxed);
+    let rows = counters.rows.swap(0, Ordering::Relaxed);
+    let bytes = counters.bytes.swap(0, Ordering::Relaxed);
+
+    let (players, enemies) = entities.iter().fold((0, 0), |(p, e), id| match id {
+        ServerId::Player(_) => (p + 1, e),
+        ServerId::Enemy(_) => (p, e + 1),
+    });
+    *stats = NetStats {
+        calls_per_sec: calls as f32 / window,
+        rows_per_sec: rows as f32 / window,
+        bytes_per_sec: bytes as f32 / window,
+        rtt_ms: ping.smoothed_rtt_ms,
+        jitter_ms: ping.jitter_ms,
+        players,
+        enemies,
+    };
+}
+
+pub(super) fn clear_net_stats(mut stats: ResMut<NetStats>) {
+    *stats = NetStats::default();
+}
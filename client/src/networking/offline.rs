@@ -0,0 +1,180 @@
+//! Offline solo: the game runs entirely in-process with no SpacetimeDB at all.
+//!
+//! Used when the local server can't be started (or the player chooses it on
+//! the Connecting screen). Enemies are plain client-owned entities — the
+//! damage and death observers already handle entities without a
+//! [`ServerId`](super::ServerId) — and a small tick here stands in for the
+//! server's `game_tick`, driven by the same shared AI decision.
+
+use avian3d::prelude::LinearVelocity;
+use bevy::prelude::*;
+use wasm_fantasia_shared::combat::{EnemyBehaviorKind, defaults, enemy_ai_decision};
+use wasm_fantasia_shared::variation::EnemyVariation;
+
+use super::GameServer;
+use crate::combat::{Combatant, Enemy, EnemyArchetype, EnemyBehavior, EnemyLook, Health, Shield};
+use crate::models::{Player, Screen, ServerTarget};
+use crate::rules::{Stat, Stats};
+
+/// Archetype offline packs spawn with, matching the server's `spawn_pack`.
+const OFFLINE_ARCHETYPE: &str = "basic";
+const PACK_SIZE_MIN: u32 = 80;
+const PACK_SIZE_SPREAD: u32 = 41;
+/// Height of an enemy's origin when its capsule stands on the arena floor.
+/// Offline enemies are kinematic, so they don't settle there on their own.
+const ENEMY_STANDING_Y: f32 = 1.0;
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        tick_offline_enemies.run_if(in_state(Screen::Gameplay).and(is_offline)),
+    )
+    .add_systems(
+        OnExit(Screen::Gameplay),
+        remove_offline_target.run_if(is_offline.and(not(in_state(Screen::Summary)))),
+    );
+}
+
+/// Run condition: the session is playing without a server.
+pub fn is_offline(target: Option<Res<ServerTarget>>) -> bool {
+    target.is_some_and(|t| *t == ServerTarget::Offline)
+}
+
+/// Switch the current session to offline play.
+pub fn go_offline(commands: &mut Commands) {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        commands.remove_resource::<super::local_server::LocalServer>();
+        commands.remove_resource::<super::local_server::LocalServerState>();
+    }
+    commands.remove_resource::<super::SpacetimeDbConnection>();
+    commands.insert_resource(ServerTarget::Offline);
+}
+
+fn remove_offline_target(mut commands: Commands) {
+    commands.remove_resource::<ServerTarget>();
+}
+
+/// Local enemy AI state the server keeps in its `enemy` row.
+#[derive(Component, Default)]
+pub struct OfflineEnemy {
+    /// Seconds since the last attack started.
+    since_attack: f32,
+}
+
+/// Scatter a pack of enemies in a ring around `center`.
+fn spawn_pack(commands: &mut Commands, center: Vec3, seed: u64) {
+    let count = PACK_SIZE_MIN + (seed % PACK_SIZE_SPREAD as u64) as u32;
+    for i in 0..count {
+        let h = (seed ^ 0xDEADBEEF)
+            .wrapping_add(i as u64)
+            .wrapping_mul(6364136223846793005);
+        let angle = (h & 0xFFFF) as f32 / 65535.0 * std::f32::consts::TAU;
+        let radius = defaults::ENEMY_SPAWN_RADIUS_MIN
+            + ((h >> 16) & 0xFFFF) as f32 / 65535.0
+                * (defaults::ENEMY_SPAWN_RADIUS_MAX - defaults::ENEMY_SPAWN_RADIUS_MIN);
+        let position = Vec3::new(
+            center.x + angle.cos() * radius,
+            ENEMY_STANDING_Y,
+            center.z + angle.sin() * radius,
+        );
+
+        // On<Add, Enemy> attaches the model and collider
+        commands.spawn((
+            Name::new(format!("OfflineEnemy_{i}")),
+            DespawnOnExit(Screen::Gameplay),
+            Transform::from_translation(position),
+            Health::new(defaults::ENEMY_HEALTH),
+            Shield::new(defaults::ENEMY_SHIELD),
+            EnemyArchetype(OFFLINE_ARCHETYPE.to_string()),
+            EnemyLook(EnemyVariation::from_id(h)),
+            Enemy,
+            Combatant,
+            OfflineEnemy {
+                // Stagger the first swings so the pack doesn't attack in unison
+                since_attack: ((h >> 32) & 0xFFFF) as f32 / 65535.0
+                    * defaults::ENEMY_ATTACK_COOLDOWN,
+            },
+            Stats::new()
+                .with(Stat::MaxHealth, defaults::ENEMY_HEALTH)
+                .with(Stat::Health, defaults::ENEMY_HEALTH)
+                .with(Stat::MaxShield, defaults::ENEMY_SHIELD)
+                .with(Stat::Shield, defaults::ENEMY_SHIELD)
+                .labeled("basic"),
+        ));
+    }
+}
+
+/// The [`GameServer`] of an offline session: requests are carried out
+/// right here. Pausing needs nothing — virtual time already stops the tick.
+pub struct OfflineServer;
+
+impl GameServer for OfflineServer {
+    fn spawn_enemies(&self, commands: &mut Commands, position: Vec3, _forward: Vec3) {
+        spawn_pack(commands, position, rand::random());
+    }
+
+    fn clear_enemies(&self, commands: &mut Commands) {
+        commands.queue(|world: &mut World| {
+            let enemies: Vec<Entity> = world
+                .query_filtered::<Entity, With<OfflineEnemy>>()
+                .iter(world)
+                .collect();
+            for entity in enemies {
+                world.despawn(entity);
+            }
+        });
+    }
+
+    fn set_paused(&self, _paused: bool) {}
+}
+
+/// In-process counterpart of the server's enemy tick: chase the player,
+/// face them and swing on cooldown.
+fn tick_offline_enemies(
+    time: Res<Time>,
+    player: Query<&Transform, (With<Player>, Without<OfflineEnemy>)>,
+    mut enemies: Query<(
+        &mut Transform,
+        &mut LinearVelocity,
+        &mut EnemyBehavior,
+        &mut OfflineEnemy,
+    )>,
+) {
+    let Ok(player) = player.single() else {
+        return;
+    };
+    let dt = time.delta_secs();
+
+    for (mut transform, mut velocity, mut behavior, mut enemy) in &mut enemies {
+        enemy.since_attack += dt;
+
+        let to_player = (player.translation - transform.translation).with_y(0.0);
+        let distance = to_player.length();
+        let cooldown_ready = enemy.since_attack >= defaults::ENEMY_ATTACK_COOLDOWN;
+        let decision = enemy_ai_decision(distance, cooldown_ready);
+
+        if decision != EnemyBehaviorKind::Idle && distance > 0.01 {
+            transform.rotation = Quat::from_rotation_y(f32::atan2(-to_player.x, -to_player.z));
+        }
+
+        let chase = if decision == EnemyBehaviorKind::Chase && distance > 0.01 {
+            to_player / distance * defaults::ENEMY_WALK_SPEED
+        } else {
+            Vec3::ZERO
+        };
+        velocity.x = chase.x;
+        velocity.z = chase.z;
+
+        if decision == EnemyBehaviorKind::Attack {
+            enemy.since_attack = 0.0;
+        }
+
+        let next = match decision {
+            EnemyBehaviorKind::Idle => EnemyBehavior::Idle,
+            EnemyBehaviorKind::Chase => EnemyBehavior::Chase,
+            EnemyBehaviorKind::Attack => EnemyBehavior::Attack,
+        };
+        behavior.set_if_neq(next);
+    }
+}
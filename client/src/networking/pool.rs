@@ -0,0 +1,121 @@
+//! Recycling of server-mirrored entities. A big fight churns through
+//! hundreds of enemies, each a model, collider and body; instead of being
+//! despawned when its row goes away, an enemy ends its exit effect hidden and
+//! parked here, and the next enemy of the same archetype takes it over.
+//! Combat event entities are recycled the same way: parked when the next
+//! batch arrives or after [`COMBAT_EVENT_TTL_SECS`], whichever is first.
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use super::reconcile::CombatEventData;
+
+/// Enemies parked beyond this are despawned instead.
+const MAX_PARKED_ENEMIES: usize = 256;
+/// Long enough for anything spawned off a combat event to have read it.
+const COMBAT_EVENT_TTL_SECS: f32 = 1.0;
+
+// =============================================================================
+// Components
+// =============================================================================
+
+/// On enemies whose exit effect ends in the [`EntityPool`] rather than a
+/// despawn.
+#[derive(Component, Debug)]
+pub(super) struct Pooled;
+
+/// On a hidden entity waiting in the [`EntityPool`].
+#[derive(Component, Debug)]
+#[component(storage = "SparseSet")]
+pub(super) struct Parked;
+
+/// Time left before a combat event entity is parked, so a quiet spell after
+/// a fight doesn't leave its last batch around.
+#[derive(Component, Debug)]
+pub(super) struct CombatEventTtl {
+    remaining: f32,
+}
+
+impl Default for CombatEventTtl {
+    fn default() -> Self {
+        Self {
+            remaining: COMBAT_EVENT_TTL_SECS,
+        }
+    }
+}
+
+// =============================================================================
+// Resources
+// =============================================================================
+
+/// Parked entities ready for reuse. Enemies are kept by archetype so a
+/// reused one already has the right model.
+#[derive(Resource, Default)]
+pub(super) struct EntityPool {
+    enemies: HashMap<String, Vec<Entity>>,
+    parked_enemies: usize,
+    combat_events: Vec<Entity>,
+}
+
+impl EntityPool {
+    /// Hide an enemy that has played its exit effect, or despawn it if the
+    /// pool is full.
+    pub(super) fn park_enemy(&mut self, commands: &mut Commands, entity: Entity, archetype: &str) {
+        if self.parked_enemies >= MAX_PARKED_ENEMIES {
+            commands.entity(entity).despawn();
+            return;
+        }
+        commands.entity(entity).insert((Parked, Visibility::Hidden));
+        self.enemies
+            .entry(archetype.to_string())
+            .or_default()
+            .push(entity);
+        self.parked_enemies += 1;
+    }
+
+    /// A parked enemy of `archetype`, out of the pool. The caller gives it
+    /// its components back and takes off [`Parked`].
+    pub(super) fn take_enemy(&mut self, archetype: &str) -> Option<Entity> {
+        let entity = self.enemies.get_mut(archetype)?.pop()?;
+        self.parked_enemies -= 1;
+        Some(entity)
+    }
+
+    /// Park a combat event entity; its data is removed so the next one
+    /// written to it is seen as added.
+    pub(super) fn park_combat_event(&mut self, commands: &mut Commands, entity: Entity) {
+        commands
+            .entity(entity)
+            .remove::<(CombatEventData, CombatEventTtl)>()
+            .insert(Parked);
+        self.combat_events.push(entity);
+    }
+
+    pub(super) fn take_combat_event(&mut self) -> Option<Entity> {
+        self.combat_events.pop()
+    }
+}
+
+// =============================================================================
+// Systems
+// =============================================================================
+
+/// Park combat event entities whose time is up.
+pub(super) fn expire_combat_events(
+    time: Res<Time>,
+    mut events: Query<(Entity, &mut CombatEventTtl), With<CombatEventData>>,
+    mut pool: ResMut<EntityPool>,
+    mut commands: Commands,
+) {
+    for (entity, mut ttl) in &mut events {
+        ttl.remaining -= time.delta_secs();
+        if ttl.remaining <= 0.0 {
+            pool.park_combat_event(&mut commands, entity);
+        }
+    }
+}
+
+/// Parked entities go with the rest of gameplay's, so the pool forgets them.
+pub(super) fn clear_entity_pool(mut pool: ResMut<EntityPool>) {
+    *pool = EntityPool::default();
+}
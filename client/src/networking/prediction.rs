@@ -0,0 +1,300 @@
+//! Client-side prediction and its reconciliation with the server.
+//!
+//! The local player moves without waiting for the server. Each
+//! `update_position` carries a sequence number from [`InputSequence`], and
+//! the player row echoes the newest one applied, so the client can compare
+//! the row against the position it sent with that number. Where the server
+//! put the player somewhere else (clamped at the arena edge, say), the
+//! difference is blended into the local transform, or snapped when large.
+//!
+//! Local hits on server-owned enemies take effect at once as
+//! [`PredictedDamage`]; the server's `combat_event` for a hit confirms it,
+//! and the enemy row already carries the real damage by then. Reducer calls
+//! from one client run in order, so once the server has acknowledged a
+//! position sent after a hit, a hit still unconfirmed was rejected and is
+//! rolled back. A timeout catches whatever the acknowledgements miss.
+
+use avian3d::prelude::LinearVelocity;
+use bevy::prelude::*;
+use spacetimedb_sdk::{DbContext, Table};
+use std::collections::{HashMap, VecDeque};
+
+use super::SpacetimeDbConnection;
+use super::combat::AttackIntents;
+use super::generated::Player;
+use super::generated::combat_event_table::CombatEventTableAccess;
+use super::generated::player_table::PlayerTableAccess;
+use super::reconcile::{ServerId, reconcile};
+use crate::combat::PredictedDamage;
+use crate::models::Player as LocalPlayer;
+
+/// How long a predicted hit waits for the server before it's rolled back.
+const PREDICTION_TIMEOUT_SECS: f32 = 1.0;
+/// Disagreements smaller than this are float noise, not corrections.
+const RECONCILE_THRESHOLD: f32 = 0.25;
+/// Corrections longer than this are applied at once instead of blended.
+const SNAP_DISTANCE: f32 = 4.0;
+/// Fraction of the remaining correction blended in per second.
+const CORRECTION_SPEED: f32 = 10.0;
+/// Sent positions kept while waiting for the server to acknowledge them,
+/// a few seconds' worth at the position sync rate.
+const HISTORY_LEN: usize = 64;
+
+// =============================================================================
+// Resources
+// =============================================================================
+
+/// Numbers the local player's `update_position` calls. Starts again at 1 on
+/// every connection, as does the server's echo of it.
+#[derive(Resource, Debug)]
+pub struct InputSequence {
+    next: u32,
+}
+
+impl Default for InputSequence {
+    fn default() -> Self {
+        Self { next: 1 }
+    }
+}
+
+impl InputSequence {
+    /// Number the next `update_position` will carry. The server handles
+    /// anything sent before then first.
+    pub fn upcoming(&self) -> u32 {
+        self.next
+    }
+
+    pub(super) fn advance(&mut self) -> u32 {
+        let seq = self.next;
+        self.next += 1;
+        seq
+    }
+}
+
+/// Positions sent with each `update_position` that the server hasn't
+/// acknowledged yet, and the correction still being blended in.
+#[derive(Resource, Debug, Default)]
+pub struct PositionHistory {
+    sent: VecDeque<(u32, Vec3)>,
+    /// Acknowledgements of updates before this were sent before the last
+    /// correction, so they'd report the same disagreement again.
+    ignore_before: u32,
+    /// Sequence number and position of the row last compared, so an
+    /// unchanged row isn't compared twice.
+    last_checked: Option<(u32, Vec3)>,
+    pending: Vec3,
+}
+
+impl PositionHistory {
+    pub(super) fn record(&mut self, seq: u32, position: Vec3) {
+        if self.sent.len() == HISTORY_LEN {
+            self.sent.pop_front();
+        }
+        self.sent.push_back((seq, position));
+    }
+
+    /// Forget what was sent so far. Called whenever the local player is moved
+    /// outright (restored, respawned, teleported), so the jump isn't also
+    /// read as a disagreement and applied a second time.
+    pub(super) fn restart(&mut self, inputs: &InputSequence) {
+        self.sent.clear();
+        self.ignore_before = inputs.upcoming();
+        self.pending = Vec3::ZERO;
+    }
+}
+
+// =============================================================================
+// Plugin
+// =============================================================================
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<InputSequence>()
+        .init_resource::<PositionHistory>()
+        .add_systems(
+            Update,
+            (
+                reset_prediction.run_if(resource_added::<SpacetimeDbConnection>),
+                (
+                    confirm_predicted_hits,
+                    drop_rejected_hits,
+                    expire_predicted_hits,
+                )
+                    .chain()
+                    .before(reconcile)
+                    .run_if(resource_exists::<SpacetimeDbConnection>),
+                (reconcile_local_position, blend_position_correction)
+                    .chain()
+                    .run_if(resource_exists::<SpacetimeDbConnection>),
+            ),
+        );
+}
+
+// =============================================================================
+// Systems
+// =============================================================================
+
+/// A new connection is a new session: the server numbers from the start too.
+fn reset_prediction(mut inputs: ResMut<InputSequence>, mut history: ResMut<PositionHistory>) {
+    *inputs = InputSequence::default();
+    *history = PositionHistory::default();
+}
+
+fn my_row(conn: &SpacetimeDbConnection) -> Option<Player> {
+    let identity = conn.conn.try_identity()?;
+    conn.conn.db.player().identity().find(&identity)
+}
+
+/// Compare the newest acknowledged update with what was sent in it, and
+/// start correcting toward the server's position if they disagree.
+fn reconcile_local_position(
+    conn: Res<SpacetimeDbConnection>,
+    inputs: Res<InputSequence>,
+    mut history: ResMut<PositionHistory>,
+    mut query: Query<(&mut Transform, &mut LinearVelocity), With<LocalPlayer>>,
+) {
+    let Some(row) = my_row(&conn) else {
+        return;
+    };
+    let acked = row.input_seq;
+    if acked == 0 || acked < history.ignore_before {
+        return;
+    }
+    let server = Vec3::new(row.x, row.y, row.z);
+    if history.last_checked == Some((acked, server)) {
+        return;
+    }
+    history.last_checked = Some((acked, server));
+
+    while history.sent.front().is_some_and(|&(seq, _)| seq < acked) {
+        history.sent.pop_front();
+    }
+    let Some(&(_, sent)) = history.sent.front().filter(|&&(seq, _)| seq == acked) else {
+        return;
+    };
+    let error = server - sent;
+    if error.length() <= RECONCILE_THRESHOLD {
+        return;
+    }
+    let Ok((mut transform, mut velocity)) = query.single_mut() else {
+        return;
+    };
+
+    debug!(
+        "Server moved us {:.2}m at input {acked}; correcting",
+        error.length()
+    );
+    // Updates already in flight were sent from the uncorrected position
+    history.restart(&inputs);
+    if error.length() > SNAP_DISTANCE {
+        transform.translation += error;
+        velocity.0 = Vec3::ZERO;
+    } else {
+        history.pending = error;
+    }
+}
+
+/// Work the pending correction into the local player a little each frame.
+fn blend_position_correction(
+    time: Res<Time>,
+    mut history: ResMut<PositionHistory>,
+    mut query: Query<&mut Transform, With<LocalPlayer>>,
+) {
+    if history.pending == Vec3::ZERO {
+        return;
+    }
+    let Ok(mut transform) = query.single_mut() else {
+        return;
+    };
+    let step = history.pending * (time.delta_secs() * CORRECTION_SPEED).min(1.0);
+    transform.translation += step;
+    history.pending -= step;
+    if history.pending.length() < 0.01 {
+        transform.translation += history.pending;
+        history.pending = Vec3::ZERO;
+    }
+}
+
+/// Drop one predicted hit per `combat_event` we caused on an enemy, oldest
+/// first. Runs before the reconciler so the server's damage and our
+/// prediction of it are never both applied.
+fn confirm_predicted_hits(
+    conn: Res<SpacetimeDbConnection>,
+    mut last_seen: Local<u64>,
+    mut enemies: Query<(&ServerId, &mut PredictedDamage)>,
+) {
+    let Some(my_id) = conn.conn.try_identity() else {
+        return;
+    };
+    let mut confirmed: HashMap<u64, usize> = HashMap::new();
+    for event in conn.conn.db.combat_event().iter() {
+        if event.id <= *last_seen {
+            continue;
+        }
+        if event.attacker == my_id && event.target_player.is_none() {
+            *confirmed.entry(event.target_id).or_default() += 1;
+        }
+    }
+    if let Some(newest) = conn.conn.db.combat_event().iter().map(|e| e.id).max() {
+        *last_seen = last_seen.max(newest);
+    }
+    if confirmed.is_empty() {
+        return;
+    }
+
+    for (id, mut predicted) in &mut enemies {
+        let ServerId::Enemy(enemy_id) = id else {
+            continue;
+        };
+        if let Some(&count) = confirmed.get(enemy_id) {
+            let count = count.min(predicted.hits.len());
+            predicted.hits.drain(..count);
+        }
+    }
+}
+
+/// Roll back hits sent before an update the server has acknowledged: the
+/// server handled them first, so any it counted are confirmed by now. Not
+/// while an attack is being resent, since the resend arrives after updates
+/// sent later.
+fn drop_rejected_hits(
+    conn: Res<SpacetimeDbConnection>,
+    intents: Res<AttackIntents>,
+    mut enemies: Query<&mut PredictedDamage>,
+) {
+    if intents.in_flight() {
+        return;
+    }
+    let Some(acked) = my_row(&conn).map(|row| row.input_seq) else {
+        return;
+    };
+    for mut predicted in &mut enemies {
+        let before = predicted.hits.len();
+        predicted.hits.retain(|hit| hit.seq >= acked);
+        if predicted.hits.len() < before {
+            debug!("Server rejected a predicted hit; rolling it back");
+        }
+    }
+}
+
+/// Roll back hits the server hasn't confirmed in time — it rejected them
+/// (out of range, on cooldown) or resolved them differently. The reconciler
+/// then shows the server's values again.
+fn expire_predicted_hits(time: Res<Time>, mut enemies: Query<&mut PredictedDamage>) {
+    let dt = time.delta_secs();
+    for mut predicted in &mut enemies {
+        if predicted.hits.is_empty() {
+            continue;
+        }
+        for hit in predicted.hits.iter_mut() {
+            hit.age += dt;
+        }
+        while predicted
+            .hits
+            .front()
+            .is_some_and(|hit| hit.age > PREDICTION_TIMEOUT_SECS)
+        {
+            predicted.hits.pop_front();
+            debug!("Server didn't confirm a predicted hit; rolling it back");
+        }
+    }
+}
@@ -0,0 +1,93 @@
+//! Connection quality: judged from the [`PingTracker`]'s round trip, jitter
+//! and loss. A degraded link sends positions less often (see
+//! [`DEGRADED_SYNC_RATE_HZ`]) and drops the cosmetic world tables from its
+//! subscription (see `connection::follow_world`), until it has been healthy
+//! again for a while.
+
+use bevy::prelude::*;
+
+use super::SpacetimeDbConnection;
+use super::sync::{PingTracker, measure_ping};
+
+/// Position sync rate cap while degraded.
+pub const DEGRADED_SYNC_RATE_HZ: f32 = 10.0;
+/// Any of these past their limit degrades the connection...
+const DEGRADE_RTT_MS: f32 = 250.0;
+const DEGRADE_JITTER_MS: f32 = 60.0;
+const DEGRADE_LOSS: f32 = 0.15;
+/// ...and all of them back under these for [`RECOVER_SECS`] restores it, so
+/// a link hovering at the limit doesn't flip back and forth.
+const RECOVER_RTT_MS: f32 = 180.0;
+const RECOVER_JITTER_MS: f32 = 40.0;
+const RECOVER_LOSS: f32 = 0.05;
+const RECOVER_SECS: f32 = 5.0;
+
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionQuality {
+    #[default]
+    Good,
+    Degraded,
+}
+
+impl ConnectionQuality {
+    pub fn is_degraded(self) -> bool {
+        self == Self::Degraded
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<ConnectionQuality>().add_systems(
+        Update,
+        (
+            reset_quality.run_if(resource_added::<SpacetimeDbConnection>),
+            assess_quality.after(measure_ping),
+        )
+            .chain()
+            .run_if(resource_exists::<SpacetimeDbConnection>),
+    );
+}
+
+/// A new connection starts out trusted.
+fn reset_quality(mut quality: ResMut<ConnectionQuality>) {
+    quality.set_if_neq(ConnectionQuality::Good);
+}
+
+fn assess_quality(
+    time: Res<Time>,
+    tracker: Res<PingTracker>,
+    mut quality: ResMut<ConnectionQuality>,
+    mut healthy_for: Local<f32>,
+) {
+    // Nothing measured yet
+    if tracker.smoothed_rtt_ms <= 0.0 {
+        return;
+    }
+    let poor = tracker.smoothed_rtt_ms > DEGRADE_RTT_MS
+        || tracker.jitter_ms > DEGRADE_JITTER_MS
+        || tracker.loss > DEGRADE_LOSS;
+    let healthy = tracker.smoothed_rtt_ms < RECOVER_RTT_MS
+        && tracker.jitter_ms < RECOVER_JITTER_MS
+        && tracker.loss < RECOVER_LOSS;
+    *healthy_for = if healthy {
+        *healthy_for + time.delta_secs()
+    } else {
+        0.0
+    };
+
+    match *quality {
+        ConnectionQuality::Good if poor => {
+            info!(
+                "Connection degraded ({:.0} ms, {:.0} ms jitter, {:.0}% loss)",
+                tracker.smoothed_rtt_ms,
+                tracker.jitter_ms,
+                tracker.loss * 100.0
+            );
+            *quality = ConnectionQuality::Degraded;
+        }
+        ConnectionQuality::Degraded if *healthy_for >= RECOVER_SECS => {
+            info!("Connection recovered");
+            *quality = ConnectionQuality::Good;
+        }
+        _ => {}
+    }
+}
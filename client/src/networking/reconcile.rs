@@ -1,19 +1,43 @@
-//! Server→client entity reconciliation: diffs SpacetimeDB cache against ECS.
+//! Server→client entity reconciliation: table callbacks queue the player and
+//! enemy rows that changed, and [`reconcile`] applies them to the ECS.
 
+use avian3d::prelude::LinearVelocity;
 use bevy::prelude::*;
-use std::collections::HashSet;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 
-use spacetimedb_sdk::{DbContext, Table};
-use wasm_fantasia_shared::combat::EnemyBehaviorKind;
+use spacetimedb_sdk::{DbContext, Table, TableWithPrimaryKey};
+use wasm_fantasia_shared::combat::{EnemyBehaviorKind, HitReaction, life_events};
+use wasm_fantasia_shared::effects::EffectKind;
+use wasm_fantasia_shared::items::{self, Item};
+use wasm_fantasia_shared::net::AnimationId;
+use wasm_fantasia_shared::presets::Loadout;
+use wasm_fantasia_shared::resources;
+use wasm_fantasia_shared::variation::EnemyVariation;
+use wasm_fantasia_shared::weapons::Weapon;
 
 use super::SpacetimeDbConnection;
+use super::clock::ServerClock;
+use super::despawn::{ExitEffect, despawn_with};
+use super::generated::active_effect_table::ActiveEffectTableAccess;
 use super::generated::combat_event_table::CombatEventTableAccess;
 use super::generated::enemy_table::EnemyTableAccess;
+use super::generated::equipped_item_table::EquippedItemTableAccess;
+use super::generated::player_loadout_table::PlayerLoadoutTableAccess;
 use super::generated::player_table::PlayerTableAccess;
-use crate::combat::{Combatant, Enemy, EnemyBehavior, Health};
-use crate::models::Player as LocalPlayer;
+use super::generated::{CombatEvent, Enemy as EnemyRow, Player};
+use super::name_tags::name_tag;
+use super::pool::{CombatEventTtl, EntityPool, Parked, Pooled};
+use super::prediction::{InputSequence, PositionHistory};
+use crate::combat::{
+    Combatant, Enemy, EnemyArchetype, EnemyBehavior, EnemyLook, Health, HitReacted,
+    PendingKnockback, PredictedDamage, Shield,
+};
+use crate::models::{
+    Equipment, EquippedWeapon, Player as LocalPlayer, PlayerLevel, PvpEnabled, SceneCamera,
+};
 use crate::player::RemotePlayer;
-use crate::rules::{Stat, Stats};
+use crate::rules::{Auras, Stat, Stats, StatusEffects};
 
 // =============================================================================
 // Components
@@ -76,6 +100,7 @@ pub struct CombatStats {
 pub struct CombatEventData {
     pub damage: f32,
     pub is_crit: bool,
+    pub reaction: HitReaction,
     pub x: f32,
     pub y: f32,
     pub z: f32,
@@ -84,211 +109,698 @@ pub struct CombatEventData {
 /// Server-synced animation state for remote players.
 #[derive(Component, Clone, Debug, Default)]
 pub struct RemotePlayerState {
-    pub animation_state: String,
+    /// Shown on the player's name tag.
+    pub name: String,
+    pub animation: AnimationId,
     pub attack_sequence: u32,
-    pub attack_animation: String,
+    pub attack_animation: AnimationId,
 }
 
 // =============================================================================
 // Resources
 // =============================================================================
 
+/// Player and enemy rows changed since the last [`reconcile`], latest state
+/// per row; `None` means the row is gone (or the player went offline).
+/// Filled from SDK table callbacks, off the ECS.
+#[derive(Resource, Default, Clone)]
+pub(super) struct RowChanges(Arc<Mutex<RowQueue>>);
+
+impl RowChanges {
+    pub(super) fn record(&self, id: ServerId, row: Option<EntityRow>) {
+        self.0.lock().unwrap().rows.insert(id, row);
+    }
+
+    /// Drop everything queued, including a pending resync.
+    pub(super) fn clear(&self) {
+        let mut queue = self.0.lock().unwrap();
+        queue.rows.clear();
+        queue.resync = false;
+    }
+}
+
+#[derive(Default)]
+struct RowQueue {
+    rows: HashMap<ServerId, Option<EntityRow>>,
+    /// Diff the whole cache next time, not just `rows`.
+    resync: bool,
+}
+
+/// What the reconciler reads from a `player` or `enemy` row.
+pub(super) struct EntityRow {
+    world: WorldEntity,
+    health: f32,
+    max_health: f32,
+    shield: f32,
+    max_shield: f32,
+    animation: AnimationId,
+    attack_sequence: u32,
+    attack_animation: AnimationId,
+    behavior: EnemyBehaviorKind,
+    enemy_type: String,
+    name: String,
+}
+
+/// Offline players aren't drawn.
+pub(super) fn player_row(p: &Player) -> Option<EntityRow> {
+    p.online.then(|| EntityRow {
+        world: WorldEntity {
+            x: p.x,
+            y: p.y,
+            z: p.z,
+            rotation_y: p.rotation_y,
+            velocity_x: 0.0,
+            velocity_y: 0.0,
+            velocity_z: 0.0,
+        },
+        health: p.health,
+        max_health: p.max_health,
+        shield: p.shield,
+        max_shield: p.max_shield,
+        animation: AnimationId::from_u8(p.animation),
+        attack_sequence: p.attack_sequence,
+        attack_animation: AnimationId::from_u8(p.attack_animation),
+        behavior: EnemyBehaviorKind::Idle,
+        enemy_type: String::new(),
+        name: p.name.clone().unwrap_or_else(|| "Player".to_string()),
+    })
+}
+
+pub(super) fn enemy_row(e: &EnemyRow) -> EntityRow {
+    EntityRow {
+        world: WorldEntity {
+            x: e.x,
+            y: e.y,
+            z: e.z,
+            rotation_y: e.rotation_y,
+            velocity_x: e.velocity_x,
+            velocity_y: e.velocity_y,
+            velocity_z: e.velocity_z,
+        },
+        health: e.health,
+        max_health: e.max_health,
+        shield: e.shield,
+        max_shield: e.max_shield,
+        animation: AnimationId::None,
+        attack_sequence: 0,
+        attack_animation: AnimationId::None,
+        behavior: EnemyBehaviorKind::from_u8(e.behavior),
+        enemy_type: e.enemy_type.clone(),
+        name: String::new(),
+    }
+}
+
+fn exit_effect(id: &ServerId) -> ExitEffect {
+    match id {
+        ServerId::Player(_) => ExitEffect::Poof,
+        ServerId::Enemy(_) => ExitEffect::Dissolve,
+    }
+}
+
 /// Tracks which CombatEvent IDs have been processed.
 #[derive(Resource, Default)]
 pub struct CombatEventTracker {
     last_processed_id: u64,
 }
 
+/// Combat events farther than this from the camera spawn no VFX — nobody
+/// can see a fight across the map.
+const COMBAT_EVENT_CULL_DISTANCE: f32 = 60.0;
+/// At most this many combat events spawn VFX per frame. A burst beyond it
+/// (an AOE into a big pack, or catching up after a hitch) is dropped rather
+/// than queued, since late hit effects are worse than missing ones.
+const MAX_COMBAT_EVENTS_PER_FRAME: usize = 32;
+
 // =============================================================================
 // Systems
 // =============================================================================
 
-/// One system that diffs the SpacetimeDB client cache against the ECS each frame.
-/// Spawns, patches, or despawns entities to match server state.
+/// Start watching a new connection's `player` and `enemy` tables. Whatever
+/// was queued for the previous connection is dropped, and the first
+/// [`reconcile`] does a full pass to pick up the new cache.
+pub(super) fn watch_entity_tables(conn: Res<SpacetimeDbConnection>, changes: Res<RowChanges>) {
+    {
+        let mut queue = changes.0.lock().unwrap();
+        queue.rows.clear();
+        queue.resync = true;
+    }
+    let db = &conn.conn.db;
+
+    let queue = changes.clone();
+    db.player()
+        .on_insert(move |_, p| queue.record(ServerId::Player(p.identity), player_row(p)));
+    let queue = changes.clone();
+    db.player()
+        .on_update(move |_, _, p| queue.record(ServerId::Player(p.identity), player_row(p)));
+    let queue = changes.clone();
+    db.player()
+        .on_delete(move |_, p| queue.record(ServerId::Player(p.identity), None));
+
+    let queue = changes.clone();
+    db.enemy()
+        .on_insert(move |_, e| queue.record(ServerId::Enemy(e.id), Some(enemy_row(e))));
+    let queue = changes.clone();
+    db.enemy()
+        .on_update(move |_, _, e| queue.record(ServerId::Enemy(e.id), Some(enemy_row(e))));
+    let queue = changes.clone();
+    db.enemy()
+        .on_delete(move |_, e| queue.record(ServerId::Enemy(e.id), None));
+}
+
+/// Applies the rows [`RowChanges`] collected since last frame to the ECS:
+/// spawns, patches, or queues despawns (see [`despawn_with`]). New enemies
+/// reuse parked ones from the [`EntityPool`] when there are any.
+///
+/// Entities whose rows didn't change are left alone, except that enemies
+/// whose [`PredictedDamage`] moved have their health derived again from the
+/// cached row. A resync (new connection) diffs the whole cache instead, and
+/// despawns entities it no longer has.
+///
+/// Without a connection (replay playback) only the queued rows are applied.
 pub(super) fn reconcile(
-    conn: Res<SpacetimeDbConnection>,
+    conn: Option<Res<SpacetimeDbConnection>>,
+    changes: Res<RowChanges>,
+    mut pool: ResMut<EntityPool>,
     mut remote_entities: Query<
         (
             Entity,
             &ServerId,
             &mut WorldEntity,
             &mut Health,
+            Option<&mut Shield>,
+            Option<Ref<PredictedDamage>>,
             Option<&mut EnemyBehavior>,
             Option<&mut RemotePlayerState>,
         ),
         Without<LocalPlayer>,
     >,
-    mut local_health: Query<(&mut Health, &mut Stats), With<LocalPlayer>>,
-    mut tracker: ResMut<CombatEventTracker>,
+    mut local_player: Query<
+        (
+            Entity,
+            &mut Health,
+            &mut Shield,
+            &mut Stats,
+            &mut PlayerLevel,
+            &mut Equipment,
+            Has<PvpEnabled>,
+        ),
+        With<LocalPlayer>,
+    >,
     mut commands: Commands,
 ) {
-    let my_id = conn.conn.try_identity();
-    let mut seen = HashSet::new();
-
-    // ── Collect all entity tables into one flat list ───
-    struct Row {
-        id: ServerId,
-        world: WorldEntity,
-        health: f32,
-        max_health: f32,
-        animation_state: String,
-        attack_sequence: u32,
-        attack_animation: String,
+    let conn = conn.as_deref();
+    let my_id = conn.and_then(|c| c.conn.try_identity());
+    let (resync, mut rows) = {
+        let mut queue = changes.0.lock().unwrap();
+        (
+            std::mem::take(&mut queue.resync),
+            std::mem::take(&mut queue.rows),
+        )
+    };
+    if let (true, Some(conn)) = (resync, conn) {
+        rows = conn
+            .conn
+            .db
+            .player()
+            .iter()
+            .map(|p| (ServerId::Player(p.identity), player_row(&p)))
+            .chain(
+                conn.conn
+                    .db
+                    .enemy()
+                    .iter()
+                    .map(|e| (ServerId::Enemy(e.id), Some(enemy_row(&e)))),
+            )
+            .collect();
     }
 
-    let rows: Vec<Row> = conn
-        .conn
-        .db
-        .player()
-        .iter()
-        .filter(|p| p.online)
-        .map(|p| Row {
-            id: ServerId::Player(p.identity),
-            world: WorldEntity {
-                x: p.x,
-                y: p.y,
-                z: p.z,
-                rotation_y: p.rotation_y,
-                velocity_x: 0.0,
-                velocity_y: 0.0,
-                velocity_z: 0.0,
-            },
-            health: p.health,
-            max_health: p.max_health,
-            animation_state: p.animation_state.clone(),
-            attack_sequence: p.attack_sequence,
-            attack_animation: p.attack_animation.clone(),
-        })
-        .chain(conn.conn.db.enemy().iter().map(|e| Row {
-            id: ServerId::Enemy(e.id),
-            world: WorldEntity {
-                x: e.x,
-                y: e.y,
-                z: e.z,
-                rotation_y: e.rotation_y,
-                velocity_x: e.velocity_x,
-                velocity_y: e.velocity_y,
-                velocity_z: e.velocity_z,
-            },
-            health: e.health,
-            max_health: e.max_health,
-            animation_state: e.animation_state.clone(),
-            attack_sequence: 0,
-            attack_animation: String::new(),
-        }))
-        .collect();
-
-    // ── Local player: patch health, skip spawning ─────
-    for row in &rows {
-        if let ServerId::Player(identity) = &row.id {
-            if Some(*identity) == my_id {
-                if let Ok((mut health, mut stats)) = local_health.single_mut() {
-                    health.current = row.health;
-                    health.max = row.max_health;
-                    stats.set(Stat::Health, row.health);
-                    stats.set(Stat::MaxHealth, row.max_health);
+    // ── Local player: patch health and shield, skip spawning ─────
+    let me = conn.zip(my_id).and_then(|(conn, id)| {
+        let me = conn.conn.db.player().identity().find(&id)?;
+        me.online.then_some((conn, me))
+    });
+    if let Some((conn, me)) = me {
+        if let Ok((entity, mut health, mut shield, mut stats, level, equipment, pvp)) =
+            local_player.single_mut()
+        {
+            health.current = me.health;
+            health.max = me.max_health;
+            stats.set(Stat::Health, me.health);
+            shield.current = me.shield;
+            shield.max = me.max_shield;
+            stats.set(Stat::Shield, me.shield);
+            stats.set(Stat::MaxShield, me.max_shield);
+            match (me.pvp, pvp) {
+                (true, false) => {
+                    commands.entity(entity).insert(PvpEnabled);
+                }
+                (false, true) => {
+                    commands.entity(entity).remove::<PvpEnabled>();
                 }
-                seen.insert(row.id.clone());
+                _ => {}
+            }
+            rebuild_character_stats(conn, &me, &mut stats, level, equipment);
+            // Only overwrite on disagreement so the breakdown survives
+            if stats.get(&Stat::MaxHealth) != me.max_health {
+                stats.set(Stat::MaxHealth, me.max_health);
             }
         }
     }
+    if let Some(id) = my_id {
+        rows.remove(&ServerId::Player(id));
+    }
 
     // ── Patch or despawn existing remote entities ──────
-    for (bevy_entity, id, mut world_entity, mut health, enemy_behavior, remote_state) in
-        &mut remote_entities
+    for (
+        bevy_entity,
+        id,
+        mut world_entity,
+        mut health,
+        shield,
+        predicted,
+        enemy_behavior,
+        remote_state,
+    ) in &mut remote_entities
     {
-        if let Some(row) = rows.iter().find(|r| &r.id == id) {
-            seen.insert(id.clone());
-            *world_entity = row.world.clone();
-            health.current = row.health;
-            health.max = row.max_health;
-
-            // Patch enemy behavior from server animation_state
-            if let Some(mut behavior) = enemy_behavior {
-                let kind = EnemyBehaviorKind::parse_str(&row.animation_state);
-                let new_behavior = match kind {
-                    EnemyBehaviorKind::Idle => EnemyBehavior::Idle,
-                    EnemyBehaviorKind::Chase => EnemyBehavior::Chase,
-                    EnemyBehaviorKind::Attack => EnemyBehavior::Attack,
-                };
-                if *behavior != new_behavior {
-                    *behavior = new_behavior;
+        let row = match rows.remove(id) {
+            Some(Some(row)) => row,
+            Some(None) => {
+                despawn_with(&mut commands, bevy_entity, exit_effect(id));
+                continue;
+            }
+            None if resync => {
+                despawn_with(&mut commands, bevy_entity, exit_effect(id));
+                continue;
+            }
+            None => {
+                // Unchanged row, but our unconfirmed hits on it may have been
+                // confirmed or rolled back
+                if let (ServerId::Enemy(enemy_id), Some(predicted), Some(conn)) =
+                    (id, &predicted, conn)
+                {
+                    if predicted.is_changed() {
+                        if let Some(e) = conn.conn.db.enemy().id().find(enemy_id) {
+                            health.current = (e.health - predicted.health()).max(0.0);
+                            if let Some(mut shield) = shield {
+                                shield.current = (e.shield - predicted.shield()).max(0.0);
+                            }
+                        }
+                    }
                 }
+                continue;
             }
+        };
+        *world_entity = row.world.clone();
+        // Our unconfirmed hits stay applied on top of the server's values
+        let (lost_health, lost_shield) = predicted
+            .as_deref()
+            .map_or((0.0, 0.0), |p| (p.health(), p.shield()));
+        health.current = (row.health - lost_health).max(0.0);
+        health.max = row.max_health;
+        if let Some(mut shield) = shield {
+            shield.current = (row.shield - lost_shield).max(0.0);
+            shield.max = row.max_shield;
+        }
 
-            // Patch remote player animation state
-            if let Some(mut state) = remote_state {
-                state.animation_state = row.animation_state.clone();
-                state.attack_sequence = row.attack_sequence;
-                state.attack_animation = row.attack_animation.clone();
+        // Patch enemy behavior from the server's decision
+        if let Some(mut behavior) = enemy_behavior {
+            let new_behavior = match row.behavior {
+                EnemyBehaviorKind::Idle => EnemyBehavior::Idle,
+                EnemyBehaviorKind::Chase => EnemyBehavior::Chase,
+                EnemyBehaviorKind::Attack => EnemyBehavior::Attack,
+            };
+            if *behavior != new_behavior {
+                *behavior = new_behavior;
             }
-        } else {
-            commands.entity(bevy_entity).despawn();
+        }
+
+        // Patch remote player animation state
+        if let Some(mut state) = remote_state {
+            state.name = row.name;
+            state.animation = row.animation;
+            state.attack_sequence = row.attack_sequence;
+            state.attack_animation = row.attack_animation;
         }
     }
 
     // ── Spawn new remote entities ──────────────────────
-    for row in &rows {
-        if seen.contains(&row.id) {
+    for (id, row) in rows {
+        let Some(row) = row else {
             continue;
-        }
+        };
 
-        let is_enemy = matches!(&row.id, ServerId::Enemy(_));
-        let name = match &row.id {
+        let name = match &id {
             ServerId::Player(id) => format!("RemotePlayer_{id:?}"),
             ServerId::Enemy(id) => format!("Enemy_{id}"),
         };
 
-        if is_enemy {
-            // Enemy: On<Add, Enemy> observer attaches GLTF model + animations
-            commands.spawn((
+        if let ServerId::Enemy(enemy_id) = id {
+            // Enemy: On<Add, Enemy> observer attaches GLTF model + animations,
+            // or restyles the model a parked one already has
+            let enemy = (
                 Name::new(name),
-                row.id.clone(),
+                EnemyLook(EnemyVariation::from_id(enemy_id)),
+                id,
                 row.world.clone(),
                 ServerSnapshot::default(),
                 Transform::from_xyz(row.world.x, row.world.y, row.world.z),
                 Health::new(row.max_health),
+                Shield {
+                    current: row.shield,
+                    ..Shield::new(row.max_shield)
+                },
+                EnemyArchetype(row.enemy_type.clone()),
+                PredictedDamage::default(),
                 Enemy,
                 Combatant,
                 Stats::new()
                     .with(Stat::MaxHealth, row.max_health)
-                    .with(Stat::Health, row.health),
-            ));
+                    .with(Stat::Health, row.health)
+                    .with(Stat::MaxShield, row.max_shield)
+                    .with(Stat::Shield, row.shield)
+                    .labeled(&row.enemy_type),
+            );
+            match pool.take_enemy(&row.enemy_type) {
+                Some(parked) => {
+                    commands
+                        .entity(parked)
+                        .remove::<Parked>()
+                        .insert((enemy, Visibility::Inherited));
+                }
+                None => {
+                    commands.spawn((enemy, Pooled));
+                }
+            }
         } else {
             // Remote player: On<Add, RemotePlayer> observer attaches GLTF model + animations
-            commands.spawn((
-                Name::new(name),
-                row.id.clone(),
-                row.world.clone(),
-                ServerSnapshot::default(),
-                Transform::from_xyz(row.world.x, row.world.y, row.world.z),
-                Health::new(row.max_health),
-                RemotePlayer,
-                RemotePlayerState {
-                    animation_state: row.animation_state.clone(),
-                    attack_sequence: row.attack_sequence,
-                    attack_animation: row.attack_animation.clone(),
-                },
-            ));
+            let player = commands
+                .spawn((
+                    Name::new(name),
+                    id,
+                    row.world.clone(),
+                    ServerSnapshot::default(),
+                    Transform::from_xyz(row.world.x, row.world.y, row.world.z),
+                    Health::new(row.max_health),
+                    Shield {
+                        current: row.shield,
+                        ..Shield::new(row.max_shield)
+                    },
+                    RemotePlayer,
+                    EquippedWeapon::default(),
+                    RemotePlayerState {
+                        name: row.name.clone(),
+                        animation: row.animation,
+                        attack_sequence: row.attack_sequence,
+                        attack_animation: row.attack_animation,
+                    },
+                ))
+                .id();
+            commands.spawn(name_tag(player, &row.name));
         }
     }
+}
 
-    // ── Combat events ─────────────────────────────────
-    for event in conn.conn.db.combat_event().iter() {
-        if event.id <= tracker.last_processed_id {
+/// Mirror `player.weapon` onto every player entity, local and remote, so
+/// each is drawn and animated with the right moveset.
+pub(super) fn sync_equipped_weapons(
+    conn: Res<SpacetimeDbConnection>,
+    mut players: Query<(Option<&ServerId>, &mut EquippedWeapon, Has<LocalPlayer>)>,
+) {
+    let my_id = conn.conn.try_identity();
+    for (server_id, mut weapon, is_local) in &mut players {
+        let identity = match (server_id, is_local) {
+            (_, true) => my_id,
+            (Some(ServerId::Player(identity)), false) => Some(*identity),
+            _ => None,
+        };
+        let Some(row) = identity.and_then(|id| conn.conn.db.player().identity().find(&id)) else {
             continue;
+        };
+        weapon.set_if_neq(EquippedWeapon(Weapon::parse_str(&row.weapon)));
+    }
+}
+
+/// The loadout a remote player's [`Auras`] were built from.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub(super) struct RemoteLoadout(Loadout);
+
+/// Give each remote player the auras of their `player_loadout`, so their
+/// rings show. The local player has theirs from spawn.
+pub(super) fn sync_remote_auras(
+    conn: Res<SpacetimeDbConnection>,
+    players: Query<(Entity, &ServerId, Option<&RemoteLoadout>), With<RemotePlayer>>,
+    mut commands: Commands,
+) {
+    for (entity, server_id, current) in &players {
+        let ServerId::Player(identity) = server_id else {
+            continue;
+        };
+        let loadout = conn
+            .conn
+            .db
+            .player_loadout()
+            .identity()
+            .find(identity)
+            .and_then(|row| Loadout::parse_str(&row.loadout))
+            .unwrap_or_default();
+        if current.is_some_and(|c| c.0 == loadout) {
+            continue;
+        }
+        commands
+            .entity(entity)
+            .try_insert((RemoteLoadout(loadout), Auras(loadout.rules().auras)));
+    }
+}
+
+/// Mirror each player's stun, slow and haste `active_effect` rows onto their
+/// [`StatusEffects`], local and remote: the local player locks up and speeds
+/// up when the server says, and everyone shows it.
+pub(super) fn sync_control_effects(
+    conn: Res<SpacetimeDbConnection>,
+    mut players: Query<
+        (
+            Entity,
+            Option<&ServerId>,
+            Option<&mut StatusEffects>,
+            Has<LocalPlayer>,
+        ),
+        Or<(With<LocalPlayer>, With<RemotePlayer>)>,
+    >,
+    mut commands: Commands,
+) {
+    let my_id = conn.conn.try_identity();
+    for (entity, server_id, statuses, is_local) in &mut players {
+        let identity = match (server_id, is_local) {
+            (_, true) => my_id,
+            (Some(ServerId::Player(identity)), false) => Some(*identity),
+            _ => None,
+        };
+        let Some(identity) = identity else {
+            continue;
+        };
+        let controls: Vec<_> = conn
+            .conn
+            .db
+            .active_effect()
+            .iter()
+            .filter(|e| e.owner == identity)
+            .filter_map(|e| match EffectKind::from_u8(e.kind)? {
+                kind @ (EffectKind::Control(_) | EffectKind::Haste) => {
+                    Some((kind, e.magnitude, e.duration))
+                }
+                _ => None,
+            })
+            .collect();
+        match statuses {
+            Some(mut statuses) => statuses.sync_controls(controls),
+            None if !controls.is_empty() => {
+                let mut statuses = StatusEffects::default();
+                statuses.sync_controls(controls);
+                commands.entity(entity).try_insert(statuses);
+            }
+            None => {}
+        }
+    }
+}
+
+/// Show the local player's mana as the server has it: what their mana row
+/// held, refilled for the time since on the [`ServerClock`]. No row means a
+/// full pool.
+pub(super) fn sync_mana(
+    conn: Res<SpacetimeDbConnection>,
+    clock: Res<ServerClock>,
+    mut player: Query<&mut Stats, With<LocalPlayer>>,
+) {
+    let Ok(mut stats) = player.single_mut() else {
+        return;
+    };
+    let Some(my_id) = conn.conn.try_identity() else {
+        return;
+    };
+    let row = conn
+        .conn
+        .db
+        .active_effect()
+        .iter()
+        .find(|e| e.owner == my_id && EffectKind::from_u8(e.kind) == Some(EffectKind::Mana));
+    let mana = row.map_or(resources::MAX_MANA, |row| {
+        resources::mana_at(
+            row.magnitude,
+            resources::MAX_MANA,
+            clock.secs_since(row.timestamp),
+        )
+    });
+    if stats.get(&Stat::Mana) != mana {
+        stats.set(Stat::Mana, mana);
+    }
+}
+
+/// Rebuild the local player's server-owned stats from their level and
+/// equipped items when either changes. The server has already applied the
+/// same numbers to the player row; redoing it here lets tooltips credit the
+/// level and each item rather than showing bare values.
+fn rebuild_character_stats(
+    conn: &SpacetimeDbConnection,
+    me: &Player,
+    stats: &mut Stats,
+    mut level: Mut<PlayerLevel>,
+    mut equipment: Mut<Equipment>,
+) {
+    let mut worn: Vec<_> = conn
+        .conn
+        .db
+        .equipped_item()
+        .iter()
+        .filter(|e| e.owner == me.identity)
+        .collect();
+    worn.sort_by_key(|e| e.inventory_id);
+    let worn: Vec<Item> = worn
+        .iter()
+        .filter_map(|e| Item::parse_str(&e.item_id))
+        .collect();
+
+    if me.level != level.level || worn != equipment.0 {
+        stats.merge(items::equipped_stats(me.level, &worn));
+        equipment.0 = worn;
+    }
+    level.set_if_neq(PlayerLevel {
+        level: me.level,
+        xp: me.xp,
+    });
+}
+
+/// Turns new `combat_event` rows into hit reactions and [`CombatEventData`]
+/// entities, nearest-first within [`COMBAT_EVENT_CULL_DISTANCE`] of the camera
+/// and capped at [`MAX_COMBAT_EVENTS_PER_FRAME`]. Culled events are still
+/// marked processed so they don't surface later.
+///
+/// Each batch's entities are parked in the [`EntityPool`] when the next batch
+/// arrives, or when their [`CombatEventTtl`] runs out, and reused after.
+///
+/// PvP hits on the local player are never culled: this client owns the
+/// player's position, so it plays out the knockback the server decided, and
+/// moves the player when an admin teleports them.
+pub(super) fn process_combat_events(
+    conn: Res<SpacetimeDbConnection>,
+    mut tracker: ResMut<CombatEventTracker>,
+    camera: Query<&GlobalTransform, With<SceneCamera>>,
+    remote_entities: Query<(Entity, &ServerId), Without<LocalPlayer>>,
+    mut local_player: Query<(Entity, &mut Transform, &mut LinearVelocity), With<LocalPlayer>>,
+    previous_batch: Query<Entity, With<CombatEventData>>,
+    inputs: Res<InputSequence>,
+    mut history: ResMut<PositionHistory>,
+    mut pool: ResMut<EntityPool>,
+    mut commands: Commands,
+) {
+    let my_id = conn.conn.try_identity();
+    let camera_pos = camera.single().ok().map(GlobalTransform::translation);
+
+    let mut events: Vec<_> = conn
+        .conn
+        .db
+        .combat_event()
+        .iter()
+        .filter(|e| e.id > tracker.last_processed_id)
+        .collect();
+    let Some(newest) = events.iter().map(|e| e.id).max() else {
+        return;
+    };
+    tracker.last_processed_id = newest;
+    for entity in &previous_batch {
+        pool.park_combat_event(&mut commands, entity);
+    }
+
+    if let (Ok((_, mut transform, mut velocity)), Some(id)) = (local_player.single_mut(), my_id) {
+        let teleport = events
+            .iter()
+            .filter(|e| e.target_player == Some(id) && e.reaction == life_events::TELEPORT)
+            .max_by_key(|e| e.id);
+        if let Some(teleport) = teleport {
+            transform.translation = Vec3::new(teleport.x, teleport.y, teleport.z);
+            velocity.0 = Vec3::ZERO;
+            history.restart(&inputs);
+        }
+    }
+    // Deaths and respawns play out through health and the death row
+    events.retain(|e| !life_events::is_life_event(&e.reaction));
+
+    if let (Ok((me, _, _)), Some(id)) = (local_player.single(), my_id) {
+        for event in events.iter().filter(|e| e.target_player == Some(id)) {
+            let knockback = Vec3::new(event.knockback_x, event.knockback_y, event.knockback_z);
+            if knockback.length_squared() > 0.0001 {
+                commands.entity(me).insert(PendingKnockback(knockback));
+            }
+            commands.trigger(HitReacted {
+                entity: me,
+                reaction: HitReaction::parse_str(&event.reaction),
+            });
+        }
+    }
+
+    // Without a camera there's nothing to measure from — keep everything
+    let distance_sq =
+        |e: &CombatEvent| camera_pos.map_or(0.0, |c| c.distance_squared(Vec3::new(e.x, e.y, e.z)));
+    events.retain(|e| distance_sq(e) <= COMBAT_EVENT_CULL_DISTANCE.powi(2));
+    events.sort_by(|a, b| distance_sq(a).total_cmp(&distance_sq(b)));
+    events.truncate(MAX_COMBAT_EVENTS_PER_FRAME);
+
+    for event in events {
+        let reaction = HitReaction::parse_str(&event.reaction);
+
+        // Our own hits already flinched their enemy target through local
+        // prediction; prediction never targets players, and hits on us were
+        // handled above
+        let target = match event.target_player {
+            Some(victim) if Some(victim) != my_id => Some(ServerId::Player(victim)),
+            Some(_) => None,
+            None if my_id != Some(event.attacker) => Some(ServerId::Enemy(event.target_id)),
+            None => None,
+        };
+        if let Some(target) = target {
+            if let Some((entity, _)) = remote_entities.iter().find(|(_, id)| **id == target) {
+                commands.trigger(HitReacted { entity, reaction });
+            }
         }
-        tracker.last_processed_id = event.id;
 
-        commands.spawn((
+        let data = (
             CombatEventData {
                 damage: event.damage,
                 is_crit: event.is_crit,
+                reaction,
                 x: event.x,
                 y: event.y,
                 z: event.z,
             },
             Transform::from_xyz(event.x, event.y, event.z),
-        ));
+            CombatEventTtl::default(),
+        );
+        match pool.take_combat_event() {
+            Some(parked) => {
+                commands.entity(parked).remove::<Parked>().insert(data);
+            }
+            None => {
+                commands.spawn(data);
+            }
+        }
     }
 }
@@ -0,0 +1,307 @@
+//! Network replays: a recording of the `player` and `enemy` rows a session
+//! received, and playback of one without any connection, for chasing
+//! desyncs and keeping regression cases.
+//!
+//! F10 in a connected session starts and stops a recording, saved under
+//! [`REPLAY_DIR`]. `--replay <file>` boots straight into an offline session
+//! and feeds the file's rows to [`reconcile`](super::reconcile::reconcile)
+//! at the pace they arrived; whoever recorded it shows up as a remote
+//! player. Only those two tables are recorded — they are everything the
+//! reconciler reads.
+
+use bevy::prelude::*;
+use spacetimedb_sdk::__codegen::__sats::{bsatn, ser::Serialize};
+use spacetimedb_sdk::{Table, TableWithPrimaryKey};
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use web_time::{Instant, SystemTime, UNIX_EPOCH};
+
+use super::generated::enemy_table::EnemyTableAccess;
+use super::generated::player_table::PlayerTableAccess;
+use super::generated::{Enemy as EnemyRow, Player};
+use super::reconcile::{EntityRow, RowChanges, ServerId, enemy_row, player_row};
+use super::{SpacetimeDbConnection, offline};
+use crate::models::{GameMode, GoTo, Screen};
+
+const REPLAY_DIR: &str = "replays";
+/// Leads every replay file, with the format version.
+const MAGIC: &[u8; 4] = b"WFR1";
+
+/// Replay path from `--replay <path>`, if this is a playback run.
+pub(super) fn replay_from_args() -> Option<PathBuf> {
+    let mut args = std::env::args().skip_while(|arg| arg != "--replay");
+    args.next()?;
+    args.next().map(PathBuf::from)
+}
+
+// =============================================================================
+// Format
+// =============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReplayTable {
+    Player,
+    Enemy,
+}
+
+/// One row change. On disk: seconds since the recording started (f32),
+/// table, deleted flag, then the BSATN-encoded row with its length (u32),
+/// all little-endian.
+struct ReplayEntry {
+    at: f32,
+    table: ReplayTable,
+    deleted: bool,
+    row: Vec<u8>,
+}
+
+impl ReplayEntry {
+    fn write(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.at.to_le_bytes());
+        out.push(match self.table {
+            ReplayTable::Player => 0,
+            ReplayTable::Enemy => 1,
+        });
+        out.push(self.deleted as u8);
+        out.extend_from_slice(&(self.row.len() as u32).to_le_bytes());
+        out.extend_from_slice(&self.row);
+    }
+
+    fn read(bytes: &mut &[u8]) -> Option<Self> {
+        let at = f32::from_le_bytes(take(bytes, 4)?.try_into().ok()?);
+        let table = match take(bytes, 1)?[0] {
+            0 => ReplayTable::Player,
+            1 => ReplayTable::Enemy,
+            _ => return None,
+        };
+        let deleted = take(bytes, 1)?[0] != 0;
+        let len = u32::from_le_bytes(take(bytes, 4)?.try_into().ok()?);
+        let row = take(bytes, len as usize)?.to_vec();
+        Some(Self {
+            at,
+            table,
+            deleted,
+            row,
+        })
+    }
+
+    /// The change in the form [`RowChanges`] queues it.
+    fn decode(&self) -> Option<(ServerId, Option<EntityRow>)> {
+        let decoded = match self.table {
+            ReplayTable::Player => bsatn::from_slice::<Player>(&self.row)
+                .map(|p| (ServerId::Player(p.identity), player_row(&p))),
+            ReplayTable::Enemy => bsatn::from_slice::<EnemyRow>(&self.row)
+                .map(|e| (ServerId::Enemy(e.id), Some(enemy_row(&e)))),
+        };
+        match decoded {
+            Ok((id, row)) => Some((id, row.filter(|_| !self.deleted))),
+            Err(e) => {
+                warn!("Skipping undecodable replay row: {e}");
+                None
+            }
+        }
+    }
+}
+
+fn take<'a>(bytes: &mut &'a [u8], n: usize) -> Option<&'a [u8]> {
+    if bytes.len() < n {
+        return None;
+    }
+    let (head, rest) = bytes.split_at(n);
+    *bytes = rest;
+    Some(head)
+}
+
+fn load(path: &Path) -> Result<VecDeque<ReplayEntry>, String> {
+    let bytes = std::fs::read(path).map_err(|e| e.to_string())?;
+    let mut bytes = bytes
+        .strip_prefix(MAGIC.as_slice())
+        .ok_or("not a replay file")?;
+    let mut entries = VecDeque::new();
+    while !bytes.is_empty() {
+        entries.push_back(ReplayEntry::read(&mut bytes).ok_or("truncated replay file")?);
+    }
+    Ok(entries)
+}
+
+// =============================================================================
+// Recording
+// =============================================================================
+
+/// The recording in progress, if any. Shared with the table callbacks.
+#[derive(Resource, Default, Clone)]
+pub(super) struct ReplayRecorder(Arc<Mutex<Option<Recording>>>);
+
+struct Recording {
+    started: Instant,
+    bytes: Vec<u8>,
+    entries: usize,
+}
+
+impl ReplayRecorder {
+    fn push<T: Serialize>(&self, table: ReplayTable, deleted: bool, row: &T) {
+        let mut recording = self.0.lock().unwrap();
+        let Some(recording) = recording.as_mut() else {
+            return;
+        };
+        let row = match bsatn::to_vec(row) {
+            Ok(row) => row,
+            Err(e) => {
+                warn!("Can't record {table:?} row: {e}");
+                return;
+            }
+        };
+        ReplayEntry {
+            at: recording.started.elapsed().as_secs_f32(),
+            table,
+            deleted,
+            row,
+        }
+        .write(&mut recording.bytes);
+        recording.entries += 1;
+    }
+
+    /// End the recording in progress and write it out.
+    fn finish(&self) {
+        let Some(recording) = self.0.lock().unwrap().take() else {
+            return;
+        };
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        let path = Path::new(REPLAY_DIR).join(format!("{secs}.replay"));
+        let result = std::fs::create_dir_all(REPLAY_DIR)
+            .and_then(|()| std::fs::write(&path, &recording.bytes));
+        match result {
+            Ok(()) => info!(
+                "Saved replay of {} rows to '{}'",
+                recording.entries,
+                path.display()
+            ),
+            Err(e) => error!("Failed to save replay '{}': {e}", path.display()),
+        }
+    }
+}
+
+/// Feed a new connection's `player` and `enemy` changes to the recorder.
+/// They're dropped unless a recording is in progress.
+pub(super) fn watch_for_recording(conn: Res<SpacetimeDbConnection>, recorder: Res<ReplayRecorder>) {
+    let db = &conn.conn.db;
+
+    let rec = recorder.clone();
+    db.player()
+        .on_insert(move |_, p| rec.push(ReplayTable::Player, false, p));
+    let rec = recorder.clone();
+    db.player()
+        .on_update(move |_, _, p| rec.push(ReplayTable::Player, false, p));
+    let rec = recorder.clone();
+    db.player()
+        .on_delete(move |_, p| rec.push(ReplayTable::Player, true, p));
+
+    let rec = recorder.clone();
+    db.enemy()
+        .on_insert(move |_, e| rec.push(ReplayTable::Enemy, false, e));
+    let rec = recorder.clone();
+    db.enemy()
+        .on_update(move |_, _, e| rec.push(ReplayTable::Enemy, false, e));
+    let rec = recorder.clone();
+    db.enemy()
+        .on_delete(move |_, e| rec.push(ReplayTable::Enemy, true, e));
+}
+
+/// F10: start a recording, opening with every row already cached, or save
+/// the one in progress.
+pub(super) fn toggle_recording(conn: Res<SpacetimeDbConnection>, recorder: Res<ReplayRecorder>) {
+    if recorder.0.lock().unwrap().is_some() {
+        recorder.finish();
+        return;
+    }
+    *recorder.0.lock().unwrap() = Some(Recording {
+        started: Instant::now(),
+        bytes: MAGIC.to_vec(),
+        entries: 0,
+    });
+    for p in conn.conn.db.player().iter() {
+        recorder.push(ReplayTable::Player, false, &p);
+    }
+    for e in conn.conn.db.enemy().iter() {
+        recorder.push(ReplayTable::Enemy, false, &e);
+    }
+    info!("Recording replay (F10 to stop)...");
+}
+
+/// A recording ends with its connection.
+pub(super) fn finish_recording(recorder: Res<ReplayRecorder>) {
+    recorder.finish();
+}
+
+// =============================================================================
+// Playback
+// =============================================================================
+
+/// Rows still to be played back, with the time into the replay.
+#[derive(Resource)]
+pub(super) struct ReplayPlayback {
+    entries: VecDeque<ReplayEntry>,
+    elapsed: f32,
+}
+
+impl ReplayPlayback {
+    pub(super) fn load(path: &Path) -> Option<Self> {
+        match load(path) {
+            Ok(entries) => {
+                info!("Playing back {} rows from {path:?}", entries.len());
+                Some(Self {
+                    entries,
+                    elapsed: 0.0,
+                })
+            }
+            Err(e) => {
+                error!("Can't play back replay {path:?}: {e}");
+                None
+            }
+        }
+    }
+}
+
+/// Run condition: a replay is being played back.
+pub(super) fn is_replaying(playback: Option<Res<ReplayPlayback>>) -> bool {
+    playback.is_some()
+}
+
+/// Playback has no server: go straight from the title screen into an
+/// offline session.
+pub(super) fn start_playback(
+    mut mode: ResMut<GameMode>,
+    changes: Res<RowChanges>,
+    mut commands: Commands,
+) {
+    *mode = GameMode::Singleplayer;
+    offline::go_offline(&mut commands);
+    changes.clear();
+    commands.trigger(GoTo(Screen::Gameplay));
+}
+
+/// Queue the rows that are due for the reconciler.
+pub(super) fn feed_playback(
+    time: Res<Time>,
+    mut playback: ResMut<ReplayPlayback>,
+    changes: Res<RowChanges>,
+    mut commands: Commands,
+) {
+    playback.elapsed += time.delta_secs();
+    while playback
+        .entries
+        .front()
+        .is_some_and(|entry| entry.at <= playback.elapsed)
+    {
+        let entry = playback.entries.pop_front().unwrap();
+        if let Some((id, row)) = entry.decode() {
+            changes.record(id, row);
+        }
+    }
+    if playback.entries.is_empty() {
+        info!("Replay finished");
+        commands.remove_resource::<ReplayPlayback>();
+    }
+}
@@ -0,0 +1,115 @@
+//! Connections to modules besides the game's, keyed by what they're for.
+//!
+//! The game module's connection stays the
+//! [`SpacetimeDbConnection`](super::SpacetimeDbConnection) resource every
+//! networking system reads. A module hosted on its own (chat and social,
+//! say) goes in [`SecondaryConnections`] under its [`ConnectionPurpose`];
+//! these are ticked, reaped when they drop and disconnected alongside the
+//! game connection, so its own systems only deal with its tables and
+//! reducers. Each module's generated `DbConnection` implements
+//! [`ModuleConnection`] to take part.
+
+// Nothing opens a secondary connection until the social module exists
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+use spacetimedb_sdk::DbContext;
+use std::any::Any;
+use std::collections::HashMap;
+
+use super::DbConnection;
+
+/// What a secondary connection is for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConnectionPurpose {
+    /// Chat, friends and parties, hosted apart from the game module.
+    Social,
+}
+
+/// The lifecycle calls the networking systems make on any module's
+/// connection, whatever its generated bindings.
+pub trait ModuleConnection: Send + Sync + 'static {
+    /// Process the messages received since the last call.
+    fn tick(&self) -> Result<(), String>;
+    fn is_active(&self) -> bool;
+    fn disconnect(&self);
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl ModuleConnection for DbConnection {
+    fn tick(&self) -> Result<(), String> {
+        self.frame_tick().map_err(|e| format!("{e:?}"))
+    }
+
+    fn is_active(&self) -> bool {
+        DbContext::is_active(self)
+    }
+
+    fn disconnect(&self) {
+        if let Err(e) = DbContext::disconnect(self) {
+            warn!("SpacetimeDB disconnect error: {e:?}");
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Open connections to modules other than the game's.
+#[derive(Resource, Default)]
+pub struct SecondaryConnections {
+    connections: HashMap<ConnectionPurpose, Box<dyn ModuleConnection>>,
+}
+
+impl SecondaryConnections {
+    /// Hold `conn` for `purpose`, disconnecting whatever held it before.
+    pub fn insert(&mut self, purpose: ConnectionPurpose, conn: impl ModuleConnection) {
+        if let Some(previous) = self.connections.insert(purpose, Box::new(conn)) {
+            previous.disconnect();
+        }
+    }
+
+    /// The connection for `purpose`, as its module's `DbConnection` type.
+    pub fn get<C: ModuleConnection>(&self, purpose: ConnectionPurpose) -> Option<&C> {
+        self.connections
+            .get(&purpose)
+            .and_then(|conn| conn.as_any().downcast_ref())
+    }
+
+    pub fn contains(&self, purpose: ConnectionPurpose) -> bool {
+        self.connections.contains_key(&purpose)
+    }
+
+    /// Disconnect and forget the connection for `purpose`.
+    pub fn remove(&mut self, purpose: ConnectionPurpose) {
+        if let Some(conn) = self.connections.remove(&purpose) {
+            conn.disconnect();
+        }
+    }
+}
+
+/// Process every secondary connection's messages, and drop the ones that
+/// went away. Whoever opened one decides whether to open it again.
+pub(super) fn tick_secondary_connections(mut secondary: ResMut<SecondaryConnections>) {
+    if secondary.connections.is_empty() {
+        return;
+    }
+    secondary.connections.retain(|purpose, conn| {
+        if !conn.is_active() {
+            warn!("{purpose:?} connection lost");
+            return false;
+        }
+        if let Err(e) = conn.tick() {
+            warn!("{purpose:?} frame_tick error: {e}");
+        }
+        true
+    });
+}
+
+/// Disconnect everything along with the game connection.
+pub(super) fn disconnect_secondary_connections(mut secondary: ResMut<SecondaryConnections>) {
+    for (_, conn) in secondary.connections.drain() {
+        conn.disconnect();
+    }
+}
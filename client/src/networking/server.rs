@@ -0,0 +1,63 @@
+//! The session's authority over the world, whichever it is: a SpacetimeDB
+//! module (local or remote) or the in-process [offline](super::offline)
+//! stand-in. Gameplay asks through [`GameServer`] instead of checking which
+//! one it has.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use spacetimedb_sdk::DbContext;
+
+use super::SpacetimeDbConnection;
+use super::combat::{server_clear_enemies, server_spawn_enemies};
+use super::generated::{pause_world_reducer::pause_world, resume_world_reducer::resume_world};
+use super::offline::OfflineServer;
+use crate::models::ServerTarget;
+
+/// World requests every backend answers. Combat itself needs no entry here:
+/// it resolves through the shared crate on both sides.
+pub trait GameServer {
+    /// Spawn a pack of enemies around `position`.
+    fn spawn_enemies(&self, commands: &mut Commands, position: Vec3, forward: Vec3);
+    /// Remove every enemy in the world.
+    fn clear_enemies(&self, commands: &mut Commands);
+    /// Freeze or resume the world tick.
+    fn set_paused(&self, paused: bool);
+}
+
+impl GameServer for SpacetimeDbConnection {
+    fn spawn_enemies(&self, _: &mut Commands, position: Vec3, forward: Vec3) {
+        server_spawn_enemies(self, position, forward);
+    }
+
+    fn clear_enemies(&self, _: &mut Commands) {
+        server_clear_enemies(self);
+    }
+
+    fn set_paused(&self, paused: bool) {
+        let result = if paused {
+            self.reducers().pause_world()
+        } else {
+            self.reducers().resume_world()
+        };
+        if let Err(e) = result {
+            warn!("Failed to send pause state: {:?}", e);
+        }
+    }
+}
+
+/// The [`GameServer`] of the current session, if there is one.
+#[derive(SystemParam)]
+pub struct ActiveServer<'w> {
+    conn: Option<Res<'w, SpacetimeDbConnection>>,
+    target: Option<Res<'w, ServerTarget>>,
+}
+
+impl ActiveServer<'_> {
+    pub fn get(&self) -> Option<&dyn GameServer> {
+        match (&self.conn, self.target.as_deref()) {
+            (Some(conn), _) if conn.conn.is_active() => Some(&**conn),
+            (_, Some(ServerTarget::Offline)) => Some(&OfflineServer),
+            _ => None,
+        }
+    }
+}
@@ -1,47 +1,150 @@
-//! Outbound position relay, entity interpolation, and ping measurement.
+//! Outbound position (or input, see [`Relay`]) relay, entity interpolation,
+//! and ping measurement (which also feeds the [`ServerClock`]).
 
+use avian3d::prelude::LinearVelocity;
 use bevy::prelude::*;
 use spacetimedb_sdk::DbContext;
+use wasm_fantasia_shared::gameplay::{Relay, gameplay};
+use wasm_fantasia_shared::net;
 use web_time::Instant;
 
-use super::SpacetimeDbConnection;
+use super::clock::ServerClock;
+use super::generated::ping_reducer::ping;
+use super::generated::ping_result_table::PingResultTableAccess;
+use super::generated::player_input_reducer::player_input;
 use super::generated::player_table::PlayerTableAccess;
+use super::generated::report_rtt_reducer::report_rtt;
 use super::generated::update_position_reducer::update_position;
+use super::generated::world_table::WorldTableAccess;
+use super::prediction::{InputSequence, PositionHistory};
+use super::quality::{ConnectionQuality, DEGRADED_SYNC_RATE_HZ};
 use super::reconcile::{ServerId, ServerSnapshot, WorldEntity};
+use super::{NetworkTuning, SpacetimeDbConnection};
 use crate::combat::AttackState;
-use crate::models::Player as LocalPlayer;
-use crate::player::Animation;
+use crate::models::{EquippedWeapon, Player as LocalPlayer, PlayerLevel};
+use crate::player::control::JumpLaunched;
+use crate::player::moveset;
 
-const INTERPOLATION_SPEED: f32 = 12.0;
 const GRAVITY: f32 = -9.81;
+/// How often a `ping` goes out.
+const PING_INTERVAL_SECS: f32 = 0.5;
+/// A ping unanswered this long is given up on and a fresh one sent.
+const PING_TIMEOUT_SECS: f32 = 2.0;
+/// How often the smoothed RTT is reported to the server for hit rewinding.
+const RTT_REPORT_INTERVAL_SECS: f32 = 1.0;
+/// A player standing still still sends this often, so the server's
+/// `last_update` keeps ticking.
+const POSITION_HEARTBEAT_SECS: f32 = 1.0;
+
+// =============================================================================
+// Components
+// =============================================================================
+
+/// On the local player once it's been moved to its saved server position.
+/// Position updates wait for it, so a fresh spawn never overwrites the save.
+#[derive(Component)]
+pub struct PositionRestored;
 
 // =============================================================================
 // Resources
 // =============================================================================
 
-/// Tracks round-trip time by comparing position send timestamps against server acks.
+/// Round-trip time measured with the `ping` reducer: the time until our
+/// nonce comes back in `ping_result`. One ping is in flight at a time.
 #[derive(Resource, Default)]
 pub struct PingTracker {
-    pub last_send: Option<Instant>,
-    pub last_seen_update: i64,
+    /// The ping in flight: its nonce and when it was sent.
+    pub pending: Option<(u32, Instant)>,
+    pub next_nonce: u32,
+    pub last_sent: Option<Instant>,
     pub smoothed_rtt_ms: f32,
+    /// Mean deviation between consecutive round trips, smoothed like RFC
+    /// 3550's interarrival jitter.
+    pub jitter_ms: f32,
+    /// Share of recent pings that timed out, 0 to 1.
+    pub loss: f32,
+    last_rtt_ms: Option<f32>,
     pub last_ack: Option<Instant>,
+    pub last_report: Option<Instant>,
+}
+
+impl PingTracker {
+    fn record_rtt(&mut self, rtt_ms: f32) {
+        if self.smoothed_rtt_ms <= 0.0 {
+            self.smoothed_rtt_ms = rtt_ms;
+        } else {
+            self.smoothed_rtt_ms = self.smoothed_rtt_ms * 0.8 + rtt_ms * 0.2;
+        }
+        if let Some(last) = self.last_rtt_ms {
+            self.jitter_ms += ((rtt_ms - last).abs() - self.jitter_ms) / 16.0;
+        }
+        self.last_rtt_ms = Some(rtt_ms);
+        self.record_loss(false);
+    }
+
+    fn record_loss(&mut self, lost: bool) {
+        let sample = if lost { 1.0 } else { 0.0 };
+        self.loss += (sample - self.loss) / 8.0;
+    }
 }
 
-/// Timer for position sync rate limiting.
+/// Timer for position sync rate limiting (see
+/// [`NetworkTuning::sync_rate_hz`]), and what was sent last.
 #[derive(Resource)]
 pub struct PositionSyncTimer {
     pub timer: Timer,
+    last_sent: Option<PositionUpdate>,
+    since_sent: f32,
+    /// Height of a jump launched since the last `player_input`.
+    pending_jump: f32,
 }
 
 impl Default for PositionSyncTimer {
     fn default() -> Self {
         Self {
             timer: Timer::from_seconds(0.05, TimerMode::Repeating),
+            last_sent: None,
+            since_sent: 0.0,
+            pending_jump: 0.0,
         }
     }
 }
 
+/// One `update_position` in its wire encoding; see [`net`].
+#[derive(Debug, Clone, Copy)]
+struct PositionUpdate {
+    x: i16,
+    y: i16,
+    z: i16,
+    heading: u8,
+    animation: u8,
+    attack_sequence: u32,
+    attack_animation: u8,
+}
+
+impl PositionUpdate {
+    /// Nothing worth sending: the position is within a step on every axis
+    /// and everything else is the same.
+    fn same_as(&self, other: &Self) -> bool {
+        let near = |a: i16, b: i16| a.abs_diff(b) <= 1;
+        near(self.x, other.x)
+            && near(self.y, other.y)
+            && near(self.z, other.z)
+            && self.heading == other.heading
+            && self.animation == other.animation
+            && self.attack_sequence == other.attack_sequence
+            && self.attack_animation == other.attack_animation
+    }
+
+    fn position(&self) -> Vec3 {
+        Vec3::new(
+            net::dequantize_position(self.x),
+            net::dequantize_position(self.y),
+            net::dequantize_position(self.z),
+        )
+    }
+}
+
 // =============================================================================
 // Systems
 // =============================================================================
@@ -52,11 +155,12 @@ impl Default for PositionSyncTimer {
 /// even when subscription updates arrive at ~10Hz.
 pub(super) fn interpolate_synced_entities(
     time: Res<Time>,
+    tuning: Res<NetworkTuning>,
     mut query: Query<(&WorldEntity, &mut ServerSnapshot, &mut Transform), With<ServerId>>,
 ) {
     let now = time.elapsed_secs();
     let dt = time.delta_secs();
-    let alpha = (dt * INTERPOLATION_SPEED).min(1.0);
+    let alpha = (dt * tuning.interpolation_speed).min(1.0);
 
     for (world_entity, mut snapshot, mut transform) in &mut query {
         let server_pos = Vec3::new(world_entity.x, world_entity.y, world_entity.z);
@@ -103,74 +207,267 @@ pub(super) fn interpolate_synced_entities(
     }
 }
 
-/// Send local player position to the server at a fixed rate.
-pub(super) fn send_local_position(
+/// Move the local player to where the server saved them last session, once
+/// `join_game` has brought their row online. Runs again after
+/// `reset_character`, which is the only thing that lowers xp, and then goes
+/// to the world's spawn point since this client may have reported a position
+/// after the reset.
+pub(super) fn restore_saved_position(
     conn: Res<SpacetimeDbConnection>,
-    mut timer: ResMut<PositionSyncTimer>,
-    mut ping: ResMut<PingTracker>,
-    time: Res<Time>,
-    query: Query<(&Transform, &LocalPlayer, Option<&AttackState>), With<LocalPlayer>>,
+    mut query: Query<
+        (
+            Entity,
+            &mut Transform,
+            &mut LinearVelocity,
+            &PlayerLevel,
+            Has<PositionRestored>,
+        ),
+        With<LocalPlayer>,
+    >,
+    inputs: Res<InputSequence>,
+    mut history: ResMut<PositionHistory>,
+    mut commands: Commands,
 ) {
-    timer.timer.tick(time.delta());
-    if !timer.timer.just_finished() {
+    let Ok((entity, mut transform, mut velocity, level, restored)) = query.single_mut() else {
+        return;
+    };
+    let Some(identity) = conn.conn.try_identity() else {
+        return;
+    };
+    let Some(me) = conn.conn.db.player().identity().find(&identity) else {
+        return;
+    };
+    if !me.online {
         return;
     }
 
-    let Ok((transform, player, attack_state)) = query.single() else {
+    if !restored {
+        transform.translation = Vec3::new(me.x, me.y, me.z);
+        transform.rotation = Quat::from_rotation_y(me.rotation_y);
+    } else if me.xp < level.xp {
+        let Some(world) = conn.conn.db.world().id().find(&me.world_id) else {
+            return;
+        };
+        transform.translation = Vec3::new(world.spawn_x, world.spawn_y, world.spawn_z);
+    } else {
         return;
-    };
+    }
+    velocity.0 = Vec3::ZERO;
+    history.restart(&inputs);
+    commands.entity(entity).insert(PositionRestored);
+}
 
+/// Send at the rate [`NetworkTuning`] asks for, or slower while the
+/// connection is degraded.
+pub(super) fn apply_sync_rate(
+    tuning: Res<NetworkTuning>,
+    quality: Res<ConnectionQuality>,
+    mut timer: ResMut<PositionSyncTimer>,
+) {
+    let (min, max) = NetworkTuning::SYNC_RATE_RANGE;
+    let mut rate = tuning.sync_rate_hz.clamp(min, max);
+    if quality.is_degraded() {
+        rate = rate.min(DEGRADED_SYNC_RATE_HZ);
+    }
+    timer
+        .timer
+        .set_duration(std::time::Duration::from_secs_f32(1.0 / rate));
+}
+
+/// Run condition: which [`Relay`] movement goes out on.
+pub(super) fn relaying(relay: Relay) -> impl Fn() -> bool {
+    move || gameplay().relay == relay
+}
+
+/// The local player's state in its wire encoding.
+fn local_update(
+    transform: &Transform,
+    player: &LocalPlayer,
+    attack_state: Option<&AttackState>,
+    weapon: Option<&EquippedWeapon>,
+) -> PositionUpdate {
     let pos = transform.translation;
     let rotation_y = transform.rotation.to_euler(EulerRot::YXZ).0;
-    let animation_state = player.animation_state.server_name().to_string();
 
     let (attack_sequence, attack_animation) = if let Some(attack) = attack_state {
-        let anim = if attack.is_crit {
-            Animation::MeleeHook
-        } else if attack.attack_count % 2 == 1 {
-            Animation::PunchJab
-        } else {
-            Animation::PunchCross
-        };
-        (attack.attack_count, anim.clip_name().to_string())
+        let anim = moveset(weapon.map(|w| w.0).unwrap_or_default())
+            .clip(attack.attack_count, attack.is_crit);
+        (attack.attack_count, anim.wire_id().as_u8())
     } else {
-        (0, String::new())
+        (0, 0)
     };
-
-    ping.last_send = Some(Instant::now());
-
-    if let Err(e) = conn.conn.reducers.update_position(
-        pos.x,
-        pos.y,
-        pos.z,
-        rotation_y,
-        animation_state,
+    PositionUpdate {
+        x: net::quantize_position(pos.x),
+        y: net::quantize_position(pos.y),
+        z: net::quantize_position(pos.z),
+        heading: net::quantize_rotation(rotation_y),
+        animation: player.animation_state.wire_id().as_u8(),
         attack_sequence,
         attack_animation,
+    }
+}
+
+/// Send local player position to the server at a fixed rate, numbered so
+/// the reply can be matched up with it. Updates that change nothing are
+/// skipped, down to one per [`POSITION_HEARTBEAT_SECS`].
+pub(super) fn send_local_position(
+    conn: Res<SpacetimeDbConnection>,
+    mut timer: ResMut<PositionSyncTimer>,
+    mut inputs: ResMut<InputSequence>,
+    mut history: ResMut<PositionHistory>,
+    time: Res<Time>,
+    query: Query<
+        (
+            &Transform,
+            &LocalPlayer,
+            Option<&AttackState>,
+            Option<&EquippedWeapon>,
+        ),
+        With<PositionRestored>,
+    >,
+) {
+    timer.timer.tick(time.delta());
+    timer.since_sent += time.delta_secs();
+    if !timer.timer.just_finished() {
+        return;
+    }
+
+    let Ok((transform, player, attack_state, weapon)) = query.single() else {
+        return;
+    };
+
+    let update = local_update(transform, player, attack_state, weapon);
+    if timer.since_sent < POSITION_HEARTBEAT_SECS
+        && timer.last_sent.is_some_and(|last| last.same_as(&update))
+    {
+        return;
+    }
+    timer.last_sent = Some(update);
+    timer.since_sent = 0.0;
+
+    let input_seq = inputs.advance();
+    // What the server will store, so reconciling compares like with like
+    history.record(input_seq, update.position());
+
+    if let Err(e) = conn.reducers().update_position(
+        update.x,
+        update.y,
+        update.z,
+        update.heading,
+        update.animation,
+        update.attack_sequence,
+        update.attack_animation,
+        input_seq,
     ) {
         warn!("Failed to send position update: {:?}", e);
     }
 }
 
-pub(super) fn measure_ping(conn: Res<SpacetimeDbConnection>, mut tracker: ResMut<PingTracker>) {
-    let Some(identity) = conn.conn.try_identity() else {
+/// Hold on to a jump until the next `player_input` carries it.
+pub(super) fn queue_jump(on: On<JumpLaunched>, mut timer: ResMut<PositionSyncTimer>) {
+    timer.pending_jump = timer.pending_jump.max(on.height);
+}
+
+/// Under the input relay: send the local player's velocity and any jump on
+/// the same clock as [`send_local_position`], for the server to move them
+/// with. The player keeps moving locally in the meantime; the position each
+/// input was sent from is kept for reconciling as usual. Sent every time,
+/// as the server keeps moving a player at their last velocity.
+pub(super) fn send_local_input(
+    conn: Res<SpacetimeDbConnection>,
+    mut timer: ResMut<PositionSyncTimer>,
+    mut inputs: ResMut<InputSequence>,
+    mut history: ResMut<PositionHistory>,
+    time: Res<Time>,
+    query: Query<
+        (
+            &Transform,
+            &LinearVelocity,
+            &LocalPlayer,
+            Option<&AttackState>,
+            Option<&EquippedWeapon>,
+        ),
+        With<PositionRestored>,
+    >,
+) {
+    timer.timer.tick(time.delta());
+    if !timer.timer.just_finished() {
+        return;
+    }
+
+    let Ok((transform, velocity, player, attack_state, weapon)) = query.single() else {
         return;
     };
-    let Some(player) = conn.conn.db.player().identity().find(&identity) else {
+    let update = local_update(transform, player, attack_state, weapon);
+    let jump_height = std::mem::take(&mut timer.pending_jump);
+
+    let input_seq = inputs.advance();
+    history.record(input_seq, update.position());
+
+    if let Err(e) = conn.reducers().player_input(
+        velocity.x,
+        velocity.z,
+        jump_height,
+        update.heading,
+        update.animation,
+        update.attack_sequence,
+        update.attack_animation,
+        input_seq,
+    ) {
+        warn!("Failed to send input: {:?}", e);
+    }
+}
+
+pub(super) fn measure_ping(
+    conn: Res<SpacetimeDbConnection>,
+    mut tracker: ResMut<PingTracker>,
+    mut clock: ResMut<ServerClock>,
+) {
+    let Some(identity) = conn.conn.try_identity() else {
         return;
     };
 
-    if player.last_update != tracker.last_seen_update {
-        tracker.last_seen_update = player.last_update;
-        tracker.last_ack = Some(Instant::now());
-
-        if let Some(send_time) = tracker.last_send.take() {
-            let rtt_ms = send_time.elapsed().as_secs_f32() * 1000.0;
-            if tracker.smoothed_rtt_ms <= 0.0 {
-                tracker.smoothed_rtt_ms = rtt_ms;
-            } else {
-                tracker.smoothed_rtt_ms = tracker.smoothed_rtt_ms * 0.8 + rtt_ms * 0.2;
-            }
+    if let Some((nonce, sent_at)) = tracker.pending {
+        let echoed = conn
+            .conn
+            .db
+            .ping_result()
+            .identity()
+            .find(&identity)
+            .filter(|row| row.nonce == nonce);
+        if let Some(row) = echoed {
+            let rtt_secs = sent_at.elapsed().as_secs_f32();
+            tracker.pending = None;
+            tracker.last_ack = Some(Instant::now());
+            tracker.record_rtt(rtt_secs * 1000.0);
+            clock.sample(row.server_time, rtt_secs);
+        } else if sent_at.elapsed().as_secs_f32() >= PING_TIMEOUT_SECS {
+            tracker.pending = None;
+            tracker.record_loss(true);
+        }
+    }
+
+    let ping_due = tracker
+        .last_sent
+        .is_none_or(|t| t.elapsed().as_secs_f32() >= PING_INTERVAL_SECS);
+    if tracker.pending.is_none() && ping_due {
+        let nonce = tracker.next_nonce;
+        tracker.next_nonce = nonce.wrapping_add(1);
+        let now = Instant::now();
+        tracker.pending = Some((nonce, now));
+        tracker.last_sent = Some(now);
+        if let Err(e) = conn.reducers().ping(nonce) {
+            warn!("Failed to send ping: {:?}", e);
+        }
+    }
+
+    let report_due = tracker
+        .last_report
+        .is_none_or(|t| t.elapsed().as_secs_f32() >= RTT_REPORT_INTERVAL_SECS);
+    if report_due && tracker.smoothed_rtt_ms > 0.0 {
+        tracker.last_report = Some(Instant::now());
+        if let Err(e) = conn.reducers().report_rtt(tracker.smoothed_rtt_ms) {
+            warn!("Failed to send report_rtt: {:?}", e);
         }
     }
 }
@@ -0,0 +1,128 @@
+//! Boss telegraphs: mirrors `telegraph` rows as ground markers showing where
+//! a special attack will land. An inner fill grows from the center and covers
+//! the whole area at the moment the hit lands. The server decides who gets
+//! hit; the row disappearing despawns the marker.
+
+use bevy::prelude::*;
+use spacetimedb_sdk::Table;
+use std::collections::HashSet;
+use std::f32::consts::FRAC_PI_2;
+use wasm_fantasia_shared::boss::TelegraphShape;
+
+use super::SpacetimeDbConnection;
+use super::generated::telegraph_table::TelegraphTableAccess;
+use crate::models::Screen;
+
+/// Ground marker for a boss attack winding up, keyed by its `telegraph` row id.
+#[derive(Component, Debug, Clone, Copy)]
+pub(super) struct TelegraphMarker {
+    id: u64,
+    /// `Time::elapsed_secs` when the marker appeared.
+    shown_at: f32,
+    windup_secs: f32,
+}
+
+/// The part of a marker that fills up during the wind-up.
+#[derive(Component)]
+pub(super) struct TelegraphFill;
+
+/// Materials shared by every marker. Meshes differ per attack.
+pub(super) struct TelegraphAssets {
+    outline: Handle<StandardMaterial>,
+    fill: Handle<StandardMaterial>,
+}
+
+/// Markers sit just above the arena floor, with the fill above the outline so
+/// they don't z-fight.
+const OUTLINE_HEIGHT: f32 = 0.03;
+const FILL_HEIGHT: f32 = 0.04;
+
+pub(super) fn reconcile_telegraphs(
+    conn: Res<SpacetimeDbConnection>,
+    time: Res<Time>,
+    markers: Query<(Entity, &TelegraphMarker)>,
+    mut assets: Local<Option<TelegraphAssets>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut commands: Commands,
+) {
+    let rows: Vec<_> = conn.conn.db.telegraph().iter().collect();
+
+    let mut present = HashSet::new();
+    for (entity, marker) in &markers {
+        if rows.iter().any(|r| r.id == marker.id) {
+            present.insert(marker.id);
+        } else {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    let assets = assets.get_or_insert_with(|| TelegraphAssets {
+        outline: materials.add(StandardMaterial {
+            base_color: Color::srgba(0.9, 0.15, 0.1, 0.25),
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            double_sided: true,
+            cull_mode: None,
+            ..default()
+        }),
+        fill: materials.add(StandardMaterial {
+            base_color: Color::srgba(1.0, 0.25, 0.1, 0.5),
+            emissive: LinearRgba::new(3.0, 0.6, 0.2, 1.0),
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            double_sided: true,
+            cull_mode: None,
+            ..default()
+        }),
+    });
+
+    for row in rows.iter().filter(|r| !present.contains(&r.id)) {
+        let mesh = match TelegraphShape::parse_str(&row.shape) {
+            TelegraphShape::Circle => meshes.add(Circle::new(row.radius)),
+            TelegraphShape::Cone => {
+                meshes.add(CircularSector::new(row.radius, row.arc.to_radians() / 2.0))
+            }
+        };
+        // Flat meshes face +Z and point up +Y; lay them on the ground with
+        // +Y along the attack's facing
+        let rotation = Quat::from_rotation_y(row.rotation_y) * Quat::from_rotation_x(-FRAC_PI_2);
+        commands
+            .spawn((
+                Name::new(format!("Telegraph {}", row.attack)),
+                TelegraphMarker {
+                    id: row.id,
+                    shown_at: time.elapsed_secs(),
+                    windup_secs: (row.lands_at - row.started_at) as f32 / 1_000_000.0,
+                },
+                Mesh3d(mesh.clone()),
+                MeshMaterial3d(assets.outline.clone()),
+                Transform::from_xyz(row.x, OUTLINE_HEIGHT, row.z).with_rotation(rotation),
+                DespawnOnExit(Screen::Gameplay),
+            ))
+            .with_child((
+                TelegraphFill,
+                Mesh3d(mesh),
+                MeshMaterial3d(assets.fill.clone()),
+                Transform::from_xyz(0.0, 0.0, FILL_HEIGHT - OUTLINE_HEIGHT).with_scale(Vec3::ZERO),
+            ));
+    }
+}
+
+/// Grow each marker's fill with its wind-up, so a full marker means the hit
+/// is landing.
+pub(super) fn fill_telegraphs(
+    time: Res<Time>,
+    markers: Query<(&TelegraphMarker, &Children)>,
+    mut fills: Query<&mut Transform, With<TelegraphFill>>,
+) {
+    for (marker, children) in &markers {
+        let progress = ((time.elapsed_secs() - marker.shown_at) / marker.windup_secs.max(0.01))
+            .clamp(0.0, 1.0);
+        for child in children.iter() {
+            if let Ok(mut transform) = fills.get_mut(child) {
+                transform.scale = Vec3::new(progress, progress, 1.0);
+            }
+        }
+    }
+}
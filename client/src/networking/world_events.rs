@@ -0,0 +1,52 @@
+//! Scheduled world events: watches `world_event` rows for the current world
+//! and fires [`WorldEventAnnounced`] as each one starts and ends.
+
+use bevy::prelude::*;
+use spacetimedb_sdk::Table;
+use std::collections::HashMap;
+use wasm_fantasia_shared::world_events::{WorldEventKind, WorldEventPhase};
+
+use super::SpacetimeDbConnection;
+use super::generated::world_event_table::WorldEventTableAccess;
+
+/// A world event started or ended. Also fired for events already running
+/// when the client joins.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct WorldEventAnnounced {
+    pub kind: WorldEventKind,
+    /// `false` when it ended.
+    pub started: bool,
+}
+
+pub(super) fn announce_world_events(
+    conn: Res<SpacetimeDbConnection>,
+    mut seen: Local<HashMap<u64, WorldEventPhase>>,
+    mut commands: Commands,
+) {
+    let rows: Vec<_> = conn.conn.db.world_event().iter().collect();
+    seen.retain(|id, _| rows.iter().any(|r| r.id == *id));
+
+    for row in rows {
+        let Some(kind) = WorldEventKind::parse_str(&row.kind) else {
+            continue;
+        };
+        let phase = WorldEventPhase::parse_str(&row.phase);
+        let previous = seen.insert(row.id, phase);
+        if previous == Some(phase) {
+            continue;
+        }
+        match phase {
+            WorldEventPhase::Active => commands.trigger(WorldEventAnnounced {
+                kind,
+                started: true,
+            }),
+            // Only announce the end of events we saw running
+            WorldEventPhase::Ended if previous == Some(WorldEventPhase::Active) => commands
+                .trigger(WorldEventAnnounced {
+                    kind,
+                    started: false,
+                }),
+            _ => {}
+        }
+    }
+}
@@ -1,12 +1,18 @@
+use super::hit_reaction::add_hit_reaction_layer;
 use super::*;
 use crate::combat::AttackState;
 use crate::player::control::{GroundPoundState, JumpCharge, LandingStun, RollingState};
-use crate::rules::{Stat, Stats};
+use crate::rules::{Stat, Stats, StatusEffects, Stunned};
+use bevy::animation::AnimationTargetId;
 use bevy_tnua::{TnuaAnimatingState, TnuaAnimatingStateDirective};
+use wasm_fantasia_shared::net::AnimationId;
 
 mod anim_knobs {
     pub const GENERAL_SPEED: f32 = 0.1;
     pub const CROUCH_ANIMATION_SPEED: f32 = 2.2;
+    /// Seconds of standing still before an idle variation plays (randomized in range).
+    pub const IDLE_VARIATION_DELAY_MIN: f32 = 8.0;
+    pub const IDLE_VARIATION_DELAY_MAX: f32 = 15.0;
 }
 
 /// Track which attack animation is playing to detect new attacks reliably
@@ -16,11 +22,38 @@ pub struct AttackAnimationState {
     pub last_attack_count: u32,
 }
 
+/// Countdown to the next idle variation. Restarts whenever the player does
+/// anything but stand still.
+#[derive(Component)]
+pub struct IdleVariationTimer {
+    remaining: f32,
+}
+
+impl Default for IdleVariationTimer {
+    fn default() -> Self {
+        Self {
+            remaining: anim_knobs::IDLE_VARIATION_DELAY_MIN,
+        }
+    }
+}
+
+impl IdleVariationTimer {
+    fn restart(&mut self) {
+        self.remaining = rand::Rng::random_range(
+            &mut rand::rng(),
+            anim_knobs::IDLE_VARIATION_DELAY_MIN..anim_knobs::IDLE_VARIATION_DELAY_MAX,
+        );
+    }
+}
+
 /// GLTF animation clips the game uses. Single source of truth for both local and remote players.
 /// Unused clips are skipped during loading to save memory (especially on WASM).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
 pub enum Animation {
     Idle,
+    IdleFoldArms,
+    IdleNo,
+    IdleTalking,
     JogFwd,
     Sprint,
     JumpStart,
@@ -33,6 +66,8 @@ pub enum Animation {
     CrouchFwd,
     CrouchIdle,
     HitChest,
+    HitHead,
+    HitKnockback,
     PunchJab,
     PunchCross,
     MeleeHook,
@@ -45,6 +80,9 @@ impl Animation {
     /// All variants — used for loading and validation.
     pub const ALL: &[Animation] = &[
         Self::Idle,
+        Self::IdleFoldArms,
+        Self::IdleNo,
+        Self::IdleTalking,
         Self::JogFwd,
         Self::Sprint,
         Self::JumpStart,
@@ -57,6 +95,8 @@ impl Animation {
         Self::CrouchFwd,
         Self::CrouchIdle,
         Self::HitChest,
+        Self::HitHead,
+        Self::HitKnockback,
         Self::PunchJab,
         Self::PunchCross,
         Self::MeleeHook,
@@ -65,10 +105,17 @@ impl Animation {
         Self::ZombieScratch,
     ];
 
+    /// Fidgets that break up a long idle. Played once, then back to [`Self::Idle`].
+    pub const IDLE_VARIATIONS: &[Animation] =
+        &[Self::IdleFoldArms, Self::IdleNo, Self::IdleTalking];
+
     /// Maps to the clip name inside the GLTF file.
     pub fn clip_name(self) -> &'static str {
         match self {
             Self::Idle => "Idle_Loop",
+            Self::IdleFoldArms => "Idle_FoldArms_Loop",
+            Self::IdleNo => "Idle_No_Loop",
+            Self::IdleTalking => "Idle_Talking_Loop",
             Self::JogFwd => "Jog_Fwd_Loop",
             Self::Sprint => "Sprint_Loop",
             Self::JumpStart => "Jump_Start",
@@ -81,6 +128,8 @@ impl Animation {
             Self::CrouchFwd => "Crouch_Fwd_Loop",
             Self::CrouchIdle => "Crouch_Idle_Loop",
             Self::HitChest => "Hit_Chest",
+            Self::HitHead => "Hit_Head",
+            Self::HitKnockback => "Hit_Knockback",
             Self::PunchJab => "Punch_Jab",
             Self::PunchCross => "Punch_Cross",
             Self::MeleeHook => "Melee_Hook",
@@ -94,6 +143,41 @@ impl Animation {
     pub fn from_clip_name(name: &str) -> Option<Self> {
         Self::ALL.iter().find(|a| a.clip_name() == name).copied()
     }
+
+    /// Wire id, for syncing attacks and idle variations to other clients.
+    pub fn wire_id(self) -> AnimationId {
+        match self {
+            Self::Idle => AnimationId::IdleLoop,
+            Self::IdleFoldArms => AnimationId::IdleFoldArmsLoop,
+            Self::IdleNo => AnimationId::IdleNoLoop,
+            Self::IdleTalking => AnimationId::IdleTalkingLoop,
+            Self::JogFwd => AnimationId::JogFwdLoop,
+            Self::Sprint => AnimationId::SprintLoop,
+            Self::JumpStart => AnimationId::JumpStartClip,
+            Self::JumpLand => AnimationId::JumpLandClip,
+            Self::JumpLoop => AnimationId::JumpLoop,
+            Self::NinjaJumpStart => AnimationId::NinjaJumpStart,
+            Self::NinjaJumpIdle => AnimationId::NinjaJumpIdleLoop,
+            Self::NinjaJumpLand => AnimationId::NinjaJumpLand,
+            Self::Roll => AnimationId::Roll,
+            Self::CrouchFwd => AnimationId::CrouchFwdLoop,
+            Self::CrouchIdle => AnimationId::CrouchIdleLoop,
+            Self::HitChest => AnimationId::HitChest,
+            Self::HitHead => AnimationId::HitHead,
+            Self::HitKnockback => AnimationId::HitKnockback,
+            Self::PunchJab => AnimationId::PunchJab,
+            Self::PunchCross => AnimationId::PunchCross,
+            Self::MeleeHook => AnimationId::MeleeHook,
+            Self::ZombieIdle => AnimationId::ZombieIdleLoop,
+            Self::ZombieWalkForward => AnimationId::ZombieWalkFwdLoop,
+            Self::ZombieScratch => AnimationId::ZombieScratch,
+        }
+    }
+
+    /// Reverse lookup: wire id → enum variant.
+    pub fn from_wire_id(id: AnimationId) -> Option<Self> {
+        Self::ALL.iter().find(|a| a.wire_id() == id).copied()
+    }
 }
 
 /// Recursively find the first entity with AnimationPlayer in a subtree.
@@ -121,6 +205,7 @@ pub fn prepare_animations(
     gltf_assets: Res<Assets<Gltf>>,
     children_q: Query<&Children>,
     anim_players: Query<Entity, With<AnimationPlayer>>,
+    bones: Query<(&Name, &AnimationTargetId)>,
     parents: Query<&ChildOf>,
     mut player: Query<&mut Player>,
     mut commands: Commands,
@@ -184,6 +269,12 @@ pub fn prepare_animations(
 
     player.anim_player_entity = Some(animation_player);
 
+    if let Some(layer) =
+        add_hit_reaction_layer(&mut graph, gltf, animation_player, &children_q, &bones)
+    {
+        commands.entity(player_entity).insert(layer);
+    }
+
     let idle_node = player.animations.get(&Animation::Idle).copied();
     let graph_handle = animation_graphs.add(graph);
 
@@ -219,6 +310,7 @@ pub fn prepare_animations(
 /// all tnua related stuff and it should still work
 pub fn animating(
     cfg: Res<Config>,
+    time: Res<Time>,
     mut player_q: Query<(
         &TnuaController<ControlScheme>,
         &mut Player,
@@ -230,6 +322,10 @@ pub fn animating(
         Option<&RollingState>,
         Option<&LandingStun>,
         Option<&GroundPoundState>,
+        &mut IdleVariationTimer,
+        Option<&EquippedWeapon>,
+        Option<&StatusEffects>,
+        Has<Stunned>,
     )>,
     mut animation_query: Query<(&mut AnimationPlayer, &mut AnimationTransitions)>,
 ) {
@@ -244,6 +340,10 @@ pub fn animating(
         rolling_state,
         landing_stun,
         ground_pound,
+        mut idle_timer,
+        weapon,
+        statuses,
+        stunned,
     )) = player_q.single_mut()
     else {
         return;
@@ -255,7 +355,8 @@ pub fn animating(
             let speed = s.get(&Stat::AttackSpeed);
             if speed == 0.0 { 1.0 } else { speed }
         })
-        .unwrap_or(1.0);
+        .unwrap_or(1.0)
+        * statuses.map_or(1.0, StatusEffects::haste);
 
     // Look up the specific AnimationPlayer for this player entity
     let Some(anim_entity) = player.anim_player_entity else {
@@ -275,14 +376,11 @@ pub fn animating(
             // Keep TnuaAnimatingState in sync (for when attack ends)
             animating_state.update_by_discriminant(AnimationState::Attack);
 
-            // Select animation: hook for crits, alternate jab/cross for normal attacks
-            let anim = if attack.is_crit {
-                Animation::MeleeHook
-            } else if attack.attack_count % 2 == 1 {
-                Animation::PunchJab
-            } else {
-                Animation::PunchCross
-            };
+            // Select animation from the weapon's moveset: finisher for crits,
+            // the combo clips in turn otherwise
+            let moveset = moveset(weapon.map(|w| w.0).unwrap_or_default());
+            let anim = moveset.clip(attack.attack_count, attack.is_crit);
+            let base_speed = moveset.clip_speed(attack.is_crit);
 
             // Detect new attack by comparing attack_count (gameplay truth)
             // This is reliable regardless of system ordering or TnuaAnimatingState bugs
@@ -293,11 +391,9 @@ pub fn animating(
 
                 if let Some(index) = player.animations.get(&anim) {
                     // Start at base speed - wind-up should look normal
-                    // Hook is slightly slower for dramatic effect
-                    let start_speed = if attack.is_crit { 1.1 } else { 1.3 };
                     transitions
                         .play(&mut animation_player, *index, BLEND_DURATION)
-                        .set_speed(start_speed);
+                        .set_speed(base_speed);
                 }
             } else {
                 // Speed curve: keep wind-up/impact readable, speed up recovery
@@ -306,7 +402,6 @@ pub fn animating(
 
                 // Wind-up to impact (0-55%): minimal speed boost
                 // Recovery (55-100%): heavy speed boost from stacks
-                let base_speed = if attack.is_crit { 1.1 } else { 1.3 };
                 let anim_speed = if progress < 0.55 {
                     // Wind-up and impact: slight boost only
                     base_speed + (speed_mult - 1.0) * 0.25
@@ -323,6 +418,21 @@ pub fn animating(
         }
     }
 
+    // Stunned: sink into the landing crouch, slowed down, and hold it until
+    // the stun wears off. Remote players see the same clip.
+    if stunned {
+        player.animation_state = AnimationState::LandingStun;
+        let directive = animating_state.update_by_discriminant(AnimationState::LandingStun);
+        if let TnuaAnimatingStateDirective::Alter { .. } = directive {
+            if let Some(index) = player.animations.get(&Animation::NinjaJumpLand) {
+                transitions
+                    .play(&mut animation_player, *index, Duration::from_millis(80))
+                    .set_speed(0.3);
+            }
+        }
+        return;
+    }
+
     // Dodge roll: force Roll animation while rolling.
     if rolling_state.is_some() {
         player.animation_state = AnimationState::Roll;
@@ -478,6 +588,14 @@ pub fn animating(
         }
     };
 
+    let current_animation = idle_variation(
+        current_animation,
+        &player,
+        &animation_player,
+        &mut idle_timer,
+        time.delta_secs(),
+    );
+
     // Update player animation state, it could be useful in some systems
     player.animation_state = current_animation.clone();
     let animating_directive = animating_state.update_by_discriminant(current_animation);
@@ -621,7 +739,50 @@ pub fn animating(
                 AnimationState::Attack | AnimationState::GroundPound => {
                     // Handled in early return above
                 }
+                AnimationState::IdleVariation(anim) => {
+                    if let Some(index) = player.animations.get(anim) {
+                        transitions
+                            .play(&mut animation_player, *index, BLEND_DURATION)
+                            .set_speed(1.0);
+                    }
+                }
             }
         }
     }
 }
+
+/// Replace a long stretch of [`AnimationState::StandIdle`] with a random idle
+/// variation, holding it until the clip finishes.
+fn idle_variation(
+    state: AnimationState,
+    player: &Player,
+    animation_player: &AnimationPlayer,
+    timer: &mut IdleVariationTimer,
+    dt: f32,
+) -> AnimationState {
+    if !matches!(state, AnimationState::StandIdle) {
+        timer.restart();
+        return state;
+    }
+
+    if let AnimationState::IdleVariation(anim) = player.animation_state {
+        let finished = player
+            .animations
+            .get(&anim)
+            .and_then(|index| animation_player.animation(*index))
+            .is_none_or(|active| active.is_finished());
+        if finished {
+            timer.restart();
+            return state;
+        }
+        return player.animation_state.clone();
+    }
+
+    timer.remaining -= dt;
+    if timer.remaining > 0.0 {
+        return state;
+    }
+    let variations = Animation::IDLE_VARIATIONS;
+    let pick = rand::Rng::random_range(&mut rand::rng(), 0..variations.len());
+    AnimationState::IdleVariation(variations[pick])
+}
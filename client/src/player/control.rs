@@ -158,7 +158,7 @@ pub struct LandingStun {
     pub timer: Timer,
 }
 
-/// Fired when the player takes a footstep while grounded. Triggers dust VFX.
+/// Fired when the player takes a footstep while grounded. Leaves a footprint and dust.
 #[derive(Event)]
 pub struct Footstep {
     pub position: Vec3,
@@ -186,6 +186,7 @@ pub fn plugin(app: &mut App) {
                 tick_rolling_state,
                 tick_landing_stun,
                 tick_ground_pound,
+                tick_stamina,
                 process_buffered_jump.after(tick_input_buffer),
             )
                 .run_if(in_state(Screen::Gameplay)),
@@ -217,10 +218,11 @@ fn movement(
         &mut StepTimer,
         Option<&RollingState>,
         Has<GroundPoundState>,
+        Option<&StatusEffects>,
     )>,
 ) -> Result {
     let Ok(navigate) = navigate.single() else {
-        for (_player, mut controller, _step_timer, _, _) in player_query.iter_mut() {
+        for (_player, mut controller, _step_timer, _, _, _) in player_query.iter_mut() {
             controller.basis = TnuaBuiltinWalk {
                 desired_motion: Vec3::ZERO,
                 desired_forward: None,
@@ -231,7 +233,9 @@ fn movement(
     let navigate = *navigate;
     let crouch = crouch.single().copied().unwrap_or_default();
 
-    for (player, mut controller, mut step_timer, rolling, ground_pounding) in player_query.iter_mut() {
+    for (player, mut controller, mut step_timer, rolling, ground_pounding, statuses) in
+        player_query.iter_mut()
+    {
         let cam_transform = camera.single()?;
         let curved_input = apply_response_curve(*navigate, MOVEMENT_CURVE_EXPONENT);
         let direction = cam_transform.movement_direction(curved_input);
@@ -240,7 +244,9 @@ fn movement(
         let desired_motion = if rolling.is_some() || ground_pounding {
             Vec3::ZERO
         } else {
-            direction * player.speed
+            // Stuns hold the player in place, slows cut their speed
+            let impairment = statuses.map(StatusEffects::impairment).unwrap_or_default();
+            direction * player.speed * impairment.speed_multiplier
         };
 
         controller.initiate_action_feeding();
@@ -279,13 +285,17 @@ fn is_grounded(controller: &TnuaController<ControlScheme>) -> bool {
 // ── Charge Jump Observers ──────────────────────────────────────────
 
 /// Jump pressed — always begin charging (works on ground or in the air).
+/// Stunned players can't start one, so they neither jump nor roll.
 fn on_jump_start(
     on: On<Start<Jump>>,
-    mut query: Query<&mut JumpCharge, With<Player>>,
+    mut query: Query<(&mut JumpCharge, Has<Stunned>), With<Player>>,
 ) {
-    let Ok(mut charge) = query.get_mut(on.context) else {
+    let Ok((mut charge, stunned)) = query.get_mut(on.context) else {
         return;
     };
+    if stunned {
+        return;
+    }
 
     charge.charging = true;
     charge.charge_time = 0.0;
@@ -622,15 +632,20 @@ fn tick_landing_stun(
     }
 }
 
+/// Sprinting needs [`resources::SPRINT_MIN_STAMINA`] to start; the stamina
+/// tick rules drain it from there.
 fn sprint_start(
     on: On<Start<Sprint>>,
     cfg: Res<Config>,
-    mut player: Query<&mut Player, With<PlayerCtx>>,
+    mut player: Query<(&mut Player, &Stats), With<PlayerCtx>>,
     mut commands: Commands,
 ) {
-    let Ok(mut player) = player.get_mut(on.context) else {
+    let Ok((mut player, stats)) = player.get_mut(on.context) else {
         return;
     };
+    if stats.get(&Stat::Stamina) < resources::SPRINT_MIN_STAMINA {
+        return;
+    }
     player.speed = cfg.player.movement.speed * cfg.player.movement.sprint_factor;
     commands.entity(on.context).try_insert(Sprinting);
 }
@@ -648,6 +663,26 @@ fn sprint_end(
     commands.entity(on.context).try_remove::<Sprinting>();
 }
 
+/// Mirror sprinting into [`Stat::IsSprinting`] for the stamina rules, and
+/// drop back to walking once they've drained stamina to zero.
+fn tick_stamina(
+    cfg: Res<Config>,
+    mut query: Query<(Entity, &mut Player, &mut Stats, Has<Sprinting>)>,
+    mut commands: Commands,
+) {
+    for (entity, mut player, mut stats, sprinting) in query.iter_mut() {
+        let exhausted = sprinting && stats.get(&Stat::Stamina) <= 0.0;
+        if exhausted {
+            player.speed = cfg.player.movement.speed;
+            commands.entity(entity).try_remove::<Sprinting>();
+        }
+        let flag = if sprinting && !exhausted { 1.0 } else { 0.0 };
+        if stats.get(&Stat::IsSprinting) != flag {
+            stats.set(Stat::IsSprinting, flag);
+        }
+    }
+}
+
 pub fn crouch_in(
     on: On<Start<Crouch>>,
     cfg: Res<Config>,
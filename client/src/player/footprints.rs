@@ -0,0 +1,438 @@
+//! Footprints and dust puffs tied to locomotion: every footstep leaves a
+//! fading print, and landings, dodge rolls and sharp sprint turns kick up
+//! dust. Remote players get prints too — their steps are inferred from the
+//! distance they cover — so you can read where others are running.
+//!
+//! Everything is drawn from two fixed pools spawned on entering gameplay,
+//! recycled oldest-first, and effects further than [`CULL_DISTANCE`] from the
+//! camera are skipped.
+
+use crate::models::{Player, SceneCamera, Screen};
+use crate::player::RemotePlayer;
+use crate::player::control::{Footstep, LandingImpact, RollingState, Sprinting};
+use avian3d::prelude::LinearVelocity;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+const FOOTPRINT_POOL: usize = 64;
+const PUFF_POOL: usize = 48;
+/// Effects further than this from the camera aren't spawned.
+const CULL_DISTANCE: f32 = 40.0;
+
+const FOOTPRINT_LIFETIME: f32 = 4.0;
+const FOOTPRINT_ALPHA: f32 = 0.45;
+/// Half width and half length of a print.
+const FOOTPRINT_SIZE: Vec2 = Vec2::new(0.07, 0.13);
+/// Sideways offset of each foot from the body's centre line.
+const FOOT_SPACING: f32 = 0.12;
+/// Distance from a character's origin down to the soles.
+const FEET_DEPTH: f32 = 1.0;
+
+const PUFF_LIFETIME: f32 = 0.45;
+const PUFF_ALPHA: f32 = 0.5;
+
+/// Distance a remote player covers per footstep.
+const REMOTE_STRIDE: f32 = 1.1;
+/// Per-frame jumps larger than this are teleports (respawns), not steps.
+const REMOTE_TELEPORT: f32 = 3.0;
+/// Vertical movement per frame above which a remote player counts as airborne.
+const REMOTE_AIRBORNE_DY: f32 = 0.05;
+
+/// A sprint turn is a heading change sharper than this (cosine of ~50°)
+/// within one check interval.
+const SPRINT_TURN_DOT: f32 = 0.65;
+const SPRINT_TURN_INTERVAL: f32 = 0.15;
+const SPRINT_TURN_MIN_SPEED: f32 = 4.0;
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Gameplay), spawn_pools)
+        .add_systems(OnExit(Screen::Gameplay), remove_pools)
+        .add_systems(
+            Update,
+            (
+                tick_footprints,
+                tick_puffs,
+                detect_sprint_turns,
+                remote_footsteps,
+            )
+                .run_if(in_state(Screen::Gameplay)),
+        )
+        .add_observer(on_footstep)
+        .add_observer(on_landing)
+        .add_observer(on_roll)
+        .add_observer(track_remote_strides);
+}
+
+// ── Pools ───────────────────────────────────────────────────────────
+
+#[derive(Resource)]
+struct FxPools {
+    footprints: Vec<Entity>,
+    next_footprint: usize,
+    puffs: Vec<Entity>,
+    next_puff: usize,
+}
+
+#[derive(Component, Default)]
+struct Footprint {
+    active: bool,
+    age: f32,
+}
+
+#[derive(Component, Default)]
+struct DustPuff {
+    active: bool,
+    age: f32,
+    velocity: Vec3,
+}
+
+/// Each pooled entity owns its material so it can fade independently.
+fn spawn_pools(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let print_mesh = meshes.add(Plane3d::new(Vec3::Y, FOOTPRINT_SIZE));
+    let puff_mesh = meshes.add(Sphere::new(0.12).mesh().ico(1).unwrap());
+
+    let footprints = (0..FOOTPRINT_POOL)
+        .map(|_| {
+            let material = materials.add(StandardMaterial {
+                base_color: Color::srgba(0.12, 0.1, 0.08, FOOTPRINT_ALPHA),
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                depth_bias: 10.0,
+                ..default()
+            });
+            commands
+                .spawn((
+                    Name::new("Footprint"),
+                    Footprint::default(),
+                    Mesh3d(print_mesh.clone()),
+                    MeshMaterial3d(material),
+                    Transform::default(),
+                    Visibility::Hidden,
+                    DespawnOnExit(Screen::Gameplay),
+                ))
+                .id()
+        })
+        .collect();
+
+    let puffs = (0..PUFF_POOL)
+        .map(|_| {
+            let material = materials.add(StandardMaterial {
+                base_color: Color::srgba(0.72, 0.66, 0.58, PUFF_ALPHA),
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                ..default()
+            });
+            commands
+                .spawn((
+                    Name::new("DustPuff"),
+                    DustPuff::default(),
+                    Mesh3d(puff_mesh.clone()),
+                    MeshMaterial3d(material),
+                    Transform::default(),
+                    Visibility::Hidden,
+                    DespawnOnExit(Screen::Gameplay),
+                ))
+                .id()
+        })
+        .collect();
+
+    commands.insert_resource(FxPools {
+        footprints,
+        next_footprint: 0,
+        puffs,
+        next_puff: 0,
+    });
+}
+
+fn remove_pools(mut commands: Commands) {
+    commands.remove_resource::<FxPools>();
+}
+
+/// Emits footprints and puffs from the pools.
+#[derive(SystemParam)]
+struct LocomotionFx<'w, 's> {
+    pools: Option<ResMut<'w, FxPools>>,
+    camera: Query<'w, 's, &'static GlobalTransform, With<SceneCamera>>,
+    footprints: Query<
+        'w,
+        's,
+        (
+            &'static mut Transform,
+            &'static mut Visibility,
+            &'static mut Footprint,
+        ),
+        Without<DustPuff>,
+    >,
+    puffs: Query<
+        'w,
+        's,
+        (
+            &'static mut Transform,
+            &'static mut Visibility,
+            &'static mut DustPuff,
+        ),
+        Without<Footprint>,
+    >,
+}
+
+impl LocomotionFx<'_, '_> {
+    fn in_range(&self, position: Vec3) -> bool {
+        let Ok(camera) = self.camera.single() else {
+            return true;
+        };
+        camera.translation().distance_squared(position) < CULL_DISTANCE * CULL_DISTANCE
+    }
+
+    /// Print one foot at `feet`, offset sideways from the centre line.
+    fn footprint(&mut self, feet: Vec3, rotation: Quat, left: bool) {
+        if !self.in_range(feet) {
+            return;
+        }
+        let Some(pools) = self.pools.as_mut() else {
+            return;
+        };
+        let entity = pools.footprints[pools.next_footprint];
+        pools.next_footprint = (pools.next_footprint + 1) % pools.footprints.len();
+
+        let Ok((mut transform, mut visibility, mut print)) = self.footprints.get_mut(entity) else {
+            return;
+        };
+        let side = if left { -FOOT_SPACING } else { FOOT_SPACING };
+        // Only the yaw matters for a print on flat ground
+        let (yaw, _, _) = rotation.to_euler(EulerRot::YXZ);
+        let rotation = Quat::from_rotation_y(yaw);
+        transform.translation = feet + rotation * Vec3::X * side + Vec3::Y * 0.02;
+        transform.rotation = rotation;
+        *visibility = Visibility::Visible;
+        *print = Footprint {
+            active: true,
+            age: 0.0,
+        };
+    }
+
+    /// Kick up `count` puffs around `feet`, biased along `drift`.
+    fn puffs(&mut self, feet: Vec3, count: usize, drift: Vec3) {
+        if !self.in_range(feet) {
+            return;
+        }
+        let Some(pools) = self.pools.as_mut() else {
+            return;
+        };
+        let mut rng = rand::rng();
+        for _ in 0..count {
+            let entity = pools.puffs[pools.next_puff];
+            pools.next_puff = (pools.next_puff + 1) % pools.puffs.len();
+            let Ok((mut transform, mut visibility, mut puff)) = self.puffs.get_mut(entity) else {
+                continue;
+            };
+
+            let angle = rand::Rng::random_range(&mut rng, 0.0..std::f32::consts::TAU);
+            let speed = rand::Rng::random_range(&mut rng, 0.6..1.6);
+            let spread = Vec3::new(angle.cos(), 0.3, angle.sin()) * speed;
+            transform.translation = feet + Vec3::Y * 0.1;
+            transform.scale = Vec3::ONE;
+            *visibility = Visibility::Visible;
+            *puff = DustPuff {
+                active: true,
+                age: 0.0,
+                velocity: spread + drift,
+            };
+        }
+    }
+}
+
+fn tick_footprints(
+    time: Res<Time>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut prints: Query<(
+        &mut Visibility,
+        &mut Footprint,
+        &MeshMaterial3d<StandardMaterial>,
+    )>,
+) {
+    for (mut visibility, mut print, material) in &mut prints {
+        if !print.active {
+            continue;
+        }
+        print.age += time.delta_secs();
+        if print.age >= FOOTPRINT_LIFETIME {
+            print.active = false;
+            *visibility = Visibility::Hidden;
+            continue;
+        }
+        // Hold, then fade over the last half
+        let fade = (2.0 - 2.0 * print.age / FOOTPRINT_LIFETIME).min(1.0);
+        if let Some(material) = materials.get_mut(&material.0) {
+            material.base_color.set_alpha(FOOTPRINT_ALPHA * fade);
+        }
+    }
+}
+
+fn tick_puffs(
+    time: Res<Time>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut puffs: Query<(
+        &mut Transform,
+        &mut Visibility,
+        &mut DustPuff,
+        &MeshMaterial3d<StandardMaterial>,
+    )>,
+) {
+    let dt = time.delta_secs();
+    for (mut transform, mut visibility, mut puff, material) in &mut puffs {
+        if !puff.active {
+            continue;
+        }
+        puff.age += dt;
+        if puff.age >= PUFF_LIFETIME {
+            puff.active = false;
+            *visibility = Visibility::Hidden;
+            continue;
+        }
+        let t = puff.age / PUFF_LIFETIME;
+        puff.velocity *= 1.0 - 4.0 * dt;
+        transform.translation += puff.velocity * dt;
+        transform.scale = Vec3::splat(1.0 + 1.5 * t);
+        if let Some(material) = materials.get_mut(&material.0) {
+            material.base_color.set_alpha(PUFF_ALPHA * (1.0 - t));
+        }
+    }
+}
+
+// ── Local player ────────────────────────────────────────────────────
+
+fn on_footstep(
+    on: On<Footstep>,
+    player: Query<&GlobalTransform, With<Player>>,
+    mut left: Local<bool>,
+    mut fx: LocomotionFx,
+) {
+    let Ok(transform) = player.single() else {
+        return;
+    };
+    let feet = on.event().position - Vec3::Y * FEET_DEPTH;
+    *left = !*left;
+    fx.footprint(feet, transform.rotation(), *left);
+    fx.puffs(feet, 2, Vec3::ZERO);
+}
+
+fn on_landing(
+    on: On<LandingImpact>,
+    player: Query<&GlobalTransform, With<Player>>,
+    mut fx: LocomotionFx,
+) {
+    let Ok(transform) = player.single() else {
+        return;
+    };
+    let event = on.event();
+    let feet = event.position - Vec3::Y * FEET_DEPTH;
+    fx.footprint(feet, transform.rotation(), true);
+    fx.footprint(feet, transform.rotation(), false);
+    // Lingering dust under the debris the landing VFX throws out
+    let count = (event.velocity_y * 0.3).clamp(2.0, 6.0) as usize;
+    fx.puffs(feet, count, Vec3::ZERO);
+}
+
+fn on_roll(
+    on: On<Add, RollingState>,
+    rolls: Query<(&GlobalTransform, &RollingState)>,
+    mut fx: LocomotionFx,
+) {
+    let Ok((transform, rolling)) = rolls.get(on.entity) else {
+        return;
+    };
+    // Dust trails behind the roll
+    fx.puffs(
+        transform.translation() - Vec3::Y * FEET_DEPTH,
+        6,
+        -rolling.direction.with_y(0.0) * 1.5,
+    );
+}
+
+#[derive(Default)]
+struct SprintTurnTracker {
+    heading: Option<Vec2>,
+    since_check: f32,
+}
+
+/// Plant a foot and throw dust when a sprint changes direction sharply.
+fn detect_sprint_turns(
+    time: Res<Time>,
+    player: Query<(&GlobalTransform, &LinearVelocity), (With<Player>, With<Sprinting>)>,
+    mut tracker: Local<SprintTurnTracker>,
+    mut fx: LocomotionFx,
+) {
+    let Ok((transform, velocity)) = player.single() else {
+        tracker.heading = None;
+        return;
+    };
+    let horizontal = Vec2::new(velocity.x, velocity.z);
+    if horizontal.length() < SPRINT_TURN_MIN_SPEED {
+        tracker.heading = None;
+        return;
+    }
+
+    tracker.since_check += time.delta_secs();
+    if tracker.since_check < SPRINT_TURN_INTERVAL {
+        return;
+    }
+    tracker.since_check = 0.0;
+
+    let heading = horizontal.normalize();
+    if tracker
+        .heading
+        .is_some_and(|old| old.dot(heading) < SPRINT_TURN_DOT)
+    {
+        let feet = transform.translation() - Vec3::Y * FEET_DEPTH;
+        let outward = -Vec3::new(heading.x, 0.0, heading.y);
+        fx.footprint(feet, transform.rotation(), true);
+        fx.puffs(feet, 5, outward);
+    }
+    tracker.heading = Some(heading);
+}
+
+// ── Remote players ──────────────────────────────────────────────────
+
+/// Distance a remote player has covered since their last inferred step.
+#[derive(Component, Default)]
+struct RemoteStride {
+    last_position: Option<Vec3>,
+    travelled: f32,
+    left: bool,
+}
+
+fn track_remote_strides(on: On<Add, RemotePlayer>, mut commands: Commands) {
+    commands.entity(on.entity).insert(RemoteStride::default());
+}
+
+fn remote_footsteps(
+    mut remotes: Query<(&GlobalTransform, &mut RemoteStride), With<RemotePlayer>>,
+    mut fx: LocomotionFx,
+) {
+    for (transform, mut stride) in &mut remotes {
+        let position = transform.translation();
+        let Some(last) = stride.last_position.replace(position) else {
+            continue;
+        };
+
+        let delta = position - last;
+        let step = delta.with_y(0.0).length();
+        if step > REMOTE_TELEPORT || delta.y.abs() > REMOTE_AIRBORNE_DY {
+            stride.travelled = 0.0;
+            continue;
+        }
+
+        stride.travelled += step;
+        if stride.travelled >= REMOTE_STRIDE {
+            stride.travelled -= REMOTE_STRIDE;
+            stride.left = !stride.left;
+            let feet = position - Vec3::Y * FEET_DEPTH;
+            fx.footprint(feet, transform.rotation(), stride.left);
+            fx.puffs(feet, 1, Vec3::ZERO);
+        }
+    }
+}
@@ -0,0 +1,122 @@
+//! Head tracking — turns the head bone toward the locked target or the
+//! nearest enemy, layered on top of whatever clip is playing.
+
+use super::*;
+use crate::combat::{Enemy, LockedTarget};
+use bevy::animation::AnimationSystems;
+
+/// Name of the head joint in the player rig.
+const HEAD_BONE: &str = "DEF-head";
+/// Enemies further than this are ignored.
+const LOOK_RANGE: f32 = 12.0;
+/// Head height above the character origin, and chest height above an enemy's.
+const EYE_HEIGHT: f32 = 0.6;
+const TARGET_HEIGHT: f32 = 0.4;
+const MAX_YAW: f32 = 70_f32.to_radians();
+const MAX_PITCH: f32 = 30_f32.to_radians();
+/// How quickly the head catches up to the target direction (1/sec).
+const TURN_SPEED: f32 = 8.0;
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(
+        PostUpdate,
+        (find_head_bones, aim_heads)
+            .chain()
+            .after(AnimationSystems)
+            .before(TransformSystems::Propagate)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+/// Head tracking state for a player, local or remote.
+#[derive(Component, Default)]
+pub struct HeadLook {
+    bone: Option<Entity>,
+    yaw: f32,
+    pitch: f32,
+}
+
+/// Resolve the head bone once the character's scene has spawned.
+fn find_head_bones(
+    mut lookers: Query<(Entity, &mut HeadLook)>,
+    children: Query<&Children>,
+    names: Query<&Name>,
+) {
+    for (entity, mut look) in &mut lookers {
+        if look.bone.is_some() {
+            continue;
+        }
+        look.bone = children
+            .iter_descendants(entity)
+            .find(|e| names.get(*e).is_ok_and(|name| name.as_str() == HEAD_BONE));
+    }
+}
+
+/// Runs after animation so the rotation is applied on top of this frame's pose.
+fn aim_heads(
+    time: Res<Time>,
+    locked: Res<LockedTarget>,
+    mut lookers: Query<(&Transform, &mut HeadLook, Has<Player>)>,
+    enemies: Query<&Transform, (With<Enemy>, Without<HeadLook>)>,
+    mut bones: Query<(&mut Transform, &ChildOf), (Without<HeadLook>, Without<Enemy>)>,
+    globals: Query<&GlobalTransform>,
+) {
+    let alpha = (TURN_SPEED * time.delta_secs()).min(1.0);
+
+    for (transform, mut look, is_local) in &mut lookers {
+        let Some(bone) = look.bone else {
+            continue;
+        };
+        let eye = transform.translation + Vec3::Y * EYE_HEIGHT;
+
+        // Only the local player knows its locked target; everyone else
+        // watches the closest enemy.
+        let locked_pos = locked
+            .get()
+            .filter(|_| is_local)
+            .and_then(|e| enemies.get(e).ok())
+            .map(|t| t.translation);
+        let target = locked_pos.or_else(|| {
+            enemies
+                .iter()
+                .map(|t| t.translation)
+                .filter(|pos| pos.distance_squared(eye) <= LOOK_RANGE * LOOK_RANGE)
+                .min_by(|a, b| a.distance_squared(eye).total_cmp(&b.distance_squared(eye)))
+        });
+
+        let (yaw, pitch) = target
+            .map(|pos| {
+                let to_target = pos + Vec3::Y * TARGET_HEIGHT - eye;
+                let forward = transform.forward().as_vec3();
+                let yaw = forward.xz().angle_to(to_target.xz());
+                let pitch = to_target.y.atan2(to_target.xz().length());
+                // Past the yaw limit the head would snap around — look ahead instead
+                if yaw.abs() > MAX_YAW {
+                    (0.0, 0.0)
+                } else {
+                    (yaw, pitch.clamp(-MAX_PITCH, MAX_PITCH))
+                }
+            })
+            .unwrap_or_default();
+        look.yaw += (yaw - look.yaw) * alpha;
+        look.pitch += (pitch - look.pitch) * alpha;
+
+        let Ok((mut bone_transform, parent)) = bones.get_mut(bone) else {
+            continue;
+        };
+        let Ok(parent_global) = globals.get(parent.parent()) else {
+            continue;
+        };
+
+        // Build the turn in world space, then express it in the bone's parent
+        // frame so it composes with the animated local rotation.
+        let yaw_rot = Quat::from_rotation_y(-look.yaw);
+        let facing = yaw_rot * transform.forward().as_vec3();
+        let pitch_axis = facing.cross(Vec3::Y).normalize_or_zero();
+        let world_turn = Quat::from_axis_angle(pitch_axis, look.pitch) * yaw_rot;
+
+        let parent_rot = parent_global.rotation();
+        bone_transform.rotation =
+            parent_rot.inverse() * world_turn * parent_rot * bone_transform.rotation;
+    }
+}
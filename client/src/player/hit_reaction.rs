@@ -0,0 +1,129 @@
+//! Flinch layer for skeletal characters. Reaction clips play on top of
+//! whatever locomotion clip is running; light hits are masked to the upper
+//! body so the legs keep moving.
+
+use super::*;
+use crate::combat::HitReacted;
+use bevy::animation::{ActiveAnimation, AnimationTargetId};
+use wasm_fantasia_shared::combat::HitReaction;
+
+/// Mask group for the hips and legs.
+const LOWER_BODY_GROUP: u32 = 0;
+const LOWER_BODY_BONES: &[&str] = &[
+    "root",
+    "DEF-hips",
+    "DEF-thighL",
+    "DEF-shinL",
+    "DEF-footL",
+    "DEF-toeL",
+    "DEF-thighR",
+    "DEF-shinR",
+    "DEF-footR",
+    "DEF-toeR",
+];
+/// Blend weight of the reaction against the clip underneath.
+const REACTION_WEIGHT: f32 = 1.0;
+
+pub fn plugin(app: &mut App) {
+    app.add_observer(on_hit_reacted).add_systems(
+        Update,
+        stop_finished_reactions.run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+/// Reaction clip nodes in a character's animation graph, one per
+/// [`HitReaction`]. Lives on the character entity.
+#[derive(Component)]
+pub struct HitReactionLayer {
+    animation_player: Entity,
+    light: AnimationNodeIndex,
+    medium: AnimationNodeIndex,
+    heavy: AnimationNodeIndex,
+}
+
+impl HitReactionLayer {
+    fn node(&self, reaction: HitReaction) -> AnimationNodeIndex {
+        match reaction {
+            HitReaction::Light => self.light,
+            HitReaction::Medium => self.medium,
+            HitReaction::Heavy => self.heavy,
+        }
+    }
+
+    fn nodes(&self) -> [AnimationNodeIndex; 3] {
+        [self.light, self.medium, self.heavy]
+    }
+}
+
+/// Add the reaction clips to `graph`. Returns `None` when the model is
+/// missing any of them.
+pub(super) fn add_hit_reaction_layer(
+    graph: &mut AnimationGraph,
+    gltf: &Gltf,
+    animation_player: Entity,
+    children_q: &Query<&Children>,
+    bones: &Query<(&Name, &AnimationTargetId)>,
+) -> Option<HitReactionLayer> {
+    for bone in children_q.iter_descendants(animation_player) {
+        let Ok((name, target)) = bones.get(bone) else {
+            continue;
+        };
+        if LOWER_BODY_BONES.contains(&name.as_str()) {
+            graph.add_target_to_mask_group(*target, LOWER_BODY_GROUP);
+        }
+    }
+
+    let clip = |anim: Animation| gltf.named_animations.get(anim.clip_name()).cloned();
+    let root = graph.root;
+    Some(HitReactionLayer {
+        animation_player,
+        light: graph.add_clip_with_mask(
+            clip(Animation::HitHead)?,
+            1 << LOWER_BODY_GROUP,
+            REACTION_WEIGHT,
+            root,
+        ),
+        medium: graph.add_clip(clip(Animation::HitChest)?, REACTION_WEIGHT, root),
+        heavy: graph.add_clip(clip(Animation::HitKnockback)?, REACTION_WEIGHT, root),
+    })
+}
+
+fn on_hit_reacted(
+    on: On<HitReacted>,
+    layers: Query<&HitReactionLayer>,
+    mut animation_players: Query<&mut AnimationPlayer>,
+) {
+    let event = on.event();
+    let Ok(layer) = layers.get(event.entity) else {
+        return;
+    };
+    let Ok(mut animation_player) = animation_players.get_mut(layer.animation_player) else {
+        return;
+    };
+
+    // A new hit cuts off the previous flinch rather than stacking on it
+    for node in layer.nodes() {
+        animation_player.stop(node);
+    }
+    animation_player.start(layer.node(event.reaction));
+}
+
+/// Finished one-shot clips hold their last frame, so drop them explicitly.
+fn stop_finished_reactions(
+    layers: Query<&HitReactionLayer>,
+    mut animation_players: Query<&mut AnimationPlayer>,
+) {
+    for layer in &layers {
+        let Ok(mut animation_player) = animation_players.get_mut(layer.animation_player) else {
+            continue;
+        };
+        for node in layer.nodes() {
+            if animation_player
+                .animation(node)
+                .is_some_and(ActiveAnimation::is_finished)
+            {
+                animation_player.stop(node);
+            }
+        }
+    }
+}
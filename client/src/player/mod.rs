@@ -1,8 +1,9 @@
-use crate::combat::{AttackState, Combatant, Health, PlayerCombatant};
+use crate::combat::{AttackState, Combatant, Health, PlayerCombatant, Shield};
 use crate::rule_presets;
-use crate::rules::{Stat, Stats};
+use crate::rules::{Stat, Stats, StatusEffects, Stunned};
 use crate::*;
 use avian3d::prelude::*;
+use bevy::animation::AnimationTargetId;
 use bevy::scene::SceneInstanceReady;
 use bevy_enhanced_input::prelude::*;
 use bevy_third_person_camera::*;
@@ -14,6 +15,7 @@ use bevy_tnua_avian3d::*;
 use std::collections::HashMap;
 use std::time::Duration;
 use wasm_fantasia_shared::combat::defaults;
+use wasm_fantasia_shared::resources;
 
 // ── Tnua Control Scheme ─────────────────────────────────────────────
 
@@ -39,9 +41,14 @@ pub struct AirActionSlots {
 
 mod animation;
 pub mod control;
+mod footprints;
+mod head_look;
+mod hit_reaction;
 mod sound;
+mod weapon;
 
 pub use animation::*;
+pub use weapon::{Moveset, moveset};
 
 /// This plugin handles player related stuff like movement, shooting
 /// Player logic is only active during the State `Screen::Playing`
@@ -51,6 +58,10 @@ pub fn plugin(app: &mut App) {
         TnuaAvian3dPlugin::new(FixedUpdate),
         TnuaAirActionsPlugin::<AirActionSlots>::new(FixedUpdate),
         control::plugin,
+        footprints::plugin,
+        head_look::plugin,
+        hit_reaction::plugin,
+        weapon::plugin,
         sound::plugin,
     ));
 
@@ -59,22 +70,26 @@ pub fn plugin(app: &mut App) {
         bevy_third_person_camera::CameraSyncSet.before(TransformSystems::Propagate),
     );
 
-    app.add_systems(OnEnter(Screen::Gameplay), spawn_player)
-        .add_systems(
-            Update,
-            (
-                animating.in_set(TnuaUserControlsSystems),
-                animate_remote_players.in_set(PostPhysicsAppSystems::PlayAnimations),
-                sync_debug_colliders,
-            )
-                .run_if(in_state(Screen::Gameplay)),
+    app.add_systems(
+        OnEnter(Screen::Gameplay),
+        spawn_player.run_if(not(is_observing)),
+    )
+    .add_systems(
+        Update,
+        (
+            animating.in_set(TnuaUserControlsSystems),
+            animate_remote_players.in_set(PostPhysicsAppSystems::PlayAnimations),
+            sync_debug_colliders,
         )
-        .add_observer(player_post_spawn)
-        .add_observer(on_remote_player_added);
+            .run_if(in_state(Screen::Gameplay)),
+    )
+    .add_observer(player_post_spawn)
+    .add_observer(on_remote_player_added);
 }
 
 pub fn spawn_player(
     cfg: Res<Config>,
+    loadout: Res<SelectedLoadout>,
     models: Res<Models>,
     gltf_assets: Res<Assets<Gltf>>,
     mut commands: Commands,
@@ -103,7 +118,7 @@ pub fn spawn_player(
             player,
             ThirdPersonCameraTarget,
             // PlayerCtx is NOT inserted here — sync_gameplay_lock adds it
-            // when no BlocksGameplay entities exist and the game isn't paused.
+            // when InputStack is at the Gameplay layer and nothing blocks gameplay.
             // tnua character control bundles
             (
                 TnuaController::<ControlScheme>::default(),
@@ -152,6 +167,8 @@ pub fn spawn_player(
                 LockedAxes::ROTATION_LOCKED.unlock_rotation_y(),
                 TnuaAnimatingState::<AnimationState>::default(),
                 animation::AttackAnimationState::default(),
+                animation::IdleVariationTimer::default(),
+                head_look::HeadLook::default(),
                 // A sensor shape is not strictly necessary, but without it we'll get weird results.
                 TnuaAvian3dSensorShape(collider.clone()),
             ),
@@ -167,11 +184,15 @@ pub fn spawn_player(
                 StepTimer(Timer::from_seconds(cfg.timers.step, TimerMode::Repeating)),
                 control::JumpCharge::default(),
                 control::AirborneTracker::default(),
+                PlayerLevel::default(),
+                Equipment::default(),
+                EquippedWeapon::default(),
                 InheritedVisibility::default(), // silence the warning because of adding SceneRoot as a child
             ),
             // combat components
             (
                 Health::new(100.0),
+                Shield::new(defaults::SHIELD),
                 AttackState::new(0.15), // Fast attack chaining
                 Combatant,
                 PlayerCombatant,
@@ -180,13 +201,21 @@ pub fn spawn_player(
             Stats::new()
                 .with(Stat::MaxHealth, defaults::HEALTH)
                 .with(Stat::Health, defaults::HEALTH)
+                .with(Stat::MaxShield, defaults::SHIELD)
+                .with(Stat::Shield, defaults::SHIELD)
+                .with(Stat::MaxMana, resources::MAX_MANA)
+                .with(Stat::Mana, resources::MAX_MANA)
+                .with(Stat::MaxStamina, resources::MAX_STAMINA)
+                .with(Stat::Stamina, resources::MAX_STAMINA)
                 .with(Stat::AttackDamage, defaults::ATTACK_DAMAGE)
                 .with(Stat::Knockback, defaults::KNOCKBACK)
                 .with(Stat::AttackRange, defaults::ATTACK_RANGE)
                 .with(Stat::AttackArc, defaults::ATTACK_ARC)
                 .with(Stat::CritChance, defaults::CRIT_CHANCE)
-                .with(Stat::CritMultiplier, defaults::CRIT_MULTIPLIER),
-            rule_presets::rules_bundle(wasm_fantasia_shared::presets::default_player_rules()),
+                .with(Stat::CritMultiplier, defaults::CRIT_MULTIPLIER)
+                .with(Stat::AttackSpeed, 1.0)
+                .labeled("Player defaults"),
+            rule_presets::rules_bundle(loadout.0.rules()),
         ))
         // spawn character mesh as child to adjust mesh position relative to the player origin
         .with_children(|parent| {
@@ -271,6 +300,7 @@ fn on_remote_player_added(
 
     commands.entity(entity).insert((
         RemotePlayerAnimations::default(),
+        head_look::HeadLook::default(),
         InheritedVisibility::default(),
     ));
 
@@ -310,6 +340,7 @@ fn prepare_remote_player_scene(
     gltf_assets: Res<Assets<Gltf>>,
     children_q: Query<&Children>,
     anim_players: Query<Entity, With<AnimationPlayer>>,
+    bones: Query<(&Name, &AnimationTargetId)>,
     parents: Query<&ChildOf>,
     mut remote_q: Query<&mut RemotePlayerAnimations>,
     mut commands: Commands,
@@ -352,6 +383,16 @@ fn prepare_remote_player_scene(
 
     anims.animation_player_entity = Some(animation_player_entity);
 
+    if let Some(layer) = hit_reaction::add_hit_reaction_layer(
+        &mut graph,
+        gltf,
+        animation_player_entity,
+        &children_q,
+        &bones,
+    ) {
+        commands.entity(remote_entity).insert(layer);
+    }
+
     let idle_node = anims.animations.get(&Animation::Idle).copied();
     let graph_handle = animation_graphs.add(graph);
 
@@ -381,20 +422,21 @@ fn prepare_remote_player_scene(
 }
 
 /// Drive remote player animations from server-synced state.
-/// Uses `AnimationState::from_server_name()` + `playback()` — the same source
+/// Uses `AnimationState::from_wire_id()` + `playback()` — the same source
 /// of truth the local player's animation system is built around.
 fn animate_remote_players(
     time: Res<Time>,
     mut remotes: Query<(
         &crate::networking::RemotePlayerState,
         &mut RemotePlayerAnimations,
+        Option<&EquippedWeapon>,
     )>,
     mut animation_query: Query<(&mut AnimationPlayer, &mut AnimationTransitions)>,
 ) {
     const BLEND_DURATION: Duration = Duration::from_millis(150);
     let now = time.elapsed_secs();
 
-    for (state, mut anims) in &mut remotes {
+    for (state, mut anims, weapon) in &mut remotes {
         let Some(anim_entity) = anims.animation_player_entity else {
             continue;
         };
@@ -406,13 +448,15 @@ fn animate_remote_players(
         if state.attack_sequence != anims.last_attack_sequence && state.attack_sequence > 0 {
             anims.last_attack_sequence = state.attack_sequence;
 
-            if let Some(attack_anim) = Animation::from_clip_name(&state.attack_animation) {
+            if let Some(attack_anim) = Animation::from_wire_id(state.attack_animation) {
                 if let Some(&index) = anims.animations.get(&attack_anim) {
+                    // Crits aren't synced, so every swing plays at combo speed
+                    let speed = moveset(weapon.map(|w| w.0).unwrap_or_default()).clip_speed;
                     anims.current_animation = Some(attack_anim);
                     anims.attack_playing_until = now + ATTACK_ANIMATION_DURATION;
                     transitions
                         .play(&mut anim_player, index, BLEND_DURATION)
-                        .set_speed(1.3);
+                        .set_speed(speed);
                     continue;
                 }
             }
@@ -424,7 +468,7 @@ fn animate_remote_players(
         }
 
         // Movement animation via shared AnimationState mapping
-        let anim_state = AnimationState::from_server_name(&state.animation_state);
+        let anim_state = AnimationState::from_wire_id(state.animation);
         let (clip, speed, looping) = anim_state.playback();
 
         if anims.current_animation == Some(clip) {
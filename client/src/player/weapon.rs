@@ -0,0 +1,205 @@
+//! Weapon movesets and models. Which clips an attack plays, how its trail
+//! and hit sound read, and the mesh held in the right hand, for any player
+//! with an [`EquippedWeapon`], local or remote.
+
+use super::*;
+use wasm_fantasia_shared::weapons::Weapon;
+
+/// Name of the right hand joint in the player rig.
+const HAND_BONE: &str = "DEF-hand.R";
+
+/// How a weapon looks and sounds when swung.
+pub struct Moveset {
+    /// Clips the basic attack cycles through, one per swing.
+    pub combo: &'static [Animation],
+    /// Clip for a crit.
+    pub finisher: Animation,
+    /// Playback speed of combo clips before attack speed scaling.
+    pub clip_speed: f32,
+    pub finisher_speed: f32,
+    pub trail_color: Color,
+    pub trail_emissive: LinearRgba,
+    /// Hit sound playback speed; lower reads heavier.
+    pub sound_pitch: f64,
+    /// Hit sound volume relative to the SFX setting.
+    pub sound_volume: f32,
+}
+
+impl Moveset {
+    /// Clip for the `attack_count`th swing (counting from 1).
+    pub fn clip(&self, attack_count: u32, is_crit: bool) -> Animation {
+        if is_crit {
+            return self.finisher;
+        }
+        self.combo[attack_count.saturating_sub(1) as usize % self.combo.len()]
+    }
+
+    /// Clip playback speed at the start of a swing.
+    pub fn clip_speed(&self, is_crit: bool) -> f32 {
+        if is_crit {
+            self.finisher_speed
+        } else {
+            self.clip_speed
+        }
+    }
+}
+
+const FISTS: Moveset = Moveset {
+    combo: &[Animation::PunchJab, Animation::PunchCross],
+    finisher: Animation::MeleeHook,
+    clip_speed: 1.3,
+    finisher_speed: 1.1,
+    trail_color: Color::srgba(0.7, 0.85, 1.0, 0.4),
+    trail_emissive: LinearRgba::new(2.0, 3.0, 5.0, 1.0),
+    sound_pitch: 1.0,
+    sound_volume: 1.0,
+};
+
+const SWORD: Moveset = Moveset {
+    combo: &[Animation::MeleeHook, Animation::PunchCross],
+    finisher: Animation::MeleeHook,
+    clip_speed: 1.25,
+    finisher_speed: 1.0,
+    trail_color: Color::srgba(0.95, 0.95, 1.0, 0.5),
+    trail_emissive: LinearRgba::new(4.0, 4.0, 5.0, 1.0),
+    sound_pitch: 1.35,
+    sound_volume: 0.85,
+};
+
+const HAMMER: Moveset = Moveset {
+    combo: &[Animation::MeleeHook],
+    finisher: Animation::MeleeHook,
+    clip_speed: 0.9,
+    finisher_speed: 0.8,
+    trail_color: Color::srgba(1.0, 0.7, 0.35, 0.45),
+    trail_emissive: LinearRgba::new(5.0, 2.5, 0.8, 1.0),
+    sound_pitch: 0.7,
+    sound_volume: 1.2,
+};
+
+pub fn moveset(weapon: Weapon) -> &'static Moveset {
+    match weapon {
+        Weapon::Fists => &FISTS,
+        Weapon::Sword => &SWORD,
+        Weapon::Hammer => &HAMMER,
+    }
+}
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(Startup, setup_weapon_models)
+        .add_observer(on_weapon_added)
+        .add_systems(
+            Update,
+            show_weapon_models.run_if(in_state(Screen::Gameplay)),
+        );
+}
+
+#[derive(Resource)]
+struct WeaponModels {
+    blade: Handle<Mesh>,
+    grip: Handle<Mesh>,
+    guard: Handle<Mesh>,
+    handle: Handle<Mesh>,
+    head: Handle<Mesh>,
+    steel: Handle<StandardMaterial>,
+    wood: Handle<StandardMaterial>,
+}
+
+/// Which model a player's hand currently holds.
+#[derive(Component, Default)]
+struct WeaponHand {
+    bone: Option<Entity>,
+    model: Option<Entity>,
+    shown: Option<Weapon>,
+}
+
+fn setup_weapon_models(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.insert_resource(WeaponModels {
+        blade: meshes.add(Cuboid::new(0.06, 0.9, 0.015)),
+        grip: meshes.add(Cylinder::new(0.022, 0.2)),
+        guard: meshes.add(Cuboid::new(0.22, 0.03, 0.04)),
+        handle: meshes.add(Cylinder::new(0.025, 0.8)),
+        head: meshes.add(Cuboid::new(0.3, 0.16, 0.16)),
+        steel: materials.add(StandardMaterial {
+            base_color: Color::srgb(0.75, 0.77, 0.8),
+            metallic: 0.9,
+            perceptual_roughness: 0.3,
+            ..default()
+        }),
+        wood: materials.add(StandardMaterial {
+            base_color: Color::srgb(0.4, 0.26, 0.14),
+            perceptual_roughness: 0.8,
+            ..default()
+        }),
+    });
+}
+
+fn on_weapon_added(on: On<Add, EquippedWeapon>, mut commands: Commands) {
+    commands.entity(on.entity).insert(WeaponHand::default());
+}
+
+/// Find each player's hand once their scene has spawned, then swap the held
+/// model whenever their weapon changes.
+fn show_weapon_models(
+    models: Option<Res<WeaponModels>>,
+    mut players: Query<(Entity, &EquippedWeapon, &mut WeaponHand)>,
+    children: Query<&Children>,
+    names: Query<&Name>,
+    mut commands: Commands,
+) {
+    let Some(models) = models else {
+        return;
+    };
+    for (entity, weapon, mut hand) in &mut players {
+        if hand.bone.is_none() {
+            hand.bone = children
+                .iter_descendants(entity)
+                .find(|e| names.get(*e).is_ok_and(|name| name.as_str() == HAND_BONE));
+        }
+        let Some(bone) = hand.bone else {
+            continue;
+        };
+        if hand.shown == Some(weapon.0) {
+            continue;
+        }
+
+        if let Some(model) = hand.model.take() {
+            commands.entity(model).try_despawn();
+        }
+        hand.shown = Some(weapon.0);
+        let parts: Vec<(Handle<Mesh>, Handle<StandardMaterial>, Vec3)> = match weapon.0 {
+            Weapon::Fists => continue,
+            Weapon::Sword => vec![
+                (models.grip.clone(), models.wood.clone(), Vec3::ZERO),
+                (models.guard.clone(), models.steel.clone(), Vec3::Y * 0.1),
+                (models.blade.clone(), models.steel.clone(), Vec3::Y * 0.55),
+            ],
+            Weapon::Hammer => vec![
+                (models.handle.clone(), models.wood.clone(), Vec3::Y * 0.2),
+                (models.head.clone(), models.steel.clone(), Vec3::Y * 0.6),
+            ],
+        };
+        let model = commands
+            .spawn((
+                Name::new(weapon.0.name()),
+                Transform::from_xyz(0.0, 0.08, 0.0),
+                Visibility::default(),
+                ChildOf(bone),
+            ))
+            .with_children(|parent| {
+                for (mesh, material, offset) in parts {
+                    parent.spawn((
+                        Mesh3d(mesh),
+                        MeshMaterial3d(material),
+                        Transform::from_translation(offset),
+                    ));
+                }
+            })
+            .id();
+        hand.model = Some(model);
+    }
+}
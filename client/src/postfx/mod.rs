@@ -3,23 +3,19 @@
 use crate::*;
 use bevy::render::view::{ColorGrading, ColorGradingGlobal, ColorGradingSection};
 
-#[derive(Resource)]
-pub struct PostFxEnabled(pub bool);
-
-impl Default for PostFxEnabled {
-    fn default() -> Self {
-        Self(true)
-    }
-}
-
 pub fn plugin(app: &mut App) {
-    app.init_resource::<PostFxEnabled>()
-        .add_systems(OnEnter(Screen::Gameplay), setup_postfx)
-        .add_systems(Update, toggle_postfx.run_if(in_state(Screen::Gameplay)));
+    app.add_systems(
+        Update,
+        (
+            toggle_postfx.run_if(in_state(Screen::Gameplay)),
+            apply_postfx.run_if(resource_changed::<Settings>),
+        )
+            .chain(),
+    );
 }
 
 /// "Clean & Sharp" preset inspired by ReShade community standards
-fn postfx_preset() -> ColorGrading {
+pub fn postfx_preset() -> ColorGrading {
     ColorGrading {
         global: ColorGradingGlobal {
             exposure: 0.0,    // Neutral — dark scene handles its own brightness
@@ -52,31 +48,30 @@ fn postfx_preset() -> ColorGrading {
     }
 }
 
-fn setup_postfx(mut commands: Commands, camera: Query<Entity, With<SceneCamera>>) {
-    let Ok(cam) = camera.single() else { return };
-
-    commands.entity(cam).insert(postfx_preset());
-    info!("Post-FX enabled (F2 to toggle)");
+/// Color grading for the scene camera with post-processing `enabled` or not.
+pub fn color_grading(enabled: bool) -> ColorGrading {
+    if enabled {
+        postfx_preset()
+    } else {
+        ColorGrading::default()
+    }
 }
 
-fn toggle_postfx(
-    keys: Res<ButtonInput<KeyCode>>,
-    mut enabled: ResMut<PostFxEnabled>,
-    mut commands: Commands,
-    camera: Query<Entity, With<SceneCamera>>,
-) {
+fn toggle_postfx(keys: Res<ButtonInput<KeyCode>>, mut settings: ResMut<Settings>) {
     if !keys.just_pressed(KeyCode::F2) {
         return;
     }
+    settings.postfx = !settings.postfx;
+    info!("Post-FX {}", if settings.postfx { "ON" } else { "OFF" });
+}
 
-    enabled.0 = !enabled.0;
+/// Keep the scene camera's color grading in step with the setting, whether it
+/// changed from F2 or the settings screen.
+fn apply_postfx(
+    settings: Res<Settings>,
+    mut commands: Commands,
+    camera: Query<Entity, With<SceneCamera>>,
+) {
     let Ok(cam) = camera.single() else { return };
-
-    if enabled.0 {
-        commands.entity(cam).insert(postfx_preset());
-        info!("Post-FX ON");
-    } else {
-        commands.entity(cam).insert(ColorGrading::default());
-        info!("Post-FX OFF");
-    }
+    commands.entity(cam).insert(color_grading(settings.postfx));
 }
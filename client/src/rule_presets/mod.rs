@@ -15,5 +15,10 @@ pub fn rules_bundle(rules: EntityRules) -> impl Bundle {
         OnTickRules(rules.on_tick),
         OnKillRules(rules.on_kill),
         OnTakeDamageRules(rules.on_take_damage),
+        OnDodgeRules(rules.on_dodge),
+        OnBlockRules(rules.on_block),
+        OnHealthThresholdRules(rules.on_health_threshold),
+        OnTimerRules(rules.on_timer),
+        Auras(rules.auras),
     )
 }
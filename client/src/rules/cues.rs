@@ -0,0 +1,19 @@
+//! Juice rules play with `Effect::Feedback`, triggered as [`RuleCue`] for
+//! the combat feedback and sound modules to play.
+
+use bevy::prelude::*;
+use wasm_fantasia_shared::rules::FeedbackCue;
+
+/// Feedback: the rules of `source` played `cue`.
+#[derive(Event, Debug, Clone)]
+pub struct RuleCue {
+    pub source: Entity,
+    pub cue: FeedbackCue,
+}
+
+/// Trigger a [`RuleCue`] for each of `cues`.
+pub fn play_cues(commands: &mut Commands, source: Entity, cues: Vec<FeedbackCue>) {
+    for cue in cues {
+        commands.trigger(RuleCue { source, cue });
+    }
+}
@@ -3,20 +3,29 @@
 //! Re-exports the shared rules engine and wraps Stats as a Bevy Component.
 
 pub use wasm_fantasia_shared::rules::{
-    Action, ActionVar, Condition, Effect, Expr, Rule, RuleEvent, RuleOutput, Stat, action,
-    check_condition, check_condition_with_roll, check_conditions, check_conditions_with_roll,
-    execute_effect, execute_effects, execute_rule, execute_rule_with_roll, execute_rules,
-    execute_rules_with_roll, stat, val,
+    Action, ActionVar, Condition, Effect, Expr, Feedback, FeedbackCue, ModifierKind, Rule,
+    RuleEvent, RuleOutput, RuleRng, Stat, StatBound, StatBounds, StatBreakdown, StatModifier,
+    TriggerTrace, action, check_condition, check_condition_with_rng, check_conditions,
+    check_conditions_with_rng, execute_effect, execute_effects, execute_rule,
+    execute_rule_with_rng, execute_rules, execute_rules_with_rng, set_tracing, stat, tracing, val,
 };
 
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::ops::{Deref, DerefMut};
 
+mod cues;
 mod preset;
+mod spawn;
+mod status;
+mod trace;
 mod triggers;
 
+pub use cues::{RuleCue, play_cues};
 pub use preset::*;
+pub use spawn::{RuleSpawned, spawn_requested};
+pub use status::{StatusEffects, Stunned, grant_statuses};
+pub use trace::{RulesTraced, report_traces};
 pub use triggers::*;
 
 /// Bevy Component wrapper around shared Stats.
@@ -33,6 +42,13 @@ impl Stats {
         self.0 = self.0.with(stat, value);
         self
     }
+
+    /// Label the base values with where they came from (a preset, an
+    /// archetype), for stat tooltips.
+    pub fn labeled(mut self, source: &str) -> Self {
+        self.0 = self.0.labeled(source);
+        self
+    }
 }
 
 impl Deref for Stats {
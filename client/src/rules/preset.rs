@@ -1,23 +1,33 @@
 //! RON-loadable rule presets
 
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 use super::{
-    OnCritHitRules, OnHitRules, OnKillRules, OnPreHitRules, OnTakeDamageRules, OnTickRules, Rule,
+    Auras, OnBlockRules, OnCritHitRules, OnDodgeRules, OnHealthThresholdRules, OnHitRules,
+    OnKillRules, OnPreHitRules, OnTakeDamageRules, OnTickRules, OnTimerRules, Rule, StatBounds,
     Stats,
 };
-use crate::asset_loading::RonAssetPlugin;
+use wasm_fantasia_shared::presets::aura::Aura;
+use wasm_fantasia_shared::presets::{HealthThreshold, TimerRules};
+use wasm_fantasia_shared::rules::schema::{self, SchemaError};
 
 // ============================================================================
 // ASSET DEFINITION
 // ============================================================================
 
-/// A rule preset loaded from RON files.
+/// A rule preset loaded from RON files. `version` is the
+/// [rules schema](schema) the file was written for; files for older
+/// versions are upgraded as they load.
 ///
 /// Example RON:
 /// ```ron
 /// (
+///     version: 1,
+///     name: Some("Glass Cannon"),
 ///     stats: { CritChance: 0.2, CritMultiplier: 2.5 },
 ///     on_pre_hit: [(
 ///         conditions: [Chance(Stat(CritChance))],
@@ -26,12 +36,24 @@ use crate::asset_loading::RonAssetPlugin;
 ///             SetAction(var: Damage, value: Multiply(Action(Damage), Stat(CritMultiplier))),
 ///         ],
 ///     )],
+///     on_health_threshold: [(
+///         fraction: 0.3,
+///         rules: [(effects: [ApplyStatus(effect_type: "regen", magnitude: Value(5.0), duration: Value(4.0))])],
+///     )],
 /// )
 /// ```
 #[derive(Asset, Clone, Debug, Default, Serialize, Deserialize, TypePath)]
 pub struct RulePreset {
+    /// Shown in stat tooltips as the origin of the preset's base stats and
+    /// of changes made by its rules (unless a rule names its own `source`).
+    #[serde(default)]
+    pub name: Option<String>,
     #[serde(default)]
     pub stats: Stats,
+    /// Replace the default [`StatBounds`] for these stats, e.g.
+    /// `{ CritChance: (max: 0.8) }`.
+    #[serde(default)]
+    pub bounds: StatBounds,
     #[serde(default)]
     pub on_pre_hit: Vec<Rule>,
     #[serde(default)]
@@ -44,31 +66,49 @@ pub struct RulePreset {
     pub on_take_damage: Vec<Rule>,
     #[serde(default)]
     pub on_tick: Vec<Rule>,
+    #[serde(default)]
+    pub on_dodge: Vec<Rule>,
+    #[serde(default)]
+    pub on_block: Vec<Rule>,
+    #[serde(default)]
+    pub on_health_threshold: Vec<HealthThreshold>,
+    #[serde(default)]
+    pub on_timer: Vec<TimerRules>,
+    #[serde(default)]
+    pub auras: Vec<Aura>,
 }
 
 impl RulePreset {
     /// Insert this preset's components onto an entity.
     pub fn insert_into(self, commands: &mut EntityCommands) {
-        commands.insert(self.stats);
-        if !self.on_pre_hit.is_empty() {
-            commands.insert(OnPreHitRules(self.on_pre_hit));
-        }
-        if !self.on_hit.is_empty() {
-            commands.insert(OnHitRules(self.on_hit));
-        }
-        if !self.on_crit_hit.is_empty() {
-            commands.insert(OnCritHitRules(self.on_crit_hit));
-        }
-        if !self.on_kill.is_empty() {
-            commands.insert(OnKillRules(self.on_kill));
-        }
-        if !self.on_take_damage.is_empty() {
-            commands.insert(OnTakeDamageRules(self.on_take_damage));
-        }
-        if !self.on_tick.is_empty() {
-            commands.insert(OnTickRules(self.on_tick));
+        commands.with_preset(&self);
+    }
+
+    fn labeled_stats(&self) -> Stats {
+        let stats = match &self.name {
+            Some(name) => self.stats.clone().labeled(name),
+            None => self.stats.clone(),
+        };
+        if self.bounds.is_empty() {
+            stats
+        } else {
+            Stats(stats.0.bounded(self.bounds.clone()))
         }
     }
+
+    /// Rules with the preset's name filled in as their source.
+    fn tagged(&self, rules: &[Rule]) -> Vec<Rule> {
+        rules
+            .iter()
+            .cloned()
+            .map(|mut rule| {
+                if rule.source.is_none() {
+                    rule.source = self.name.clone();
+                }
+                rule
+            })
+            .collect()
+    }
 }
 
 // ============================================================================
@@ -83,31 +123,109 @@ pub trait RulePresetCommands {
 
 impl RulePresetCommands for EntityCommands<'_> {
     fn with_preset(&mut self, preset: &RulePreset) -> &mut Self {
-        self.insert(preset.stats.clone());
+        self.insert(preset.labeled_stats());
 
         if !preset.on_pre_hit.is_empty() {
-            self.insert(OnPreHitRules(preset.on_pre_hit.clone()));
+            self.insert(OnPreHitRules(preset.tagged(&preset.on_pre_hit)));
         }
         if !preset.on_hit.is_empty() {
-            self.insert(OnHitRules(preset.on_hit.clone()));
+            self.insert(OnHitRules(preset.tagged(&preset.on_hit)));
         }
         if !preset.on_crit_hit.is_empty() {
-            self.insert(OnCritHitRules(preset.on_crit_hit.clone()));
+            self.insert(OnCritHitRules(preset.tagged(&preset.on_crit_hit)));
         }
         if !preset.on_kill.is_empty() {
-            self.insert(OnKillRules(preset.on_kill.clone()));
+            self.insert(OnKillRules(preset.tagged(&preset.on_kill)));
         }
         if !preset.on_take_damage.is_empty() {
-            self.insert(OnTakeDamageRules(preset.on_take_damage.clone()));
+            self.insert(OnTakeDamageRules(preset.tagged(&preset.on_take_damage)));
         }
         if !preset.on_tick.is_empty() {
-            self.insert(OnTickRules(preset.on_tick.clone()));
+            self.insert(OnTickRules(preset.tagged(&preset.on_tick)));
+        }
+        if !preset.on_dodge.is_empty() {
+            self.insert(OnDodgeRules(preset.tagged(&preset.on_dodge)));
+        }
+        if !preset.on_block.is_empty() {
+            self.insert(OnBlockRules(preset.tagged(&preset.on_block)));
+        }
+        if !preset.on_health_threshold.is_empty() {
+            let thresholds = preset
+                .on_health_threshold
+                .iter()
+                .map(|threshold| HealthThreshold {
+                    fraction: threshold.fraction,
+                    rules: preset.tagged(&threshold.rules),
+                })
+                .collect();
+            self.insert(OnHealthThresholdRules(thresholds));
+        }
+        if !preset.on_timer.is_empty() {
+            let timers = preset
+                .on_timer
+                .iter()
+                .map(|timer| TimerRules {
+                    interval: timer.interval,
+                    rules: preset.tagged(&timer.rules),
+                })
+                .collect();
+            self.insert(OnTimerRules(timers));
+        }
+        if !preset.auras.is_empty() {
+            let auras = preset
+                .auras
+                .iter()
+                .map(|aura| Aura {
+                    rules: preset.tagged(&aura.rules),
+                    ..aura.clone()
+                })
+                .collect();
+            self.insert(Auras(auras));
         }
 
         self
     }
 }
 
+// ============================================================================
+// LOADER
+// ============================================================================
+
+/// Loads `.preset.ron` files through [`schema::from_ron`].
+#[derive(TypePath)]
+struct RulePresetLoader;
+
+#[derive(Debug, Error)]
+enum RulePresetError {
+    #[error("Could not read the file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Not UTF-8: {0}")]
+    Utf8(#[from] std::str::Utf8Error),
+    #[error("Could not load preset: {0}")]
+    Schema(#[from] SchemaError),
+}
+
+impl AssetLoader for RulePresetLoader {
+    type Asset = RulePreset;
+    type Settings = ();
+    type Error = RulePresetError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(schema::from_ron(std::str::from_utf8(&bytes)?)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["preset.ron"]
+    }
+}
+
 // ============================================================================
 // PLUGIN
 // ============================================================================
@@ -116,6 +234,7 @@ pub struct RulePresetPlugin;
 
 impl Plugin for RulePresetPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(RonAssetPlugin::<RulePreset>::default());
+        app.init_asset::<RulePreset>()
+            .register_asset_loader(RulePresetLoader);
     }
 }
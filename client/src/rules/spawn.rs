@@ -0,0 +1,31 @@
+//! Things rules spawn with `Effect::Spawn`, triggered as [`RuleSpawned`] for
+//! whichever module draws that kind: VFX plays the shockwave ring. The
+//! shockwave's damage and loot are server rows, so they only happen online.
+
+use bevy::prelude::*;
+use wasm_fantasia_shared::rules::SpawnRequest;
+
+/// Feedback: a rule spawned `request`, at `position` or, without one, at
+/// the `source` whose rules ran.
+#[derive(Event, Debug, Clone)]
+pub struct RuleSpawned {
+    pub source: Entity,
+    pub position: Option<Vec3>,
+    pub request: SpawnRequest,
+}
+
+/// Trigger a [`RuleSpawned`] for each of `requests`.
+pub fn spawn_requested(
+    commands: &mut Commands,
+    source: Entity,
+    position: Option<Vec3>,
+    requests: Vec<SpawnRequest>,
+) {
+    for request in requests {
+        commands.trigger(RuleSpawned {
+            source,
+            position,
+            request,
+        });
+    }
+}
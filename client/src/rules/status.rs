@@ -0,0 +1,181 @@
+//! Timed effects rules grant with `Effect::ApplyStatus`: the client's copy
+//! of the server's `active_effect` rows for its own entities. Every status
+//! counts down and drops off when it runs out. Periodic ones only change
+//! health here when playing offline; otherwise the server applies them.
+//! Control effects always count: while [`Stunned`], the controller, attacks
+//! and animations lock up, and slows scale movement. So do hastes, which
+//! speed up attacks.
+
+use bevy::prelude::*;
+use wasm_fantasia_shared::effects::{
+    EffectKind, Impairment, haste_multiplier, refreshed_duration, ticks_due,
+};
+use wasm_fantasia_shared::rules::StatusGrant;
+
+use crate::combat::Health;
+use crate::models::Screen;
+use crate::networking::offline::is_offline;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        (
+            tick_statuses,
+            apply_periodic_statuses.run_if(is_offline),
+            mark_stunned,
+        )
+            .chain()
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+#[derive(Debug, Clone)]
+pub struct Status {
+    pub kind: EffectKind,
+    /// The ability of a cooldown, or the script of a scripted effect.
+    pub name: String,
+    pub magnitude: f32,
+    /// Seconds left. Negative never runs out.
+    pub remaining: f32,
+    pub elapsed: f32,
+    /// `elapsed` before this frame, for periodic ticks.
+    elapsed_before: f32,
+}
+
+impl Status {
+    fn ran_out(&self) -> bool {
+        self.remaining == 0.0
+    }
+}
+
+/// Timed effects on an entity.
+#[derive(Component, Debug, Clone, Default)]
+pub struct StatusEffects(pub Vec<Status>);
+
+impl StatusEffects {
+    /// What the control effects among these stop the entity doing.
+    pub fn impairment(&self) -> Impairment {
+        Impairment::resolve(self.0.iter().map(|s| (s.kind, s.magnitude)))
+    }
+
+    /// How much faster the hastes among these make attacks.
+    pub fn haste(&self) -> f32 {
+        haste_multiplier(self.0.iter().map(|s| (s.kind, s.magnitude)))
+    }
+
+    /// Replace the control effects and hastes with the server's `(kind,
+    /// magnitude, seconds left)`, which decide them online.
+    pub fn sync_controls(&mut self, controls: impl IntoIterator<Item = (EffectKind, f32, f32)>) {
+        self.0
+            .retain(|s| !matches!(s.kind, EffectKind::Control(_) | EffectKind::Haste));
+        self.0.extend(
+            controls
+                .into_iter()
+                .map(|(kind, magnitude, remaining)| Status {
+                    kind,
+                    name: String::new(),
+                    magnitude,
+                    remaining,
+                    elapsed: 0.0,
+                    elapsed_before: 0.0,
+                }),
+        );
+    }
+
+    /// Apply what rules granted, the same way the server does: one the
+    /// entity already has takes the new magnitude and keeps the longer
+    /// duration.
+    pub fn grant(&mut self, grants: &[StatusGrant]) {
+        for grant in grants {
+            let (kind, name) = grant.kind();
+            match self.0.iter_mut().find(|s| s.kind == kind && s.name == name) {
+                Some(status) => {
+                    status.magnitude = grant.magnitude;
+                    status.remaining = refreshed_duration(status.remaining, grant.duration);
+                }
+                None => self.0.push(Status {
+                    kind,
+                    name: name.to_string(),
+                    magnitude: grant.magnitude,
+                    remaining: grant.duration,
+                    elapsed: 0.0,
+                    elapsed_before: 0.0,
+                }),
+            }
+        }
+    }
+}
+
+/// Marks an entity a stun is holding, for the systems that lock it up.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Stunned;
+
+/// Give `entity` the statuses its rules granted, if any.
+pub fn grant_statuses(commands: &mut Commands, entity: Entity, grants: Vec<StatusGrant>) {
+    if grants.is_empty() {
+        return;
+    }
+    commands
+        .entity(entity)
+        .entry::<StatusEffects>()
+        .or_default()
+        .and_modify(move |mut statuses| statuses.grant(&grants));
+}
+
+fn tick_statuses(time: Res<Time>, mut query: Query<&mut StatusEffects>) {
+    let dt = time.delta_secs();
+    for mut statuses in query.iter_mut() {
+        if statuses.0.is_empty() {
+            continue;
+        }
+        // Dropped a frame after running out, so their last ticks still land
+        statuses.0.retain(|s| !s.ran_out());
+        for status in &mut statuses.0 {
+            status.elapsed_before = status.elapsed;
+            if status.remaining < 0.0 {
+                status.elapsed += dt;
+                continue;
+            }
+            let step = dt.min(status.remaining);
+            status.elapsed += step;
+            status.remaining -= step;
+        }
+    }
+}
+
+/// Offline stand-in for the server's periodic effect ticks.
+fn apply_periodic_statuses(mut query: Query<(&StatusEffects, &mut Health)>) {
+    for (statuses, mut health) in query.iter_mut() {
+        for status in &statuses.0 {
+            let EffectKind::Periodic(periodic) = status.kind else {
+                continue;
+            };
+            let ticks = ticks_due(
+                status.elapsed_before,
+                status.elapsed,
+                periodic.interval_secs(),
+            );
+            for _ in 0..ticks {
+                if health.is_dead() {
+                    break;
+                }
+                let change = periodic.tick_amount(status.magnitude, health.current, health.max);
+                health.current = (health.current + change).clamp(0.0, health.max);
+            }
+        }
+    }
+}
+
+fn mark_stunned(query: Query<(Entity, &StatusEffects, Has<Stunned>)>, mut commands: Commands) {
+    for (entity, statuses, was_stunned) in query.iter() {
+        match (statuses.impairment().stunned, was_stunned) {
+            (true, false) => {
+                commands.entity(entity).try_insert(Stunned);
+            }
+            (false, true) => {
+                commands.entity(entity).try_remove::<Stunned>();
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,20 @@
+//! Rule pass traces, triggered as [`RulesTraced`] while tracing is on (see
+//! `set_tracing`) for the dev overlay to show and dump.
+
+use bevy::prelude::*;
+use wasm_fantasia_shared::rules::TriggerTrace;
+
+/// Debug: the rule passes `source` just ran, in the order they ran.
+#[derive(Event, Debug, Clone)]
+pub struct RulesTraced {
+    pub source: Entity,
+    pub traces: Vec<TriggerTrace>,
+}
+
+/// Trigger a [`RulesTraced`] for `traces`, unless there are none (tracing
+/// is off, or no rule list ran).
+pub fn report_traces(commands: &mut Commands, source: Entity, traces: Vec<TriggerTrace>) {
+    if !traces.is_empty() {
+        commands.trigger(RulesTraced { source, traces });
+    }
+}
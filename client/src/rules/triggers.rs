@@ -1,8 +1,13 @@
 //! Rule trigger components and observers
 
 use super::*;
-use crate::combat::{AttackState, DamageDealt};
+use crate::combat::{AttackState, Blocked, DamageDealt, Health, PlayerCombatant};
+use crate::networking::offline::is_offline;
+use crate::player::control::RollingState;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use wasm_fantasia_shared::presets::aura::Aura;
+use wasm_fantasia_shared::presets::{HealthThreshold, TimerRules};
 
 // ============================================================================
 // DERIVED EVENTS
@@ -59,6 +64,28 @@ pub struct OnTakeDamageRules(pub Vec<Rule>);
 #[derive(Component, Default, Clone, Debug, Serialize, Deserialize)]
 pub struct OnTickRules(pub Vec<Rule>);
 
+/// Rules that execute when starting a dodge roll.
+#[derive(Component, Default, Clone, Debug, Serialize, Deserialize)]
+pub struct OnDodgeRules(pub Vec<Rule>);
+
+/// Rules that execute when the shield soaks a whole hit (defender).
+#[derive(Component, Default, Clone, Debug, Serialize, Deserialize)]
+pub struct OnBlockRules(pub Vec<Rule>);
+
+/// Rules that execute when health drops below a fraction of max health.
+/// Each threshold fires on the frame health crosses it.
+#[derive(Component, Default, Clone, Debug, Serialize, Deserialize)]
+pub struct OnHealthThresholdRules(pub Vec<HealthThreshold>);
+
+/// Rules that execute every `interval` seconds.
+#[derive(Component, Default, Clone, Debug, Serialize, Deserialize)]
+pub struct OnTimerRules(pub Vec<TimerRules>);
+
+/// Rules run on the players around the entity every aura's `interval`.
+/// Online the server runs them; the client draws their reach.
+#[derive(Component, Default, Clone, Debug, Serialize, Deserialize)]
+pub struct Auras(pub Vec<Aura>);
+
 // ============================================================================
 // OBSERVERS
 // ============================================================================
@@ -78,6 +105,122 @@ fn on_take_damage_observer(
     }
 }
 
+/// Give the entity what its trigger rules granted, spawned and played.
+fn apply_output(
+    commands: &mut Commands,
+    entity: Entity,
+    trigger: &'static str,
+    output: RuleOutput,
+) {
+    grant_statuses(commands, entity, output.statuses);
+    spawn_requested(commands, entity, None, output.spawns);
+    play_cues(commands, entity, output.cues);
+    if let Some(pass) = output.trace {
+        let trace = TriggerTrace {
+            trigger,
+            target: None,
+            pass,
+        };
+        report_traces(commands, entity, vec![trace]);
+    }
+}
+
+fn on_dodge_observer(
+    on: On<Add, RollingState>,
+    mut query: Query<(&OnDodgeRules, &mut Stats)>,
+    mut commands: Commands,
+) {
+    if let Ok((rules, mut stats)) = query.get_mut(on.entity) {
+        let output = execute_rules(&rules.0, &mut stats.0, &mut Action::new());
+        apply_output(&mut commands, on.entity, "on_dodge", output);
+    }
+}
+
+fn on_block_observer(
+    trigger: On<Blocked>,
+    mut query: Query<(&OnBlockRules, &mut Stats)>,
+    mut commands: Commands,
+) {
+    let entity = trigger.event().entity;
+    if let Ok((rules, mut stats)) = query.get_mut(entity) {
+        let output = execute_rules(&rules.0, &mut stats.0, &mut Action::new());
+        apply_output(&mut commands, entity, "on_block", output);
+    }
+}
+
+/// Compares health against last frame's, so a threshold fires once per
+/// crossing however the health was lost.
+fn health_threshold_rules_system(
+    mut last_health: Local<HashMap<Entity, f32>>,
+    mut query: Query<(Entity, &OnHealthThresholdRules, &Health, &mut Stats)>,
+    mut commands: Commands,
+) {
+    let mut seen = HashMap::new();
+    for (entity, thresholds, health, mut stats) in query.iter_mut() {
+        if let Some(&before) = last_health.get(&entity) {
+            for threshold in &thresholds.0 {
+                if threshold.crossed(before, health.current, health.max) {
+                    let output = execute_rules(&threshold.rules, &mut stats.0, &mut Action::new());
+                    apply_output(&mut commands, entity, "on_health_threshold", output);
+                }
+            }
+        }
+        seen.insert(entity, health.current);
+    }
+    *last_health = seen;
+}
+
+fn timer_rules_system(
+    time: Res<Time>,
+    mut query: Query<(Entity, &OnTimerRules, &mut Stats)>,
+    mut commands: Commands,
+) {
+    let now = time.elapsed().as_micros() as i64;
+    let before = now - time.delta().as_micros() as i64;
+
+    for (entity, timers, mut stats) in query.iter_mut() {
+        for timer in &timers.0 {
+            for _ in 0..timer.fires_between(before, now) {
+                let output = execute_rules(&timer.rules, &mut stats.0, &mut Action::new());
+                apply_output(&mut commands, entity, "on_timer", output);
+            }
+        }
+    }
+}
+
+/// Offline stand-in for the server's aura pulses, on the players in reach.
+fn aura_rules_system(
+    time: Res<Time>,
+    owners: Query<(Entity, &Auras, &GlobalTransform)>,
+    mut targets: Query<(Entity, &GlobalTransform, &mut Stats), With<PlayerCombatant>>,
+    mut commands: Commands,
+) {
+    let now = time.elapsed().as_micros() as i64;
+    let before = now - time.delta().as_micros() as i64;
+
+    for (owner, auras, owner_tf) in owners.iter() {
+        let center = owner_tf.translation().xz();
+        for aura in &auras.0 {
+            let pulses = aura.fires_between(before, now);
+            if pulses == 0 {
+                continue;
+            }
+            for (target, target_tf, mut stats) in targets.iter_mut() {
+                if target == owner && !aura.affects_owner {
+                    continue;
+                }
+                if !aura.reaches(center.into(), target_tf.translation().xz().into()) {
+                    continue;
+                }
+                for _ in 0..pulses {
+                    let output = execute_rules(&aura.rules, &mut stats.0, &mut Action::new());
+                    apply_output(&mut commands, target, "aura", output);
+                }
+            }
+        }
+    }
+}
+
 fn tick_rules_system(time: Res<Time>, mut query: Query<(&OnTickRules, &mut Stats)>) {
     let delta = time.delta_secs();
 
@@ -121,11 +264,19 @@ pub fn plugin(app: &mut App) {
     use super::RulePresetPlugin;
     use crate::models::Screen;
 
-    app.add_plugins(RulePresetPlugin)
+    app.add_plugins((RulePresetPlugin, super::status::plugin))
         .add_observer(on_take_damage_observer)
+        .add_observer(on_dodge_observer)
+        .add_observer(on_block_observer)
         .add_systems(
             Update,
-            (sync_attack_state_to_stats, tick_rules_system)
+            (
+                sync_attack_state_to_stats,
+                tick_rules_system,
+                timer_rules_system,
+                aura_rules_system.run_if(is_offline),
+                health_threshold_rules_system,
+            )
                 .chain()
                 .run_if(in_state(Screen::Gameplay)),
         );
@@ -2,6 +2,7 @@
 use crate::*;
 use avian3d::prelude::*;
 use bevy_skein::SkeinPlugin;
+use wasm_fantasia_shared::combat::defaults::ARENA_HALF_SIZE;
 
 pub fn plugin(app: &mut App) {
     app.add_plugins((PhysicsPlugins::default(), SkeinPlugin::default()))
@@ -14,8 +15,9 @@ fn setup_animus_scene(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
 ) {
-    // Large floor plane
-    let floor_size = 500.0;
+    // Large floor plane — finite, so anything pushed past the edge falls
+    // through to the server's kill plane
+    let floor_size = ARENA_HALF_SIZE * 2.0;
     let floor_mesh = meshes.add(Plane3d::default().mesh().size(floor_size, floor_size));
 
     let floor_material = materials.add(StandardMaterial {
@@ -32,8 +34,12 @@ fn setup_animus_scene(
         Mesh3d(floor_mesh),
         MeshMaterial3d(floor_material),
         Transform::from_translation(Vec3::ZERO),
-        Collider::half_space(Vec3::Y),
         RigidBody::Static,
+        children![(
+            // Top face flush with the visible plane
+            Transform::from_xyz(0.0, -0.5, 0.0),
+            Collider::cuboid(floor_size, 1.0, floor_size),
+        )],
     ));
 
     // Grid lines - much larger extent for "infinite" feel
@@ -94,4 +100,13 @@ fn setup_animus_scene(
     ));
 
     commands.insert_resource(ClearColor(colors::VOID));
+
+    // Open void — wind when no zone says otherwise
+    commands.spawn((
+        Name::new("SceneAudio"),
+        SceneAudio {
+            mood: Mood::Exploration,
+            ambience: Some(Ambience::Wind),
+        },
+    ));
 }
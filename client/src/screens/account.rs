@@ -0,0 +1,215 @@
+//! Account panel on the title screen: a username and password that sign in
+//! (or register the current character) on the next connect. See
+//! [`AccountLogin`] and the server's `accounts` module.
+use super::*;
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput};
+
+const MAX_FIELD_LEN: usize = 24;
+
+pub fn plugin(app: &mut App) {
+    app.init_resource::<AccountForm>()
+        .add_systems(OnEnter(Screen::Title), setup_panel)
+        .add_systems(
+            Update,
+            (
+                type_into_form,
+                refresh_panel.run_if(resource_changed::<AccountForm>),
+            )
+                .chain()
+                .run_if(in_state(Screen::Title)),
+        );
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    #[default]
+    Username,
+    Password,
+}
+
+/// What's typed so far. The password is dropped along with the form on
+/// leaving the title screen; [`AccountLogin`] keeps its own copy.
+#[derive(Resource, Default)]
+struct AccountForm {
+    username: String,
+    password: String,
+    focus: Field,
+}
+
+#[derive(Component)]
+struct FieldLabel(Field);
+
+#[derive(Component)]
+struct AccountStatus;
+
+fn field_label(form: &AccountForm, field: Field) -> String {
+    let cursor = if form.focus == field { "_" } else { "" };
+    match field {
+        Field::Username => format!("Username: {}{cursor}", form.username),
+        Field::Password => format!(
+            "Password: {}{cursor}",
+            "*".repeat(form.password.chars().count())
+        ),
+    }
+}
+
+fn status_label(login: Option<&AccountLogin>) -> String {
+    match login {
+        Some(login) if login.register => format!("Registers as {} on connect", login.username),
+        Some(login) => format!("Signs in as {} on connect", login.username),
+        None => "Playing without an account".into(),
+    }
+}
+
+fn setup_panel(
+    mut commands: Commands,
+    mut form: ResMut<AccountForm>,
+    login: Option<Res<AccountLogin>>,
+) {
+    *form = AccountForm {
+        username: login
+            .as_ref()
+            .map(|l| l.username.clone())
+            .unwrap_or_default(),
+        ..default()
+    };
+    let field = || {
+        Props::default()
+            .min_width(Vw(24.0))
+            .padding(UiRect::axes(Vw(2.0), Vh(1.5)))
+    };
+    let half = || Props::default().padding(UiRect::axes(Vw(2.0), Vh(1.5)));
+
+    commands.spawn((
+        DespawnOnExit(Screen::Title),
+        GlobalZIndex(2),
+        Name::new("Account Panel"),
+        Node {
+            position_type: PositionType::Absolute,
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::FlexEnd,
+            row_gap: Vh(1.5),
+            bottom: Vw(5.0),
+            right: Vw(5.0),
+            ..default()
+        },
+        children![
+            label("Account"),
+            (
+                btn(
+                    field().text(field_label(&form, Field::Username)),
+                    focus_username
+                ),
+                FieldLabel(Field::Username),
+            ),
+            (
+                btn(
+                    field().text(field_label(&form, Field::Password)),
+                    focus_password
+                ),
+                FieldLabel(Field::Password),
+            ),
+            (
+                Node {
+                    column_gap: Vh(1.5),
+                    ..default()
+                },
+                children![
+                    btn(half().text("Sign In"), sign_in),
+                    btn(half().text("Register"), register),
+                    btn(half().text("Sign Out"), sign_out),
+                ],
+            ),
+            (label(status_label(login.as_deref())), AccountStatus),
+        ],
+    ));
+}
+
+fn focus_username(_: On<Pointer<Click>>, mut form: ResMut<AccountForm>) {
+    form.focus = Field::Username;
+}
+
+fn focus_password(_: On<Pointer<Click>>, mut form: ResMut<AccountForm>) {
+    form.focus = Field::Password;
+}
+
+/// Typing goes to the focused field; Tab switches fields.
+fn type_into_form(mut keys: MessageReader<KeyboardInput>, mut form: ResMut<AccountForm>) {
+    for key in keys.read().filter(|k| k.state == ButtonState::Pressed) {
+        let focus = form.focus;
+        let text = match focus {
+            Field::Username => &mut form.username,
+            Field::Password => &mut form.password,
+        };
+        match &key.logical_key {
+            Key::Character(c) if text.chars().count() < MAX_FIELD_LEN => {
+                text.extend(c.chars().filter(|c| !c.is_control()));
+            }
+            Key::Space if focus == Field::Password && text.chars().count() < MAX_FIELD_LEN => {
+                text.push(' ');
+            }
+            Key::Backspace => {
+                text.pop();
+            }
+            Key::Tab => {
+                form.focus = match focus {
+                    Field::Username => Field::Password,
+                    Field::Password => Field::Username,
+                };
+            }
+            _ => {}
+        }
+    }
+}
+
+fn refresh_panel(
+    form: Res<AccountForm>,
+    login: Option<Res<AccountLogin>>,
+    fields: Query<(Entity, &FieldLabel)>,
+    status: Query<Entity, With<AccountStatus>>,
+    children: Query<&Children>,
+    mut texts: Query<&mut Text>,
+) {
+    for (button, field) in &fields {
+        for entity in children.iter_descendants(button) {
+            if let Ok(mut text) = texts.get_mut(entity) {
+                text.0 = field_label(&form, field.0);
+            }
+        }
+    }
+    for entity in &status {
+        if let Ok(mut text) = texts.get_mut(entity) {
+            text.0 = status_label(login.as_deref());
+        }
+    }
+}
+
+fn sign_in(_: On<Pointer<Click>>, mut commands: Commands, mut form: ResMut<AccountForm>) {
+    use_account(&mut commands, &mut form, false);
+}
+
+fn register(_: On<Pointer<Click>>, mut commands: Commands, mut form: ResMut<AccountForm>) {
+    use_account(&mut commands, &mut form, true);
+}
+
+/// The server checks the details; an empty field just clears the account.
+fn use_account(commands: &mut Commands, form: &mut AccountForm, register: bool) {
+    let username = form.username.trim().to_string();
+    if username.is_empty() || form.password.is_empty() {
+        commands.remove_resource::<AccountLogin>();
+    } else {
+        commands.insert_resource(AccountLogin {
+            username,
+            password: form.password.clone(),
+            register,
+        });
+    }
+    // Marks the form changed so the status label catches up
+    form.password.clear();
+}
+
+fn sign_out(_: On<Pointer<Click>>, mut commands: Commands, mut form: ResMut<AccountForm>) {
+    commands.remove_resource::<AccountLogin>();
+    form.password.clear();
+}
@@ -2,9 +2,14 @@
 //! Shows a console-style connection log with live status updates.
 //!
 //! Handles both local server startup (native SP) and remote connections (MP / web solo).
+//! Solo sessions that can't get a server fall back to offline play.
+//!
+//! Gameplay-only assets are still streaming when this screen opens, so the
+//! handshake runs alongside them and gameplay starts once both are done.
 
 use super::*;
 
+use crate::networking::offline;
 use crate::networking::{ReconnectTimer, SpacetimeDbConfig, SpacetimeDbConnection};
 use crate::ui::hud::HudFont;
 use spacetimedb_sdk::DbContext;
@@ -23,6 +28,9 @@ struct ConnectionLog {
     saw_resource: bool,
     saw_identity: bool,
     saw_ready: bool,
+    saw_loading: bool,
+    saw_assets: bool,
+    entering: bool,
 }
 
 impl ConnectionLog {
@@ -51,6 +59,7 @@ pub fn plugin(app: &mut App) {
             (
                 advance_local_server,
                 track_connection_state,
+                track_asset_loading,
                 tick_connection,
                 tick_timeout,
                 update_log_display,
@@ -67,6 +76,7 @@ fn spawn_connecting_screen(
     font: Res<HudFont>,
     server_target: Option<Res<ServerTarget>>,
     config: Res<SpacetimeDbConfig>,
+    mode: Res<GameMode>,
 ) {
     info!("Entering connecting screen");
 
@@ -76,8 +86,11 @@ fn spawn_connecting_screen(
         Some(ServerTarget::Local { .. }) => {
             log.push("Starting local SpacetimeDB server...");
         }
-        Some(ServerTarget::Remote { uri }) => {
-            log.push(format!("Connecting to {} ({})...", uri, config.module_name));
+        Some(ServerTarget::Remote { uri, module }) => {
+            log.push(format!("Connecting to {} ({})...", uri, module));
+        }
+        Some(ServerTarget::Offline) => {
+            log.push("Starting offline session...");
         }
         None => {
             log.push(format!(
@@ -140,12 +153,21 @@ fn spawn_connecting_screen(
                 ));
             });
 
-            root.spawn(btn(
-                Props::new("Cancel")
+            let small = |text| {
+                Props::new(text)
                     .margin(UiRect::ZERO)
-                    .padding(UiRect::axes(Vw(1.0), Px(6.0))),
-                cancel_connecting,
-            ));
+                    .padding(UiRect::axes(Vw(1.0), Px(6.0)))
+            };
+            root.spawn(Node {
+                column_gap: Px(12.0),
+                ..default()
+            })
+            .with_children(|buttons| {
+                if *mode == GameMode::Singleplayer {
+                    buttons.spawn(btn(small("Play offline"), play_offline));
+                }
+                buttons.spawn(btn(small("Cancel"), cancel_connecting));
+            });
         });
 }
 
@@ -153,6 +175,17 @@ fn cancel_connecting(_: On<Pointer<Click>>, mut commands: Commands) {
     commands.trigger(GoTo(Screen::Title));
 }
 
+fn play_offline(_: On<Pointer<Click>>, mut log: ResMut<ConnectionLog>, mut commands: Commands) {
+    continue_offline(&mut log, &mut commands);
+}
+
+/// Drop whatever server this session was waiting on and play in-process.
+/// Gameplay starts from [`tick_connection`] once assets are in.
+fn continue_offline(log: &mut ConnectionLog, commands: &mut Commands) {
+    log.push("Continuing offline.");
+    offline::go_offline(commands);
+}
+
 // ── Local server state machine ──────────────────────────────────────
 
 /// Drive the local SpacetimeDB subprocess forward (native SP only).
@@ -203,10 +236,15 @@ fn advance_local_server(
                 // Kick off the reconnect timer so auto_connect fires
                 commands.insert_resource(ReconnectTimer::default());
             }
+            LocalServerState::Crashed { retry, .. } => {
+                log.push(format!(
+                    "Server crashed. Restarting in {:.0}s...",
+                    retry.duration().as_secs_f32()
+                ));
+            }
             LocalServerState::Failed(err) => {
                 log.push(format!("Local server error: {err}"));
-                // Go back to title after a brief pause
-                commands.trigger(GoTo(Screen::Title));
+                continue_offline(&mut log, &mut commands);
             }
             LocalServerState::Starting => {}
         }
@@ -250,7 +288,6 @@ fn track_connection_state(
         if conn.conn.try_identity().is_some() && !log.saw_identity {
             log.saw_identity = true;
             log.push("Handshake complete!");
-            log.push("Entering gameplay...");
         }
     }
 
@@ -262,6 +299,25 @@ fn track_connection_state(
     }
 }
 
+fn track_asset_loading(resource_handles: Res<ResourceHandles>, mut log: ResMut<ConnectionLog>) {
+    if log.saw_assets {
+        return;
+    }
+    if resource_handles.is_all_done() {
+        log.saw_assets = true;
+        // Only worth a line if loading was announced
+        if log.saw_loading {
+            log.push("Game assets loaded.");
+        }
+    } else if !log.saw_loading {
+        log.saw_loading = true;
+        log.push(format!(
+            "Loading game assets ({} remaining)...",
+            resource_handles.pending()
+        ));
+    }
+}
+
 // ── Log display sync ────────────────────────────────────────────────
 
 fn update_log_display(log: Res<ConnectionLog>, mut text: Query<&mut Text, With<LogText>>) {
@@ -275,27 +331,54 @@ fn update_log_display(log: Res<ConnectionLog>, mut text: Query<&mut Text, With<L
 
 // ── Connection check + timeout ──────────────────────────────────────
 
+/// Whether the session has something to play against: a completed
+/// handshake, or an offline fallback.
+fn session_ready(
+    connection: Option<&SpacetimeDbConnection>,
+    server_target: Option<&ServerTarget>,
+) -> bool {
+    matches!(server_target, Some(ServerTarget::Offline))
+        || connection.is_some_and(|c| c.conn.try_identity().is_some())
+}
+
 fn tick_connection(
     connection: Option<Res<SpacetimeDbConnection>>,
+    server_target: Option<Res<ServerTarget>>,
+    resource_handles: Res<ResourceHandles>,
+    mut log: ResMut<ConnectionLog>,
     mut next_screen: ResMut<NextState<Screen>>,
 ) {
-    let connected = connection
-        .as_ref()
-        .is_some_and(|c| c.conn.try_identity().is_some());
-    if connected {
-        next_screen.set(Screen::Gameplay);
+    if log.entering
+        || !resource_handles.is_all_done()
+        || !session_ready(connection.as_deref(), server_target.as_deref())
+    {
+        return;
     }
+    log.entering = true;
+    log.push("Entering gameplay...");
+    next_screen.set(Screen::Gameplay);
 }
 
 fn tick_timeout(
     mut timeout: ResMut<ConnectionTimeout>,
     mut log: ResMut<ConnectionLog>,
+    mode: Res<GameMode>,
     time: Res<Time>,
+    connection: Option<Res<SpacetimeDbConnection>>,
+    server_target: Option<Res<ServerTarget>>,
     mut commands: Commands,
 ) {
+    // The timeout covers the handshake; slow asset loading isn't a failure
+    if session_ready(connection.as_deref(), server_target.as_deref()) {
+        return;
+    }
     timeout.0.tick(time.delta());
     if timeout.0.just_finished() {
         log.push("Connection timed out.");
-        commands.trigger(GoTo(Screen::Title));
+        if *mode == GameMode::Singleplayer {
+            continue_offline(&mut log, &mut commands);
+        } else {
+            commands.trigger(GoTo(Screen::Title));
+        }
     }
 }
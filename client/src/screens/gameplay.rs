@@ -3,9 +3,8 @@ use super::*;
 use bevy_seedling::prelude::*;
 use bevy_third_person_camera::ThirdPersonCamera;
 
-use crate::networking::generated::{
-    pause_world_reducer::pause_world, resume_world_reducer::resume_world,
-};
+use crate::camera::ObserverRig;
+use crate::networking::{ActiveServer, GameServer};
 
 pub(super) fn plugin(app: &mut App) {
     app.insert_resource(Modals(Vec::default()))
@@ -13,7 +12,11 @@ pub(super) fn plugin(app: &mut App) {
         .add_systems(OnEnter(Screen::Gameplay), spawn_gameplay_ui)
         .add_systems(
             OnExit(Screen::Gameplay),
-            (unpause_server_on_exit, cleanup_gameplay_entities)
+            (
+                unpause_server_on_exit,
+                reset_input_stack,
+                cleanup_gameplay_entities,
+            )
                 .chain()
                 .in_set(GameplayCleanup),
         )
@@ -43,14 +46,10 @@ fn mark_startup_entities_persistent(
 /// Ensure the server is unpaused when leaving gameplay.
 /// Covers all exit paths (Main Menu, disconnect, etc.) so the server tick
 /// isn't left frozen when the player returns.
-fn unpause_server_on_exit(
-    mut session: ResMut<Session>,
-    mode: Res<GameMode>,
-    conn: Option<Res<crate::networking::SpacetimeDbConnection>>,
-) {
+fn unpause_server_on_exit(mut session: ResMut<Session>, mode: Res<GameMode>, server: ActiveServer) {
     if session.paused && *mode != GameMode::Multiplayer {
-        if let Some(conn) = conn {
-            let _ = conn.conn.reducers.resume_world();
+        if let Some(server) = server.get() {
+            server.set_paused(false);
         }
     }
     session.paused = false;
@@ -80,31 +79,55 @@ fn cleanup_gameplay_entities(
 fn spawn_gameplay_ui() {}
 
 /// Declarative cursor/input lock. Runs every frame during gameplay.
-/// Gameplay is blocked when: paused, or any entity with [`BlocksGameplay`] exists.
-/// When blocked: cursor unlocked, PlayerCtx removed.
-/// When unblocked: cursor locked, PlayerCtx restored.
+/// The top of [`InputStack`] picks which context lives on the player entity;
+/// every other context is removed so e.g. clicking through a menu can never
+/// attack. Gameplay and spectating are blocked when paused or while any entity
+/// with [`BlocksGameplay`] exists — menus are what you use to get out of that.
+/// Cursor is locked only for gameplay and spectating.
+/// Observers have no character to play, so their base layer is spectating and
+/// the contexts live on the [`ObserverRig`] instead.
 fn sync_gameplay_lock(
     blockers: Query<(), With<BlocksGameplay>>,
     session: Res<Session>,
-    player: Query<Entity, With<Player>>,
+    input_stack: Res<InputStack>,
+    observing: Option<Res<Observing>>,
+    player: Query<Entity, Or<(With<Player>, With<ObserverRig>)>>,
     mut cam: Query<&mut ThirdPersonCamera>,
     mut commands: Commands,
 ) {
-    let should_lock = !session.paused && blockers.is_empty();
-
+    let blocked = session.paused || !blockers.is_empty();
+    let layer = match input_stack.top() {
+        InputLayer::Gameplay if observing.is_some() => InputLayer::Spectator,
+        layer => layer,
+    };
+    let active = (layer == InputLayer::Menu || !blocked).then_some(layer);
+
+    let should_lock = matches!(active, Some(InputLayer::Gameplay | InputLayer::Spectator));
     if let Ok(mut cam) = cam.single_mut() {
         cam.cursor_lock_active = should_lock;
     }
 
     if let Ok(entity) = player.single() {
-        if should_lock {
-            commands.entity(entity).insert(PlayerCtx);
-        } else {
-            commands.entity(entity).remove::<PlayerCtx>();
-        }
+        let mut e = commands.entity(entity);
+        set_ctx::<PlayerCtx>(&mut e, active == Some(InputLayer::Gameplay));
+        set_ctx::<ModalCtx>(&mut e, active == Some(InputLayer::Menu));
+        set_ctx::<SpectatorCtx>(&mut e, active == Some(InputLayer::Spectator));
     }
 }
 
+fn set_ctx<C: Component + Default>(e: &mut EntityCommands, enabled: bool) {
+    if enabled {
+        e.insert(C::default());
+    } else {
+        e.remove::<C>();
+    }
+}
+
+/// Menus and spectating don't outlive the gameplay screen.
+fn reset_input_stack(mut input_stack: ResMut<InputStack>) {
+    input_stack.clear();
+}
+
 /// Keeps `Time<Virtual>` in sync with `session.paused`.
 /// Runs globally so leaving gameplay with time paused always cleans up.
 fn sync_virtual_time(session: Res<Session>, mode: Res<GameMode>, mut time: ResMut<Time<Virtual>>) {
@@ -122,17 +145,13 @@ fn toggle_pause(
     _: On<TogglePause>,
     mut session: ResMut<Session>,
     mode: Res<GameMode>,
-    conn: Option<Res<crate::networking::SpacetimeDbConnection>>,
+    server: ActiveServer,
 ) {
     session.paused = !session.paused;
 
     if *mode != GameMode::Multiplayer {
-        if let Some(conn) = conn {
-            let _ = if session.paused {
-                conn.conn.reducers.pause_world()
-            } else {
-                conn.conn.reducers.resume_world()
-            };
+        if let Some(server) = server.get() {
+            server.set_paused(session.paused);
         }
     }
 }
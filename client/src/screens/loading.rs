@@ -1,5 +1,6 @@
-//! A loading screen during which game assets are loaded.
-//! This reduces stuttering, especially for audio on WASM.
+//! A loading screen during which the assets the menus need are loaded.
+//! This reduces stuttering, especially for audio on WASM. Gameplay-only
+//! assets keep streaming afterwards; the connecting screen waits for them.
 
 use super::*;
 
@@ -7,7 +8,7 @@ pub(super) fn plugin(app: &mut App) {
     app.add_systems(OnEnter(Screen::Loading), spawn_loading_screen)
         .add_systems(
             Update,
-            continue_to_menu_screen.run_if(in_state(Screen::Loading).and(all_assets_loaded)),
+            continue_to_menu_screen.run_if(in_state(Screen::Loading).and(critical_assets_loaded)),
         );
 }
 
@@ -23,6 +24,6 @@ fn continue_to_menu_screen(mut next_screen: ResMut<NextState<Screen>>) {
     next_screen.set(Screen::Title);
 }
 
-fn all_assets_loaded(resource_handles: Res<ResourceHandles>) -> bool {
-    resource_handles.is_all_done()
+fn critical_assets_loaded(resource_handles: Res<ResourceHandles>) -> bool {
+    resource_handles.is_critical_done()
 }
@@ -2,11 +2,13 @@
 use crate::*;
 use bevy::ui::Val::*;
 
+mod account;
 mod connecting;
 mod gameplay;
 mod loading;
 mod settings;
 mod splash;
+mod summary;
 mod title;
 
 pub fn plugin(app: &mut App) {
@@ -17,8 +19,10 @@ pub fn plugin(app: &mut App) {
         splash::plugin,
         loading::plugin,
         title::plugin,
+        account::plugin,
         settings::plugin,
         gameplay::plugin,
+        summary::plugin,
     ));
 
     app.add_plugins(connecting::plugin);
@@ -71,6 +75,7 @@ pub mod to {
         // setup_menu resets on OnEnter(Title).
         modals.clear();
         commands.remove_resource::<ServerTarget>();
+        commands.remove_resource::<Observing>();
         commands.trigger(GoTo(Screen::Title));
     }
     pub fn settings(_: On<Pointer<Click>>, mut commands: Commands) {
@@ -83,7 +88,6 @@ pub mod to {
         _: On<Pointer<Click>>,
         mut mode: ResMut<GameMode>,
         mut commands: Commands,
-        resource_handles: Res<ResourceHandles>,
         mut next_screen: ResMut<NextState<Screen>>,
         existing_server: Option<Res<crate::networking::local_server::LocalServer>>,
     ) {
@@ -101,11 +105,7 @@ pub mod to {
         };
         commands.insert_resource(ServerTarget::Local { port });
 
-        if resource_handles.is_all_done() {
-            next_screen.set(Screen::Connecting);
-        } else {
-            next_screen.set(Screen::Loading);
-        }
+        next_screen.set(Screen::Connecting);
     }
 
     /// Native singleplayer: kill the existing server and start a fresh one.
@@ -114,7 +114,6 @@ pub mod to {
         _: On<Pointer<Click>>,
         mut mode: ResMut<GameMode>,
         mut commands: Commands,
-        resource_handles: Res<ResourceHandles>,
         mut next_screen: ResMut<NextState<Screen>>,
         existing_connection: Option<Res<crate::networking::SpacetimeDbConnection>>,
     ) {
@@ -137,11 +136,7 @@ pub mod to {
         commands.insert_resource(state);
         commands.insert_resource(ServerTarget::Local { port });
 
-        if resource_handles.is_all_done() {
-            next_screen.set(Screen::Connecting);
-        } else {
-            next_screen.set(Screen::Loading);
-        }
+        next_screen.set(Screen::Connecting);
     }
 
     /// Web solo: private session on the remote server.
@@ -151,27 +146,64 @@ pub mod to {
         mut mode: ResMut<GameMode>,
         mut commands: Commands,
         config: Res<crate::networking::SpacetimeDbConfig>,
-        resource_handles: Res<ResourceHandles>,
+        selected: Res<SelectedServer>,
         mut next_screen: ResMut<NextState<Screen>>,
     ) {
         *mode = GameMode::Singleplayer;
-        commands.insert_resource(ServerTarget::Remote {
-            uri: config.uri.clone(),
-        });
+        commands.insert_resource(remote_target(&config, &selected));
 
-        if resource_handles.is_all_done() {
-            next_screen.set(Screen::Connecting);
-        } else {
-            next_screen.set(Screen::Loading);
-        }
+        next_screen.set(Screen::Connecting);
     }
 
     pub fn multiplayer(
         _: On<Pointer<Click>>,
-        mut mode: ResMut<GameMode>,
+        mode: ResMut<GameMode>,
         mut commands: Commands,
         config: Res<crate::networking::SpacetimeDbConfig>,
-        resource_handles: Res<ResourceHandles>,
+        selected: Res<SelectedServer>,
+        next_screen: ResMut<NextState<Screen>>,
+        existing_connection: Option<Res<crate::networking::SpacetimeDbConnection>>,
+    ) {
+        commands.remove_resource::<Observing>();
+        let target = remote_target(&config, &selected);
+        connect_remote(mode, commands, target, next_screen, existing_connection);
+    }
+
+    /// Watch the shared world without a character: the multiplayer
+    /// connection, with the observer camera and broadcast HUD.
+    pub fn observe(
+        _: On<Pointer<Click>>,
+        mode: ResMut<GameMode>,
+        mut commands: Commands,
+        config: Res<crate::networking::SpacetimeDbConfig>,
+        selected: Res<SelectedServer>,
+        next_screen: ResMut<NextState<Screen>>,
+        existing_connection: Option<Res<crate::networking::SpacetimeDbConnection>>,
+    ) {
+        commands.insert_resource(Observing);
+        let target = remote_target(&config, &selected);
+        connect_remote(mode, commands, target, next_screen, existing_connection);
+    }
+
+    /// The server picked on the title screen, or the configured one.
+    fn remote_target(
+        config: &crate::networking::SpacetimeDbConfig,
+        selected: &SelectedServer,
+    ) -> ServerTarget {
+        let server = selected
+            .0
+            .clone()
+            .unwrap_or_else(|| config.default_server());
+        ServerTarget::Remote {
+            uri: server.uri,
+            module: server.module,
+        }
+    }
+
+    fn connect_remote(
+        mut mode: ResMut<GameMode>,
+        mut commands: Commands,
+        target: ServerTarget,
         mut next_screen: ResMut<NextState<Screen>>,
         existing_connection: Option<Res<crate::networking::SpacetimeDbConnection>>,
     ) {
@@ -183,9 +215,7 @@ pub mod to {
             commands.remove_resource::<crate::networking::SpacetimeDbConnection>();
         }
 
-        commands.insert_resource(ServerTarget::Remote {
-            uri: config.uri.clone(),
-        });
+        commands.insert_resource(target);
 
         // Shut down local server — not needed for multiplayer
         #[cfg(not(target_arch = "wasm32"))]
@@ -194,10 +224,6 @@ pub mod to {
             commands.remove_resource::<crate::networking::local_server::LocalServerState>();
         }
 
-        if resource_handles.is_all_done() {
-            next_screen.set(Screen::Connecting);
-        } else {
-            next_screen.set(Screen::Loading);
-        }
+        next_screen.set(Screen::Connecting);
     }
 }
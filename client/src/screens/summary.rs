@@ -0,0 +1,126 @@
+//! End-of-match summary: per-player stats, XP and loot from the match that
+//! just ended, with a button to restart in the same world.
+
+use super::*;
+
+use crate::networking::{MatchSummary, PlayerMatchResult, SpacetimeDbConnection};
+use crate::ui::hud::HudFont;
+
+const COLUMNS: [&str; 6] = ["Player", "Kills", "Damage", "Crits", "XP", "Loot"];
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Summary), spawn_summary_screen);
+}
+
+fn spawn_summary_screen(mut commands: Commands, font: Res<HudFont>, summary: Res<MatchSummary>) {
+    let font_of = |size: f32| TextFont {
+        font: font.0.clone(),
+        font_size: size,
+        ..default()
+    };
+
+    let minutes = (summary.duration_secs / 60.0) as u32;
+    let seconds = (summary.duration_secs % 60.0) as u32;
+
+    commands
+        .spawn((
+            DespawnOnExit(Screen::Summary),
+            GlobalZIndex(1),
+            ui_root("Summary Screen"),
+            BackgroundColor(colors::NEUTRAL950.with_alpha(0.95)),
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Text::new("MATCH COMPLETE"),
+                font_of(24.0),
+                TextColor(colors::NEUTRAL300),
+            ));
+            root.spawn((
+                Text::new(format!("{minutes}:{seconds:02}")),
+                font_of(14.0),
+                TextColor(colors::NEUTRAL500),
+            ));
+
+            // Stats table — one column per stat so values line up
+            root.spawn((
+                Node {
+                    column_gap: Vw(3.0),
+                    padding: UiRect::all(Px(16.0)),
+                    border_radius: BorderRadius::all(Px(4.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.3)),
+            ))
+            .with_children(|table| {
+                for (i, title) in COLUMNS.into_iter().enumerate() {
+                    table
+                        .spawn(Node {
+                            flex_direction: FlexDirection::Column,
+                            align_items: if i == 0 {
+                                AlignItems::FlexStart
+                            } else {
+                                AlignItems::FlexEnd
+                            },
+                            row_gap: Px(6.0),
+                            ..default()
+                        })
+                        .with_children(|column| {
+                            column.spawn((
+                                Text::new(title),
+                                font_of(14.0),
+                                TextColor(colors::NEUTRAL500),
+                            ));
+                            for player in &summary.players {
+                                let color = if player.is_you {
+                                    colors::NEUTRAL100
+                                } else {
+                                    colors::NEUTRAL400
+                                };
+                                column.spawn((
+                                    Text::new(cell(player, i)),
+                                    font_of(16.0),
+                                    TextColor(color),
+                                ));
+                            }
+                        });
+                }
+            });
+
+            root.spawn(Node {
+                column_gap: Vw(2.0),
+                ..default()
+            })
+            .with_children(|buttons| {
+                let opts = || Props::default().padding(UiRect::axes(Vw(4.0), Vh(2.0)));
+                buttons.spawn(btn(opts().text("Play Again"), play_again));
+                buttons.spawn(btn(opts().text("Main Menu"), to::title));
+            });
+        });
+}
+
+fn cell(player: &PlayerMatchResult, column: usize) -> String {
+    match column {
+        0 if player.is_you => format!("{} (you)", player.name),
+        0 => player.name.clone(),
+        1 => player.kills.to_string(),
+        2 => format!("{:.0}", player.damage_dealt),
+        3 => player.crits.to_string(),
+        4 => format!("+{}", player.xp_gained),
+        _ => player.loot_earned.to_string(),
+    }
+}
+
+fn play_again(
+    _: On<Pointer<Click>>,
+    conn: Option<Res<SpacetimeDbConnection>>,
+    mut commands: Commands,
+) {
+    let Some(conn) = conn else {
+        warn!("Connection lost — cannot play again");
+        commands.remove_resource::<ServerTarget>();
+        commands.trigger(GoTo(Screen::Title));
+        return;
+    };
+    crate::networking::request_play_again(&conn);
+    commands.trigger(GoTo(Screen::Gameplay));
+}
@@ -1,4 +1,6 @@
 use super::*;
+use crate::networking::SpacetimeDbConfig;
+use wasm_fantasia_shared::presets::Loadout;
 
 /// This plugin is responsible for the game menu
 /// The menu is only drawn during the State [`Screen::Title`] and is removed when that state is exited
@@ -6,9 +8,38 @@ pub fn plugin(app: &mut App) {
     app.add_systems(OnEnter(Screen::Title), setup_menu);
 }
 
+#[derive(Component)]
+struct LoadoutLabel;
+
+fn loadout_label(loadout: Loadout) -> String {
+    format!("Loadout: {}", loadout.name())
+}
+
+#[derive(Component)]
+struct ServerLabel;
+
+fn server_label(server: &RemoteServer) -> String {
+    format!("Server: {}", server.label())
+}
+
+/// What the server button cycles through: the configured server, then the
+/// recent ones.
+fn server_choices(config: &SpacetimeDbConfig, settings: &Settings) -> Vec<RemoteServer> {
+    let mut choices = vec![config.default_server()];
+    for server in &settings.recent_servers {
+        if !choices.contains(server) {
+            choices.push(server.clone());
+        }
+    }
+    choices
+}
+
 fn setup_menu(
     mut commands: Commands,
     mut state: ResMut<Session>,
+    loadout: Res<SelectedLoadout>,
+    selected_server: Res<SelectedServer>,
+    config: Res<SpacetimeDbConfig>,
     #[cfg(not(target_arch = "wasm32"))] server_state: Option<
         Res<crate::networking::local_server::LocalServerState>,
     >,
@@ -82,6 +113,21 @@ fn setup_menu(
                 buttons.spawn(btn(menu().text("Solo"), to::solo));
 
                 buttons.spawn(btn(menu().text("Multiplayer"), to::multiplayer));
+                buttons.spawn(btn(menu().text("Watch"), to::observe));
+
+                let server = selected_server
+                    .0
+                    .clone()
+                    .unwrap_or_else(|| config.default_server());
+                buttons.spawn((
+                    btn(menu().text(server_label(&server)), cycle_server),
+                    ServerLabel,
+                ));
+
+                buttons.spawn((
+                    btn(menu().text(loadout_label(loadout.0)), cycle_loadout),
+                    LoadoutLabel,
+                ));
 
                 buttons.spawn(btn(menu().text("Settings"), to::settings));
 
@@ -93,6 +139,60 @@ fn setup_menu(
     state.reset();
 }
 
+/// Step to the next loadout. The pick applies to the next session.
+fn cycle_loadout(
+    _: On<Pointer<Click>>,
+    mut loadout: ResMut<SelectedLoadout>,
+    buttons: Query<Entity, With<LoadoutLabel>>,
+    children: Query<&Children>,
+    mut texts: Query<&mut Text>,
+) {
+    let next = Loadout::ALL
+        .iter()
+        .position(|l| *l == loadout.0)
+        .map_or(0, |i| (i + 1) % Loadout::ALL.len());
+    loadout.0 = Loadout::ALL[next];
+
+    for button in &buttons {
+        for entity in children.iter_descendants(button) {
+            if let Ok(mut text) = texts.get_mut(entity) {
+                text.0 = loadout_label(loadout.0);
+            }
+        }
+    }
+}
+
+/// Step to the next server for multiplayer and watching.
+fn cycle_server(
+    _: On<Pointer<Click>>,
+    config: Res<SpacetimeDbConfig>,
+    settings: Res<Settings>,
+    mut selected: ResMut<SelectedServer>,
+    buttons: Query<Entity, With<ServerLabel>>,
+    children: Query<&Children>,
+    mut texts: Query<&mut Text>,
+) {
+    let choices = server_choices(&config, &settings);
+    let current = selected
+        .0
+        .clone()
+        .unwrap_or_else(|| config.default_server());
+    let next = choices
+        .iter()
+        .position(|s| *s == current)
+        .map_or(0, |i| (i + 1) % choices.len());
+    let server = choices[next].clone();
+
+    for button in &buttons {
+        for entity in children.iter_descendants(button) {
+            if let Ok(mut text) = texts.get_mut(entity) {
+                text.0 = server_label(&server);
+            }
+        }
+    }
+    selected.0 = Some(server);
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 fn exit_app(_: On<Pointer<Click>>, mut app_exit: MessageWriter<AppExit>) {
     app_exit.write(AppExit::Success);
@@ -0,0 +1,256 @@
+//! Scripted smoke runs. Built with the `smoke_test` feature and started with
+//! `--smoke <script.ron>`: the game boots with a hidden window, replays the
+//! script's input as real keyboard/mouse messages (so it goes through the
+//! same `bevy_enhanced_input` bindings as a player would), then checks the
+//! script's expectations and exits non-zero if any failed.
+//!
+//! Scripts live in `client/tests/smoke/` and are run by `client/tests/smoke.rs`.
+
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput, NativeKey};
+use bevy::input::mouse::MouseButtonInput;
+use bevy::window::PrimaryWindow;
+use serde::Deserialize;
+use std::collections::VecDeque;
+use std::path::PathBuf;
+
+use crate::combat::DamageDealt;
+use crate::models::{GameMode, GoTo, Player, Screen};
+use crate::networking::offline;
+use bevy::prelude::*;
+
+/// Script path from `--smoke <path>`, if this is a smoke run.
+pub fn script_from_args() -> Option<PathBuf> {
+    let mut args = std::env::args().skip_while(|arg| arg != "--smoke");
+    args.next()?;
+    args.next().map(PathBuf::from)
+}
+
+pub struct SmokeTestPlugin {
+    pub script: PathBuf,
+}
+
+impl Plugin for SmokeTestPlugin {
+    fn build(&self, app: &mut App) {
+        let source = std::fs::read_to_string(&self.script)
+            .unwrap_or_else(|e| panic!("Can't read smoke script {:?}: {e}", self.script));
+        let script: SmokeScript = ron::from_str(&source)
+            .unwrap_or_else(|e| panic!("Invalid smoke script {:?}: {e}", self.script));
+        info!(
+            "Smoke run: {} steps from {:?}",
+            script.steps.len(),
+            self.script
+        );
+
+        app.insert_resource(SmokeRun::new(script))
+            .add_observer(count_damage)
+            .add_systems(Update, (observe_world, drive_script).chain());
+    }
+}
+
+// ── Script format ───────────────────────────────────────────────────
+
+#[derive(Deserialize)]
+struct SmokeScript {
+    /// Fail the run if the steps haven't finished after this many seconds.
+    timeout_secs: f32,
+    steps: Vec<Step>,
+    expect: Vec<Expect>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+enum Step {
+    /// Block until this screen is active.
+    Screen(Screen),
+    /// Do nothing for this many seconds.
+    Wait(f32),
+    /// Start a solo session without a server, as the Connecting screen's
+    /// "Play offline" button does.
+    PlayOffline,
+    Press(Input),
+    Release(Input),
+    /// Press, then release on the next frame.
+    Tap(Input),
+}
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+enum Input {
+    Key(KeyCode),
+    Mouse(MouseButton),
+}
+
+#[derive(Deserialize, Debug)]
+enum Expect {
+    /// The local player existed at some point.
+    PlayerSpawned,
+    /// At least this many [`DamageDealt`] events fired.
+    DamageDealt(u32),
+    /// This screen was active at some point.
+    Reached(Screen),
+}
+
+// ── Run state ───────────────────────────────────────────────────────
+
+#[derive(Resource)]
+struct SmokeRun {
+    steps: VecDeque<Step>,
+    expect: Vec<Expect>,
+    timeout_secs: f32,
+    elapsed: f32,
+    wait: Option<Timer>,
+    /// Inputs tapped last frame, released this one.
+    releases: Vec<Input>,
+    // What the run has seen so far
+    damage_events: u32,
+    player_seen: bool,
+    screens_seen: Vec<Screen>,
+}
+
+impl SmokeRun {
+    fn new(script: SmokeScript) -> Self {
+        Self {
+            steps: script.steps.into(),
+            expect: script.expect,
+            timeout_secs: script.timeout_secs,
+            elapsed: 0.0,
+            wait: None,
+            releases: Vec::new(),
+            damage_events: 0,
+            player_seen: false,
+            screens_seen: Vec::new(),
+        }
+    }
+
+    fn check(&self, expect: &Expect) -> bool {
+        match expect {
+            Expect::PlayerSpawned => self.player_seen,
+            Expect::DamageDealt(min) => self.damage_events >= *min,
+            Expect::Reached(screen) => self.screens_seen.contains(screen),
+        }
+    }
+}
+
+fn count_damage(_: On<DamageDealt>, mut run: ResMut<SmokeRun>) {
+    run.damage_events += 1;
+}
+
+fn observe_world(
+    mut run: ResMut<SmokeRun>,
+    screen: Res<State<Screen>>,
+    players: Query<(), With<Player>>,
+) {
+    if !run.screens_seen.contains(screen.get()) {
+        info!("Smoke run reached {:?}", screen.get());
+        run.screens_seen.push(screen.get().clone());
+    }
+    if !players.is_empty() {
+        run.player_seen = true;
+    }
+}
+
+// ── Driver ──────────────────────────────────────────────────────────
+
+#[allow(clippy::too_many_arguments)]
+fn drive_script(
+    time: Res<Time>,
+    mut run: ResMut<SmokeRun>,
+    screen: Res<State<Screen>>,
+    mut mode: ResMut<GameMode>,
+    window: Query<Entity, With<PrimaryWindow>>,
+    mut keys: MessageWriter<KeyboardInput>,
+    mut mouse: MessageWriter<MouseButtonInput>,
+    mut exit: MessageWriter<AppExit>,
+    mut commands: Commands,
+) {
+    let Ok(window) = window.single() else {
+        return;
+    };
+    let mut send = |input: Input, state: ButtonState| match input {
+        Input::Key(key_code) => {
+            keys.write(KeyboardInput {
+                key_code,
+                logical_key: Key::Unidentified(NativeKey::Unidentified),
+                state,
+                text: None,
+                repeat: false,
+                window,
+            });
+        }
+        Input::Mouse(button) => {
+            mouse.write(MouseButtonInput {
+                button,
+                state,
+                window,
+            });
+        }
+    };
+
+    run.elapsed += time.delta_secs();
+
+    // A frame of its own for releases, so a tap followed by another tap of
+    // the same input reads as two presses
+    if !run.releases.is_empty() {
+        for input in std::mem::take(&mut run.releases) {
+            send(input, ButtonState::Released);
+        }
+        return;
+    }
+
+    if run.elapsed > run.timeout_secs {
+        let step = run.steps.front().cloned();
+        error!(
+            "Smoke run timed out after {:.0}s at {step:?}",
+            run.timeout_secs
+        );
+        exit.write(AppExit::error());
+        return;
+    }
+
+    while let Some(step) = run.steps.front().cloned() {
+        match step {
+            Step::Screen(target) => {
+                if *screen.get() != target {
+                    return;
+                }
+            }
+            Step::Wait(secs) => {
+                let timer = run
+                    .wait
+                    .get_or_insert_with(|| Timer::from_seconds(secs, TimerMode::Once));
+                if !timer.tick(time.delta()).is_finished() {
+                    return;
+                }
+                run.wait = None;
+            }
+            Step::PlayOffline => {
+                *mode = GameMode::Singleplayer;
+                offline::go_offline(&mut commands);
+                commands.trigger(GoTo(Screen::Gameplay));
+            }
+            Step::Press(input) => send(input, ButtonState::Pressed),
+            Step::Release(input) => send(input, ButtonState::Released),
+            Step::Tap(input) => {
+                send(input, ButtonState::Pressed);
+                run.releases.push(input);
+                run.steps.pop_front();
+                return;
+            }
+        }
+        run.steps.pop_front();
+    }
+
+    let failed: Vec<&Expect> = run.expect.iter().filter(|e| !run.check(e)).collect();
+    if failed.is_empty() {
+        info!("Smoke run passed in {:.1}s", run.elapsed);
+        exit.write(AppExit::Success);
+    } else {
+        for expect in &failed {
+            error!("Smoke expectation failed: {expect:?}");
+        }
+        error!(
+            "Seen: screens {:?}, player {}, {} damage events",
+            run.screens_seen, run.player_seen, run.damage_events
+        );
+        exit.write(AppExit::error());
+    }
+}
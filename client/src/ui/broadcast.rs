@@ -0,0 +1,311 @@
+//! Broadcast overlay for observers — a live badge with the camera mode, and a
+//! row of player frames along the bottom in place of the player HUD.
+
+use bevy::prelude::*;
+use spacetimedb_sdk::{Identity, Table};
+
+use crate::camera::{ObserverMode, ObserverView};
+use crate::models::{Screen, is_observing};
+use crate::networking::SpacetimeDbConnection;
+use crate::networking::generated::player_table::PlayerTableAccess;
+use crate::networking::generated::world_table::WorldTableAccess;
+use crate::ui::colors::{HEALTH_RED, NEUTRAL300, NEUTRAL500, NEUTRAL700, NEUTRAL920, SAND_YELLOW};
+use crate::ui::hud::HudFont;
+
+// ── Components ──────────────────────────────────────────────────────
+
+#[derive(Component)]
+struct LiveText;
+
+#[derive(Component)]
+struct CameraModeText;
+
+/// Bottom row holding one [`PlayerFrame`] per online player.
+#[derive(Component)]
+struct FrameRow;
+
+#[derive(Component)]
+struct PlayerFrame(Identity);
+
+#[derive(Component)]
+struct FrameName(Identity);
+
+#[derive(Component)]
+struct FrameHealth(Identity);
+
+// ── Plugin ──────────────────────────────────────────────────────────
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(
+        OnEnter(Screen::Gameplay),
+        spawn_broadcast_hud.run_if(is_observing),
+    )
+    .add_systems(
+        Update,
+        (
+            tick_live,
+            tick_camera_mode,
+            rebuild_frames,
+            tick_frames.after(rebuild_frames),
+        )
+            .run_if(in_state(Screen::Gameplay).and(is_observing)),
+    );
+}
+
+// ── Layout ──────────────────────────────────────────────────────────
+
+const FRAME_WIDTH: f32 = 140.0;
+const FRAME_BAR_HEIGHT: f32 = 6.0;
+const LIVE_RED: Color = Color::srgb(0.878, 0.2, 0.2);
+
+// ── Spawn ───────────────────────────────────────────────────────────
+
+fn spawn_broadcast_hud(mut commands: Commands, font: Res<HudFont>) {
+    let font = font.0.clone();
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(32.0),
+                left: Val::Px(32.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            GlobalZIndex(90),
+            Pickable::IGNORE,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                LiveText,
+                Text::new("● LIVE"),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(LIVE_RED),
+            ));
+            parent.spawn((
+                CameraModeText,
+                Text::new(""),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(NEUTRAL300),
+            ));
+        });
+
+    commands.spawn((
+        FrameRow,
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(32.0),
+            left: Val::Px(0.0),
+            right: Val::Px(0.0),
+            flex_direction: FlexDirection::Row,
+            justify_content: JustifyContent::Center,
+            column_gap: Val::Px(12.0),
+            ..default()
+        },
+        GlobalZIndex(90),
+        Pickable::IGNORE,
+    ));
+}
+
+// ── Tick systems ────────────────────────────────────────────────────
+
+fn tick_live(
+    conn: Option<Res<SpacetimeDbConnection>>,
+    mut texts: Query<&mut Text, With<LiveText>>,
+) {
+    let Ok(mut text) = texts.single_mut() else {
+        return;
+    };
+    let world = conn
+        .as_ref()
+        .and_then(|c| c.conn.db.world().iter().next())
+        .map(|w| w.id.to_uppercase());
+    let label = match world {
+        Some(world) => format!("● LIVE  {world}"),
+        None => "● LIVE".to_string(),
+    };
+    if text.0 != label {
+        text.0 = label;
+    }
+}
+
+fn tick_camera_mode(
+    conn: Option<Res<SpacetimeDbConnection>>,
+    view: Res<ObserverView>,
+    mut texts: Query<&mut Text, With<CameraModeText>>,
+) {
+    let Ok(mut text) = texts.single_mut() else {
+        return;
+    };
+    let label = match view.mode {
+        ObserverMode::Action => "FOLLOWING ACTION   [ ] CYCLE PLAYERS   V FREE CAMERA".to_string(),
+        ObserverMode::Player(identity) => {
+            let name = conn
+                .as_ref()
+                .and_then(|c| c.conn.db.player().identity().find(&identity))
+                .and_then(|p| p.name)
+                .unwrap_or_else(|| "PLAYER".to_string());
+            format!("FOLLOWING {}   [ ] CYCLE PLAYERS", name.to_uppercase())
+        }
+        ObserverMode::FreeCamera => "FREE CAMERA   WASD MOVE   V FOLLOW ACTION".to_string(),
+    };
+    if text.0 != label {
+        text.0 = label;
+    }
+}
+
+/// Respawn the frames whenever someone joins or leaves.
+fn rebuild_frames(
+    conn: Option<Res<SpacetimeDbConnection>>,
+    font: Res<HudFont>,
+    rows: Query<Entity, With<FrameRow>>,
+    frames: Query<(Entity, &PlayerFrame)>,
+    mut commands: Commands,
+) {
+    let Ok(row) = rows.single() else {
+        return;
+    };
+    let mut players: Vec<Identity> = conn
+        .as_ref()
+        .map(|c| {
+            c.conn
+                .db
+                .player()
+                .iter()
+                .filter(|p| p.online)
+                .map(|p| p.identity)
+                .collect()
+        })
+        .unwrap_or_default();
+    players.sort_by_key(|id| id.to_hex().to_string());
+
+    let mut shown: Vec<Identity> = frames.iter().map(|(_, f)| f.0).collect();
+    shown.sort_by_key(|id| id.to_hex().to_string());
+    if shown == players {
+        return;
+    }
+    for (entity, _) in &frames {
+        commands.entity(entity).despawn();
+    }
+    for identity in players {
+        let frame = commands
+            .spawn((
+                PlayerFrame(identity),
+                Node {
+                    width: Val::Px(FRAME_WIDTH),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(4.0),
+                    padding: UiRect::all(Val::Px(6.0)),
+                    border: UiRect::all(Val::Px(1.0)),
+                    ..default()
+                },
+                BackgroundColor(NEUTRAL920.with_alpha(0.8)),
+                BorderColor::all(NEUTRAL700),
+                Pickable::IGNORE,
+            ))
+            .with_children(|frame| {
+                frame.spawn((
+                    FrameName(identity),
+                    Text::new(""),
+                    TextFont {
+                        font: font.0.clone(),
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(NEUTRAL300),
+                ));
+                frame
+                    .spawn((
+                        Node {
+                            width: Val::Percent(100.0),
+                            height: Val::Px(FRAME_BAR_HEIGHT),
+                            ..default()
+                        },
+                        BackgroundColor(NEUTRAL700),
+                    ))
+                    .with_child((
+                        FrameHealth(identity),
+                        Node {
+                            width: Val::Percent(100.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(HEALTH_RED),
+                    ));
+            })
+            .id();
+        commands.entity(row).add_child(frame);
+    }
+}
+
+/// Names, levels and health; the followed player's frame is highlighted and
+/// dead players are dimmed.
+fn tick_frames(
+    conn: Option<Res<SpacetimeDbConnection>>,
+    view: Res<ObserverView>,
+    mut frames: Query<(&PlayerFrame, &mut BorderColor)>,
+    mut names: Query<(&FrameName, &mut Text, &mut TextColor)>,
+    mut bars: Query<(&FrameHealth, &mut Node, &mut BackgroundColor)>,
+) {
+    let Some(conn) = conn else {
+        return;
+    };
+    let followed = match view.mode {
+        ObserverMode::Player(identity) => Some(identity),
+        _ => None,
+    };
+
+    for (frame, mut border) in &mut frames {
+        let color = if followed == Some(frame.0) {
+            SAND_YELLOW
+        } else {
+            NEUTRAL700
+        };
+        *border = BorderColor::all(color);
+    }
+
+    for (name, mut text, mut color) in &mut names {
+        let Some(player) = conn.conn.db.player().identity().find(&name.0) else {
+            continue;
+        };
+        let dead = player.health <= 0.0;
+        let display = player.name.as_deref().unwrap_or("PLAYER").to_uppercase();
+        let label = if dead {
+            format!("{display}  DOWN")
+        } else {
+            format!("{display}  LV {}", player.level)
+        };
+        if text.0 != label {
+            text.0 = label;
+        }
+        color.0 = if followed == Some(name.0) {
+            SAND_YELLOW
+        } else if dead {
+            NEUTRAL500
+        } else {
+            NEUTRAL300
+        };
+    }
+
+    for (bar, mut node, mut background) in &mut bars {
+        let Some(player) = conn.conn.db.player().identity().find(&bar.0) else {
+            continue;
+        };
+        let fraction = (player.health / player.max_health.max(1.0)).clamp(0.0, 1.0);
+        node.width = Val::Percent(fraction * 100.0);
+        background.0 = if player.health <= 0.0 {
+            NEUTRAL500
+        } else {
+            HEALTH_RED
+        };
+    }
+}
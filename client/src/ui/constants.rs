@@ -39,6 +39,12 @@ pub mod size {
 
     /// Health bar height
     pub const HEALTH_BAR_HEIGHT: f32 = 16.0;
+
+    /// Shield bar height (drawn above the health bar)
+    pub const SHIELD_BAR_HEIGHT: f32 = 6.0;
+
+    /// Mana and stamina bar height (drawn under the health bar)
+    pub const POOL_BAR_HEIGHT: f32 = 5.0;
 }
 
 /// Tailwind CSS neutral palette (oklch, zero chroma)
@@ -81,6 +87,8 @@ pub mod colors {
     pub const GRASS_GREEN: Color = Color::oklcha(0.5866, 0.1543, 129.84, 1.0);
     pub const RED: Color = Color::oklcha(0.5232, 0.1404, 13.84, 1.0);
     pub const HEALTH_RED: Color = Color::srgb(0.816, 0.125, 0.125);
+    pub const SHIELD_BLUE: Color = Color::srgb(0.341, 0.741, 0.945);
+    pub const MANA_BLUE: Color = Color::srgb(0.298, 0.365, 0.906);
 
     // ── Scene ──────────────────────────────────────────────────────────
     /// Near-black void used for ClearColor and fog
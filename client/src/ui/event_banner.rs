@@ -0,0 +1,95 @@
+//! World event banner — a line across the top of the screen when an invasion
+//! or double XP window starts or ends, fading out after a few seconds.
+
+use bevy::prelude::*;
+
+use crate::models::Screen;
+use crate::networking::WorldEventAnnounced;
+use crate::ui::colors::SAND_YELLOW;
+use crate::ui::hud::HudFont;
+
+// ── Components ──────────────────────────────────────────────────────
+
+#[derive(Component)]
+struct EventBanner {
+    shown_for: f32,
+}
+
+// ── Plugin ──────────────────────────────────────────────────────────
+
+pub fn plugin(app: &mut App) {
+    app.add_observer(show_banner)
+        .add_systems(Update, fade_banners.run_if(in_state(Screen::Gameplay)));
+}
+
+// ── Layout ──────────────────────────────────────────────────────────
+
+const BANNER_SECS: f32 = 4.0;
+/// Seconds at the end of [`BANNER_SECS`] spent fading out.
+const FADE_SECS: f32 = 1.0;
+
+// ── Spawn ───────────────────────────────────────────────────────────
+
+/// A new banner replaces whatever is showing.
+fn show_banner(
+    on: On<WorldEventAnnounced>,
+    font: Res<HudFont>,
+    banners: Query<Entity, With<EventBanner>>,
+    mut commands: Commands,
+) {
+    for banner in &banners {
+        commands.entity(banner).despawn();
+    }
+    let title = on.kind.title();
+    let text = if on.started {
+        format!("{title} has begun!")
+    } else {
+        format!("{title} is over")
+    };
+
+    commands.spawn((
+        EventBanner { shown_for: 0.0 },
+        DespawnOnExit(Screen::Gameplay),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Percent(12.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        GlobalZIndex(95),
+        Pickable::IGNORE,
+        children![(
+            Text::new(text),
+            TextFont {
+                font: font.0.clone(),
+                font_size: 32.0,
+                ..default()
+            },
+            TextColor(SAND_YELLOW),
+        )],
+    ));
+}
+
+// ── Tick systems ────────────────────────────────────────────────────
+
+fn fade_banners(
+    time: Res<Time>,
+    mut banners: Query<(Entity, &mut EventBanner, &Children)>,
+    mut colors: Query<&mut TextColor>,
+    mut commands: Commands,
+) {
+    for (entity, mut banner, children) in &mut banners {
+        banner.shown_for += time.delta_secs();
+        if banner.shown_for >= BANNER_SECS {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let alpha = ((BANNER_SECS - banner.shown_for) / FADE_SECS).min(1.0);
+        for child in children.iter() {
+            if let Ok(mut color) = colors.get_mut(child) {
+                color.0 = SAND_YELLOW.with_alpha(alpha);
+            }
+        }
+    }
+}
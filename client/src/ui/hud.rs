@@ -1,24 +1,64 @@
 use bevy::prelude::*;
 
-use crate::combat::Health;
-use crate::models::{Player, Screen};
-use crate::ui::colors::{HEALTH_RED, NEUTRAL300, NEUTRAL700, NEUTRAL920};
-use crate::ui::size::{HEALTH_BAR_HEIGHT, HEALTH_BAR_WIDTH};
+use crate::combat::{Health, Shield};
+use crate::models::{Player, PlayerLevel, PvpEnabled, RespawnCountdown, Screen, is_observing};
+use crate::rules::{Stat, Stats};
+use crate::ui::StatTooltip;
+use crate::ui::colors::{
+    ACID_GREEN, HEALTH_RED, MANA_BLUE, NEUTRAL300, NEUTRAL700, NEUTRAL920, SAND_YELLOW, SHIELD_BLUE,
+};
+use crate::ui::size::{HEALTH_BAR_HEIGHT, HEALTH_BAR_WIDTH, POOL_BAR_HEIGHT, SHIELD_BAR_HEIGHT};
+use crate::ui::tween::{TrailingFill, approach};
+use wasm_fantasia_shared::resources::Pool;
+
+/// How fast the shield strip eases to its new value.
+const SHIELD_EASE_RATE: f32 = 10.0;
 
 // ── Components ──────────────────────────────────────────────────────
 
 #[derive(Component)]
 struct PlayerHud;
 
+/// Eased health fill; the state lives here and drives [`HudHealthTrail`] too.
+#[derive(Component)]
+struct HudHealthFill(TrailingFill);
+
+/// Segment behind the health fill showing recently lost health.
 #[derive(Component)]
-struct HudHealthFill;
+struct HudHealthTrail;
 
 #[derive(Component)]
 struct HudHealthText;
 
+/// Eased shield fraction shown by the shield strip.
+#[derive(Component)]
+struct HudShieldFill(f32);
+
+/// Fill of the mana or stamina bar under the HP bar.
+#[derive(Component)]
+struct HudPoolFill(Pool);
+
 #[derive(Component)]
 struct HudPlayerName;
 
+/// Centre-screen countdown shown while the local player is dead.
+#[derive(Component)]
+struct HudRespawnText;
+
+/// One entry of the stats row under the HP bar. Hover for a breakdown.
+#[derive(Component)]
+struct HudStat {
+    stat: Stat,
+    label: &'static str,
+}
+
+/// Stats shown in the HUD, in order.
+const HUD_STATS: [(Stat, &str); 3] = [
+    (Stat::AttackDamage, "ATK"),
+    (Stat::CritChance, "CRIT"),
+    (Stat::AttackSpeed, "SPD"),
+];
+
 // ── Font ────────────────────────────────────────────────────────────
 
 #[derive(Resource)]
@@ -28,8 +68,14 @@ pub struct HudFont(pub Handle<Font>);
 
 pub fn plugin(app: &mut App) {
     app.add_systems(Startup, load_hud_font)
-        .add_systems(OnEnter(Screen::Gameplay), spawn_hud)
-        .add_systems(Update, (tick_health, tick_name));
+        .add_systems(
+            OnEnter(Screen::Gameplay),
+            spawn_hud.run_if(not(is_observing)),
+        )
+        .add_systems(
+            Update,
+            (tick_health, tick_pools, tick_name, tick_stats, tick_respawn),
+        );
 }
 
 fn load_hud_font(mut commands: Commands, asset_server: Res<AssetServer>) {
@@ -43,6 +89,31 @@ fn load_hud_font(mut commands: Commands, asset_server: Res<AssetServer>) {
 fn spawn_hud(mut commands: Commands, font: Res<HudFont>) {
     let font = font.0.clone();
 
+    commands
+        .spawn((
+            PlayerHud,
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                top: Val::Percent(40.0),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            GlobalZIndex(90),
+            Pickable::IGNORE,
+        ))
+        .with_child((
+            HudRespawnText,
+            Text::default(),
+            TextFont {
+                font: font.clone(),
+                font_size: 32.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            Visibility::Hidden,
+        ));
+
     commands
         .spawn((
             PlayerHud,
@@ -74,6 +145,29 @@ fn spawn_hud(mut commands: Commands, font: Res<HudFont>) {
                 },
             ));
 
+            // Shield bar — thin strip above HP, empty when the shield is down
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(HEALTH_BAR_WIDTH),
+                        height: Val::Px(SHIELD_BAR_HEIGHT),
+                        margin: UiRect::bottom(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BackgroundColor(NEUTRAL920.with_alpha(0.6)),
+                ))
+                .with_children(|bar| {
+                    bar.spawn((
+                        HudShieldFill(1.0),
+                        Node {
+                            width: Val::Percent(100.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(SHIELD_BLUE),
+                    ));
+                });
+
             // HP bar
             parent
                 .spawn((
@@ -88,7 +182,17 @@ fn spawn_hud(mut commands: Commands, font: Res<HudFont>) {
                 ))
                 .with_children(|bar| {
                     bar.spawn((
-                        HudHealthFill,
+                        HudHealthTrail,
+                        Node {
+                            position_type: PositionType::Absolute,
+                            width: Val::Percent(100.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(SAND_YELLOW.with_alpha(0.8)),
+                    ));
+                    bar.spawn((
+                        HudHealthFill(TrailingFill::new(1.0)),
                         Node {
                             width: Val::Percent(100.0),
                             height: Val::Percent(100.0),
@@ -98,6 +202,31 @@ fn spawn_hud(mut commands: Commands, font: Res<HudFont>) {
                     ));
                 });
 
+            // Mana and stamina bars — thin strips under HP
+            for (pool, color) in [(Pool::Mana, MANA_BLUE), (Pool::Stamina, ACID_GREEN)] {
+                parent
+                    .spawn((
+                        Node {
+                            width: Val::Px(HEALTH_BAR_WIDTH),
+                            height: Val::Px(POOL_BAR_HEIGHT),
+                            margin: UiRect::top(Val::Px(2.0)),
+                            ..default()
+                        },
+                        BackgroundColor(NEUTRAL920.with_alpha(0.6)),
+                    ))
+                    .with_children(|bar| {
+                        bar.spawn((
+                            HudPoolFill(pool),
+                            Node {
+                                width: Val::Percent(100.0),
+                                height: Val::Percent(100.0),
+                                ..default()
+                            },
+                            BackgroundColor(color),
+                        ));
+                    });
+            }
+
             // HP label row
             parent
                 .spawn(Node {
@@ -122,46 +251,149 @@ fn spawn_hud(mut commands: Commands, font: Res<HudFont>) {
                         HudHealthText,
                         Text::new("100 / 100"),
                         TextFont {
-                            font,
+                            font: font.clone(),
                             font_size: 14.0,
                             ..default()
                         },
                         TextColor(Color::WHITE),
                     ));
                 });
+
+            // Stats row — the only hoverable part of the HUD
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(14.0),
+                    margin: UiRect::top(Val::Px(6.0)),
+                    padding: UiRect::horizontal(Val::Px(2.0)),
+                    ..default()
+                })
+                .with_children(|row| {
+                    for (stat, label) in HUD_STATS {
+                        row.spawn((
+                            HudStat {
+                                stat: stat.clone(),
+                                label,
+                            },
+                            StatTooltip::player(stat),
+                            Text::new(label),
+                            TextFont {
+                                font: font.clone(),
+                                font_size: 12.0,
+                                ..default()
+                            },
+                            TextColor(NEUTRAL300),
+                            Pickable::default(),
+                        ));
+                    }
+                });
         });
 }
 
 // ── Tick systems ────────────────────────────────────────────────────
 
 fn tick_health(
-    player: Query<&Health, With<Player>>,
-    mut fills: Query<&mut Node, With<HudHealthFill>>,
+    time: Res<Time>,
+    player: Query<(&Health, Option<&Shield>), With<Player>>,
+    mut fills: Query<(&mut Node, &mut HudHealthFill)>,
+    mut trails: Query<&mut Node, (With<HudHealthTrail>, Without<HudHealthFill>)>,
+    mut shield_fills: Query<
+        (&mut Node, &mut HudShieldFill),
+        (Without<HudHealthFill>, Without<HudHealthTrail>),
+    >,
     mut texts: Query<&mut Text, With<HudHealthText>>,
 ) {
-    let Ok(health) = player.single() else { return };
+    let Ok((health, shield)) = player.single() else {
+        return;
+    };
+    let dt = time.delta_secs();
 
-    if let Ok(mut fill) = fills.single_mut() {
-        fill.width = Val::Percent(health.fraction() * 100.0);
+    if let Ok((mut node, mut fill)) = fills.single_mut() {
+        fill.0.tick(health.fraction(), dt);
+        node.width = Val::Percent(fill.0.fill * 100.0);
+        if let Ok(mut trail) = trails.single_mut() {
+            trail.width = Val::Percent(fill.0.trail * 100.0);
+        }
+    }
+    if let Ok((mut node, mut fill)) = shield_fills.single_mut() {
+        let target = shield.map_or(0.0, Shield::fraction);
+        fill.0 = approach(fill.0, target, SHIELD_EASE_RATE, dt);
+        node.width = Val::Percent(fill.0 * 100.0);
     }
     if let Ok(mut text) = texts.single_mut() {
         text.0 = format!("{:.0} / {:.0}", health.current, health.max);
     }
 }
 
+fn tick_pools(player: Query<&Stats, With<Player>>, mut fills: Query<(&mut Node, &HudPoolFill)>) {
+    let Ok(stats) = player.single() else {
+        return;
+    };
+    for (mut node, fill) in &mut fills {
+        let max = stats.get(&fill.0.max_stat());
+        let fraction = if max > 0.0 {
+            (stats.get(&fill.0.stat()) / max).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        node.width = Val::Percent(fraction * 100.0);
+    }
+}
+
+fn tick_stats(player: Query<&Stats, With<Player>>, mut texts: Query<(&HudStat, &mut Text)>) {
+    let Ok(stats) = player.single() else {
+        return;
+    };
+    for (hud_stat, mut text) in &mut texts {
+        let value = stats.get(&hud_stat.stat);
+        let formatted = match hud_stat.stat {
+            Stat::CritChance => format!("{} {:.0}%", hud_stat.label, value * 100.0),
+            Stat::AttackSpeed => format!("{} {value:.2}", hud_stat.label),
+            _ => format!("{} {value:.0}", hud_stat.label),
+        };
+        if text.0 != formatted {
+            text.0 = formatted;
+        }
+    }
+}
+
+fn tick_respawn(
+    player: Query<Option<&RespawnCountdown>, With<Player>>,
+    mut texts: Query<(&mut Text, &mut Visibility), With<HudRespawnText>>,
+) {
+    let Ok((mut text, mut visibility)) = texts.single_mut() else {
+        return;
+    };
+    let Some(countdown) = player.single().ok().flatten() else {
+        *visibility = Visibility::Hidden;
+        return;
+    };
+    let label = if countdown.0 > 0.0 {
+        format!("RESPAWN IN {:.0}", countdown.0.ceil())
+    } else {
+        "RESPAWNING".to_string()
+    };
+    if text.0 != label {
+        text.0 = label;
+    }
+    *visibility = Visibility::Inherited;
+}
+
 fn tick_name(
-    player: Query<Option<&Name>, With<Player>>,
+    player: Query<(Option<&Name>, Option<&PlayerLevel>, Has<PvpEnabled>), With<Player>>,
     mut names: Query<&mut Text, With<HudPlayerName>>,
 ) {
-    let Ok(name_opt) = player.single() else {
+    let Ok((name_opt, level, pvp)) = player.single() else {
         return;
     };
     let display = name_opt.map(|n| n.as_str()).unwrap_or("PLAYER");
+    let level = level.map_or(1, |l| l.level);
+    let pvp = if pvp { "  PVP" } else { "" };
 
     if let Ok(mut text) = names.single_mut() {
-        let upper = display.to_uppercase();
-        if text.0 != upper {
-            text.0 = upper;
+        let label = format!("{}  LV {level}{pvp}", display.to_uppercase());
+        if text.0 != label {
+            text.0 = label;
         }
     }
 }
@@ -14,7 +14,9 @@ use bevy::{
 };
 use serde::{Deserialize, Serialize};
 
+mod broadcast;
 mod constants;
+mod event_banner;
 pub mod hud;
 mod interaction;
 mod modal;
@@ -23,12 +25,15 @@ mod performance;
 mod prefabs;
 mod props;
 mod server_status;
+mod stat_tooltip;
+pub mod tween;
 mod widget;
 
 pub use constants::*;
 pub use modal::*;
 pub use prefabs::*;
 pub use props::*;
+pub use stat_tooltip::StatTooltip;
 pub use widget::*;
 
 pub fn plugin(app: &mut App) {
@@ -37,9 +42,14 @@ pub fn plugin(app: &mut App) {
         interaction::plugin,
         modal::plugin,
         hud::plugin,
+        stat_tooltip::plugin,
     ));
 
-    app.add_plugins(server_status::plugin);
+    app.add_plugins((
+        server_status::plugin,
+        broadcast::plugin,
+        event_banner::plugin,
+    ));
 
     #[cfg(feature = "dev")]
     app.add_plugins(performance::plugin);
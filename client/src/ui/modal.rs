@@ -1,5 +1,3 @@
-use bevy_enhanced_input::prelude::Actions;
-
 use super::*;
 
 pub fn plugin(app: &mut App) {
@@ -20,14 +18,14 @@ pub fn add_new_modal(
     session: Res<Session>,
     mut commands: Commands,
     mut modals: ResMut<Modals>,
+    mut input_stack: ResMut<InputStack>,
 ) {
     if *screen.get() != Screen::Gameplay {
         return;
     }
 
-    let mut target = commands.entity(on.entity);
     if modals.is_empty() {
-        target.insert(ModalCtx);
+        input_stack.push(InputLayer::Menu);
         if Modal::Main == on.modal {
             if !session.paused {
                 commands.trigger(TogglePause);
@@ -59,9 +57,9 @@ pub fn pop_modal(
     menu_marker: Query<Entity, With<MenuModal>>,
     settings_marker: Query<Entity, With<SettingsModal>>,
     backdrop: Query<Entity, With<ModalBackdrop>>,
-    modal_ctx_holder: Query<Entity, With<ModalCtx>>,
     mut commands: Commands,
     mut modals: ResMut<Modals>,
+    mut input_stack: ResMut<InputStack>,
 ) {
     if Screen::Gameplay != *screen.get() {
         return;
@@ -96,12 +94,7 @@ pub fn pop_modal(
         if let Ok(bg) = backdrop.single() {
             commands.entity(bg).despawn();
         }
-        if let Ok(entity) = modal_ctx_holder.single() {
-            commands
-                .entity(entity)
-                .remove::<ModalCtx>()
-                .despawn_related::<Actions<ModalCtx>>();
-        }
+        input_stack.pop(InputLayer::Menu);
         commands.trigger(TogglePause);
         commands.trigger(CamCursorToggle);
     }
@@ -3,6 +3,8 @@ use bevy::dev_tools::fps_overlay::{FpsOverlayConfig, FpsOverlayPlugin, FrameTime
 use bevy::input::common_conditions::input_just_pressed;
 use std::time::Duration;
 
+use crate::networking::{NetStats, is_server_connected};
+
 const FPS_OVERLAY_ZINDEX: i32 = i32::MAX - 32;
 const BENCHMARK_DURATION: Duration = Duration::from_secs(10);
 
@@ -26,6 +28,11 @@ pub fn plugin(app: &mut App) {
     });
 
     app.add_systems(PostStartup, (strip_fps_label, adjust_fps_layout));
+    app.add_systems(OnEnter(Screen::Gameplay), spawn_net_overlay);
+    app.add_systems(
+        Update,
+        update_net_overlay.run_if(in_state(Screen::Gameplay).and(is_server_connected)),
+    );
     app.add_systems(
         Update,
         toggle_benchmark.run_if(input_just_pressed(KeyCode::F9)),
@@ -55,6 +62,51 @@ fn adjust_fps_layout(mut nodes: Query<(&GlobalZIndex, &mut Node)>) {
     }
 }
 
+// ── Net overlay ──────────────────────────────────────────────────────────
+
+/// Network traffic from [`NetStats`], under the FPS counter.
+#[derive(Component)]
+struct NetOverlay;
+
+fn spawn_net_overlay(mut commands: Commands) {
+    commands.spawn((
+        NetOverlay,
+        DespawnOnExit(Screen::Gameplay),
+        Text::default(),
+        TextFont {
+            font_size: 12.0,
+            ..default()
+        },
+        TextColor(colors::NEUTRAL400),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(16.0),
+            top: Val::Px(36.0),
+            ..default()
+        },
+        GlobalZIndex(FPS_OVERLAY_ZINDEX - 1),
+        Pickable::IGNORE,
+    ));
+}
+
+fn update_net_overlay(stats: Res<NetStats>, mut overlay: Query<&mut Text, With<NetOverlay>>) {
+    if !stats.is_changed() {
+        return;
+    }
+    for mut text in &mut overlay {
+        text.0 = format!(
+            "rtt {:.0}ms ±{:.0}  out {:.0}/s  in {:.0} rows/s ~{:.1} KB/s\n{} players  {} enemies",
+            stats.rtt_ms,
+            stats.jitter_ms,
+            stats.calls_per_sec,
+            stats.rows_per_sec,
+            stats.bytes_per_sec / 1024.0,
+            stats.players,
+            stats.enemies,
+        );
+    }
+}
+
 // ── ECS resources ────────────────────────────────────────────────────────
 
 #[derive(Resource)]
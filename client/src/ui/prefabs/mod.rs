@@ -2,11 +2,12 @@ use super::*;
 
 mod modals;
 mod settings;
+mod video_preview;
 
 pub use modals::*;
 pub use settings::*;
 
 pub fn plugin(app: &mut App) {
     // app.add_plugins((keybind_editor::plugin, settings::plugin));
-    app.add_plugins(settings::plugin);
+    app.add_plugins((settings::plugin, video_preview::plugin));
 }
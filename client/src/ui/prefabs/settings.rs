@@ -1,7 +1,10 @@
+use super::video_preview::{UiScaleRevert, preview_frame, start_ui_scale_revert};
 use super::*;
 use bevy::window::{PresentMode, PrimaryWindow};
 use bevy_seedling::prelude::*;
 
+use crate::networking::NetworkTuning;
+
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(
         Update,
@@ -10,18 +13,30 @@ pub(super) fn plugin(app: &mut App) {
             update_music_volume_label,
             update_sfx_volume_label,
             update_fov_label,
+            update_rumble_label,
+            update_ui_scale_label,
+            update_interpolation_label,
+            update_sync_rate_label,
             update_tab_content.run_if(resource_changed::<ActiveTab>),
         ),
     );
 }
 
 markers!(
+    SettingsRoot,
     GeneralVolumeLabel,
     MusicVolumeLabel,
     SfxVolumeLabel,
     SaveSettingsLabel,
     VsyncLabel,
     FovLabel,
+    RumbleLabel,
+    PostFxLabel,
+    NamesLabel,
+    UiScaleLabel,
+    InterpolationLabel,
+    SyncRateLabel,
+    VideoGrid,
     TabBar,
     TabContent,
     ScreenShakeLabel
@@ -61,6 +76,7 @@ pub fn save_settings(
 // TAB CHANGING
 fn update_tab_content(
     session: Res<Session>,
+    settings: Res<Settings>,
     active_tab: Res<ActiveTab>,
     tab_bar: Query<&Children, With<TabBar>>,
     mut tab_content: Query<(Entity, &Children), With<TabContent>>,
@@ -136,7 +152,12 @@ fn update_tab_content(
                         commands.spawn(audio_grid()).insert(ChildOf(e));
                     }
                     UiTab::Video => {
-                        commands.spawn(video_grid(&session)).insert(ChildOf(e));
+                        commands
+                            .spawn(video_grid(&session, &settings))
+                            .insert(ChildOf(e));
+                    }
+                    UiTab::Network => {
+                        commands.spawn(network_grid()).insert(ChildOf(e));
                     }
                 }
             }
@@ -152,7 +173,7 @@ fn fov_lower(
     _: On<Pointer<Click>>,
     cfg: Res<Config>,
     mut settings: ResMut<Settings>,
-    mut world_model_projection: Single<&mut Projection>,
+    mut world_model_projection: Single<&mut Projection, With<SceneCamera>>,
 ) {
     let Projection::Perspective(perspective) = world_model_projection.as_mut() else {
         return;
@@ -166,7 +187,7 @@ fn fov_raise(
     _: On<Pointer<Click>>,
     cfg: Res<Config>,
     mut settings: ResMut<Settings>,
-    mut world_model_projection: Single<&mut Projection>,
+    mut world_model_projection: Single<&mut Projection, With<SceneCamera>>,
 ) {
     let Projection::Perspective(perspective) = world_model_projection.as_mut() else {
         return;
@@ -182,6 +203,103 @@ fn update_fov_label(settings: Res<Settings>, mut label: Single<&mut Text, With<F
     label.0 = text;
 }
 
+// UI SCALE
+const MIN_UI_SCALE: f32 = 0.75;
+const MAX_UI_SCALE: f32 = 1.5;
+const UI_SCALE_STEP: f32 = 0.05;
+
+fn ui_scale_lower(
+    _: On<Pointer<Click>>,
+    mut settings: ResMut<Settings>,
+    pending: Option<ResMut<UiScaleRevert>>,
+    mut commands: Commands,
+) {
+    let previous = settings.ui_scale;
+    settings.ui_scale = (previous - UI_SCALE_STEP).max(MIN_UI_SCALE);
+    if settings.ui_scale != previous {
+        start_ui_scale_revert(previous, pending, &mut commands);
+    }
+}
+
+fn ui_scale_raise(
+    _: On<Pointer<Click>>,
+    mut settings: ResMut<Settings>,
+    pending: Option<ResMut<UiScaleRevert>>,
+    mut commands: Commands,
+) {
+    let previous = settings.ui_scale;
+    settings.ui_scale = (previous + UI_SCALE_STEP).min(MAX_UI_SCALE);
+    if settings.ui_scale != previous {
+        start_ui_scale_revert(previous, pending, &mut commands);
+    }
+}
+
+fn update_ui_scale_label(
+    settings: Res<Settings>,
+    mut label: Single<&mut Text, With<UiScaleLabel>>,
+) {
+    let percent = (settings.ui_scale * 100.0).round();
+    let text = format!("{percent: <3}%"); // pad the percent to 3 chars
+    label.0 = text;
+}
+
+// RUMBLE
+const MAX_RUMBLE: f32 = 1.0;
+
+fn rumble_lower(_: On<Pointer<Click>>, cfg: Res<Config>, mut settings: ResMut<Settings>) {
+    settings.rumble = (settings.rumble - cfg.settings.step).max(0.0);
+}
+
+fn rumble_raise(_: On<Pointer<Click>>, cfg: Res<Config>, mut settings: ResMut<Settings>) {
+    settings.rumble = (settings.rumble + cfg.settings.step).min(MAX_RUMBLE);
+}
+
+fn update_rumble_label(settings: Res<Settings>, mut label: Single<&mut Text, With<RumbleLabel>>) {
+    let percent = (settings.rumble * 100.0).round();
+    let text = format!("{percent: <3}%"); // pad the percent to 3 chars
+    label.0 = text;
+}
+
+// NETWORK
+const INTERPOLATION_STEP: f32 = 2.0;
+const SYNC_RATE_STEP: f32 = 5.0;
+
+fn interpolation_lower(_: On<Pointer<Click>>, mut tuning: ResMut<NetworkTuning>) {
+    let (min, _) = NetworkTuning::INTERPOLATION_SPEED_RANGE;
+    tuning.interpolation_speed = (tuning.interpolation_speed - INTERPOLATION_STEP).max(min);
+}
+
+fn interpolation_raise(_: On<Pointer<Click>>, mut tuning: ResMut<NetworkTuning>) {
+    let (_, max) = NetworkTuning::INTERPOLATION_SPEED_RANGE;
+    tuning.interpolation_speed = (tuning.interpolation_speed + INTERPOLATION_STEP).min(max);
+}
+
+fn update_interpolation_label(
+    tuning: Res<NetworkTuning>,
+    mut label: Single<&mut Text, With<InterpolationLabel>>,
+) {
+    let speed = tuning.interpolation_speed.round();
+    label.0 = format!("{speed: <3}");
+}
+
+fn sync_rate_lower(_: On<Pointer<Click>>, mut tuning: ResMut<NetworkTuning>) {
+    let (min, _) = NetworkTuning::SYNC_RATE_RANGE;
+    tuning.sync_rate_hz = (tuning.sync_rate_hz - SYNC_RATE_STEP).max(min);
+}
+
+fn sync_rate_raise(_: On<Pointer<Click>>, mut tuning: ResMut<NetworkTuning>) {
+    let (_, max) = NetworkTuning::SYNC_RATE_RANGE;
+    tuning.sync_rate_hz = (tuning.sync_rate_hz + SYNC_RATE_STEP).min(max);
+}
+
+fn update_sync_rate_label(
+    tuning: Res<NetworkTuning>,
+    mut label: Single<&mut Text, With<SyncRateLabel>>,
+) {
+    let rate = tuning.sync_rate_hz.round();
+    label.0 = format!("{rate}Hz");
+}
+
 // GENERAL
 fn general_lower(
     _: On<Pointer<Click>>,
@@ -356,6 +474,36 @@ fn click_toggle_debug_ui(
     }
 }
 
+fn click_toggle_postfx(
+    _: On<Pointer<Click>>,
+    mut settings: ResMut<Settings>,
+    buttons: Query<Entity, With<PostFxLabel>>,
+    children_q: Query<&Children>,
+    mut text_q: Query<&mut Text>,
+) {
+    settings.postfx = !settings.postfx;
+    let label = if settings.postfx { "on" } else { "off" };
+
+    for button in buttons.iter() {
+        update_button_text(button, label, &children_q, &mut text_q);
+    }
+}
+
+fn click_toggle_names(
+    _: On<Pointer<Click>>,
+    mut settings: ResMut<Settings>,
+    buttons: Query<Entity, With<NamesLabel>>,
+    children_q: Query<&Children>,
+    mut text_q: Query<&mut Text>,
+) {
+    settings.show_names = !settings.show_names;
+    let label = if settings.show_names { "on" } else { "off" };
+
+    for button in buttons.iter() {
+        update_button_text(button, label, &children_q, &mut text_q);
+    }
+}
+
 fn click_toggle_screen_shake(
     _: On<Pointer<Click>>,
     mut state: ResMut<Session>,
@@ -388,23 +536,27 @@ fn click_toggle_settings(
 
 pub fn settings_ui() -> impl Bundle {
     (
+        SettingsRoot,
         ui_root("Settings Screen"),
         GlobalZIndex(200),
-        children![(
-            Node {
-                width: Percent(80.0),
-                height: Percent(80.0),
-                flex_direction: FlexDirection::Column,
-                justify_content: JustifyContent::Center,
-                align_items: AlignItems::Center,
-                ..default()
-            },
-            children![
-                tab_bar(),
-                (TabContent, Node::default(), children![audio_grid()]),
-                bottom_row()
-            ]
-        )],
+        children![
+            (
+                Node {
+                    width: Percent(80.0),
+                    height: Percent(80.0),
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                children![
+                    tab_bar(),
+                    (TabContent, Node::default(), children![audio_grid()]),
+                    bottom_row()
+                ]
+            ),
+            preview_frame(),
+        ],
     )
 }
 
@@ -414,8 +566,11 @@ fn tab_bar() -> impl Bundle {
     let left_tab = Props::default()
         .text("Audio")
         .border_radius_custom(BorderRadius::new(r, z, z, r));
-    let right_tab = Props::default()
+    let middle_tab = Props::default()
         .text("Video")
+        .border_radius_custom(BorderRadius::ZERO);
+    let right_tab = Props::default()
+        .text("Network")
         .border_radius_custom(BorderRadius::new(z, r, r, z));
     (
         Node {
@@ -439,7 +594,11 @@ fn tab_bar() -> impl Bundle {
                 TabBar,
                 children![
                     (btn(left_tab, switch_to_tab(UiTab::Audio)), UiTab::Audio),
-                    (btn(right_tab, switch_to_tab(UiTab::Video)), UiTab::Video),
+                    (btn(middle_tab, switch_to_tab(UiTab::Video)), UiTab::Video),
+                    (
+                        btn(right_tab, switch_to_tab(UiTab::Network)),
+                        UiTab::Network
+                    ),
                 ],
             ),
         ],
@@ -463,7 +622,9 @@ fn bottom_row() -> impl Bundle {
     )
 }
 
-fn video_grid(state: &Session) -> impl Bundle {
+fn video_grid(state: &Session, settings: &Settings) -> impl Bundle {
+    let postfx_label = if settings.postfx { "on" } else { "off" };
+    let names_label = if settings.show_names { "on" } else { "off" };
     let screen_shake_label = if state.screen_shake { "on" } else { "off" };
 
     #[cfg(feature = "dev")]
@@ -473,6 +634,7 @@ fn video_grid(state: &Session) -> impl Bundle {
 
     (
         Name::new("Settings Video Grid"),
+        VideoGrid,
         Node {
             row_gap: Px(14.0),
             column_gap: Px(30.0),
@@ -486,6 +648,10 @@ fn video_grid(state: &Session) -> impl Bundle {
         children![
             label("FOV"),
             plus_minus_bar(FovLabel, fov_lower, fov_raise),
+            label("Post FX"),
+            (btn(postfx_label, click_toggle_postfx), PostFxLabel),
+            label("UI Scale"),
+            plus_minus_bar(UiScaleLabel, ui_scale_lower, ui_scale_raise),
             label("VSync"),
             (btn("on", click_toggle_vsync), VsyncLabel),
             label("Screen Shake"),
@@ -493,11 +659,19 @@ fn video_grid(state: &Session) -> impl Bundle {
                 btn(screen_shake_label, click_toggle_screen_shake),
                 ScreenShakeLabel
             ),
+            label("Rumble"),
+            plus_minus_bar(RumbleLabel, rumble_lower, rumble_raise),
+            label("Player Names"),
+            (btn(names_label, click_toggle_names), NamesLabel),
         ],
         #[cfg(feature = "dev")]
         children![
             label("FOV"),
             plus_minus_bar(FovLabel, fov_lower, fov_raise),
+            label("Post FX"),
+            (btn(postfx_label, click_toggle_postfx), PostFxLabel),
+            label("UI Scale"),
+            plus_minus_bar(UiScaleLabel, ui_scale_lower, ui_scale_raise),
             label("VSync"),
             (btn("on", click_toggle_vsync), VsyncLabel),
             label("Screen Shake"),
@@ -505,6 +679,10 @@ fn video_grid(state: &Session) -> impl Bundle {
                 btn(screen_shake_label, click_toggle_screen_shake),
                 ScreenShakeLabel
             ),
+            label("Rumble"),
+            plus_minus_bar(RumbleLabel, rumble_lower, rumble_raise),
+            label("Player Names"),
+            (btn(names_label, click_toggle_names), NamesLabel),
             label("Diagnostics"),
             (
                 btn(diagnostics_label, click_toggle_diagnostics),
@@ -538,3 +716,26 @@ fn audio_grid() -> impl Bundle {
         ],
     )
 }
+
+/// Live [`NetworkTuning`]: how tightly remote players follow the server, and
+/// how often ours is sent.
+fn network_grid() -> impl Bundle {
+    (
+        Name::new("Settings Network Grid"),
+        Node {
+            row_gap: Px(14.0),
+            column_gap: Px(30.0),
+            display: Display::Grid,
+            grid_template_columns: RepeatedGridTrack::px(2, 240.0),
+            align_items: AlignItems::Center,
+            justify_items: JustifyItems::Center,
+            ..default()
+        },
+        children![
+            label("Interpolation"),
+            plus_minus_bar(InterpolationLabel, interpolation_lower, interpolation_raise),
+            label("Sync Rate"),
+            plus_minus_bar(SyncRateLabel, sync_rate_lower, sync_rate_raise),
+        ],
+    )
+}
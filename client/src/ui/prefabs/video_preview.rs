@@ -0,0 +1,337 @@
+//! Live preview for the video settings. Changes apply to the running game as
+//! they're made; while the Video tab is open in game, two small cameras split
+//! a frame in the corner between the view as it was when settings opened
+//! (before) and as it is now (after). A UI scale change reverts on its own
+//! unless it's kept within [`REVERT_SECS`], so a scale that pushes the
+//! buttons off screen can't lock anyone out.
+
+use super::*;
+use crate::postfx::color_grading;
+use bevy::camera::Viewport;
+use bevy::core_pipeline::prepass::DeferredPrepass;
+use bevy::pbr::DistanceFog;
+use bevy::render::view::{ColorGrading, Hdr};
+use bevy::ui::UiScale;
+use bevy::window::PrimaryWindow;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<VideoBaseline>()
+        .add_observer(capture_baseline)
+        .add_systems(
+            Update,
+            (
+                apply_ui_scale.run_if(resource_changed::<Settings>),
+                (spawn_preview_cameras, sync_preview_cameras).chain(),
+                (
+                    spawn_revert_prompt.run_if(resource_added::<UiScaleRevert>),
+                    tick_ui_scale_revert.run_if(resource_exists::<UiScaleRevert>),
+                    despawn_revert_prompt.run_if(resource_removed::<UiScaleRevert>),
+                )
+                    .chain(),
+            ),
+        );
+}
+
+/// Seconds a UI scale change waits to be kept before it's undone.
+pub const REVERT_SECS: f32 = 10.0;
+
+/// Logical size of each half of the split preview.
+const PREVIEW_HALF: Vec2 = Vec2::new(180.0, 200.0);
+const PREVIEW_BORDER: f32 = 2.0;
+/// Gap between the preview frame and the bottom right corner of the window.
+const PREVIEW_MARGIN: f32 = 24.0;
+
+markers!(PreviewFrame, RevertPrompt, RevertCountdownLabel);
+
+/// Video settings in effect when the settings screen opened: the "before"
+/// half of the preview.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct VideoBaseline {
+    pub fov: f32,
+    pub postfx: bool,
+}
+
+impl Default for VideoBaseline {
+    fn default() -> Self {
+        let settings = Settings::default();
+        Self {
+            fov: settings.fov,
+            postfx: settings.postfx,
+        }
+    }
+}
+
+/// One half of the split preview.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+enum PreviewCamera {
+    Before,
+    After,
+}
+
+/// A UI scale change waiting to be kept. Present only while the countdown
+/// runs.
+#[derive(Resource, Debug)]
+pub struct UiScaleRevert {
+    /// Scale to go back to.
+    pub previous: f32,
+    pub timer: Timer,
+}
+
+impl UiScaleRevert {
+    pub fn new(previous: f32) -> Self {
+        Self {
+            previous,
+            timer: Timer::from_seconds(REVERT_SECS, TimerMode::Once),
+        }
+    }
+}
+
+fn capture_baseline(
+    _: On<Add, SettingsRoot>,
+    settings: Res<Settings>,
+    mut baseline: ResMut<VideoBaseline>,
+) {
+    *baseline = VideoBaseline {
+        fov: settings.fov,
+        postfx: settings.postfx,
+    };
+}
+
+fn apply_ui_scale(settings: Res<Settings>, mut ui_scale: ResMut<UiScale>) {
+    ui_scale.0 = settings.ui_scale;
+}
+
+/// The preview is up while the Video tab is open over gameplay; the cameras
+/// come and go with it.
+fn spawn_preview_cameras(
+    screen: Res<State<Screen>>,
+    video_grid: Query<(), With<VideoGrid>>,
+    mut frame: Query<&mut Visibility, With<PreviewFrame>>,
+    scene_camera: Query<Option<&DistanceFog>, With<SceneCamera>>,
+    previews: Query<Entity, With<PreviewCamera>>,
+    mut commands: Commands,
+) {
+    let mut shown = false;
+    for mut visibility in &mut frame {
+        shown = *screen.get() == Screen::Gameplay && !video_grid.is_empty();
+        visibility.set_if_neq(if shown {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        });
+    }
+
+    if !shown {
+        for entity in &previews {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+    if !previews.is_empty() {
+        return;
+    }
+    let Ok(fog) = scene_camera.single() else {
+        return;
+    };
+    for (order, side) in [(1, PreviewCamera::Before), (2, PreviewCamera::After)] {
+        let mut preview = commands.spawn((
+            Name::new(format!("Settings Preview ({side:?})")),
+            side,
+            Camera3d::default(),
+            Camera { order, ..default() },
+            Hdr,
+            Msaa::Off,
+            DeferredPrepass,
+        ));
+        if let Some(fog) = fog {
+            preview.insert(fog.clone());
+        }
+    }
+}
+
+/// Point both halves where the scene camera looks, give each its settings and
+/// fit their viewports into the frame.
+fn sync_preview_cameras(
+    settings: Res<Settings>,
+    baseline: Res<VideoBaseline>,
+    ui_scale: Res<UiScale>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    scene_camera: Query<(&Transform, &Projection), (With<SceneCamera>, Without<PreviewCamera>)>,
+    mut previews: Query<(
+        Entity,
+        &PreviewCamera,
+        &mut Camera,
+        &mut Transform,
+        &mut Projection,
+        Option<&mut ColorGrading>,
+    )>,
+    mut commands: Commands,
+) {
+    let Ok((scene_transform, scene_projection)) = scene_camera.single() else {
+        return;
+    };
+
+    // The frame hugs the bottom right corner, so its physical rect follows
+    // from the window size alone
+    let scale = window.scale_factor() * ui_scale.0;
+    let half = (PREVIEW_HALF * scale).as_uvec2();
+    let inset = ((PREVIEW_MARGIN + PREVIEW_BORDER) * scale) as u32;
+    let size = window.physical_size();
+    let Some(left) = size.x.checked_sub(inset + half.x * 2) else {
+        return;
+    };
+    let Some(top) = size.y.checked_sub(inset + half.y) else {
+        return;
+    };
+
+    for (entity, side, mut camera, mut transform, mut projection, grading) in &mut previews {
+        let (x, fov_offset, postfx) = match side {
+            PreviewCamera::Before => (left, baseline.fov - settings.fov, baseline.postfx),
+            PreviewCamera::After => (left + half.x, 0.0, settings.postfx),
+        };
+        camera.viewport = Some(Viewport {
+            physical_position: UVec2::new(x, top),
+            physical_size: half,
+            ..default()
+        });
+        *transform = *scene_transform;
+        *projection = scene_projection.clone();
+        if let Projection::Perspective(perspective) = projection.as_mut() {
+            perspective.fov += fov_offset.to_radians();
+            perspective.aspect_ratio = PREVIEW_HALF.x / PREVIEW_HALF.y;
+        }
+        match grading {
+            Some(mut grading) => *grading = color_grading(postfx),
+            None => {
+                commands.entity(entity).insert(color_grading(postfx));
+            }
+        }
+    }
+}
+
+pub(super) fn preview_frame() -> impl Bundle {
+    let width = Px(PREVIEW_HALF.x * 2.0 + PREVIEW_BORDER * 2.0);
+    (
+        Name::new("Settings Preview"),
+        PreviewFrame,
+        Visibility::Hidden,
+        Node {
+            position_type: PositionType::Absolute,
+            right: Px(PREVIEW_MARGIN),
+            bottom: Px(PREVIEW_MARGIN),
+            flex_direction: FlexDirection::Column,
+            row_gap: Px(4.0),
+            ..default()
+        },
+        Pickable::IGNORE,
+        children![
+            (
+                Node {
+                    width,
+                    justify_content: JustifyContent::SpaceAround,
+                    ..default()
+                },
+                children![label("Before"), label("After")],
+            ),
+            (
+                // The cameras draw over the inside, leaving the border
+                Node {
+                    width,
+                    height: Px(PREVIEW_HALF.y + PREVIEW_BORDER * 2.0),
+                    border: UiRect::all(Px(PREVIEW_BORDER)),
+                    ..default()
+                },
+                BorderColor::all(colors::NEUTRAL600),
+            ),
+        ],
+    )
+}
+
+// ============================ REVERT COUNTDOWN ============================
+
+/// Remember the scale to go back to the first time it changes; later
+/// changes only restart the countdown.
+pub(super) fn start_ui_scale_revert(
+    previous: f32,
+    pending: Option<ResMut<UiScaleRevert>>,
+    commands: &mut Commands,
+) {
+    match pending {
+        Some(mut pending) => pending.timer.reset(),
+        None => commands.insert_resource(UiScaleRevert::new(previous)),
+    }
+}
+
+/// Counts in real time: the game is paused behind the settings modal.
+fn tick_ui_scale_revert(
+    time: Res<Time<Real>>,
+    mut pending: ResMut<UiScaleRevert>,
+    mut settings: ResMut<Settings>,
+    mut label: Query<&mut Text, With<RevertCountdownLabel>>,
+    mut commands: Commands,
+) {
+    pending.timer.tick(time.delta());
+    if pending.timer.is_finished() {
+        info!("UI scale not kept, reverting to {:.2}", pending.previous);
+        settings.ui_scale = pending.previous;
+        commands.remove_resource::<UiScaleRevert>();
+        return;
+    }
+    let secs = pending.timer.remaining_secs().ceil();
+    for mut text in &mut label {
+        text.0 = format!("Keep this UI scale? Reverting in {secs}s");
+    }
+}
+
+fn keep_ui_scale(_: On<Pointer<Click>>, mut commands: Commands) {
+    commands.remove_resource::<UiScaleRevert>();
+}
+
+fn revert_ui_scale(
+    _: On<Pointer<Click>>,
+    pending: Option<Res<UiScaleRevert>>,
+    mut settings: ResMut<Settings>,
+    mut commands: Commands,
+) {
+    if let Some(pending) = pending {
+        settings.ui_scale = pending.previous;
+    }
+    commands.remove_resource::<UiScaleRevert>();
+}
+
+fn spawn_revert_prompt(mut commands: Commands) {
+    commands.spawn((
+        RevertPrompt,
+        Name::new("UI Scale Revert Prompt"),
+        GlobalZIndex(300),
+        Node {
+            position_type: PositionType::Absolute,
+            width: Percent(100.0),
+            top: Vh(14.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Px(10.0),
+            ..default()
+        },
+        Pickable::IGNORE,
+        children![
+            (
+                RevertCountdownLabel,
+                label(format!("Keep this UI scale? Reverting in {REVERT_SECS}s")),
+            ),
+            (
+                Node {
+                    column_gap: Px(16.0),
+                    ..default()
+                },
+                children![btn("Keep", keep_ui_scale), btn("Revert", revert_ui_scale)],
+            ),
+        ],
+    ));
+}
+
+fn despawn_revert_prompt(prompts: Query<Entity, With<RevertPrompt>>, mut commands: Commands) {
+    for prompt in &prompts {
+        commands.entity(prompt).despawn();
+    }
+}
@@ -5,8 +5,10 @@ use spacetimedb_sdk::{DbContext, Table};
 
 use crate::models::{Screen, is_multiplayer_mode};
 use crate::networking::generated::player_table::PlayerTableAccess;
-use crate::networking::{PingTracker, STALE_THRESHOLD_SECS, SpacetimeDbConnection};
-use crate::ui::colors::NEUTRAL300;
+use crate::networking::{
+    ConnectionQuality, PingTracker, STALE_THRESHOLD_SECS, SpacetimeDbConnection,
+};
+use crate::ui::colors::{NEUTRAL300, NEUTRAL600};
 use crate::ui::hud::HudFont;
 
 // ── Components ──────────────────────────────────────────────────────
@@ -23,6 +25,10 @@ struct PlayersText;
 #[derive(Component)]
 struct PingText;
 
+/// Signal-bars icon, shown while the connection is degraded.
+#[derive(Component)]
+struct QualityIcon;
+
 // ── Plugin ──────────────────────────────────────────────────────────
 
 pub fn plugin(app: &mut App) {
@@ -32,7 +38,7 @@ pub fn plugin(app: &mut App) {
     )
     .add_systems(
         Update,
-        (tick_status, tick_players, tick_ping)
+        (tick_status, tick_players, tick_ping, tick_quality)
             .run_if(in_state(Screen::Gameplay).and(is_multiplayer_mode)),
     );
 }
@@ -103,13 +109,45 @@ fn spawn_status_hud(mut commands: Commands, font: Res<HudFont>) {
                 TextColor(NEUTRAL300),
             ));
 
-            // Row 3: ping
-            parent.spawn((
-                PingText,
-                Text::new("-- ms"),
-                text_style,
-                TextColor(NEUTRAL300),
-            ));
+            // Row 3: quality icon + ping
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(6.0),
+                    ..default()
+                })
+                .with_children(|row| {
+                    row.spawn((
+                        QualityIcon,
+                        Node {
+                            align_items: AlignItems::FlexEnd,
+                            column_gap: Val::Px(2.0),
+                            height: Val::Px(10.0),
+                            ..default()
+                        },
+                        Visibility::Hidden,
+                    ))
+                    .with_children(|icon| {
+                        // One bar of three lit
+                        for (i, height) in [4.0, 7.0, 10.0].into_iter().enumerate() {
+                            icon.spawn((
+                                Node {
+                                    width: Val::Px(3.0),
+                                    height: Val::Px(height),
+                                    ..default()
+                                },
+                                BackgroundColor(if i == 0 { YELLOW } else { NEUTRAL600 }),
+                            ));
+                        }
+                    });
+                    row.spawn((
+                        PingText,
+                        Text::new("-- ms"),
+                        text_style,
+                        TextColor(NEUTRAL300),
+                    ));
+                });
         });
 }
 
@@ -234,3 +272,18 @@ fn tick_ping(
         tc.0 = color;
     }
 }
+
+fn tick_quality(
+    quality: Option<Res<ConnectionQuality>>,
+    mut icons: Query<&mut Visibility, With<QualityIcon>>,
+) {
+    let degraded = quality.is_some_and(|q| q.is_degraded());
+    let visibility = if degraded {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    for mut icon in &mut icons {
+        icon.set_if_neq(visibility);
+    }
+}
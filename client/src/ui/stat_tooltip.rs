@@ -0,0 +1,151 @@
+//! Hover breakdowns for stats: put [`StatTooltip`] on any UI node and
+//! hovering it shows where the stat's value came from — base value and its
+//! preset, then each flat modifier and multiplier with the rule that applied it.
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use std::fmt::Write;
+
+use crate::models::{Player, Screen};
+use crate::rules::{ModifierKind, Stat, Stats};
+use crate::ui::colors::{NEUTRAL300, NEUTRAL700, NEUTRAL920};
+use crate::ui::hud::HudFont;
+
+/// Shows a breakdown of `stat` while this node is hovered.
+#[derive(Component, Clone)]
+pub struct StatTooltip {
+    pub stat: Stat,
+    /// Entity whose stats to explain. `None` follows the local player.
+    pub entity: Option<Entity>,
+}
+
+impl StatTooltip {
+    pub fn player(stat: Stat) -> Self {
+        Self { stat, entity: None }
+    }
+
+    pub fn of(entity: Entity, stat: Stat) -> Self {
+        Self {
+            stat,
+            entity: Some(entity),
+        }
+    }
+}
+
+/// The node currently under the pointer, if it has a [`StatTooltip`].
+#[derive(Resource, Default)]
+struct HoveredStat(Option<Entity>);
+
+#[derive(Component)]
+struct TooltipPanel;
+
+const CURSOR_OFFSET: Vec2 = Vec2::new(16.0, 16.0);
+
+pub fn plugin(app: &mut App) {
+    app.init_resource::<HoveredStat>()
+        .add_observer(on_over)
+        .add_observer(on_out)
+        .add_systems(OnEnter(Screen::Gameplay), spawn_panel)
+        .add_systems(Update, update_panel);
+}
+
+fn on_over(
+    over: On<Pointer<Over>>,
+    tooltips: Query<(), With<StatTooltip>>,
+    mut hovered: ResMut<HoveredStat>,
+) {
+    if tooltips.contains(over.event_target()) {
+        hovered.0 = Some(over.event_target());
+    }
+}
+
+fn on_out(out: On<Pointer<Out>>, mut hovered: ResMut<HoveredStat>) {
+    if hovered.0 == Some(out.event_target()) {
+        hovered.0 = None;
+    }
+}
+
+fn spawn_panel(mut commands: Commands, font: Res<HudFont>) {
+    commands.spawn((
+        TooltipPanel,
+        Text::default(),
+        TextFont {
+            font: font.0.clone(),
+            font_size: 13.0,
+            ..default()
+        },
+        TextColor(NEUTRAL300),
+        Node {
+            position_type: PositionType::Absolute,
+            padding: UiRect::axes(Val::Px(10.0), Val::Px(8.0)),
+            border: UiRect::all(Val::Px(1.0)),
+            ..default()
+        },
+        BackgroundColor(NEUTRAL920.with_alpha(0.95)),
+        BorderColor::all(NEUTRAL700),
+        GlobalZIndex(150),
+        Pickable::IGNORE,
+        Visibility::Hidden,
+    ));
+}
+
+fn update_panel(
+    hovered: Res<HoveredStat>,
+    tooltips: Query<&StatTooltip>,
+    stats: Query<&Stats>,
+    player: Query<Entity, With<Player>>,
+    window: Query<&Window, With<PrimaryWindow>>,
+    mut panel: Query<(&mut Text, &mut Node, &mut Visibility), With<TooltipPanel>>,
+) {
+    let Ok((mut text, mut node, mut visibility)) = panel.single_mut() else {
+        return;
+    };
+
+    // The hovered node may have been despawned (the F4 panel rebuilds its rows)
+    let breakdown = hovered
+        .0
+        .and_then(|node| tooltips.get(node).ok())
+        .and_then(|tooltip| {
+            let entity = tooltip.entity.or_else(|| player.single().ok())?;
+            Some(describe(&tooltip.stat, stats.get(entity).ok()?))
+        });
+    let cursor = window.single().ok().and_then(Window::cursor_position);
+
+    let (Some(breakdown), Some(cursor)) = (breakdown, cursor) else {
+        *visibility = Visibility::Hidden;
+        return;
+    };
+
+    if text.0 != breakdown {
+        text.0 = breakdown;
+    }
+    node.left = Val::Px(cursor.x + CURSOR_OFFSET.x);
+    node.top = Val::Px(cursor.y + CURSOR_OFFSET.y);
+    *visibility = Visibility::Visible;
+}
+
+/// Tooltip body: current value, then base and modifiers in the order they
+/// were first applied.
+fn describe(stat: &Stat, stats: &Stats) -> String {
+    let breakdown = stats.breakdown(stat);
+    let mut out = format!("{}  {:.2}\n", stat_label(stat), stats.get(stat));
+
+    let _ = write!(out, "base  {:.2}", breakdown.base);
+    if let Some(source) = &breakdown.base_source {
+        let _ = write!(out, "  ({source})");
+    }
+    for modifier in &breakdown.modifiers {
+        let _ = match modifier.kind {
+            ModifierKind::Flat(v) => write!(out, "\n{v:+.2}  {}", modifier.source),
+            ModifierKind::Multiplier(v) => write!(out, "\nx{v:.2}  {}", modifier.source),
+        };
+    }
+    out
+}
+
+fn stat_label(stat: &Stat) -> String {
+    match stat {
+        Stat::Custom(name) => name.clone(),
+        other => format!("{other:?}"),
+    }
+}
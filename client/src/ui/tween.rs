@@ -0,0 +1,61 @@
+//! Small tweening helpers for HUD widgets that follow server-synced values,
+//! so bars and numbers ease toward a new value instead of snapping to it.
+
+/// How fast a bar's fill falls to a lower value.
+const DROP_RATE: f32 = 18.0;
+/// How fast a bar's fill rises to a higher value.
+const RISE_RATE: f32 = 6.0;
+/// How long the trail holds after a drop before catching up.
+const TRAIL_HOLD_SECS: f32 = 0.45;
+/// How fast the trail drains down to the fill once it lets go.
+const TRAIL_RATE: f32 = 3.0;
+
+/// Frame-rate independent exponential approach: each second the gap to
+/// `target` shrinks by a factor of e^`rate`. Snaps once the gap is negligible
+/// so callers can tell when a tween has settled.
+pub fn approach(current: f32, target: f32, rate: f32, dt: f32) -> f32 {
+    let next = current + (target - current) * (1.0 - (-rate * dt).exp());
+    if (target - next).abs() <= 1e-3 * target.abs().max(1.0) {
+        target
+    } else {
+        next
+    }
+}
+
+/// Fill state for a bar with a trailing segment: the fill drops fast when
+/// the value falls, while the trail holds at the old value for a moment and
+/// then drains slowly, showing how much was just lost. Both are fractions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrailingFill {
+    pub fill: f32,
+    pub trail: f32,
+    hold: f32,
+}
+
+impl TrailingFill {
+    pub fn new(value: f32) -> Self {
+        Self {
+            fill: value,
+            trail: value,
+            hold: 0.0,
+        }
+    }
+
+    /// Advance toward `target` by `dt` seconds.
+    pub fn tick(&mut self, target: f32, dt: f32) {
+        if target < self.fill {
+            // Each new drop restarts the hold, so a combo reads as one chunk
+            self.hold = TRAIL_HOLD_SECS;
+            self.fill = approach(self.fill, target, DROP_RATE, dt);
+        } else {
+            self.fill = approach(self.fill, target, RISE_RATE, dt);
+        }
+
+        if self.hold > 0.0 {
+            self.hold -= dt;
+        } else {
+            self.trail = approach(self.trail, self.fill, TRAIL_RATE, dt);
+        }
+        self.trail = self.trail.max(self.fill);
+    }
+}
@@ -0,0 +1,21 @@
+//! Smoke runs: boot the game with a hidden window, replay a script from
+//! `tests/smoke/` and fail if the game panics, times out or misses an
+//! expectation. Needs a GPU — run with `just smoke`.
+
+#![cfg(feature = "smoke_test")]
+
+use std::process::Command;
+
+fn run_script(name: &str) {
+    let status = Command::new(env!("CARGO_BIN_EXE_wasm_fantasia"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["--smoke", &format!("tests/smoke/{name}")])
+        .status()
+        .expect("failed to launch the game");
+    assert!(status.success(), "smoke script {name} failed: {status}");
+}
+
+#[test]
+fn basic_offline_session() {
+    run_script("basic.ron");
+}
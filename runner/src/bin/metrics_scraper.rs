@@ -0,0 +1,169 @@
+//! Prometheus exporter for the game module: `metrics-scraper [options]`.
+//!
+//! Each scrape of `/metrics` reads the module's private `metrics` table
+//! through `spacetime sql` and answers with it in Prometheus text format.
+//! The CLI must be logged in as the database owner to read it, which is the
+//! case on the machine that published the module.
+
+use std::collections::BTreeMap;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::Path;
+use std::time::Duration;
+
+const USAGE: &str = "\
+Usage: metrics-scraper [options]
+
+Options:
+  --server <url>          SpacetimeDB HTTP address [default: http://127.0.0.1:3000]
+  --listen <addr>         Address to serve /metrics on [default: 0.0.0.0:9464]
+  -h, --help              Show this help";
+
+/// Prefix on every exported metric name.
+const NAMESPACE: &str = "wasm_fantasia";
+const QUERY: &str = "SELECT name, reducer, kind, value FROM metrics";
+
+struct Args {
+    server: String,
+    listen: String,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args {
+        server: "http://127.0.0.1:3000".into(),
+        listen: "0.0.0.0:9464".into(),
+    };
+
+    let mut iter = std::env::args().skip(1);
+    while let Some(flag) = iter.next() {
+        let mut value = || iter.next().ok_or_else(|| format!("{flag} expects a value"));
+        match flag.as_str() {
+            "--server" => args.server = value()?,
+            "--listen" => args.listen = value()?,
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                std::process::exit(0);
+            }
+            other => return Err(format!("Unknown option {other}\n\n{USAGE}")),
+        }
+    }
+    Ok(args)
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(2);
+        }
+    };
+    if let Err(e) = run(args) {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    }
+}
+
+fn run(args: Args) -> Result<(), String> {
+    let binary = wasm_fantasia_runner::find_spacetime_binary()
+        .ok_or("SpacetimeDB CLI not found (set SPACETIMEDB_PATH or install it)")?;
+    let listener = TcpListener::bind(&args.listen)
+        .map_err(|e| format!("Failed to bind {}: {e}", args.listen))?;
+    println!(
+        "Serving metrics for {} on http://{}/metrics",
+        args.server, args.listen
+    );
+
+    for stream in listener.incoming().flatten() {
+        if let Err(e) = respond(stream, &binary, &args.server) {
+            eprintln!("Scrape failed: {e}");
+        }
+    }
+    Ok(())
+}
+
+fn respond(mut stream: TcpStream, binary: &Path, server: &str) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(Duration::from_secs(2)))?;
+    let mut request_line = String::new();
+    BufReader::new(&stream).read_line(&mut request_line)?;
+    let path = request_line.split_whitespace().nth(1).unwrap_or("/");
+
+    let (code, body) = match path {
+        "/metrics" => match scrape(binary, server) {
+            Ok(body) => ("200 OK", body),
+            Err(e) => ("503 Service Unavailable", format!("{e}\n")),
+        },
+        _ => ("404 Not Found", "not found".to_string()),
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {code}\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
+
+/// One row of the `metrics` table.
+struct Sample {
+    reducer: String,
+    kind: String,
+    value: f64,
+}
+
+fn scrape(binary: &Path, server: &str) -> Result<String, String> {
+    let table = wasm_fantasia_runner::query_sql(binary, server, QUERY)?;
+
+    // Grouped by name, so each metric gets one TYPE line
+    let mut by_name: BTreeMap<String, Vec<Sample>> = BTreeMap::new();
+    for row in parse_rows(&table) {
+        let [name, reducer, kind, value] = row.as_slice() else {
+            continue;
+        };
+        let Ok(value) = value.parse() else {
+            continue;
+        };
+        by_name.entry(name.clone()).or_default().push(Sample {
+            reducer: reducer.clone(),
+            kind: kind.clone(),
+            value,
+        });
+    }
+
+    let mut out = String::new();
+    for (name, samples) in &by_name {
+        let kind = samples.first().map_or("untyped", |s| s.kind.as_str());
+        out.push_str(&format!("# TYPE {NAMESPACE}_{name} {kind}\n"));
+        for sample in samples {
+            if sample.reducer.is_empty() {
+                out.push_str(&format!("{NAMESPACE}_{name} {}\n", sample.value));
+            } else {
+                out.push_str(&format!(
+                    "{NAMESPACE}_{name}{{reducer=\"{}\"}} {}\n",
+                    sample.reducer, sample.value
+                ));
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// Cells of each data row in the CLI's table output: a header line, a
+/// `----+----` rule, then one line per row with cells split by `|`. String
+/// cells come quoted.
+fn parse_rows(table: &str) -> Vec<Vec<String>> {
+    table
+        .lines()
+        .skip_while(|line| !is_rule(line))
+        .skip(1)
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            line.split('|')
+                .map(|cell| cell.trim().trim_matches('"').to_string())
+                .collect()
+        })
+        .collect()
+}
+
+fn is_rule(line: &str) -> bool {
+    let line = line.trim();
+    !line.is_empty() && line.chars().all(|c| c == '-' || c == '+')
+}
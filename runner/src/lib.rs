@@ -0,0 +1,486 @@
+//! SpacetimeDB process management shared by native singleplayer and the
+//! `server-runner` binary: find (or install) the `spacetime` CLI, start a
+//! server, publish the game module and call reducers on it.
+//!
+//! Nothing here blocks except [`Publish::wait`] and [`SpacetimeServer::call`],
+//! so the client can drive the same steps from a per-frame state machine.
+
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader, Read};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// Database name the module is published under.
+pub const DATABASE_NAME: &str = "wasm-fantasia";
+/// File name of the compiled server module in release bundles.
+pub const MODULE_WASM: &str = "wasm_fantasia_module.wasm";
+const INSTALL_SCRIPT_URL: &str = "https://install.spacetimedb.com";
+/// Captured output lines kept per server.
+const MAX_LOG_LINES: usize = 500;
+/// Stderr lines quoted when the server exits.
+const EXIT_DETAIL_LINES: usize = 20;
+
+// =============================================================================
+// Binary discovery
+// =============================================================================
+
+/// Find the `spacetime` CLI binary.
+///
+/// Search order:
+/// 1. Adjacent to the running executable (bundled distribution)
+/// 2. `SPACETIMEDB_PATH` environment variable
+/// 3. `~/.local/bin/spacetime` (default install location)
+/// 4. System PATH via `which`
+pub fn find_spacetime_binary() -> Option<PathBuf> {
+    if let Some(adjacent) = next_to_exe("spacetime") {
+        return Some(adjacent);
+    }
+
+    if let Ok(path) = std::env::var("SPACETIMEDB_PATH") {
+        let p = PathBuf::from(path);
+        if p.exists() {
+            return Some(p);
+        }
+    }
+
+    if let Some(default_path) = default_install_path() {
+        if default_path.exists() {
+            return Some(default_path);
+        }
+    }
+
+    if let Ok(output) = Command::new("which").arg("spacetime").output() {
+        if output.status.success() {
+            let path = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            if !path.is_empty() {
+                return Some(PathBuf::from(path));
+            }
+        }
+    }
+
+    None
+}
+
+/// Run the official install script, then look the binary up again.
+pub fn install_spacetime() -> Result<PathBuf, String> {
+    let status = Command::new("sh")
+        .args([
+            "-c",
+            &format!("curl -sSf {INSTALL_SCRIPT_URL} | sh -s -- --yes"),
+        ])
+        .status()
+        .map_err(|e| format!("Failed to run the SpacetimeDB installer: {e}"))?;
+    if !status.success() {
+        return Err(format!("SpacetimeDB installer failed ({status})"));
+    }
+    find_spacetime_binary()
+        .ok_or_else(|| "SpacetimeDB installed, but the `spacetime` binary wasn't found".into())
+}
+
+fn default_install_path() -> Option<PathBuf> {
+    home::home_dir().map(|home| home.join(".local/bin/spacetime"))
+}
+
+fn next_to_exe(file_name: &str) -> Option<PathBuf> {
+    let exe = std::env::current_exe().ok()?;
+    let path = exe.parent()?.join(file_name);
+    path.exists().then_some(path)
+}
+
+/// Run `query` against the game database on the server at `server_url` (an
+/// HTTP address, as [`SpacetimeServer::http_url`] gives) as the CLI's
+/// logged-in identity, and return the CLI's table output.
+pub fn query_sql(binary: &Path, server_url: &str, query: &str) -> Result<String, String> {
+    let output = Command::new(binary)
+        .args(["sql", "-s", server_url, DATABASE_NAME, query])
+        .output()
+        .map_err(|e| format!("Failed to run spacetime sql: {e}"))?;
+    let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+    if output.status.success() {
+        Ok(stdout)
+    } else {
+        Err(format!(
+            "Query failed:\nstdout: {stdout}\nstderr: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ))
+    }
+}
+
+/// Pick a random available port by binding to :0 and reading the assigned port.
+pub fn pick_available_port() -> Option<u16> {
+    TcpListener::bind("127.0.0.1:0")
+        .ok()
+        .and_then(|listener| listener.local_addr().ok())
+        .map(|addr| addr.port())
+}
+
+// =============================================================================
+// Server process
+// =============================================================================
+
+/// Where the server keeps its data.
+#[derive(Clone, Debug)]
+pub enum Storage {
+    /// In-memory database with a throwaway data directory (for the pid
+    /// file), removed on shutdown.
+    Temporary,
+    /// Persistent data directory that survives restarts.
+    Dir(PathBuf),
+}
+
+/// What happens to the server's stdout and stderr.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Output {
+    /// Forward to our own stdout/stderr.
+    Inherit,
+    /// Keep the most recent lines in a [`ServerLog`].
+    Capture,
+}
+
+#[derive(Clone, Debug)]
+pub struct StartOptions {
+    pub listen_addr: String,
+    pub storage: Storage,
+    pub output: Output,
+}
+
+/// A running `spacetime start` subprocess. Killed on drop.
+pub struct SpacetimeServer {
+    process: Option<Child>,
+    binary: PathBuf,
+    options: StartOptions,
+    temp_dir: Option<PathBuf>,
+    log: ServerLog,
+    readers: Vec<JoinHandle<()>>,
+}
+
+impl SpacetimeServer {
+    pub fn start(binary: PathBuf, options: StartOptions) -> Result<Self, String> {
+        let mut server = Self {
+            process: None,
+            binary,
+            options,
+            temp_dir: None,
+            log: ServerLog::default(),
+            readers: Vec::new(),
+        };
+        server.spawn()?;
+        Ok(server)
+    }
+
+    /// Stop the process if it's still running and start it again with the
+    /// same options. The captured log carries over.
+    pub fn restart(&mut self) -> Result<(), String> {
+        self.shutdown();
+        self.log.push(Stream::Stderr, "--- restarting ---".into());
+        self.spawn()
+    }
+
+    fn spawn(&mut self) -> Result<(), String> {
+        let (data_dir, in_memory) = match &self.options.storage {
+            Storage::Temporary => {
+                // Unique per address so the pid file doesn't conflict with
+                // any other running SpacetimeDB instance
+                let slug = self.options.listen_addr.replace([':', '.'], "-");
+                (
+                    std::env::temp_dir().join(format!("spacetimedb-wf-{slug}")),
+                    true,
+                )
+            }
+            Storage::Dir(dir) => (dir.clone(), false),
+        };
+        std::fs::create_dir_all(&data_dir)
+            .map_err(|e| format!("Failed to create {}: {e}", data_dir.display()))?;
+
+        let mut cmd = Command::new(&self.binary);
+        cmd.args(["start", "--listen-addr", &self.options.listen_addr]);
+        if in_memory {
+            cmd.arg("--in-memory");
+        }
+        cmd.arg("--data-dir").arg(&data_dir);
+        if self.options.output == Output::Capture {
+            cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
+        }
+
+        let mut child = cmd
+            .spawn()
+            .map_err(|e| format!("Failed to start SpacetimeDB: {e}"))?;
+
+        // Drain both pipes continuously — a full pipe would stall the server
+        if let Some(out) = child.stdout.take() {
+            self.readers.push(self.log.capture(Stream::Stdout, out));
+        }
+        if let Some(err) = child.stderr.take() {
+            self.readers.push(self.log.capture(Stream::Stderr, err));
+        }
+
+        self.process = Some(child);
+        self.temp_dir = in_memory.then_some(data_dir);
+        Ok(())
+    }
+
+    pub fn listen_addr(&self) -> &str {
+        &self.options.listen_addr
+    }
+
+    pub fn pid(&self) -> Option<u32> {
+        self.process.as_ref().map(Child::id)
+    }
+
+    /// Captured output. Stays empty with [`Output::Inherit`].
+    pub fn log(&self) -> &ServerLog {
+        &self.log
+    }
+
+    /// Whether the server accepts connections yet.
+    pub fn is_listening(&self) -> bool {
+        self.probe_addr().is_some_and(|addr| {
+            TcpStream::connect_timeout(&addr, Duration::from_millis(100)).is_ok()
+        })
+    }
+
+    /// Exit detail if the process has stopped, ending with the last captured
+    /// stderr lines.
+    pub fn exit_status(&mut self) -> Option<String> {
+        let status = self.process.as_mut()?.try_wait().ok()??;
+        self.join_readers();
+
+        let stderr = self.log.tail_of(Stream::Stderr, EXIT_DETAIL_LINES);
+        Some(if stderr.is_empty() {
+            format!("exit status: {status}")
+        } else {
+            format!("exit status: {status}\n{}", stderr.join("\n"))
+        })
+    }
+
+    /// HTTP address of the server, as the CLI's `-s` flag expects it.
+    pub fn http_url(&self) -> String {
+        format!("http://{}", self.local_authority())
+    }
+
+    /// WebSocket URI a client on this machine connects to.
+    pub fn ws_url(&self) -> String {
+        format!("ws://{}", self.local_authority())
+    }
+
+    /// Start publishing `module`. `delete_data` wipes the existing database
+    /// first, which also reruns the module's `init` reducer.
+    pub fn publish(&self, module: &Module, delete_data: bool) -> Result<Publish, String> {
+        let mut cmd = Command::new(&self.binary);
+        cmd.args(["publish", DATABASE_NAME, "--yes", "-s", &self.http_url()]);
+        if delete_data {
+            cmd.arg("--delete-data");
+        }
+        match module {
+            Module::Wasm(path) => cmd.arg("--bin-path").arg(path),
+            Module::Project(path) => cmd.arg("--project-path").arg(path),
+        };
+
+        cmd.stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map(Publish)
+            .map_err(|e| format!("Failed to run spacetime publish: {e}"))
+    }
+
+    /// Call `reducer` with JSON-encoded `args` and wait for it to finish.
+    pub fn call(&self, reducer: &str, args: &[&str]) -> Result<String, String> {
+        let output = Command::new(&self.binary)
+            .args(["call", "-s", &self.http_url(), DATABASE_NAME, reducer])
+            .args(args)
+            .output()
+            .map_err(|e| format!("Failed to run spacetime call: {e}"))?;
+        let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+        if output.status.success() {
+            Ok(stdout)
+        } else {
+            Err(format!(
+                "{reducer} failed:\nstdout: {stdout}\nstderr: {}",
+                String::from_utf8_lossy(&output.stderr)
+            ))
+        }
+    }
+
+    /// Kill the process and remove the temporary data directory.
+    pub fn shutdown(&mut self) -> Option<std::process::ExitStatus> {
+        let status = self.process.take().and_then(|mut child| {
+            let _ = child.kill();
+            child.wait().ok()
+        });
+        self.join_readers();
+        if let Some(dir) = self.temp_dir.take() {
+            let _ = std::fs::remove_dir_all(dir);
+        }
+        status
+    }
+
+    /// The pipes close with the process, so this only waits for the last
+    /// buffered lines.
+    fn join_readers(&mut self) {
+        for reader in self.readers.drain(..) {
+            let _ = reader.join();
+        }
+    }
+
+    /// `listen_addr` with unspecified hosts swapped for loopback, so it can
+    /// be dialed from this machine.
+    fn local_authority(&self) -> String {
+        match self.options.listen_addr.rsplit_once(':') {
+            Some(("0.0.0.0" | "[::]", port)) => format!("127.0.0.1:{port}"),
+            _ => self.options.listen_addr.clone(),
+        }
+    }
+
+    fn probe_addr(&self) -> Option<SocketAddr> {
+        self.local_authority().to_socket_addrs().ok()?.next()
+    }
+}
+
+impl Drop for SpacetimeServer {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+// =============================================================================
+// Log capture
+// =============================================================================
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stream {
+    Stdout,
+    Stderr,
+}
+
+#[derive(Clone, Debug)]
+pub struct LogLine {
+    pub stream: Stream,
+    pub text: String,
+}
+
+/// Ring buffer of the server's most recent output lines, filled from
+/// background reader threads. Cheap to clone; clones share the buffer.
+#[derive(Clone, Default)]
+pub struct ServerLog {
+    lines: Arc<Mutex<VecDeque<LogLine>>>,
+}
+
+impl ServerLog {
+    /// Up to `n` of the most recent lines, oldest first.
+    pub fn tail(&self, n: usize) -> Vec<LogLine> {
+        let lines = self.lines.lock().unwrap_or_else(|e| e.into_inner());
+        lines
+            .iter()
+            .skip(lines.len().saturating_sub(n))
+            .cloned()
+            .collect()
+    }
+
+    fn tail_of(&self, stream: Stream, n: usize) -> Vec<String> {
+        let lines = self.lines.lock().unwrap_or_else(|e| e.into_inner());
+        let mut texts: Vec<String> = lines
+            .iter()
+            .rev()
+            .filter(|line| line.stream == stream)
+            .take(n)
+            .map(|line| line.text.clone())
+            .collect();
+        texts.reverse();
+        texts
+    }
+
+    fn push(&self, stream: Stream, text: String) {
+        let mut lines = self.lines.lock().unwrap_or_else(|e| e.into_inner());
+        if lines.len() >= MAX_LOG_LINES {
+            lines.pop_front();
+        }
+        lines.push_back(LogLine { stream, text });
+    }
+
+    fn capture(&self, stream: Stream, pipe: impl Read + Send + 'static) -> JoinHandle<()> {
+        let log = self.clone();
+        std::thread::spawn(move || {
+            for line in BufReader::new(pipe).lines() {
+                let Ok(line) = line else {
+                    break;
+                };
+                log.push(stream, line);
+            }
+        })
+    }
+}
+
+// =============================================================================
+// Module publishing
+// =============================================================================
+
+/// The server module to publish.
+#[derive(Clone, Debug)]
+pub enum Module {
+    /// Pre-compiled WASM (release bundles).
+    Wasm(PathBuf),
+    /// Cargo project built by the CLI (dev workflow).
+    Project(PathBuf),
+}
+
+impl Module {
+    /// The bundled WASM next to the executable if there is one, otherwise
+    /// the `server` project in the working directory.
+    pub fn locate() -> Self {
+        next_to_exe(MODULE_WASM)
+            .map(Self::Wasm)
+            .unwrap_or_else(|| Self::Project(PathBuf::from("server")))
+    }
+}
+
+/// A running `spacetime publish`. Killed on drop so cancelled deploys don't
+/// leave orphaned processes.
+pub struct Publish(Child);
+
+impl Publish {
+    /// `None` while still running, then the CLI's stdout or an error with
+    /// both streams.
+    pub fn try_finish(&mut self) -> Option<Result<String, String>> {
+        match self.0.try_wait() {
+            Ok(Some(status)) => Some(self.collect(status.success())),
+            Ok(None) => None,
+            Err(e) => Some(Err(format!("Error polling deploy process: {e}"))),
+        }
+    }
+
+    pub fn wait(mut self) -> Result<String, String> {
+        match self.0.wait() {
+            Ok(status) => self.collect(status.success()),
+            Err(e) => Err(format!("Error waiting for deploy process: {e}")),
+        }
+    }
+
+    fn collect(&mut self, success: bool) -> Result<String, String> {
+        let mut stdout = String::new();
+        let mut stderr = String::new();
+        if let Some(out) = self.0.stdout.as_mut() {
+            let _ = out.read_to_string(&mut stdout);
+        }
+        if let Some(err) = self.0.stderr.as_mut() {
+            let _ = err.read_to_string(&mut stderr);
+        }
+        if success {
+            Ok(stdout)
+        } else {
+            Err(format!(
+                "Module deploy failed:\nstdout: {stdout}\nstderr: {stderr}"
+            ))
+        }
+    }
+}
+
+impl Drop for Publish {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
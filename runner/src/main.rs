@@ -0,0 +1,238 @@
+//! Dedicated server runner: `server-runner [options]`.
+//!
+//! Installs SpacetimeDB if it's missing, starts it, publishes the game module,
+//! applies an optional seed file and then serves health checks until the
+//! database process exits.
+//!
+//! Seed files list one reducer call per line — the reducer name followed by
+//! its JSON arguments, separated by whitespace. Blank lines and lines starting
+//! with `#` are skipped.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use wasm_fantasia_runner::{Module, Output, SpacetimeServer, StartOptions, Storage};
+
+const USAGE: &str = "\
+Usage: server-runner [options]
+
+Options:
+  --listen <addr>         SpacetimeDB listen address [default: 0.0.0.0:3000]
+  --data-dir <dir>        Persistent data directory [default: spacetimedb-data]
+  --in-memory             Keep the database in memory (wiped on exit)
+  --module <path>         Module .wasm or server project directory
+                          [default: bundled wasm, else ./server]
+  --reset                 Delete existing data when publishing
+  --seed <file>           Reducer calls to run after publishing
+  --health <addr>         Health endpoint address [default: 0.0.0.0:3080]
+  --no-install            Fail instead of installing SpacetimeDB when missing
+  -h, --help              Show this help";
+
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(30);
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+struct Args {
+    listen: String,
+    storage: Storage,
+    module: Module,
+    reset: bool,
+    seed: Option<PathBuf>,
+    health: String,
+    install: bool,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args {
+        listen: "0.0.0.0:3000".into(),
+        storage: Storage::Dir(PathBuf::from("spacetimedb-data")),
+        module: Module::locate(),
+        reset: false,
+        seed: None,
+        health: "0.0.0.0:3080".into(),
+        install: true,
+    };
+
+    let mut iter = std::env::args().skip(1);
+    while let Some(flag) = iter.next() {
+        let mut value = || iter.next().ok_or_else(|| format!("{flag} expects a value"));
+        match flag.as_str() {
+            "--listen" => args.listen = value()?,
+            "--data-dir" => args.storage = Storage::Dir(value()?.into()),
+            "--in-memory" => args.storage = Storage::Temporary,
+            "--module" => {
+                let path = PathBuf::from(value()?);
+                args.module = if path.extension().is_some_and(|ext| ext == "wasm") {
+                    Module::Wasm(path)
+                } else {
+                    Module::Project(path)
+                };
+            }
+            "--reset" => args.reset = true,
+            "--seed" => args.seed = Some(value()?.into()),
+            "--health" => args.health = value()?,
+            "--no-install" => args.install = false,
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                std::process::exit(0);
+            }
+            other => return Err(format!("Unknown option {other}\n\n{USAGE}")),
+        }
+    }
+    Ok(args)
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(2);
+        }
+    };
+    if let Err(e) = run(args) {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    }
+}
+
+fn run(args: Args) -> Result<(), String> {
+    let binary = match wasm_fantasia_runner::find_spacetime_binary() {
+        Some(binary) => binary,
+        None if args.install => {
+            println!("SpacetimeDB not found, installing...");
+            wasm_fantasia_runner::install_spacetime()?
+        }
+        None => return Err("SpacetimeDB CLI not found (and --no-install was given)".into()),
+    };
+
+    // Health goes up first so orchestrators can poll it during boot
+    let status = HealthStatus::default();
+    serve_health(&args.health, status.clone())?;
+    println!(
+        "Health endpoints on http://{}/healthz and /readyz",
+        args.health
+    );
+
+    println!("Starting SpacetimeDB on {}", args.listen);
+    let mut server = SpacetimeServer::start(
+        binary,
+        StartOptions {
+            listen_addr: args.listen,
+            storage: args.storage,
+            output: Output::Inherit,
+        },
+    )?;
+    wait_until_listening(&mut server)?;
+    status.alive.store(true, Ordering::Relaxed);
+
+    println!("Publishing module ({:?})", args.module);
+    let output = server.publish(&args.module, args.reset)?.wait()?;
+    print!("{output}");
+
+    if let Some(seed) = &args.seed {
+        apply_seed(&server, seed)?;
+    }
+
+    status.ready.store(true, Ordering::Relaxed);
+    println!("Ready — clients connect to {}", server.ws_url());
+
+    loop {
+        std::thread::sleep(POLL_INTERVAL);
+        if let Some(detail) = server.exit_status() {
+            status.alive.store(false, Ordering::Relaxed);
+            status.ready.store(false, Ordering::Relaxed);
+            return Err(format!("SpacetimeDB exited: {detail}"));
+        }
+    }
+}
+
+fn wait_until_listening(server: &mut SpacetimeServer) -> Result<(), String> {
+    let started = Instant::now();
+    while !server.is_listening() {
+        if let Some(detail) = server.exit_status() {
+            return Err(format!("SpacetimeDB exited prematurely: {detail}"));
+        }
+        if started.elapsed() > STARTUP_TIMEOUT {
+            return Err(format!(
+                "SpacetimeDB didn't start listening on {} within {}s",
+                server.listen_addr(),
+                STARTUP_TIMEOUT.as_secs()
+            ));
+        }
+        std::thread::sleep(POLL_INTERVAL);
+    }
+    Ok(())
+}
+
+fn apply_seed(server: &SpacetimeServer, path: &Path) -> Result<(), String> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read seed file {}: {e}", path.display()))?;
+
+    for (number, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut parts = line.split_whitespace();
+        let Some(reducer) = parts.next() else {
+            continue;
+        };
+        let call_args: Vec<&str> = parts.collect();
+        server
+            .call(reducer, &call_args)
+            .map_err(|e| format!("{}:{}: {e}", path.display(), number + 1))?;
+        println!("Seeded: {line}");
+    }
+    Ok(())
+}
+
+// ── Health endpoints ────────────────────────────────────────────────
+
+#[derive(Clone, Default)]
+struct HealthStatus {
+    /// SpacetimeDB is up and accepting connections.
+    alive: Arc<AtomicBool>,
+    /// The module is published and seeded.
+    ready: Arc<AtomicBool>,
+}
+
+fn serve_health(addr: &str, status: HealthStatus) -> Result<(), String> {
+    let listener = TcpListener::bind(addr)
+        .map_err(|e| format!("Failed to bind health endpoint on {addr}: {e}"))?;
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let _ = respond(stream, &status);
+        }
+    });
+    Ok(())
+}
+
+fn respond(mut stream: TcpStream, status: &HealthStatus) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(Duration::from_secs(2)))?;
+    let mut request_line = String::new();
+    BufReader::new(&stream).read_line(&mut request_line)?;
+    let path = request_line.split_whitespace().nth(1).unwrap_or("/");
+
+    let (code, body) = match path {
+        "/healthz" => ok_or_unavailable(status.alive.load(Ordering::Relaxed)),
+        "/readyz" => ok_or_unavailable(status.ready.load(Ordering::Relaxed)),
+        _ => ("404 Not Found", "not found"),
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {code}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
+
+fn ok_or_unavailable(ok: bool) -> (&'static str, &'static str) {
+    if ok {
+        ("200 OK", "ok")
+    } else {
+        ("503 Service Unavailable", "unavailable")
+    }
+}
@@ -0,0 +1,247 @@
+use spacetimedb::Table;
+use wasm_fantasia_shared::abilities::{self, AbilityDef, AbilityInput, AbilityShape};
+use wasm_fantasia_shared::combat::{self, defaults, HitTarget};
+use wasm_fantasia_shared::effects::EffectKind;
+use wasm_fantasia_shared::resources;
+use wasm_fantasia_shared::rules::{Stat, Stats};
+
+use crate::combat::apply_area_hits;
+use crate::config::Tuning;
+use crate::effects::impairment;
+use crate::loadouts::player_rules;
+use crate::metrics::count_call;
+use crate::rewind::rewound_position;
+use crate::schema::*;
+
+fn to_row(name: &str, def: &AbilityDef) -> Ability {
+    Ability {
+        name: name.to_string(),
+        shape: def.shape.as_str().to_string(),
+        range: def.range,
+        arc: def.arc,
+        width: def.width,
+        damage_multiplier: def.damage_multiplier,
+        knockback: def.knockback,
+        launch: def.launch,
+        cooldown: def.cooldown_secs,
+        stack_cost: def.stack_cost,
+        mana_cost: def.mana_cost,
+    }
+}
+
+fn to_def(row: &Ability) -> Option<AbilityDef> {
+    Some(AbilityDef {
+        shape: AbilityShape::parse_str(&row.shape)?,
+        range: row.range,
+        arc: row.arc,
+        width: row.width,
+        damage_multiplier: row.damage_multiplier,
+        knockback: row.knockback,
+        launch: row.launch,
+        cooldown_secs: row.cooldown,
+        stack_cost: row.stack_cost,
+        mana_cost: row.mana_cost,
+    })
+}
+
+/// The sender's active effect of `kind` named `name`, if any.
+fn find_effect(
+    ctx: &spacetimedb::ReducerContext,
+    kind: EffectKind,
+    name: &str,
+) -> Option<ActiveEffect> {
+    ctx.db
+        .active_effect()
+        .owner()
+        .filter(ctx.sender)
+        .find(|e| e.kind == kind.as_u8() && e.name == name)
+}
+
+/// Mana the sender's `row` has refilled to by `now`; full without one.
+fn current_mana(row: Option<&ActiveEffect>, now: i64) -> f32 {
+    row.map_or(resources::MAX_MANA, |row| {
+        let elapsed = (now - row.timestamp) as f32 / 1_000_000.0;
+        resources::mana_at(row.magnitude, resources::MAX_MANA, elapsed)
+    })
+}
+
+/// Leave the sender with `remaining` mana as of `now`, refilling from there.
+fn spend_mana(
+    ctx: &spacetimedb::ReducerContext,
+    row: Option<ActiveEffect>,
+    remaining: f32,
+    now: i64,
+) {
+    match row {
+        Some(effect) => {
+            ctx.db.active_effect().id().update(ActiveEffect {
+                magnitude: remaining,
+                timestamp: now,
+                ..effect
+            });
+        }
+        None => {
+            ctx.db.active_effect().insert(ActiveEffect {
+                id: 0,
+                owner: ctx.sender,
+                kind: EffectKind::Mana.as_u8(),
+                name: String::new(),
+                magnitude: remaining,
+                duration: -1.0,
+                timestamp: now,
+            });
+        }
+    }
+}
+
+/// Insert the built-in abilities unless they already exist.
+pub(crate) fn seed_abilities(ctx: &spacetimedb::ReducerContext) {
+    for (name, def) in abilities::builtin() {
+        if ctx.db.ability().name().find(name.to_string()).is_none() {
+            ctx.db.ability().insert(to_row(name, &def));
+        }
+    }
+}
+
+/// Cast one of the sender's abilities in the direction they're facing.
+#[spacetimedb::reducer]
+pub fn cast_ability(ctx: &spacetimedb::ReducerContext, name: String) -> Result<(), String> {
+    count_call(ctx, "cast_ability");
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    let Some(caster) = ctx.db.player().identity().find(ctx.sender) else {
+        return Err("Not in a game".into());
+    };
+    if caster.health <= 0.0 {
+        return Err("Dead players can't cast".into());
+    }
+    if !impairment(ctx, caster.identity).can_attack() {
+        return Err("Stunned players can't cast".into());
+    }
+    let Some(row) = ctx.db.ability().name().find(&name) else {
+        return Err(format!("No ability named '{name}'"));
+    };
+    let Some(ability) = to_def(&row) else {
+        return Err(format!(
+            "Ability '{name}' has unknown shape '{}'",
+            row.shape
+        ));
+    };
+
+    let cooldown = find_effect(ctx, EffectKind::Cooldown, &name);
+    if cooldown
+        .as_ref()
+        .is_some_and(|c| !abilities::cooldown_ready(c.timestamp, now, ability.cooldown_secs))
+    {
+        return Err(format!("'{name}' is on cooldown"));
+    }
+
+    // Casts spend the stacks basic attacks build up, after the same decay
+    let stacking = find_effect(ctx, EffectKind::StackingDamage, "");
+    let stacks = stacking.as_ref().map_or(0.0, |e| {
+        let elapsed = (now - e.timestamp) as f64 / 1_000_000.0;
+        combat::decay_stacks(e.magnitude, elapsed, Tuning::load(ctx).stack_decay)
+    });
+    if stacks < ability.stack_cost {
+        return Err(format!(
+            "'{name}' needs {} stacks, have {stacks:.0}",
+            ability.stack_cost
+        ));
+    }
+
+    let mana_row = find_effect(ctx, EffectKind::Mana, "");
+    let mana = current_mana(mana_row.as_ref(), now);
+    let caster_stats = Stats::new()
+        .with(Stat::AttackDamage, caster.attack_damage)
+        .with(Stat::CritChance, caster.crit_chance)
+        .with(Stat::CritMultiplier, caster.crit_multiplier)
+        .with(Stat::MaxMana, resources::MAX_MANA)
+        .with(Stat::Mana, mana);
+    let rules = player_rules(ctx, caster.identity);
+    let mana_cost = resources::cast_cost(ability.mana_cost, &caster_stats, &rules.on_cast);
+    if mana < mana_cost {
+        return Err(format!(
+            "'{name}' needs {mana_cost:.0} mana, have {mana:.0}"
+        ));
+    }
+
+    if let Some(effect) = stacking {
+        let remaining = stacks - ability.stack_cost;
+        if remaining > 0.0 {
+            ctx.db.active_effect().id().update(ActiveEffect {
+                magnitude: remaining,
+                ..effect
+            });
+        } else {
+            ctx.db.active_effect().delete(effect);
+        }
+    }
+    if mana_cost > 0.0 {
+        spend_mana(ctx, mana_row, mana - mana_cost, now);
+    }
+    match cooldown {
+        Some(effect) => {
+            ctx.db.active_effect().id().update(ActiveEffect {
+                duration: ability.cooldown_secs,
+                timestamp: now,
+                ..effect
+            });
+        }
+        None => {
+            ctx.db.active_effect().insert(ActiveEffect {
+                id: 0,
+                owner: ctx.sender,
+                kind: EffectKind::Cooldown.as_u8(),
+                name: name.clone(),
+                magnitude: 0.0,
+                duration: ability.cooldown_secs,
+                timestamp: now,
+            });
+        }
+    }
+
+    let origin = glam::Vec2::new(caster.x, caster.z);
+    let forward = glam::Vec2::new(-caster.rotation_y.sin(), -caster.rotation_y.cos());
+    let reach = ability.range + ability.width;
+    let targets: Vec<HitTarget> = ctx
+        .db
+        .enemy()
+        .iter()
+        .filter(|e| e.health > 0.0 && e.world_id == caster.world_id)
+        .filter(|e| (e.y - caster.y).abs() <= defaults::ATTACK_VERTICAL_REACH)
+        .filter_map(|e| {
+            let (x, _, z) = rewound_position(ctx, &e, caster.rtt_ms, now);
+            let pos = glam::Vec2::new(x, z);
+            (pos.distance(origin) <= reach * combat::CRIT_RANGE_BONUS).then_some(HitTarget {
+                id: e.id,
+                seed: e.id,
+                pos,
+                health: e.health,
+                max_health: e.max_health,
+                shield: e.shield,
+                armor: 0.0,
+            })
+        })
+        .collect();
+
+    let output = abilities::resolve_ability(&AbilityInput {
+        ability: &ability,
+        origin,
+        forward,
+        caster_stats: &caster_stats,
+        rules: &rules,
+        rng_seed: now as u64,
+        targets: &targets,
+    });
+
+    apply_area_hits(
+        ctx,
+        &caster,
+        &output,
+        origin,
+        forward,
+        ability.knockback,
+        ability.launch,
+        now,
+    );
+    Ok(())
+}
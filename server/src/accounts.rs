@@ -0,0 +1,285 @@
+//! Accounts: a username and password on top of the anonymous identity a
+//! client's token stands for. `register_account` names the sender's
+//! character; `sign_in` from any other connection moves that character over
+//! to it, which is how a player picks it up on a new device. Identity
+//! providers (OpenID) aren't supported; SpacetimeDB would verify those
+//! tokens itself, with nothing for the module to do.
+//!
+//! Failed sign-ins count against the account rather than the caller, so
+//! guesses can't be spread over connections: past a few, each one locks the
+//! account for twice as long as the last.
+
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::{Algorithm, Argon2, Params, Version};
+use spacetimedb::{Identity, Table};
+
+use crate::metrics::count_call;
+use crate::rate_limit;
+use crate::schema::*;
+
+const MIN_PASSWORD_LEN: usize = 8;
+const MAX_USERNAME_LEN: usize = 24;
+/// Argon2id cost: KiB of memory and passes per hash, so a leaked hash is
+/// slow to brute-force without making a sign-in slow.
+const HASH_MEMORY_KIB: u32 = 8 * 1024;
+const HASH_PASSES: u32 = 3;
+/// Failed sign-ins an account takes before it locks.
+const FREE_FAILURES: u32 = 5;
+/// Lockout after the first failure past the free ones, doubling with each
+/// failure after that up to [`MAX_LOCKOUT_SECS`].
+const BASE_LOCKOUT_SECS: i64 = 5;
+const MAX_LOCKOUT_SECS: i64 = 15 * 60;
+/// Failures are forgotten after this long without another.
+const FAILURE_MEMORY_SECS: i64 = 60 * 60;
+
+fn normalize_username(username: &str) -> Result<String, String> {
+    let username = username.trim().to_lowercase();
+    let valid_char = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '-';
+    if username.len() < 3 || username.len() > MAX_USERNAME_LEN {
+        return Err(format!(
+            "Usernames are 3 to {MAX_USERNAME_LEN} characters long"
+        ));
+    }
+    if !username.chars().all(valid_char) {
+        return Err("Usernames may only use letters, digits, '_' and '-'".into());
+    }
+    Ok(username)
+}
+
+fn argon2() -> Argon2<'static> {
+    let params = Params::new(HASH_MEMORY_KIB, HASH_PASSES, 1, None).unwrap_or_default();
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+}
+
+fn hash_password(salt: &[u8], password: &str) -> Result<String, String> {
+    let salt = SaltString::encode_b64(salt).map_err(|e| format!("Bad salt: {e}"))?;
+    argon2()
+        .hash_password(password.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|e| format!("Couldn't hash the password: {e}"))
+}
+
+fn password_matches(password_hash: &str, password: &str) -> bool {
+    PasswordHash::new(password_hash)
+        .is_ok_and(|hash| argon2().verify_password(password.as_bytes(), &hash).is_ok())
+}
+
+/// Refuse a sign-in to `username` while it's locked.
+fn check_lockout(
+    ctx: &spacetimedb::ReducerContext,
+    username: &str,
+    now: i64,
+) -> Result<(), String> {
+    let attempt = ctx
+        .db
+        .sign_in_attempt()
+        .username()
+        .find(username.to_string());
+    match attempt {
+        Some(attempt) if attempt.locked_until > now => {
+            let secs = (attempt.locked_until - now + 999_999) / 1_000_000;
+            Err(format!("Too many failed sign-ins, try again in {secs}s"))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Count a failed sign-in to `username`, locking it once past the free ones.
+fn record_failure(ctx: &spacetimedb::ReducerContext, username: String, now: i64) {
+    let existing = ctx.db.sign_in_attempt().username().find(&username);
+    let failures = existing
+        .as_ref()
+        .filter(|a| now - a.last_failure < FAILURE_MEMORY_SECS * 1_000_000)
+        .map_or(0, |a| a.failures)
+        + 1;
+    let locked_until = match failures.checked_sub(FREE_FAILURES + 1) {
+        Some(extra) => {
+            let secs = BASE_LOCKOUT_SECS
+                .saturating_mul(1 << extra.min(20))
+                .min(MAX_LOCKOUT_SECS);
+            spacetimedb::log::warn!(
+                "Account '{username}' locked for {secs}s after {failures} failed sign-ins"
+            );
+            now + secs * 1_000_000
+        }
+        None => 0,
+    };
+    let attempt = SignInAttempt {
+        username,
+        failures,
+        last_failure: now,
+        locked_until,
+    };
+    if existing.is_some() {
+        ctx.db.sign_in_attempt().username().update(attempt);
+    } else {
+        ctx.db.sign_in_attempt().insert(attempt);
+    }
+}
+
+/// Put a username and password on the sender's character.
+#[spacetimedb::reducer]
+pub fn register_account(
+    ctx: &spacetimedb::ReducerContext,
+    username: String,
+    password: String,
+) -> Result<(), String> {
+    count_call(ctx, "register_account");
+    if !rate_limit::allow(ctx, "register_account") {
+        return Err("Too many attempts, try again shortly".into());
+    }
+    let username = normalize_username(&username)?;
+    if password.len() < MIN_PASSWORD_LEN {
+        return Err(format!(
+            "Passwords are at least {MIN_PASSWORD_LEN} characters long"
+        ));
+    }
+    if ctx.db.player().identity().find(ctx.sender).is_none() {
+        return Err("Join a game first, so there is a character to register".into());
+    }
+    if let Some(existing) = ctx.db.account().identity().find(ctx.sender) {
+        // Registering again on a reconnect is fine
+        return if existing.username == username {
+            Ok(())
+        } else {
+            Err(format!("Already registered as '{}'", existing.username))
+        };
+    }
+    if ctx.db.account().username().find(&username).is_some() {
+        return Err(format!("The username '{username}' is taken"));
+    }
+
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    let seed = format!("{username}:{}:{now}", ctx.sender);
+    let salt = blake3::hash(seed.as_bytes());
+    ctx.db.account().insert(Account {
+        password_hash: hash_password(&salt.as_bytes()[..16], &password)?,
+        username,
+        identity: ctx.sender,
+        created_at: now,
+    });
+    Ok(())
+}
+
+/// Take over the character registered as `username`. Whatever character the
+/// sender had is deleted in its place. Called before `join_game`, which then
+/// brings the character into the world.
+#[spacetimedb::reducer]
+pub fn sign_in(
+    ctx: &spacetimedb::ReducerContext,
+    username: String,
+    password: String,
+) -> Result<(), String> {
+    count_call(ctx, "sign_in");
+    if !rate_limit::allow(ctx, "sign_in") {
+        return Err("Too many attempts, try again shortly".into());
+    }
+    let username = normalize_username(&username)?;
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    check_lockout(ctx, &username, now)?;
+    let Some(account) = ctx.db.account().username().find(&username) else {
+        return Err("Wrong username or password".into());
+    };
+    if !password_matches(&account.password_hash, &password) {
+        record_failure(ctx, username, now);
+        return Err("Wrong username or password".into());
+    }
+    ctx.db.sign_in_attempt().username().delete(&username);
+    let previous = account.identity;
+    if previous == ctx.sender {
+        return Ok(());
+    }
+    if ctx.db.account().identity().find(ctx.sender).is_some() {
+        return Err("This device holds another account's character".into());
+    }
+    if ctx
+        .db
+        .player()
+        .identity()
+        .find(previous)
+        .is_some_and(|p| p.online)
+    {
+        return Err("That character is playing on another device".into());
+    }
+
+    delete_character(ctx, ctx.sender);
+    move_character(ctx, previous, ctx.sender);
+    ctx.db.account().username().update(Account {
+        identity: ctx.sender,
+        ..account
+    });
+    spacetimedb::log::info!("Account '{username}' signed in from a new identity");
+    Ok(())
+}
+
+/// Everything a character is, besides what goes with the session anyway.
+fn delete_character(ctx: &spacetimedb::ReducerContext, identity: Identity) {
+    ctx.db.player().identity().delete(identity);
+    ctx.db.player_loadout().identity().delete(identity);
+    ctx.db.death().identity().delete(identity);
+    ctx.db.match_stats().identity().delete(identity);
+    let equipped: Vec<EquippedItem> = ctx.db.equipped_item().owner().filter(identity).collect();
+    for item in equipped {
+        ctx.db.equipped_item().delete(item);
+    }
+    let owned: Vec<InventoryItem> = ctx.db.inventory_item().owner().filter(identity).collect();
+    for item in owned {
+        ctx.db.inventory_item().delete(item);
+    }
+    let effects: Vec<ActiveEffect> = ctx.db.active_effect().owner().filter(identity).collect();
+    for effect in effects {
+        ctx.db.active_effect().delete(effect);
+    }
+    let standings: Vec<Leaderboard> = ctx.db.leaderboard().identity().filter(identity).collect();
+    for standing in standings {
+        ctx.db.leaderboard().delete(standing);
+    }
+}
+
+/// Hand `from`'s character, items and standings to `to`. Effects, a death
+/// timer and match stats are dropped, as a new session would drop them.
+fn move_character(ctx: &spacetimedb::ReducerContext, from: Identity, to: Identity) {
+    if let Some(player) = ctx.db.player().identity().find(from) {
+        ctx.db.player().identity().delete(from);
+        ctx.db.player().insert(Player {
+            identity: to,
+            online: false,
+            ..player
+        });
+    }
+    if let Some(loadout) = ctx.db.player_loadout().identity().find(from) {
+        ctx.db.player_loadout().identity().delete(from);
+        ctx.db.player_loadout().insert(PlayerLoadout {
+            identity: to,
+            ..loadout
+        });
+    }
+    let equipped: Vec<EquippedItem> = ctx.db.equipped_item().owner().filter(from).collect();
+    for item in equipped {
+        ctx.db
+            .equipped_item()
+            .inventory_id()
+            .update(EquippedItem { owner: to, ..item });
+    }
+    let owned: Vec<InventoryItem> = ctx.db.inventory_item().owner().filter(from).collect();
+    for item in owned {
+        ctx.db
+            .inventory_item()
+            .id()
+            .update(InventoryItem { owner: to, ..item });
+    }
+    let standings: Vec<Leaderboard> = ctx.db.leaderboard().identity().filter(from).collect();
+    for standing in standings {
+        ctx.db.leaderboard().id().update(Leaderboard {
+            identity: to,
+            ..standing
+        });
+    }
+
+    ctx.db.death().identity().delete(from);
+    ctx.db.match_stats().identity().delete(from);
+    let effects: Vec<ActiveEffect> = ctx.db.active_effect().owner().filter(from).collect();
+    for effect in effects {
+        ctx.db.active_effect().delete(effect);
+    }
+}
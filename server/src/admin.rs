@@ -0,0 +1,212 @@
+use spacetimedb::{Identity, Table};
+use wasm_fantasia_shared::combat::{defaults, life_events};
+use wasm_fantasia_shared::effects::EffectKind;
+
+use crate::archetypes::{find_archetype, is_admin};
+use crate::enemy_ai::spawn_ring;
+use crate::lifecycle::{insert_life_event, record_death};
+use crate::matches::start_match;
+use crate::schema::*;
+
+/// Most enemies one `admin_spawn` call may add.
+const MAX_ADMIN_SPAWN: u32 = 50;
+
+fn require_admin(ctx: &spacetimedb::ReducerContext) -> Result<(), String> {
+    if is_admin(ctx) {
+        Ok(())
+    } else {
+        Err("Only admins can do that".into())
+    }
+}
+
+fn find_target(ctx: &spacetimedb::ReducerContext, target: Identity) -> Result<Player, String> {
+    ctx.db
+        .player()
+        .identity()
+        .find(target)
+        .ok_or_else(|| format!("No player {target}"))
+}
+
+/// Add `identity` to the admin list. Admin only.
+#[spacetimedb::reducer]
+pub fn admin_grant(ctx: &spacetimedb::ReducerContext, identity: Identity) -> Result<(), String> {
+    require_admin(ctx)?;
+    if ctx.db.admin().identity().find(identity).is_none() {
+        ctx.db.admin().insert(Admin { identity });
+    }
+    Ok(())
+}
+
+/// Remove `identity` from the admin list. The last admin can't be removed.
+#[spacetimedb::reducer]
+pub fn admin_revoke(ctx: &spacetimedb::ReducerContext, identity: Identity) -> Result<(), String> {
+    require_admin(ctx)?;
+    if ctx.db.admin().identity().find(identity).is_none() {
+        return Err(format!("{identity} isn't an admin"));
+    }
+    if ctx.db.admin().count() <= 1 {
+        return Err("Can't remove the last admin".into());
+    }
+    ctx.db.admin().identity().delete(identity);
+    Ok(())
+}
+
+/// Take `target` out of the session: their player goes offline and any
+/// observer session ends. The module can't close their connection, so the
+/// server ignores their movement and attacks until they join again, and
+/// their client goes back to the title screen when it sees the change.
+#[spacetimedb::reducer]
+pub fn admin_kick(ctx: &spacetimedb::ReducerContext, target: Identity) -> Result<(), String> {
+    require_admin(ctx)?;
+    let observing = ctx.db.observer().identity().delete(target);
+    match ctx.db.player().identity().find(target) {
+        Some(player) if player.online => {
+            ctx.db.player().identity().update(Player {
+                online: false,
+                last_update: ctx.timestamp.to_micros_since_unix_epoch(),
+                ..player
+            });
+            Ok(())
+        }
+        _ if observing => Ok(()),
+        _ => Err(format!("{target} isn't in a game")),
+    }
+}
+
+/// Whether a position is on the arena floor's footprint and above the kill
+/// plane.
+pub(crate) fn in_arena(x: f32, y: f32, z: f32) -> bool {
+    [x, y, z].iter().all(|v| v.is_finite())
+        && x.abs() <= defaults::ARENA_HALF_SIZE
+        && z.abs() <= defaults::ARENA_HALF_SIZE
+        && y >= defaults::KILL_PLANE_Y
+}
+
+/// Move `target` to the given position in their world.
+#[spacetimedb::reducer]
+pub fn admin_teleport(
+    ctx: &spacetimedb::ReducerContext,
+    target: Identity,
+    x: f32,
+    y: f32,
+    z: f32,
+) -> Result<(), String> {
+    require_admin(ctx)?;
+    if !in_arena(x, y, z) {
+        return Err("Position is outside the arena".into());
+    }
+    let player = find_target(ctx, target)?;
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    let player = ctx.db.player().identity().update(Player {
+        x,
+        y,
+        z,
+        last_update: now,
+        ..player
+    });
+    // The client owns its position, so tell it to move there too
+    insert_life_event(ctx, &player, Some(ctx.sender), life_events::TELEPORT, now);
+    Ok(())
+}
+
+/// Spawn `count` enemies of the named archetype around the given position in
+/// `world_id`, starting a match there if none is running.
+#[spacetimedb::reducer]
+pub fn admin_spawn(
+    ctx: &spacetimedb::ReducerContext,
+    world_id: String,
+    archetype: String,
+    count: u32,
+    x: f32,
+    y: f32,
+    z: f32,
+) -> Result<(), String> {
+    require_admin(ctx)?;
+    if ctx.db.world().id().find(&world_id).is_none() {
+        return Err(format!("No world named '{world_id}'"));
+    }
+    let Some(archetype) = find_archetype(ctx, &archetype) else {
+        return Err(format!("Unknown enemy archetype '{archetype}'"));
+    };
+    if count == 0 || count > MAX_ADMIN_SPAWN {
+        return Err(format!("Count must be 1 to {MAX_ADMIN_SPAWN}"));
+    }
+
+    start_match(ctx, &world_id, x, y, z);
+    spawn_ring(ctx, &world_id, &archetype, count, x, y, z);
+    Ok(())
+}
+
+/// Overwrite one of `target`'s stats, named after its `player` column:
+/// `health`, `max_health`, `attack_damage`, `crit_chance`,
+/// `crit_multiplier`, `attack_range`, `attack_arc`, `knockback_force` or
+/// `attack_speed`. Combat stats hold until something recomputes them from
+/// level and gear, such as equipping an item or levelling up.
+#[spacetimedb::reducer]
+pub fn admin_set_stat(
+    ctx: &spacetimedb::ReducerContext,
+    target: Identity,
+    stat: String,
+    value: f32,
+) -> Result<(), String> {
+    require_admin(ctx)?;
+    if !value.is_finite() || value < 0.0 {
+        return Err("Stats can't be negative".into());
+    }
+    let mut player = find_target(ctx, target)?;
+    let was_alive = player.health > 0.0;
+    match stat.as_str() {
+        "health" if !was_alive => {
+            return Err("They're dead; health comes back when they respawn".into())
+        }
+        "health" => player.health = value.min(player.max_health),
+        "max_health" => {
+            player.max_health = value;
+            player.health = player.health.min(value);
+        }
+        "attack_damage" => player.attack_damage = value,
+        "crit_chance" => player.crit_chance = value.min(1.0),
+        "crit_multiplier" => player.crit_multiplier = value,
+        "attack_range" => player.attack_range = value,
+        "attack_arc" => player.attack_arc = value,
+        "knockback_force" => player.knockback_force = value,
+        "attack_speed" => player.attack_speed = value,
+        _ => return Err(format!("Unknown stat '{stat}'")),
+    }
+
+    let player = ctx.db.player().identity().update(player);
+    if was_alive && player.health <= 0.0 {
+        let now = ctx.timestamp.to_micros_since_unix_epoch();
+        record_death(ctx, &player, None, now);
+    }
+    Ok(())
+}
+
+/// Give `target` an active effect of `effect_type`: a built-in's name, e.g.
+/// `burn`, or an effect script's (see [`EffectKind::parse_str`]). A negative
+/// `duration` never runs out.
+#[spacetimedb::reducer]
+pub fn admin_add_effect(
+    ctx: &spacetimedb::ReducerContext,
+    target: Identity,
+    effect_type: String,
+    magnitude: f32,
+    duration: f32,
+) -> Result<(), String> {
+    require_admin(ctx)?;
+    if !magnitude.is_finite() || !duration.is_finite() {
+        return Err("Magnitude and duration must be finite".into());
+    }
+    find_target(ctx, target)?;
+    let (kind, name) = EffectKind::parse_str(effect_type.trim());
+    ctx.db.active_effect().insert(ActiveEffect {
+        id: 0,
+        owner: target,
+        kind: kind.as_u8(),
+        name: name.to_string(),
+        magnitude,
+        duration,
+        timestamp: ctx.timestamp.to_micros_since_unix_epoch(),
+    });
+    Ok(())
+}
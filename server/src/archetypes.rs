@@ -0,0 +1,177 @@
+use spacetimedb::Table;
+use wasm_fantasia_shared::boss;
+use wasm_fantasia_shared::combat::defaults;
+
+use crate::schema::*;
+
+/// Archetype every pack used before archetypes were data, and the one
+/// `spawn_enemies` and `play_again` still spawn.
+pub const BASIC_ARCHETYPE: &str = "basic";
+
+/// The built-in `basic` archetype, straight from the shared defaults.
+fn basic_archetype() -> EnemyArchetype {
+    EnemyArchetype {
+        name: BASIC_ARCHETYPE.to_string(),
+        max_health: defaults::ENEMY_HEALTH,
+        max_shield: defaults::ENEMY_SHIELD,
+        attack_damage: defaults::ENEMY_ATTACK_DAMAGE,
+        attack_range: defaults::ENEMY_ATTACK_RANGE,
+        attack_speed: 1.0,
+        attack_cooldown: defaults::ENEMY_ATTACK_COOLDOWN,
+        walk_speed: defaults::ENEMY_WALK_SPEED,
+        mass: 50.0,
+        pack_size_min: 80,
+        pack_size_max: 120,
+    }
+}
+
+/// The built-in `boss`: a lone, slow heavyweight whose special attacks are
+/// run by [`crate::boss`] on top of the regular AI.
+fn boss_archetype() -> EnemyArchetype {
+    EnemyArchetype {
+        name: boss::BOSS_ARCHETYPE.to_string(),
+        max_health: defaults::ENEMY_HEALTH * 12.0,
+        max_shield: defaults::ENEMY_SHIELD * 6.0,
+        attack_damage: defaults::ENEMY_ATTACK_DAMAGE * 2.0,
+        attack_range: 3.0,
+        attack_speed: 1.0,
+        attack_cooldown: 2.5,
+        walk_speed: 1.6,
+        mass: 500.0,
+        pack_size_min: 1,
+        pack_size_max: 1,
+    }
+}
+
+/// Insert the built-in archetypes unless they already exist.
+pub(crate) fn seed_archetypes(ctx: &spacetimedb::ReducerContext) {
+    for archetype in [basic_archetype(), boss_archetype()] {
+        if ctx
+            .db
+            .enemy_archetype()
+            .name()
+            .find(&archetype.name)
+            .is_none()
+        {
+            ctx.db.enemy_archetype().insert(archetype);
+        }
+    }
+}
+
+/// Look up an archetype by name. `basic` always resolves, even in databases
+/// published before the table existed.
+pub(crate) fn find_archetype(
+    ctx: &spacetimedb::ReducerContext,
+    name: &str,
+) -> Option<EnemyArchetype> {
+    ctx.db
+        .enemy_archetype()
+        .name()
+        .find(name.to_string())
+        .or_else(|| (name == BASIC_ARCHETYPE).then(basic_archetype))
+}
+
+/// The `basic` archetype as currently configured.
+pub(crate) fn configured_basic(ctx: &spacetimedb::ReducerContext) -> EnemyArchetype {
+    find_archetype(ctx, BASIC_ARCHETYPE).unwrap_or_else(basic_archetype)
+}
+
+pub(crate) fn is_admin(ctx: &spacetimedb::ReducerContext) -> bool {
+    ctx.db.admin().identity().find(ctx.sender).is_some()
+}
+
+/// Create or replace an enemy archetype. Admin only.
+#[spacetimedb::reducer]
+pub fn upsert_enemy_archetype(
+    ctx: &spacetimedb::ReducerContext,
+    name: String,
+    max_health: f32,
+    max_shield: f32,
+    attack_damage: f32,
+    attack_range: f32,
+    attack_speed: f32,
+    attack_cooldown: f32,
+    walk_speed: f32,
+    mass: f32,
+    pack_size_min: u32,
+    pack_size_max: u32,
+) -> Result<(), String> {
+    if !is_admin(ctx) {
+        return Err("Only admins can edit enemy archetypes".into());
+    }
+    let archetype = EnemyArchetype {
+        name,
+        max_health,
+        max_shield,
+        attack_damage,
+        attack_range,
+        attack_speed,
+        attack_cooldown,
+        walk_speed,
+        mass,
+        pack_size_min,
+        pack_size_max,
+    };
+    validate_archetype(&archetype)?;
+    spacetimedb::log::info!("Enemy archetype '{}' updated", archetype.name);
+    save_archetype(ctx, archetype);
+    Ok(())
+}
+
+/// Reject archetypes the AI and physics can't work with.
+pub(crate) fn validate_archetype(archetype: &EnemyArchetype) -> Result<(), String> {
+    if archetype.name.trim().is_empty() {
+        return Err("Archetype name can't be empty".into());
+    }
+    let stats = [
+        archetype.max_health,
+        archetype.max_shield,
+        archetype.attack_damage,
+        archetype.attack_range,
+        archetype.attack_speed,
+        archetype.attack_cooldown,
+        archetype.walk_speed,
+        archetype.mass,
+    ];
+    if stats.iter().any(|v| !v.is_finite() || *v < 0.0) {
+        return Err("Archetype stats must be finite and non-negative".into());
+    }
+    if archetype.max_health == 0.0 || archetype.attack_speed == 0.0 || archetype.mass == 0.0 {
+        return Err("max_health, attack_speed and mass must be positive".into());
+    }
+    if archetype.pack_size_min == 0 || archetype.pack_size_min > archetype.pack_size_max {
+        return Err("Pack size needs 0 < min <= max".into());
+    }
+    Ok(())
+}
+
+/// Insert the archetype, or replace the one with the same name.
+pub(crate) fn save_archetype(ctx: &spacetimedb::ReducerContext, archetype: EnemyArchetype) {
+    if ctx
+        .db
+        .enemy_archetype()
+        .name()
+        .find(&archetype.name)
+        .is_some()
+    {
+        ctx.db.enemy_archetype().name().update(archetype);
+    } else {
+        ctx.db.enemy_archetype().insert(archetype);
+    }
+}
+
+/// Remove an enemy archetype. Enemies already spawned from it fall back to
+/// `basic` movement until they die. Admin only.
+#[spacetimedb::reducer]
+pub fn delete_enemy_archetype(
+    ctx: &spacetimedb::ReducerContext,
+    name: String,
+) -> Result<(), String> {
+    if !is_admin(ctx) {
+        return Err("Only admins can edit enemy archetypes".into());
+    }
+    if !ctx.db.enemy_archetype().name().delete(&name) {
+        return Err(format!("No enemy archetype named '{name}'"));
+    }
+    Ok(())
+}
@@ -0,0 +1,248 @@
+use spacetimedb::Table;
+use wasm_fantasia_shared::boss::{self, in_telegraph, BossAttack, BossPhase, TelegraphShape};
+use wasm_fantasia_shared::combat::{absorb_damage, knockback_displacement, HitReaction};
+
+use crate::combat_log::log_hit;
+use crate::lifecycle::record_death;
+use crate::schema::*;
+use crate::triggers::health_dropped;
+
+fn secs_to_micros(secs: f32) -> i64 {
+    (secs * 1_000_000.0) as i64
+}
+
+/// Whether `enemy` is winding up a special attack. Bosses stop chasing while
+/// they do, so players can step out of the telegraph.
+pub(crate) fn is_casting(ctx: &spacetimedb::ReducerContext, enemy: &Enemy) -> bool {
+    ctx.db
+        .telegraph()
+        .enemy_id()
+        .filter(enemy.id)
+        .next()
+        .is_some()
+}
+
+/// Called from `game_tick` for each unpaused world: land the telegraphs
+/// whose wind-up is over, then advance each boss's phase and start its next
+/// special attack when it's due and a player is in range.
+pub(crate) fn tick_bosses(
+    ctx: &spacetimedb::ReducerContext,
+    world_id: &str,
+    enemies: &[Enemy],
+    players: &[Player],
+    now: i64,
+) {
+    land_telegraphs(ctx, world_id, players, now);
+    for enemy in enemies
+        .iter()
+        .filter(|e| e.enemy_type == boss::BOSS_ARCHETYPE)
+    {
+        tick_boss(ctx, enemy, players, now);
+    }
+}
+
+fn tick_boss(ctx: &spacetimedb::ReducerContext, enemy: &Enemy, players: &[Player], now: i64) {
+    let state = ctx
+        .db
+        .boss_state()
+        .enemy_id()
+        .find(enemy.id)
+        .unwrap_or_else(|| {
+            ctx.db.boss_state().insert(BossState {
+                enemy_id: enemy.id,
+                world_id: enemy.world_id.clone(),
+                phase: BossPhase::Opening.as_str().to_string(),
+                specials_cast: 0,
+                next_special_at: now + secs_to_micros(BossPhase::Opening.special_cooldown_secs()),
+            })
+        });
+
+    let current = BossPhase::parse_str(&state.phase);
+    let phase = BossPhase::for_health(enemy.health / enemy.max_health, current);
+    // A new phase opens with a special attack straight away
+    let next_special_at = if phase != current {
+        spacetimedb::log::info!("Boss {} enters its {} phase", enemy.id, phase.as_str());
+        now
+    } else {
+        state.next_special_at
+    };
+
+    let target = players
+        .iter()
+        .map(|p| (p, (p.x - enemy.x).hypot(p.z - enemy.z)))
+        .filter(|(_, dist)| *dist <= boss::SPECIAL_TRIGGER_RANGE)
+        .min_by(|a, b| a.1.total_cmp(&b.1));
+    let Some((target, _)) = target.filter(|_| now >= next_special_at && !is_casting(ctx, enemy))
+    else {
+        if phase != current {
+            ctx.db.boss_state().enemy_id().update(BossState {
+                phase: phase.as_str().to_string(),
+                next_special_at,
+                ..state
+            });
+        }
+        return;
+    };
+
+    let attacks = phase.attacks();
+    let attack = attacks[state.specials_cast as usize % attacks.len()];
+    let lands_at = now + secs_to_micros(attack.spec().windup_secs * phase.windup_scale());
+    start_special(ctx, enemy, target, attack, now, lands_at);
+    ctx.db.boss_state().enemy_id().update(BossState {
+        phase: phase.as_str().to_string(),
+        specials_cast: state.specials_cast + 1,
+        next_special_at: lands_at + secs_to_micros(phase.special_cooldown_secs()),
+        ..state
+    });
+}
+
+/// Write the telegraph for `attack`, aimed at `target`.
+fn start_special(
+    ctx: &spacetimedb::ReducerContext,
+    enemy: &Enemy,
+    target: &Player,
+    attack: BossAttack,
+    now: i64,
+    lands_at: i64,
+) {
+    let spec = attack.spec();
+    let (x, y, z) = match attack {
+        BossAttack::Slam => (target.x, target.y, target.z),
+        BossAttack::Cleave | BossAttack::Shockwave => (enemy.x, enemy.y, enemy.z),
+    };
+    ctx.db.telegraph().insert(Telegraph {
+        id: 0,
+        world_id: enemy.world_id.clone(),
+        enemy_id: enemy.id,
+        attack: attack.as_str().to_string(),
+        shape: spec.shape.as_str().to_string(),
+        x,
+        y,
+        z,
+        rotation_y: f32::atan2(-(target.x - enemy.x), -(target.z - enemy.z)),
+        radius: spec.radius,
+        arc: spec.arc,
+        damage: spec.damage,
+        knockback: spec.knockback,
+        started_at: now,
+        lands_at,
+    });
+}
+
+/// Hit every player inside each telegraph in `world_id` whose wind-up is
+/// over, then delete it.
+fn land_telegraphs(
+    ctx: &spacetimedb::ReducerContext,
+    world_id: &str,
+    players: &[Player],
+    now: i64,
+) {
+    let landed: Vec<Telegraph> = ctx
+        .db
+        .telegraph()
+        .iter()
+        .filter(|t| t.world_id == world_id && now >= t.lands_at)
+        .collect();
+    for telegraph in landed {
+        let shape = TelegraphShape::parse_str(&telegraph.shape);
+        let center = glam::Vec2::new(telegraph.x, telegraph.z);
+        for player in players {
+            let position = glam::Vec2::new(player.x, player.z);
+            if in_telegraph(
+                shape,
+                center,
+                telegraph.rotation_y,
+                telegraph.radius,
+                telegraph.arc,
+                position,
+            ) {
+                hit_player(ctx, &telegraph, player, now);
+            }
+        }
+        ctx.db.telegraph().id().delete(telegraph.id);
+    }
+}
+
+fn hit_player(ctx: &spacetimedb::ReducerContext, telegraph: &Telegraph, player: &Player, now: i64) {
+    let Some(current) = ctx.db.player().identity().find(player.identity) else {
+        return;
+    };
+    if current.health <= 0.0 {
+        return;
+    }
+    let absorbed = absorb_damage(current.shield, current.health, telegraph.damage);
+    let died = absorbed.health <= 0.0;
+
+    let radial = glam::Vec2::new(player.x - telegraph.x, player.z - telegraph.z);
+    let forward = glam::Vec2::new(-telegraph.rotation_y.sin(), -telegraph.rotation_y.cos());
+    let disp = if died {
+        glam::Vec3::ZERO
+    } else {
+        knockback_displacement(
+            radial.normalize_or(forward),
+            forward,
+            telegraph.knockback,
+            0.0,
+            0.0,
+        )
+    };
+
+    let event = ctx.db.combat_event().insert(CombatEvent {
+        id: 0,
+        x: player.x,
+        y: player.y,
+        z: player.z,
+        damage: telegraph.damage,
+        is_crit: false,
+        attacker: ctx.identity(),
+        target_id: 0,
+        target_player: Some(player.identity),
+        knockback_x: disp.x,
+        knockback_y: disp.y,
+        knockback_z: disp.z,
+        reaction: HitReaction::from_damage(telegraph.damage, current.max_health)
+            .as_str()
+            .to_string(),
+        world_id: telegraph.world_id.clone(),
+        timestamp: now,
+    });
+    log_hit(ctx, &event);
+
+    let before = current.health;
+    let victim = ctx.db.player().identity().update(Player {
+        health: absorbed.health,
+        shield: absorbed.shield,
+        last_hit_time: now,
+        ..current
+    });
+    health_dropped(ctx, &victim, before, now);
+    if died {
+        record_death(ctx, &victim, None, now);
+    }
+}
+
+/// Drop boss state and telegraphs left behind by bosses that died or were
+/// cleared.
+pub(crate) fn prune_bosses(ctx: &spacetimedb::ReducerContext) {
+    let gone = |enemy_id: u64| ctx.db.enemy().id().find(enemy_id).is_none();
+    let states: Vec<u64> = ctx
+        .db
+        .boss_state()
+        .iter()
+        .map(|s| s.enemy_id)
+        .filter(|id| gone(*id))
+        .collect();
+    for enemy_id in states {
+        ctx.db.boss_state().enemy_id().delete(enemy_id);
+    }
+    let telegraphs: Vec<u64> = ctx
+        .db
+        .telegraph()
+        .iter()
+        .filter(|t| gone(t.enemy_id))
+        .map(|t| t.id)
+        .collect();
+    for id in telegraphs {
+        ctx.db.telegraph().id().delete(id);
+    }
+}
@@ -0,0 +1,65 @@
+use spacetimedb::Table;
+
+use crate::metrics::count_call;
+use crate::schema::*;
+
+/// Longer messages are cut to this many characters.
+const MAX_CHAT_CHARS: usize = 200;
+/// At most this many messages per sender within the rate window.
+const RATE_LIMIT_MESSAGES: usize = 5;
+const RATE_WINDOW_MICROS: i64 = 10_000_000;
+/// Messages older than this are deleted.
+const RETENTION_MICROS: i64 = 5 * 60 * 1_000_000;
+
+/// Post a chat message to the sender's world.
+#[spacetimedb::reducer]
+pub fn send_chat(ctx: &spacetimedb::ReducerContext, text: String) -> Result<(), String> {
+    count_call(ctx, "send_chat");
+    let Some(player) = ctx.db.player().identity().find(ctx.sender) else {
+        return Err("Not in a game".into());
+    };
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    prune_chat(ctx, now);
+
+    let text: String = text
+        .trim()
+        .chars()
+        .filter(|c| !c.is_control())
+        .take(MAX_CHAT_CHARS)
+        .collect();
+    if text.is_empty() {
+        return Err("Empty message".into());
+    }
+
+    let recent = ctx
+        .db
+        .chat_message()
+        .iter()
+        .filter(|m| m.sender == ctx.sender && now - m.timestamp < RATE_WINDOW_MICROS)
+        .count();
+    if recent >= RATE_LIMIT_MESSAGES {
+        return Err("Sending messages too quickly".into());
+    }
+
+    ctx.db.chat_message().insert(ChatMessage {
+        id: 0,
+        sender: ctx.sender,
+        world_id: player.world_id,
+        text,
+        timestamp: now,
+    });
+    Ok(())
+}
+
+/// Drop messages past the retention window, across all worlds.
+fn prune_chat(ctx: &spacetimedb::ReducerContext, now: i64) {
+    let stale: Vec<ChatMessage> = ctx
+        .db
+        .chat_message()
+        .iter()
+        .filter(|m| now - m.timestamp > RETENTION_MICROS)
+        .collect();
+    for message in stale {
+        ctx.db.chat_message().delete(message);
+    }
+}
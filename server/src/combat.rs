@@ -1,21 +1,64 @@
 use spacetimedb::Table;
 use wasm_fantasia_shared::combat::{
-    self, defaults, knockback_displacement, resolve_combat, CombatInput, HitTarget,
+    self, defaults, knockback_displacement, resolve_combat, CombatInput, CombatOutput, HitReaction,
+    HitResult, HitTarget,
 };
-use wasm_fantasia_shared::presets;
+use wasm_fantasia_shared::effects::EffectKind;
+use wasm_fantasia_shared::presets::lifesteal;
+use wasm_fantasia_shared::rng::{attack_seed, seed_from_identity};
 use wasm_fantasia_shared::rules::{Stat, Stats};
-
+use wasm_fantasia_shared::weapons::Weapon;
+
+use crate::combat_log::log_hit;
+use crate::config::Tuning;
+use crate::effects::{apply_statuses, haste, impairment};
+use crate::leaderboard::tally_hit;
+use crate::lifecycle::record_death;
+use crate::loadouts::player_rules;
+use crate::loot::drop_loot;
+use crate::matches::{end_match_if_cleared, record_hit};
+use crate::metrics::count_call;
+use crate::rate_limit;
+use crate::rewind::rewound_position;
 use crate::schema::*;
-
-/// Server-authoritative attack resolution.
+use crate::scripting::ScriptHost;
+use crate::spawns::spawn_requested;
+use crate::threat::add_threat;
+use crate::triggers::health_dropped;
+
+/// Player targets share the [`HitTarget`] id space with enemies, offset past
+/// anything the enemy table's auto-increment will reach.
+const PLAYER_TARGET_BASE: u64 = 1 << 63;
+
+/// Server-authoritative attack resolution. `intent_seq` numbers the client's
+/// attack intents, which it resends until the player row echoes the number
+/// back; a resent intent that already arrived is skipped. The number also
+/// seeds the swing's rolls, as it does the client's prediction.
 #[spacetimedb::reducer]
-pub fn attack_hit(ctx: &spacetimedb::ReducerContext) {
-    let now = ctx.timestamp.to_micros_since_unix_epoch();
+pub fn attack_hit(ctx: &spacetimedb::ReducerContext, intent_seq: u32) {
+    count_call(ctx, "attack_hit");
     let Some(attacker) = ctx.db.player().identity().find(ctx.sender) else {
         return;
     };
+    // Retries of an intent already handled don't spend the attack budget
+    if intent_seq <= attacker.attack_intent_seq {
+        return;
+    }
+    if !rate_limit::allow(ctx, "attack_hit") {
+        return;
+    }
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
 
-    if attacker.health <= 0.0 {
+    if !attacker.online || attacker.health <= 0.0 {
+        return;
+    }
+    // Acknowledged whether or not the swing lands, so the client stops
+    // resending it either way
+    let attacker = ctx.db.player().identity().update(Player {
+        attack_intent_seq: intent_seq,
+        ..attacker
+    });
+    if !impairment(ctx, attacker.identity).can_attack() {
         return;
     }
 
@@ -31,21 +74,28 @@ pub fn attack_hit(ctx: &spacetimedb::ReducerContext) {
         ctx.db.combat_event().delete(event);
     }
 
-    // Cooldown check
-    if !combat::can_attack(attacker.last_attack_time, now, attacker.attack_speed) {
+    // Cooldown check, paced by the weapon and any haste
+    let weapon_speed = Weapon::parse_str(&attacker.weapon).stats().speed;
+    if !combat::can_attack(
+        attacker.last_attack_time,
+        now,
+        attacker.attack_speed * weapon_speed * haste(ctx, attacker.identity),
+    ) {
         return;
     }
 
+    let tuning = Tuning::load(ctx);
+
     // Read stacking buff from active_effect table
     let stacking_effect = ctx
         .db
         .active_effect()
         .iter()
-        .find(|e| e.owner == ctx.sender && e.effect_type == "stacking_damage");
+        .find(|e| e.owner == ctx.sender && e.kind == EffectKind::StackingDamage.as_u8());
 
     let (stacks, last_hit_time) = if let Some(ref effect) = stacking_effect {
         let decay_elapsed = (now - effect.timestamp) as f64 / 1_000_000.0;
-        let decayed = combat::decay_stacks(effect.magnitude, decay_elapsed, defaults::STACK_DECAY);
+        let decayed = combat::decay_stacks(effect.magnitude, decay_elapsed, tuning.stack_decay);
         (decayed, effect.timestamp)
     } else {
         (0.0, 0_i64)
@@ -57,14 +107,17 @@ pub fn attack_hit(ctx: &spacetimedb::ReducerContext) {
         1.0
     };
 
-    let rules = presets::default_player_rules();
+    let rules = player_rules(ctx, attacker.identity);
 
     let half_arc_cos = (attacker.attack_arc / 2.0_f32).to_radians().cos();
     let fwd = glam::Vec2::new(-attacker.rotation_y.sin(), -attacker.rotation_y.cos());
     let origin = glam::Vec2::new(attacker.x, attacker.z);
 
     let attacker_stats = Stats::new()
-        .with(Stat::AttackDamage, attacker.attack_damage)
+        .with(
+            Stat::AttackDamage,
+            attacker.attack_damage * tuning.player_damage_scale,
+        )
         .with(Stat::CritChance, attacker.crit_chance)
         .with(Stat::CritMultiplier, attacker.crit_multiplier)
         .with(Stat::Knockback, attacker.knockback_force)
@@ -81,47 +134,113 @@ pub fn attack_hit(ctx: &spacetimedb::ReducerContext) {
         .filter(|e| e.health > 0.0 && e.world_id == attacker.world_id)
         .collect();
 
-    let hit_targets: Vec<HitTarget> = enemy_targets
+    // Judge the swing against where the attacker saw each enemy, not where
+    // it has moved to on the server since
+    let mut hit_targets: Vec<HitTarget> = enemy_targets
         .iter()
-        .map(|e| HitTarget {
-            id: e.id,
-            pos: glam::Vec2::new(e.x, e.z),
-            health: e.health,
+        .map(|e| {
+            let (x, _, z) = rewound_position(ctx, e, attacker.rtt_ms, now);
+            HitTarget {
+                id: e.id,
+                seed: e.id,
+                pos: glam::Vec2::new(x, z),
+                health: e.health,
+                max_health: e.max_health,
+                shield: e.shield,
+                // Neither enemies nor players have armor server-side yet
+                armor: 0.0,
+            }
         })
         .collect();
 
-    let output = resolve_combat(&CombatInput {
+    // Other opted-in players are fair game for an opted-in attacker. Players
+    // own their positions, so there's no history to rewind.
+    let pvp_targets: Vec<Player> = if attacker.pvp {
+        ctx.db
+            .player()
+            .iter()
+            .filter(|p| p.identity != attacker.identity && p.world_id == attacker.world_id)
+            .filter(|p| p.pvp && p.online && p.health > 0.0)
+            .collect()
+    } else {
+        Vec::new()
+    };
+    hit_targets.extend(pvp_targets.iter().enumerate().map(|(i, p)| HitTarget {
+        id: PLAYER_TARGET_BASE + i as u64,
+        seed: seed_from_identity(&p.identity.to_byte_array()),
+        pos: glam::Vec2::new(p.x, p.z),
+        health: p.health,
+        max_health: p.max_health,
+        shield: p.shield,
+        armor: 0.0,
+    }));
+
+    let mut output = resolve_combat(&CombatInput {
         origin,
         forward: fwd,
         base_range: attacker.attack_range,
         half_arc_cos,
         attacker_stats: &attacker_stats,
         rules: &rules,
-        rng_seed: now as u64,
+        // The client seeds its prediction the same way
+        rng_seed: attack_seed(
+            seed_from_identity(&attacker.identity.to_byte_array()),
+            intent_seq,
+        ),
         targets: &hit_targets,
     });
+    ScriptHost::new(ctx).on_hits(
+        attacker.identity,
+        &attacker_stats,
+        &hit_targets,
+        &mut output.hits,
+    );
 
     // Apply results to DB
     for hit in &output.hits {
+        if let Some(victim) = hit
+            .target_id
+            .checked_sub(PLAYER_TARGET_BASE)
+            .and_then(|i| pvp_targets.get(i as usize))
+        {
+            apply_player_hit(ctx, &attacker, victim, hit, fwd, now);
+            continue;
+        }
+
         // Combat event at the target's position for VFX
         let target_enemy = enemy_targets.iter().find(|e| e.id == hit.target_id);
         let (hit_x, hit_y, hit_z) = target_enemy
             .map(|e| (e.x, e.y, e.z))
             .unwrap_or((attacker.x, attacker.y, attacker.z));
 
-        ctx.db.combat_event().insert(CombatEvent {
+        let max_health = target_enemy.map_or(defaults::ENEMY_HEALTH, |e| e.max_health);
+        let event = ctx.db.combat_event().insert(CombatEvent {
             id: 0,
             x: hit_x,
             y: hit_y,
             z: hit_z,
             damage: hit.damage,
             is_crit: hit.is_crit,
+            attacker: attacker.identity,
+            target_id: hit.target_id,
+            target_player: None,
+            knockback_x: 0.0,
+            knockback_y: 0.0,
+            knockback_z: 0.0,
+            reaction: HitReaction::from_damage(hit.damage, max_health)
+                .as_str()
+                .to_string(),
             world_id: attacker.world_id.clone(),
             timestamp: now,
         });
+        log_hit(ctx, &event);
+
+        record_hit(ctx, &attacker, hit);
+        add_threat(ctx, hit.target_id, attacker.identity, hit.damage);
 
         if let Some(enemy) = ctx.db.enemy().id().find(hit.target_id) {
             if hit.died {
+                drop_loot(ctx, &enemy, now);
                 ctx.db.enemy().delete(enemy);
             } else {
                 // Physics-based knockback: insert an impulse for the next game_tick
@@ -148,15 +267,23 @@ pub fn attack_hit(ctx: &spacetimedb::ReducerContext) {
 
                 ctx.db.enemy().id().update(Enemy {
                     health: hit.new_health,
+                    shield: hit.new_shield,
+                    last_hit_time: now,
+                    last_hit_by: Some(attacker.identity),
                     ..enemy
                 });
             }
         }
     }
 
+    if output.hits.iter().any(|h| h.died) {
+        end_match_if_cleared(ctx, &attacker.world_id);
+    }
+
     // Update attacker state
     let new_stacks = output.attacker_stats.get(&Stat::Custom("Stacks".into()));
     let new_speed = output.attacker_stats.get(&Stat::AttackSpeed);
+    let heal = output.attacker_stats.get(&lifesteal::heal_stat());
 
     // Persist stacking buff to active_effect
     if new_stacks > 0.0 || stacking_effect.is_some() {
@@ -174,20 +301,147 @@ pub fn attack_hit(ctx: &spacetimedb::ReducerContext) {
             ctx.db.active_effect().insert(ActiveEffect {
                 id: 0,
                 owner: ctx.sender,
-                effect_type: "stacking_damage".to_string(),
+                kind: EffectKind::StackingDamage.as_u8(),
+                name: String::new(),
                 magnitude: new_stacks,
                 duration: -1.0,
                 timestamp: now,
             });
         }
     }
+    apply_statuses(ctx, attacker.identity, &output.statuses, now);
 
-    ctx.db.player().identity().update(Player {
+    // Kills above may have leveled the attacker up — update the current row,
+    // not the snapshot taken before the swing.
+    let attacker = ctx
+        .db
+        .player()
+        .identity()
+        .find(ctx.sender)
+        .unwrap_or(attacker);
+    let health = if attacker.health > 0.0 {
+        (attacker.health + heal).min(attacker.max_health)
+    } else {
+        attacker.health
+    };
+    let attacker = ctx.db.player().identity().update(Player {
         last_attack_time: now,
         attack_speed: new_speed,
+        health,
         last_update: now,
         ..attacker
     });
+
+    // What the attacker's rules spawned goes off where each hit landed
+    for hit in output.hits.iter().filter(|h| !h.spawns.is_empty()) {
+        let at = match hit
+            .target_id
+            .checked_sub(PLAYER_TARGET_BASE)
+            .and_then(|i| pvp_targets.get(i as usize))
+        {
+            Some(victim) => (victim.x, victim.y, victim.z),
+            None => enemy_targets
+                .iter()
+                .find(|e| e.id == hit.target_id)
+                .map_or((attacker.x, attacker.y, attacker.z), |e| (e.x, e.y, e.z)),
+        };
+        spawn_requested(ctx, &attacker, at, &hit.spawns, now);
+    }
+}
+
+/// Apply a PvP hit. Damage goes to the victim's shield and health; knockback rides
+/// on the combat event for the victim's client to play out. PvP kills count
+/// on the leaderboard, but not toward match stats or XP.
+fn apply_player_hit(
+    ctx: &spacetimedb::ReducerContext,
+    attacker: &Player,
+    victim: &Player,
+    hit: &HitResult,
+    forward: glam::Vec2,
+    now: i64,
+) {
+    let radial = glam::Vec2::new(victim.x - attacker.x, victim.z - attacker.z);
+    let disp = if hit.died {
+        glam::Vec3::ZERO
+    } else {
+        knockback_displacement(
+            radial.normalize_or(forward),
+            forward,
+            hit.knockback,
+            hit.push,
+            hit.launch,
+        )
+    };
+
+    let event = ctx.db.combat_event().insert(CombatEvent {
+        id: 0,
+        x: victim.x,
+        y: victim.y,
+        z: victim.z,
+        damage: hit.damage,
+        is_crit: hit.is_crit,
+        attacker: attacker.identity,
+        target_id: 0,
+        target_player: Some(victim.identity),
+        knockback_x: disp.x,
+        knockback_y: disp.y,
+        knockback_z: disp.z,
+        reaction: HitReaction::from_damage(hit.damage, victim.max_health)
+            .as_str()
+            .to_string(),
+        world_id: attacker.world_id.clone(),
+        timestamp: now,
+    });
+    log_hit(ctx, &event);
+
+    if let Some(current) = ctx.db.player().identity().find(victim.identity) {
+        let current_health = current.health;
+        let victim = ctx.db.player().identity().update(Player {
+            health: hit.new_health,
+            shield: hit.new_shield,
+            last_hit_time: now,
+            ..current
+        });
+        tally_hit(ctx, attacker, hit.damage, hit.died);
+        health_dropped(ctx, &victim, current_health, now);
+        if hit.died {
+            record_death(ctx, &victim, Some(attacker.identity), now);
+        }
+    }
+}
+
+/// Opt the sender in or out of player-vs-player combat.
+#[spacetimedb::reducer]
+pub fn set_pvp(ctx: &spacetimedb::ReducerContext, enabled: bool) -> Result<(), String> {
+    let Some(player) = ctx.db.player().identity().find(ctx.sender) else {
+        return Err("Not in a game".into());
+    };
+    ctx.db.player().identity().update(Player {
+        pvp: enabled,
+        ..player
+    });
+    Ok(())
+}
+
+/// Called from `game_tick`: recharge the shields of live, online players the
+/// way enemy shields recharge. Rows only change while a shield is refilling.
+pub(crate) fn regen_player_shields(ctx: &spacetimedb::ReducerContext, now: i64, dt: f32) {
+    let recharging: Vec<Player> = ctx
+        .db
+        .player()
+        .iter()
+        .filter(|p| p.online && p.health > 0.0 && p.shield < p.max_shield)
+        .collect();
+    for player in recharging {
+        let secs_since_hit = (now - player.last_hit_time) as f32 / 1_000_000.0;
+        let shield = combat::regen_shield(player.shield, player.max_shield, secs_since_hit, dt);
+        if shield != player.shield {
+            ctx.db
+                .player()
+                .identity()
+                .update(Player { shield, ..player });
+        }
+    }
 }
 
 // ── Ground Pound AOE ─────────────────────────────────────────────
@@ -195,12 +449,13 @@ pub fn attack_hit(ctx: &spacetimedb::ReducerContext) {
 /// Server-authoritative ground pound AOE. Client sends impact position.
 #[spacetimedb::reducer]
 pub fn ground_pound_hit(ctx: &spacetimedb::ReducerContext, x: f32, y: f32, z: f32) {
+    count_call(ctx, "ground_pound_hit");
     use combat::ground_pound as gp;
 
     let Some(attacker) = ctx.db.player().identity().find(ctx.sender) else {
         return;
     };
-    if attacker.health <= 0.0 {
+    if attacker.health <= 0.0 || !impairment(ctx, attacker.identity).can_attack() {
         return;
     }
 
@@ -218,12 +473,13 @@ pub fn landing_aoe_hit(
     y: f32,
     z: f32,
 ) {
+    count_call(ctx, "landing_aoe_hit");
     use combat::landing_aoe;
 
     let Some(attacker) = ctx.db.player().identity().find(ctx.sender) else {
         return;
     };
-    if attacker.health <= 0.0 {
+    if attacker.health <= 0.0 || !impairment(ctx, attacker.identity).can_attack() {
         return;
     }
 
@@ -237,7 +493,7 @@ pub fn landing_aoe_hit(
 
 // ── Shared AOE helper ────────────────────────────────────────────
 
-fn aoe_hit(
+pub(crate) fn aoe_hit(
     ctx: &spacetimedb::ReducerContext,
     attacker: &Player,
     impact_x: f32,
@@ -249,7 +505,7 @@ fn aoe_hit(
     damage_multiplier: f32,
 ) {
     let now = ctx.timestamp.to_micros_since_unix_epoch();
-    let rules = presets::default_player_rules();
+    let rules = player_rules(ctx, attacker.identity);
 
     let base_damage = if attacker.attack_damage > 0.0 {
         attacker.attack_damage
@@ -257,8 +513,10 @@ fn aoe_hit(
         defaults::ATTACK_DAMAGE
     };
 
+    let damage_scale = Tuning::load(ctx).player_damage_scale;
+
     let attacker_stats = Stats::new()
-        .with(Stat::AttackDamage, base_damage * damage_multiplier)
+        .with(Stat::AttackDamage, base_damage * damage_multiplier * damage_scale)
         .with(Stat::CritChance, attacker.crit_chance)
         .with(Stat::CritMultiplier, attacker.crit_multiplier)
         .with(Stat::Knockback, kb);
@@ -285,19 +543,23 @@ fn aoe_hit(
         return;
     }
 
-    let hit_targets: Vec<HitTarget> = enemy_targets
+    let mut hit_targets: Vec<HitTarget> = enemy_targets
         .iter()
         .map(|e| HitTarget {
             id: e.id,
+            seed: e.id,
             pos: glam::Vec2::new(e.x, e.z),
             health: e.health,
+            max_health: e.max_health,
+            shield: e.shield,
+            armor: 0.0,
         })
         .collect();
 
     let origin_xz = glam::Vec2::new(impact_x, impact_z);
     let forward_xz = glam::Vec2::new(1.0, 0.0); // direction irrelevant for 360° AOE
 
-    let output = resolve_combat(&CombatInput {
+    let mut output = resolve_combat(&CombatInput {
         origin: origin_xz,
         forward: forward_xz,
         base_range: radius,
@@ -307,7 +569,30 @@ fn aoe_hit(
         rng_seed: now as u64,
         targets: &hit_targets,
     });
+    ScriptHost::new(ctx).on_hits(
+        attacker.identity,
+        &attacker_stats,
+        &hit_targets,
+        &mut output.hits,
+    );
+
+    apply_area_hits(
+        ctx, attacker, &output, origin_xz, forward_xz, kb, launch, now,
+    );
+}
 
+/// Write the results of a hit that pushes targets away from `center` —
+/// damage, deaths, combat events for VFX and knockback impulses.
+pub(crate) fn apply_area_hits(
+    ctx: &spacetimedb::ReducerContext,
+    attacker: &Player,
+    output: &CombatOutput,
+    center: glam::Vec2,
+    forward: glam::Vec2,
+    kb: f32,
+    launch: f32,
+    now: i64,
+) {
     let enemy_mass = 50.0_f32;
 
     for hit in &output.hits {
@@ -315,22 +600,35 @@ fn aoe_hit(
             continue;
         };
 
-        ctx.db.combat_event().insert(CombatEvent {
+        let event = ctx.db.combat_event().insert(CombatEvent {
             id: 0,
             x: enemy.x,
             y: enemy.y,
             z: enemy.z,
             damage: hit.damage,
             is_crit: hit.is_crit,
+            attacker: attacker.identity,
+            target_id: enemy.id,
+            target_player: None,
+            knockback_x: 0.0,
+            knockback_y: 0.0,
+            knockback_z: 0.0,
+            reaction: HitReaction::from_damage(hit.damage, enemy.max_health)
+                .as_str()
+                .to_string(),
             world_id: attacker.world_id.clone(),
             timestamp: now,
         });
+        log_hit(ctx, &event);
+        record_hit(ctx, attacker, hit);
+        add_threat(ctx, enemy.id, attacker.identity, hit.damage);
 
         if hit.died {
+            drop_loot(ctx, &enemy, now);
             ctx.db.enemy().delete(enemy);
         } else {
-            let radial = glam::Vec2::new(enemy.x - impact_x, enemy.z - impact_z);
-            let radial_dir = radial.normalize_or(forward_xz);
+            let radial = glam::Vec2::new(enemy.x, enemy.z) - center;
+            let radial_dir = radial.normalize_or(forward);
             let disp = knockback_displacement(radial_dir, radial_dir, kb, 0.0, launch);
 
             ctx.db.knockback_impulse().insert(KnockbackImpulse {
@@ -344,8 +642,16 @@ fn aoe_hit(
 
             ctx.db.enemy().id().update(Enemy {
                 health: hit.new_health,
+                shield: hit.new_shield,
+                last_hit_time: now,
+                last_hit_by: Some(attacker.identity),
                 ..enemy
             });
         }
     }
+
+    if output.hits.iter().any(|h| h.died) {
+        end_match_if_cleared(ctx, &attacker.world_id);
+    }
+    apply_statuses(ctx, attacker.identity, &output.statuses, now);
 }
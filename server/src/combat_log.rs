@@ -0,0 +1,47 @@
+//! Condensed, long-lived record of every hit. `combat_event` rows only live
+//! long enough for clients to play their VFX; `combat_log` keeps the last
+//! [`CAPACITY`] hits across all worlds for post-match review and replay,
+//! dropping the oldest as new ones come in.
+
+use spacetimedb::Table;
+
+use crate::schema::*;
+
+/// Hits kept before the oldest are dropped.
+pub const CAPACITY: u64 = 20_000;
+/// Hits allowed past [`CAPACITY`] before a prune, so the oldest rows are
+/// dropped in batches rather than one per hit.
+const PRUNE_BATCH: u64 = 500;
+
+/// Append the hit `event` records. Events that don't deal damage (deaths,
+/// respawns) aren't hits and are skipped.
+pub(crate) fn log_hit(ctx: &spacetimedb::ReducerContext, event: &CombatEvent) {
+    if event.damage <= 0.0 {
+        return;
+    }
+    ctx.db.combat_log().insert(CombatLog {
+        id: 0,
+        world_id: event.world_id.clone(),
+        attacker: event.attacker,
+        target_id: event.target_id,
+        target_player: event.target_player,
+        damage: event.damage,
+        is_crit: event.is_crit,
+        timestamp: event.timestamp,
+    });
+
+    let len = ctx.db.combat_log().count();
+    if len <= CAPACITY + PRUNE_BATCH {
+        return;
+    }
+    let oldest: Vec<CombatLog> = ctx
+        .db
+        .combat_log()
+        .timestamp()
+        .filter(i64::MIN..)
+        .take((len - CAPACITY) as usize)
+        .collect();
+    for entry in oldest {
+        ctx.db.combat_log().id().delete(entry.id);
+    }
+}
@@ -0,0 +1,205 @@
+//! Balance values that can be tuned on a live deployment. Each one is a row
+//! of `server_config` named after its [`ConfigKey`]; keys without a row use
+//! their compile-time default. Reducers read them all at once with
+//! [`Tuning::load`].
+
+use spacetimedb::{Table, TimeDuration};
+use wasm_fantasia_shared::combat::defaults;
+
+use crate::archetypes::is_admin;
+use crate::schema::*;
+use crate::TICK_INTERVAL_MICROS;
+
+/// A tunable value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigKey {
+    /// Microseconds between `game_tick`s.
+    TickIntervalMicros,
+    /// Spawn ring radii around the spawn point, in meters.
+    EnemySpawnRadiusMin,
+    EnemySpawnRadiusMax,
+    /// Enemies within this radius push each other apart.
+    EnemySeparationRadius,
+    /// Separation speed in m/s.
+    EnemySeparationStrength,
+    /// Rate knockback decays into AI movement.
+    EnemyVelocityDamping,
+    /// Seconds without a hit before attack stacks start decaying.
+    StackDecay,
+    /// Multiplier on the damage of player attacks and landing slams.
+    PlayerDamageScale,
+    /// A void-out counts as a kill for whoever hit the victim within this
+    /// many seconds.
+    KillCreditWindowSecs,
+}
+
+impl ConfigKey {
+    pub const ALL: [Self; 9] = [
+        Self::TickIntervalMicros,
+        Self::EnemySpawnRadiusMin,
+        Self::EnemySpawnRadiusMax,
+        Self::EnemySeparationRadius,
+        Self::EnemySeparationStrength,
+        Self::EnemyVelocityDamping,
+        Self::StackDecay,
+        Self::PlayerDamageScale,
+        Self::KillCreditWindowSecs,
+    ];
+
+    /// Convert to the key stored in `server_config.key`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::TickIntervalMicros => "tick_interval_micros",
+            Self::EnemySpawnRadiusMin => "enemy_spawn_radius_min",
+            Self::EnemySpawnRadiusMax => "enemy_spawn_radius_max",
+            Self::EnemySeparationRadius => "enemy_separation_radius",
+            Self::EnemySeparationStrength => "enemy_separation_strength",
+            Self::EnemyVelocityDamping => "enemy_velocity_damping",
+            Self::StackDecay => "stack_decay",
+            Self::PlayerDamageScale => "player_damage_scale",
+            Self::KillCreditWindowSecs => "kill_credit_window_secs",
+        }
+    }
+
+    pub fn parse_str(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|k| k.as_str() == s)
+    }
+
+    /// Value without a `server_config` row.
+    pub fn default_value(self) -> f32 {
+        match self {
+            Self::TickIntervalMicros => TICK_INTERVAL_MICROS as f32,
+            Self::EnemySpawnRadiusMin => defaults::ENEMY_SPAWN_RADIUS_MIN,
+            Self::EnemySpawnRadiusMax => defaults::ENEMY_SPAWN_RADIUS_MAX,
+            Self::EnemySeparationRadius => defaults::ENEMY_SEPARATION_RADIUS,
+            Self::EnemySeparationStrength => defaults::ENEMY_SEPARATION_STRENGTH,
+            Self::EnemyVelocityDamping => defaults::ENEMY_VELOCITY_DAMPING,
+            Self::StackDecay => defaults::STACK_DECAY,
+            Self::PlayerDamageScale => 1.0,
+            Self::KillCreditWindowSecs => defaults::KILL_CREDIT_WINDOW_SECS,
+        }
+    }
+
+    /// Values `set_config` accepts, so a typo can't stall the tick or
+    /// one-shot everything.
+    fn range(self) -> (f32, f32) {
+        match self {
+            // 100 down to 5 ticks a second
+            Self::TickIntervalMicros => (10_000.0, 200_000.0),
+            Self::EnemySpawnRadiusMin | Self::EnemySpawnRadiusMax => {
+                (0.0, defaults::ARENA_HALF_SIZE)
+            }
+            Self::EnemySeparationRadius => (0.0, 10.0),
+            Self::EnemySeparationStrength => (0.0, 20.0),
+            Self::EnemyVelocityDamping => (0.0, 60.0),
+            Self::StackDecay => (0.0, 60.0),
+            Self::PlayerDamageScale => (0.0, 10.0),
+            Self::KillCreditWindowSecs => (0.0, 60.0),
+        }
+    }
+}
+
+/// Every [`ConfigKey`]'s current value.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Tuning {
+    pub tick_interval_micros: i64,
+    pub enemy_spawn_radius_min: f32,
+    pub enemy_spawn_radius_max: f32,
+    pub enemy_separation_radius: f32,
+    pub enemy_separation_strength: f32,
+    pub enemy_velocity_damping: f32,
+    pub stack_decay: f32,
+    pub player_damage_scale: f32,
+    pub kill_credit_window_secs: f32,
+}
+
+impl Tuning {
+    pub(crate) fn load(ctx: &spacetimedb::ReducerContext) -> Self {
+        let get = |key: ConfigKey| {
+            ctx.db
+                .server_config()
+                .key()
+                .find(key.as_str().to_string())
+                .map_or(key.default_value(), |row| row.value)
+        };
+        Self {
+            tick_interval_micros: get(ConfigKey::TickIntervalMicros) as i64,
+            enemy_spawn_radius_min: get(ConfigKey::EnemySpawnRadiusMin),
+            enemy_spawn_radius_max: get(ConfigKey::EnemySpawnRadiusMax),
+            enemy_separation_radius: get(ConfigKey::EnemySeparationRadius),
+            enemy_separation_strength: get(ConfigKey::EnemySeparationStrength),
+            enemy_velocity_damping: get(ConfigKey::EnemyVelocityDamping),
+            stack_decay: get(ConfigKey::StackDecay),
+            player_damage_scale: get(ConfigKey::PlayerDamageScale),
+            kill_credit_window_secs: get(ConfigKey::KillCreditWindowSecs),
+        }
+    }
+
+    /// Seconds between `game_tick`s.
+    pub(crate) fn tick_dt(&self) -> f32 {
+        self.tick_interval_micros as f32 / 1_000_000.0
+    }
+}
+
+/// Replace the tick schedule with one firing every `interval_micros`.
+pub(crate) fn schedule_tick(ctx: &spacetimedb::ReducerContext, interval_micros: i64) {
+    for row in ctx.db.tick_schedule().iter().collect::<Vec<_>>() {
+        ctx.db.tick_schedule().delete(row);
+    }
+    ctx.db.tick_schedule().insert(TickSchedule {
+        scheduled_id: 0,
+        scheduled_at: TimeDuration::from_micros(interval_micros).into(),
+    });
+}
+
+/// Set a [`ConfigKey`] by name. Takes effect from the next reducer that
+/// reads it; a new tick interval reschedules `game_tick` right away. Admin
+/// only.
+#[spacetimedb::reducer]
+pub fn set_config(
+    ctx: &spacetimedb::ReducerContext,
+    key: String,
+    value: f32,
+) -> Result<(), String> {
+    if !is_admin(ctx) {
+        return Err("Only admins can change the server config".into());
+    }
+    let Some(config_key) = ConfigKey::parse_str(&key) else {
+        return Err(format!("Unknown config key '{key}'"));
+    };
+    let (min, max) = config_key.range();
+    if !value.is_finite() || value < min || value > max {
+        return Err(format!("{key} must be between {min} and {max}"));
+    }
+
+    let row = ServerConfig {
+        key: key.clone(),
+        value,
+        updated_at: ctx.timestamp.to_micros_since_unix_epoch(),
+    };
+    if ctx.db.server_config().key().find(&key).is_some() {
+        ctx.db.server_config().key().update(row);
+    } else {
+        ctx.db.server_config().insert(row);
+    }
+    if config_key == ConfigKey::TickIntervalMicros {
+        schedule_tick(ctx, value as i64);
+    }
+    spacetimedb::log::info!("Config {key} set to {value} by {}", ctx.sender);
+    Ok(())
+}
+
+/// Put a [`ConfigKey`] back to its compile-time default. Admin only.
+#[spacetimedb::reducer]
+pub fn reset_config(ctx: &spacetimedb::ReducerContext, key: String) -> Result<(), String> {
+    if !is_admin(ctx) {
+        return Err("Only admins can change the server config".into());
+    }
+    let Some(config_key) = ConfigKey::parse_str(&key) else {
+        return Err(format!("Unknown config key '{key}'"));
+    };
+    if ctx.db.server_config().key().delete(&key) && config_key == ConfigKey::TickIntervalMicros {
+        schedule_tick(ctx, TICK_INTERVAL_MICROS);
+    }
+    Ok(())
+}
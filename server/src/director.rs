@@ -0,0 +1,233 @@
+use spacetimedb::Table;
+use std::collections::HashMap;
+
+use crate::archetypes::{configured_basic, find_archetype};
+use crate::enemy_ai::spawn_ring;
+use crate::matches::{end_match_if_cleared, start_match};
+use crate::schema::*;
+
+/// One wave: which archetype it sends, how many in total, and how fast.
+struct WaveDef {
+    /// Falls back to `basic` if no archetype of this name exists.
+    archetype: &'static str,
+    /// Enemies the wave spawns before it can be cleared.
+    budget: u32,
+    /// Enemies per spawn.
+    group_size: u32,
+    /// Seconds between spawns.
+    spawn_interval_secs: f32,
+}
+
+const WAVES: [WaveDef; 5] = [
+    WaveDef {
+        archetype: "basic",
+        budget: 12,
+        group_size: 3,
+        spawn_interval_secs: 3.0,
+    },
+    WaveDef {
+        archetype: "basic",
+        budget: 24,
+        group_size: 4,
+        spawn_interval_secs: 2.5,
+    },
+    WaveDef {
+        archetype: "brute",
+        budget: 8,
+        group_size: 2,
+        spawn_interval_secs: 4.0,
+    },
+    WaveDef {
+        archetype: "basic",
+        budget: 40,
+        group_size: 6,
+        spawn_interval_secs: 2.0,
+    },
+    WaveDef {
+        archetype: "brute",
+        budget: 20,
+        group_size: 4,
+        spawn_interval_secs: 3.0,
+    },
+];
+
+/// Each wave past the first adds this much to enemy health and damage.
+const DIFFICULTY_PER_WAVE: f32 = 0.15;
+/// Past the defined waves the last one repeats, its budget growing by this
+/// fraction per extra wave.
+const EXTRA_BUDGET_PER_WAVE: f32 = 0.25;
+/// Spawns pause while a world has this many enemies alive.
+const MAX_ALIVE: usize = 60;
+/// Breather between clearing a wave and the next one starting.
+const WAVE_BREAK_SECS: f32 = 6.0;
+
+fn secs_to_micros(secs: f32) -> i64 {
+    (secs * 1_000_000.0) as i64
+}
+
+/// Definition of `wave` (counting from 1), and how many waves past the
+/// defined ones it is.
+fn wave_def(wave: u32) -> (&'static WaveDef, u32) {
+    let index = (wave.max(1) - 1) as usize;
+    let last = WAVES.len() - 1;
+    (&WAVES[index.min(last)], index.saturating_sub(last) as u32)
+}
+
+fn wave_budget(wave: u32) -> u32 {
+    let (def, extra) = wave_def(wave);
+    (def.budget as f32 * (1.0 + EXTRA_BUDGET_PER_WAVE * extra as f32)).round() as u32
+}
+
+/// The wave's archetype with health and damage scaled for its difficulty.
+fn wave_archetype(ctx: &spacetimedb::ReducerContext, wave: u32) -> EnemyArchetype {
+    let (def, _) = wave_def(wave);
+    let archetype = find_archetype(ctx, def.archetype).unwrap_or_else(|| configured_basic(ctx));
+    let scale = 1.0 + DIFFICULTY_PER_WAVE * (wave.max(1) - 1) as f32;
+    EnemyArchetype {
+        max_health: archetype.max_health * scale,
+        max_shield: archetype.max_shield * scale,
+        attack_damage: archetype.attack_damage * scale,
+        ..archetype
+    }
+}
+
+/// Start a match in `world_id` fed by a director from wave 1, replacing any
+/// director already running there.
+pub(crate) fn start_director_in(
+    ctx: &spacetimedb::ReducerContext,
+    world_id: &str,
+    x: f32,
+    y: f32,
+    z: f32,
+) {
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    start_match(ctx, world_id, x, y, z);
+    ctx.db
+        .spawn_director()
+        .world_id()
+        .delete(world_id.to_string());
+    ctx.db.spawn_director().insert(SpawnDirector {
+        world_id: world_id.to_string(),
+        wave: 1,
+        budget: wave_budget(1),
+        next_spawn_at: now,
+        started_at: now,
+    });
+}
+
+/// Whether a director is feeding `world_id`, so its match isn't over
+/// between spawns.
+pub(crate) fn director_running(ctx: &spacetimedb::ReducerContext, world_id: &str) -> bool {
+    ctx.db
+        .spawn_director()
+        .world_id()
+        .find(world_id.to_string())
+        .is_some()
+}
+
+/// Start spawning waves in the sender's world around where they stand.
+#[spacetimedb::reducer]
+pub fn start_director(ctx: &spacetimedb::ReducerContext) -> Result<(), String> {
+    let Some(player) = ctx.db.player().identity().find(ctx.sender) else {
+        return Err("Not in a game".into());
+    };
+    if director_running(ctx, &player.world_id) {
+        return Err("Waves are already running in this world".into());
+    }
+    start_director_in(ctx, &player.world_id, player.x, player.y, player.z);
+    Ok(())
+}
+
+/// Stop spawning waves in the sender's world. Enemies already out stay; the
+/// match ends once they're cleared.
+#[spacetimedb::reducer]
+pub fn stop_director(ctx: &spacetimedb::ReducerContext) -> Result<(), String> {
+    let Some(player) = ctx.db.player().identity().find(ctx.sender) else {
+        return Err("Not in a game".into());
+    };
+    if !director_running(ctx, &player.world_id) {
+        return Err("No waves running in this world".into());
+    }
+    ctx.db.spawn_director().world_id().delete(&player.world_id);
+    end_match_if_cleared(ctx, &player.world_id);
+    Ok(())
+}
+
+/// Called from `game_tick`: spawn the next group of each director's wave
+/// near one of the world's living players, and move on to the next wave once
+/// the current one is spent and cleared. Worlds that are paused or have
+/// nobody alive hold their wave.
+pub(crate) fn tick_directors(
+    ctx: &spacetimedb::ReducerContext,
+    now: i64,
+    players_by_world: &HashMap<String, Vec<Player>>,
+    enemies_by_world: &HashMap<String, Vec<Enemy>>,
+) {
+    let directors: Vec<SpawnDirector> = ctx
+        .db
+        .spawn_director()
+        .iter()
+        .filter(|d| now >= d.next_spawn_at)
+        .collect();
+    for director in directors {
+        if ctx
+            .db
+            .world_pause()
+            .world_id()
+            .find(&director.world_id)
+            .is_some()
+        {
+            continue;
+        }
+        let Some(players) = players_by_world.get(&director.world_id) else {
+            continue;
+        };
+        let alive = enemies_by_world
+            .get(&director.world_id)
+            .map_or(0, |enemies| enemies.len());
+
+        if director.budget == 0 {
+            if alive == 0 {
+                let wave = director.wave + 1;
+                spacetimedb::log::info!("World {} starts wave {wave}", director.world_id);
+                ctx.db.spawn_director().world_id().update(SpawnDirector {
+                    wave,
+                    budget: wave_budget(wave),
+                    next_spawn_at: now + secs_to_micros(WAVE_BREAK_SECS),
+                    ..director
+                });
+            }
+            continue;
+        }
+
+        let (def, _) = wave_def(director.wave);
+        let next_spawn_at = now + secs_to_micros(def.spawn_interval_secs);
+        if alive >= MAX_ALIVE {
+            ctx.db.spawn_director().world_id().update(SpawnDirector {
+                next_spawn_at,
+                ..director
+            });
+            continue;
+        }
+
+        let count = def
+            .group_size
+            .min(director.budget)
+            .min((MAX_ALIVE - alive) as u32);
+        let target = &players[(now as u64 % players.len() as u64) as usize];
+        spawn_ring(
+            ctx,
+            &director.world_id,
+            &wave_archetype(ctx, director.wave),
+            count,
+            target.x,
+            target.y,
+            target.z,
+        );
+        ctx.db.spawn_director().world_id().update(SpawnDirector {
+            budget: director.budget - count,
+            next_spawn_at,
+            ..director
+        });
+    }
+}
@@ -0,0 +1,199 @@
+use spacetimedb::Table;
+use wasm_fantasia_shared::combat::HitReaction;
+use wasm_fantasia_shared::effects::{
+    haste_multiplier, refreshed_duration, ticks_due, EffectKind, Impairment, PeriodicEffect,
+};
+use wasm_fantasia_shared::rules::StatusGrant;
+
+use crate::combat_log::log_hit;
+use crate::lifecycle::record_death;
+use crate::schema::*;
+use crate::scripting::ScriptHost;
+use crate::triggers::health_dropped;
+
+/// Called from `game_tick`: count every effect's duration down by `dt`,
+/// apply the ticks periodic effects owe their owners, run scripted effects'
+/// tick, and delete the effects that ran out. Effects with a negative
+/// duration never run out. Owners who are offline or dead take no ticks, but
+/// their effects still run down.
+pub(crate) fn tick_effects(ctx: &spacetimedb::ReducerContext, now: i64, dt: f32) {
+    let mut scripts = ScriptHost::new(ctx);
+    let effects: Vec<ActiveEffect> = ctx.db.active_effect().iter().collect();
+    for mut effect in effects {
+        let kind = EffectKind::from_u8(effect.kind);
+        let scripted = kind == Some(EffectKind::Scripted) && scripts.has_script(&effect.name);
+        if scripted {
+            if let Some(owner) = live_owner(ctx, &effect) {
+                if let Some((change, magnitude)) =
+                    scripts.on_tick(&effect, owner.health, owner.max_health, dt)
+                {
+                    effect.magnitude = magnitude;
+                    apply_change(ctx, &effect, owner, change, now);
+                }
+            }
+        } else if let Some(EffectKind::Periodic(periodic)) = kind {
+            // Only the part of this frame the effect was still running for
+            let before = (now - effect.timestamp) as f32 / 1_000_000.0 - dt;
+            let step = if effect.duration >= 0.0 {
+                dt.min(effect.duration)
+            } else {
+                dt
+            };
+            let ticks = ticks_due(before, before + step, periodic.interval_secs());
+            for _ in 0..ticks {
+                apply_tick(ctx, &effect, periodic, now);
+            }
+        }
+
+        if effect.duration < 0.0 {
+            // Scripts may have changed the magnitude
+            if scripted {
+                ctx.db.active_effect().id().update(effect);
+            }
+            continue;
+        }
+        let duration = effect.duration - dt;
+        if duration <= 0.0 {
+            ctx.db.active_effect().id().delete(effect.id);
+        } else {
+            ctx.db
+                .active_effect()
+                .id()
+                .update(ActiveEffect { duration, ..effect });
+        }
+    }
+}
+
+/// Give `owner` the timed effects their rules granted. One they already have
+/// takes the new magnitude and keeps the longer duration.
+pub(crate) fn apply_statuses(
+    ctx: &spacetimedb::ReducerContext,
+    owner: spacetimedb::Identity,
+    statuses: &[StatusGrant],
+    now: i64,
+) {
+    for status in statuses {
+        let (kind, name) = status.kind();
+        let existing = ctx
+            .db
+            .active_effect()
+            .owner()
+            .filter(owner)
+            .find(|e| e.kind == kind.as_u8() && e.name == name);
+        if let Some(effect) = existing {
+            ctx.db.active_effect().id().update(ActiveEffect {
+                magnitude: status.magnitude,
+                duration: refreshed_duration(effect.duration, status.duration),
+                ..effect
+            });
+        } else {
+            ctx.db.active_effect().insert(ActiveEffect {
+                id: 0,
+                owner,
+                kind: kind.as_u8(),
+                name: name.to_string(),
+                magnitude: status.magnitude,
+                duration: status.duration,
+                timestamp: now,
+            });
+        }
+    }
+}
+
+/// What `owner`'s control effects stop them doing.
+pub(crate) fn impairment(
+    ctx: &spacetimedb::ReducerContext,
+    owner: spacetimedb::Identity,
+) -> Impairment {
+    Impairment::resolve(
+        ctx.db
+            .active_effect()
+            .owner()
+            .filter(owner)
+            .filter_map(|e| Some((EffectKind::from_u8(e.kind)?, e.magnitude))),
+    )
+}
+
+/// How much faster `owner`'s hastes make their attacks.
+pub(crate) fn haste(ctx: &spacetimedb::ReducerContext, owner: spacetimedb::Identity) -> f32 {
+    haste_multiplier(
+        ctx.db
+            .active_effect()
+            .owner()
+            .filter(owner)
+            .filter_map(|e| Some((EffectKind::from_u8(e.kind)?, e.magnitude))),
+    )
+}
+
+/// The effect's owner, if they're online and alive to be affected.
+fn live_owner(ctx: &spacetimedb::ReducerContext, effect: &ActiveEffect) -> Option<Player> {
+    ctx.db
+        .player()
+        .identity()
+        .find(effect.owner)
+        .filter(|owner| owner.online && owner.health > 0.0)
+}
+
+/// One tick of `periodic` on the effect's owner.
+fn apply_tick(
+    ctx: &spacetimedb::ReducerContext,
+    effect: &ActiveEffect,
+    periodic: PeriodicEffect,
+    now: i64,
+) {
+    let Some(owner) = live_owner(ctx, effect) else {
+        return;
+    };
+    let change = periodic.tick_amount(effect.magnitude, owner.health, owner.max_health);
+    apply_change(ctx, effect, owner, change, now);
+}
+
+/// Change the owner's health by `change` from their effect, with a combat
+/// event for damage and a death if it kills them.
+fn apply_change(
+    ctx: &spacetimedb::ReducerContext,
+    effect: &ActiveEffect,
+    owner: Player,
+    change: f32,
+    now: i64,
+) {
+    if change == 0.0 {
+        return;
+    }
+    let health = (owner.health + change).clamp(0.0, owner.max_health);
+    let died = health <= 0.0;
+
+    if change < 0.0 {
+        let event = ctx.db.combat_event().insert(CombatEvent {
+            id: 0,
+            x: owner.x,
+            y: owner.y,
+            z: owner.z,
+            damage: -change,
+            is_crit: false,
+            attacker: ctx.identity(),
+            target_id: 0,
+            target_player: Some(owner.identity),
+            knockback_x: 0.0,
+            knockback_y: 0.0,
+            knockback_z: 0.0,
+            reaction: HitReaction::from_damage(-change, owner.max_health)
+                .as_str()
+                .to_string(),
+            world_id: owner.world_id.clone(),
+            timestamp: now,
+        });
+        log_hit(ctx, &event);
+    }
+
+    let before = owner.health;
+    let owner = ctx
+        .db
+        .player()
+        .identity()
+        .update(Player { health, ..owner });
+    health_dropped(ctx, &owner, before, now);
+    if died {
+        record_death(ctx, &owner, None, now);
+    }
+}
@@ -1,12 +1,28 @@
 use avian3d::prelude::*;
-use spacetimedb::Table;
+use spacetimedb::{Identity, Table};
 use std::collections::HashMap;
-use wasm_fantasia_shared::combat::{self, defaults, enemy_ai_decision};
-
+use wasm_fantasia_shared::combat::{self, defaults, enemy_ai_decision_in_range, EnemyTickTier};
+use wasm_fantasia_shared::spatial::SpatialGrid;
+
+use crate::admin::in_arena;
+use crate::archetypes::{configured_basic, find_archetype};
+use crate::boss::{is_casting, prune_bosses, tick_bosses};
+use crate::combat::regen_player_shields;
+use crate::config::Tuning;
+use crate::director::{start_director_in, tick_directors};
+use crate::effects::tick_effects;
+use crate::matches::{abort_match, end_match_if_cleared, record_kill, start_match};
+use crate::metrics::record_tick;
+use crate::movement::{input_relay, tick_player_inputs};
+use crate::rate_limit;
+use crate::rewind::{prune_history, record_position};
 use crate::schema::*;
-use crate::TICK_INTERVAL_MICROS;
+use crate::threat::{current_threat, top_threat};
+use crate::triggers::{tick_auras, tick_timers};
+use crate::world_events::tick_world_events;
 
-/// Spawn a pack of enemies at the given position and facing direction.
+/// Start a match at the given position, fed in waves by the spawn director.
+/// Restarts from wave 1 if one is already running.
 #[spacetimedb::reducer]
 pub fn spawn_enemies(
     ctx: &spacetimedb::ReducerContext,
@@ -16,47 +32,132 @@ pub fn spawn_enemies(
     _forward_x: f32,
     _forward_z: f32,
 ) {
+    if !rate_limit::allow(ctx, "spawn_enemies") {
+        return;
+    }
     let Some(player) = ctx.db.player().identity().find(ctx.sender) else {
         return;
     };
 
-    let world_id = player.world_id;
+    start_director_in(ctx, &player.world_id, x, y, z);
+}
 
-    // Per-enemy scatter using hash that varies meaningfully per index
+/// Spawn a pack of the named archetype at the given position.
+#[spacetimedb::reducer]
+pub fn spawn_archetype(
+    ctx: &spacetimedb::ReducerContext,
+    archetype: String,
+    x: f32,
+    y: f32,
+    z: f32,
+) -> Result<(), String> {
+    if !rate_limit::allow(ctx, "spawn_archetype") {
+        return Err("Too many spawns, try again shortly".into());
+    }
+    if !in_arena(x, y, z) {
+        return Err("Position is outside the arena".into());
+    }
+    let Some(player) = ctx.db.player().identity().find(ctx.sender) else {
+        return Err("Not in a game".into());
+    };
+    let Some(archetype) = find_archetype(ctx, &archetype) else {
+        return Err(format!("Unknown enemy archetype '{archetype}'"));
+    };
+
+    start_match(ctx, &player.world_id, x, y, z);
+    spawn_pack(ctx, &player.world_id, &archetype, x, y, z);
+    Ok(())
+}
+
+/// Scatter a pack of enemies in a ring around the given position.
+pub(crate) fn spawn_pack(
+    ctx: &spacetimedb::ReducerContext,
+    world_id: &str,
+    archetype: &EnemyArchetype,
+    x: f32,
+    y: f32,
+    z: f32,
+) {
     let seed = ctx.timestamp.to_micros_since_unix_epoch() as u64;
-    let count = 80 + (seed % 41) as u32; // 80–120 enemies
+    let spread = (archetype.pack_size_max - archetype.pack_size_min) as u64 + 1;
+    let count = archetype.pack_size_min + (seed % spread) as u32;
+    spawn_ring(ctx, world_id, archetype, count, x, y, z);
+}
+
+/// Scatter `count` enemies in the spawn ring around the given position.
+pub(crate) fn spawn_ring(
+    ctx: &spacetimedb::ReducerContext,
+    world_id: &str,
+    archetype: &EnemyArchetype,
+    count: u32,
+    x: f32,
+    y: f32,
+    z: f32,
+) {
+    let tuning = Tuning::load(ctx);
+    let (radius_min, radius_max) = (
+        tuning.enemy_spawn_radius_min,
+        tuning
+            .enemy_spawn_radius_max
+            .max(tuning.enemy_spawn_radius_min),
+    );
 
+    // Per-enemy scatter using hash that varies meaningfully per index
+    let seed = ctx.timestamp.to_micros_since_unix_epoch() as u64;
     for i in 0..count {
         let h = (seed ^ 0xDEADBEEF)
             .wrapping_add(i as u64)
             .wrapping_mul(6364136223846793005);
         let angle = (h & 0xFFFF) as f32 / 65535.0 * std::f32::consts::TAU;
-        let radius = defaults::ENEMY_SPAWN_RADIUS_MIN
-            + ((h >> 16) & 0xFFFF) as f32 / 65535.0
-                * (defaults::ENEMY_SPAWN_RADIUS_MAX - defaults::ENEMY_SPAWN_RADIUS_MIN);
-
-        ctx.db.enemy().insert(Enemy {
-            id: 0,
-            enemy_type: "basic".to_string(),
-            world_id: world_id.clone(),
-            x: x + angle.cos() * radius,
+        let radius = radius_min + ((h >> 16) & 0xFFFF) as f32 / 65535.0 * (radius_max - radius_min);
+
+        spawn_enemy(
+            ctx,
+            world_id,
+            archetype,
+            x + angle.cos() * radius,
             y,
-            z: z + angle.sin() * radius,
-            rotation_y: 0.0,
-            velocity_x: 0.0,
-            velocity_y: 0.0,
-            velocity_z: 0.0,
-            animation_state: "Idle".to_string(),
-            health: defaults::ENEMY_HEALTH,
-            max_health: defaults::ENEMY_HEALTH,
-            attack_damage: defaults::ENEMY_ATTACK_DAMAGE,
-            attack_range: defaults::ENEMY_ATTACK_RANGE,
-            attack_speed: 1.0,
-            last_attack_time: 0,
-        });
+            z + angle.sin() * radius,
+            0.0,
+        );
     }
 }
 
+/// Insert one fresh enemy of `archetype` at the given position and facing.
+pub(crate) fn spawn_enemy(
+    ctx: &spacetimedb::ReducerContext,
+    world_id: &str,
+    archetype: &EnemyArchetype,
+    x: f32,
+    y: f32,
+    z: f32,
+    rotation_y: f32,
+) {
+    ctx.db.enemy().insert(Enemy {
+        id: 0,
+        enemy_type: archetype.name.clone(),
+        world_id: world_id.to_string(),
+        x,
+        y,
+        z,
+        rotation_y,
+        velocity_x: 0.0,
+        velocity_y: 0.0,
+        velocity_z: 0.0,
+        behavior: combat::EnemyBehaviorKind::Idle.as_u8(),
+        health: archetype.max_health,
+        max_health: archetype.max_health,
+        shield: archetype.max_shield,
+        max_shield: archetype.max_shield,
+        attack_damage: archetype.attack_damage,
+        attack_range: archetype.attack_range,
+        attack_speed: archetype.attack_speed,
+        last_attack_time: 0,
+        last_hit_time: 0,
+        last_hit_by: None,
+    });
+}
+
 /// Delete all enemies in the caller's world.
 #[spacetimedb::reducer]
 pub fn clear_enemies(ctx: &spacetimedb::ReducerContext) {
@@ -77,6 +178,9 @@ pub fn clear_enemies(ctx: &spacetimedb::ReducerContext) {
         ctx.db.enemy().delete(enemy);
     }
 
+    ctx.db.spawn_director().world_id().delete(&world_id);
+    abort_match(ctx, &world_id);
+
     spacetimedb::log::info!("Cleared {} enemies from world {}", count, world_id);
 }
 
@@ -84,6 +188,63 @@ pub fn clear_enemies(ctx: &spacetimedb::ReducerContext) {
 // Server-side enemy AI tick
 // =============================================================================
 
+/// Few players spread over a large arena — big cells keep the outward
+/// nearest-player search short.
+const PLAYER_GRID_CELL_SIZE: f32 = 32.0;
+/// About twice the separation radius, so a separation query touches at most
+/// a 2×2 block of cells.
+const ENEMY_GRID_CELL_SIZE: f32 = 2.5;
+
+/// Spatial lookups for one world, rebuilt at the start of its tick. Items
+/// are indices into that world's player and enemy lists.
+struct WorldGrids {
+    players: SpatialGrid<usize>,
+    enemies: SpatialGrid<usize>,
+}
+
+impl WorldGrids {
+    fn new() -> Self {
+        Self {
+            players: SpatialGrid::new(PLAYER_GRID_CELL_SIZE),
+            enemies: SpatialGrid::new(ENEMY_GRID_CELL_SIZE),
+        }
+    }
+
+    fn rebuild(&mut self, players: &[Player], enemies: &[Enemy]) {
+        self.players
+            .rebuild(players.iter().enumerate().map(|(i, p)| (p.x, p.z, i)));
+        self.enemies
+            .rebuild(enemies.iter().enumerate().map(|(i, e)| (e.x, e.z, i)));
+    }
+}
+
+/// Push away from enemies closer than the separation radius, stronger the
+/// closer they are. Capped at the separation strength.
+fn separation(grids: &WorldGrids, tuning: &Tuning, enemies: &[Enemy], index: usize) -> (f32, f32) {
+    let enemy = &enemies[index];
+    let radius = tuning.enemy_separation_radius;
+    if radius <= 0.0 {
+        return (0.0, 0.0);
+    }
+    let (mut push_x, mut push_z) = (0.0, 0.0);
+    for (other, dist_sq) in grids.enemies.within(enemy.x, enemy.z, radius) {
+        if other == index || dist_sq < 1e-6 {
+            continue;
+        }
+        let dist = dist_sq.sqrt();
+        let weight = (1.0 - dist / radius) / dist;
+        push_x += (enemy.x - enemies[other].x) * weight;
+        push_z += (enemy.z - enemies[other].z) * weight;
+    }
+
+    let len = (push_x * push_x + push_z * push_z).sqrt();
+    if len < 1e-6 {
+        return (0.0, 0.0);
+    }
+    let scale = tuning.enemy_separation_strength * len.min(1.0) / len;
+    (push_x * scale, push_z * scale)
+}
+
 /// Periodic server tick — drives enemy AI for multiplayer.
 /// Uses avian3d PhysicsWorld for physics-based movement and knockback.
 ///
@@ -92,9 +253,21 @@ pub fn clear_enemies(ctx: &spacetimedb::ReducerContext) {
 /// across multiple WASM module instances, each with independent memory.
 #[spacetimedb::reducer]
 pub fn game_tick(ctx: &spacetimedb::ReducerContext, _args: TickSchedule) {
-    let dt = TICK_INTERVAL_MICROS as f32 / 1_000_000.0;
+    let tuning = Tuning::load(ctx);
+    record_tick(ctx, &tuning);
+    let dt = tuning.tick_dt();
     let now = ctx.timestamp.to_micros_since_unix_epoch();
 
+    tick_effects(ctx, now, dt);
+    tick_timers(ctx, now, dt);
+    tick_auras(ctx, now, dt);
+    tick_world_events(ctx, now);
+    regen_player_shields(ctx, now, dt);
+    if input_relay() {
+        tick_player_inputs(ctx, now, dt);
+    }
+    let threat = current_threat(ctx, now);
+
     // Group alive online players by world_id
     let mut players_by_world: HashMap<String, Vec<Player>> = HashMap::new();
     for p in ctx
@@ -109,10 +282,6 @@ pub fn game_tick(ctx: &spacetimedb::ReducerContext, _args: TickSchedule) {
             .push(p);
     }
 
-    if players_by_world.is_empty() {
-        return;
-    }
-
     // Group alive enemies by world_id
     let mut enemies_by_world: HashMap<String, Vec<Enemy>> = HashMap::new();
     for e in ctx.db.enemy().iter().filter(|e| e.health > 0.0) {
@@ -122,129 +291,268 @@ pub fn game_tick(ctx: &spacetimedb::ReducerContext, _args: TickSchedule) {
             .push(e);
     }
 
-    // Collect knockback impulses by world
-    let mut impulses_by_world: HashMap<String, Vec<KnockbackImpulse>> = HashMap::new();
+    // Nothing moves with no one online to see it, but housekeeping below
+    // still runs
+    if !players_by_world.is_empty() {
+        simulate_worlds(
+            ctx,
+            &tuning,
+            &players_by_world,
+            &enemies_by_world,
+            &threat,
+            now,
+            dt,
+        );
+    }
+
+    // Delete consumed knockback impulses
+    for impulse in ctx.db.knockback_impulse().iter().collect::<Vec<_>>() {
+        ctx.db.knockback_impulse().id().delete(impulse.id);
+    }
+
+    tick_directors(ctx, now, &players_by_world, &enemies_by_world);
+    prune_bosses(ctx);
+    prune_history(ctx, now);
+}
+
+/// Step enemy AI and physics in every unpaused world with players in it.
+fn simulate_worlds(
+    ctx: &spacetimedb::ReducerContext,
+    tuning: &Tuning,
+    players_by_world: &HashMap<String, Vec<Player>>,
+    enemies_by_world: &HashMap<String, Vec<Enemy>>,
+    threat: &HashMap<u64, Vec<(Identity, f32)>>,
+    now: i64,
+    dt: f32,
+) {
+    // Collect knockback impulses by target enemy
+    let mut impulses_by_enemy: HashMap<u64, Vec<KnockbackImpulse>> = HashMap::new();
     for impulse in ctx.db.knockback_impulse().iter() {
-        impulses_by_world
-            .entry(impulse.world_id.clone())
+        impulses_by_enemy
+            .entry(impulse.enemy_id)
             .or_default()
             .push(impulse);
     }
 
-    for (world_id, enemies) in &enemies_by_world {
+    // Per-type movement and attack tuning; enemies whose archetype was
+    // deleted move like `basic`
+    let archetypes: HashMap<String, EnemyArchetype> = ctx
+        .db
+        .enemy_archetype()
+        .iter()
+        .map(|a| (a.name.clone(), a))
+        .collect();
+    let fallback = configured_basic(ctx);
+
+    let mut grids = WorldGrids::new();
+    for (world_id, enemies) in enemies_by_world {
         if ctx.db.world_pause().world_id().find(world_id).is_some() {
             continue;
         }
         let Some(players) = players_by_world.get(world_id) else {
             continue;
         };
+        grids.rebuild(players, enemies);
+        let world = WorldTick {
+            ctx,
+            tuning,
+            grids: &grids,
+            players,
+            enemies,
+            impulses: &impulses_by_enemy,
+            threat,
+            archetypes: &archetypes,
+            fallback: &fallback,
+            now,
+        };
+
+        // Sort enemies into tiers by how close the nearest player is. The
+        // reduced tier is staggered by id so its work spreads over ticks.
+        let tick_index = (now / tuning.tick_interval_micros.max(1)) as u64;
+        let divisor = defaults::ENEMY_LOD_TICK_DIVISOR as u64;
+        let (mut active, mut reduced) = (Vec::new(), Vec::new());
+        for (index, enemy) in enemies.iter().enumerate() {
+            let nearest = grids
+                .players
+                .nearest(enemy.x, enemy.z)
+                .map_or(f32::MAX, |(_, dist)| dist);
+            // Anything just hit wakes up to take its knockback
+            let tier = if impulses_by_enemy.contains_key(&enemy.id) {
+                EnemyTickTier::Active
+            } else {
+                EnemyTickTier::from_distance(nearest)
+            };
+            match tier {
+                EnemyTickTier::Active => active.push(index),
+                EnemyTickTier::Reduced if (tick_index + enemy.id) % divisor == 0 => {
+                    reduced.push(index)
+                }
+                EnemyTickTier::Reduced => {}
+                EnemyTickTier::Frozen => settle(ctx, enemy),
+            }
+        }
+
+        // Reduced-rate enemies step over all the ticks they skipped, in a
+        // physics world of their own
+        let mut voided_out = world.step(&active, dt);
+        voided_out |= world.step(&reduced, dt * divisor as f32);
+        if voided_out {
+            end_match_if_cleared(ctx, world_id);
+        }
+
+        tick_bosses(ctx, world_id, enemies, players, now);
+    }
+}
 
-        // Create a physics world for this tick
+/// One world's state for a tick, shared by its tiers.
+struct WorldTick<'a> {
+    ctx: &'a spacetimedb::ReducerContext,
+    tuning: &'a Tuning,
+    grids: &'a WorldGrids,
+    players: &'a [Player],
+    enemies: &'a [Enemy],
+    impulses: &'a HashMap<u64, Vec<KnockbackImpulse>>,
+    threat: &'a HashMap<u64, Vec<(Identity, f32)>>,
+    archetypes: &'a HashMap<String, EnemyArchetype>,
+    fallback: &'a EnemyArchetype,
+    now: i64,
+}
+
+impl WorldTick<'_> {
+    fn archetype_of(&self, enemy: &Enemy) -> &EnemyArchetype {
+        self.archetypes
+            .get(&enemy.enemy_type)
+            .unwrap_or(self.fallback)
+    }
+
+    /// Simulate the enemies at `batch` (indices into `enemies`) for `dt`
+    /// seconds and write them back. Returns whether any fell into the void.
+    fn step(&self, batch: &[usize], dt: f32) -> bool {
+        if batch.is_empty() {
+            return false;
+        }
+        let (ctx, tuning, now) = (self.ctx, self.tuning, self.now);
+
+        // Create a physics world for this step
         let mut physics = PhysicsWorld::new(PhysicsConfig {
             gravity: Vector::new(0.0, -9.81, 0.0),
             substeps: 4,
             ..Default::default()
         });
 
-        // Add a static floor
-        let floor = physics.add_body(RigidBodyBundle::static_body(Vector::ZERO));
-        physics.add_collider(floor, ColliderBundle::half_space(Vector::Y));
+        // Add the arena floor — a finite slab, so enemies knocked past the
+        // edge fall into the void
+        let floor = physics.add_body(RigidBodyBundle::static_body(Vector::new(0.0, -0.5, 0.0)));
+        physics.add_collider(
+            floor,
+            ColliderBundle::cuboid(defaults::ARENA_HALF_SIZE, 0.5, defaults::ARENA_HALF_SIZE),
+        );
 
         // Add enemies as dynamic bodies
-        let mut enemy_handles: Vec<(BodyHandle, &Enemy)> = Vec::with_capacity(enemies.len());
-        for enemy in enemies {
+        let mut enemy_handles: Vec<(usize, BodyHandle, &Enemy)> = Vec::with_capacity(batch.len());
+        for &index in batch {
+            let enemy = &self.enemies[index];
             let handle = physics.add_body(RigidBodyBundle {
                 body_type: RigidBodyType::Dynamic,
                 position: Vector::new(enemy.x, enemy.y, enemy.z),
                 linear_velocity: Vector::new(enemy.velocity_x, enemy.velocity_y, enemy.velocity_z),
-                mass: 50.0,
+                mass: self.archetype_of(enemy).mass,
                 ..Default::default()
             });
             physics.add_collider(handle, ColliderBundle::capsule(0.5, 1.0));
-            enemy_handles.push((handle, enemy));
+            enemy_handles.push((index, handle, enemy));
         }
 
-        // Apply AI-driven velocities and knockback impulses
-        for (handle, enemy) in &enemy_handles {
-            // Find nearest player (XZ distance)
-            let mut nearest_dist = f32::MAX;
-            let mut nearest_pos = (0.0_f32, 0.0_f32);
-            for p in players {
-                let dx = p.x - enemy.x;
-                let dz = p.z - enemy.z;
-                let dist = (dx * dx + dz * dz).sqrt();
-                if dist < nearest_dist {
-                    nearest_dist = dist;
-                    nearest_pos = (p.x, p.z);
-                }
-            }
+        // AI decisions from the pre-step positions, reused at write-back.
+        // Enemies go after whoever they hold the most threat for, else the
+        // nearest player.
+        let decisions: Vec<(combat::EnemyBehaviorKind, f32, (f32, f32))> = enemy_handles
+            .iter()
+            .map(|(_, _, enemy)| {
+                let (target_dist, target_pos) =
+                    match top_threat(self.threat, enemy.id, self.players) {
+                        Some(target) => (
+                            (target.x - enemy.x).hypot(target.z - enemy.z),
+                            (target.x, target.z),
+                        ),
+                        None => self
+                            .grids
+                            .players
+                            .nearest(enemy.x, enemy.z)
+                            .map_or((f32::MAX, (0.0, 0.0)), |(i, dist)| {
+                                (dist, (self.players[i].x, self.players[i].z))
+                            }),
+                    };
+                let cooldown_micros =
+                    (self.archetype_of(enemy).attack_cooldown * 1_000_000.0) as i64;
+                let attack_cooldown_ready = (now - enemy.last_attack_time) >= cooldown_micros;
+                let decision = enemy_ai_decision_in_range(
+                    target_dist,
+                    enemy.attack_range,
+                    attack_cooldown_ready,
+                );
+                (decision, target_dist, target_pos)
+            })
+            .collect();
 
-            // Check attack cooldown
-            let cooldown_micros = (defaults::ENEMY_ATTACK_COOLDOWN * 1_000_000.0) as i64;
-            let attack_cooldown_ready = (now - enemy.last_attack_time) >= cooldown_micros;
-            let decision = enemy_ai_decision(nearest_dist, attack_cooldown_ready);
-
-            // Apply knockback impulses for this enemy (before AI velocity so
-            // we can skip chase when being knocked back)
-            let has_knockback = impulses_by_world
-                .get(world_id)
-                .is_some_and(|impulses| {
-                    impulses.iter().any(|i| i.enemy_id == enemy.id)
-                });
-            if let Some(impulses) = impulses_by_world.get(world_id) {
-                for impulse in impulses.iter().filter(|i| i.enemy_id == enemy.id) {
-                    physics.apply_impulse(
-                        *handle,
-                        Vector::new(impulse.impulse_x, impulse.impulse_y, impulse.impulse_z),
-                    );
-                }
-            }
+        // Apply knockback impulses, then ease velocities toward the AI's
+        let decay = (-tuning.enemy_velocity_damping * dt).exp();
+        for (slot, (index, handle, enemy)) in enemy_handles.iter().enumerate() {
+            let (decision, target_dist, target_pos) = decisions[slot];
 
-            // Move toward player when chasing — but skip when being knocked
-            // back so the impulse isn't immediately overridden by chase velocity.
-            if !has_knockback
-                && decision == combat::EnemyBehaviorKind::Chase
-                && nearest_dist > 0.01
-            {
-                let dx = nearest_pos.0 - enemy.x;
-                let dz = nearest_pos.1 - enemy.z;
-                let inv_dist = 1.0 / nearest_dist;
-                let move_x = dx * inv_dist * defaults::ENEMY_WALK_SPEED;
-                let move_z = dz * inv_dist * defaults::ENEMY_WALK_SPEED;
-                physics.set_linear_velocity(
+            for impulse in self.impulses.get(&enemy.id).into_iter().flatten() {
+                physics.apply_impulse(
                     *handle,
-                    Vector::new(move_x, physics.body(*handle).linear_velocity().y, move_z),
+                    Vector::new(impulse.impulse_x, impulse.impulse_y, impulse.impulse_z),
                 );
             }
+
+            // Move toward player when chasing and keep out of neighbours'
+            // way — but not while winding up a boss attack.
+            let (mut move_x, mut move_z) = separation(self.grids, tuning, self.enemies, *index);
+            let chasing = decision == combat::EnemyBehaviorKind::Chase
+                && target_dist > 0.01
+                && !is_casting(ctx, enemy);
+            if chasing {
+                let speed = self.archetype_of(enemy).walk_speed / target_dist;
+                move_x += (target_pos.0 - enemy.x) * speed;
+                move_z += (target_pos.1 - enemy.z) * speed;
+            }
+
+            // Whatever the enemy carries beyond that (knockback) decays
+            // over a few ticks instead of being overwritten, so hits stack
+            // with movement and with each other
+            let velocity = physics.body(*handle).linear_velocity();
+            physics.set_linear_velocity(
+                *handle,
+                Vector::new(
+                    move_x + (velocity.x - move_x) * decay,
+                    velocity.y,
+                    move_z + (velocity.z - move_z) * decay,
+                ),
+            );
         }
 
         // Step physics
         let _result = physics.step(dt);
 
         // Write back physics state to DB and update AI state
-        for (handle, enemy) in &enemy_handles {
+        let mut voided_out = false;
+        for (slot, (_, handle, enemy)) in enemy_handles.iter().enumerate() {
             let body = physics.body(*handle);
 
-            // AI decision (recomputed — cheap)
-            let mut nearest_dist = f32::MAX;
-            let mut nearest_pos = (0.0_f32, 0.0_f32);
-            for p in players {
-                let dx = p.x - enemy.x;
-                let dz = p.z - enemy.z;
-                let dist = (dx * dx + dz * dz).sqrt();
-                if dist < nearest_dist {
-                    nearest_dist = dist;
-                    nearest_pos = (p.x, p.z);
-                }
+            if body.position().y < defaults::KILL_PLANE_Y {
+                void_out(ctx, tuning, enemy, now);
+                voided_out = true;
+                continue;
             }
-            let cooldown_micros = (defaults::ENEMY_ATTACK_COOLDOWN * 1_000_000.0) as i64;
-            let attack_cooldown_ready = (now - enemy.last_attack_time) >= cooldown_micros;
-            let decision = enemy_ai_decision(nearest_dist, attack_cooldown_ready);
+
+            let (decision, target_dist, target_pos) = decisions[slot];
 
             let mut new_rotation_y = enemy.rotation_y;
-            if decision != combat::EnemyBehaviorKind::Idle && nearest_dist > 0.01 {
-                let dx = nearest_pos.0 - enemy.x;
-                let dz = nearest_pos.1 - enemy.z;
+            if decision != combat::EnemyBehaviorKind::Idle && target_dist > 0.01 {
+                let dx = target_pos.0 - enemy.x;
+                let dz = target_pos.1 - enemy.z;
                 new_rotation_y = f32::atan2(-dx, -dz);
             }
 
@@ -268,19 +576,63 @@ pub fn game_tick(ctx: &spacetimedb::ReducerContext, _args: TickSchedule) {
                 velocity_x: vel.x,
                 velocity_y: vel.y,
                 velocity_z: vel.z,
-                animation_state: decision.as_str().to_string(),
+                behavior: decision.as_u8(),
                 health: enemy.health,
                 max_health: enemy.max_health,
+                shield: combat::regen_shield(
+                    enemy.shield,
+                    enemy.max_shield,
+                    (now - enemy.last_hit_time) as f32 / 1_000_000.0,
+                    dt,
+                ),
+                max_shield: enemy.max_shield,
                 attack_damage: enemy.attack_damage,
                 attack_range: enemy.attack_range,
                 attack_speed: enemy.attack_speed,
                 last_attack_time: new_last_attack_time,
+                last_hit_time: enemy.last_hit_time,
+                last_hit_by: enemy.last_hit_by,
             });
+            record_position(ctx, enemy.id, pos.x, pos.y, pos.z, now);
         }
+
+        voided_out
     }
+}
 
-    // Delete consumed knockback impulses
-    for impulse in ctx.db.knockback_impulse().iter().collect::<Vec<_>>() {
-        ctx.db.knockback_impulse().id().delete(impulse.id);
+/// Stop a frozen enemy where it stands. Clients extrapolate along the last
+/// velocity written, so it's zeroed once rather than left to drift.
+fn settle(ctx: &spacetimedb::ReducerContext, enemy: &Enemy) {
+    let idle = combat::EnemyBehaviorKind::Idle.as_u8();
+    if enemy.velocity_x == 0.0
+        && enemy.velocity_y == 0.0
+        && enemy.velocity_z == 0.0
+        && enemy.behavior == idle
+    {
+        return;
+    }
+    if let Some(current) = ctx.db.enemy().id().find(enemy.id) {
+        ctx.db.enemy().id().update(Enemy {
+            velocity_x: 0.0,
+            velocity_y: 0.0,
+            velocity_z: 0.0,
+            behavior: idle,
+            ..current
+        });
+    }
+}
+
+/// Remove an enemy that fell below the kill plane. The kill goes to whoever
+/// last hit it, if that was recent enough to have caused the fall.
+fn void_out(ctx: &spacetimedb::ReducerContext, tuning: &Tuning, enemy: &Enemy, now: i64) {
+    ctx.db.enemy().id().delete(enemy.id);
+
+    let window_micros = (tuning.kill_credit_window_secs * 1_000_000.0) as i64;
+    let killer = enemy
+        .last_hit_by
+        .filter(|_| now - enemy.last_hit_time <= window_micros)
+        .and_then(|identity| ctx.db.player().identity().find(identity));
+    if let Some(killer) = killer {
+        record_kill(ctx, &killer, enemy);
     }
 }
@@ -0,0 +1,127 @@
+use spacetimedb::Table;
+use wasm_fantasia_shared::items::{self, Item};
+use wasm_fantasia_shared::rules::Stat;
+use wasm_fantasia_shared::weapons::Weapon;
+
+use crate::schema::*;
+
+/// Put a starting kit in a new character's inventory.
+pub(crate) fn grant_starter_items(ctx: &spacetimedb::ReducerContext, owner: spacetimedb::Identity) {
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    for item in items::STARTER_ITEMS {
+        ctx.db.inventory_item().insert(InventoryItem {
+            id: 0,
+            owner,
+            item_id: item.as_str().to_string(),
+            acquired_at: now,
+        });
+    }
+}
+
+/// Recompute a player's combat stats and weapon from their level and
+/// equipped items.
+///
+/// Max health going up heals a living player by the difference; going down
+/// only clamps, so swapping armor can't be used to top up health.
+pub(crate) fn with_character_stats(ctx: &spacetimedb::ReducerContext, player: Player) -> Player {
+    let equipped: Vec<Item> = ctx
+        .db
+        .equipped_item()
+        .owner()
+        .filter(player.identity)
+        .filter_map(|e| Item::parse_str(&e.item_id))
+        .collect();
+    let stats = items::equipped_stats(player.level, &equipped);
+
+    let max_health = stats.get(&Stat::MaxHealth);
+    let health = if player.health > 0.0 {
+        (player.health + (max_health - player.max_health).max(0.0)).min(max_health)
+    } else {
+        player.health
+    };
+    Player {
+        health,
+        max_health,
+        attack_damage: stats.get(&Stat::AttackDamage),
+        crit_chance: stats.get(&Stat::CritChance),
+        crit_multiplier: stats.get(&Stat::CritMultiplier),
+        attack_range: stats.get(&Stat::AttackRange),
+        attack_arc: stats.get(&Stat::AttackArc),
+        knockback_force: stats.get(&Stat::Knockback),
+        weapon: Weapon::from_equipped(&equipped).as_str().to_string(),
+        ..player
+    }
+}
+
+/// Equip an item from the sender's inventory, replacing whatever is in its slot.
+#[spacetimedb::reducer]
+pub fn equip_item(ctx: &spacetimedb::ReducerContext, inventory_id: u64) -> Result<(), String> {
+    let Some(player) = ctx.db.player().identity().find(ctx.sender) else {
+        return Err("Not in a game".into());
+    };
+    let Some(owned) = ctx
+        .db
+        .inventory_item()
+        .id()
+        .find(inventory_id)
+        .filter(|i| i.owner == ctx.sender)
+    else {
+        return Err("Item not in inventory".into());
+    };
+    let Some(item) = Item::parse_str(&owned.item_id) else {
+        return Err(format!("Unknown item '{}'", owned.item_id));
+    };
+    if ctx
+        .db
+        .equipped_item()
+        .inventory_id()
+        .find(inventory_id)
+        .is_some()
+    {
+        return Ok(());
+    }
+
+    let slot = item.slot().as_str();
+    let occupant: Vec<EquippedItem> = ctx
+        .db
+        .equipped_item()
+        .owner()
+        .filter(ctx.sender)
+        .filter(|e| e.slot == slot)
+        .collect();
+    for equipped in occupant {
+        ctx.db.equipped_item().delete(equipped);
+    }
+    ctx.db.equipped_item().insert(EquippedItem {
+        inventory_id,
+        owner: ctx.sender,
+        slot: slot.to_string(),
+        item_id: owned.item_id,
+    });
+
+    let player = with_character_stats(ctx, player);
+    ctx.db.player().identity().update(player);
+    Ok(())
+}
+
+/// Take off an equipped item. It stays in the inventory.
+#[spacetimedb::reducer]
+pub fn unequip_item(ctx: &spacetimedb::ReducerContext, inventory_id: u64) -> Result<(), String> {
+    let Some(player) = ctx.db.player().identity().find(ctx.sender) else {
+        return Err("Not in a game".into());
+    };
+    let Some(equipped) = ctx
+        .db
+        .equipped_item()
+        .inventory_id()
+        .find(inventory_id)
+        .filter(|e| e.owner == ctx.sender)
+    else {
+        return Err("Item not equipped".into());
+    };
+    ctx.db.equipped_item().delete(equipped);
+
+    let player = with_character_stats(ctx, player);
+    ctx.db.player().identity().update(player);
+    Ok(())
+}
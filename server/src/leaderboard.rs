@@ -0,0 +1,82 @@
+use spacetimedb::{Identity, Table};
+
+use crate::archetypes::is_admin;
+use crate::schema::*;
+
+/// Add a landed hit to the attacker's damage, and a kill if it was fatal.
+pub(crate) fn tally_hit(
+    ctx: &spacetimedb::ReducerContext,
+    attacker: &Player,
+    damage: f32,
+    killed: bool,
+) {
+    let mut entry = entry_for(ctx, attacker.identity, &attacker.world_id);
+    entry.damage_dealt += damage;
+    if killed {
+        entry.kills += 1;
+    }
+    save_entry(ctx, entry);
+}
+
+/// Count a kill that didn't come from a hit, e.g. knocking an enemy off the arena.
+pub(crate) fn tally_kill(ctx: &spacetimedb::ReducerContext, killer: &Player) {
+    let mut entry = entry_for(ctx, killer.identity, &killer.world_id);
+    entry.kills += 1;
+    save_entry(ctx, entry);
+}
+
+/// Count a death against the victim.
+pub(crate) fn tally_death(ctx: &spacetimedb::ReducerContext, victim: &Player) {
+    let mut entry = entry_for(ctx, victim.identity, &victim.world_id);
+    entry.deaths += 1;
+    save_entry(ctx, entry);
+}
+
+/// Clear every leaderboard row in `world_id`. Owner or admin only.
+#[spacetimedb::reducer]
+pub fn reset_leaderboard(
+    ctx: &spacetimedb::ReducerContext,
+    world_id: String,
+) -> Result<(), String> {
+    let Some(world) = ctx.db.world().id().find(&world_id) else {
+        return Err(format!("No world named '{world_id}'"));
+    };
+    if world.owner != ctx.sender && !is_admin(ctx) {
+        return Err("Only the owner or an admin can reset the leaderboard".into());
+    }
+    clear_leaderboard(ctx, &world_id);
+    Ok(())
+}
+
+/// Drop every leaderboard row in `world_id`.
+pub(crate) fn clear_leaderboard(ctx: &spacetimedb::ReducerContext, world_id: &str) {
+    let rows: Vec<Leaderboard> = ctx.db.leaderboard().world_id().filter(world_id).collect();
+    for row in rows {
+        ctx.db.leaderboard().delete(row);
+    }
+}
+
+/// The player's row in `world_id`, or a zeroed one (id 0) if they have none yet.
+fn entry_for(ctx: &spacetimedb::ReducerContext, identity: Identity, world_id: &str) -> Leaderboard {
+    ctx.db
+        .leaderboard()
+        .identity()
+        .filter(identity)
+        .find(|row| row.world_id == world_id)
+        .unwrap_or(Leaderboard {
+            id: 0,
+            identity,
+            world_id: world_id.to_string(),
+            kills: 0,
+            deaths: 0,
+            damage_dealt: 0.0,
+        })
+}
+
+fn save_entry(ctx: &spacetimedb::ReducerContext, entry: Leaderboard) {
+    if entry.id == 0 {
+        ctx.db.leaderboard().insert(entry);
+    } else {
+        ctx.db.leaderboard().id().update(entry);
+    }
+}
@@ -1,9 +1,41 @@
 use spacetimedb::{Table, TimeDuration};
+use wasm_fantasia_shared::combat::defaults;
+use wasm_fantasia_shared::gameplay;
+use wasm_fantasia_shared::net::{self, AnimationId};
 
+mod abilities;
+mod accounts;
+mod admin;
+mod archetypes;
+mod boss;
+mod chat;
 mod combat;
+mod combat_log;
+mod config;
+mod director;
+mod effects;
 mod enemy_ai;
+mod inventory;
+mod leaderboard;
 mod lifecycle;
+mod loadouts;
+mod lobby;
+mod loot;
+mod matches;
+mod metrics;
+mod movement;
+mod progression;
+mod rate_limit;
+mod rewind;
 pub mod schema;
+mod scripting;
+mod snapshots;
+mod spawns;
+mod threat;
+mod trade;
+mod triggers;
+mod world_events;
+mod worlds;
 
 pub use schema::*;
 
@@ -17,12 +49,43 @@ pub fn init(ctx: &spacetimedb::ReducerContext) {
         scheduled_id: 0,
         scheduled_at: TimeDuration::from_micros(TICK_INTERVAL_MICROS).into(),
     });
+    ctx.db.world_gc_schedule().insert(WorldGcSchedule {
+        scheduled_id: 0,
+        scheduled_at: TimeDuration::from_micros(worlds::WORLD_GC_INTERVAL_MICROS).into(),
+    });
+    // The publisher administers this database
+    let _ = ctx.db.admin().try_insert(Admin {
+        identity: ctx.sender,
+    });
+    archetypes::seed_archetypes(ctx);
+    abilities::seed_abilities(ctx);
+    worlds::seed_shared_world(ctx);
+    publish_server_info(ctx);
     spacetimedb::log::info!(
         "Server initialized — game tick scheduled at {}ms interval",
         TICK_INTERVAL_MICROS / 1000
     );
 }
 
+/// Fresh connections refresh the info row, so it follows a republish
+/// (which doesn't rerun `init`) before any client reads it.
+#[spacetimedb::reducer(client_connected)]
+pub fn on_connect(ctx: &spacetimedb::ReducerContext) {
+    publish_server_info(ctx);
+}
+
+fn publish_server_info(ctx: &spacetimedb::ReducerContext) {
+    let info = ServerInfo {
+        id: 0,
+        gameplay_checksum: gameplay::checksum(),
+    };
+    if ctx.db.server_info().id().find(0).is_some() {
+        ctx.db.server_info().id().update(info);
+    } else {
+        ctx.db.server_info().insert(info);
+    }
+}
+
 #[spacetimedb::reducer]
 pub fn pause_world(ctx: &spacetimedb::ReducerContext) {
     if let Some(player) = ctx.db.player().identity().find(ctx.sender) {
@@ -39,28 +102,103 @@ pub fn resume_world(ctx: &spacetimedb::ReducerContext) {
     }
 }
 
-/// Client state relay.
+/// Client state relay, in the compact encoding of
+/// [`net`](wasm_fantasia_shared::net): fixed-point position, a one-byte
+/// heading and animation ids. `input_seq` numbers the client's updates; it's
+/// echoed on the row so the client can tell which update a position answers.
 #[spacetimedb::reducer]
 pub fn update_position(
     ctx: &spacetimedb::ReducerContext,
-    x: f32,
-    y: f32,
-    z: f32,
-    rotation_y: f32,
-    animation_state: String,
+    x: i16,
+    y: i16,
+    z: i16,
+    heading: u8,
+    animation: u8,
     attack_sequence: u32,
-    attack_animation: String,
+    attack_animation: u8,
+    input_seq: u32,
 ) {
-    if let Some(player) = ctx.db.player().identity().find(ctx.sender) {
-        ctx.db.player().identity().update(Player {
+    metrics::count_call(ctx, "update_position");
+    // Under the input relay the server moves players itself
+    if movement::input_relay() || !rate_limit::allow(ctx, "update_position") {
+        return;
+    }
+    // Offline players (e.g. kicked by an admin) stay where they were
+    if let Some(player) = ctx
+        .db
+        .player()
+        .identity()
+        .find(ctx.sender)
+        .filter(|p| p.online)
+    {
+        let (x, y, z) = (
+            net::dequantize_position(x),
+            net::dequantize_position(y),
+            net::dequantize_position(z),
+        );
+        // Fell off the world — die and go through the normal respawn flow
+        let health = if y < defaults::KILL_PLANE_Y {
+            0.0
+        } else {
+            player.health
+        };
+        let just_died = player.health > 0.0 && health <= 0.0;
+        // Nobody walks out of the arena; the client pulls back when it sees
+        // the clamped position
+        let bound = defaults::ARENA_HALF_SIZE;
+        // Stunned players stay put but may still fall. The client applies
+        // slows itself; positions it sends aren't speed checked.
+        let (x, z) = if effects::impairment(ctx, player.identity).can_move() {
+            (x.clamp(-bound, bound), z.clamp(-bound, bound))
+        } else {
+            (player.x, player.z)
+        };
+        let previous_animation = player.animation;
+        let player = ctx.db.player().identity().update(Player {
             x,
             y,
             z,
-            rotation_y,
-            animation_state,
+            rotation_y: net::dequantize_rotation(heading),
+            animation: AnimationId::from_u8(animation).as_u8(),
             attack_sequence,
-            attack_animation,
+            attack_animation: AnimationId::from_u8(attack_animation).as_u8(),
+            health,
             last_update: ctx.timestamp.to_micros_since_unix_epoch(),
+            input_seq,
+            ..player
+        });
+        triggers::dodge_started(ctx, previous_animation, &player, player.last_update);
+        if just_died {
+            lifecycle::record_death(ctx, &player, None, player.last_update);
+        }
+    }
+}
+
+/// Echo `nonce` into the caller's `ping_result` row. Does nothing else, so
+/// the time until the row comes back is network latency plus one reducer
+/// call, not however long the client waits on a game tick.
+#[spacetimedb::reducer]
+pub fn ping(ctx: &spacetimedb::ReducerContext, nonce: u32) {
+    metrics::count_call(ctx, "ping");
+    let row = PingResult {
+        identity: ctx.sender,
+        nonce,
+        server_time: ctx.timestamp.to_micros_since_unix_epoch(),
+    };
+    if ctx.db.ping_result().identity().find(ctx.sender).is_some() {
+        ctx.db.ping_result().identity().update(row);
+    } else {
+        ctx.db.ping_result().insert(row);
+    }
+}
+
+/// Client-measured round-trip time, kept for lag-compensated hit validation.
+#[spacetimedb::reducer]
+pub fn report_rtt(ctx: &spacetimedb::ReducerContext, rtt_ms: f32) {
+    metrics::count_call(ctx, "report_rtt");
+    if let Some(player) = ctx.db.player().identity().find(ctx.sender) {
+        ctx.db.player().identity().update(Player {
+            rtt_ms: rtt_ms.max(0.0),
             ..player
         });
     }
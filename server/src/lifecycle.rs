@@ -1,59 +1,232 @@
 use spacetimedb::Table;
-use wasm_fantasia_shared::combat::defaults;
+use wasm_fantasia_shared::combat::{defaults, life_events};
+use wasm_fantasia_shared::gameplay::gameplay;
+use wasm_fantasia_shared::net::AnimationId;
+use wasm_fantasia_shared::weapons::Weapon;
 
+use crate::inventory::grant_starter_items;
+use crate::leaderboard::tally_death;
+use crate::lobby;
+use crate::metrics::count_call;
+use crate::rate_limit;
 use crate::schema::*;
+use crate::trade;
+use crate::worlds::{admit, seed_shared_world, spawn_point, SHARED_WORLD};
 
+/// A level 1 character with default stats standing at `world`'s spawn point.
+fn fresh_player(ctx: &spacetimedb::ReducerContext, name: Option<String>, world: &World) -> Player {
+    Player {
+        identity: ctx.sender,
+        name,
+        online: true,
+        world_id: world.id.clone(),
+        x: world.spawn_x,
+        y: world.spawn_y,
+        z: world.spawn_z,
+        rotation_y: 0.0,
+        animation: AnimationId::Idle.as_u8(),
+        attack_sequence: 0,
+        attack_animation: AnimationId::None.as_u8(),
+        last_update: ctx.timestamp.to_micros_since_unix_epoch(),
+        health: defaults::HEALTH,
+        max_health: defaults::HEALTH,
+        shield: defaults::SHIELD,
+        max_shield: defaults::SHIELD,
+        last_hit_time: 0,
+        attack_damage: defaults::ATTACK_DAMAGE,
+        crit_chance: defaults::CRIT_CHANCE,
+        crit_multiplier: defaults::CRIT_MULTIPLIER,
+        attack_range: defaults::ATTACK_RANGE,
+        attack_arc: defaults::ATTACK_ARC,
+        knockback_force: defaults::KNOCKBACK,
+        attack_speed: defaults::ATTACK_SPEED,
+        last_attack_time: 0,
+        weapon: Weapon::Fists.as_str().to_string(),
+        pvp: false,
+        xp: 0,
+        level: 1,
+        rtt_ms: 0.0,
+        input_seq: 0,
+        attack_intent_seq: 0,
+    }
+}
+
+/// Enter `world_id`: the sender's own solo world (created on first join),
+/// the shared world, or an instance opened with `create_world` that has room.
+///
+/// A returning character is restored as it was saved: position, health,
+/// level, stats and inventory all persist in their tables between sessions.
+/// Only a character that logged out dead, or that changes worlds, is moved
+/// to the world's spawn point.
 #[spacetimedb::reducer]
-pub fn join_game(ctx: &spacetimedb::ReducerContext, name: Option<String>, world_id: String) {
+pub fn join_game(
+    ctx: &spacetimedb::ReducerContext,
+    name: Option<String>,
+    world_id: String,
+) -> Result<(), String> {
+    count_call(ctx, "join_game");
+    let world = admit(ctx, &world_id)?;
     let now = ctx.timestamp.to_micros_since_unix_epoch();
+    ctx.db.observer().identity().delete(ctx.sender);
     if let Some(existing) = ctx.db.player().identity().find(ctx.sender) {
+        // A death timer doesn't outlive the session; a dead character comes
+        // back at spawn below.
+        ctx.db.death().identity().delete(ctx.sender);
+        let (x, y, z, health) = if existing.world_id == world.id && existing.health > 0.0 {
+            (existing.x, existing.y, existing.z, existing.health)
+        } else {
+            (
+                world.spawn_x,
+                world.spawn_y,
+                world.spawn_z,
+                existing.max_health,
+            )
+        };
         ctx.db.player().identity().update(Player {
             online: true,
             world_id,
-            health: existing.max_health,
+            x,
+            y,
+            z,
+            health,
             last_update: now,
+            // The new session numbers its inputs from the start again
+            input_seq: 0,
+            attack_intent_seq: 0,
             ..existing
         });
     } else {
-        ctx.db.player().insert(Player {
-            identity: ctx.sender,
-            name,
-            online: true,
-            world_id,
-            x: 0.0,
-            y: 1.0,
-            z: 0.0,
-            rotation_y: 0.0,
-            animation_state: "Idle".to_string(),
-            attack_sequence: 0,
-            attack_animation: String::new(),
-            last_update: now,
-            health: defaults::HEALTH,
-            max_health: defaults::HEALTH,
-            attack_damage: defaults::ATTACK_DAMAGE,
-            crit_chance: defaults::CRIT_CHANCE,
-            crit_multiplier: defaults::CRIT_MULTIPLIER,
-            attack_range: defaults::ATTACK_RANGE,
-            attack_arc: defaults::ATTACK_ARC,
-            knockback_force: defaults::KNOCKBACK,
-            attack_speed: defaults::ATTACK_SPEED,
-            last_attack_time: 0,
-        });
+        ctx.db.player().insert(fresh_player(ctx, name, &world));
+        grant_starter_items(ctx, ctx.sender);
     }
+    Ok(())
 }
 
-/// Reset health to max and reposition player at spawn point.
+/// Watch `world_id` without a character. A character the sender already
+/// has goes offline, so enemies stop chasing it; `join_game` brings it back.
 #[spacetimedb::reducer]
-pub fn respawn(ctx: &spacetimedb::ReducerContext) {
+pub fn observe(ctx: &spacetimedb::ReducerContext, world_id: String) -> Result<(), String> {
+    if world_id == SHARED_WORLD {
+        seed_shared_world(ctx);
+    }
+    if ctx.db.world().id().find(&world_id).is_none() {
+        return Err(format!("No world named '{world_id}'"));
+    }
+    set_player_offline(ctx);
+    ctx.db.observer().insert(Observer {
+        identity: ctx.sender,
+        world_id,
+        joined_at: ctx.timestamp.to_micros_since_unix_epoch(),
+    });
+    Ok(())
+}
+
+/// Wipe the sender's character for a fresh start: level, xp, stats,
+/// inventory and effects go back to those of a new player, at their world's
+/// spawn point. The name and world are kept.
+#[spacetimedb::reducer]
+pub fn reset_character(ctx: &spacetimedb::ReducerContext) -> Result<(), String> {
     let Some(player) = ctx.db.player().identity().find(ctx.sender) else {
-        return;
+        return Err("No character to reset".into());
+    };
+    let Some(world) = ctx.db.world().id().find(&player.world_id) else {
+        return Err(format!("No world named '{}'", player.world_id));
+    };
+
+    let equipped: Vec<EquippedItem> = ctx.db.equipped_item().owner().filter(ctx.sender).collect();
+    for item in equipped {
+        ctx.db.equipped_item().delete(item);
+    }
+    let owned: Vec<InventoryItem> = ctx.db.inventory_item().owner().filter(ctx.sender).collect();
+    for item in owned {
+        ctx.db.inventory_item().delete(item);
+    }
+    let effects: Vec<ActiveEffect> = ctx
+        .db
+        .active_effect()
+        .iter()
+        .filter(|e| e.owner == ctx.sender)
+        .collect();
+    for effect in effects {
+        ctx.db.active_effect().delete(effect);
+    }
+    ctx.db.death().identity().delete(ctx.sender);
+
+    ctx.db.player().identity().update(Player {
+        online: player.online,
+        ..fresh_player(ctx, player.name, &world)
+    });
+    grant_starter_items(ctx, ctx.sender);
+    Ok(())
+}
+
+/// Start `victim`'s death timer, count the death on the leaderboard and tell
+/// their world they died. Called once per death, when health first reaches 0.
+pub(crate) fn record_death(
+    ctx: &spacetimedb::ReducerContext,
+    victim: &Player,
+    killer: Option<spacetimedb::Identity>,
+    now: i64,
+) {
+    ctx.db.death().identity().delete(victim.identity);
+    ctx.db.death().insert(Death {
+        identity: victim.identity,
+        world_id: victim.world_id.clone(),
+        killer,
+        died_at: now,
+        respawn_at: now + (gameplay().respawn_delay_secs * 1_000_000.0) as i64,
+    });
+    tally_death(ctx, victim);
+    insert_life_event(ctx, victim, killer, life_events::DEATH, now);
+}
+
+pub(crate) fn insert_life_event(
+    ctx: &spacetimedb::ReducerContext,
+    player: &Player,
+    attacker: Option<spacetimedb::Identity>,
+    reaction: &str,
+    now: i64,
+) {
+    ctx.db.combat_event().insert(CombatEvent {
+        id: 0,
+        x: player.x,
+        y: player.y,
+        z: player.z,
+        damage: 0.0,
+        is_crit: false,
+        attacker: attacker.unwrap_or(player.identity),
+        target_id: 0,
+        target_player: Some(player.identity),
+        knockback_x: 0.0,
+        knockback_y: 0.0,
+        knockback_z: 0.0,
+        reaction: reaction.to_string(),
+        world_id: player.world_id.clone(),
+        timestamp: now,
+    });
+}
+
+/// Reset health to max and reposition player at their world's spawn point.
+/// Refused until the death timer started by [`record_death`] runs out.
+#[spacetimedb::reducer]
+pub fn respawn(ctx: &spacetimedb::ReducerContext) -> Result<(), String> {
+    count_call(ctx, "respawn");
+    let Some(player) = ctx.db.player().identity().find(ctx.sender) else {
+        return Err("Not in a game".into());
     };
 
     if player.health > 0.0 {
-        return;
+        return Err("Not dead".into());
     }
 
     let now = ctx.timestamp.to_micros_since_unix_epoch();
+    if let Some(death) = ctx.db.death().identity().find(ctx.sender) {
+        if now < death.respawn_at {
+            let wait = (death.respawn_at - now) as f32 / 1_000_000.0;
+            return Err(format!("Can't respawn for another {wait:.1}s"));
+        }
+        ctx.db.death().delete(death);
+    }
 
     // Clear stacking buff on respawn
     let stacking: Vec<ActiveEffect> = ctx
@@ -66,15 +239,19 @@ pub fn respawn(ctx: &spacetimedb::ReducerContext) {
         ctx.db.active_effect().delete(effect);
     }
 
-    ctx.db.player().identity().update(Player {
+    let (x, y, z) = spawn_point(ctx, &player.world_id);
+    let player = ctx.db.player().identity().update(Player {
         health: player.max_health,
-        x: 0.0,
-        y: 1.0,
-        z: 0.0,
+        shield: player.max_shield,
+        x,
+        y,
+        z,
         attack_speed: 1.0,
         last_update: now,
         ..player
     });
+    insert_life_event(ctx, &player, None, life_events::RESPAWN, now);
+    Ok(())
 }
 
 #[spacetimedb::reducer]
@@ -87,39 +264,23 @@ pub fn leave_game(ctx: &spacetimedb::ReducerContext) {
 #[spacetimedb::reducer(client_disconnected)]
 pub fn on_disconnect(ctx: &spacetimedb::ReducerContext) {
     set_player_offline(ctx);
+    rate_limit::clear(ctx);
+    lobby::drop_member(ctx);
+    trade::drop_offers(ctx);
+    ctx.db.ping_result().identity().delete(ctx.sender);
+    ctx.db.player_input().identity().delete(ctx.sender);
 }
 
+/// Also ends an observer session. Empty worlds are left to
+/// `collect_empty_worlds`, so a dropped connection can come back to its world
+/// within the world's lifetime.
 fn set_player_offline(ctx: &spacetimedb::ReducerContext) {
+    ctx.db.observer().identity().delete(ctx.sender);
     if let Some(player) = ctx.db.player().identity().find(ctx.sender) {
-        let world_id = player.world_id.clone();
-
         ctx.db.player().identity().update(Player {
             online: false,
             last_update: ctx.timestamp.to_micros_since_unix_epoch(),
             ..player
         });
-
-        // Clean up solo world data to prevent abandoned state accumulating.
-        // "shared" is the multiplayer world — never delete its entities.
-        if world_id != "shared" {
-            let enemies: Vec<Enemy> = ctx
-                .db
-                .enemy()
-                .iter()
-                .filter(|e| e.world_id == world_id)
-                .collect();
-            for enemy in enemies {
-                ctx.db.enemy().delete(enemy);
-            }
-            let events: Vec<CombatEvent> = ctx
-                .db
-                .combat_event()
-                .iter()
-                .filter(|e| e.world_id == world_id)
-                .collect();
-            for event in events {
-                ctx.db.combat_event().delete(event);
-            }
-        }
     }
 }
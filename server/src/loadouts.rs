@@ -0,0 +1,43 @@
+use spacetimedb::{Identity, Table};
+use wasm_fantasia_shared::presets::{EntityRules, Loadout};
+
+use crate::schema::*;
+
+/// The loadout `identity` picked, or the default if they never picked one.
+pub(crate) fn loadout_of(ctx: &spacetimedb::ReducerContext, identity: Identity) -> Loadout {
+    ctx.db
+        .player_loadout()
+        .identity()
+        .find(identity)
+        .and_then(|row| Loadout::parse_str(&row.loadout))
+        .unwrap_or_default()
+}
+
+/// Rules `identity`'s attacks and abilities run.
+pub(crate) fn player_rules(ctx: &spacetimedb::ReducerContext, identity: Identity) -> EntityRules {
+    loadout_of(ctx, identity).rules()
+}
+
+/// Pick the sender's loadout. Takes effect from their next swing.
+#[spacetimedb::reducer]
+pub fn set_loadout(ctx: &spacetimedb::ReducerContext, loadout: String) -> Result<(), String> {
+    let Some(loadout) = Loadout::parse_str(&loadout) else {
+        return Err(format!("Unknown loadout '{loadout}'"));
+    };
+    let row = PlayerLoadout {
+        identity: ctx.sender,
+        loadout: loadout.as_str().to_string(),
+    };
+    if ctx
+        .db
+        .player_loadout()
+        .identity()
+        .find(ctx.sender)
+        .is_some()
+    {
+        ctx.db.player_loadout().identity().update(row);
+    } else {
+        ctx.db.player_loadout().insert(row);
+    }
+    Ok(())
+}
@@ -0,0 +1,191 @@
+//! Lobbies gather players for a match of their own. The host opens one with
+//! `create_lobby`, others come in with `join_lobby`, and `start_match` opens
+//! a fresh world for it. Members then enter that world with `join_game`;
+//! nobody else can.
+
+use spacetimedb::{Identity, Table};
+
+use crate::schema::*;
+use crate::worlds::{new_world, MAX_INSTANCE_PLAYERS};
+
+const MAX_LOBBY_NAME_LEN: usize = 32;
+
+fn members_of(ctx: &spacetimedb::ReducerContext, lobby_id: u64) -> Vec<LobbyMember> {
+    ctx.db.lobby_member().lobby_id().filter(lobby_id).collect()
+}
+
+/// Delete `lobby` and its members.
+fn disband(ctx: &spacetimedb::ReducerContext, lobby: Lobby) {
+    for member in members_of(ctx, lobby.id) {
+        ctx.db.lobby_member().identity().delete(member.identity);
+    }
+    ctx.db.lobby().id().delete(lobby.id);
+}
+
+/// Take the sender out of their lobby, if they're in one that hasn't
+/// started. A host leaving disbands it.
+fn leave_open_lobby(ctx: &spacetimedb::ReducerContext) -> Result<(), String> {
+    let Some(member) = ctx.db.lobby_member().identity().find(ctx.sender) else {
+        return Ok(());
+    };
+    let Some(lobby) = ctx.db.lobby().id().find(member.lobby_id) else {
+        ctx.db.lobby_member().identity().delete(ctx.sender);
+        return Ok(());
+    };
+    if lobby.started_at != 0 {
+        return Err(format!("Lobby '{}' has already started", lobby.name));
+    }
+    if lobby.host == ctx.sender {
+        disband(ctx, lobby);
+    } else {
+        ctx.db.lobby_member().identity().delete(ctx.sender);
+    }
+    Ok(())
+}
+
+/// Whether `identity` may enter `world_id`: anyone may, unless it belongs to
+/// a lobby they aren't in.
+pub(crate) fn may_enter(
+    ctx: &spacetimedb::ReducerContext,
+    world_id: &str,
+    identity: Identity,
+) -> bool {
+    let Some(lobby) = ctx.db.lobby().world_id().filter(world_id).next() else {
+        return true;
+    };
+    ctx.db
+        .lobby_member()
+        .identity()
+        .find(identity)
+        .is_some_and(|m| m.lobby_id == lobby.id)
+}
+
+/// Called when `world_id` is deleted: lobbies playing in it go with it.
+pub(crate) fn disband_for_world(ctx: &spacetimedb::ReducerContext, world_id: &str) {
+    let lobbies: Vec<Lobby> = ctx.db.lobby().world_id().filter(world_id).collect();
+    for lobby in lobbies {
+        disband(ctx, lobby);
+    }
+}
+
+/// Called on disconnect: a player who drops doesn't hold a lobby open.
+pub(crate) fn drop_member(ctx: &spacetimedb::ReducerContext) {
+    let _ = leave_open_lobby(ctx);
+}
+
+/// Open a lobby hosted by the sender, leaving any other they're waiting in.
+#[spacetimedb::reducer]
+pub fn create_lobby(
+    ctx: &spacetimedb::ReducerContext,
+    name: String,
+    max_players: u32,
+) -> Result<(), String> {
+    let name = name.trim().to_string();
+    if name.is_empty() || name.chars().count() > MAX_LOBBY_NAME_LEN {
+        return Err(format!(
+            "Lobby names are 1 to {MAX_LOBBY_NAME_LEN} characters"
+        ));
+    }
+    if !(2..=MAX_INSTANCE_PLAYERS).contains(&max_players) {
+        return Err(format!(
+            "Max players must be between 2 and {MAX_INSTANCE_PLAYERS}"
+        ));
+    }
+    leave_open_lobby(ctx)?;
+
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    let lobby = ctx.db.lobby().insert(Lobby {
+        id: 0,
+        name,
+        host: ctx.sender,
+        max_players,
+        world_id: String::new(),
+        created_at: now,
+        started_at: 0,
+    });
+    ctx.db.lobby_member().insert(LobbyMember {
+        identity: ctx.sender,
+        lobby_id: lobby.id,
+        joined_at: now,
+    });
+    Ok(())
+}
+
+/// Join a lobby that hasn't started and has room, leaving any other the
+/// sender is waiting in.
+#[spacetimedb::reducer]
+pub fn join_lobby(ctx: &spacetimedb::ReducerContext, lobby_id: u64) -> Result<(), String> {
+    let Some(lobby) = ctx.db.lobby().id().find(lobby_id) else {
+        return Err(format!("No lobby {lobby_id}"));
+    };
+    if lobby.started_at != 0 {
+        return Err(format!("Lobby '{}' has already started", lobby.name));
+    }
+    if ctx
+        .db
+        .lobby_member()
+        .identity()
+        .find(ctx.sender)
+        .is_some_and(|m| m.lobby_id == lobby_id)
+    {
+        return Ok(());
+    }
+    if members_of(ctx, lobby_id).len() >= lobby.max_players as usize {
+        return Err(format!("Lobby '{}' is full", lobby.name));
+    }
+    leave_open_lobby(ctx)?;
+
+    ctx.db.lobby_member().insert(LobbyMember {
+        identity: ctx.sender,
+        lobby_id,
+        joined_at: ctx.timestamp.to_micros_since_unix_epoch(),
+    });
+    Ok(())
+}
+
+/// Leave the lobby the sender is waiting in. A host leaving disbands it.
+#[spacetimedb::reducer]
+pub fn leave_lobby(ctx: &spacetimedb::ReducerContext) -> Result<(), String> {
+    if ctx.db.lobby_member().identity().find(ctx.sender).is_none() {
+        return Err("Not in a lobby".into());
+    }
+    leave_open_lobby(ctx)
+}
+
+/// Open a fresh world for the sender's lobby and close it to newcomers.
+/// Members enter it with `join_game`. Host only.
+#[spacetimedb::reducer]
+pub fn start_match(ctx: &spacetimedb::ReducerContext) -> Result<(), String> {
+    let Some(member) = ctx.db.lobby_member().identity().find(ctx.sender) else {
+        return Err("Not in a lobby".into());
+    };
+    let Some(lobby) = ctx.db.lobby().id().find(member.lobby_id) else {
+        return Err("Not in a lobby".into());
+    };
+    if lobby.host != ctx.sender {
+        return Err("Only the host can start the match".into());
+    }
+    if lobby.started_at != 0 {
+        return Err(format!("Lobby '{}' has already started", lobby.name));
+    }
+
+    let world_id = format!("lobby-{}", lobby.id);
+    if ctx.db.world().id().find(&world_id).is_some() {
+        return Err(format!("World '{world_id}' already exists"));
+    }
+    ctx.db.world().insert(World {
+        max_players: lobby.max_players,
+        ..new_world(ctx, world_id.clone(), ctx.sender)
+    });
+    spacetimedb::log::info!(
+        "Lobby '{}' started with {} players in '{world_id}'",
+        lobby.name,
+        members_of(ctx, lobby.id).len()
+    );
+    ctx.db.lobby().id().update(Lobby {
+        world_id,
+        started_at: ctx.timestamp.to_micros_since_unix_epoch(),
+        ..lobby
+    });
+    Ok(())
+}
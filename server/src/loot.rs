@@ -0,0 +1,93 @@
+use spacetimedb::Table;
+use wasm_fantasia_shared::loot::{self, LootItem};
+use wasm_fantasia_shared::rng::deterministic_random_u64;
+
+use crate::matches::record_loot_pickup;
+use crate::metrics::count_call;
+use crate::schema::*;
+
+/// Position updates lag the client, so a player who reached the loot on
+/// their screen may still be this much further away on the server.
+const RANGE_TOLERANCE: f32 = 1.5;
+
+fn lifetime_micros() -> i64 {
+    (loot::LIFETIME_SECS * 1_000_000.0) as i64
+}
+
+/// Roll a drop for an enemy that just died and place it where it fell.
+pub(crate) fn drop_loot(ctx: &spacetimedb::ReducerContext, enemy: &Enemy, now: i64) {
+    prune_loot(ctx, &enemy.world_id, now);
+
+    let Some(item) = loot::roll_drop(deterministic_random_u64(now, enemy.id)) else {
+        return;
+    };
+    place_loot(ctx, &enemy.world_id, item, (enemy.x, enemy.y, enemy.z), now);
+}
+
+/// Put `item` on the ground for anyone in the world to pick up.
+pub(crate) fn place_loot(
+    ctx: &spacetimedb::ReducerContext,
+    world_id: &str,
+    item: LootItem,
+    (x, y, z): (f32, f32, f32),
+    now: i64,
+) {
+    ctx.db.loot_drop().insert(LootDrop {
+        id: 0,
+        item_id: item.as_str().to_string(),
+        world_id: world_id.to_string(),
+        x,
+        y,
+        z,
+        timestamp: now,
+    });
+}
+
+fn prune_loot(ctx: &spacetimedb::ReducerContext, world_id: &str, now: i64) {
+    let expired: Vec<LootDrop> = ctx
+        .db
+        .loot_drop()
+        .iter()
+        .filter(|l| l.world_id == world_id && now - l.timestamp > lifetime_micros())
+        .collect();
+    for drop in expired {
+        ctx.db.loot_drop().delete(drop);
+    }
+}
+
+/// Pick up a loot drop next to the sender and apply it.
+#[spacetimedb::reducer]
+pub fn pickup_loot(ctx: &spacetimedb::ReducerContext, loot_id: u64) -> Result<(), String> {
+    count_call(ctx, "pickup_loot");
+    let Some(player) = ctx.db.player().identity().find(ctx.sender) else {
+        return Err("Not in a game".into());
+    };
+    if player.health <= 0.0 {
+        return Err("Dead players can't pick up loot".into());
+    }
+    let Some(drop) = ctx.db.loot_drop().id().find(loot_id) else {
+        // Someone else got there first
+        return Err("Loot is gone".into());
+    };
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    if drop.world_id != player.world_id || now - drop.timestamp > lifetime_micros() {
+        return Err("Loot is gone".into());
+    }
+
+    let distance =
+        glam::Vec3::new(player.x - drop.x, player.y - drop.y, player.z - drop.z).length();
+    if distance > loot::PICKUP_RANGE + RANGE_TOLERANCE {
+        return Err("Too far away".into());
+    }
+
+    let item = LootItem::parse_str(&drop.item_id);
+    ctx.db.loot_drop().delete(drop);
+    record_loot_pickup(ctx, &player);
+    if let Some(item) = item {
+        ctx.db.player().identity().update(Player {
+            health: (player.health + item.heal()).min(player.max_health),
+            ..player
+        });
+    }
+    Ok(())
+}
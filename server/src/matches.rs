@@ -0,0 +1,289 @@
+use spacetimedb::Table;
+use wasm_fantasia_shared::combat::{rewards, HitResult};
+
+use crate::director::{director_running, start_director_in};
+use crate::leaderboard::{tally_hit, tally_kill};
+use crate::progression::grant_xp;
+use crate::schema::*;
+use crate::world_events::xp_multiplier;
+
+/// Restart a finished match in the caller's world: revive the party, reset
+/// their stats and start the waves over where the last match started.
+#[spacetimedb::reducer]
+pub fn play_again(ctx: &spacetimedb::ReducerContext) {
+    let Some(player) = ctx.db.player().identity().find(ctx.sender) else {
+        return;
+    };
+    let world_id = player.world_id;
+
+    // Another party member may have restarted already.
+    let Some(state) = ctx.db.match_state().world_id().find(&world_id) else {
+        return;
+    };
+    if state.active {
+        return;
+    }
+
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    let party: Vec<Player> = ctx
+        .db
+        .player()
+        .iter()
+        .filter(|p| p.world_id == world_id)
+        .collect();
+    for member in party {
+        // Revived here, so no respawn timer is left to run out
+        ctx.db.death().identity().delete(member.identity);
+        ctx.db.player().identity().update(Player {
+            health: member.max_health,
+            shield: member.max_shield,
+            last_update: now,
+            ..member
+        });
+    }
+
+    start_director_in(ctx, &world_id, state.spawn_x, state.spawn_y, state.spawn_z);
+}
+
+/// Begin a match in `world_id` unless one is already running.
+pub(crate) fn start_match(
+    ctx: &spacetimedb::ReducerContext,
+    world_id: &str,
+    x: f32,
+    y: f32,
+    z: f32,
+) {
+    let existing = ctx.db.match_state().world_id().find(world_id.to_string());
+    if existing.as_ref().is_some_and(|s| s.active) {
+        return;
+    }
+
+    let state = MatchState {
+        world_id: world_id.to_string(),
+        active: true,
+        started_at: ctx.timestamp.to_micros_since_unix_epoch(),
+        ended_at: 0,
+        spawn_x: x,
+        spawn_y: y,
+        spawn_z: z,
+    };
+    if existing.is_some() {
+        ctx.db.match_state().world_id().update(state);
+    } else {
+        ctx.db.match_state().insert(state);
+    }
+
+    clear_stats(ctx, world_id);
+}
+
+/// Drop the match and its stats without producing a summary.
+pub(crate) fn abort_match(ctx: &spacetimedb::ReducerContext, world_id: &str) {
+    ctx.db.match_state().world_id().delete(world_id.to_string());
+    clear_stats(ctx, world_id);
+}
+
+/// Tally a resolved hit into the attacker's leaderboard row and match stats.
+pub(crate) fn record_hit(ctx: &spacetimedb::ReducerContext, attacker: &Player, hit: &HitResult) {
+    tally_hit(ctx, attacker, hit.damage, hit.died);
+    let Some(mut stats) = stats_for(ctx, attacker) else {
+        return;
+    };
+
+    stats.damage_dealt += hit.damage;
+    if hit.is_crit {
+        stats.crits += 1;
+    }
+    if hit.died {
+        credit_kill(&mut stats);
+    }
+    save_stats(ctx, stats);
+
+    // Call sites record before deleting the dead enemy, so it's still here.
+    if hit.died {
+        if let Some(target) = ctx.db.enemy().id().find(hit.target_id) {
+            share_kill_xp(ctx, attacker, &target);
+        }
+    }
+}
+
+/// Credit a kill the killer didn't land directly, e.g. knocking an enemy off the arena.
+pub(crate) fn record_kill(ctx: &spacetimedb::ReducerContext, killer: &Player, target: &Enemy) {
+    tally_kill(ctx, killer);
+    let Some(mut stats) = stats_for(ctx, killer) else {
+        return;
+    };
+
+    credit_kill(&mut stats);
+    save_stats(ctx, stats);
+    share_kill_xp(ctx, killer, target);
+}
+
+/// Split a kill's XP across alive party members near the target, with a
+/// bonus for each extra member, and notify each recipient. The XP counts
+/// toward their level whether or not a match is running.
+fn share_kill_xp(ctx: &spacetimedb::ReducerContext, killer: &Player, target: &Enemy) {
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    let radius_sq = rewards::SHARED_XP_RADIUS * rewards::SHARED_XP_RADIUS;
+
+    let stale_threshold = now - 5_000_000;
+    let stale: Vec<XpEvent> = ctx
+        .db
+        .xp_event()
+        .iter()
+        .filter(|e| e.world_id == killer.world_id && e.timestamp < stale_threshold)
+        .collect();
+    for event in stale {
+        ctx.db.xp_event().delete(event);
+    }
+
+    // The killer always counts, even if the blow came from range.
+    let recipients: Vec<Player> = ctx
+        .db
+        .player()
+        .iter()
+        .filter(|p| p.world_id == killer.world_id && p.online && p.health > 0.0)
+        .filter(|p| {
+            let dx = p.x - target.x;
+            let dz = p.z - target.z;
+            p.identity == killer.identity || dx * dx + dz * dz <= radius_sq
+        })
+        .collect();
+
+    let xp =
+        rewards::shared_kill_xp(recipients.len() as u32) * xp_multiplier(ctx, &killer.world_id);
+    for member in &recipients {
+        grant_xp(ctx, member.identity, xp);
+        if let Some(mut stats) = stats_for(ctx, member) {
+            stats.xp_gained += xp;
+            save_stats(ctx, stats);
+        }
+
+        ctx.db.xp_event().insert(XpEvent {
+            id: 0,
+            recipient: member.identity,
+            amount: xp,
+            shared: member.identity != killer.identity,
+            x: target.x,
+            y: target.y,
+            z: target.z,
+            world_id: killer.world_id.clone(),
+            timestamp: now,
+        });
+    }
+}
+
+/// The player's stats for the active match in their world, or `None` outside a match.
+fn stats_for(ctx: &spacetimedb::ReducerContext, player: &Player) -> Option<MatchStats> {
+    let in_match = ctx
+        .db
+        .match_state()
+        .world_id()
+        .find(&player.world_id)
+        .is_some_and(|s| s.active);
+    if !in_match {
+        return None;
+    }
+
+    // A row left over from another world shares the identity key — start fresh.
+    let stats = match ctx.db.match_stats().identity().find(player.identity) {
+        Some(stats) if stats.world_id == player.world_id => stats,
+        _ => MatchStats {
+            identity: player.identity,
+            world_id: player.world_id.clone(),
+            kills: 0,
+            damage_dealt: 0.0,
+            crits: 0,
+            xp_gained: 0,
+            loot_earned: 0,
+        },
+    };
+    Some(stats)
+}
+
+fn save_stats(ctx: &spacetimedb::ReducerContext, stats: MatchStats) {
+    ctx.db.match_stats().identity().delete(stats.identity);
+    ctx.db.match_stats().insert(stats);
+}
+
+/// Kill count stays with the killer; XP goes through [`share_kill_xp`].
+fn credit_kill(stats: &mut MatchStats) {
+    stats.kills += 1;
+}
+
+/// Count a loot drop the player picked up.
+pub(crate) fn record_loot_pickup(ctx: &spacetimedb::ReducerContext, player: &Player) {
+    let Some(mut stats) = stats_for(ctx, player) else {
+        return;
+    };
+    stats.loot_earned += 1;
+    save_stats(ctx, stats);
+}
+
+/// End the match once the world has no enemies left, granting the clear bonus.
+pub(crate) fn end_match_if_cleared(ctx: &spacetimedb::ReducerContext, world_id: &str) {
+    let Some(state) = ctx.db.match_state().world_id().find(world_id.to_string()) else {
+        return;
+    };
+    if !state.active
+        || director_running(ctx, world_id)
+        || ctx.db.enemy().iter().any(|e| e.world_id == world_id)
+    {
+        return;
+    }
+
+    let party: Vec<Player> = ctx
+        .db
+        .player()
+        .iter()
+        .filter(|p| p.world_id == world_id && p.online)
+        .collect();
+    for member in party {
+        grant_xp(ctx, member.identity, rewards::XP_MATCH_CLEAR);
+        let stats = ctx
+            .db
+            .match_stats()
+            .identity()
+            .find(member.identity)
+            .filter(|s| s.world_id == world_id);
+        match stats {
+            Some(stats) => {
+                ctx.db.match_stats().identity().update(MatchStats {
+                    xp_gained: stats.xp_gained + rewards::XP_MATCH_CLEAR,
+                    ..stats
+                });
+            }
+            None => {
+                // Joined late or never landed a hit — still shares the clear.
+                ctx.db.match_stats().identity().delete(member.identity);
+                ctx.db.match_stats().insert(MatchStats {
+                    identity: member.identity,
+                    world_id: world_id.to_string(),
+                    kills: 0,
+                    damage_dealt: 0.0,
+                    crits: 0,
+                    xp_gained: rewards::XP_MATCH_CLEAR,
+                    loot_earned: 0,
+                });
+            }
+        }
+    }
+
+    ctx.db.match_state().world_id().update(MatchState {
+        active: false,
+        ended_at: ctx.timestamp.to_micros_since_unix_epoch(),
+        ..state
+    });
+    spacetimedb::log::info!("Match ended in world {}", world_id);
+}
+
+fn clear_stats(ctx: &spacetimedb::ReducerContext, world_id: &str) {
+    let stale: Vec<MatchStats> = ctx
+        .db
+        .match_stats()
+        .iter()
+        .filter(|s| s.world_id == world_id)
+        .collect();
+    for stats in stale {
+        ctx.db.match_stats().delete(stats);
+    }
+}
@@ -0,0 +1,117 @@
+use spacetimedb::Table;
+
+use crate::config::Tuning;
+use crate::schema::*;
+
+const COUNTER: &str = "counter";
+const GAUGE: &str = "gauge";
+/// How often the row-count gauges are refreshed.
+const GAUGE_REFRESH_MICROS: i64 = 1_000_000;
+/// A tick arriving this many intervals after the last one counts as overrun.
+const TICK_OVERRUN_FACTOR: i64 = 2;
+
+/// Count one call of `reducer`. Called at the top of the reducers clients
+/// drive during play, so their call rates can be graphed.
+pub(crate) fn count_call(ctx: &spacetimedb::ReducerContext, reducer: &str) {
+    add(ctx, "reducer_calls_total", reducer, 1.0);
+}
+
+/// Count one call of `reducer` dropped by its rate limit.
+pub(crate) fn count_rejection(ctx: &spacetimedb::ReducerContext, reducer: &str) {
+    add(ctx, "rate_limited_total", reducer, 1.0);
+}
+
+/// Called from `game_tick`. Reducers run one at a time, so a tick that
+/// fires late means the reducers before it took that long: the spacing
+/// between ticks is the module's view of how long reducers are taking,
+/// since it can't read a clock mid-call. Also refreshes the row-count
+/// gauges about once a second.
+pub(crate) fn record_tick(ctx: &spacetimedb::ReducerContext, tuning: &Tuning) {
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    count_call(ctx, "game_tick");
+
+    let last = find(ctx, "game_tick_interval_seconds", "").map(|m| m.updated_at);
+    if let Some(last) = last {
+        let interval = now - last;
+        set(
+            ctx,
+            "game_tick_interval_seconds",
+            interval as f64 / 1_000_000.0,
+        );
+        add(
+            ctx,
+            "game_tick_seconds_total",
+            "",
+            interval as f64 / 1_000_000.0,
+        );
+        if interval >= tuning.tick_interval_micros * TICK_OVERRUN_FACTOR {
+            add(ctx, "game_tick_overruns_total", "", 1.0);
+        }
+    } else {
+        set(ctx, "game_tick_interval_seconds", 0.0);
+    }
+
+    let refreshed = find(ctx, "players_online", "").map_or(0, |m| m.updated_at);
+    if now - refreshed < GAUGE_REFRESH_MICROS {
+        return;
+    }
+    let players_online = ctx.db.player().iter().filter(|p| p.online).count();
+    set(ctx, "players_online", players_online as f64);
+    set(ctx, "observers", ctx.db.observer().count() as f64);
+    set(ctx, "enemies_alive", ctx.db.enemy().count() as f64);
+    set(ctx, "worlds", ctx.db.world().count() as f64);
+    set(ctx, "combat_events", ctx.db.combat_event().count() as f64);
+}
+
+fn key(name: &str, reducer: &str) -> String {
+    if reducer.is_empty() {
+        name.to_string()
+    } else {
+        format!("{name}/{reducer}")
+    }
+}
+
+fn find(ctx: &spacetimedb::ReducerContext, name: &str, reducer: &str) -> Option<Metric> {
+    ctx.db.metrics().key().find(key(name, reducer))
+}
+
+/// Increase a counter by `amount`.
+fn add(ctx: &spacetimedb::ReducerContext, name: &str, reducer: &str, amount: f64) {
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    match find(ctx, name, reducer) {
+        Some(metric) => {
+            ctx.db.metrics().key().update(Metric {
+                value: metric.value + amount,
+                updated_at: now,
+                ..metric
+            });
+        }
+        None => {
+            ctx.db.metrics().insert(Metric {
+                key: key(name, reducer),
+                name: name.to_string(),
+                reducer: reducer.to_string(),
+                kind: COUNTER.to_string(),
+                value: amount,
+                updated_at: now,
+            });
+        }
+    }
+}
+
+/// Set a server-wide gauge.
+fn set(ctx: &spacetimedb::ReducerContext, name: &str, value: f64) {
+    let metric = Metric {
+        key: key(name, ""),
+        name: name.to_string(),
+        reducer: String::new(),
+        kind: GAUGE.to_string(),
+        value,
+        updated_at: ctx.timestamp.to_micros_since_unix_epoch(),
+    };
+    if find(ctx, name, "").is_some() {
+        ctx.db.metrics().key().update(metric);
+    } else {
+        ctx.db.metrics().insert(metric);
+    }
+}
@@ -0,0 +1,145 @@
+//! The input relay (see [`Relay`]): clients send `player_input` instead of
+//! `update_position`, and `game_tick` moves each player with the shared
+//! [`movement::step`], so both sides integrate the same way.
+
+use spacetimedb::Table;
+use wasm_fantasia_shared::gameplay::{gameplay, Relay};
+use wasm_fantasia_shared::movement::{self, MoveInput, MoveState};
+use wasm_fantasia_shared::net::{self, AnimationId};
+
+use crate::schema::*;
+use crate::{effects, metrics, rate_limit, triggers};
+
+pub(crate) fn input_relay() -> bool {
+    gameplay().relay == Relay::Input
+}
+
+/// Client input relay: the horizontal velocity the player wants, a jump to
+/// start (height in meters, 0 for none) and the same heading, animation and
+/// attack fields as `update_position`. Movement itself waits for the next
+/// `game_tick`; `input_seq` comes back on the row once it has been applied.
+#[spacetimedb::reducer]
+pub fn player_input(
+    ctx: &spacetimedb::ReducerContext,
+    velocity_x: f32,
+    velocity_z: f32,
+    jump_height: f32,
+    heading: u8,
+    animation: u8,
+    attack_sequence: u32,
+    attack_animation: u8,
+    input_seq: u32,
+) {
+    metrics::count_call(ctx, "player_input");
+    if !input_relay() || !rate_limit::allow(ctx, "player_input") {
+        return;
+    }
+    let Some(player) = ctx
+        .db
+        .player()
+        .identity()
+        .find(ctx.sender)
+        .filter(|p| p.online)
+    else {
+        return;
+    };
+    let previous_animation = player.animation;
+    let player = ctx.db.player().identity().update(Player {
+        rotation_y: net::dequantize_rotation(heading),
+        animation: AnimationId::from_u8(animation).as_u8(),
+        attack_sequence,
+        attack_animation: AnimationId::from_u8(attack_animation).as_u8(),
+        ..player
+    });
+    triggers::dodge_started(
+        ctx,
+        previous_animation,
+        &player,
+        ctx.timestamp.to_micros_since_unix_epoch(),
+    );
+
+    // Non-finite velocities stop the player rather than poison their row
+    let finite = |v: f32| if v.is_finite() { v } else { 0.0 };
+    let jump_height = finite(jump_height).max(0.0);
+    match ctx.db.player_input().identity().find(ctx.sender) {
+        // A jump sent between ticks isn't lost to the next input
+        Some(input) => {
+            ctx.db.player_input().identity().update(PlayerInput {
+                velocity_x: finite(velocity_x),
+                velocity_z: finite(velocity_z),
+                jump_height: input.jump_height.max(jump_height),
+                input_seq,
+                ..input
+            });
+        }
+        None => {
+            ctx.db.player_input().insert(PlayerInput {
+                identity: ctx.sender,
+                velocity_x: finite(velocity_x),
+                velocity_z: finite(velocity_z),
+                jump_height,
+                velocity_y: 0.0,
+                input_seq,
+            });
+        }
+    }
+}
+
+/// Called from `game_tick` under the input relay: move every alive, online
+/// player by their latest input. Players in paused worlds hold still.
+pub(crate) fn tick_player_inputs(ctx: &spacetimedb::ReducerContext, now: i64, dt: f32) {
+    for input in ctx.db.player_input().iter() {
+        let Some(player) = ctx
+            .db
+            .player()
+            .identity()
+            .find(input.identity)
+            .filter(|p| p.online && p.health > 0.0)
+        else {
+            continue;
+        };
+        if ctx
+            .db
+            .world_pause()
+            .world_id()
+            .find(&player.world_id)
+            .is_some()
+        {
+            continue;
+        }
+
+        // Stunned players still fall, they just can't steer or jump
+        let impairment = effects::impairment(ctx, player.identity);
+        let state = movement::step(
+            MoveState {
+                x: player.x,
+                y: player.y,
+                z: player.z,
+                velocity_y: input.velocity_y,
+            },
+            MoveInput {
+                velocity_x: input.velocity_x * impairment.speed_multiplier,
+                velocity_z: input.velocity_z * impairment.speed_multiplier,
+                jump_height: if impairment.can_move() {
+                    input.jump_height
+                } else {
+                    0.0
+                },
+            },
+            dt,
+        );
+        ctx.db.player().identity().update(Player {
+            x: state.x,
+            y: state.y,
+            z: state.z,
+            last_update: now,
+            input_seq: input.input_seq,
+            ..player
+        });
+        ctx.db.player_input().identity().update(PlayerInput {
+            velocity_y: state.velocity_y,
+            jump_height: 0.0,
+            ..input
+        });
+    }
+}
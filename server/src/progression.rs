@@ -0,0 +1,36 @@
+use wasm_fantasia_shared::leveling;
+
+use crate::inventory::with_character_stats;
+use crate::schema::*;
+
+/// Add XP to a player and apply any level-ups it brings.
+///
+/// A level-up rescales the player's stats and heals by the max health
+/// gained, so leveling mid-fight doesn't leave the bar looking emptier.
+pub(crate) fn grant_xp(
+    ctx: &spacetimedb::ReducerContext,
+    identity: spacetimedb::Identity,
+    amount: u32,
+) {
+    let Some(player) = ctx.db.player().identity().find(identity) else {
+        return;
+    };
+
+    let xp = player.xp.saturating_add(amount);
+    let level = leveling::level_for_xp(xp);
+    if level == player.level {
+        ctx.db.player().identity().update(Player { xp, ..player });
+        return;
+    }
+
+    spacetimedb::log::info!("{} reached level {}", identity, level);
+    let leveled = Player {
+        xp,
+        level,
+        ..player
+    };
+    ctx.db
+        .player()
+        .identity()
+        .update(with_character_stats(ctx, leveled));
+}
@@ -0,0 +1,151 @@
+//! Per-identity rate limits on the reducers clients call during play. Each
+//! (identity, reducer) pair has a token bucket in the private `rate_limit`
+//! table: a call spends a token, tokens refill at a steady rate up to the
+//! bucket's capacity, and a call finding the bucket empty is dropped. Honest
+//! clients stay well inside the limits; they're there so a rogue one can't
+//! flood the module with work.
+
+use spacetimedb::{Identity, Table};
+
+use crate::metrics::count_rejection;
+use crate::schema::*;
+
+/// Log a sender every this many rejected calls, so a flood shows up in the
+/// logs without filling them.
+const LOG_EVERY: u32 = 100;
+
+/// Buckets that refill from empty faster than this are dropped on
+/// disconnect; slower ones are kept until they've refilled.
+const FORGET_REFILL_SECS: f32 = 5.0;
+
+/// A reducer's bucket size and refill rate.
+struct Limit {
+    reducer: &'static str,
+    /// Calls that can be made in one burst.
+    capacity: f32,
+    /// Calls a second that can be kept up.
+    per_sec: f32,
+}
+
+const LIMITS: [Limit; 7] = [
+    // Clients send their position 20 times a second
+    Limit {
+        reducer: "update_position",
+        capacity: 40.0,
+        per_sec: 30.0,
+    },
+    // …or their input, at the same rate
+    Limit {
+        reducer: "player_input",
+        capacity: 40.0,
+        per_sec: 30.0,
+    },
+    // Well past the fastest attack speed the cooldown allows
+    Limit {
+        reducer: "attack_hit",
+        capacity: 10.0,
+        per_sec: 8.0,
+    },
+    // Each call restarts a match and its spawn director
+    Limit {
+        reducer: "spawn_enemies",
+        capacity: 3.0,
+        per_sec: 0.5,
+    },
+    // Each call starts a match and drops a whole pack, bosses included
+    Limit {
+        reducer: "spawn_archetype",
+        capacity: 3.0,
+        per_sec: 0.5,
+    },
+    // Password guesses; a typo or two shouldn't lock anyone out
+    Limit {
+        reducer: "sign_in",
+        capacity: 5.0,
+        per_sec: 0.2,
+    },
+    Limit {
+        reducer: "register_account",
+        capacity: 5.0,
+        per_sec: 0.2,
+    },
+];
+
+fn key(identity: Identity, reducer: &str) -> String {
+    format!("{}/{reducer}", identity.to_hex())
+}
+
+/// Take a token from the sender's bucket for `reducer`. Returns false when
+/// the call is over the limit and should be dropped.
+pub(crate) fn allow(ctx: &spacetimedb::ReducerContext, reducer: &str) -> bool {
+    let Some(limit) = LIMITS.iter().find(|l| l.reducer == reducer) else {
+        return true;
+    };
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    let key = key(ctx.sender, reducer);
+
+    let Some(bucket) = ctx.db.rate_limit().key().find(&key) else {
+        ctx.db.rate_limit().insert(RateLimit {
+            key,
+            identity: ctx.sender,
+            tokens: limit.capacity - 1.0,
+            refilled_at: now,
+            rejected: 0,
+        });
+        return true;
+    };
+
+    let elapsed = (now - bucket.refilled_at).max(0) as f32 / 1_000_000.0;
+    let tokens = (bucket.tokens + elapsed * limit.per_sec).min(limit.capacity);
+    if tokens >= 1.0 {
+        ctx.db.rate_limit().key().update(RateLimit {
+            tokens: tokens - 1.0,
+            refilled_at: now,
+            ..bucket
+        });
+        return true;
+    }
+
+    let rejected = bucket.rejected + 1;
+    if rejected % LOG_EVERY == 1 {
+        spacetimedb::log::warn!(
+            "Rate limiting {reducer} from {} ({rejected} calls dropped)",
+            ctx.sender
+        );
+    }
+    count_rejection(ctx, reducer);
+    ctx.db.rate_limit().key().update(RateLimit {
+        tokens,
+        refilled_at: now,
+        rejected,
+        ..bucket
+    });
+    false
+}
+
+/// Forget the sender's buckets that refill within a few seconds anyway.
+/// Slow ones, like `sign_in`'s, stay until they're full again, so
+/// reconnecting doesn't buy a fresh burst; the next disconnect after that
+/// drops them.
+pub(crate) fn clear(ctx: &spacetimedb::ReducerContext) {
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    for bucket in ctx
+        .db
+        .rate_limit()
+        .identity()
+        .filter(ctx.sender)
+        .collect::<Vec<_>>()
+    {
+        let keep = LIMITS
+            .iter()
+            .find(|l| key(ctx.sender, l.reducer) == bucket.key)
+            .is_some_and(|limit| {
+                let elapsed = (now - bucket.refilled_at).max(0) as f32 / 1_000_000.0;
+                limit.capacity / limit.per_sec > FORGET_REFILL_SECS
+                    && bucket.tokens + elapsed * limit.per_sec < limit.capacity
+            });
+        if !keep {
+            ctx.db.rate_limit().key().delete(&bucket.key);
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use spacetimedb::Table;
+use wasm_fantasia_shared::combat::defaults;
+
+use crate::schema::*;
+
+/// How long position samples are kept. A little past the max rewind so the
+/// oldest rewind target still has a sample at or before it.
+const HISTORY_MICROS: i64 = (defaults::MAX_HIT_REWIND_SECS * 1_000_000.0) as i64 + 100_000;
+
+/// Record where an enemy ended up this tick.
+pub(crate) fn record_position(
+    ctx: &spacetimedb::ReducerContext,
+    enemy_id: u64,
+    x: f32,
+    y: f32,
+    z: f32,
+    now: i64,
+) {
+    ctx.db
+        .enemy_position_history()
+        .insert(EnemyPositionHistory {
+            id: 0,
+            enemy_id,
+            x,
+            y,
+            z,
+            timestamp: now,
+        });
+}
+
+/// Drop samples too old to ever be rewound to.
+pub(crate) fn prune_history(ctx: &spacetimedb::ReducerContext, now: i64) {
+    let cutoff = now - HISTORY_MICROS;
+    ctx.db.enemy_position_history().timestamp().delete(..cutoff);
+}
+
+/// Where `enemy` was as seen by an attacker with the given round trip time.
+///
+/// Uses the newest sample at or before the rewound time; falls back to the
+/// live position when there's no history (freshly spawned, or zero RTT).
+pub(crate) fn rewound_position(
+    ctx: &spacetimedb::ReducerContext,
+    enemy: &Enemy,
+    rtt_ms: f32,
+    now: i64,
+) -> (f32, f32, f32) {
+    let rewind_secs = (rtt_ms / 1000.0).min(defaults::MAX_HIT_REWIND_SECS);
+    let at = now - (rewind_secs * 1_000_000.0) as i64;
+
+    ctx.db
+        .enemy_position_history()
+        .enemy_id()
+        .filter(enemy.id)
+        .filter(|h| h.timestamp <= at)
+        .max_by_key(|h| h.timestamp)
+        .map(|h| (h.x, h.y, h.z))
+        .unwrap_or((enemy.x, enemy.y, enemy.z))
+}
@@ -5,6 +5,7 @@ pub struct Player {
     pub identity: spacetimedb::Identity,
     pub name: Option<String>,
     pub online: bool,
+    #[index(btree)]
     pub world_id: String,
     pub last_update: i64,
 
@@ -14,14 +15,20 @@ pub struct Player {
     pub z: f32,
     pub rotation_y: f32,
 
-    // Animation
-    pub animation_state: String,
+    // Animation, as [`AnimationId`](wasm_fantasia_shared::net::AnimationId)s
+    pub animation: u8,
     pub attack_sequence: u32,
-    pub attack_animation: String,
+    pub attack_animation: u8,
 
     // Health
     pub health: f32,
     pub max_health: f32,
+    /// Absorbs damage before health; recharges once
+    /// [`SHIELD_REGEN_DELAY`](wasm_fantasia_shared::combat::defaults::SHIELD_REGEN_DELAY)
+    /// has passed since `last_hit_time`.
+    pub shield: f32,
+    pub max_shield: f32,
+    pub last_hit_time: i64,
 
     // Combat
     pub attack_damage: f32,
@@ -32,6 +39,28 @@ pub struct Player {
     pub knockback_force: f32,
     pub attack_speed: f32,
     pub last_attack_time: i64,
+    /// [`Weapon`](wasm_fantasia_shared::weapons::Weapon) id, from the
+    /// equipped weapon-slot item. Sets the attack pace and moveset.
+    pub weapon: String,
+    /// Opted in to player-vs-player. Hits only land between two opted-in players.
+    pub pvp: bool,
+
+    // Progression
+    /// Lifetime XP; `level` and the level-scaled stats above follow from it.
+    pub xp: u32,
+    pub level: u32,
+
+    // Network
+    /// Round-trip time reported by the client, used to rewind hit validation.
+    pub rtt_ms: f32,
+    /// Sequence number of the newest `update_position` applied this session.
+    /// Clients compare the row against what they sent with it to spot where
+    /// the server disagrees.
+    pub input_seq: u32,
+    /// Sequence number of the newest `attack_hit` handled this session.
+    /// Retried intents at or below it are duplicates and aren't resolved
+    /// again; clients stop retrying once it reaches theirs.
+    pub attack_intent_seq: u32,
 }
 
 /// Server-authoritative enemy.
@@ -41,6 +70,7 @@ pub struct Enemy {
     #[auto_inc]
     pub id: u64,
     pub enemy_type: String,
+    #[index(btree)]
     pub world_id: String,
 
     // Position
@@ -54,18 +84,109 @@ pub struct Enemy {
     pub velocity_y: f32,
     pub velocity_z: f32,
 
-    // Animation
-    pub animation_state: String,
+    // AI decision, which also picks the animation
+    /// [`EnemyBehaviorKind`](wasm_fantasia_shared::combat::EnemyBehaviorKind) id.
+    pub behavior: u8,
 
     // Health
     pub health: f32,
     pub max_health: f32,
+    pub shield: f32,
+    pub max_shield: f32,
 
     // Combat
     pub attack_damage: f32,
     pub attack_range: f32,
     pub attack_speed: f32,
     pub last_attack_time: i64,
+    /// Last time this enemy took damage — gates shield regen.
+    pub last_hit_time: i64,
+    /// Player who dealt that damage — credited if the enemy falls out of the world.
+    pub last_hit_by: Option<spacetimedb::Identity>,
+}
+
+/// Tunables for one kind of enemy, keyed by the `enemy_type` that enemies of
+/// this kind carry. `basic` is seeded on init; the rest are added at runtime
+/// through `upsert_enemy_archetype`.
+#[spacetimedb::table(name = enemy_archetype, public)]
+pub struct EnemyArchetype {
+    #[primary_key]
+    pub name: String,
+    pub max_health: f32,
+    pub max_shield: f32,
+    pub attack_damage: f32,
+    pub attack_range: f32,
+    pub attack_speed: f32,
+    /// Seconds between attacks.
+    pub attack_cooldown: f32,
+    /// Chase speed in m/s.
+    pub walk_speed: f32,
+    /// Physics mass — heavier enemies are knocked back less.
+    pub mass: f32,
+    pub pack_size_min: u32,
+    pub pack_size_max: u32,
+}
+
+/// A castable ability, keyed by the name `cast_ability` takes. The built-in
+/// ones from `wasm_fantasia_shared::abilities` are seeded on init.
+#[spacetimedb::table(name = ability, public)]
+pub struct Ability {
+    #[primary_key]
+    pub name: String,
+    /// [`AbilityShape`](wasm_fantasia_shared::abilities::AbilityShape) name.
+    pub shape: String,
+    pub range: f32,
+    /// Cone only: full arc in degrees.
+    pub arc: f32,
+    /// Projectile only: how far off its line a target still gets hit.
+    pub width: f32,
+    pub damage_multiplier: f32,
+    pub knockback: f32,
+    pub launch: f32,
+    /// Seconds between casts.
+    pub cooldown: f32,
+    /// Attack stacks spent per cast.
+    pub stack_cost: f32,
+    /// Mana spent per cast, before the caster's `on_cast` rules.
+    pub mana_cost: f32,
+}
+
+/// Identities allowed to edit server data such as enemy archetypes. Whoever
+/// published the module is added on init.
+#[spacetimedb::table(name = admin)]
+pub struct Admin {
+    #[primary_key]
+    pub identity: spacetimedb::Identity,
+}
+
+/// A world's setup exported as JSON: enemy archetypes, abilities, enemy
+/// placements and the match spawn point. Written by `export_world`, applied
+/// by `import_world`; `store_world_snapshot` uploads one taken elsewhere.
+#[spacetimedb::table(name = world_snapshot)]
+pub struct WorldSnapshot {
+    #[primary_key]
+    pub name: String,
+    /// World it was exported from; empty for uploaded snapshots.
+    pub source_world: String,
+    pub json: String,
+    pub saved_by: spacetimedb::Identity,
+    pub saved_at: i64,
+}
+
+/// Recent enemy position, one row per enemy per tick. Lets `attack_hit` see
+/// targets where a lagging attacker saw them. Pruned by `game_tick`.
+#[spacetimedb::table(name = enemy_position_history)]
+pub struct EnemyPositionHistory {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub enemy_id: u64,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    #[index(btree)]
+    pub timestamp: i64,
 }
 
 /// Ephemeral hit notification. Inserted by attack_hit, consumed by clients for VFX.
@@ -79,24 +200,412 @@ pub struct CombatEvent {
     pub z: f32,
     pub damage: f32,
     pub is_crit: bool,
+    pub attacker: spacetimedb::Identity,
+    pub target_id: u64,
+    /// Set when the target was a player; `target_id` is meaningless then.
+    pub target_player: Option<spacetimedb::Identity>,
+    /// Knockback for a player target, which their own client applies since
+    /// it owns their position. Enemies get theirs through `knockback_impulse`.
+    pub knockback_x: f32,
+    pub knockback_y: f32,
+    pub knockback_z: f32,
+    /// [`HitReaction`](wasm_fantasia_shared::combat::HitReaction) as a string,
+    /// or one of [`life_events`](wasm_fantasia_shared::combat::life_events)
+    /// for a player dying or respawning.
+    pub reaction: String,
+    #[index(btree)]
+    pub world_id: String,
+    pub timestamp: i64,
+}
+
+/// One hit, kept by [`combat_log`](crate::combat_log) after its
+/// `combat_event` is gone. Private: review tools read it with `spacetime sql`.
+#[spacetimedb::table(name = combat_log)]
+pub struct CombatLog {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub world_id: String,
+    pub attacker: spacetimedb::Identity,
+    /// Enemy hit; meaningless when `target_player` is set.
+    pub target_id: u64,
+    pub target_player: Option<spacetimedb::Identity>,
+    pub damage: f32,
+    pub is_crit: bool,
+    #[index(btree)]
+    pub timestamp: i64,
+}
+
+/// An invasion or double XP window in `world_id`, from `starts_at` to
+/// `ends_at`; see [`world_events`](crate::world_events). `phase` is a
+/// [`WorldEventPhase`](wasm_fantasia_shared::world_events::WorldEventPhase)
+/// and `kind` a [`WorldEventKind`](wasm_fantasia_shared::world_events::WorldEventKind).
+#[spacetimedb::table(name = world_event, public)]
+pub struct WorldEvent {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub world_id: String,
+    pub kind: String,
+    pub phase: String,
+    pub starts_at: i64,
+    pub ends_at: i64,
+}
+
+/// Players gathering for a match of their own; see [`lobby`](crate::lobby).
+/// `world_id` is empty until the host starts the match.
+#[spacetimedb::table(name = lobby, public)]
+pub struct Lobby {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub name: String,
+    pub host: spacetimedb::Identity,
+    pub max_players: u32,
+    #[index(btree)]
+    pub world_id: String,
+    pub created_at: i64,
+    /// 0 while the lobby is still taking players.
+    pub started_at: i64,
+}
+
+/// A player in a lobby. Each player is in at most one.
+#[spacetimedb::table(name = lobby_member, public)]
+pub struct LobbyMember {
+    #[primary_key]
+    pub identity: spacetimedb::Identity,
+    #[index(btree)]
+    pub lobby_id: u64,
+    pub joined_at: i64,
+}
+
+/// A dead player. `respawn` is refused until `respawn_at`; the row goes away
+/// when they respawn.
+#[spacetimedb::table(name = death, public)]
+pub struct Death {
+    #[primary_key]
+    pub identity: spacetimedb::Identity,
+    pub world_id: String,
+    pub killer: Option<spacetimedb::Identity>,
+    pub died_at: i64,
+    pub respawn_at: i64,
+}
+
+/// The last `ping` nonce each connection sent, echoed back so the client can
+/// time the round trip, with the server time (µs since the Unix epoch) it
+/// was answered at for the client's clock estimate. Deleted on disconnect.
+#[spacetimedb::table(name = ping_result, public)]
+pub struct PingResult {
+    #[primary_key]
+    pub identity: spacetimedb::Identity,
+    pub nonce: u32,
+    pub server_time: i64,
+}
+
+/// Each connection's latest movement input under the input relay, which
+/// `game_tick` integrates into their `player` row. Also carries their
+/// vertical speed between ticks. Deleted on disconnect.
+#[spacetimedb::table(name = player_input)]
+pub struct PlayerInput {
+    #[primary_key]
+    pub identity: spacetimedb::Identity,
+    pub velocity_x: f32,
+    pub velocity_z: f32,
+    /// A jump waiting for the next tick, 0 for none.
+    pub jump_height: f32,
+    pub velocity_y: f32,
+    pub input_seq: u32,
+}
+
+/// A username and password a character can be recovered with on another
+/// device: signing in moves the character to the connection that signed in.
+/// Private, as it holds the password hash.
+#[spacetimedb::table(name = account)]
+pub struct Account {
+    /// Lowercase.
+    #[primary_key]
+    pub username: String,
+    /// Whoever holds the character now.
+    #[unique]
+    pub identity: spacetimedb::Identity,
+    /// Argon2id PHC string, salt and parameters included; see
+    /// `accounts::hash_password`.
+    pub password_hash: String,
+    pub created_at: i64,
+}
+
+/// Failed sign-ins to an account, whoever made them. Kept apart from the
+/// per-identity rate limits so neither reconnecting nor a fresh identity
+/// resets the count. Private; the row goes when a sign-in succeeds.
+#[spacetimedb::table(name = sign_in_attempt)]
+pub struct SignInAttempt {
+    /// The account's username, lowercase.
+    #[primary_key]
+    pub username: String,
+    pub failures: u32,
+    pub last_failure: i64,
+    /// No sign-in to the account is checked before this time.
+    pub locked_until: i64,
+}
+
+/// A connection watching a world without a character, e.g. to capture
+/// footage. Enemies and matches ignore observers. The row goes away when
+/// they disconnect or join with `join_game`.
+#[spacetimedb::table(name = observer, public)]
+pub struct Observer {
+    #[primary_key]
+    pub identity: spacetimedb::Identity,
+    pub world_id: String,
+    pub joined_at: i64,
+}
+
+/// The build a player picked; see
+/// [`Loadout`](wasm_fantasia_shared::presets::Loadout). Players without a row
+/// use the default one.
+#[spacetimedb::table(name = player_loadout, public)]
+pub struct PlayerLoadout {
+    #[primary_key]
+    pub identity: spacetimedb::Identity,
+    pub loadout: String,
+}
+
+/// Ephemeral XP award. Inserted when a kill pays out, consumed by the
+/// recipient's client for XP floaters.
+#[spacetimedb::table(name = xp_event, public)]
+pub struct XpEvent {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub recipient: spacetimedb::Identity,
+    pub amount: u32,
+    /// Earned from a party member's kill rather than the recipient's own.
+    pub shared: bool,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    #[index(btree)]
+    pub world_id: String,
+    pub timestamp: i64,
+}
+
+/// Chat line sent to everyone in a world. Pruned by `send_chat` once stale.
+#[spacetimedb::table(name = chat_message, public)]
+pub struct ChatMessage {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub sender: spacetimedb::Identity,
+    #[index(btree)]
     pub world_id: String,
+    pub text: String,
     pub timestamp: i64,
 }
 
-/// Dynamic effect (buff, debuff, DoT). Managed by combat reducers now,
-/// by Rhai/Lua scripts later.
+/// Item an enemy dropped on death, waiting to be picked up with
+/// `pickup_loot`. Expired drops are pruned whenever new loot drops.
+#[spacetimedb::table(name = loot_drop, public)]
+pub struct LootDrop {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    /// [`LootItem`](wasm_fantasia_shared::loot::LootItem) id.
+    pub item_id: String,
+    #[index(btree)]
+    pub world_id: String,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub timestamp: i64,
+}
+
+/// Item a player owns, equipped or not.
+#[spacetimedb::table(name = inventory_item, public)]
+pub struct InventoryItem {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub owner: spacetimedb::Identity,
+    /// [`Item`](wasm_fantasia_shared::items::Item) id.
+    pub item_id: String,
+    pub acquired_at: i64,
+}
+
+/// An inventory item the owner is wearing. One row per occupied slot; the
+/// owner's combat stats on `player` already include its bonuses.
+#[spacetimedb::table(name = equipped_item, public)]
+pub struct EquippedItem {
+    #[primary_key]
+    pub inventory_id: u64,
+    #[index(btree)]
+    pub owner: spacetimedb::Identity,
+    /// [`ItemSlot`](wasm_fantasia_shared::items::ItemSlot) name.
+    pub slot: String,
+    pub item_id: String,
+}
+
+/// A trade one player has put to another: the items on offer and the items
+/// asked for in return are `trade_offer_item` rows. Dropped when either side
+/// disconnects.
+#[spacetimedb::table(name = trade_offer, public)]
+pub struct TradeOffer {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub offerer: spacetimedb::Identity,
+    #[index(btree)]
+    pub recipient: spacetimedb::Identity,
+    pub world_id: String,
+    pub created_at: i64,
+}
+
+/// One inventory item in a `trade_offer`. `owner` says which side gives it.
+#[spacetimedb::table(name = trade_offer_item, public)]
+pub struct TradeOfferItem {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub offer_id: u64,
+    #[index(btree)]
+    pub inventory_id: u64,
+    pub item_id: String,
+    pub owner: spacetimedb::Identity,
+}
+
+/// Dynamic effect (buff, debuff, DoT). Managed by combat reducers and admins.
+/// `game_tick` runs them down and applies the periodic ones; see
+/// [`PeriodicEffect`](wasm_fantasia_shared::effects::PeriodicEffect). A
+/// scripted effect runs the [`EffectScript`] it names instead.
 #[spacetimedb::table(name = active_effect, public)]
 pub struct ActiveEffect {
     #[primary_key]
     #[auto_inc]
     pub id: u64,
+    #[index(btree)]
     pub owner: spacetimedb::Identity,
-    pub effect_type: String,
+    /// [`EffectKind`](wasm_fantasia_shared::effects::EffectKind) id.
+    pub kind: u8,
+    /// The ability of a cooldown, or the script of a scripted effect; empty
+    /// for the rest.
+    pub name: String,
     pub magnitude: f32,
+    /// Seconds left. Negative never runs out.
     pub duration: f32,
     pub timestamp: i64,
 }
 
+/// Rhai source for a scripted effect. Scripted active effects named `name`
+/// run it when their owner lands a hit and on every tick; see
+/// [`scripting`](crate::scripting). Admins manage them with
+/// `set_effect_script`.
+#[spacetimedb::table(name = effect_script)]
+pub struct EffectScript {
+    #[primary_key]
+    pub name: String,
+    pub source: String,
+    pub updated_at: i64,
+}
+
+/// A world instance players can be in. `world_id` on other tables refers to
+/// its `id`. Solo worlds are created on first join and named after their
+/// owner; empty ones are deleted once `empty_lifetime_secs` passes.
+#[spacetimedb::table(name = world, public)]
+pub struct World {
+    #[primary_key]
+    pub id: String,
+    pub owner: spacetimedb::Identity,
+    pub max_players: u32,
+    /// 0 keeps the world until it's closed.
+    pub empty_lifetime_secs: u32,
+    pub spawn_x: f32,
+    pub spawn_y: f32,
+    pub spawn_z: f32,
+    pub created_at: i64,
+    /// When the last player left; 0 while anyone is inside.
+    pub emptied_at: i64,
+}
+
+/// Scheduled garbage collection of empty worlds.
+/// Facts about the published module, one row with id 0. Clients compare
+/// `gameplay_checksum` with their own to detect a mismatched build.
+#[spacetimedb::table(name = server_info, public)]
+pub struct ServerInfo {
+    #[primary_key]
+    pub id: u32,
+    /// [`checksum`](wasm_fantasia_shared::gameplay::checksum) of the
+    /// `gameplay.ron` this module was built with.
+    pub gameplay_checksum: u64,
+}
+
+/// Server health counters and gauges, one row per metric and label. Private:
+/// operators read it with `metrics-scraper`, which serves it in Prometheus
+/// text format.
+#[spacetimedb::table(name = metrics)]
+pub struct Metric {
+    /// `name`, plus `/reducer` when the metric is about one reducer.
+    #[primary_key]
+    pub key: String,
+    pub name: String,
+    /// Reducer the metric is about; empty for server-wide metrics.
+    pub reducer: String,
+    /// `counter` (only goes up) or `gauge`.
+    pub kind: String,
+    pub value: f64,
+    pub updated_at: i64,
+}
+
+#[spacetimedb::table(name = world_gc_schedule, scheduled(crate::worlds::collect_empty_worlds))]
+pub struct WorldGcSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: spacetimedb::ScheduleAt,
+}
+
+/// A balance value tuned on the live deployment, overriding the
+/// compile-time default of the [`ConfigKey`](crate::config::ConfigKey)
+/// named `key`. Admins change them with `set_config`.
+#[spacetimedb::table(name = server_config, public)]
+pub struct ServerConfig {
+    #[primary_key]
+    pub key: String,
+    pub value: f32,
+    pub updated_at: i64,
+}
+
+/// Threat `enemy_id` holds for `player`, as of `updated_at`; see
+/// [`threat`](crate::threat). Private.
+#[spacetimedb::table(name = threat)]
+pub struct Threat {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub enemy_id: u64,
+    pub player: spacetimedb::Identity,
+    pub value: f32,
+    pub updated_at: i64,
+}
+
+/// Token bucket limiting how often `identity` may call `reducer`; see
+/// [`rate_limit`](crate::rate_limit). Private; cleared on disconnect, apart
+/// from slow-refilling buckets that aren't full yet.
+#[spacetimedb::table(name = rate_limit)]
+pub struct RateLimit {
+    /// `identity/reducer`.
+    #[primary_key]
+    pub key: String,
+    #[index(btree)]
+    pub identity: spacetimedb::Identity,
+    pub tokens: f32,
+    pub refilled_at: i64,
+    /// Calls dropped since the bucket was created.
+    pub rejected: u32,
+}
+
 /// Scheduled tick for server-side game logic (enemy AI, etc.).
 #[spacetimedb::table(name = tick_schedule, scheduled(crate::enemy_ai::game_tick))]
 pub struct TickSchedule {
@@ -120,9 +629,112 @@ pub struct KnockbackImpulse {
     pub impulse_z: f32,
 }
 
+/// A world being fed enemies in waves by the spawn director, which runs from
+/// `game_tick` until `stop_director` deletes the row.
+#[spacetimedb::table(name = spawn_director, public)]
+pub struct SpawnDirector {
+    #[primary_key]
+    pub world_id: String,
+    /// Current wave, counting from 1.
+    pub wave: u32,
+    /// Enemies the current wave has yet to spawn.
+    pub budget: u32,
+    pub next_spawn_at: i64,
+    pub started_at: i64,
+}
+
+/// Phase state of a living boss enemy, created the first tick it's seen and
+/// deleted once it's gone.
+#[spacetimedb::table(name = boss_state, public)]
+pub struct BossState {
+    #[primary_key]
+    pub enemy_id: u64,
+    pub world_id: String,
+    /// [`BossPhase`](wasm_fantasia_shared::boss::BossPhase) id.
+    pub phase: String,
+    /// Special attacks started so far; picks the next one from the phase's list.
+    pub specials_cast: u32,
+    pub next_special_at: i64,
+}
+
+/// A boss special attack winding up. Clients draw `shape` on the ground until
+/// `lands_at`, when the server hits every player inside and deletes the row.
+#[spacetimedb::table(name = telegraph, public)]
+pub struct Telegraph {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub world_id: String,
+    /// The boss casting it.
+    #[index(btree)]
+    pub enemy_id: u64,
+    /// [`BossAttack`](wasm_fantasia_shared::boss::BossAttack) id.
+    pub attack: String,
+    /// [`TelegraphShape`](wasm_fantasia_shared::boss::TelegraphShape) id.
+    pub shape: String,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    /// Facing of cone shapes, same convention as `Enemy::rotation_y`.
+    pub rotation_y: f32,
+    pub radius: f32,
+    /// Full arc in degrees.
+    pub arc: f32,
+    pub damage: f32,
+    pub knockback: f32,
+    pub started_at: i64,
+    pub lands_at: i64,
+}
+
 /// Tracks which worlds are paused (singleplayer ESC menu).
 #[spacetimedb::table(name = world_pause, public)]
 pub struct WorldPause {
     #[primary_key]
     pub world_id: String,
 }
+
+/// Match lifecycle for a world. A match starts when the first pack spawns
+/// and ends once every enemy in the world is dead.
+#[spacetimedb::table(name = match_state, public)]
+pub struct MatchState {
+    #[primary_key]
+    pub world_id: String,
+    pub active: bool,
+    pub started_at: i64,
+    pub ended_at: i64,
+    /// Pack centre, reused when the party plays again.
+    pub spawn_x: f32,
+    pub spawn_y: f32,
+    pub spawn_z: f32,
+}
+
+/// Per-player stats for the current (or last finished) match in a world.
+#[spacetimedb::table(name = match_stats, public)]
+pub struct MatchStats {
+    #[primary_key]
+    pub identity: spacetimedb::Identity,
+    #[index(btree)]
+    pub world_id: String,
+    pub kills: u32,
+    pub damage_dealt: f32,
+    pub crits: u32,
+    pub xp_gained: u32,
+    pub loot_earned: u32,
+}
+
+/// Running per-player totals in a world, across matches, until someone
+/// resets them with `reset_leaderboard`. Kills count enemies and players.
+#[spacetimedb::table(name = leaderboard, public)]
+pub struct Leaderboard {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub identity: spacetimedb::Identity,
+    #[index(btree)]
+    pub world_id: String,
+    pub kills: u32,
+    pub deaths: u32,
+    pub damage_dealt: f32,
+}
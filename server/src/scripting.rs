@@ -0,0 +1,322 @@
+//! Rhai scripting for effects. An `effect_script` row holds a script; a
+//! scripted active effect naming it runs it at two trigger points:
+//!
+//! - `"on_hit"`, for each target its owner hits, before the hit is written.
+//!   `action` holds `Damage`, `Knockback`, `Push` and `Launch`, which the
+//!   script may change; `stats` holds the attacker's stats plus the target's
+//!   `TargetHealth` and `TargetShield`.
+//! - `"on_tick"`, on every `game_tick`. `action` holds `DeltaTime`; the
+//!   script writes the owner's health change (negative for damage) to
+//!   `Damage`. `stats` holds the owner's `Health` and `MaxHealth`.
+//!
+//! Scripts are plain top-level code that branches on the `trigger` variable,
+//! and read the effect's magnitude from `magnitude`. Stats and action
+//! variables are read with `get("Name")` and written with `set("Name", v)`,
+//! using the names of [`Stat`] and [`ActionVar`] variants; other names are
+//! custom ones:
+//!
+//! ```rhai
+//! if trigger == "on_hit" && stats.get("TargetHealth") < 30.0 {
+//!     action.set("Damage", action.get("Damage") * (1.0 + magnitude));
+//! }
+//! ```
+//!
+//! All scripts run by one reducer call share [`SCRIPT_BUDGET`] operations.
+//! Once it's spent, the remaining scripts are skipped for that call; a
+//! script that fails or runs out leaves the hit or tick as it was.
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use rhai::{Engine, Scope, AST, FLOAT, INT};
+use spacetimedb::{Identity, Table};
+use wasm_fantasia_shared::combat::{absorb_damage, HitResult, HitTarget};
+use wasm_fantasia_shared::effects::{magnitude_stat, EffectKind};
+use wasm_fantasia_shared::rules::{Action, ActionVar, Stat, Stats};
+
+use crate::archetypes::is_admin;
+use crate::schema::*;
+
+/// Rhai operations one reducer call may spend across all the scripts it runs.
+pub const SCRIPT_BUDGET: u64 = 50_000;
+/// Longest script source `set_effect_script` accepts, in bytes.
+const MAX_SCRIPT_LEN: usize = 8 * 1024;
+
+/// Stats as scripts see them.
+#[derive(Clone)]
+struct ScriptStats(Stats);
+
+/// Action variables as scripts see them.
+#[derive(Clone)]
+struct ScriptAction(Action);
+
+/// Runs effect scripts for one reducer call, within its budget. The engine
+/// and scripts load the first time a scripted effect turns up.
+pub(crate) struct ScriptHost<'a> {
+    ctx: &'a spacetimedb::ReducerContext,
+    engine: Option<Engine>,
+    /// Compiled scripts by name; `None` for names without one.
+    scripts: HashMap<String, Option<AST>>,
+    /// Operations the running script has used so far.
+    used: Rc<Cell<u64>>,
+    remaining: u64,
+}
+
+impl<'a> ScriptHost<'a> {
+    pub(crate) fn new(ctx: &'a spacetimedb::ReducerContext) -> Self {
+        Self {
+            ctx,
+            engine: None,
+            scripts: HashMap::new(),
+            used: Rc::new(Cell::new(0)),
+            remaining: SCRIPT_BUDGET,
+        }
+    }
+
+    /// Whether `name` names a script.
+    pub(crate) fn has_script(&mut self, name: &str) -> bool {
+        self.script(name).is_some()
+    }
+
+    /// Let the scripted effects on `attacker` adjust `hits` against
+    /// `targets`, then settle the health, shield and death of every hit
+    /// whose damage changed.
+    pub(crate) fn on_hits(
+        &mut self,
+        attacker: Identity,
+        attacker_stats: &Stats,
+        targets: &[HitTarget],
+        hits: &mut [HitResult],
+    ) {
+        if hits.is_empty() {
+            return;
+        }
+        let effects: Vec<ActiveEffect> = self
+            .ctx
+            .db
+            .active_effect()
+            .owner()
+            .filter(attacker)
+            .filter(|e| e.kind == EffectKind::Scripted.as_u8())
+            .collect();
+        for effect in effects {
+            if !self.has_script(&effect.name) {
+                continue;
+            }
+            for hit in hits.iter_mut() {
+                let Some(target) = targets.iter().find(|t| t.id == hit.target_id) else {
+                    continue;
+                };
+                let stats = attacker_stats
+                    .clone()
+                    .with(Stat::Custom("TargetHealth".into()), target.health)
+                    .with(Stat::Custom("TargetShield".into()), target.shield);
+                let action = Action::new()
+                    .with(ActionVar::Damage, hit.damage)
+                    .with(ActionVar::Knockback, hit.knockback)
+                    .with(ActionVar::Push, hit.push)
+                    .with(ActionVar::Launch, hit.launch);
+                let Some((_, action)) =
+                    self.run(&effect.name, "on_hit", effect.magnitude, stats, action)
+                else {
+                    continue;
+                };
+
+                let damage = action.get(&ActionVar::Damage).max(0.0);
+                hit.knockback = action.get(&ActionVar::Knockback);
+                hit.push = action.get(&ActionVar::Push);
+                hit.launch = action.get(&ActionVar::Launch);
+                if damage != hit.damage {
+                    let absorbed = absorb_damage(target.shield, target.health, damage);
+                    hit.damage = damage;
+                    hit.new_health = absorbed.health;
+                    hit.new_shield = absorbed.shield;
+                    hit.shield_broke = absorbed.shield_broke;
+                    hit.died = absorbed.health <= 0.0;
+                }
+            }
+        }
+    }
+
+    /// Run `effect`'s script for one tick of `dt` seconds on an owner at
+    /// `health` out of `max_health`. Returns the health change it asks for
+    /// and the effect's magnitude afterwards, or `None` if the script didn't
+    /// run to the end.
+    pub(crate) fn on_tick(
+        &mut self,
+        effect: &ActiveEffect,
+        health: f32,
+        max_health: f32,
+        dt: f32,
+    ) -> Option<(f32, f32)> {
+        let stats = Stats::new()
+            .with(Stat::Health, health)
+            .with(Stat::MaxHealth, max_health)
+            .with(magnitude_stat(), effect.magnitude);
+        let action = Action::new().with(ActionVar::DeltaTime, dt);
+        let (stats, action) = self.run(&effect.name, "on_tick", effect.magnitude, stats, action)?;
+        Some((action.get(&ActionVar::Damage), stats.get(&magnitude_stat())))
+    }
+
+    /// Run the script `name` for `trigger` with `stats` and `action` in
+    /// scope, and hand them back as the script left them.
+    fn run(
+        &mut self,
+        name: &str,
+        trigger: &str,
+        magnitude: f32,
+        stats: Stats,
+        action: Action,
+    ) -> Option<(Stats, Action)> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let ast = self.script(name)?.clone();
+        let engine = self
+            .engine
+            .get_or_insert_with(|| new_engine(self.used.clone()));
+        // Zero would mean no limit
+        engine.set_max_operations(self.remaining);
+        self.used.set(0);
+
+        let mut scope = Scope::new();
+        scope.push_constant("trigger", trigger.to_string());
+        scope.push_constant("magnitude", magnitude as FLOAT);
+        scope.push("stats", ScriptStats(stats));
+        scope.push("action", ScriptAction(action));
+        let result = engine.run_ast_with_scope(&mut scope, &ast);
+
+        self.remaining = self.remaining.saturating_sub(self.used.get().max(1));
+        if let Err(e) = result {
+            spacetimedb::log::warn!("Effect script '{name}' failed on {trigger}: {e}");
+            return None;
+        }
+        let stats = scope.get_value::<ScriptStats>("stats")?.0;
+        let action = scope.get_value::<ScriptAction>("action")?.0;
+        Some((stats, action))
+    }
+
+    fn script(&mut self, name: &str) -> Option<&AST> {
+        if !self.scripts.contains_key(name) {
+            let ast = self
+                .ctx
+                .db
+                .effect_script()
+                .name()
+                .find(name.to_string())
+                .and_then(|script| {
+                    let engine = self
+                        .engine
+                        .get_or_insert_with(|| new_engine(self.used.clone()));
+                    match engine.compile(&script.source) {
+                        Ok(ast) => Some(ast),
+                        Err(e) => {
+                            spacetimedb::log::warn!("Effect script '{name}' doesn't compile: {e}");
+                            None
+                        }
+                    }
+                });
+            self.scripts.insert(name.to_string(), ast);
+        }
+        self.scripts.get(name)?.as_ref()
+    }
+}
+
+/// An engine with the stats and action bindings, counting the operations
+/// each run uses into `used`.
+fn new_engine(used: Rc<Cell<u64>>) -> Engine {
+    let mut engine = Engine::new();
+    engine.on_progress(move |ops| {
+        used.set(ops);
+        None
+    });
+    engine.on_print(|text| spacetimedb::log::info!("[effect script] {text}"));
+    engine.on_debug(|text, _, pos| spacetimedb::log::debug!("[effect script] {pos:?}: {text}"));
+
+    engine
+        .register_type_with_name::<ScriptStats>("Stats")
+        .register_fn("get", |stats: &mut ScriptStats, name: &str| {
+            stats.0.get(&Stat::from_name(name)) as FLOAT
+        })
+        .register_fn(
+            "set",
+            |stats: &mut ScriptStats, name: &str, value: FLOAT| {
+                stats.0.set(Stat::from_name(name), value as f32);
+            },
+        )
+        .register_fn("set", |stats: &mut ScriptStats, name: &str, value: INT| {
+            stats.0.set(Stat::from_name(name), value as f32);
+        });
+    engine
+        .register_type_with_name::<ScriptAction>("Action")
+        .register_fn("get", |action: &mut ScriptAction, name: &str| {
+            action.0.get(&ActionVar::from_name(name)) as FLOAT
+        })
+        .register_fn(
+            "set",
+            |action: &mut ScriptAction, name: &str, value: FLOAT| {
+                action.0.set(ActionVar::from_name(name), value as f32);
+            },
+        )
+        .register_fn(
+            "set",
+            |action: &mut ScriptAction, name: &str, value: INT| {
+                action.0.set(ActionVar::from_name(name), value as f32);
+            },
+        );
+    engine
+}
+
+/// Add or replace the script `name`. It must compile. Names of built-in
+/// effects are refused. Admin only.
+#[spacetimedb::reducer]
+pub fn set_effect_script(
+    ctx: &spacetimedb::ReducerContext,
+    name: String,
+    source: String,
+) -> Result<(), String> {
+    if !is_admin(ctx) {
+        return Err("Only admins can manage effect scripts".into());
+    }
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err("Script name can't be empty".into());
+    }
+    // `admin_add_effect` would read the name as the built-in
+    if EffectKind::parse_str(&name).0 != EffectKind::Scripted {
+        return Err(format!("'{name}' is a built-in effect"));
+    }
+    if source.len() > MAX_SCRIPT_LEN {
+        return Err(format!("Scripts are limited to {MAX_SCRIPT_LEN} bytes"));
+    }
+    Engine::new()
+        .compile(&source)
+        .map_err(|e| format!("Script doesn't compile: {e}"))?;
+
+    let script = EffectScript {
+        name: name.clone(),
+        source,
+        updated_at: ctx.timestamp.to_micros_since_unix_epoch(),
+    };
+    if ctx.db.effect_script().name().find(&name).is_some() {
+        ctx.db.effect_script().name().update(script);
+    } else {
+        ctx.db.effect_script().insert(script);
+    }
+    spacetimedb::log::info!("Effect script '{name}' set by {}", ctx.sender);
+    Ok(())
+}
+
+/// Remove the script `name`. Effects naming it keep running down, doing
+/// nothing. Admin only.
+#[spacetimedb::reducer]
+pub fn delete_effect_script(ctx: &spacetimedb::ReducerContext, name: String) -> Result<(), String> {
+    if !is_admin(ctx) {
+        return Err("Only admins can manage effect scripts".into());
+    }
+    if !ctx.db.effect_script().name().delete(&name) {
+        return Err(format!("No effect script '{name}'"));
+    }
+    Ok(())
+}
@@ -0,0 +1,323 @@
+use serde::{Deserialize, Serialize};
+use spacetimedb::Table;
+use wasm_fantasia_shared::abilities::AbilityShape;
+
+use crate::archetypes::{find_archetype, is_admin, save_archetype, validate_archetype};
+use crate::enemy_ai::spawn_enemy;
+use crate::matches::{abort_match, start_match};
+use crate::schema::*;
+
+/// Bumped whenever the JSON layout changes in a way old snapshots can't load.
+const SNAPSHOT_VERSION: u32 = 1;
+
+/// The JSON stored in a `world_snapshot` row.
+#[derive(Serialize, Deserialize)]
+struct WorldSetup {
+    version: u32,
+    archetypes: Vec<ArchetypeSetup>,
+    abilities: Vec<AbilitySetup>,
+    enemies: Vec<EnemyPlacement>,
+    /// Where the world's match was started, if it had one.
+    spawn: Option<[f32; 3]>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct ArchetypeSetup {
+    name: String,
+    max_health: f32,
+    max_shield: f32,
+    attack_damage: f32,
+    attack_range: f32,
+    attack_speed: f32,
+    attack_cooldown: f32,
+    walk_speed: f32,
+    mass: f32,
+    pack_size_min: u32,
+    pack_size_max: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct AbilitySetup {
+    name: String,
+    shape: String,
+    range: f32,
+    arc: f32,
+    width: f32,
+    damage_multiplier: f32,
+    knockback: f32,
+    launch: f32,
+    cooldown: f32,
+    stack_cost: f32,
+    /// Missing from snapshots taken before abilities cost mana.
+    #[serde(default)]
+    mana_cost: f32,
+}
+
+/// A living enemy's type and placement. Imported enemies start fresh from
+/// their archetype, so health and AI state aren't kept.
+#[derive(Serialize, Deserialize)]
+struct EnemyPlacement {
+    enemy_type: String,
+    x: f32,
+    y: f32,
+    z: f32,
+    rotation_y: f32,
+}
+
+impl From<EnemyArchetype> for ArchetypeSetup {
+    fn from(a: EnemyArchetype) -> Self {
+        Self {
+            name: a.name,
+            max_health: a.max_health,
+            max_shield: a.max_shield,
+            attack_damage: a.attack_damage,
+            attack_range: a.attack_range,
+            attack_speed: a.attack_speed,
+            attack_cooldown: a.attack_cooldown,
+            walk_speed: a.walk_speed,
+            mass: a.mass,
+            pack_size_min: a.pack_size_min,
+            pack_size_max: a.pack_size_max,
+        }
+    }
+}
+
+impl From<ArchetypeSetup> for EnemyArchetype {
+    fn from(a: ArchetypeSetup) -> Self {
+        Self {
+            name: a.name,
+            max_health: a.max_health,
+            max_shield: a.max_shield,
+            attack_damage: a.attack_damage,
+            attack_range: a.attack_range,
+            attack_speed: a.attack_speed,
+            attack_cooldown: a.attack_cooldown,
+            walk_speed: a.walk_speed,
+            mass: a.mass,
+            pack_size_min: a.pack_size_min,
+            pack_size_max: a.pack_size_max,
+        }
+    }
+}
+
+impl From<Ability> for AbilitySetup {
+    fn from(a: Ability) -> Self {
+        Self {
+            name: a.name,
+            shape: a.shape,
+            range: a.range,
+            arc: a.arc,
+            width: a.width,
+            damage_multiplier: a.damage_multiplier,
+            knockback: a.knockback,
+            launch: a.launch,
+            cooldown: a.cooldown,
+            stack_cost: a.stack_cost,
+            mana_cost: a.mana_cost,
+        }
+    }
+}
+
+impl From<AbilitySetup> for Ability {
+    fn from(a: AbilitySetup) -> Self {
+        Self {
+            name: a.name,
+            shape: a.shape,
+            range: a.range,
+            arc: a.arc,
+            width: a.width,
+            damage_multiplier: a.damage_multiplier,
+            knockback: a.knockback,
+            launch: a.launch,
+            cooldown: a.cooldown,
+            stack_cost: a.stack_cost,
+            mana_cost: a.mana_cost,
+        }
+    }
+}
+
+/// Parse and check a snapshot without touching any tables.
+fn parse_setup(json: &str) -> Result<WorldSetup, String> {
+    let setup: WorldSetup =
+        serde_json::from_str(json).map_err(|e| format!("Invalid world snapshot: {e}"))?;
+    if setup.version != SNAPSHOT_VERSION {
+        return Err(format!(
+            "Snapshot version {} can't be loaded, expected {SNAPSHOT_VERSION}",
+            setup.version
+        ));
+    }
+    for archetype in &setup.archetypes {
+        let archetype = EnemyArchetype::from(archetype.clone());
+        validate_archetype(&archetype)
+            .map_err(|e| format!("Archetype '{}': {e}", archetype.name))?;
+    }
+    if let Some(ability) = setup
+        .abilities
+        .iter()
+        .find(|a| AbilityShape::parse_str(&a.shape).is_none())
+    {
+        return Err(format!(
+            "Ability '{}' has unknown shape '{}'",
+            ability.name, ability.shape
+        ));
+    }
+    Ok(setup)
+}
+
+fn save_snapshot(
+    ctx: &spacetimedb::ReducerContext,
+    name: String,
+    source_world: String,
+    json: String,
+) {
+    let row = WorldSnapshot {
+        name,
+        source_world,
+        json,
+        saved_by: ctx.sender,
+        saved_at: ctx.timestamp.to_micros_since_unix_epoch(),
+    };
+    if ctx.db.world_snapshot().name().find(&row.name).is_some() {
+        ctx.db.world_snapshot().name().update(row);
+    } else {
+        ctx.db.world_snapshot().insert(row);
+    }
+}
+
+/// Save `world_id`'s enemies alongside the archetype and ability tables as
+/// the snapshot `name`, replacing any snapshot of that name. Admin only.
+#[spacetimedb::reducer]
+pub fn export_world(
+    ctx: &spacetimedb::ReducerContext,
+    world_id: String,
+    name: String,
+) -> Result<(), String> {
+    if !is_admin(ctx) {
+        return Err("Only admins can export worlds".into());
+    }
+    if name.trim().is_empty() {
+        return Err("Snapshot name can't be empty".into());
+    }
+
+    let setup = WorldSetup {
+        version: SNAPSHOT_VERSION,
+        archetypes: ctx.db.enemy_archetype().iter().map(Into::into).collect(),
+        abilities: ctx.db.ability().iter().map(Into::into).collect(),
+        enemies: ctx
+            .db
+            .enemy()
+            .iter()
+            .filter(|e| e.world_id == world_id && e.health > 0.0)
+            .map(|e| EnemyPlacement {
+                enemy_type: e.enemy_type,
+                x: e.x,
+                y: e.y,
+                z: e.z,
+                rotation_y: e.rotation_y,
+            })
+            .collect(),
+        spawn: ctx
+            .db
+            .match_state()
+            .world_id()
+            .find(&world_id)
+            .map(|m| [m.spawn_x, m.spawn_y, m.spawn_z]),
+    };
+    let json = serde_json::to_string_pretty(&setup)
+        .map_err(|e| format!("Couldn't serialize world: {e}"))?;
+
+    spacetimedb::log::info!(
+        "Exported world {world_id} as '{name}' ({} enemies)",
+        setup.enemies.len()
+    );
+    save_snapshot(ctx, name, world_id, json);
+    Ok(())
+}
+
+/// Store a snapshot exported from another database, e.g. to promote a dev
+/// server's arena to production. Admin only.
+#[spacetimedb::reducer]
+pub fn store_world_snapshot(
+    ctx: &spacetimedb::ReducerContext,
+    name: String,
+    json: String,
+) -> Result<(), String> {
+    if !is_admin(ctx) {
+        return Err("Only admins can store world snapshots".into());
+    }
+    if name.trim().is_empty() {
+        return Err("Snapshot name can't be empty".into());
+    }
+    parse_setup(&json)?;
+    save_snapshot(ctx, name, String::new(), json);
+    Ok(())
+}
+
+/// Apply the snapshot `name` to `world_id`: archetypes and abilities are
+/// created or replaced, and the world's enemies are swapped for the
+/// snapshot's. Admin only.
+#[spacetimedb::reducer]
+pub fn import_world(
+    ctx: &spacetimedb::ReducerContext,
+    name: String,
+    world_id: String,
+) -> Result<(), String> {
+    if !is_admin(ctx) {
+        return Err("Only admins can import worlds".into());
+    }
+    let Some(snapshot) = ctx.db.world_snapshot().name().find(&name) else {
+        return Err(format!("No world snapshot named '{name}'"));
+    };
+    let setup = parse_setup(&snapshot.json)?;
+
+    for archetype in setup.archetypes {
+        save_archetype(ctx, archetype.into());
+    }
+    for ability in setup.abilities {
+        let ability = Ability::from(ability);
+        if ctx.db.ability().name().find(&ability.name).is_some() {
+            ctx.db.ability().name().update(ability);
+        } else {
+            ctx.db.ability().insert(ability);
+        }
+    }
+
+    let existing: Vec<Enemy> = ctx
+        .db
+        .enemy()
+        .iter()
+        .filter(|e| e.world_id == world_id)
+        .collect();
+    for enemy in existing {
+        ctx.db.enemy().delete(enemy);
+    }
+    abort_match(ctx, &world_id);
+
+    for placement in &setup.enemies {
+        let Some(archetype) = find_archetype(ctx, &placement.enemy_type) else {
+            return Err(format!(
+                "Snapshot places unknown archetype '{}'",
+                placement.enemy_type
+            ));
+        };
+        spawn_enemy(
+            ctx,
+            &world_id,
+            &archetype,
+            placement.x,
+            placement.y,
+            placement.z,
+            placement.rotation_y,
+        );
+    }
+    if let Some(first) = setup.enemies.first() {
+        let [x, y, z] = setup.spawn.unwrap_or([first.x, first.y, first.z]);
+        start_match(ctx, &world_id, x, y, z);
+    }
+
+    spacetimedb::log::info!(
+        "Imported '{name}' into world {world_id} ({} enemies)",
+        setup.enemies.len()
+    );
+    Ok(())
+}
@@ -0,0 +1,44 @@
+//! What player rules spawn with `Effect::Spawn`, mapped onto the tables: a
+//! shockwave resolves as an area hit, loot becomes a `loot_drop` row. Area
+//! hits don't spawn anything themselves, so one shockwave can't set off
+//! another.
+
+use wasm_fantasia_shared::rules::SpawnRequest;
+use wasm_fantasia_shared::spawns::{loot_item, Shockwave, SpawnKind};
+
+use crate::combat::aoe_hit;
+use crate::loot::place_loot;
+use crate::schema::*;
+
+/// Spawn what `owner`'s rules asked for at `(x, y, z)`. Kinds this build
+/// doesn't know are skipped.
+pub(crate) fn spawn_requested(
+    ctx: &spacetimedb::ReducerContext,
+    owner: &Player,
+    (x, y, z): (f32, f32, f32),
+    requests: &[SpawnRequest],
+    now: i64,
+) {
+    for request in requests {
+        match request.kind() {
+            Some(SpawnKind::Shockwave) => {
+                let wave = Shockwave::from_request(request);
+                aoe_hit(
+                    ctx,
+                    owner,
+                    x,
+                    y,
+                    z,
+                    wave.radius,
+                    wave.knockback,
+                    wave.launch,
+                    wave.damage_multiplier,
+                );
+            }
+            Some(SpawnKind::Loot) => {
+                place_loot(ctx, &owner.world_id, loot_item(request), (x, y, z), now);
+            }
+            None => {}
+        }
+    }
+}
@@ -0,0 +1,100 @@
+//! Threat: how much each enemy wants to go after each player. Damage builds
+//! it, it fades with [`THREAT_HALF_LIFE_SECS`], and `game_tick` sends each
+//! enemy after the player it holds the most threat for, falling back to the
+//! nearest one. Values are stored as of `updated_at` and decayed on read, so
+//! a quiet fight costs no writes.
+
+use std::collections::HashMap;
+
+use spacetimedb::{Identity, Table};
+
+use crate::schema::*;
+
+/// Threat gained per point of damage dealt.
+pub const THREAT_PER_DAMAGE: f32 = 1.0;
+/// Seconds for threat to halve with no new damage.
+pub const THREAT_HALF_LIFE_SECS: f32 = 8.0;
+/// Rows decayed below this are dropped.
+const MIN_THREAT: f32 = 1.0;
+
+fn decayed(threat: &Threat, now: i64) -> f32 {
+    let elapsed = (now - threat.updated_at).max(0) as f32 / 1_000_000.0;
+    threat.value * 0.5_f32.powf(elapsed / THREAT_HALF_LIFE_SECS)
+}
+
+/// Add threat on `enemy_id` for `player` dealing `damage` to it.
+pub(crate) fn add_threat(
+    ctx: &spacetimedb::ReducerContext,
+    enemy_id: u64,
+    player: Identity,
+    damage: f32,
+) {
+    if damage <= 0.0 {
+        return;
+    }
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    let existing = ctx
+        .db
+        .threat()
+        .enemy_id()
+        .filter(enemy_id)
+        .find(|t| t.player == player);
+    match existing {
+        Some(threat) => {
+            ctx.db.threat().id().update(Threat {
+                value: decayed(&threat, now) + damage * THREAT_PER_DAMAGE,
+                updated_at: now,
+                ..threat
+            });
+        }
+        None => {
+            ctx.db.threat().insert(Threat {
+                id: 0,
+                enemy_id,
+                player,
+                value: damage * THREAT_PER_DAMAGE,
+                updated_at: now,
+            });
+        }
+    }
+}
+
+/// Current threat by enemy, dropping rows that have faded out or whose
+/// enemy is gone. Called once per `game_tick`.
+pub(crate) fn current_threat(
+    ctx: &spacetimedb::ReducerContext,
+    now: i64,
+) -> HashMap<u64, Vec<(Identity, f32)>> {
+    let mut by_enemy: HashMap<u64, Vec<(Identity, f32)>> = HashMap::new();
+    for threat in ctx.db.threat().iter().collect::<Vec<_>>() {
+        let value = decayed(&threat, now);
+        if value < MIN_THREAT || ctx.db.enemy().id().find(threat.enemy_id).is_none() {
+            ctx.db.threat().id().delete(threat.id);
+            continue;
+        }
+        by_enemy
+            .entry(threat.enemy_id)
+            .or_default()
+            .push((threat.player, value));
+    }
+    by_enemy
+}
+
+/// The player in `players` `enemy_id` holds the most threat for, if any.
+pub(crate) fn top_threat<'a>(
+    threat: &HashMap<u64, Vec<(Identity, f32)>>,
+    enemy_id: u64,
+    players: &'a [Player],
+) -> Option<&'a Player> {
+    threat
+        .get(&enemy_id)?
+        .iter()
+        .filter_map(|(identity, value)| {
+            players
+                .iter()
+                .find(|p| p.identity == *identity)
+                .map(|p| (p, *value))
+        })
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(player, _)| player)
+}
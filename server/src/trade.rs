@@ -0,0 +1,227 @@
+//! Player-to-player trades. `offer_trade` proposes swapping some of the
+//! sender's inventory items for some of another player's; the other player
+//! takes it with `accept_trade`, which moves every item across in one
+//! transaction, or either side drops it with `cancel_trade`. Ownership is
+//! checked both when the offer is made and when it's accepted, since items
+//! can change hands or be salvaged in between.
+
+use spacetimedb::{Identity, Table};
+
+use crate::inventory::with_character_stats;
+use crate::schema::*;
+
+/// Most items either side can put into one trade.
+const MAX_TRADE_ITEMS: usize = 8;
+/// Most offers a player can have waiting at once.
+const MAX_OPEN_OFFERS: usize = 5;
+
+/// The online player `identity`, if there is one.
+fn online(ctx: &spacetimedb::ReducerContext, identity: Identity) -> Option<Player> {
+    ctx.db
+        .player()
+        .identity()
+        .find(identity)
+        .filter(|p| p.online)
+}
+
+/// Check `owner` holds every item in `ids`, with no repeats.
+fn check_owned(
+    ctx: &spacetimedb::ReducerContext,
+    owner: Identity,
+    ids: &[u64],
+) -> Result<Vec<InventoryItem>, String> {
+    if ids.len() > MAX_TRADE_ITEMS {
+        return Err(format!(
+            "Trades are limited to {MAX_TRADE_ITEMS} items a side"
+        ));
+    }
+    let mut items: Vec<InventoryItem> = Vec::with_capacity(ids.len());
+    for &id in ids {
+        if items.iter().any(|i| i.id == id) {
+            return Err(format!("Item {id} is listed twice"));
+        }
+        let Some(item) = ctx
+            .db
+            .inventory_item()
+            .id()
+            .find(id)
+            .filter(|i| i.owner == owner)
+        else {
+            return Err(format!("Item {id} isn't in their inventory"));
+        };
+        items.push(item);
+    }
+    Ok(items)
+}
+
+/// Delete `offer_id` and its items.
+fn remove_offer(ctx: &spacetimedb::ReducerContext, offer_id: u64) {
+    let items: Vec<TradeOfferItem> = ctx
+        .db
+        .trade_offer_item()
+        .offer_id()
+        .filter(offer_id)
+        .collect();
+    for item in items {
+        ctx.db.trade_offer_item().id().delete(item.id);
+    }
+    ctx.db.trade_offer().id().delete(offer_id);
+}
+
+/// Give `item` to `new_owner`, taking it off its old owner first.
+fn transfer(ctx: &spacetimedb::ReducerContext, item: InventoryItem, new_owner: Identity) {
+    ctx.db.equipped_item().inventory_id().delete(item.id);
+    ctx.db.inventory_item().id().update(InventoryItem {
+        owner: new_owner,
+        ..item
+    });
+}
+
+/// Called on disconnect: offers to or from the sender are dropped.
+pub(crate) fn drop_offers(ctx: &spacetimedb::ReducerContext) {
+    let offers: Vec<u64> = ctx
+        .db
+        .trade_offer()
+        .offerer()
+        .filter(ctx.sender)
+        .chain(ctx.db.trade_offer().recipient().filter(ctx.sender))
+        .map(|o| o.id)
+        .collect();
+    for offer_id in offers {
+        remove_offer(ctx, offer_id);
+    }
+}
+
+/// Offer `offered` from the sender's inventory for `requested` from
+/// `recipient`'s. Both must be online in the same world, and one side may be
+/// empty to give items away or ask for them.
+#[spacetimedb::reducer]
+pub fn offer_trade(
+    ctx: &spacetimedb::ReducerContext,
+    recipient: Identity,
+    offered: Vec<u64>,
+    requested: Vec<u64>,
+) -> Result<(), String> {
+    let Some(offerer) = online(ctx, ctx.sender) else {
+        return Err("Not in a game".into());
+    };
+    if recipient == ctx.sender {
+        return Err("Can't trade with yourself".into());
+    }
+    if !online(ctx, recipient).is_some_and(|p| p.world_id == offerer.world_id) {
+        return Err("They aren't in this world".into());
+    }
+    if offered.is_empty() && requested.is_empty() {
+        return Err("A trade needs at least one item".into());
+    }
+    if ctx.db.trade_offer().offerer().filter(ctx.sender).count() >= MAX_OPEN_OFFERS {
+        return Err(format!(
+            "You can have at most {MAX_OPEN_OFFERS} offers waiting"
+        ));
+    }
+    let offered = check_owned(ctx, ctx.sender, &offered)?;
+    let requested = check_owned(ctx, recipient, &requested)?;
+
+    let offer = ctx.db.trade_offer().insert(TradeOffer {
+        id: 0,
+        offerer: ctx.sender,
+        recipient,
+        world_id: offerer.world_id,
+        created_at: ctx.timestamp.to_micros_since_unix_epoch(),
+    });
+    for item in offered.into_iter().chain(requested) {
+        ctx.db.trade_offer_item().insert(TradeOfferItem {
+            id: 0,
+            offer_id: offer.id,
+            inventory_id: item.id,
+            item_id: item.item_id,
+            owner: item.owner,
+        });
+    }
+    Ok(())
+}
+
+/// Take an offer made to the sender. Every item changes hands or none do;
+/// items that were equipped come off first.
+#[spacetimedb::reducer]
+pub fn accept_trade(ctx: &spacetimedb::ReducerContext, offer_id: u64) -> Result<(), String> {
+    let Some(offer) = ctx
+        .db
+        .trade_offer()
+        .id()
+        .find(offer_id)
+        .filter(|o| o.recipient == ctx.sender)
+    else {
+        return Err(format!("No trade offer {offer_id} for you"));
+    };
+    let (Some(offerer), Some(recipient)) = (online(ctx, offer.offerer), online(ctx, ctx.sender))
+    else {
+        return Err("Both players must be online to trade".into());
+    };
+    if offerer.world_id != recipient.world_id {
+        return Err("Both players must be in the same world to trade".into());
+    }
+
+    let listed: Vec<TradeOfferItem> = ctx
+        .db
+        .trade_offer_item()
+        .offer_id()
+        .filter(offer_id)
+        .collect();
+    let ids_of = |owner: Identity| -> Vec<u64> {
+        listed
+            .iter()
+            .filter(|i| i.owner == owner)
+            .map(|i| i.inventory_id)
+            .collect()
+    };
+    // Either side may have traded, salvaged or lost items since the offer
+    let gives = check_owned(ctx, offer.offerer, &ids_of(offer.offerer))?;
+    let gets = check_owned(ctx, ctx.sender, &ids_of(ctx.sender))?;
+
+    let traded: Vec<u64> = gives.iter().chain(&gets).map(|i| i.id).collect();
+    for item in gives {
+        transfer(ctx, item, ctx.sender);
+    }
+    for item in gets {
+        transfer(ctx, item, offer.offerer);
+    }
+    // Drops this offer, and any other listing the traded items, which can no
+    // longer go through
+    let stale: Vec<u64> = traded
+        .iter()
+        .flat_map(|&id| ctx.db.trade_offer_item().inventory_id().filter(id))
+        .map(|i| i.offer_id)
+        .collect();
+    for stale_id in stale {
+        remove_offer(ctx, stale_id);
+    }
+
+    for player in [offerer, recipient] {
+        let player = with_character_stats(ctx, player);
+        ctx.db.player().identity().update(player);
+    }
+    spacetimedb::log::info!(
+        "Trade {offer_id}: {} items between {} and {}",
+        traded.len(),
+        offer.offerer,
+        offer.recipient
+    );
+    Ok(())
+}
+
+/// Withdraw an offer the sender made, or turn down one made to them.
+#[spacetimedb::reducer]
+pub fn cancel_trade(ctx: &spacetimedb::ReducerContext, offer_id: u64) -> Result<(), String> {
+    if !ctx
+        .db
+        .trade_offer()
+        .id()
+        .find(offer_id)
+        .is_some_and(|o| o.offerer == ctx.sender || o.recipient == ctx.sender)
+    {
+        return Err(format!("No trade offer {offer_id} of yours"));
+    }
+    remove_offer(ctx, offer_id);
+    Ok(())
+}
@@ -0,0 +1,125 @@
+//! Player rules that fire outside a swing: on starting a dodge roll, on
+//! health dropping below a threshold, on timers and from the auras of
+//! players nearby. Rule stats only live for
+//! the pass here, so what these rules leave behind is the statuses they
+//! grant and what they spawn. Players have no shields server-side, so nothing blocks and
+//! `on_block` only runs on the client.
+
+use spacetimedb::Table;
+use wasm_fantasia_shared::net::AnimationId;
+use wasm_fantasia_shared::rng::{mix, seed_from_identity};
+use wasm_fantasia_shared::rules::{execute_rules_with_rng, Action, Rule, RuleRng, Stat, Stats};
+
+use crate::effects::apply_statuses;
+use crate::loadouts::player_rules;
+use crate::schema::*;
+use crate::spawns::spawn_requested;
+
+/// Run `on_dodge` if `player`'s relayed animation just switched from
+/// `previous_animation` to a roll.
+pub(crate) fn dodge_started(
+    ctx: &spacetimedb::ReducerContext,
+    previous_animation: u8,
+    player: &Player,
+    now: i64,
+) {
+    let roll = AnimationId::Roll.as_u8();
+    if previous_animation == roll || player.animation != roll {
+        return;
+    }
+    let rules = player_rules(ctx, player.identity);
+    run(ctx, player, &rules.on_dodge, now);
+}
+
+/// Run the `on_health_threshold` rules `player`'s health crossed on its way
+/// down from `before`.
+pub(crate) fn health_dropped(
+    ctx: &spacetimedb::ReducerContext,
+    player: &Player,
+    before: f32,
+    now: i64,
+) {
+    if player.health >= before {
+        return;
+    }
+    let rules = player_rules(ctx, player.identity);
+    for threshold in &rules.on_health_threshold {
+        if threshold.crossed(before, player.health, player.max_health) {
+            run(ctx, player, &threshold.rules, now);
+        }
+    }
+}
+
+/// Called from `game_tick`: run the `on_timer` rules of every live player
+/// whose interval boundary fell in the last `dt` seconds.
+pub(crate) fn tick_timers(ctx: &spacetimedb::ReducerContext, now: i64, dt: f32) {
+    let before = now - (dt * 1_000_000.0) as i64;
+    let players: Vec<Player> = ctx
+        .db
+        .player()
+        .iter()
+        .filter(|p| p.online && p.health > 0.0)
+        .collect();
+    for player in players {
+        let rules = player_rules(ctx, player.identity);
+        for timer in &rules.on_timer {
+            for _ in 0..timer.fires_between(before, now) {
+                run(ctx, &player, &timer.rules, now);
+            }
+        }
+    }
+}
+
+/// Called from `game_tick`: pulse the auras of every live player whose
+/// interval boundary fell in the last `dt` seconds, running their rules on
+/// each live player in the same world within reach.
+pub(crate) fn tick_auras(ctx: &spacetimedb::ReducerContext, now: i64, dt: f32) {
+    let before = now - (dt * 1_000_000.0) as i64;
+    let players: Vec<Player> = ctx
+        .db
+        .player()
+        .iter()
+        .filter(|p| p.online && p.health > 0.0)
+        .collect();
+    for owner in &players {
+        let rules = player_rules(ctx, owner.identity);
+        for aura in &rules.auras {
+            let pulses = aura.fires_between(before, now);
+            if pulses == 0 {
+                continue;
+            }
+            let in_reach = players.iter().filter(|p| {
+                p.world_id == owner.world_id
+                    && (aura.affects_owner || p.identity != owner.identity)
+                    && aura.reaches((owner.x, owner.z), (p.x, p.z))
+            });
+            for target in in_reach {
+                for _ in 0..pulses {
+                    run(ctx, target, &aura.rules, now);
+                }
+            }
+        }
+    }
+}
+
+fn run(ctx: &spacetimedb::ReducerContext, player: &Player, rules: &[Rule], now: i64) {
+    if rules.is_empty() {
+        return;
+    }
+    let mut stats = Stats::new()
+        .with(Stat::Health, player.health)
+        .with(Stat::MaxHealth, player.max_health);
+    let mut rng = RuleRng::new(mix(
+        seed_from_identity(&player.identity.to_byte_array()),
+        now as u64,
+    ));
+    let output = execute_rules_with_rng(rules, &mut stats, &mut Action::new(), &mut rng);
+    apply_statuses(ctx, player.identity, &output.statuses, now);
+    spawn_requested(
+        ctx,
+        player,
+        (player.x, player.y, player.z),
+        &output.spawns,
+        now,
+    );
+}
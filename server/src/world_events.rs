@@ -0,0 +1,153 @@
+//! Scheduled world events. Admins queue them with `schedule_world_event`;
+//! `game_tick` starts and ends them on time, writing each change of phase to
+//! the row so clients can announce it.
+
+use spacetimedb::Table;
+use wasm_fantasia_shared::world_events::{
+    WorldEventKind, WorldEventPhase, DOUBLE_XP_MULTIPLIER, ENDED_LINGER_SECS,
+};
+
+use crate::archetypes::is_admin;
+use crate::director::start_director_in;
+use crate::matches::end_match_if_cleared;
+use crate::schema::*;
+use crate::worlds::spawn_point;
+
+/// Longest an event can be scheduled ahead, and longest it can run.
+const MAX_EVENT_SECS: u32 = 7 * 24 * 60 * 60;
+
+/// XP multiplier from the events running in `world_id`.
+pub(crate) fn xp_multiplier(ctx: &spacetimedb::ReducerContext, world_id: &str) -> u32 {
+    let double_xp = ctx.db.world_event().world_id().filter(world_id).any(|e| {
+        WorldEventKind::parse_str(&e.kind) == Some(WorldEventKind::DoubleXp)
+            && WorldEventPhase::parse_str(&e.phase) == WorldEventPhase::Active
+    });
+    if double_xp {
+        DOUBLE_XP_MULTIPLIER
+    } else {
+        1
+    }
+}
+
+fn set_phase(ctx: &spacetimedb::ReducerContext, event: WorldEvent, phase: WorldEventPhase) {
+    spacetimedb::log::info!(
+        "World event {} ({}) in '{}' is now {}",
+        event.id,
+        event.kind,
+        event.world_id,
+        phase.as_str()
+    );
+    ctx.db.world_event().id().update(WorldEvent {
+        phase: phase.as_str().to_string(),
+        ..event
+    });
+}
+
+fn on_start(ctx: &spacetimedb::ReducerContext, event: &WorldEvent) {
+    if WorldEventKind::parse_str(&event.kind) == Some(WorldEventKind::Invasion) {
+        let (x, y, z) = spawn_point(ctx, &event.world_id);
+        start_director_in(ctx, &event.world_id, x, y, z);
+    }
+}
+
+/// An invasion stops sending waves; the match ends once what's out is
+/// cleared.
+fn on_end(ctx: &spacetimedb::ReducerContext, event: &WorldEvent) {
+    if WorldEventKind::parse_str(&event.kind) == Some(WorldEventKind::Invasion) {
+        ctx.db.spawn_director().world_id().delete(&event.world_id);
+        end_match_if_cleared(ctx, &event.world_id);
+    }
+}
+
+/// Called from `game_tick`: start events whose time has come, end those that
+/// have run their course, and delete ended ones once clients have seen them.
+pub(crate) fn tick_world_events(ctx: &spacetimedb::ReducerContext, now: i64) {
+    let linger_micros = (ENDED_LINGER_SECS * 1_000_000.0) as i64;
+    for event in ctx.db.world_event().iter().collect::<Vec<_>>() {
+        match WorldEventPhase::parse_str(&event.phase) {
+            WorldEventPhase::Scheduled if now >= event.starts_at => {
+                on_start(ctx, &event);
+                set_phase(ctx, event, WorldEventPhase::Active);
+            }
+            WorldEventPhase::Active if now >= event.ends_at => {
+                on_end(ctx, &event);
+                set_phase(ctx, event, WorldEventPhase::Ended);
+            }
+            WorldEventPhase::Ended if now >= event.ends_at + linger_micros => {
+                ctx.db.world_event().id().delete(event.id);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Called when `world_id` is deleted.
+pub(crate) fn clear_world_events(ctx: &spacetimedb::ReducerContext, world_id: &str) {
+    let events: Vec<WorldEvent> = ctx.db.world_event().world_id().filter(world_id).collect();
+    for event in events {
+        ctx.db.world_event().id().delete(event.id);
+    }
+}
+
+/// Queue an event of `kind` in `world_id`, starting `delay_secs` from now and
+/// running for `duration_secs`. Admin only.
+#[spacetimedb::reducer]
+pub fn schedule_world_event(
+    ctx: &spacetimedb::ReducerContext,
+    world_id: String,
+    kind: String,
+    delay_secs: u32,
+    duration_secs: u32,
+) -> Result<(), String> {
+    if !is_admin(ctx) {
+        return Err("Only admins can schedule world events".into());
+    }
+    if ctx.db.world().id().find(&world_id).is_none() {
+        return Err(format!("No world named '{world_id}'"));
+    }
+    let Some(kind) = WorldEventKind::parse_str(&kind) else {
+        return Err(format!("Unknown world event '{kind}'"));
+    };
+    if delay_secs > MAX_EVENT_SECS || !(1..=MAX_EVENT_SECS).contains(&duration_secs) {
+        return Err(format!(
+            "Delay and duration are limited to {MAX_EVENT_SECS} seconds"
+        ));
+    }
+
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    let starts_at = now + delay_secs as i64 * 1_000_000;
+    ctx.db.world_event().insert(WorldEvent {
+        id: 0,
+        world_id,
+        kind: kind.as_str().to_string(),
+        phase: WorldEventPhase::Scheduled.as_str().to_string(),
+        starts_at,
+        ends_at: starts_at + duration_secs as i64 * 1_000_000,
+    });
+    Ok(())
+}
+
+/// Call off an event: one still scheduled is dropped, one running ends on
+/// the next tick. Admin only.
+#[spacetimedb::reducer]
+pub fn cancel_world_event(ctx: &spacetimedb::ReducerContext, id: u64) -> Result<(), String> {
+    if !is_admin(ctx) {
+        return Err("Only admins can cancel world events".into());
+    }
+    let Some(event) = ctx.db.world_event().id().find(id) else {
+        return Err(format!("No world event {id}"));
+    };
+    match WorldEventPhase::parse_str(&event.phase) {
+        WorldEventPhase::Scheduled => {
+            ctx.db.world_event().id().delete(id);
+        }
+        WorldEventPhase::Active => {
+            ctx.db.world_event().id().update(WorldEvent {
+                ends_at: ctx.timestamp.to_micros_since_unix_epoch(),
+                ..event
+            });
+        }
+        WorldEventPhase::Ended => return Err(format!("World event {id} is already over")),
+    }
+    Ok(())
+}
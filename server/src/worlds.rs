@@ -0,0 +1,287 @@
+use spacetimedb::{Identity, Table};
+use wasm_fantasia_shared::gameplay::gameplay;
+
+use crate::archetypes::is_admin;
+use crate::leaderboard::clear_leaderboard;
+use crate::lobby;
+use crate::matches::abort_match;
+use crate::schema::*;
+use crate::world_events::clear_world_events;
+
+/// The multiplayer world every client can join. Never collected or closed.
+pub const SHARED_WORLD: &str = "shared";
+
+/// How often empty worlds are checked for collection.
+pub const WORLD_GC_INTERVAL_MICROS: i64 = 10_000_000;
+
+/// Empty instances without their own lifetime are collected after this long.
+const DEFAULT_EMPTY_LIFETIME_SECS: u32 = 300;
+const SHARED_MAX_PLAYERS: u32 = 64;
+pub(crate) const MAX_INSTANCE_PLAYERS: u32 = 16;
+
+pub(crate) fn new_world(ctx: &spacetimedb::ReducerContext, id: String, owner: Identity) -> World {
+    let (spawn_x, spawn_y, spawn_z) = gameplay().spawn_pos;
+    World {
+        id,
+        owner,
+        max_players: 1,
+        empty_lifetime_secs: DEFAULT_EMPTY_LIFETIME_SECS,
+        spawn_x,
+        spawn_y,
+        spawn_z,
+        created_at: ctx.timestamp.to_micros_since_unix_epoch(),
+        emptied_at: 0,
+    }
+}
+
+/// Insert the shared world unless it already exists.
+pub(crate) fn seed_shared_world(ctx: &spacetimedb::ReducerContext) {
+    if ctx.db.world().id().find(SHARED_WORLD.to_string()).is_none() {
+        ctx.db.world().insert(World {
+            max_players: SHARED_MAX_PLAYERS,
+            empty_lifetime_secs: 0,
+            ..new_world(ctx, SHARED_WORLD.to_string(), ctx.identity())
+        });
+    }
+}
+
+fn online_players_in(ctx: &spacetimedb::ReducerContext, world_id: &str) -> usize {
+    ctx.db
+        .player()
+        .iter()
+        .filter(|p| p.online && p.world_id == world_id)
+        .count()
+}
+
+/// The world the sender may enter as `world_id`, creating their solo world
+/// on first use. Other instances must exist and have room.
+pub(crate) fn admit(ctx: &spacetimedb::ReducerContext, world_id: &str) -> Result<World, String> {
+    let world = match ctx.db.world().id().find(world_id.to_string()) {
+        Some(world) => world,
+        None if world_id == ctx.sender.to_hex().to_string() => {
+            ctx.db
+                .world()
+                .insert(new_world(ctx, world_id.to_string(), ctx.sender))
+        }
+        None if world_id == SHARED_WORLD => {
+            seed_shared_world(ctx);
+            return admit(ctx, world_id);
+        }
+        None => return Err(format!("No world named '{world_id}'")),
+    };
+
+    let already_inside = ctx
+        .db
+        .player()
+        .identity()
+        .find(ctx.sender)
+        .is_some_and(|p| p.online && p.world_id == world_id);
+    if !lobby::may_enter(ctx, world_id, ctx.sender) {
+        return Err(format!("World '{world_id}' is reserved for its lobby"));
+    }
+    if !already_inside && online_players_in(ctx, world_id) >= world.max_players as usize {
+        return Err(format!("World '{world_id}' is full"));
+    }
+    Ok(world)
+}
+
+/// Spawn point of `world_id`, or the shared default if it has no row.
+pub(crate) fn spawn_point(ctx: &spacetimedb::ReducerContext, world_id: &str) -> (f32, f32, f32) {
+    ctx.db
+        .world()
+        .id()
+        .find(world_id.to_string())
+        .map_or(gameplay().spawn_pos, |w| (w.spawn_x, w.spawn_y, w.spawn_z))
+}
+
+/// Delete everything that lives in `world_id`: enemies, boss attacks, events,
+/// drops, chat, match state, scheduled events and the lobby playing in it.
+/// Players are left alone.
+fn purge_world_data(ctx: &spacetimedb::ReducerContext, world_id: &str) {
+    let enemies: Vec<Enemy> = ctx
+        .db
+        .enemy()
+        .iter()
+        .filter(|e| e.world_id == world_id)
+        .collect();
+    for enemy in enemies {
+        ctx.db.enemy().delete(enemy);
+    }
+    let events: Vec<CombatEvent> = ctx
+        .db
+        .combat_event()
+        .iter()
+        .filter(|e| e.world_id == world_id)
+        .collect();
+    for event in events {
+        ctx.db.combat_event().delete(event);
+    }
+    let impulses: Vec<KnockbackImpulse> = ctx
+        .db
+        .knockback_impulse()
+        .iter()
+        .filter(|i| i.world_id == world_id)
+        .collect();
+    for impulse in impulses {
+        ctx.db.knockback_impulse().delete(impulse);
+    }
+    let telegraphs: Vec<Telegraph> = ctx
+        .db
+        .telegraph()
+        .iter()
+        .filter(|t| t.world_id == world_id)
+        .collect();
+    for telegraph in telegraphs {
+        ctx.db.telegraph().delete(telegraph);
+    }
+    let bosses: Vec<BossState> = ctx
+        .db
+        .boss_state()
+        .iter()
+        .filter(|b| b.world_id == world_id)
+        .collect();
+    for boss in bosses {
+        ctx.db.boss_state().delete(boss);
+    }
+    let drops: Vec<LootDrop> = ctx
+        .db
+        .loot_drop()
+        .iter()
+        .filter(|d| d.world_id == world_id)
+        .collect();
+    for drop in drops {
+        ctx.db.loot_drop().delete(drop);
+    }
+    let messages: Vec<ChatMessage> = ctx
+        .db
+        .chat_message()
+        .iter()
+        .filter(|m| m.world_id == world_id)
+        .collect();
+    for message in messages {
+        ctx.db.chat_message().delete(message);
+    }
+    ctx.db
+        .world_pause()
+        .world_id()
+        .delete(&world_id.to_string());
+    ctx.db
+        .spawn_director()
+        .world_id()
+        .delete(&world_id.to_string());
+    abort_match(ctx, world_id);
+    clear_leaderboard(ctx, world_id);
+    lobby::disband_for_world(ctx, world_id);
+    clear_world_events(ctx, world_id);
+}
+
+/// Open a new instance owned by the sender. A lifetime of 0 keeps it around
+/// while empty until its owner closes it.
+#[spacetimedb::reducer]
+pub fn create_world(
+    ctx: &spacetimedb::ReducerContext,
+    world_id: String,
+    max_players: u32,
+    empty_lifetime_secs: u32,
+    spawn_x: f32,
+    spawn_y: f32,
+    spawn_z: f32,
+) -> Result<(), String> {
+    let world_id = world_id.trim().to_string();
+    if world_id.is_empty() {
+        return Err("World id can't be empty".into());
+    }
+    if ctx.db.world().id().find(&world_id).is_some() {
+        return Err(format!("World '{world_id}' already exists"));
+    }
+    if !(1..=MAX_INSTANCE_PLAYERS).contains(&max_players) {
+        return Err(format!(
+            "Max players must be between 1 and {MAX_INSTANCE_PLAYERS}"
+        ));
+    }
+    if ![spawn_x, spawn_y, spawn_z].iter().all(|v| v.is_finite()) {
+        return Err("Spawn point must be finite".into());
+    }
+
+    ctx.db.world().insert(World {
+        max_players,
+        empty_lifetime_secs,
+        spawn_x,
+        spawn_y,
+        spawn_z,
+        ..new_world(ctx, world_id, ctx.sender)
+    });
+    Ok(())
+}
+
+/// Close an instance now: players inside are moved to the shared world and
+/// its data is deleted. Owner or admin only.
+#[spacetimedb::reducer]
+pub fn close_world(ctx: &spacetimedb::ReducerContext, world_id: String) -> Result<(), String> {
+    if world_id == SHARED_WORLD {
+        return Err("The shared world can't be closed".into());
+    }
+    let Some(world) = ctx.db.world().id().find(&world_id) else {
+        return Err(format!("No world named '{world_id}'"));
+    };
+    if world.owner != ctx.sender && !is_admin(ctx) {
+        return Err("Only the owner or an admin can close a world".into());
+    }
+
+    let (x, y, z) = spawn_point(ctx, SHARED_WORLD);
+    let inside: Vec<Player> = ctx
+        .db
+        .player()
+        .iter()
+        .filter(|p| p.world_id == world_id)
+        .collect();
+    for player in inside {
+        ctx.db.player().identity().update(Player {
+            world_id: SHARED_WORLD.to_string(),
+            x,
+            y,
+            z,
+            ..player
+        });
+    }
+    purge_world_data(ctx, &world_id);
+    ctx.db.world().delete(world);
+    Ok(())
+}
+
+/// Scheduled: stamp worlds as they empty and delete those that have stayed
+/// empty past their lifetime.
+#[spacetimedb::reducer]
+pub fn collect_empty_worlds(ctx: &spacetimedb::ReducerContext, _args: WorldGcSchedule) {
+    if ctx.sender != ctx.identity() {
+        return;
+    }
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+
+    let worlds: Vec<World> = ctx
+        .db
+        .world()
+        .iter()
+        .filter(|w| w.id != SHARED_WORLD && w.empty_lifetime_secs > 0)
+        .collect();
+    for world in worlds {
+        let occupied = online_players_in(ctx, &world.id) > 0;
+        if occupied {
+            if world.emptied_at != 0 {
+                ctx.db.world().id().update(World {
+                    emptied_at: 0,
+                    ..world
+                });
+            }
+        } else if world.emptied_at == 0 {
+            ctx.db.world().id().update(World {
+                emptied_at: now,
+                ..world
+            });
+        } else if now - world.emptied_at >= world.empty_lifetime_secs as i64 * 1_000_000 {
+            spacetimedb::log::info!("Collecting empty world '{}'", world.id);
+            purge_world_data(ctx, &world.id);
+            ctx.db.world().delete(world);
+        }
+    }
+}
@@ -0,0 +1,95 @@
+//! Tree-walking vs compiled rule evaluation.
+//!
+//! `cargo bench -p wasm-fantasia-shared`. No bench framework: each case runs
+//! a fixed number of iterations after a warm-up and prints ns/iter, after
+//! checking both evaluators agree.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use wasm_fantasia_shared::presets::default_player_rules;
+use wasm_fantasia_shared::rules::{
+    Action, ActionVar, CompiledRules, Expr, Program, RuleRng, Stat, Stats, action,
+    execute_rules_with_rng, stat, val,
+};
+
+const ITERATIONS: u32 = 1_000_000;
+
+fn bench(name: &str, mut f: impl FnMut() -> f32) {
+    for _ in 0..ITERATIONS / 10 {
+        black_box(f());
+    }
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(f());
+    }
+    let ns = start.elapsed().as_nanos() as f64 / ITERATIONS as f64;
+    println!("{name:<40} {ns:>8.1} ns/iter");
+}
+
+fn add(a: Expr, b: Expr) -> Expr {
+    Expr::Add(Box::new(a), Box::new(b))
+}
+
+fn mul(a: Expr, b: Expr) -> Expr {
+    Expr::Multiply(Box::new(a), Box::new(b))
+}
+
+/// Damage formula shaped like a hand-written preset: constant tuning terms
+/// mixed with stat and action reads, nested a few levels deep.
+fn damage_expr() -> Expr {
+    let tuning = mul(add(val(1.0), mul(val(0.25), val(4.0))), val(0.5));
+    let scaling = add(
+        mul(stat(Stat::AttackDamage), tuning),
+        Expr::Min(
+            Box::new(mul(stat(Stat::CritMultiplier), val(10.0))),
+            Box::new(add(val(20.0), val(5.0))),
+        ),
+    );
+    Expr::Max(
+        Box::new(mul(scaling, action(ActionVar::Damage))),
+        Box::new(Expr::Divide(Box::new(val(1.0)), Box::new(val(3.0)))),
+    )
+}
+
+fn main() {
+    let stats = Stats::new()
+        .with(Stat::AttackDamage, 25.0)
+        .with(Stat::CritChance, 0.3)
+        .with(Stat::CritMultiplier, 2.0);
+    let act = Action::new().with(ActionVar::Damage, 1.5);
+
+    let expr = damage_expr();
+    let program = Program::compile(&expr);
+    assert_eq!(
+        expr.eval(&stats, &act).to_bits(),
+        program.eval(&stats, &act).to_bits()
+    );
+    bench("expr: tree", || expr.eval(black_box(&stats), &act));
+    bench("expr: compiled", || program.eval(black_box(&stats), &act));
+
+    // Pre-hit rules only write the action, so one stats/action pair is
+    // reused and the loop measures rule evaluation rather than cloning
+    let rules = default_player_rules();
+    let compiled = CompiledRules::compile(&rules.pre_hit);
+    let mut rule_stats = stats.clone();
+    let mut rule_act = act.clone();
+    let mut run_tree = |seed: u64| {
+        rule_act.set(ActionVar::Damage, 1.5);
+        let mut rng = RuleRng::new(seed);
+        let out = execute_rules_with_rng(&rules.pre_hit, &mut rule_stats, &mut rule_act, &mut rng);
+        (out.is_crit(), rule_act.get(&ActionVar::Damage))
+    };
+    let seeds = [1, 2, 3, 4, 5, 6, 7, 8];
+    let tree_results = seeds.map(&mut run_tree);
+    bench("pre-hit rules: tree", || run_tree(black_box(1)).1);
+
+    let mut run_compiled = |seed: u64| {
+        rule_act.set(ActionVar::Damage, 1.5);
+        let mut rng = RuleRng::new(seed);
+        let out = compiled.execute_with_rng(&mut rule_stats, &mut rule_act, &mut rng);
+        (out.is_crit(), rule_act.get(&ActionVar::Damage))
+    };
+    assert_eq!(tree_results, seeds.map(&mut run_compiled));
+    bench("pre-hit rules: compiled", || run_compiled(black_box(1)).1);
+}
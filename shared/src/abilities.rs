@@ -0,0 +1,206 @@
+//! Cast abilities beyond the basic attack. Definitions live in the server's
+//! `ability` table; this module holds the built-in ones and how each shape
+//! picks its targets before damage goes through [`resolve_combat`].
+
+use crate::combat::{CombatInput, CombatOutput, HitTarget, resolve_combat};
+use crate::presets::EntityRules;
+use crate::rules::{Stat, Stats};
+
+/// Abilities bound to the hotbar, in slot order.
+pub const HOTBAR: [&str; 3] = [SHOCKWAVE, CLEAVE, BOLT];
+
+pub const SHOCKWAVE: &str = "shockwave";
+pub const CLEAVE: &str = "cleave";
+pub const BOLT: &str = "bolt";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AbilityShape {
+    /// Everything within `range` of the caster.
+    Aoe,
+    /// Everything within `range` and `arc` degrees of the caster's facing.
+    Cone,
+    /// The first target along the caster's facing, up to `range` away and
+    /// within `width` of the line.
+    Projectile,
+}
+
+impl AbilityShape {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Aoe => "aoe",
+            Self::Cone => "cone",
+            Self::Projectile => "projectile",
+        }
+    }
+
+    pub fn parse_str(s: &str) -> Option<Self> {
+        match s {
+            "aoe" => Some(Self::Aoe),
+            "cone" => Some(Self::Cone),
+            "projectile" => Some(Self::Projectile),
+            _ => None,
+        }
+    }
+}
+
+/// Tunables for one ability, mirroring a row of the `ability` table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AbilityDef {
+    pub shape: AbilityShape,
+    pub range: f32,
+    /// Cone only: full arc in degrees.
+    pub arc: f32,
+    /// Projectile only: how far off the line a target can be and still get hit.
+    pub width: f32,
+    /// Scales the caster's attack damage.
+    pub damage_multiplier: f32,
+    pub knockback: f32,
+    pub launch: f32,
+    pub cooldown_secs: f32,
+    /// Attack stacks spent per cast.
+    pub stack_cost: f32,
+    /// Mana spent per cast, before the caster's `on_cast` rules.
+    pub mana_cost: f32,
+}
+
+/// The abilities every server starts with, by name.
+pub fn builtin() -> [(&'static str, AbilityDef); 3] {
+    [
+        (
+            SHOCKWAVE,
+            AbilityDef {
+                shape: AbilityShape::Aoe,
+                range: 5.0,
+                arc: 360.0,
+                width: 0.0,
+                damage_multiplier: 1.5,
+                knockback: 16.0,
+                launch: 4.0,
+                cooldown_secs: 8.0,
+                stack_cost: 3.0,
+                mana_cost: 30.0,
+            },
+        ),
+        (
+            CLEAVE,
+            AbilityDef {
+                shape: AbilityShape::Cone,
+                range: 4.5,
+                arc: 120.0,
+                width: 0.0,
+                damage_multiplier: 2.0,
+                knockback: 10.0,
+                launch: 0.0,
+                cooldown_secs: 4.0,
+                stack_cost: 1.0,
+                mana_cost: 15.0,
+            },
+        ),
+        (
+            BOLT,
+            AbilityDef {
+                shape: AbilityShape::Projectile,
+                range: 18.0,
+                arc: 0.0,
+                width: 0.8,
+                damage_multiplier: 3.0,
+                knockback: 8.0,
+                launch: 1.0,
+                cooldown_secs: 6.0,
+                stack_cost: 2.0,
+                mana_cost: 25.0,
+            },
+        ),
+    ]
+}
+
+/// Whether `cooldown_secs` have passed since the ability was last cast.
+pub fn cooldown_ready(last_cast_micros: i64, now_micros: i64, cooldown_secs: f32) -> bool {
+    now_micros - last_cast_micros >= (cooldown_secs as f64 * 1_000_000.0) as i64
+}
+
+/// Distance along the path at which a projectile from `origin` heading
+/// `forward` meets `target`, or `None` if it passes by or falls short.
+pub fn projectile_hit_check(
+    origin: glam::Vec2,
+    forward: glam::Vec2,
+    target: glam::Vec2,
+    range: f32,
+    width: f32,
+) -> Option<f32> {
+    let delta = target - origin;
+    let along = delta.dot(forward);
+    if !(0.0..=range).contains(&along) {
+        return None;
+    }
+    let off_line = (delta - forward * along).length();
+    (off_line <= width).then_some(along)
+}
+
+/// Everything needed to resolve one cast.
+pub struct AbilityInput<'a> {
+    pub ability: &'a AbilityDef,
+    pub origin: glam::Vec2,
+    pub forward: glam::Vec2,
+    /// The caster's own stats; the ability scales damage and sets knockback.
+    pub caster_stats: &'a Stats,
+    pub rules: &'a EntityRules,
+    pub rng_seed: u64,
+    pub targets: &'a [HitTarget],
+}
+
+/// Resolve a cast: pick targets by shape, then run them through
+/// [`resolve_combat`] with the ability's damage and knockback.
+pub fn resolve_ability(input: &AbilityInput) -> CombatOutput {
+    let ability = input.ability;
+    let mut stats = input.caster_stats.clone();
+    stats.set(
+        Stat::AttackDamage,
+        input.caster_stats.get(&Stat::AttackDamage) * ability.damage_multiplier,
+    );
+    stats.set(Stat::Knockback, ability.knockback);
+
+    let (base_range, half_arc_cos, targets) = match ability.shape {
+        AbilityShape::Aoe => (ability.range, -1.0, input.targets),
+        AbilityShape::Cone => (
+            ability.range,
+            (ability.arc / 2.0).to_radians().cos(),
+            input.targets,
+        ),
+        AbilityShape::Projectile => {
+            // Stops at the first target; the cone check after is a formality
+            let first = input
+                .targets
+                .iter()
+                .enumerate()
+                .filter_map(|(i, t)| {
+                    projectile_hit_check(
+                        input.origin,
+                        input.forward,
+                        t.pos,
+                        ability.range,
+                        ability.width,
+                    )
+                    .map(|along| (i, along))
+                })
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .map(|(i, _)| i);
+            let targets = match first {
+                Some(i) => &input.targets[i..=i],
+                None => &[],
+            };
+            (ability.range + ability.width, -1.0, targets)
+        }
+    };
+
+    resolve_combat(&CombatInput {
+        origin: input.origin,
+        forward: input.forward,
+        base_range,
+        half_arc_cos,
+        attacker_stats: &stats,
+        rules: input.rules,
+        rng_seed: input.rng_seed,
+        targets,
+    })
+}
@@ -0,0 +1,199 @@
+//! Boss fights. A boss moves through phases as its health drops, and each
+//! phase has its own set of special attacks. A special attack is announced
+//! by a telegraph (the area it will hit) and lands once the wind-up ends.
+//! The server runs this from `game_tick`. Clients only draw the telegraphs.
+
+use crate::combat::cone_hit_check;
+
+/// Archetype name the server treats as a boss.
+pub const BOSS_ARCHETYPE: &str = "boss";
+/// A boss only starts a special attack on a player within this distance.
+pub const SPECIAL_TRIGGER_RANGE: f32 = 14.0;
+
+/// Phases in the order a boss goes through them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum BossPhase {
+    #[default]
+    Opening,
+    Frenzy,
+    Desperation,
+}
+
+impl BossPhase {
+    /// Convert to the phase id stored in `boss_state` rows.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Opening => "opening",
+            Self::Frenzy => "frenzy",
+            Self::Desperation => "desperation",
+        }
+    }
+
+    pub fn parse_str(s: &str) -> Self {
+        match s {
+            "frenzy" => Self::Frenzy,
+            "desperation" => Self::Desperation,
+            _ => Self::Opening,
+        }
+    }
+
+    /// The phase a boss at `health_fraction` of its max health is in. Phases
+    /// only go forward: a boss healed back over a threshold stays where it is.
+    pub fn for_health(health_fraction: f32, current: Self) -> Self {
+        let by_health = if health_fraction > 0.66 {
+            Self::Opening
+        } else if health_fraction > 0.33 {
+            Self::Frenzy
+        } else {
+            Self::Desperation
+        };
+        by_health.max(current)
+    }
+
+    /// Special attacks the phase cycles through, in order.
+    pub fn attacks(self) -> &'static [BossAttack] {
+        match self {
+            Self::Opening => &[BossAttack::Cleave, BossAttack::Slam],
+            Self::Frenzy => &[BossAttack::Cleave, BossAttack::Slam, BossAttack::Shockwave],
+            Self::Desperation => &[BossAttack::Shockwave, BossAttack::Slam, BossAttack::Cleave],
+        }
+    }
+
+    /// Seconds between one special attack landing and the next starting.
+    pub fn special_cooldown_secs(self) -> f32 {
+        match self {
+            Self::Opening => 6.0,
+            Self::Frenzy => 4.5,
+            Self::Desperation => 3.0,
+        }
+    }
+
+    /// Multiplier on wind-up time; later phases give less warning.
+    pub fn windup_scale(self) -> f32 {
+        match self {
+            Self::Opening => 1.0,
+            Self::Frenzy => 0.85,
+            Self::Desperation => 0.7,
+        }
+    }
+}
+
+/// Area a telegraph covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TelegraphShape {
+    /// Everything within `radius` of the center.
+    Circle,
+    /// A wedge of `arc` degrees and `radius` long, facing `rotation_y`.
+    Cone,
+}
+
+impl TelegraphShape {
+    /// Convert to the shape id stored in `telegraph` rows.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Circle => "circle",
+            Self::Cone => "cone",
+        }
+    }
+
+    pub fn parse_str(s: &str) -> Self {
+        match s {
+            "cone" => Self::Cone,
+            _ => Self::Circle,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BossAttack {
+    /// Wide cone in front of the boss.
+    Cleave,
+    /// Circle dropped on the targeted player's position.
+    Slam,
+    /// Large ring centered on the boss.
+    Shockwave,
+}
+
+/// Tuning of one special attack.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BossAttackSpec {
+    pub shape: TelegraphShape,
+    pub radius: f32,
+    /// Full arc in degrees. Circles use 360.
+    pub arc: f32,
+    /// Seconds from the telegraph appearing to the hit landing, before the
+    /// phase's [`BossPhase::windup_scale`].
+    pub windup_secs: f32,
+    pub damage: f32,
+    /// Horizontal knockback on players caught in the area.
+    pub knockback: f32,
+}
+
+impl BossAttack {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Cleave => "cleave",
+            Self::Slam => "slam",
+            Self::Shockwave => "shockwave",
+        }
+    }
+
+    /// Parse an attack id. Unknown ids (from a newer server) are `None`.
+    pub fn parse_str(s: &str) -> Option<Self> {
+        match s {
+            "cleave" => Some(Self::Cleave),
+            "slam" => Some(Self::Slam),
+            "shockwave" => Some(Self::Shockwave),
+            _ => None,
+        }
+    }
+
+    pub fn spec(self) -> BossAttackSpec {
+        match self {
+            Self::Cleave => BossAttackSpec {
+                shape: TelegraphShape::Cone,
+                radius: 7.0,
+                arc: 120.0,
+                windup_secs: 1.2,
+                damage: 30.0,
+                knockback: 6.0,
+            },
+            Self::Slam => BossAttackSpec {
+                shape: TelegraphShape::Circle,
+                radius: 3.5,
+                arc: 360.0,
+                windup_secs: 1.6,
+                damage: 40.0,
+                knockback: 4.0,
+            },
+            Self::Shockwave => BossAttackSpec {
+                shape: TelegraphShape::Circle,
+                radius: 9.0,
+                arc: 360.0,
+                windup_secs: 2.0,
+                damage: 25.0,
+                knockback: 10.0,
+            },
+        }
+    }
+}
+
+/// Whether `target` (on the XZ plane) is inside a telegraph centered on
+/// `center` and facing `rotation_y`.
+pub fn in_telegraph(
+    shape: TelegraphShape,
+    center: glam::Vec2,
+    rotation_y: f32,
+    radius: f32,
+    arc: f32,
+    target: glam::Vec2,
+) -> bool {
+    match shape {
+        TelegraphShape::Circle => center.distance(target) <= radius,
+        TelegraphShape::Cone => {
+            let forward = glam::Vec2::new(-rotation_y.sin(), -rotation_y.cos());
+            let half_arc_cos = (arc.to_radians() / 2.0).cos();
+            cone_hit_check(center, forward, target, radius, half_arc_cos)
+        }
+    }
+}
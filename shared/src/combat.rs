@@ -25,17 +25,57 @@ pub mod defaults {
     pub const ENEMY_SEPARATION_RADIUS: f32 = 1.2;
     /// Separation speed in m/s (higher than walk speed so separation wins).
     pub const ENEMY_SEPARATION_STRENGTH: f32 = 2.0;
+    /// Rate (1/s) at which an enemy's horizontal velocity eases toward the
+    /// one its AI wants. Knockback decays at this rate on top of movement
+    /// rather than being replaced by it on the next tick.
+    pub const ENEMY_VELOCITY_DAMPING: f32 = 6.0;
     /// Spawn ring inner radius (meters from player).
     pub const ENEMY_SPAWN_RADIUS_MIN: f32 = 10.0;
     /// Spawn ring outer radius (meters from player).
     pub const ENEMY_SPAWN_RADIUS_MAX: f32 = 25.0;
+    pub const SHIELD: f32 = 50.0;
+    pub const ENEMY_SHIELD: f32 = 100.0;
+    /// Seconds without taking damage before the shield starts recharging.
+    pub const SHIELD_REGEN_DELAY: f32 = 4.0;
+    pub const SHIELD_REGEN_PER_SEC: f32 = 25.0;
+    /// Half the side length of the square arena floor (meters).
+    pub const ARENA_HALF_SIZE: f32 = 250.0;
+    /// Anything that falls below this height is out of the world.
+    pub const KILL_PLANE_Y: f32 = -30.0;
+    /// A void-out counts as a kill for whoever hit the victim within this window.
+    pub const KILL_CREDIT_WINDOW_SECS: f32 = 5.0;
+    /// Enemies with no player closer than this tick at a reduced rate.
+    pub const ENEMY_LOD_NEAR: f32 = 40.0;
+    /// Enemies with no player closer than this are frozen in place.
+    pub const ENEMY_LOD_FAR: f32 = 120.0;
+    /// Reduced-rate enemies tick once every this many server ticks.
+    pub const ENEMY_LOD_TICK_DIVISOR: u32 = 4;
+    /// Upper bound on how far back the server rewinds targets for a laggy attacker.
+    pub const MAX_HIT_REWIND_SECS: f32 = 0.25;
+    /// Damage as a fraction of max health at which a flinch becomes a
+    /// full-body medium reaction, and at which it becomes a heavy one.
+    pub const HIT_REACTION_MEDIUM_FRACTION: f32 = 0.08;
+    pub const HIT_REACTION_HEAVY_FRACTION: f32 = 0.2;
 }
 
 /// Pure decision function for enemy AI state machine.
 /// Both client (singleplayer) and server (multiplayer) call this to ensure
 /// identical behavior logic. The caller handles movement/DB writes.
 pub fn enemy_ai_decision(distance: f32, attack_cooldown_ready: bool) -> EnemyBehaviorKind {
-    if distance > defaults::ENEMY_ATTACK_RANGE {
+    enemy_ai_decision_in_range(
+        distance,
+        defaults::ENEMY_ATTACK_RANGE,
+        attack_cooldown_ready,
+    )
+}
+
+/// [`enemy_ai_decision`] for an enemy with its own attack range.
+pub fn enemy_ai_decision_in_range(
+    distance: f32,
+    attack_range: f32,
+    attack_cooldown_ready: bool,
+) -> EnemyBehaviorKind {
+    if distance > attack_range {
         EnemyBehaviorKind::Chase
     } else if attack_cooldown_ready {
         EnemyBehaviorKind::Attack
@@ -45,7 +85,7 @@ pub fn enemy_ai_decision(distance: f32, attack_cooldown_ready: bool) -> EnemyBeh
 }
 
 /// Shared enum for enemy AI decisions. Mirrors the client `EnemyBehavior`
-/// component and the server `animation_state` string.
+/// component and the server `enemy.behavior` column.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EnemyBehaviorKind {
     Idle,
@@ -54,25 +94,102 @@ pub enum EnemyBehaviorKind {
 }
 
 impl EnemyBehaviorKind {
+    /// Stable wire id stored in `enemy.behavior`. Append only.
+    pub fn as_u8(self) -> u8 {
+        match self {
+            Self::Idle => 0,
+            Self::Chase => 1,
+            Self::Attack => 2,
+        }
+    }
+
+    /// Unknown ids read as idle.
+    pub fn from_u8(id: u8) -> Self {
+        match id {
+            1 => Self::Chase,
+            2 => Self::Attack,
+            _ => Self::Idle,
+        }
+    }
+}
+
+/// How often an enemy is simulated, by its distance to the nearest player.
+/// Keeps the cost of a tick bounded by the enemies near someone rather than
+/// every enemy in the world.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnemyTickTier {
+    /// Every tick.
+    Active,
+    /// Once every [`defaults::ENEMY_LOD_TICK_DIVISOR`] ticks.
+    Reduced,
+    /// Not at all, until a player comes back in range.
+    Frozen,
+}
+
+impl EnemyTickTier {
+    pub fn from_distance(nearest_player: f32) -> Self {
+        if nearest_player < defaults::ENEMY_LOD_NEAR {
+            Self::Active
+        } else if nearest_player < defaults::ENEMY_LOD_FAR {
+            Self::Reduced
+        } else {
+            Self::Frozen
+        }
+    }
+}
+
+/// How hard a hit rocks the target, picked from damage relative to max health.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HitReaction {
+    Light,
+    Medium,
+    Heavy,
+}
+
+impl HitReaction {
+    pub fn from_damage(damage: f32, max_health: f32) -> Self {
+        let fraction = damage / max_health.max(1.0);
+        if fraction >= defaults::HIT_REACTION_HEAVY_FRACTION {
+            Self::Heavy
+        } else if fraction >= defaults::HIT_REACTION_MEDIUM_FRACTION {
+            Self::Medium
+        } else {
+            Self::Light
+        }
+    }
+
     /// Convert to the string representation used in server DB rows.
     pub fn as_str(self) -> &'static str {
         match self {
-            Self::Idle => "Idle",
-            Self::Chase => "Chase",
-            Self::Attack => "Attack",
+            Self::Light => "Light",
+            Self::Medium => "Medium",
+            Self::Heavy => "Heavy",
         }
     }
 
     /// Parse from the server DB string representation.
     pub fn parse_str(s: &str) -> Self {
         match s {
-            "Chase" => Self::Chase,
-            "Attack" => Self::Attack,
-            _ => Self::Idle,
+            "Medium" => Self::Medium,
+            "Heavy" => Self::Heavy,
+            _ => Self::Light,
         }
     }
 }
 
+/// `combat_event.reaction` values that mark a player dying or respawning
+/// rather than a hit. Such rows carry no damage or knockback.
+pub mod life_events {
+    pub const DEATH: &str = "Death";
+    pub const RESPAWN: &str = "Respawn";
+    /// An admin moved the target; its client snaps to the event's position.
+    pub const TELEPORT: &str = "Teleport";
+
+    pub fn is_life_event(reaction: &str) -> bool {
+        reaction == DEATH || reaction == RESPAWN || reaction == TELEPORT
+    }
+}
+
 /// Attack timing constants (at 1.0x speed)
 pub mod attack_timing {
     /// Base duration for punch animations (jab/cross)
@@ -165,6 +282,37 @@ pub fn decay_stacks(stacks: f32, elapsed_secs: f64, decay_threshold: f32) -> f32
     }
 }
 
+/// Damage split between shield and health. See [`absorb_damage`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Absorbed {
+    pub shield: f32,
+    pub health: f32,
+    /// The shield was up before this hit and is down after it.
+    pub shield_broke: bool,
+    /// The shield soaked the whole hit, so health took none of it.
+    pub blocked: bool,
+}
+
+/// Apply damage to the shield first; whatever it can't soak spills into health.
+pub fn absorb_damage(shield: f32, health: f32, damage: f32) -> Absorbed {
+    let soaked = damage.min(shield.max(0.0));
+    let new_shield = shield - soaked;
+    Absorbed {
+        shield: new_shield,
+        health: (health - (damage - soaked)).max(0.0),
+        shield_broke: shield > 0.0 && new_shield <= 0.0,
+        blocked: damage > 0.0 && soaked >= damage,
+    }
+}
+
+/// Recharge the shield once [`defaults::SHIELD_REGEN_DELAY`] has passed since the last hit.
+pub fn regen_shield(shield: f32, max_shield: f32, secs_since_hit: f32, dt: f32) -> f32 {
+    if secs_since_hit < defaults::SHIELD_REGEN_DELAY {
+        return shield;
+    }
+    (shield + defaults::SHIELD_REGEN_PER_SEC * dt).min(max_shield)
+}
+
 /// Check if enough time has passed since last attack (respecting attack speed).
 pub fn can_attack(last_attack_micros: i64, now_micros: i64, attack_speed: f32) -> bool {
     let cooldown_micros =
@@ -178,16 +326,17 @@ pub fn can_attack(last_attack_micros: i64, now_micros: i64, attack_speed: f32) -
 
 use crate::presets::EntityRules;
 use crate::presets::feedback;
+use crate::rng::{RuleRng, mix};
 use crate::rules::{
-    Action, ActionVar, Rule, RuleOutput, Stats, execute_effects, execute_rules,
-    execute_rules_with_roll,
+    Action, ActionVar, CompiledRules, FeedbackCue, PassTrace, Rule, RuleOutput, SpawnRequest,
+    Stats, StatusGrant, TriggerTrace, execute_effects, execute_rules_with_rng,
 };
 
 /// Input to the shared attack resolver.
 pub struct AttackInput {
     pub attacker_stats: Stats,
     pub pre_hit_rules: Vec<Rule>,
-    pub rng_roll: f32,
+    pub rng_seed: u64,
 }
 
 /// Output from the shared attack resolver.
@@ -202,11 +351,26 @@ pub struct AttackOutput {
 }
 
 /// Unified attack resolution. Both client and server call this with identical inputs
-/// to produce identical outputs. Deterministic when `rng_roll` is computed from
+/// to produce identical outputs. Deterministic when `rng_seed` is computed from
 /// shared RNG with the same seeds.
 pub fn resolve_attack(input: &AttackInput) -> AttackOutput {
-    let stats = &input.attacker_stats;
+    resolve_compiled_attack(
+        &input.attacker_stats,
+        &CompiledRules::compile(&input.pre_hit_rules),
+        &mut RuleRng::new(input.rng_seed),
+        Action::new(),
+    )
+}
 
+/// [`resolve_attack`] with pre-hit rules compiled by the caller, so a
+/// multi-target attack compiles them once. The rules' [`Action`] starts out
+/// as `context`.
+fn resolve_compiled_attack(
+    stats: &Stats,
+    pre_hit_rules: &CompiledRules,
+    rng: &mut RuleRng,
+    context: Action,
+) -> AttackOutput {
     let base_damage = {
         let v = stats.get(&crate::rules::Stat::AttackDamage);
         if v > 0.0 { v } else { defaults::ATTACK_DAMAGE }
@@ -217,7 +381,7 @@ pub fn resolve_attack(input: &AttackInput) -> AttackOutput {
     };
 
     // Build action context with base combat values
-    let mut action = Action::new()
+    let mut action = context
         .with(ActionVar::Damage, base_damage)
         .with(ActionVar::Knockback, base_knockback)
         .with(ActionVar::Push, 0.0)
@@ -227,14 +391,9 @@ pub fn resolve_attack(input: &AttackInput) -> AttackOutput {
     let mut dummy_stats = Stats::new();
     let _ = execute_effects(&feedback::standard(), &mut dummy_stats, &mut action);
 
-    // Execute pre-hit rules with deterministic roll
-    let mut eval_stats = input.attacker_stats.clone();
-    let rule_output = execute_rules_with_roll(
-        &input.pre_hit_rules,
-        &mut eval_stats,
-        &mut action,
-        input.rng_roll,
-    );
+    // Execute pre-hit rules with deterministic rolls
+    let mut eval_stats = stats.clone();
+    let rule_output = pre_hit_rules.execute_with_rng(&mut eval_stats, &mut action, rng);
 
     let is_crit = rule_output.is_crit();
     let damage = action.get(&ActionVar::Damage);
@@ -273,8 +432,25 @@ pub const CRIT_RANGE_BONUS: f32 = 1.3;
 /// A potential target for the attack.
 pub struct HitTarget {
     pub id: u64,
+    /// Salts the target's rolls. Must be the same on client and server for
+    /// predicted crits to match: the enemy's id, or a player's identity seed.
+    pub seed: u64,
     pub pos: glam::Vec2,
     pub health: f32,
+    pub max_health: f32,
+    pub shield: f32,
+    pub armor: f32,
+}
+
+impl HitTarget {
+    /// The target's [`ActionVar`]s for rules run against it, at `health`.
+    fn context(&self, origin: glam::Vec2, health: f32) -> Action {
+        Action::new()
+            .with(ActionVar::TargetHealth, health)
+            .with(ActionVar::TargetMaxHealth, self.max_health)
+            .with(ActionVar::TargetDistance, self.pos.distance(origin))
+            .with(ActionVar::TargetArmor, self.armor)
+    }
 }
 
 /// Result for a single target that was hit.
@@ -286,8 +462,14 @@ pub struct HitResult {
     pub push: f32,
     pub launch: f32,
     pub new_health: f32,
+    pub new_shield: f32,
+    pub shield_broke: bool,
     pub died: bool,
     pub feedback: HitFeedback,
+    /// What the attacker's rules spawned on this hit, to go off at the target.
+    pub spawns: Vec<SpawnRequest>,
+    /// Feedback the attacker's rules played on this hit.
+    pub cues: Vec<FeedbackCue>,
 }
 
 /// Everything needed to resolve a full attack against multiple targets.
@@ -298,6 +480,7 @@ pub struct CombatInput<'a> {
     pub half_arc_cos: f32,
     pub attacker_stats: &'a Stats,
     pub rules: &'a EntityRules,
+    /// Seeds every roll of the attack, e.g. [`attack_seed`](crate::rng::attack_seed).
     pub rng_seed: u64,
     pub targets: &'a [HitTarget],
 }
@@ -308,6 +491,12 @@ pub struct CombatOutput {
     pub hits: Vec<HitResult>,
     /// Attacker stats after on_hit/on_crit_hit/on_kill rule execution.
     pub attacker_stats: Stats,
+    /// Timed effects the attacker's rules granted them on the hits that
+    /// landed, for the caller to apply.
+    pub statuses: Vec<StatusGrant>,
+    /// Every rule pass run for the hits that landed, while
+    /// [`tracing`](crate::rules::tracing) is on.
+    pub traces: Vec<TriggerTrace>,
     pub hit_any: bool,
 }
 
@@ -319,17 +508,22 @@ pub struct CombatOutput {
 pub fn resolve_combat(input: &CombatInput) -> CombatOutput {
     let mut hits = Vec::new();
     let mut rule_stats = input.attacker_stats.clone();
+    let mut statuses = Vec::new();
+    let mut traces = Vec::new();
     let mut hit_any = false;
+    let pre_hit_rules = CompiledRules::compile(&input.rules.pre_hit);
 
     for target in input.targets {
-        // Per-target deterministic RNG
-        let rng_roll = crate::rng::deterministic_random_u64(input.rng_seed as i64, target.id);
+        // Per-target deterministic RNG, shared by every rule run for the target
+        let mut rng = RuleRng::new(mix(input.rng_seed, target.seed));
 
-        let result = resolve_attack(&AttackInput {
-            attacker_stats: input.attacker_stats.clone(),
-            pre_hit_rules: input.rules.pre_hit.clone(),
-            rng_roll,
-        });
+        // Pre-hit rules see the target as it was, the rest as the hit left it
+        let result = resolve_compiled_attack(
+            input.attacker_stats,
+            &pre_hit_rules,
+            &mut rng,
+            target.context(input.origin, target.health),
+        );
 
         // Crits get bonus range
         let range = if result.is_crit {
@@ -348,9 +542,53 @@ pub fn resolve_combat(input: &CombatInput) -> CombatOutput {
             continue;
         }
 
-        let new_health = (target.health - result.damage).max(0.0);
+        let absorbed = absorb_damage(target.shield, target.health, result.damage);
+        let new_health = absorbed.health;
         let died = new_health <= 0.0;
 
+        let mut spawns = result.rule_output.spawns;
+        let mut cues = result.rule_output.cues;
+        statuses.extend(result.rule_output.statuses);
+        keep_trace(&mut traces, "pre_hit", target.id, result.rule_output.trace);
+
+        // On-hit rules accumulate on the attacker's stats (e.g. stacking)
+        let context = target.context(input.origin, new_health);
+        let mut action = context.clone();
+        let out =
+            execute_rules_with_rng(&input.rules.on_hit, &mut rule_stats, &mut action, &mut rng);
+        statuses.extend(out.statuses);
+        spawns.extend(out.spawns);
+        cues.extend(out.cues);
+        keep_trace(&mut traces, "on_hit", target.id, out.trace);
+
+        if result.is_crit {
+            let mut action = context.clone();
+            let out = execute_rules_with_rng(
+                &input.rules.on_crit_hit,
+                &mut rule_stats,
+                &mut action,
+                &mut rng,
+            );
+            statuses.extend(out.statuses);
+            spawns.extend(out.spawns);
+            cues.extend(out.cues);
+            keep_trace(&mut traces, "on_crit_hit", target.id, out.trace);
+        }
+
+        if died {
+            let mut action = context;
+            let out = execute_rules_with_rng(
+                &input.rules.on_kill,
+                &mut rule_stats,
+                &mut action,
+                &mut rng,
+            );
+            statuses.extend(out.statuses);
+            spawns.extend(out.spawns);
+            cues.extend(out.cues);
+            keep_trace(&mut traces, "on_kill", target.id, out.trace);
+        }
+
         hits.push(HitResult {
             target_id: target.id,
             damage: result.damage,
@@ -359,34 +597,41 @@ pub fn resolve_combat(input: &CombatInput) -> CombatOutput {
             push: result.push,
             launch: result.launch,
             new_health,
+            new_shield: absorbed.shield,
+            shield_broke: absorbed.shield_broke,
             died,
             feedback: result.feedback,
+            spawns,
+            cues,
         });
 
-        // On-hit rules accumulate on the attacker's stats (e.g. stacking)
-        let mut action = Action::new();
-        execute_rules(&input.rules.on_hit, &mut rule_stats, &mut action);
-
-        if result.is_crit {
-            let mut action = Action::new();
-            execute_rules(&input.rules.on_crit_hit, &mut rule_stats, &mut action);
-        }
-
-        if died {
-            let mut action = Action::new();
-            execute_rules(&input.rules.on_kill, &mut rule_stats, &mut action);
-        }
-
         hit_any = true;
     }
 
     CombatOutput {
         hits,
         attacker_stats: rule_stats,
+        statuses,
+        traces,
         hit_any,
     }
 }
 
+fn keep_trace(
+    traces: &mut Vec<TriggerTrace>,
+    trigger: &'static str,
+    target: u64,
+    pass: Option<PassTrace>,
+) {
+    if let Some(pass) = pass {
+        traces.push(TriggerTrace {
+            trigger,
+            target: Some(target),
+            pass,
+        });
+    }
+}
+
 // ============================================================================
 // AOE CONSTANTS — shared between client and server
 // ============================================================================
@@ -422,3 +667,27 @@ pub mod landing_aoe {
         (radius, kb, launch)
     }
 }
+
+// ============================================================================
+// MATCH REWARDS
+// ============================================================================
+
+/// Rewards tallied into the end-of-match summary.
+pub mod rewards {
+    pub const XP_PER_KILL: u32 = 10;
+    /// Bonus XP for every player still in the world when the last enemy dies.
+    pub const XP_MATCH_CLEAR: u32 = 100;
+    /// Chance that a dying enemy drops loot (see [`crate::loot::roll_drop`]).
+    pub const LOOT_DROP_CHANCE: f32 = 0.15;
+    /// Party members within this distance of a kill share its XP.
+    pub const SHARED_XP_RADIUS: f32 = 20.0;
+    /// Extra kill XP per additional party member in range.
+    pub const GROUP_XP_BONUS: f32 = 0.1;
+
+    /// XP each party member in range receives for a kill, the killer included.
+    /// Grouping up pays: three players nearby each get 20% more than a solo kill.
+    pub fn shared_kill_xp(members_in_range: u32) -> u32 {
+        let extra = members_in_range.saturating_sub(1) as f32;
+        (XP_PER_KILL as f32 * (1.0 + GROUP_XP_BONUS * extra)).round() as u32
+    }
+}
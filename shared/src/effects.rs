@@ -0,0 +1,279 @@
+//! Timed effects on players (`active_effect` rows). Every effect counts its
+//! `duration` down and is removed when it runs out; a negative duration never
+//! runs out. Periodic effects also change their owner's health once per
+//! interval, by an amount their rules work out from the effect's magnitude.
+//! Control effects hold their owner back instead; see [`Impairment`]. Hastes
+//! speed up their owner's attacks; see [`haste_multiplier`]. The server runs
+//! them from `game_tick`.
+
+use crate::rules::{
+    Action, ActionVar, Effect, Expr, Rule, Stat, Stats, action, execute_rules, stat, val,
+};
+
+/// Effects that damage or heal their owner on an interval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PeriodicEffect {
+    /// `magnitude` damage per tick.
+    Burn,
+    /// `magnitude` as a fraction of max health per tick.
+    Poison,
+    /// `magnitude` healing per tick, up to max health.
+    Regen,
+}
+
+impl PeriodicEffect {
+    pub const ALL: [Self; 3] = [Self::Burn, Self::Poison, Self::Regen];
+
+    /// Name an admin types for it; see [`EffectKind::parse_str`].
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Burn => "burn",
+            Self::Poison => "poison",
+            Self::Regen => "regen",
+        }
+    }
+
+    /// Parse a name from [`Self::as_str`].
+    pub fn parse_str(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|e| e.as_str() == s)
+    }
+
+    /// Seconds between ticks.
+    pub fn interval_secs(self) -> f32 {
+        match self {
+            Self::Burn => 0.5,
+            Self::Poison | Self::Regen => 1.0,
+        }
+    }
+
+    /// Rules for one tick. They read the effect's magnitude from
+    /// [`magnitude_stat`] and write the health change (negative for damage)
+    /// to [`ActionVar::Damage`].
+    pub fn tick_rules(self) -> Vec<Rule> {
+        let change = match self {
+            Self::Burn => Expr::Negate(Box::new(stat(magnitude_stat()))),
+            Self::Poison => Expr::Negate(Box::new(Expr::Multiply(
+                Box::new(stat(Stat::MaxHealth)),
+                Box::new(stat(magnitude_stat())),
+            ))),
+            Self::Regen => Expr::Min(
+                Box::new(stat(magnitude_stat())),
+                Box::new(Expr::Max(
+                    Box::new(Expr::Subtract(
+                        Box::new(stat(Stat::MaxHealth)),
+                        Box::new(stat(Stat::Health)),
+                    )),
+                    Box::new(val(0.0)),
+                )),
+            ),
+        };
+        vec![Rule::new().then(Effect::SetAction {
+            var: ActionVar::Damage,
+            value: Expr::Add(Box::new(action(ActionVar::Damage)), Box::new(change)),
+        })]
+    }
+
+    /// Health change of one tick on an owner at `health` out of `max_health`.
+    pub fn tick_amount(self, magnitude: f32, health: f32, max_health: f32) -> f32 {
+        let mut stats = Stats::new()
+            .with(Stat::Health, health)
+            .with(Stat::MaxHealth, max_health)
+            .with(magnitude_stat(), magnitude);
+        let mut action = Action::new();
+        execute_rules(&self.tick_rules(), &mut stats, &mut action);
+        action.get(&ActionVar::Damage)
+    }
+}
+
+/// Effects that hold their owner back while they last.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ControlEffect {
+    /// No moving, attacking or casting.
+    Stun,
+    /// Movement speed cut by `magnitude`, as a fraction: 0.3 is 30% slower.
+    Slow,
+}
+
+impl ControlEffect {
+    pub const ALL: [Self; 2] = [Self::Stun, Self::Slow];
+
+    /// Name an admin types for it; see [`EffectKind::parse_str`].
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Stun => "stun",
+            Self::Slow => "slow",
+        }
+    }
+
+    /// Parse a name from [`Self::as_str`].
+    pub fn parse_str(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|e| e.as_str() == s)
+    }
+}
+
+/// What an owner's control effects stop them doing right now. Both sides
+/// resolve it from the same effects: the server to reject moves and swings,
+/// the client to lock its controller and animations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Impairment {
+    pub stunned: bool,
+    /// Scales movement speed: 1 is unhindered, 0 holds the owner in place.
+    pub speed_multiplier: f32,
+}
+
+impl Default for Impairment {
+    fn default() -> Self {
+        Self {
+            stunned: false,
+            speed_multiplier: 1.0,
+        }
+    }
+}
+
+impl Impairment {
+    /// Resolve the kind and magnitude of every effect on an owner. Slows
+    /// don't stack; the strongest one applies.
+    pub fn resolve(effects: impl IntoIterator<Item = (EffectKind, f32)>) -> Self {
+        let mut stunned = false;
+        let mut slow: f32 = 0.0;
+        for (kind, magnitude) in effects {
+            match kind {
+                EffectKind::Control(ControlEffect::Stun) => stunned = true,
+                EffectKind::Control(ControlEffect::Slow) => slow = slow.max(magnitude),
+                _ => {}
+            }
+        }
+        Self {
+            stunned,
+            speed_multiplier: if stunned {
+                0.0
+            } else {
+                1.0 - slow.clamp(0.0, 1.0)
+            },
+        }
+    }
+
+    pub fn can_move(self) -> bool {
+        self.speed_multiplier > 0.0
+    }
+
+    /// Whether the owner may swing, slam or cast.
+    pub fn can_attack(self) -> bool {
+        !self.stunned
+    }
+}
+
+/// Scales attack speed by the hastes among an owner's effects. Hastes don't
+/// stack; the strongest one applies.
+pub fn haste_multiplier(effects: impl IntoIterator<Item = (EffectKind, f32)>) -> f32 {
+    let haste = effects
+        .into_iter()
+        .filter(|(kind, _)| *kind == EffectKind::Haste)
+        .fold(0.0_f32, |strongest, (_, magnitude)| {
+            strongest.max(magnitude)
+        });
+    1.0 + haste
+}
+
+/// What an `active_effect` row is, stored as its [`EffectKind::as_u8`] id.
+/// Cooldowns and scripted effects are data-defined, so their rows also carry
+/// a name: the ability's, or the effect script's.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EffectKind {
+    /// The stacking damage buff basic attacks build up.
+    StackingDamage,
+    Periodic(PeriodicEffect),
+    Control(ControlEffect),
+    /// An ability's cooldown.
+    Cooldown,
+    /// Mana its owner had at the row's timestamp, refilling from there (see
+    /// [`mana_at`](crate::resources::mana_at)). No row means a full pool.
+    Mana,
+    /// Attacks come faster by `magnitude`, as a fraction: 0.2 is 20% faster.
+    /// See [`haste_multiplier`].
+    Haste,
+    /// Runs the effect script its row names.
+    Scripted,
+}
+
+impl EffectKind {
+    /// Stable wire id. Append only; 0 is left unused so a zeroed row reads
+    /// as nothing.
+    pub fn as_u8(self) -> u8 {
+        match self {
+            Self::StackingDamage => 1,
+            Self::Periodic(PeriodicEffect::Burn) => 2,
+            Self::Periodic(PeriodicEffect::Poison) => 3,
+            Self::Periodic(PeriodicEffect::Regen) => 4,
+            Self::Cooldown => 5,
+            Self::Scripted => 6,
+            Self::Control(ControlEffect::Stun) => 7,
+            Self::Control(ControlEffect::Slow) => 8,
+            Self::Mana => 9,
+            Self::Haste => 10,
+        }
+    }
+
+    pub fn from_u8(id: u8) -> Option<Self> {
+        Some(match id {
+            1 => Self::StackingDamage,
+            2 => Self::Periodic(PeriodicEffect::Burn),
+            3 => Self::Periodic(PeriodicEffect::Poison),
+            4 => Self::Periodic(PeriodicEffect::Regen),
+            5 => Self::Cooldown,
+            6 => Self::Scripted,
+            7 => Self::Control(ControlEffect::Stun),
+            8 => Self::Control(ControlEffect::Slow),
+            9 => Self::Mana,
+            10 => Self::Haste,
+            _ => return None,
+        })
+    }
+
+    /// Parse an effect as typed by an admin: a built-in's name,
+    /// `cooldown:<ability>`, or anything else as a script's name. Returns the
+    /// kind and the name its row carries.
+    pub fn parse_str(s: &str) -> (Self, &str) {
+        if s == "stacking_damage" {
+            (Self::StackingDamage, "")
+        } else if s == "mana" {
+            (Self::Mana, "")
+        } else if s == "haste" {
+            (Self::Haste, "")
+        } else if let Some(periodic) = PeriodicEffect::parse_str(s) {
+            (Self::Periodic(periodic), "")
+        } else if let Some(control) = ControlEffect::parse_str(s) {
+            (Self::Control(control), "")
+        } else if let Some(ability) = s.strip_prefix("cooldown:") {
+            (Self::Cooldown, ability)
+        } else {
+            (Self::Scripted, s)
+        }
+    }
+}
+
+/// Duration of an effect granted for `granted` seconds on top of one of the
+/// same kind with `current` left: the longer of the two, and never running
+/// out if either doesn't.
+pub fn refreshed_duration(current: f32, granted: f32) -> f32 {
+    if current < 0.0 || granted < 0.0 {
+        -1.0
+    } else {
+        current.max(granted)
+    }
+}
+
+/// The effect's `magnitude`, as seen by its tick rules.
+pub fn magnitude_stat() -> Stat {
+    Stat::Custom("EffectMagnitude".into())
+}
+
+/// Ticks an effect of `interval` seconds owes when it has been running from
+/// `elapsed_before` to `elapsed_after` seconds. The first tick comes one
+/// interval in.
+pub fn ticks_due(elapsed_before: f32, elapsed_after: f32, interval: f32) -> u32 {
+    let interval = interval.max(f32::EPSILON);
+    let before = (elapsed_before.max(0.0) / interval).floor();
+    let after = (elapsed_after.max(0.0) / interval).floor();
+    (after - before).max(0.0) as u32
+}
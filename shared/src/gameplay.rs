@@ -0,0 +1,62 @@
+//! Gameplay values both sides must agree on, parsed from the canonical
+//! `shared/gameplay.ron`. The client folds them into its `Config` and the
+//! server module embeds the same file when published, so they can't drift;
+//! [`checksum`] lets a client tell it is talking to a module built from
+//! another version of the file.
+
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+/// The canonical file, embedded at build time.
+pub const GAMEPLAY_RON: &str = include_str!("../gameplay.ron");
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Gameplay {
+    /// Spawn point of worlds that don't set their own.
+    pub spawn_pos: (f32, f32, f32),
+    pub movement: Movement,
+    pub respawn_delay_secs: f32,
+    /// How player movement reaches the server.
+    #[serde(default)]
+    pub relay: Relay,
+}
+
+/// The two ways clients sync their movement, kept side by side so they can
+/// be compared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Relay {
+    /// Clients move themselves and send where they are (`update_position`);
+    /// the server only clamps.
+    #[default]
+    Position,
+    /// Clients send their inputs (`player_input`) and the server moves them
+    /// in `game_tick`; see [`movement`](crate::movement).
+    Input,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Movement {
+    /// Walk speed in m/s.
+    pub speed: f32,
+    pub sprint_factor: f32,
+    pub crouch_factor: f32,
+}
+
+/// The parsed canonical values. Panics if the embedded file is malformed,
+/// which fails the first run of any build that ships one.
+pub fn gameplay() -> &'static Gameplay {
+    static GAMEPLAY: OnceLock<Gameplay> = OnceLock::new();
+    GAMEPLAY.get_or_init(|| {
+        ron::from_str(GAMEPLAY_RON).unwrap_or_else(|e| panic!("invalid gameplay.ron: {e}"))
+    })
+}
+
+/// FNV-1a hash of the embedded file. The server publishes it; a client
+/// built from a different file sees a different value.
+pub fn checksum() -> u64 {
+    GAMEPLAY_RON
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+        })
+}
@@ -0,0 +1,139 @@
+//! Equipment: what each item id is, which slot it goes in and how it changes
+//! its wearer's stats. The server applies these to the player row; the
+//! client rebuilds the same [`Stats`] so tooltips can name each item.
+
+use crate::leveling;
+use crate::rules::{LayeredStats, Stat, Stats};
+use crate::weapons::Weapon;
+
+/// Equipment slot. A player wears at most one item per slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ItemSlot {
+    Weapon,
+    Armor,
+    Trinket,
+}
+
+impl ItemSlot {
+    /// Convert to the slot name stored in `equipped_item` rows.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Weapon => "weapon",
+            Self::Armor => "armor",
+            Self::Trinket => "trinket",
+        }
+    }
+
+    pub fn parse_str(s: &str) -> Option<Self> {
+        match s {
+            "weapon" => Some(Self::Weapon),
+            "armor" => Some(Self::Armor),
+            "trinket" => Some(Self::Trinket),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Item {
+    IronSword,
+    WarHammer,
+    LeatherVest,
+    ChainMail,
+    LuckyCharm,
+}
+
+/// Items every new character starts with in their inventory.
+pub const STARTER_ITEMS: [Item; 4] = [
+    Item::IronSword,
+    Item::WarHammer,
+    Item::LeatherVest,
+    Item::LuckyCharm,
+];
+
+impl Item {
+    /// Convert to the item id stored in inventory rows.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::IronSword => "iron_sword",
+            Self::WarHammer => "war_hammer",
+            Self::LeatherVest => "leather_vest",
+            Self::ChainMail => "chain_mail",
+            Self::LuckyCharm => "lucky_charm",
+        }
+    }
+
+    /// Parse an item id. Unknown ids (from a newer server) are `None`.
+    pub fn parse_str(s: &str) -> Option<Self> {
+        match s {
+            "iron_sword" => Some(Self::IronSword),
+            "war_hammer" => Some(Self::WarHammer),
+            "leather_vest" => Some(Self::LeatherVest),
+            "chain_mail" => Some(Self::ChainMail),
+            "lucky_charm" => Some(Self::LuckyCharm),
+            _ => None,
+        }
+    }
+
+    /// Display name, also used as the source of the stat changes it makes.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::IronSword => "Iron Sword",
+            Self::WarHammer => "War Hammer",
+            Self::LeatherVest => "Leather Vest",
+            Self::ChainMail => "Chain Mail",
+            Self::LuckyCharm => "Lucky Charm",
+        }
+    }
+
+    pub fn slot(self) -> ItemSlot {
+        match self {
+            Self::IronSword | Self::WarHammer => ItemSlot::Weapon,
+            Self::LeatherVest | Self::ChainMail => ItemSlot::Armor,
+            Self::LuckyCharm => ItemSlot::Trinket,
+        }
+    }
+
+    /// The weapon a weapon-slot item is wielded as.
+    pub fn weapon(self) -> Option<Weapon> {
+        match self {
+            Self::IronSword => Some(Weapon::Sword),
+            Self::WarHammer => Some(Weapon::Hammer),
+            Self::LeatherVest | Self::ChainMail | Self::LuckyCharm => None,
+        }
+    }
+
+    /// Flat bonuses while equipped. Reach and arc come from [`Self::weapon`].
+    pub fn bonuses(self) -> &'static [(Stat, f32)] {
+        match self {
+            Self::IronSword => &[(Stat::AttackDamage, 6.0)],
+            Self::WarHammer => &[(Stat::AttackDamage, 14.0), (Stat::Knockback, 2.0)],
+            Self::LeatherVest => &[(Stat::MaxHealth, 20.0)],
+            Self::ChainMail => &[(Stat::MaxHealth, 45.0), (Stat::Knockback, -0.5)],
+            Self::LuckyCharm => &[(Stat::CritChance, 0.05), (Stat::CritMultiplier, 0.25)],
+        }
+    }
+}
+
+/// Player stats at `level` wearing `equipped`: the level-scaled defaults
+/// from [`leveling::leveled_stats`] plus each item's bonuses and the reach
+/// of the weapon they wield.
+pub fn equipped_stats(level: u32, equipped: &[Item]) -> Stats {
+    equipped_layers(level, equipped).into_totals()
+}
+
+/// [`equipped_stats`] with each item and the weapon kept as its own layer,
+/// so one can be taken off again with [`LayeredStats::remove_source`].
+pub fn equipped_layers(level: u32, equipped: &[Item]) -> LayeredStats {
+    let mut layers = LayeredStats::from_base(leveling::leveled_stats(level));
+    for item in equipped {
+        for (stat, amount) in item.bonuses() {
+            layers.add(item.name(), stat.clone(), *amount);
+        }
+    }
+    let weapon = Weapon::from_equipped(equipped);
+    let reach = weapon.stats();
+    layers.add(weapon.name(), Stat::AttackRange, reach.range_bonus);
+    layers.set_base(Stat::AttackArc, reach.arc, Some(weapon.name()));
+    layers
+}
@@ -0,0 +1,62 @@
+//! Player XP and levels. The server owns a player's XP; both sides derive
+//! the level-scaled stats from it here so they agree on the numbers.
+
+use crate::combat::defaults;
+use crate::rules::{Action, Effect, Expr, Rule, Stat, Stats, execute_rules, stat, val};
+
+pub const MAX_LEVEL: u32 = 30;
+/// XP to go from level 1 to 2. Each level after costs this much more than the last.
+pub const XP_PER_LEVEL_STEP: u32 = 50;
+/// Max health gained per level, as a fraction of the level-1 value.
+pub const HEALTH_PER_LEVEL: f32 = 0.05;
+/// Attack damage gained per level, as a fraction of the level-1 value.
+pub const DAMAGE_PER_LEVEL: f32 = 0.04;
+
+/// Total XP needed to reach `level`.
+pub fn xp_for_level(level: u32) -> u32 {
+    let steps = level.clamp(1, MAX_LEVEL) - 1;
+    XP_PER_LEVEL_STEP * steps * (steps + 1) / 2
+}
+
+/// Level reached with `xp` total XP.
+pub fn level_for_xp(xp: u32) -> u32 {
+    (1..MAX_LEVEL)
+        .find(|&level| xp < xp_for_level(level + 1))
+        .unwrap_or(MAX_LEVEL)
+}
+
+/// Rules scaling level-1 stats up to `level`.
+pub fn level_rules(level: u32) -> Vec<Rule> {
+    let steps = (level.clamp(1, MAX_LEVEL) - 1) as f32;
+    let source = format!("Level {}", level.clamp(1, MAX_LEVEL));
+    [
+        (Stat::MaxHealth, HEALTH_PER_LEVEL),
+        (Stat::AttackDamage, DAMAGE_PER_LEVEL),
+    ]
+    .into_iter()
+    .map(|(s, per_level)| {
+        Rule::new().source(source.clone()).then(Effect::SetStat {
+            stat: s.clone(),
+            value: Expr::Multiply(Box::new(stat(s)), Box::new(val(1.0 + per_level * steps))),
+        })
+    })
+    .collect()
+}
+
+/// Level-scaled player stats, starting from the combat defaults. Covers
+/// every stat the server keeps on the player row.
+pub fn leveled_stats(level: u32) -> Stats {
+    let mut stats = Stats::new()
+        .with(Stat::MaxHealth, defaults::HEALTH)
+        .with(Stat::AttackDamage, defaults::ATTACK_DAMAGE)
+        .with(Stat::CritChance, defaults::CRIT_CHANCE)
+        .with(Stat::CritMultiplier, defaults::CRIT_MULTIPLIER)
+        .with(Stat::AttackRange, defaults::ATTACK_RANGE)
+        .with(Stat::AttackArc, defaults::ATTACK_ARC)
+        .with(Stat::Knockback, defaults::KNOCKBACK)
+        .labeled("Player defaults");
+    if level > 1 {
+        execute_rules(&level_rules(level), &mut stats, &mut Action::new());
+    }
+    stats
+}
@@ -1,4 +1,19 @@
+pub mod abilities;
+pub mod boss;
 pub mod combat;
+pub mod effects;
+pub mod gameplay;
+pub mod items;
+pub mod leveling;
+pub mod loot;
+pub mod movement;
+pub mod net;
 pub mod presets;
+pub mod resources;
 pub mod rng;
 pub mod rules;
+pub mod spatial;
+pub mod spawns;
+pub mod variation;
+pub mod weapons;
+pub mod world_events;
@@ -0,0 +1,54 @@
+//! Enemy loot drops — shared so the client knows pickup range and what an
+//! item id means, while the server stays the one that rolls and grants them.
+
+use crate::combat::rewards::LOOT_DROP_CHANCE;
+
+/// Pick up loot within this distance of the player.
+pub const PICKUP_RANGE: f32 = 1.5;
+/// Drops nobody picks up disappear after this long.
+pub const LIFETIME_SECS: f32 = 60.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LootItem {
+    HealthOrb,
+    LargeHealthOrb,
+}
+
+impl LootItem {
+    /// Convert to the item id stored in `loot_drop` rows.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::HealthOrb => "health_orb",
+            Self::LargeHealthOrb => "large_health_orb",
+        }
+    }
+
+    /// Parse an item id. Unknown ids (from a newer server) are `None`.
+    pub fn parse_str(s: &str) -> Option<Self> {
+        match s {
+            "health_orb" => Some(Self::HealthOrb),
+            "large_health_orb" => Some(Self::LargeHealthOrb),
+            _ => None,
+        }
+    }
+
+    /// Health restored on pickup.
+    pub fn heal(self) -> f32 {
+        match self {
+            Self::HealthOrb => 20.0,
+            Self::LargeHealthOrb => 50.0,
+        }
+    }
+}
+
+/// What a dying enemy drops for a `roll` in [0, 1). Drops happen at
+/// [`LOOT_DROP_CHANCE`], and one in five of them is a large orb.
+pub fn roll_drop(roll: f32) -> Option<LootItem> {
+    if roll >= LOOT_DROP_CHANCE {
+        None
+    } else if roll < LOOT_DROP_CHANCE * 0.2 {
+        Some(LootItem::LargeHealthOrb)
+    } else {
+        Some(LootItem::HealthOrb)
+    }
+}
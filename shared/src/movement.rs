@@ -0,0 +1,77 @@
+//! Player movement the server integrates itself under the input relay (see
+//! [`Relay`](crate::gameplay::Relay)). Clients send what they want to do —
+//! a walking velocity and any jump — and [`step`] moves the player on the
+//! server's tick: walk speed capped at a sprint, a flat arena floor and
+//! plain gravity. Clients keep predicting with their own physics and are
+//! corrected toward where this puts them.
+
+use crate::combat::defaults;
+use crate::gameplay::gameplay;
+
+/// Height of a player's origin standing on the arena floor.
+pub const STANDING_Y: f32 = 1.0;
+/// Downward acceleration in m/s².
+pub const GRAVITY: f32 = 9.81;
+/// Jumps higher than this are clamped, in meters.
+pub const MAX_JUMP_HEIGHT: f32 = 6.0;
+
+/// What a client asked for since its previous input.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct MoveInput {
+    /// Desired horizontal velocity in m/s.
+    pub velocity_x: f32,
+    pub velocity_z: f32,
+    /// Height of a jump to start, 0 for none. Ignored in the air.
+    pub jump_height: f32,
+}
+
+/// Where the server has a player, and how fast they're rising or falling.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct MoveState {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub velocity_y: f32,
+}
+
+impl MoveState {
+    pub fn grounded(&self) -> bool {
+        self.y <= STANDING_Y && self.velocity_y <= 0.0
+    }
+}
+
+/// Fastest a player may move, sprinting.
+pub fn max_speed() -> f32 {
+    let movement = &gameplay().movement;
+    movement.speed * movement.sprint_factor
+}
+
+/// Advance `state` by `dt` seconds of `input`.
+pub fn step(state: MoveState, input: MoveInput, dt: f32) -> MoveState {
+    let horizontal = (input.velocity_x, input.velocity_z);
+    let speed = (horizontal.0 * horizontal.0 + horizontal.1 * horizontal.1).sqrt();
+    let scale = if speed > max_speed() {
+        max_speed() / speed
+    } else {
+        1.0
+    };
+
+    let mut velocity_y = state.velocity_y;
+    if state.grounded() && input.jump_height > 0.0 {
+        velocity_y = (2.0 * GRAVITY * input.jump_height.min(MAX_JUMP_HEIGHT)).sqrt();
+    }
+    velocity_y -= GRAVITY * dt;
+    let mut y = state.y + velocity_y * dt;
+    if y <= STANDING_Y {
+        y = STANDING_Y;
+        velocity_y = 0.0;
+    }
+
+    let bound = defaults::ARENA_HALF_SIZE;
+    MoveState {
+        x: (state.x + horizontal.0 * scale * dt).clamp(-bound, bound),
+        y,
+        z: (state.z + horizontal.1 * scale * dt).clamp(-bound, bound),
+        velocity_y,
+    }
+}
@@ -0,0 +1,61 @@
+//! Auras: rules a player runs on everyone around them every `interval`
+//! seconds. The server runs them from `game_tick` on the players in reach;
+//! the client only draws the reach, as a ring pulsing out from the owner.
+
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+
+use crate::rules::{Effect, Rule, val};
+
+/// Shown as the origin of what the rally aura grants.
+pub const SOURCE: &str = "Rally";
+
+pub const RALLY_RADIUS: f32 = 8.0;
+pub const RALLY_INTERVAL: f32 = 1.0;
+/// Attack speed the rally aura adds, as a fraction.
+pub const RALLY_HASTE: f32 = 0.2;
+/// Seconds each pulse's haste lasts. Longer than the interval, so staying
+/// in reach keeps it up without gaps.
+pub const RALLY_DURATION: f32 = 1.5;
+
+/// Rules run on every player within `radius` of the aura's owner, once per
+/// `interval` seconds.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Aura {
+    pub radius: f32,
+    pub interval: f32,
+    /// Run as the player in reach: they see their own stats, and the
+    /// statuses the rules grant land on them.
+    pub rules: Vec<Rule>,
+    /// Whether the owner is in reach of their own aura.
+    #[serde(default)]
+    pub affects_owner: bool,
+}
+
+impl Aura {
+    /// Whether a player at `target` is in reach of an owner at `owner`, both
+    /// on the ground plane as `(x, z)`.
+    pub fn reaches(&self, owner: (f32, f32), target: (f32, f32)) -> bool {
+        Vec2::from(owner).distance_squared(Vec2::from(target)) <= self.radius * self.radius
+    }
+
+    /// Times the aura pulses between two clock readings in microseconds.
+    pub fn fires_between(&self, before_micros: i64, after_micros: i64) -> u32 {
+        super::fires_between(self.interval, before_micros, after_micros)
+    }
+}
+
+/// Allies near the owner, and the owner too, attack faster while they stay
+/// in reach.
+pub fn rally_aura() -> Aura {
+    Aura {
+        radius: RALLY_RADIUS,
+        interval: RALLY_INTERVAL,
+        rules: vec![Rule::new().source(SOURCE).then(Effect::ApplyStatus {
+            effect_type: "haste".into(),
+            magnitude: val(RALLY_HASTE),
+            duration: val(RALLY_DURATION),
+        })],
+        affects_owner: true,
+    }
+}
@@ -1,13 +1,29 @@
 //! Critical hit rule construction.
 
 use crate::presets::feedback;
-use crate::rules::{ActionVar, Condition, Effect, Expr, Rule, RuleEvent, Stat, action, stat};
+use crate::rules::{ActionVar, Condition, Effect, Expr, Rule, RuleEvent, Stat, action, stat, val};
 
 pub const CRIT_FEEDBACK_MULT: f32 = 2.5;
 
+/// Shown as the origin of the stat changes these rules make.
+pub const SOURCE: &str = "Crit";
+
+/// Crit chance the crit build adds on top of the character's own.
+pub const FOCUS_CRIT_BONUS: f32 = 0.15;
+
+/// Raise crit chance by `bonus` for the swing. Goes before [`crit_rules`] in
+/// the pre-hit list so the roll sees it.
+pub fn focus_rule(bonus: f32) -> Rule {
+    Rule::new().source(SOURCE).then(Effect::SetStat {
+        stat: Stat::CritChance,
+        value: Expr::Add(Box::new(stat(Stat::CritChance)), Box::new(val(bonus))),
+    })
+}
+
 /// Construct the crit rules (OnPreHitRules content).
 pub fn crit_rules() -> Vec<Rule> {
     let mut rule = Rule::new()
+        .source(SOURCE)
         .when(Condition::Chance(stat(Stat::CritChance)))
         .then(Effect::Trigger(RuleEvent::Crit))
         .then(Effect::SetAction {
@@ -1,8 +1,10 @@
 //! Feedback presets — bundles of juice effects.
 //!
 //! Level 2 abstractions that set multiple ActionVars for common feedback patterns.
+//! The ActionVars tune the hit's own juice; the cues at the bottom play
+//! extra juice from any rule.
 
-use crate::rules::{ActionVar, Effect, Expr, action, val};
+use crate::rules::{ActionVar, Effect, Expr, Feedback, action, val};
 
 fn set(var: ActionVar, value: f32) -> Effect {
     Effect::SetAction {
@@ -103,3 +105,32 @@ pub fn amplify(mult: f32) -> Vec<Effect> {
 pub fn dampen(factor: f32) -> Vec<Effect> {
     amplify(1.0 / factor)
 }
+
+// ── Cues ────────────────────────────────────────────────────────────
+
+pub fn hit_stop(duration: f32) -> Effect {
+    Effect::Feedback(Feedback::HitStop {
+        duration: val(duration),
+    })
+}
+
+pub fn shake(intensity: f32) -> Effect {
+    Effect::Feedback(Feedback::Shake {
+        intensity: val(intensity),
+    })
+}
+
+/// `duration` in milliseconds.
+pub fn rumble(intensity: f32, duration: f32) -> Effect {
+    Effect::Feedback(Feedback::Rumble {
+        intensity: val(intensity),
+        duration: val(duration),
+    })
+}
+
+pub fn sound(name: &str, volume: f32) -> Effect {
+    Effect::Feedback(Feedback::Sound {
+        name: name.to_string(),
+        volume: val(volume),
+    })
+}
@@ -0,0 +1,29 @@
+//! Lifesteal rules: basic attack hits heal the attacker.
+
+use crate::rules::{Effect, Expr, Rule, Stat, stat, val};
+
+/// Shown as the origin of the stat changes these rules make.
+pub const SOURCE: &str = "Lifesteal";
+
+/// Share of attack damage each hit heals.
+pub const DEFAULT_FRACTION: f32 = 0.15;
+
+/// Healing a swing has earned so far. Whoever resolves the swing adds it to
+/// the attacker's health and drops it; it never carries over between swings.
+pub fn heal_stat() -> Stat {
+    Stat::Custom("Heal".into())
+}
+
+/// Construct the lifesteal rules (OnHitRules content).
+pub fn lifesteal_rules(fraction: f32) -> Vec<Rule> {
+    vec![Rule::new().source(SOURCE).then(Effect::SetStat {
+        stat: heal_stat(),
+        value: Expr::Add(
+            Box::new(stat(heal_stat())),
+            Box::new(Expr::Multiply(
+                Box::new(stat(Stat::AttackDamage)),
+                Box::new(val(fraction)),
+            )),
+        ),
+    })]
+}
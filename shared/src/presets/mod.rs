@@ -1,12 +1,18 @@
+pub mod aura;
 pub mod crit;
 pub mod feedback;
+pub mod lifesteal;
 pub mod stacking;
 
+use serde::{Deserialize, Serialize};
+
+use crate::resources;
 use crate::rules::Rule;
 
 /// Complete set of rules for an entity, grouped by trigger point.
 /// Both client and server consume this — the client wraps each field
 /// in a Bevy component, the server runs them directly.
+#[derive(Default)]
 pub struct EntityRules {
     pub pre_hit: Vec<Rule>,
     pub on_hit: Vec<Rule>,
@@ -14,17 +20,139 @@ pub struct EntityRules {
     pub on_tick: Vec<Rule>,
     pub on_kill: Vec<Rule>,
     pub on_take_damage: Vec<Rule>,
+    /// When the entity starts a dodge roll.
+    pub on_dodge: Vec<Rule>,
+    /// When the entity's shield soaks a whole hit.
+    pub on_block: Vec<Rule>,
+    /// When the entity casts an ability, to work out its cost from
+    /// `ManaCost`. See [`crate::resources::cast_cost`].
+    pub on_cast: Vec<Rule>,
+    pub on_health_threshold: Vec<HealthThreshold>,
+    pub on_timer: Vec<TimerRules>,
+    /// Run on the players around the entity; see [`aura::Aura`].
+    pub auras: Vec<aura::Aura>,
+}
+
+/// Rules that fire when health drops below `fraction` of max health, e.g.
+/// 0.3 for "below 30% HP". They fire again after health climbs back over it
+/// and drops below once more.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct HealthThreshold {
+    pub fraction: f32,
+    pub rules: Vec<Rule>,
+}
+
+impl HealthThreshold {
+    /// Whether health going from `before` to `after` crossed below the threshold.
+    pub fn crossed(&self, before: f32, after: f32, max_health: f32) -> bool {
+        let line = self.fraction * max_health;
+        before >= line && after < line
+    }
+}
+
+/// Rules that fire every `interval` seconds.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct TimerRules {
+    pub interval: f32,
+    pub rules: Vec<Rule>,
+}
+
+impl TimerRules {
+    /// Times the timer fires between two clock readings in microseconds:
+    /// once per whole interval boundary passed.
+    pub fn fires_between(&self, before_micros: i64, after_micros: i64) -> u32 {
+        fires_between(self.interval, before_micros, after_micros)
+    }
+}
+
+/// Interval boundaries of `interval` seconds passed between two clock
+/// readings in microseconds.
+fn fires_between(interval: f32, before_micros: i64, after_micros: i64) -> u32 {
+    let interval = ((interval * 1_000_000.0) as i64).max(1);
+    (after_micros.div_euclid(interval) - before_micros.div_euclid(interval)).max(0) as u32
+}
+
+/// A build a player picks before joining. Decides which rules their attacks
+/// run; the server stores the choice in `player_loadout`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Loadout {
+    /// Higher crit chance, no attack speed stacking.
+    Crit,
+    /// Crits plus attack speed that stacks on hit.
+    #[default]
+    Stacking,
+    /// Crits plus healing on hit.
+    Lifesteal,
+    /// Crits plus an aura that hastes everyone nearby.
+    Rally,
+}
+
+impl Loadout {
+    pub const ALL: [Self; 4] = [Self::Crit, Self::Stacking, Self::Lifesteal, Self::Rally];
+
+    /// Convert to the id stored in `player_loadout.loadout`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Crit => "crit",
+            Self::Stacking => "stacking",
+            Self::Lifesteal => "lifesteal",
+            Self::Rally => "rally",
+        }
+    }
+
+    /// Parse a loadout id.
+    pub fn parse_str(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|l| l.as_str() == s)
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Crit => "Crit",
+            Self::Stacking => "Stacking",
+            Self::Lifesteal => "Lifesteal",
+            Self::Rally => "Rally",
+        }
+    }
+
+    pub fn rules(self) -> EntityRules {
+        let mut rules = EntityRules {
+            pre_hit: crit::crit_rules(),
+            on_hit: vec![],
+            on_crit_hit: vec![],
+            on_tick: resources::stamina_rules(),
+            on_kill: vec![],
+            on_take_damage: vec![],
+            on_dodge: vec![],
+            on_block: vec![],
+            on_cast: vec![],
+            on_health_threshold: vec![],
+            on_timer: vec![],
+            auras: vec![],
+        };
+        match self {
+            Self::Crit => {
+                rules
+                    .pre_hit
+                    .insert(0, crit::focus_rule(crit::FOCUS_CRIT_BONUS));
+            }
+            Self::Stacking => {
+                let stacking = stacking::stacking_rules(stacking::StackingConfig::default());
+                rules.on_hit = stacking.on_hit;
+                rules.on_crit_hit = stacking.on_crit_hit;
+                rules.on_tick.extend(stacking.on_tick);
+            }
+            Self::Lifesteal => {
+                rules.on_hit = lifesteal::lifesteal_rules(lifesteal::DEFAULT_FRACTION);
+            }
+            Self::Rally => {
+                rules.auras.push(aura::rally_aura());
+            }
+        }
+        rules
+    }
 }
 
 /// Default player rules. Single source of truth for client and server.
 pub fn default_player_rules() -> EntityRules {
-    let stacking = stacking::stacking_rules(stacking::StackingConfig::default());
-    EntityRules {
-        pre_hit: crit::crit_rules(),
-        on_hit: stacking.on_hit,
-        on_crit_hit: stacking.on_crit_hit,
-        on_tick: stacking.on_tick,
-        on_kill: vec![],
-        on_take_damage: vec![],
-    }
+    Loadout::default().rules()
 }
@@ -2,6 +2,9 @@
 
 use crate::rules::{ActionVar, Condition, Effect, Expr, Rule, Stat, action, stat, val};
 
+/// Shown as the origin of the stat changes these rules make.
+pub const SOURCE: &str = "Stacking";
+
 fn stacks_stat() -> Stat {
     Stat::Custom("Stacks".into())
 }
@@ -52,6 +55,7 @@ pub fn stacking_rules(config: StackingConfig) -> StackingRules {
     StackingRules {
         on_hit: vec![
             Rule::new()
+                .source(SOURCE)
                 .then(Effect::SetStat {
                     stat: stacks_stat(),
                     value: Expr::Add(
@@ -64,18 +68,19 @@ pub fn stacking_rules(config: StackingConfig) -> StackingRules {
                     value: val(config.decay_interval),
                 }),
             Rule::new()
+                .source(SOURCE)
                 .when(Condition::GreaterThan(stat(stacks_stat()), val(max)))
                 .then(Effect::SetStat {
                     stat: stacks_stat(),
                     value: val(max),
                 }),
-            Rule::new().then(Effect::SetStat {
+            Rule::new().source(SOURCE).then(Effect::SetStat {
                 stat: Stat::AttackSpeed,
                 value: attack_speed_expr.clone(),
             }),
         ],
         on_crit_hit: vec![
-            Rule::new().then(Effect::SetStat {
+            Rule::new().source(SOURCE).then(Effect::SetStat {
                 stat: stacks_stat(),
                 value: Expr::Add(
                     Box::new(stat(stacks_stat())),
@@ -83,18 +88,20 @@ pub fn stacking_rules(config: StackingConfig) -> StackingRules {
                 ),
             }),
             Rule::new()
+                .source(SOURCE)
                 .when(Condition::GreaterThan(stat(stacks_stat()), val(max)))
                 .then(Effect::SetStat {
                     stat: stacks_stat(),
                     value: val(max),
                 }),
-            Rule::new().then(Effect::SetStat {
+            Rule::new().source(SOURCE).then(Effect::SetStat {
                 stat: Stat::AttackSpeed,
                 value: attack_speed_expr,
             }),
         ],
         on_tick: vec![
             Rule::new()
+                .source(SOURCE)
                 .when(Condition::GreaterThan(stat(decay_stat()), val(0.0)))
                 .then(Effect::SetStat {
                     stat: decay_stat(),
@@ -104,6 +111,7 @@ pub fn stacking_rules(config: StackingConfig) -> StackingRules {
                     ),
                 }),
             Rule::new()
+                .source(SOURCE)
                 .when(Condition::LessOrEqual(stat(decay_stat()), val(0.0)))
                 .when(Condition::GreaterThan(stat(stacks_stat()), val(0.0)))
                 .then(Effect::SetStat {
@@ -0,0 +1,115 @@
+//! Mana and stamina: pools abilities and sprinting spend from, refilled by
+//! tick rules that read [`ActionVar::DeltaTime`]. Rules can change what a
+//! cast costs through `on_cast`, which sees the base cost as
+//! [`ActionVar::ManaCost`].
+//!
+//! The server owns mana. What's left after a cast goes in an `active_effect`
+//! row ([`EffectKind::Mana`](crate::effects::EffectKind::Mana)) and refills
+//! from the row's timestamp with [`mana_at`] rather than every tick. Stamina
+//! only gates sprinting, which the client drives, so it lives in the
+//! client's stats alone.
+
+use crate::rules::{
+    Action, ActionVar, Condition, Effect, Expr, Rule, Stat, Stats, action, execute_rules, stat, val,
+};
+
+pub const MAX_MANA: f32 = 100.0;
+/// Mana refilled per second.
+pub const MANA_REGEN: f32 = 6.0;
+pub const MAX_STAMINA: f32 = 100.0;
+/// Stamina refilled per second while not sprinting.
+pub const STAMINA_REGEN: f32 = 25.0;
+/// Stamina spent per second of sprinting.
+pub const SPRINT_DRAIN: f32 = 20.0;
+/// Stamina needed to start sprinting, so an empty bar doesn't flicker the
+/// sprint on and off.
+pub const SPRINT_MIN_STAMINA: f32 = 15.0;
+
+/// Shown as the origin of the pool changes these rules make.
+pub const SOURCE: &str = "Regen";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pool {
+    Mana,
+    Stamina,
+}
+
+impl Pool {
+    pub fn stat(self) -> Stat {
+        match self {
+            Self::Mana => Stat::Mana,
+            Self::Stamina => Stat::Stamina,
+        }
+    }
+
+    pub fn max_stat(self) -> Stat {
+        match self {
+            Self::Mana => Stat::MaxMana,
+            Self::Stamina => Stat::MaxStamina,
+        }
+    }
+}
+
+/// Tick rule refilling `pool` by `per_sec` (negative drains it), kept
+/// between zero and the pool's max.
+pub fn regen_rule(pool: Pool, per_sec: f32) -> Rule {
+    let refilled = Expr::Add(
+        Box::new(stat(pool.stat())),
+        Box::new(Expr::Multiply(
+            Box::new(val(per_sec)),
+            Box::new(action(ActionVar::DeltaTime)),
+        )),
+    );
+    Rule::new().source(SOURCE).then(Effect::SetStat {
+        stat: pool.stat(),
+        value: Expr::Clamp(
+            Box::new(refilled),
+            Box::new(val(0.0)),
+            Box::new(stat(pool.max_stat())),
+        ),
+    })
+}
+
+/// Tick rules for mana. The server runs them once over the whole gap since
+/// a cast, so they should grow linearly with `DeltaTime`.
+pub fn mana_rules() -> Vec<Rule> {
+    vec![regen_rule(Pool::Mana, MANA_REGEN)]
+}
+
+/// Tick rules for stamina: drained while [`Stat::IsSprinting`], refilled
+/// otherwise.
+pub fn stamina_rules() -> Vec<Rule> {
+    let sprinting = || Condition::GreaterThan(stat(Stat::IsSprinting), val(0.5));
+    let drain = regen_rule(Pool::Stamina, -SPRINT_DRAIN).when(sprinting());
+    let regen =
+        regen_rule(Pool::Stamina, STAMINA_REGEN).when(Condition::Not(Box::new(sprinting())));
+    vec![drain, regen]
+}
+
+/// `stats` with both pools full at their default sizes.
+pub fn with_pools(stats: Stats) -> Stats {
+    stats
+        .with(Stat::MaxMana, MAX_MANA)
+        .with(Stat::Mana, MAX_MANA)
+        .with(Stat::MaxStamina, MAX_STAMINA)
+        .with(Stat::Stamina, MAX_STAMINA)
+}
+
+/// Mana `elapsed_secs` after it stood at `mana`, out of `max_mana`.
+pub fn mana_at(mana: f32, max_mana: f32, elapsed_secs: f32) -> f32 {
+    let mut stats = Stats::new()
+        .with(Stat::MaxMana, max_mana)
+        .with(Stat::Mana, mana);
+    let mut action = Action::new().with(ActionVar::DeltaTime, elapsed_secs.max(0.0));
+    execute_rules(&mana_rules(), &mut stats, &mut action);
+    stats.get(&Stat::Mana)
+}
+
+/// Mana a cast costs: `base` as the caster's `on_cast` rules leave it, never
+/// below zero.
+pub fn cast_cost(base: f32, caster_stats: &Stats, on_cast: &[Rule]) -> f32 {
+    let mut stats = caster_stats.clone();
+    let mut action = Action::new().with(ActionVar::ManaCost, base);
+    execute_rules(on_cast, &mut stats, &mut action);
+    action.get(&ActionVar::ManaCost).max(0.0)
+}
@@ -14,6 +14,54 @@ pub fn deterministic_roll(attacker_seed: u64, target_seed: u64, attack_seq: u32)
     (hash & 0x00FF_FFFF) as f32 / 0x0100_0000 as f32
 }
 
+/// Well-mixed value in [0.0, 1.0) from a seed and a salt. Unlike
+/// [`deterministic_roll`], neighbouring seeds (sequential ids) land far apart.
+pub fn hashed_unit(seed: u64, salt: u64) -> f32 {
+    unit(mix(seed, salt))
+}
+
+/// Seed that differs a lot for each `salt`, e.g. a target's rolls within an
+/// attack seeded by [`attack_seed`].
+pub fn mix(seed: u64, salt: u64) -> u64 {
+    // splitmix64 finalizer
+    let mut hash = seed ^ salt.wrapping_mul(GOLDEN_GAMMA);
+    hash = (hash ^ (hash >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    hash = (hash ^ (hash >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    hash ^ (hash >> 31)
+}
+
+const GOLDEN_GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Top 24 bits as a value in [0.0, 1.0).
+fn unit(hash: u64) -> f32 {
+    (hash >> 40) as f32 / (1u64 << 24) as f32
+}
+
+/// Seed for the rolls of an attacker's `attack_seq`th swing. The client
+/// numbers its swings and sends the number with `attack_hit`, so its
+/// prediction and the server roll the same crits.
+pub fn attack_seed(attacker_seed: u64, attack_seq: u32) -> u64 {
+    mix(attacker_seed, attack_seq as u64)
+}
+
+/// Rolls for rules' `Chance` conditions, one per condition checked. The
+/// same seed gives the same rolls everywhere. The default (seed 0) is for
+/// rules with nothing to seed them, which then roll the same every time.
+#[derive(Clone, Debug, Default)]
+pub struct RuleRng(u64);
+
+impl RuleRng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    /// The next roll, in [0.0, 1.0).
+    pub fn roll(&mut self) -> f32 {
+        self.0 = self.0.wrapping_add(GOLDEN_GAMMA);
+        unit(mix(self.0, 0))
+    }
+}
+
 /// Seed from identity bytes (first 8 bytes of SpacetimeDB Identity).
 pub fn seed_from_identity(identity_bytes: &[u8]) -> u64 {
     let mut seed: u64 = 0;
@@ -12,6 +12,22 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::effects::EffectKind;
+pub use crate::rng::RuleRng;
+use crate::spawns::SpawnKind;
+
+mod bounds;
+mod layers;
+mod program;
+pub mod schema;
+mod trace;
+
+pub use bounds::{StatBound, StatBounds};
+pub use layers::{LayeredStats, StatLayer};
+pub use program::{CompiledRules, Program};
+pub use trace::{Delta, PassTrace, RuleTrace, TriggerTrace, set_tracing, tracing};
 
 // ============================================================================
 // LEVEL 2: STORAGE - Stats (persistent) and Action (per-action)
@@ -19,9 +35,25 @@ use std::collections::HashMap;
 
 /// Persistent entity stats - "who you are"
 ///
-/// These values live with the entity and persist across actions.
+/// These values live with the entity and persist across actions. Alongside
+/// each value, stats remember where it came from (see [`StatBreakdown`]) so
+/// tooltips can explain a number. Provenance isn't serialized — RON presets
+/// and saves stay a plain `{ Stat: value }` map.
+///
+/// Values stay within their [`StatBounds`]: the defaults unless
+/// [`Stats::bounded`] gave others.
+///
+/// Stats hold only the current values; for bonuses that come and go
+/// (equipment, buffs) build them with [`LayeredStats`], which can take one
+/// off again.
 #[derive(Default, Clone, Debug, Serialize, Deserialize)]
-pub struct Stats(pub HashMap<Stat, f32>);
+#[serde(from = "HashMap<Stat, f32>", into = "HashMap<Stat, f32>")]
+pub struct Stats {
+    values: HashMap<Stat, f32>,
+    sources: HashMap<Stat, StatBreakdown>,
+    /// `None` keeps every stat to [`StatBound::default_for`].
+    bounds: Option<Arc<StatBounds>>,
+}
 
 impl Stats {
     pub fn new() -> Self {
@@ -29,16 +61,202 @@ impl Stats {
     }
 
     pub fn with(mut self, stat: Stat, value: f32) -> Self {
-        self.0.insert(stat, value);
+        self.set(stat, value);
+        self
+    }
+
+    /// Label every base value that has no source yet, e.g. with the preset
+    /// the stats were loaded from.
+    pub fn labeled(mut self, source: &str) -> Self {
+        for breakdown in self.sources.values_mut() {
+            breakdown
+                .base_source
+                .get_or_insert_with(|| source.to_string());
+        }
         self
     }
 
     pub fn get(&self, stat: &Stat) -> f32 {
-        self.0.get(stat).copied().unwrap_or(0.0)
+        self.values.get(stat).copied().unwrap_or(0.0)
     }
 
+    /// Keep stats to `bounds` instead of the defaults, clamping the values
+    /// already set.
+    pub fn bounded(mut self, bounds: StatBounds) -> Self {
+        self.bounds = Some(Arc::new(bounds));
+        self.enforce_bounds();
+        self
+    }
+
+    /// The range `stat` is kept to.
+    pub fn bound(&self, stat: &Stat) -> StatBound {
+        match &self.bounds {
+            Some(bounds) => bounds.get(stat),
+            None => StatBound::default_for(stat),
+        }
+    }
+
+    /// Overwrite a stat outright, clamped to its bound. The new value becomes
+    /// its base and any modifiers recorded against it are dropped.
     pub fn set(&mut self, stat: Stat, value: f32) {
-        self.0.insert(stat, value);
+        let value = self.bound(&stat).clamp(value);
+        self.sources.insert(
+            stat.clone(),
+            StatBreakdown {
+                base: value,
+                ..Default::default()
+            },
+        );
+        self.values.insert(stat, value);
+    }
+
+    /// Apply a rule's `SetStat`, recording the change as a modifier from
+    /// `source`: a multiplier when the rule scaled the stat by itself (see
+    /// [`scales_stat`]), a flat delta otherwise.
+    fn modify(&mut self, stat: Stat, value: f32, scales_self: bool, source: Option<&str>) {
+        let old = self.get(&stat);
+        let kind = if scales_self && old.abs() > f32::EPSILON {
+            ModifierKind::Multiplier(value / old)
+        } else {
+            ModifierKind::Flat(value - old)
+        };
+        self.sources
+            .entry(stat.clone())
+            .or_default()
+            .apply(source.unwrap_or("Rules"), kind);
+        self.values.insert(stat, value);
+    }
+
+    /// Apply a rule's `AddStat` or `MulStat`, recorded as coming from `source`.
+    fn apply_modifier(&mut self, stat: Stat, kind: ModifierKind, source: Option<&str>) {
+        let value = kind.apply_to(self.get(&stat));
+        self.sources
+            .entry(stat.clone())
+            .or_default()
+            .apply(source.unwrap_or("Rules"), kind);
+        self.values.insert(stat, value);
+    }
+
+    /// Pull every stat rules took out of bounds back in, recording the
+    /// correction as a flat modifier from "Bounds".
+    fn enforce_bounds(&mut self) {
+        let clamped: Vec<(Stat, f32, f32)> = self
+            .values
+            .iter()
+            .filter_map(|(stat, &value)| {
+                let bounded = self.bound(stat).clamp(value);
+                (bounded != value).then(|| (stat.clone(), value, bounded))
+            })
+            .collect();
+        for (stat, value, bounded) in clamped {
+            self.sources
+                .entry(stat.clone())
+                .or_default()
+                .apply("Bounds", ModifierKind::Flat(bounded - value));
+            self.values.insert(stat, bounded);
+        }
+    }
+
+    /// Where `stat`'s current value came from.
+    pub fn breakdown(&self, stat: &Stat) -> StatBreakdown {
+        self.sources.get(stat).cloned().unwrap_or_default()
+    }
+
+    /// Take every stat `other` has, value and provenance, over this one's.
+    pub fn merge(&mut self, other: Stats) {
+        self.values.extend(other.values);
+        self.sources.extend(other.sources);
+    }
+}
+
+impl From<HashMap<Stat, f32>> for Stats {
+    fn from(values: HashMap<Stat, f32>) -> Self {
+        values
+            .into_iter()
+            .fold(Stats::new(), |stats, (stat, value)| stats.with(stat, value))
+    }
+}
+
+impl From<Stats> for HashMap<Stat, f32> {
+    fn from(stats: Stats) -> Self {
+        stats.values
+    }
+}
+
+/// Whether `expr` is `stat` times something, i.e. a `SetStat` with it
+/// applies a multiplier rather than a flat change.
+fn scales_stat(expr: &Expr, stat: &Stat) -> bool {
+    match expr {
+        Expr::Multiply(a, b) => {
+            matches!(a.as_ref(), Expr::Stat(s) if s == stat)
+                || matches!(b.as_ref(), Expr::Stat(s) if s == stat)
+        }
+        _ => false,
+    }
+}
+
+/// A stat's base value plus the modifiers rules have applied on top,
+/// grouped per source.
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct StatBreakdown {
+    pub base: f32,
+    /// Preset or loadout the base value came from, if known.
+    pub base_source: Option<String>,
+    pub modifiers: Vec<StatModifier>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StatModifier {
+    /// Rule or preset that applied it (`Rule::source`).
+    pub source: String,
+    pub kind: ModifierKind,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ModifierKind {
+    Flat(f32),
+    Multiplier(f32),
+}
+
+impl ModifierKind {
+    fn apply_to(self, value: f32) -> f32 {
+        match self {
+            Self::Flat(v) => value + v,
+            Self::Multiplier(v) => value * v,
+        }
+    }
+}
+
+impl StatBreakdown {
+    /// Fold a change into the existing modifier of the same source and kind.
+    /// Modifiers that cancel out (a flat 0 or a x1 multiplier) are dropped.
+    fn apply(&mut self, source: &str, kind: ModifierKind) {
+        let existing = self.modifiers.iter().position(|m| {
+            m.source == source && std::mem::discriminant(&m.kind) == std::mem::discriminant(&kind)
+        });
+        let combined = match (existing.map(|i| self.modifiers[i].kind), kind) {
+            (Some(ModifierKind::Flat(a)), ModifierKind::Flat(b)) => ModifierKind::Flat(a + b),
+            (Some(ModifierKind::Multiplier(a)), ModifierKind::Multiplier(b)) => {
+                ModifierKind::Multiplier(a * b)
+            }
+            _ => kind,
+        };
+        let neutral = match combined {
+            ModifierKind::Flat(v) => v.abs() < 1e-4,
+            ModifierKind::Multiplier(v) => (v - 1.0).abs() < 1e-4,
+        };
+
+        match (existing, neutral) {
+            (Some(i), true) => {
+                self.modifiers.remove(i);
+            }
+            (Some(i), false) => self.modifiers[i].kind = combined,
+            (None, true) => {}
+            (None, false) => self.modifiers.push(StatModifier {
+                source: source.to_string(),
+                kind: combined,
+            }),
+        }
     }
 }
 
@@ -76,6 +294,14 @@ pub enum Stat {
     // === Core (engine reads/writes) ===
     Health,
     MaxHealth,
+    Shield,
+    MaxShield,
+
+    // === Resources (spent by abilities and sprinting) ===
+    Mana,
+    MaxMana,
+    Stamina,
+    MaxStamina,
 
     // === Offensive ===
     AttackDamage,
@@ -100,6 +326,9 @@ pub enum Stat {
     InWindup,
     InRecovery,
 
+    // === Movement State (synced from the controller) ===
+    IsSprinting,
+
     // === Attack Parameters ===
     Knockback,
     AttackRange,
@@ -109,6 +338,41 @@ pub enum Stat {
     Custom(String),
 }
 
+impl Stat {
+    /// Parse a stat from its variant name, as scripts write them
+    /// (`"Health"`, `"CritChance"`). Any other name is a [`Stat::Custom`].
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "Health" => Self::Health,
+            "MaxHealth" => Self::MaxHealth,
+            "Shield" => Self::Shield,
+            "MaxShield" => Self::MaxShield,
+            "Mana" => Self::Mana,
+            "MaxMana" => Self::MaxMana,
+            "Stamina" => Self::Stamina,
+            "MaxStamina" => Self::MaxStamina,
+            "AttackDamage" => Self::AttackDamage,
+            "AbilityPower" => Self::AbilityPower,
+            "Armor" => Self::Armor,
+            "MagicResist" => Self::MagicResist,
+            "AttackSpeed" => Self::AttackSpeed,
+            "MovementSpeed" => Self::MovementSpeed,
+            "CritChance" => Self::CritChance,
+            "CritMultiplier" => Self::CritMultiplier,
+            "IsAttacking" => Self::IsAttacking,
+            "AttackProgress" => Self::AttackProgress,
+            "ComboCount" => Self::ComboCount,
+            "InWindup" => Self::InWindup,
+            "InRecovery" => Self::InRecovery,
+            "IsSprinting" => Self::IsSprinting,
+            "Knockback" => Self::Knockback,
+            "AttackRange" => Self::AttackRange,
+            "AttackArc" => Self::AttackArc,
+            other => Self::Custom(other.to_string()),
+        }
+    }
+}
+
 // ============================================================================
 // ACTIONVAR ENUM - Per-action context variables
 // ============================================================================
@@ -128,6 +392,16 @@ pub enum ActionVar {
     Range,
     DeltaTime,
 
+    // === Target (set per target while resolving an attack) ===
+    TargetHealth,
+    TargetMaxHealth,
+    /// From the attack's origin, on the ground plane.
+    TargetDistance,
+    TargetArmor,
+
+    // === Cost (set while working out a cast) ===
+    ManaCost,
+
     // === Feedback (per-action juice) ===
     HitStopDuration,
     ShakeIntensity,
@@ -139,6 +413,33 @@ pub enum ActionVar {
     Custom(String),
 }
 
+impl ActionVar {
+    /// Parse an action variable from its variant name, as scripts write them
+    /// (`"Damage"`, `"Knockback"`). Any other name is an [`ActionVar::Custom`].
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "Damage" => Self::Damage,
+            "DamageType" => Self::DamageType,
+            "Knockback" => Self::Knockback,
+            "Launch" => Self::Launch,
+            "Push" => Self::Push,
+            "Range" => Self::Range,
+            "DeltaTime" => Self::DeltaTime,
+            "TargetHealth" => Self::TargetHealth,
+            "TargetMaxHealth" => Self::TargetMaxHealth,
+            "TargetDistance" => Self::TargetDistance,
+            "TargetArmor" => Self::TargetArmor,
+            "ManaCost" => Self::ManaCost,
+            "HitStopDuration" => Self::HitStopDuration,
+            "ShakeIntensity" => Self::ShakeIntensity,
+            "RumbleIntensity" => Self::RumbleIntensity,
+            "RumbleDuration" => Self::RumbleDuration,
+            "FlashDuration" => Self::FlashDuration,
+            other => Self::Custom(other.to_string()),
+        }
+    }
+}
+
 // ============================================================================
 // LEVEL 1: EXPRESSIONS
 // ============================================================================
@@ -160,35 +461,94 @@ pub enum Expr {
     Abs(Box<Expr>),
     Floor(Box<Expr>),
     Ceil(Box<Expr>),
+
+    /// `value` held within `lo..=hi`. A `lo` above `hi` yields `hi`.
+    Clamp(Box<Expr>, Box<Expr>, Box<Expr>),
+    Pow(Box<Expr>, Box<Expr>),
+    /// Square root, with negative inputs treated as zero.
+    Sqrt(Box<Expr>),
+    /// `a` at `t = 0` to `b` at `t = 1`. `t` isn't clamped.
+    Lerp(Box<Expr>, Box<Expr>, Box<Expr>),
+    /// Euclidean remainder, never negative. Modulo zero is zero, like `Divide`.
+    Mod(Box<Expr>, Box<Expr>),
+
+    /// The second expression if the condition holds, else the third. A
+    /// `Chance` in the condition rolls the pass's [`RuleRng`], like one in
+    /// the rule's own conditions.
+    If(Box<Condition>, Box<Expr>, Box<Expr>),
 }
 
 impl Expr {
-    pub fn eval(&self, stats: &Stats, action: &Action) -> f32 {
+    /// Evaluate, rolling `rng` for each `Chance` an [`Expr::If`] checks.
+    pub fn eval_with_rng(&self, stats: &Stats, action: &Action, rng: &mut RuleRng) -> f32 {
+        let eval = |e: &Expr, rng: &mut RuleRng| e.eval_with_rng(stats, action, rng);
         match self {
             Expr::Value(v) => *v,
             Expr::Stat(stat) => stats.get(stat),
             Expr::Action(var) => action.get(var),
 
-            Expr::Add(a, b) => a.eval(stats, action) + b.eval(stats, action),
-            Expr::Subtract(a, b) => a.eval(stats, action) - b.eval(stats, action),
-            Expr::Multiply(a, b) => a.eval(stats, action) * b.eval(stats, action),
+            Expr::Add(a, b) => eval(a, rng) + eval(b, rng),
+            Expr::Subtract(a, b) => eval(a, rng) - eval(b, rng),
+            Expr::Multiply(a, b) => eval(a, rng) * eval(b, rng),
             Expr::Divide(a, b) => {
-                let divisor = b.eval(stats, action);
+                // Both sides first, in order, so a zero divisor rolls the
+                // same as any other, as in the compiled evaluator
+                let (dividend, divisor) = (eval(a, rng), eval(b, rng));
                 if divisor.abs() < f32::EPSILON {
                     0.0
                 } else {
-                    a.eval(stats, action) / divisor
+                    dividend / divisor
+                }
+            }
+            Expr::Negate(e) => -eval(e, rng),
+
+            Expr::Min(a, b) => eval(a, rng).min(eval(b, rng)),
+            Expr::Max(a, b) => eval(a, rng).max(eval(b, rng)),
+            Expr::Abs(e) => eval(e, rng).abs(),
+            Expr::Floor(e) => eval(e, rng).floor(),
+            Expr::Ceil(e) => eval(e, rng).ceil(),
+
+            Expr::Clamp(value, lo, hi) => clamp(eval(value, rng), eval(lo, rng), eval(hi, rng)),
+            Expr::Pow(a, b) => eval(a, rng).powf(eval(b, rng)),
+            Expr::Sqrt(e) => eval(e, rng).max(0.0).sqrt(),
+            Expr::Lerp(a, b, t) => lerp(eval(a, rng), eval(b, rng), eval(t, rng)),
+            Expr::Mod(a, b) => modulo(eval(a, rng), eval(b, rng)),
+
+            Expr::If(cond, then, otherwise) => {
+                if check_condition_with_rng(cond, stats, action, rng) {
+                    eval(then, rng)
+                } else {
+                    eval(otherwise, rng)
                 }
             }
-            Expr::Negate(e) => -e.eval(stats, action),
-
-            Expr::Min(a, b) => a.eval(stats, action).min(b.eval(stats, action)),
-            Expr::Max(a, b) => a.eval(stats, action).max(b.eval(stats, action)),
-            Expr::Abs(e) => e.eval(stats, action).abs(),
-            Expr::Floor(e) => e.eval(stats, action).floor(),
-            Expr::Ceil(e) => e.eval(stats, action).ceil(),
         }
     }
+
+    /// Evaluate with an unseeded [`RuleRng`], so a `Chance` in an
+    /// [`Expr::If`] comes out the same every call; prefer
+    /// [`Expr::eval_with_rng`] inside a rule pass.
+    pub fn eval(&self, stats: &Stats, action: &Action) -> f32 {
+        self.eval_with_rng(stats, action, &mut RuleRng::default())
+    }
+}
+
+// Shared with the compiled evaluator so both give the same bits.
+
+/// Unlike `f32::clamp`, doesn't panic when `lo > hi`.
+fn clamp(value: f32, lo: f32, hi: f32) -> f32 {
+    value.max(lo).min(hi)
+}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+fn modulo(a: f32, b: f32) -> f32 {
+    if b.abs() < f32::EPSILON {
+        0.0
+    } else {
+        a.rem_euclid(b)
+    }
 }
 
 // ============================================================================
@@ -203,7 +563,7 @@ pub enum Condition {
     LessOrEqual(Expr, Expr),
     Equals(Expr, Expr),
 
-    /// True if rng_roll < expr. Caller provides the roll value.
+    /// True if the next roll of the caller's [`RuleRng`] is below `expr`.
     Chance(Expr),
 
     All(Vec<Condition>),
@@ -211,53 +571,52 @@ pub enum Condition {
     Not(Box<Condition>),
 }
 
-/// Check a condition using a provided RNG roll for Chance nodes.
-pub fn check_condition_with_roll(
+/// Check a condition, rolling `rng` for each Chance node it gets to.
+pub fn check_condition_with_rng(
     cond: &Condition,
     stats: &Stats,
     action: &Action,
-    rng_roll: f32,
+    rng: &mut RuleRng,
 ) -> bool {
+    let eval = |e: &Expr, rng: &mut RuleRng| e.eval_with_rng(stats, action, rng);
     match cond {
-        Condition::GreaterThan(a, b) => a.eval(stats, action) > b.eval(stats, action),
-        Condition::GreaterOrEqual(a, b) => a.eval(stats, action) >= b.eval(stats, action),
-        Condition::LessThan(a, b) => a.eval(stats, action) < b.eval(stats, action),
-        Condition::LessOrEqual(a, b) => a.eval(stats, action) <= b.eval(stats, action),
-        Condition::Equals(a, b) => {
-            (a.eval(stats, action) - b.eval(stats, action)).abs() < f32::EPSILON
-        }
+        Condition::GreaterThan(a, b) => eval(a, rng) > eval(b, rng),
+        Condition::GreaterOrEqual(a, b) => eval(a, rng) >= eval(b, rng),
+        Condition::LessThan(a, b) => eval(a, rng) < eval(b, rng),
+        Condition::LessOrEqual(a, b) => eval(a, rng) <= eval(b, rng),
+        Condition::Equals(a, b) => (eval(a, rng) - eval(b, rng)).abs() < f32::EPSILON,
 
-        Condition::Chance(expr) => rng_roll < expr.eval(stats, action),
+        Condition::Chance(expr) => rng.roll() < eval(expr, rng),
 
         Condition::All(conds) => conds
             .iter()
-            .all(|c| check_condition_with_roll(c, stats, action, rng_roll)),
+            .all(|c| check_condition_with_rng(c, stats, action, rng)),
         Condition::Any(conds) => conds
             .iter()
-            .any(|c| check_condition_with_roll(c, stats, action, rng_roll)),
-        Condition::Not(c) => !check_condition_with_roll(c, stats, action, rng_roll),
+            .any(|c| check_condition_with_rng(c, stats, action, rng)),
+        Condition::Not(c) => !check_condition_with_rng(c, stats, action, rng),
     }
 }
 
-/// Check a condition. Chance nodes use `rng_roll = 0.5` (50/50 default).
-/// Prefer `check_condition_with_roll` for deterministic behavior.
+/// Check a condition. Chance nodes roll an unseeded [`RuleRng`], so they
+/// come out the same every call; prefer [`check_condition_with_rng`].
 pub fn check_condition(cond: &Condition, stats: &Stats, action: &Action) -> bool {
-    check_condition_with_roll(cond, stats, action, 0.5)
+    check_condition_with_rng(cond, stats, action, &mut RuleRng::default())
 }
 
 pub fn check_conditions(conditions: &[Condition], stats: &Stats, action: &Action) -> bool {
-    conditions.iter().all(|c| check_condition(c, stats, action))
+    check_conditions_with_rng(conditions, stats, action, &mut RuleRng::default())
 }
 
-pub fn check_conditions_with_roll(
+pub fn check_conditions_with_rng(
     conditions: &[Condition],
     stats: &Stats,
     action: &Action,
-    rng_roll: f32,
+    rng: &mut RuleRng,
 ) -> bool {
     conditions
         .iter()
-        .all(|c| check_condition_with_roll(c, stats, action, rng_roll))
+        .all(|c| check_condition_with_rng(c, stats, action, rng))
 }
 
 // ============================================================================
@@ -270,19 +629,179 @@ pub enum RuleEvent {
     Crit,
 }
 
+/// `Set*` effects apply as their rule runs: later rules see the new value,
+/// and a later `Set*` of the same stat replaces it.
+///
+/// `Add*` and `Mul*` effects are evaluated as their rule runs but applied
+/// once the whole rule list has: every flat change first, then every
+/// multiplier. Buffs stacked on one stat therefore come out as
+/// `(value + adds) * multipliers` whatever order their rules are in. Until
+/// then, conditions and expressions in the same pass see the value without
+/// them.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum Effect {
-    SetStat { stat: Stat, value: Expr },
-    SetAction { var: ActionVar, value: Expr },
+    SetStat {
+        stat: Stat,
+        value: Expr,
+    },
+    SetAction {
+        var: ActionVar,
+        value: Expr,
+    },
+    AddStat {
+        stat: Stat,
+        value: Expr,
+    },
+    MulStat {
+        stat: Stat,
+        value: Expr,
+    },
+    AddAction {
+        var: ActionVar,
+        value: Expr,
+    },
+    MulAction {
+        var: ActionVar,
+        value: Expr,
+    },
+    /// Put a timed effect on the entity whose rules these are. `effect_type`
+    /// is read like an admin's `add_effect` (see
+    /// [`EffectKind::parse_str`]); a
+    /// negative duration never runs out. Collected into
+    /// [`RuleOutput::statuses`] for the caller to apply.
+    ApplyStatus {
+        effect_type: String,
+        magnitude: Expr,
+        duration: Expr,
+    },
+    /// Spawn something where the rule ran: at the target for hit rules, at
+    /// the entity for the rest. `kind` is a [`SpawnKind`] name and `params`
+    /// its tunables. Collected into [`RuleOutput::spawns`] for the caller to
+    /// spawn.
+    Spawn {
+        kind: String,
+        #[serde(default)]
+        params: HashMap<String, Expr>,
+    },
+    /// Juice for whoever's watching: hit-stop, shake, rumble or a sound.
+    /// Collected into [`RuleOutput::cues`] for the client to play; the
+    /// server has no use for them.
+    Feedback(Feedback),
     Trigger(RuleEvent),
     Log(String),
 }
 
+/// A cue [`Effect::Feedback`] plays, with the amounts still to work out.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum Feedback {
+    /// Slow the game nearly to a stop for `duration` seconds.
+    HitStop { duration: Expr },
+    /// Add `intensity` trauma to the camera shake, 0 to 1.
+    Shake { intensity: Expr },
+    /// Rumble the gamepad at `intensity`, 0 to 1, for `duration` ms.
+    Rumble { intensity: Expr, duration: Expr },
+    /// Play the sound effect the client knows as `name`, e.g. `"punch"`, at
+    /// `volume` times the usual.
+    Sound { name: String, volume: Expr },
+}
+
+impl Feedback {
+    pub fn eval_with_rng(&self, stats: &Stats, action: &Action, rng: &mut RuleRng) -> FeedbackCue {
+        match self {
+            Self::HitStop { duration } => FeedbackCue::HitStop {
+                duration: duration.eval_with_rng(stats, action, rng),
+            },
+            Self::Shake { intensity } => FeedbackCue::Shake {
+                intensity: intensity.eval_with_rng(stats, action, rng),
+            },
+            Self::Rumble {
+                intensity,
+                duration,
+            } => FeedbackCue::Rumble {
+                intensity: intensity.eval_with_rng(stats, action, rng),
+                duration: duration.eval_with_rng(stats, action, rng),
+            },
+            Self::Sound { name, volume } => FeedbackCue::Sound {
+                name: name.clone(),
+                volume: volume.eval_with_rng(stats, action, rng),
+            },
+        }
+    }
+
+    pub fn eval(&self, stats: &Stats, action: &Action) -> FeedbackCue {
+        self.eval_with_rng(stats, action, &mut RuleRng::default())
+    }
+}
+
+/// A [`Feedback`] a rule played, with its amounts worked out.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FeedbackCue {
+    HitStop { duration: f32 },
+    Shake { intensity: f32 },
+    Rumble { intensity: f32, duration: f32 },
+    Sound { name: String, volume: f32 },
+}
+
+/// A timed effect a rule granted, with its magnitude and duration worked out.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StatusGrant {
+    pub effect_type: String,
+    pub magnitude: f32,
+    pub duration: f32,
+}
+
+impl StatusGrant {
+    /// The effect's kind and the name its row carries.
+    pub fn kind(&self) -> (EffectKind, &str) {
+        EffectKind::parse_str(&self.effect_type)
+    }
+}
+
+/// Something a rule spawned, with its params worked out.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpawnRequest {
+    pub kind: String,
+    pub params: HashMap<String, f32>,
+}
+
+impl SpawnRequest {
+    /// What to spawn, or `None` for a kind this build doesn't know.
+    pub fn kind(&self) -> Option<SpawnKind> {
+        SpawnKind::parse_str(&self.kind)
+    }
+
+    /// The param named `name`, or `default` if the rule didn't set it.
+    pub fn param(&self, name: &str, default: f32) -> f32 {
+        self.params.get(name).copied().unwrap_or(default)
+    }
+}
+
+/// What an `Add*` or `Mul*` effect changes.
+#[derive(Clone, Debug)]
+enum Target {
+    Stat(Stat),
+    Action(ActionVar),
+}
+
+/// An `Add*` or `Mul*` effect waiting for the end of the pass.
+#[derive(Clone, Debug)]
+struct PendingModifier {
+    target: Target,
+    kind: ModifierKind,
+    source: Option<String>,
+}
+
 /// Output from rule execution - collected events and log messages.
 #[derive(Default, Debug)]
 pub struct RuleOutput {
     pub events: Vec<RuleEvent>,
     pub logs: Vec<String>,
+    pub statuses: Vec<StatusGrant>,
+    pub spawns: Vec<SpawnRequest>,
+    pub cues: Vec<FeedbackCue>,
+    /// What the pass did, while [`tracing`] is on.
+    pub trace: Option<PassTrace>,
+    modifiers: Vec<PendingModifier>,
 }
 
 impl RuleOutput {
@@ -297,6 +816,37 @@ impl RuleOutput {
     pub fn is_crit(&self) -> bool {
         self.has(&RuleEvent::Crit)
     }
+
+    fn defer(&mut self, target: Target, kind: ModifierKind, source: Option<&str>) {
+        self.modifiers.push(PendingModifier {
+            target,
+            kind,
+            source: source.map(str::to_string),
+        });
+    }
+
+    /// Apply the `Add*` and `Mul*` effects collected so far, flat changes
+    /// before multipliers (see [`Effect`]), then clamp the stats back to
+    /// their bounds. The `execute_*` functions that return a [`RuleOutput`]
+    /// call this themselves; callers of [`execute_effect`] and
+    /// [`execute_rule`] call it once they're done.
+    pub fn apply_modifiers(&mut self, stats: &mut Stats, action: &mut Action) {
+        let (flat, multipliers): (Vec<_>, Vec<_>) = std::mem::take(&mut self.modifiers)
+            .into_iter()
+            .partition(|m| matches!(m.kind, ModifierKind::Flat(_)));
+        for modifier in flat.into_iter().chain(multipliers) {
+            match modifier.target {
+                Target::Stat(stat) => {
+                    stats.apply_modifier(stat, modifier.kind, modifier.source.as_deref())
+                }
+                Target::Action(var) => {
+                    let value = modifier.kind.apply_to(action.get(&var));
+                    action.set(var, value);
+                }
+            }
+        }
+        stats.enforce_bounds();
+    }
 }
 
 pub fn execute_effect(
@@ -304,16 +854,62 @@ pub fn execute_effect(
     stats: &mut Stats,
     action: &mut Action,
     output: &mut RuleOutput,
+) {
+    apply_effect(effect, None, stats, action, output, &mut RuleRng::default());
+}
+
+/// [`execute_effect`] on behalf of a rule, so stat changes are attributed
+/// to `source`, rolling `rng` for any `Chance` its amounts check.
+fn apply_effect(
+    effect: &Effect,
+    source: Option<&str>,
+    stats: &mut Stats,
+    action: &mut Action,
+    output: &mut RuleOutput,
+    rng: &mut RuleRng,
 ) {
     match effect {
         Effect::SetStat { stat, value } => {
-            let v = value.eval(stats, action);
-            stats.set(stat.clone(), v);
+            let v = value.eval_with_rng(stats, action, rng);
+            stats.modify(stat.clone(), v, scales_stat(value, stat), source);
         }
         Effect::SetAction { var, value } => {
-            let v = value.eval(stats, action);
+            let v = value.eval_with_rng(stats, action, rng);
             action.set(var.clone(), v);
         }
+        Effect::AddStat { stat, value } => {
+            let v = ModifierKind::Flat(value.eval_with_rng(stats, action, rng));
+            output.defer(Target::Stat(stat.clone()), v, source);
+        }
+        Effect::MulStat { stat, value } => {
+            let v = ModifierKind::Multiplier(value.eval_with_rng(stats, action, rng));
+            output.defer(Target::Stat(stat.clone()), v, source);
+        }
+        Effect::AddAction { var, value } => {
+            let v = ModifierKind::Flat(value.eval_with_rng(stats, action, rng));
+            output.defer(Target::Action(var.clone()), v, source);
+        }
+        Effect::MulAction { var, value } => {
+            let v = ModifierKind::Multiplier(value.eval_with_rng(stats, action, rng));
+            output.defer(Target::Action(var.clone()), v, source);
+        }
+        Effect::ApplyStatus {
+            effect_type,
+            magnitude,
+            duration,
+        } => output.statuses.push(StatusGrant {
+            effect_type: effect_type.clone(),
+            magnitude: magnitude.eval_with_rng(stats, action, rng),
+            duration: duration.eval_with_rng(stats, action, rng),
+        }),
+        Effect::Spawn { kind, params } => output.spawns.push(SpawnRequest {
+            kind: kind.clone(),
+            params: params
+                .iter()
+                .map(|(name, value)| (name.clone(), value.eval_with_rng(stats, action, rng)))
+                .collect(),
+        }),
+        Effect::Feedback(feedback) => output.cues.push(feedback.eval_with_rng(stats, action, rng)),
         Effect::Trigger(event) => {
             if !output.events.contains(event) {
                 output.events.push(event.clone());
@@ -330,6 +926,7 @@ pub fn execute_effects(effects: &[Effect], stats: &mut Stats, action: &mut Actio
     for effect in effects {
         execute_effect(effect, stats, action, &mut output);
     }
+    output.apply_modifiers(stats, action);
     output
 }
 
@@ -339,6 +936,10 @@ pub fn execute_effects(effects: &[Effect], stats: &mut Stats, action: &mut Actio
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct Rule {
+    /// Preset or item this rule belongs to, shown as the origin of the stat
+    /// changes it makes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
     #[serde(default)]
     pub conditions: Vec<Condition>,
     pub effects: Vec<Effect>,
@@ -358,49 +959,71 @@ impl Rule {
         self.effects.push(effect);
         self
     }
+
+    pub fn source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
 }
 
 pub fn execute_rule(rule: &Rule, stats: &mut Stats, action: &mut Action, output: &mut RuleOutput) {
-    if check_conditions(&rule.conditions, stats, action) {
-        for effect in &rule.effects {
-            execute_effect(effect, stats, action, output);
-        }
-    }
+    execute_rule_with_rng(rule, stats, action, output, &mut RuleRng::default());
 }
 
+/// Execute rules with an unseeded [`RuleRng`] for Chance conditions; see
+/// [`execute_rules_with_rng`].
 pub fn execute_rules(rules: &[Rule], stats: &mut Stats, action: &mut Action) -> RuleOutput {
-    let mut output = RuleOutput::new();
-    for rule in rules {
-        execute_rule(rule, stats, action, &mut output);
+    execute_rules_with_rng(rules, stats, action, &mut RuleRng::default())
+}
+
+/// Execute a rule, rolling `rng` for its Chance conditions.
+pub fn execute_rule_with_rng(
+    rule: &Rule,
+    stats: &mut Stats,
+    action: &mut Action,
+    output: &mut RuleOutput,
+    rng: &mut RuleRng,
+) {
+    if check_conditions_with_rng(&rule.conditions, stats, action, rng) {
+        apply_rule_effects(rule, stats, action, output, rng);
     }
-    output
 }
 
-/// Execute rules with a provided RNG roll for Chance conditions.
-pub fn execute_rule_with_roll(
+fn apply_rule_effects(
     rule: &Rule,
     stats: &mut Stats,
     action: &mut Action,
     output: &mut RuleOutput,
-    rng_roll: f32,
+    rng: &mut RuleRng,
 ) {
-    if check_conditions_with_roll(&rule.conditions, stats, action, rng_roll) {
-        for effect in &rule.effects {
-            execute_effect(effect, stats, action, output);
-        }
+    for effect in &rule.effects {
+        apply_effect(effect, rule.source.as_deref(), stats, action, output, rng);
     }
 }
 
-pub fn execute_rules_with_roll(
+pub fn execute_rules_with_rng(
     rules: &[Rule],
     stats: &mut Stats,
     action: &mut Action,
-    rng_roll: f32,
+    rng: &mut RuleRng,
 ) -> RuleOutput {
     let mut output = RuleOutput::new();
-    for rule in rules {
-        execute_rule_with_roll(rule, stats, action, &mut output, rng_roll);
+    let mut trace = PassTrace::start(stats, action);
+    for (index, rule) in rules.iter().enumerate() {
+        let Some(trace) = &mut trace else {
+            execute_rule_with_rng(rule, stats, action, &mut output, rng);
+            continue;
+        };
+        let checks = rule
+            .conditions
+            .iter()
+            .map(|c| check_condition_with_rng(c, stats, action, rng));
+        if trace.check(index, rule.source.as_deref(), checks) {
+            apply_rule_effects(rule, stats, action, &mut output, rng);
+        }
     }
+    output.apply_modifiers(stats, action);
+    output.trace = trace.map(|trace| trace.finish(stats, action));
     output
 }
 
@@ -0,0 +1,102 @@
+//! The range each stat may hold, so rules can't push crit chance past 100%
+//! or attack speed below zero. [`Stats`](super::Stats) clamps to its bounds
+//! in `set` and once each rule pass is over.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::Stat;
+
+/// Lowest and highest value a stat may hold. Either end may be left open;
+/// in RON, `(max: 0.9)` caps a stat and leaves its floor where it was.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StatBound {
+    #[serde(default = "open_min")]
+    pub min: f32,
+    #[serde(default = "open_max")]
+    pub max: f32,
+}
+
+fn open_min() -> f32 {
+    f32::NEG_INFINITY
+}
+
+fn open_max() -> f32 {
+    f32::INFINITY
+}
+
+impl StatBound {
+    /// No bound at all.
+    pub const OPEN: Self = Self {
+        min: f32::NEG_INFINITY,
+        max: f32::INFINITY,
+    };
+
+    pub const fn new(min: f32, max: f32) -> Self {
+        Self { min, max }
+    }
+
+    pub const fn at_least(min: f32) -> Self {
+        Self::new(min, f32::INFINITY)
+    }
+
+    /// The bound every [`Stats`](super::Stats) starts with for `stat`.
+    pub fn default_for(stat: &Stat) -> Self {
+        match stat {
+            Stat::CritChance => Self::new(0.0, 1.0),
+            Stat::CritMultiplier => Self::at_least(1.0),
+            // Attack pace divides by it
+            Stat::AttackSpeed => Self::at_least(0.1),
+            Stat::AttackArc => Self::new(0.0, 360.0),
+            Stat::Health
+            | Stat::MaxHealth
+            | Stat::Shield
+            | Stat::MaxShield
+            | Stat::Mana
+            | Stat::MaxMana
+            | Stat::Stamina
+            | Stat::MaxStamina
+            | Stat::AttackDamage
+            | Stat::AbilityPower
+            | Stat::Armor
+            | Stat::MagicResist
+            | Stat::MovementSpeed
+            | Stat::Knockback
+            | Stat::AttackRange => Self::at_least(0.0),
+            _ => Self::OPEN,
+        }
+    }
+
+    pub fn clamp(self, value: f32) -> f32 {
+        value.max(self.min).min(self.max)
+    }
+}
+
+/// Per-stat bounds that replace [`StatBound::default_for`], e.g. a preset
+/// letting crit chance reach only 80%. Stats without an entry keep their
+/// defaults.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct StatBounds(HashMap<Stat, StatBound>);
+
+impl StatBounds {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, stat: Stat, bound: StatBound) -> Self {
+        self.0.insert(stat, bound);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get(&self, stat: &Stat) -> StatBound {
+        self.0
+            .get(stat)
+            .copied()
+            .unwrap_or_else(|| StatBound::default_for(stat))
+    }
+}
@@ -0,0 +1,127 @@
+//! Stats built up in layers: a base, then flat bonuses, then multipliers,
+//! each credited to a source. Totals are recomputed from the layers on every
+//! change, so taking a buff or an item off restores exactly what was there
+//! before it.
+
+use super::{ModifierKind, Stat, Stats};
+
+/// One source's bonus to one stat.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StatLayer {
+    pub source: String,
+    pub stat: Stat,
+    pub kind: ModifierKind,
+}
+
+/// A stat container that keeps every contribution apart and derives the
+/// final values as `(base + flat bonuses) × multipliers`, clamped to the
+/// base's bounds. [`LayeredStats::totals`] is an ordinary [`Stats`] whose
+/// breakdowns list the layers, ready for rules and tooltips.
+#[derive(Clone, Debug, Default)]
+pub struct LayeredStats {
+    base: Stats,
+    layers: Vec<StatLayer>,
+    totals: Stats,
+}
+
+impl LayeredStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Layers on top of `base`, which keeps its own provenance and bounds.
+    pub fn from_base(base: Stats) -> Self {
+        Self {
+            totals: base.clone(),
+            base,
+            layers: Vec::new(),
+        }
+    }
+
+    /// Replace `stat`'s base value, crediting it to `source` if given.
+    pub fn set_base(&mut self, stat: Stat, value: f32, source: Option<&str>) {
+        self.base.set(stat.clone(), value);
+        if let Some(source) = source {
+            self.base.sources.entry(stat).or_default().base_source = Some(source.to_string());
+        }
+        self.recompute();
+    }
+
+    /// Add `amount` to `stat` before multipliers, replacing any flat bonus
+    /// `source` already gave it.
+    pub fn add(&mut self, source: &str, stat: Stat, amount: f32) {
+        self.put(source, stat, ModifierKind::Flat(amount));
+    }
+
+    /// Scale `stat` by `factor` after flat bonuses, replacing any multiplier
+    /// `source` already gave it.
+    pub fn multiply(&mut self, source: &str, stat: Stat, factor: f32) {
+        self.put(source, stat, ModifierKind::Multiplier(factor));
+    }
+
+    /// Drop everything `source` contributed. Returns whether it had any.
+    pub fn remove_source(&mut self, source: &str) -> bool {
+        let before = self.layers.len();
+        self.layers.retain(|layer| layer.source != source);
+        let removed = self.layers.len() != before;
+        if removed {
+            self.recompute();
+        }
+        removed
+    }
+
+    pub fn base(&self) -> &Stats {
+        &self.base
+    }
+
+    pub fn layers(&self) -> &[StatLayer] {
+        &self.layers
+    }
+
+    /// The final values.
+    pub fn totals(&self) -> &Stats {
+        &self.totals
+    }
+
+    pub fn get(&self, stat: &Stat) -> f32 {
+        self.totals.get(stat)
+    }
+
+    pub fn into_totals(self) -> Stats {
+        self.totals
+    }
+
+    fn put(&mut self, source: &str, stat: Stat, kind: ModifierKind) {
+        let same_kind = |layer: &StatLayer| {
+            layer.source == source
+                && layer.stat == stat
+                && std::mem::discriminant(&layer.kind) == std::mem::discriminant(&kind)
+        };
+        match self.layers.iter_mut().find(|layer| same_kind(layer)) {
+            Some(layer) => layer.kind = kind,
+            None => self.layers.push(StatLayer {
+                source: source.to_string(),
+                stat,
+                kind,
+            }),
+        }
+        self.recompute();
+    }
+
+    fn recompute(&mut self) {
+        let mut totals = self.base.clone();
+        let flats = self
+            .layers
+            .iter()
+            .filter(|layer| matches!(layer.kind, ModifierKind::Flat(_)));
+        let multipliers = self
+            .layers
+            .iter()
+            .filter(|layer| matches!(layer.kind, ModifierKind::Multiplier(_)));
+        for layer in flats.chain(multipliers) {
+            totals.apply_modifier(layer.stat.clone(), layer.kind, Some(&layer.source));
+        }
+        totals.enforce_bounds();
+        self.totals = totals;
+    }
+}
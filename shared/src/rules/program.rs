@@ -0,0 +1,684 @@
+//! Precompiled rules.
+//!
+//! [`Expr`] trees evaluate by boxed recursion, and rules that run per hit per
+//! target walk them again for every target. [`Program::compile`] folds
+//! constant subtrees once and flattens the rest into a postfix op list that
+//! runs on a fixed-size stack, without allocating. [`CompiledRules`] does the
+//! same for whole rule lists.
+//!
+//! Results are bit-identical to the tree evaluator: folding uses the same
+//! `f32` operations, just earlier. `cargo bench -p wasm-fantasia-shared`
+//! compares the two.
+
+use super::{
+    Action, ActionVar, Condition, Effect, Expr, Feedback, FeedbackCue, ModifierKind, PassTrace,
+    Rule, RuleEvent, RuleOutput, RuleRng, SpawnRequest, Stat, Stats, StatusGrant, Target,
+    check_condition_with_rng, clamp, lerp, modulo, scales_stat,
+};
+
+/// Deepest operand stack a program may use. Deeper expressions fall back to
+/// tree evaluation — presets nest a handful of levels at most.
+const STACK_SIZE: usize = 16;
+
+#[derive(Clone, Debug)]
+enum Op {
+    Value(f32),
+    Stat(Stat),
+    Action(ActionVar),
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Negate,
+    Min,
+    Max,
+    Abs,
+    Floor,
+    Ceil,
+    Clamp,
+    Pow,
+    Sqrt,
+    Lerp,
+    Mod,
+    /// Pops both branches and keeps the one the condition picks.
+    If(Box<Condition>),
+}
+
+#[derive(Clone, Debug)]
+enum Code {
+    /// The whole expression folded to a constant.
+    Value(f32),
+    Ops(Vec<Op>),
+    /// Too deep for the fixed stack.
+    Tree(Expr),
+}
+
+/// A compiled [`Expr`].
+#[derive(Clone, Debug)]
+pub struct Program(Code);
+
+impl Program {
+    pub fn compile(expr: &Expr) -> Self {
+        let folded = fold(expr);
+        if let Expr::Value(v) = folded {
+            return Self(Code::Value(v));
+        }
+        let mut ops = Vec::new();
+        if emit(&folded, &mut ops) > STACK_SIZE || branch_rolls(&folded) {
+            return Self(Code::Tree(folded));
+        }
+        Self(Code::Ops(ops))
+    }
+
+    /// Same as [`Expr::eval_with_rng`] on the source expression.
+    pub fn eval_with_rng(&self, stats: &Stats, action: &Action, rng: &mut RuleRng) -> f32 {
+        match &self.0 {
+            Code::Value(v) => *v,
+            Code::Ops(ops) => run(ops, stats, action, rng),
+            Code::Tree(expr) => expr.eval_with_rng(stats, action, rng),
+        }
+    }
+
+    pub fn eval(&self, stats: &Stats, action: &Action) -> f32 {
+        self.eval_with_rng(stats, action, &mut RuleRng::default())
+    }
+}
+
+/// Whether evaluating `expr` can roll a [`RuleRng`].
+fn rolls(expr: &Expr) -> bool {
+    match expr {
+        Expr::If(cond, ..) if condition_rolls(cond) => true,
+        _ => any_operand(expr, rolls),
+    }
+}
+
+fn condition_rolls(cond: &Condition) -> bool {
+    match cond {
+        Condition::GreaterThan(a, b)
+        | Condition::GreaterOrEqual(a, b)
+        | Condition::LessThan(a, b)
+        | Condition::LessOrEqual(a, b)
+        | Condition::Equals(a, b) => rolls(a) || rolls(b),
+        Condition::Chance(_) => true,
+        Condition::All(conds) | Condition::Any(conds) => conds.iter().any(condition_rolls),
+        Condition::Not(c) => condition_rolls(c),
+    }
+}
+
+/// Whether an `If` in `expr` has a branch that rolls. Postfix ops run both
+/// branches, which would roll for the one the tree evaluator skips.
+fn branch_rolls(expr: &Expr) -> bool {
+    match expr {
+        Expr::If(_, then, otherwise) => rolls(then) || rolls(otherwise),
+        _ => any_operand(expr, branch_rolls),
+    }
+}
+
+/// Whether `f` holds for any operand of `expr`. An `If`'s condition isn't
+/// an operand, its branches are.
+fn any_operand(expr: &Expr, f: fn(&Expr) -> bool) -> bool {
+    match expr {
+        Expr::Value(_) | Expr::Stat(_) | Expr::Action(_) => false,
+        Expr::Add(a, b)
+        | Expr::Subtract(a, b)
+        | Expr::Multiply(a, b)
+        | Expr::Divide(a, b)
+        | Expr::Min(a, b)
+        | Expr::Max(a, b)
+        | Expr::Pow(a, b)
+        | Expr::Mod(a, b)
+        | Expr::If(_, a, b) => f(a) || f(b),
+        Expr::Clamp(a, b, c) | Expr::Lerp(a, b, c) => f(a) || f(b) || f(c),
+        Expr::Negate(e) | Expr::Abs(e) | Expr::Floor(e) | Expr::Ceil(e) | Expr::Sqrt(e) => f(e),
+    }
+}
+
+/// Replace every subtree without `Stat`/`Action` leaves by its value.
+fn fold(expr: &Expr) -> Expr {
+    let fold_box = |e: &Expr| Box::new(fold(e));
+    let folded = match expr {
+        Expr::Value(_) | Expr::Stat(_) | Expr::Action(_) => return expr.clone(),
+        Expr::Add(a, b) => Expr::Add(fold_box(a), fold_box(b)),
+        Expr::Subtract(a, b) => Expr::Subtract(fold_box(a), fold_box(b)),
+        Expr::Multiply(a, b) => Expr::Multiply(fold_box(a), fold_box(b)),
+        Expr::Divide(a, b) => Expr::Divide(fold_box(a), fold_box(b)),
+        Expr::Min(a, b) => Expr::Min(fold_box(a), fold_box(b)),
+        Expr::Max(a, b) => Expr::Max(fold_box(a), fold_box(b)),
+        Expr::Negate(e) => Expr::Negate(fold_box(e)),
+        Expr::Abs(e) => Expr::Abs(fold_box(e)),
+        Expr::Floor(e) => Expr::Floor(fold_box(e)),
+        Expr::Ceil(e) => Expr::Ceil(fold_box(e)),
+        Expr::Clamp(v, lo, hi) => Expr::Clamp(fold_box(v), fold_box(lo), fold_box(hi)),
+        Expr::Pow(a, b) => Expr::Pow(fold_box(a), fold_box(b)),
+        Expr::Sqrt(e) => Expr::Sqrt(fold_box(e)),
+        Expr::Lerp(a, b, t) => Expr::Lerp(fold_box(a), fold_box(b), fold_box(t)),
+        Expr::Mod(a, b) => Expr::Mod(fold_box(a), fold_box(b)),
+        Expr::If(cond, then, otherwise) => {
+            Expr::If(cond.clone(), fold_box(then), fold_box(otherwise))
+        }
+    };
+
+    let is_value = |e: &Expr| matches!(e, Expr::Value(_));
+    let constant = match &folded {
+        Expr::Add(a, b)
+        | Expr::Subtract(a, b)
+        | Expr::Multiply(a, b)
+        | Expr::Divide(a, b)
+        | Expr::Min(a, b)
+        | Expr::Max(a, b)
+        | Expr::Pow(a, b)
+        | Expr::Mod(a, b) => is_value(a) && is_value(b),
+        Expr::Clamp(a, b, c) | Expr::Lerp(a, b, c) => is_value(a) && is_value(b) && is_value(c),
+        Expr::Negate(e) | Expr::Abs(e) | Expr::Floor(e) | Expr::Ceil(e) | Expr::Sqrt(e) => {
+            is_value(e)
+        }
+        _ => false,
+    };
+    if constant {
+        // Same evaluator as at runtime, so folding can't change a result
+        Expr::Value(folded.eval(&Stats::new(), &Action::new()))
+    } else {
+        folded
+    }
+}
+
+/// Append `expr` in postfix order. Returns the stack depth it needs.
+fn emit(expr: &Expr, ops: &mut Vec<Op>) -> usize {
+    fn binary(a: &Expr, b: &Expr, op: Op, ops: &mut Vec<Op>) -> usize {
+        let depth_a = emit(a, ops);
+        let depth_b = emit(b, ops);
+        ops.push(op);
+        // `a`'s result sits under `b` while `b` is evaluated
+        depth_a.max(depth_b + 1)
+    }
+    fn ternary(a: &Expr, b: &Expr, c: &Expr, op: Op, ops: &mut Vec<Op>) -> usize {
+        let depth_a = emit(a, ops);
+        let depth_b = emit(b, ops);
+        let depth_c = emit(c, ops);
+        ops.push(op);
+        depth_a.max(depth_b + 1).max(depth_c + 2)
+    }
+    match expr {
+        Expr::Value(v) => {
+            ops.push(Op::Value(*v));
+            1
+        }
+        Expr::Stat(stat) => {
+            ops.push(Op::Stat(stat.clone()));
+            1
+        }
+        Expr::Action(var) => {
+            ops.push(Op::Action(var.clone()));
+            1
+        }
+        Expr::Add(a, b) => binary(a, b, Op::Add, ops),
+        Expr::Subtract(a, b) => binary(a, b, Op::Subtract, ops),
+        Expr::Multiply(a, b) => binary(a, b, Op::Multiply, ops),
+        Expr::Divide(a, b) => binary(a, b, Op::Divide, ops),
+        Expr::Min(a, b) => binary(a, b, Op::Min, ops),
+        Expr::Max(a, b) => binary(a, b, Op::Max, ops),
+        Expr::Pow(a, b) => binary(a, b, Op::Pow, ops),
+        Expr::Mod(a, b) => binary(a, b, Op::Mod, ops),
+        Expr::Clamp(v, lo, hi) => ternary(v, lo, hi, Op::Clamp, ops),
+        Expr::Lerp(a, b, t) => ternary(a, b, t, Op::Lerp, ops),
+        // Both branches run and one is kept; `compile` leaves branches that
+        // roll to the tree evaluator
+        Expr::If(cond, then, otherwise) => binary(then, otherwise, Op::If(cond.clone()), ops),
+        Expr::Negate(e) | Expr::Abs(e) | Expr::Floor(e) | Expr::Ceil(e) | Expr::Sqrt(e) => {
+            let depth = emit(e, ops);
+            ops.push(match expr {
+                Expr::Negate(_) => Op::Negate,
+                Expr::Abs(_) => Op::Abs,
+                Expr::Floor(_) => Op::Floor,
+                Expr::Sqrt(_) => Op::Sqrt,
+                _ => Op::Ceil,
+            });
+            depth
+        }
+    }
+}
+
+fn run(ops: &[Op], stats: &Stats, action: &Action, rng: &mut RuleRng) -> f32 {
+    let mut stack = [0.0_f32; STACK_SIZE];
+    let mut len = 0;
+    for op in ops {
+        let pushed = match op {
+            Op::Value(v) => *v,
+            Op::Stat(stat) => stats.get(stat),
+            Op::Action(var) => action.get(var),
+            Op::Negate => {
+                stack[len - 1] = -stack[len - 1];
+                continue;
+            }
+            Op::Abs => {
+                stack[len - 1] = stack[len - 1].abs();
+                continue;
+            }
+            Op::Floor => {
+                stack[len - 1] = stack[len - 1].floor();
+                continue;
+            }
+            Op::Ceil => {
+                stack[len - 1] = stack[len - 1].ceil();
+                continue;
+            }
+            Op::Sqrt => {
+                stack[len - 1] = stack[len - 1].max(0.0).sqrt();
+                continue;
+            }
+            Op::Clamp | Op::Lerp => {
+                len -= 2;
+                let (a, b, c) = (stack[len - 1], stack[len], stack[len + 1]);
+                stack[len - 1] = match op {
+                    Op::Clamp => clamp(a, b, c),
+                    _ => lerp(a, b, c),
+                };
+                continue;
+            }
+            Op::If(cond) => {
+                len -= 1;
+                if !check_condition_with_rng(cond, stats, action, rng) {
+                    stack[len - 1] = stack[len];
+                }
+                continue;
+            }
+            binary => {
+                len -= 1;
+                let (a, b) = (stack[len - 1], stack[len]);
+                stack[len - 1] = match binary {
+                    Op::Add => a + b,
+                    Op::Subtract => a - b,
+                    Op::Multiply => a * b,
+                    Op::Divide if b.abs() < f32::EPSILON => 0.0,
+                    Op::Divide => a / b,
+                    Op::Min => a.min(b),
+                    Op::Max => a.max(b),
+                    Op::Pow => a.powf(b),
+                    Op::Mod => modulo(a, b),
+                    _ => unreachable!("unary, ternary and leaf ops are handled above"),
+                };
+                continue;
+            }
+        };
+        stack[len] = pushed;
+        len += 1;
+    }
+    stack[0]
+}
+
+// ============================================================================
+// COMPILED RULES
+// ============================================================================
+
+#[derive(Clone, Copy, Debug)]
+enum Compare {
+    GreaterThan,
+    GreaterOrEqual,
+    LessThan,
+    LessOrEqual,
+    Equals,
+}
+
+#[derive(Clone, Debug)]
+enum CompiledCondition {
+    Compare(Compare, Program, Program),
+    Chance(Program),
+    All(Vec<CompiledCondition>),
+    Any(Vec<CompiledCondition>),
+    Not(Box<CompiledCondition>),
+}
+
+impl CompiledCondition {
+    fn compile(cond: &Condition) -> Self {
+        let compare =
+            |cmp, a: &Expr, b: &Expr| Self::Compare(cmp, Program::compile(a), Program::compile(b));
+        match cond {
+            Condition::GreaterThan(a, b) => compare(Compare::GreaterThan, a, b),
+            Condition::GreaterOrEqual(a, b) => compare(Compare::GreaterOrEqual, a, b),
+            Condition::LessThan(a, b) => compare(Compare::LessThan, a, b),
+            Condition::LessOrEqual(a, b) => compare(Compare::LessOrEqual, a, b),
+            Condition::Equals(a, b) => compare(Compare::Equals, a, b),
+            Condition::Chance(e) => Self::Chance(Program::compile(e)),
+            Condition::All(conds) => Self::All(conds.iter().map(Self::compile).collect()),
+            Condition::Any(conds) => Self::Any(conds.iter().map(Self::compile).collect()),
+            Condition::Not(c) => Self::Not(Box::new(Self::compile(c))),
+        }
+    }
+
+    /// Mirrors [`super::check_condition_with_rng`].
+    fn check(&self, stats: &Stats, action: &Action, rng: &mut RuleRng) -> bool {
+        match self {
+            Self::Compare(cmp, a, b) => {
+                let (a, b) = (
+                    a.eval_with_rng(stats, action, rng),
+                    b.eval_with_rng(stats, action, rng),
+                );
+                match cmp {
+                    Compare::GreaterThan => a > b,
+                    Compare::GreaterOrEqual => a >= b,
+                    Compare::LessThan => a < b,
+                    Compare::LessOrEqual => a <= b,
+                    Compare::Equals => (a - b).abs() < f32::EPSILON,
+                }
+            }
+            Self::Chance(p) => rng.roll() < p.eval_with_rng(stats, action, rng),
+            Self::All(conds) => conds.iter().all(|c| c.check(stats, action, rng)),
+            Self::Any(conds) => conds.iter().any(|c| c.check(stats, action, rng)),
+            Self::Not(c) => !c.check(stats, action, rng),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum CompiledEffect {
+    SetStat {
+        stat: Stat,
+        value: Program,
+        /// Whether `value` scales the stat by itself, for provenance.
+        scales_self: bool,
+    },
+    SetAction {
+        var: ActionVar,
+        value: Program,
+    },
+    /// An `Add*` or `Mul*` effect.
+    Defer {
+        target: Target,
+        multiply: bool,
+        value: Program,
+    },
+    ApplyStatus {
+        effect_type: String,
+        magnitude: Program,
+        duration: Program,
+    },
+    Spawn {
+        kind: String,
+        params: Vec<(String, Program)>,
+    },
+    Feedback(CompiledFeedback),
+    Trigger(RuleEvent),
+    Log(String),
+}
+
+#[derive(Clone, Debug)]
+enum CompiledFeedback {
+    HitStop {
+        duration: Program,
+    },
+    Shake {
+        intensity: Program,
+    },
+    Rumble {
+        intensity: Program,
+        duration: Program,
+    },
+    Sound {
+        name: String,
+        volume: Program,
+    },
+}
+
+impl CompiledFeedback {
+    fn compile(feedback: &Feedback) -> Self {
+        match feedback {
+            Feedback::HitStop { duration } => Self::HitStop {
+                duration: Program::compile(duration),
+            },
+            Feedback::Shake { intensity } => Self::Shake {
+                intensity: Program::compile(intensity),
+            },
+            Feedback::Rumble {
+                intensity,
+                duration,
+            } => Self::Rumble {
+                intensity: Program::compile(intensity),
+                duration: Program::compile(duration),
+            },
+            Feedback::Sound { name, volume } => Self::Sound {
+                name: name.clone(),
+                volume: Program::compile(volume),
+            },
+        }
+    }
+
+    fn eval(&self, stats: &Stats, action: &Action, rng: &mut RuleRng) -> FeedbackCue {
+        match self {
+            Self::HitStop { duration } => FeedbackCue::HitStop {
+                duration: duration.eval_with_rng(stats, action, rng),
+            },
+            Self::Shake { intensity } => FeedbackCue::Shake {
+                intensity: intensity.eval_with_rng(stats, action, rng),
+            },
+            Self::Rumble {
+                intensity,
+                duration,
+            } => FeedbackCue::Rumble {
+                intensity: intensity.eval_with_rng(stats, action, rng),
+                duration: duration.eval_with_rng(stats, action, rng),
+            },
+            Self::Sound { name, volume } => FeedbackCue::Sound {
+                name: name.clone(),
+                volume: volume.eval_with_rng(stats, action, rng),
+            },
+        }
+    }
+}
+
+impl CompiledEffect {
+    fn compile(effect: &Effect) -> Self {
+        match effect {
+            Effect::SetStat { stat, value } => Self::SetStat {
+                stat: stat.clone(),
+                value: Program::compile(value),
+                scales_self: scales_stat(value, stat),
+            },
+            Effect::SetAction { var, value } => Self::SetAction {
+                var: var.clone(),
+                value: Program::compile(value),
+            },
+            Effect::AddStat { stat, value } => {
+                Self::defer(Target::Stat(stat.clone()), false, value)
+            }
+            Effect::MulStat { stat, value } => Self::defer(Target::Stat(stat.clone()), true, value),
+            Effect::AddAction { var, value } => {
+                Self::defer(Target::Action(var.clone()), false, value)
+            }
+            Effect::MulAction { var, value } => {
+                Self::defer(Target::Action(var.clone()), true, value)
+            }
+            Effect::ApplyStatus {
+                effect_type,
+                magnitude,
+                duration,
+            } => Self::ApplyStatus {
+                effect_type: effect_type.clone(),
+                magnitude: Program::compile(magnitude),
+                duration: Program::compile(duration),
+            },
+            Effect::Spawn { kind, params } => Self::Spawn {
+                kind: kind.clone(),
+                params: params
+                    .iter()
+                    .map(|(name, value)| (name.clone(), Program::compile(value)))
+                    .collect(),
+            },
+            Effect::Feedback(feedback) => Self::Feedback(CompiledFeedback::compile(feedback)),
+            Effect::Trigger(event) => Self::Trigger(event.clone()),
+            Effect::Log(msg) => Self::Log(msg.clone()),
+        }
+    }
+
+    fn defer(target: Target, multiply: bool, value: &Expr) -> Self {
+        Self::Defer {
+            target,
+            multiply,
+            value: Program::compile(value),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct CompiledRule {
+    source: Option<String>,
+    conditions: Vec<CompiledCondition>,
+    effects: Vec<CompiledEffect>,
+}
+
+/// A rule list compiled once and executed many times, e.g. pre-hit rules
+/// across every target of an attack.
+#[derive(Clone, Debug, Default)]
+pub struct CompiledRules(Vec<CompiledRule>);
+
+impl CompiledRules {
+    pub fn compile(rules: &[Rule]) -> Self {
+        Self(
+            rules
+                .iter()
+                .map(|rule| CompiledRule {
+                    source: rule.source.clone(),
+                    conditions: rule
+                        .conditions
+                        .iter()
+                        .map(CompiledCondition::compile)
+                        .collect(),
+                    effects: rule.effects.iter().map(CompiledEffect::compile).collect(),
+                })
+                .collect(),
+        )
+    }
+
+    /// Same as [`super::execute_rules_with_rng`] on the source rules.
+    pub fn execute_with_rng(
+        &self,
+        stats: &mut Stats,
+        action: &mut Action,
+        rng: &mut RuleRng,
+    ) -> RuleOutput {
+        let mut output = RuleOutput::new();
+        let mut trace = PassTrace::start(stats, action);
+        for (index, rule) in self.0.iter().enumerate() {
+            let mut checks = rule.conditions.iter().map(|c| c.check(stats, action, rng));
+            let fired = match &mut trace {
+                Some(trace) => trace.check(index, rule.source.as_deref(), checks),
+                None => checks.all(|passed| passed),
+            };
+            if !fired {
+                continue;
+            }
+            for effect in &rule.effects {
+                match effect {
+                    CompiledEffect::SetStat {
+                        stat,
+                        value,
+                        scales_self,
+                    } => {
+                        let v = value.eval_with_rng(stats, action, rng);
+                        stats.modify(stat.clone(), v, *scales_self, rule.source.as_deref());
+                    }
+                    CompiledEffect::SetAction { var, value } => {
+                        let v = value.eval_with_rng(stats, action, rng);
+                        action.set(var.clone(), v);
+                    }
+                    CompiledEffect::Defer {
+                        target,
+                        multiply,
+                        value,
+                    } => {
+                        let v = value.eval_with_rng(stats, action, rng);
+                        let kind = if *multiply {
+                            ModifierKind::Multiplier(v)
+                        } else {
+                            ModifierKind::Flat(v)
+                        };
+                        output.defer(target.clone(), kind, rule.source.as_deref());
+                    }
+                    CompiledEffect::ApplyStatus {
+                        effect_type,
+                        magnitude,
+                        duration,
+                    } => output.statuses.push(StatusGrant {
+                        effect_type: effect_type.clone(),
+                        magnitude: magnitude.eval_with_rng(stats, action, rng),
+                        duration: duration.eval_with_rng(stats, action, rng),
+                    }),
+                    CompiledEffect::Spawn { kind, params } => output.spawns.push(SpawnRequest {
+                        kind: kind.clone(),
+                        params: params
+                            .iter()
+                            .map(|(name, value)| {
+                                (name.clone(), value.eval_with_rng(stats, action, rng))
+                            })
+                            .collect(),
+                    }),
+                    CompiledEffect::Feedback(feedback) => {
+                        output.cues.push(feedback.eval(stats, action, rng))
+                    }
+                    CompiledEffect::Trigger(event) => {
+                        if !output.events.contains(event) {
+                            output.events.push(event.clone());
+                        }
+                    }
+                    CompiledEffect::Log(msg) => output.logs.push(msg.clone()),
+                }
+            }
+        }
+        output.apply_modifiers(stats, action);
+        output.trace = trace.map(|trace| trace.finish(stats, action));
+        output
+    }
+
+    /// Same as [`super::execute_rules`]: `Chance` conditions roll an
+    /// unseeded [`RuleRng`].
+    pub fn execute(&self, stats: &mut Stats, action: &mut Action) -> RuleOutput {
+        self.execute_with_rng(stats, action, &mut RuleRng::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coin(heads: f32, tails: f32) -> Box<Expr> {
+        Box::new(Expr::If(
+            Box::new(Condition::Chance(Expr::Value(0.5))),
+            Box::new(Expr::Value(heads)),
+            Box::new(Expr::Value(tails)),
+        ))
+    }
+
+    /// Tree and program agree on the value and leave the rng in the same state.
+    fn assert_same_rolls(expr: &Expr) {
+        let program = Program::compile(expr);
+        let (stats, action) = (Stats::new(), Action::new());
+        for seed in 0..64 {
+            let (mut tree_rng, mut program_rng) = (RuleRng::new(seed), RuleRng::new(seed));
+            let tree = expr.eval_with_rng(&stats, &action, &mut tree_rng);
+            let compiled = program.eval_with_rng(&stats, &action, &mut program_rng);
+            assert_eq!(tree.to_bits(), compiled.to_bits(), "seed {seed}");
+            assert_eq!(tree_rng.roll(), program_rng.roll(), "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn rolling_divide_matches_tree() {
+        let divisor = Expr::Add(coin(1.0, 2.0), Box::new(Expr::Stat(Stat::AttackDamage)));
+        assert_same_rolls(&Expr::Divide(coin(10.0, 20.0), Box::new(divisor)));
+    }
+
+    #[test]
+    fn rolling_divide_by_zero_matches_tree() {
+        let zero = Expr::Multiply(coin(1.0, 2.0), Box::new(Expr::Stat(Stat::AttackDamage)));
+        assert_same_rolls(&Expr::Divide(coin(10.0, 20.0), Box::new(zero)));
+    }
+
+    #[test]
+    fn rolling_branches_match_tree() {
+        let nested = Expr::If(
+            Box::new(Condition::Chance(Expr::Value(0.5))),
+            coin(1.0, 2.0),
+            coin(3.0, 4.0),
+        );
+        assert_same_rolls(&Expr::Add(coin(10.0, 20.0), Box::new(nested)));
+    }
+}
@@ -0,0 +1,309 @@
+//! Schema versions for rules stored as RON: rule presets, and anything else
+//! that keeps [`Rule`](super::Rule)s outside the code. A document names the
+//! version it was written for in a top-level `version` field; documents from
+//! before versioning have none and count as version 0.
+//!
+//! A release that renames a variant or field of the rule types bumps
+//! [`SCHEMA_VERSION`] and adds a [`Migration`] to [`MIGRATIONS`] saying what
+//! changed. [`from_ron`] then upgrades older documents as it reads them.
+//! New variants and `#[serde(default)]` fields need no migration: older
+//! documents still read as they are.
+
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use std::fmt;
+
+/// Version of the rule types in this build.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// A name that changed between two versions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rename {
+    /// A struct or enum variant, e.g. `Value` in `Value(1.0)`.
+    Variant {
+        from: &'static str,
+        to: &'static str,
+    },
+    /// A field, e.g. `value` in `value: Value(1.0)`.
+    Field {
+        from: &'static str,
+        to: &'static str,
+    },
+}
+
+/// What changed going from the version before `to` up to `to`.
+#[derive(Clone, Copy, Debug)]
+pub struct Migration {
+    pub to: u32,
+    pub renames: &'static [Rename],
+}
+
+/// Every migration, oldest first. Append only.
+pub const MIGRATIONS: &[Migration] = &[
+    // Version 1 only added the `version` field
+    Migration {
+        to: 1,
+        renames: &[],
+    },
+];
+
+#[derive(Debug)]
+pub enum SchemaError {
+    /// Written for a version newer than [`SCHEMA_VERSION`], by a later
+    /// release than this one.
+    TooNew(u32),
+    Parse(ron::error::SpannedError),
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooNew(version) => write!(
+                f,
+                "rules schema version {version} is newer than this build's {SCHEMA_VERSION}"
+            ),
+            Self::Parse(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for SchemaError {}
+
+impl From<ron::error::SpannedError> for SchemaError {
+    fn from(e: ron::error::SpannedError) -> Self {
+        Self::Parse(e)
+    }
+}
+
+/// Just the version of a document; serde skips the rest.
+#[derive(Deserialize)]
+struct Header {
+    #[serde(default)]
+    version: u32,
+}
+
+/// The version a RON document was written for. Anything that isn't a
+/// struct, or doesn't parse at all, counts as unversioned.
+pub fn version_of(text: &str) -> u32 {
+    ron::from_str::<Header>(text).map_or(0, |header| header.version)
+}
+
+/// `text`, written for `version`, with every migration since applied.
+pub fn upgrade(text: &str, version: u32) -> Result<String, SchemaError> {
+    if version > SCHEMA_VERSION {
+        return Err(SchemaError::TooNew(version));
+    }
+    let renames: Vec<Rename> = MIGRATIONS
+        .iter()
+        .filter(|migration| migration.to > version)
+        .flat_map(|migration| migration.renames.iter().copied())
+        .collect();
+    Ok(rename_identifiers(text, &renames))
+}
+
+/// Read a RON document written for any version up to [`SCHEMA_VERSION`].
+pub fn from_ron<T: DeserializeOwned>(text: &str) -> Result<T, SchemaError> {
+    let upgraded = upgrade(text, version_of(text))?;
+    Ok(ron::from_str(&upgraded)?)
+}
+
+/// Apply `renames` in order to the identifiers of a RON document, leaving
+/// strings and comments alone. An identifier followed by a single `:` inside
+/// `(...)` is a field; any other is a variant, map keys in `{...}` included,
+/// since those are enum values like `stats: { CritChance: 0.2 }`.
+fn rename_identifiers(text: &str, renames: &[Rename]) -> String {
+    if renames.is_empty() {
+        return text.to_string();
+    }
+    let mut out = String::with_capacity(text.len());
+    // Brackets open around the current position
+    let mut open: Vec<char> = Vec::new();
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        let len = if rest.starts_with("//") {
+            rest.find('\n').unwrap_or(rest.len())
+        } else if rest.starts_with("/*") {
+            block_comment_len(rest)
+        } else if c == '"' {
+            quoted_len(rest, '"')
+        } else if c == '\'' {
+            quoted_len(rest, '\'')
+        } else if let Some(len) = raw_string_len(rest) {
+            len
+        } else if c.is_ascii_digit() {
+            // Suffixes like `1e5` or `0xff` aren't identifiers
+            rest.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '.'))
+                .unwrap_or(rest.len())
+        } else if c.is_alphabetic() || c == '_' {
+            let len = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            let (ident, after) = rest.split_at(len);
+            let after = after.trim_start();
+            let is_field =
+                open.last() == Some(&'(') && after.starts_with(':') && !after.starts_with("::");
+            out.push_str(&renamed(ident, is_field, renames));
+            rest = &rest[len..];
+            continue;
+        } else {
+            match c {
+                '(' | '[' | '{' => open.push(c),
+                ')' | ']' | '}' => {
+                    open.pop();
+                }
+                _ => {}
+            }
+            c.len_utf8()
+        };
+        out.push_str(&rest[..len]);
+        rest = &rest[len..];
+    }
+    out
+}
+
+fn renamed(ident: &str, is_field: bool, renames: &[Rename]) -> String {
+    let mut name = ident.to_string();
+    for rename in renames {
+        match *rename {
+            Rename::Variant { from, to } if !is_field && name == from => name = to.to_string(),
+            Rename::Field { from, to } if is_field && name == from => name = to.to_string(),
+            _ => {}
+        }
+    }
+    name
+}
+
+/// Length of the block comment `text` starts with. RON lets them nest.
+fn block_comment_len(text: &str) -> usize {
+    let mut depth = 0;
+    let mut i = 0;
+    while i < text.len() {
+        let rest = &text[i..];
+        if rest.starts_with("/*") {
+            depth += 1;
+            i += 2;
+        } else if rest.starts_with("*/") {
+            depth -= 1;
+            i += 2;
+            if depth == 0 {
+                return i;
+            }
+        } else {
+            i += rest.chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    text.len()
+}
+
+/// Length of the string or char literal `text` starts with, up to and
+/// including the closing `quote`.
+fn quoted_len(text: &str, quote: char) -> usize {
+    let mut escaped = false;
+    for (i, c) in text.char_indices().skip(1) {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            _ if c == quote => return i + c.len_utf8(),
+            _ => {}
+        }
+    }
+    text.len()
+}
+
+/// Length of the raw string (`r"..."`, `r#"..."#`) `text` starts with, if
+/// it starts with one.
+fn raw_string_len(text: &str) -> Option<usize> {
+    let hashes = text.strip_prefix('r')?;
+    let count = hashes.len() - hashes.trim_start_matches('#').len();
+    hashes[count..].strip_prefix('"')?;
+    let close = format!("\"{}", "#".repeat(count));
+    let body = 1 + count + 1;
+    Some(
+        text[body..]
+            .find(&close)
+            .map_or(text.len(), |end| body + end + close.len()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RENAMES: &[Rename] = &[
+        Rename::Variant {
+            from: "CritChance",
+            to: "CritRate",
+        },
+        Rename::Field {
+            from: "value",
+            to: "amount",
+        },
+    ];
+
+    fn migrate(text: &str) -> String {
+        rename_identifiers(text, RENAMES)
+    }
+
+    #[test]
+    fn renames_variants_and_fields() {
+        assert_eq!(
+            migrate("(effects: [SetStat(stat: CritChance, value: Stat(CritChance))])"),
+            "(effects: [SetStat(stat: CritRate, amount: Stat(CritRate))])"
+        );
+    }
+
+    #[test]
+    fn map_keys_are_variants() {
+        assert_eq!(
+            migrate("(stats: { CritChance: 0.2 }, bounds: { CritChance: (max: 0.8) })"),
+            "(stats: { CritRate: 0.2 }, bounds: { CritRate: (max: 0.8) })"
+        );
+        // A key named like a field is still a value, not a field
+        assert_eq!(migrate("{ value: 1 }"), "{ value: 1 }");
+    }
+
+    #[test]
+    fn leaves_strings_alone() {
+        assert_eq!(
+            migrate(r#"(source: Some("CritChance value: \"x\""), stat: CritChance)"#),
+            r#"(source: Some("CritChance value: \"x\""), stat: CritRate)"#
+        );
+        assert_eq!(migrate("Log('C')"), "Log('C')");
+    }
+
+    #[test]
+    fn leaves_raw_strings_alone() {
+        assert_eq!(
+            migrate(r###"(log: r#"CritChance "value: 1""#, stat: CritChance)"###),
+            r###"(log: r#"CritChance "value: 1""#, stat: CritRate)"###
+        );
+    }
+
+    #[test]
+    fn leaves_comments_alone() {
+        assert_eq!(
+            migrate("// CritChance value: 1\n(/* CritChance /* value: */ */ value: CritChance)"),
+            "// CritChance value: 1\n(/* CritChance /* value: */ */ amount: CritRate)"
+        );
+    }
+
+    #[test]
+    fn numbers_are_not_identifiers() {
+        let renames = [Rename::Variant {
+            from: "e5",
+            to: "x",
+        }];
+        assert_eq!(rename_identifiers("[1e5, 0xe5]", &renames), "[1e5, 0xe5]");
+    }
+
+    #[test]
+    fn reads_versions() {
+        assert_eq!(version_of("(version: 1, on_hit: [])"), 1);
+        assert_eq!(version_of("(on_hit: [(effects: [Log(\"x\")])])"), 0);
+        assert_eq!(version_of("[]"), 0);
+        assert!(matches!(
+            upgrade("(version: 99)", 99),
+            Err(SchemaError::TooNew(99))
+        ));
+    }
+}
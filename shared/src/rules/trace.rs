@@ -0,0 +1,162 @@
+//! Opt-in record of what a rule pass did: which rules fired, what each of
+//! their conditions came out as, and how stats and action values moved.
+//! Off until [`set_tracing`] turns it on, so passes pay nothing for it in
+//! normal play.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use super::{Action, ActionVar, Stat, Stats};
+
+static TRACING: AtomicBool = AtomicBool::new(false);
+
+/// Make every rule pass from now on leave a [`PassTrace`] in its
+/// [`RuleOutput`](super::RuleOutput), or stop.
+pub fn set_tracing(enabled: bool) {
+    TRACING.store(enabled, Ordering::Relaxed);
+}
+
+pub fn tracing() -> bool {
+    TRACING.load(Ordering::Relaxed)
+}
+
+/// How one rule of a pass went.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RuleTrace {
+    /// Position in the rule list.
+    pub index: usize,
+    pub source: Option<String>,
+    /// Each condition checked, in order. Checking stops at the first that
+    /// fails, so the ones after it are missing.
+    pub conditions: Vec<bool>,
+    pub fired: bool,
+}
+
+/// A value the pass changed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Delta<K> {
+    pub key: K,
+    pub before: f32,
+    pub after: f32,
+}
+
+/// What one rule pass did, with the stats and action values it changed
+/// once every rule (and every `Add*`/`Mul*` effect) had run.
+#[derive(Clone, Debug, Default)]
+pub struct PassTrace {
+    pub rules: Vec<RuleTrace>,
+    pub stats: Vec<Delta<Stat>>,
+    pub action: Vec<Delta<ActionVar>>,
+    stats_before: HashMap<Stat, f32>,
+    action_before: HashMap<ActionVar, f32>,
+}
+
+impl PassTrace {
+    /// A trace for a pass about to run on `stats` and `action`, if tracing
+    /// is on.
+    pub(super) fn start(stats: &Stats, action: &Action) -> Option<Self> {
+        tracing().then(|| Self {
+            stats_before: stats.values.clone(),
+            action_before: action.0.clone(),
+            ..Default::default()
+        })
+    }
+
+    /// Check a rule's conditions, recording each result, and return whether
+    /// the rule fires.
+    pub(super) fn check(
+        &mut self,
+        index: usize,
+        source: Option<&str>,
+        checks: impl Iterator<Item = bool>,
+    ) -> bool {
+        let mut conditions = Vec::new();
+        let fired = checks
+            .inspect(|&passed| conditions.push(passed))
+            .all(|passed| passed);
+        self.rules.push(RuleTrace {
+            index,
+            source: source.map(str::to_string),
+            conditions,
+            fired,
+        });
+        fired
+    }
+
+    /// Close the trace once the pass is over, working out what it changed.
+    pub(super) fn finish(mut self, stats: &Stats, action: &Action) -> Self {
+        self.stats = deltas(&std::mem::take(&mut self.stats_before), &stats.values);
+        self.action = deltas(&std::mem::take(&mut self.action_before), &action.0);
+        self
+    }
+}
+
+/// Every key whose value differs between `before` and `after`, missing
+/// values counting as 0.
+fn deltas<K: Clone + Eq + std::hash::Hash + fmt::Debug>(
+    before: &HashMap<K, f32>,
+    after: &HashMap<K, f32>,
+) -> Vec<Delta<K>> {
+    let mut deltas: Vec<Delta<K>> = after
+        .keys()
+        .chain(before.keys().filter(|key| !after.contains_key(*key)))
+        .filter_map(|key| {
+            let before = before.get(key).copied().unwrap_or(0.0);
+            let after = after.get(key).copied().unwrap_or(0.0);
+            (before != after).then(|| Delta {
+                key: key.clone(),
+                before,
+                after,
+            })
+        })
+        .collect();
+    // Hash order isn't stable; sort so dumps of the same pass read the same
+    deltas.sort_by_key(|delta| format!("{:?}", delta.key));
+    deltas
+}
+
+impl fmt::Display for PassTrace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for rule in &self.rules {
+            let source = rule.source.as_deref().unwrap_or("-");
+            let verdict = if rule.fired { "fired" } else { "skipped" };
+            write!(f, "  #{} {source} {verdict}", rule.index)?;
+            if !rule.conditions.is_empty() {
+                let checks: Vec<&str> = rule
+                    .conditions
+                    .iter()
+                    .map(|&passed| if passed { "T" } else { "F" })
+                    .collect();
+                write!(f, " [{}]", checks.join(" "))?;
+            }
+            writeln!(f)?;
+        }
+        for delta in &self.stats {
+            writeln!(f, "  {:?}: {} -> {}", delta.key, delta.before, delta.after)?;
+        }
+        for delta in &self.action {
+            writeln!(f, "  {:?}: {} -> {}", delta.key, delta.before, delta.after)?;
+        }
+        Ok(())
+    }
+}
+
+/// A [`PassTrace`] labeled with the trigger it ran for and, for hit rules,
+/// the target it ran against.
+#[derive(Clone, Debug)]
+pub struct TriggerTrace {
+    pub trigger: &'static str,
+    pub target: Option<u64>,
+    pub pass: PassTrace,
+}
+
+impl fmt::Display for TriggerTrace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.target {
+            Some(target) => writeln!(f, "{} -> #{target}", self.trigger)?,
+            None => writeln!(f, "{}", self.trigger)?,
+        }
+        write!(f, "{}", self.pass)
+    }
+}
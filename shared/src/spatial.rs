@@ -0,0 +1,133 @@
+//! Uniform spatial hash over the XZ plane.
+//!
+//! The server rebuilds one grid per world at the start of each tick so enemy
+//! separation and nearest-player lookups only visit nearby cells instead of
+//! every entity in the world.
+
+use std::collections::HashMap;
+
+type Cell = (i32, i32);
+
+/// Entities bucketed into square cells of `cell_size` meters, keyed by a
+/// caller-chosen handle (an index or row id).
+#[derive(Debug, Clone)]
+pub struct SpatialGrid<T> {
+    cell_size: f32,
+    cells: HashMap<Cell, Vec<(f32, f32, T)>>,
+    /// Occupied cell bounds, inclusive — lets `nearest` stop expanding.
+    min: Cell,
+    max: Cell,
+}
+
+impl<T: Copy> SpatialGrid<T> {
+    pub fn new(cell_size: f32) -> Self {
+        Self {
+            cell_size: cell_size.max(f32::EPSILON),
+            cells: HashMap::new(),
+            min: (i32::MAX, i32::MAX),
+            max: (i32::MIN, i32::MIN),
+        }
+    }
+
+    /// Replace the contents with `items`, keeping cell allocations around.
+    pub fn rebuild(&mut self, items: impl IntoIterator<Item = (f32, f32, T)>) {
+        self.clear();
+        for (x, z, item) in items {
+            self.insert(x, z, item);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        for cell in self.cells.values_mut() {
+            cell.clear();
+        }
+        self.min = (i32::MAX, i32::MAX);
+        self.max = (i32::MIN, i32::MIN);
+    }
+
+    pub fn insert(&mut self, x: f32, z: f32, item: T) {
+        let cell = self.cell_of(x, z);
+        self.min = (self.min.0.min(cell.0), self.min.1.min(cell.1));
+        self.max = (self.max.0.max(cell.0), self.max.1.max(cell.1));
+        self.cells.entry(cell).or_default().push((x, z, item));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.min.0 > self.max.0
+    }
+
+    /// Every item within `radius` of `(x, z)`, with its squared distance.
+    pub fn within(&self, x: f32, z: f32, radius: f32) -> impl Iterator<Item = (T, f32)> + '_ {
+        let (lo_x, lo_z) = self.cell_of(x - radius, z - radius);
+        let (hi_x, hi_z) = self.cell_of(x + radius, z + radius);
+        let radius_sq = radius * radius;
+
+        (lo_x..=hi_x)
+            .flat_map(move |cx| (lo_z..=hi_z).map(move |cz| (cx, cz)))
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .filter_map(move |&(ix, iz, item)| {
+                let dist_sq = (ix - x).powi(2) + (iz - z).powi(2);
+                (dist_sq <= radius_sq).then_some((item, dist_sq))
+            })
+    }
+
+    /// Closest item to `(x, z)` at any range, with its distance. Searches
+    /// outward ring by ring and stops once no unvisited cell can be closer.
+    pub fn nearest(&self, x: f32, z: f32) -> Option<(T, f32)> {
+        if self.is_empty() {
+            return None;
+        }
+        let (cx, cz) = self.cell_of(x, z);
+        // Rings beyond this reach no occupied cell
+        let max_ring = [
+            cx - self.min.0,
+            self.max.0 - cx,
+            cz - self.min.1,
+            self.max.1 - cz,
+        ]
+        .into_iter()
+        .max()
+        .unwrap_or(0)
+        .max(0);
+
+        let mut best: Option<(T, f32)> = None;
+        for ring in 0..=max_ring {
+            // Anything in this ring is at least (ring - 1) cells away
+            if let Some((_, best_sq)) = best {
+                let reach = (ring - 1).max(0) as f32 * self.cell_size;
+                if reach * reach > best_sq {
+                    break;
+                }
+            }
+            for cell in ring_cells(cx, cz, ring) {
+                let Some(items) = self.cells.get(&cell) else {
+                    continue;
+                };
+                for &(ix, iz, item) in items {
+                    let dist_sq = (ix - x).powi(2) + (iz - z).powi(2);
+                    if best.is_none_or(|(_, best_sq)| dist_sq < best_sq) {
+                        best = Some((item, dist_sq));
+                    }
+                }
+            }
+        }
+        best.map(|(item, dist_sq)| (item, dist_sq.sqrt()))
+    }
+
+    fn cell_of(&self, x: f32, z: f32) -> Cell {
+        (
+            (x / self.cell_size).floor() as i32,
+            (z / self.cell_size).floor() as i32,
+        )
+    }
+}
+
+/// Cells at Chebyshev distance exactly `ring` from `(cx, cz)`.
+fn ring_cells(cx: i32, cz: i32, ring: i32) -> impl Iterator<Item = Cell> {
+    (-ring..=ring).flat_map(move |dx| {
+        (-ring..=ring)
+            .filter(move |dz| dx.abs() == ring || dz.abs() == ring)
+            .map(move |dz| (cx + dx, cz + dz))
+    })
+}
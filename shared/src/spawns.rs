@@ -0,0 +1,64 @@
+//! What rules can spawn with [`Effect::Spawn`](crate::rules::Effect::Spawn).
+//! Each side maps a [`SpawnRequest`] onto its own storage: the server writes
+//! rows and resolves hits, the client spawns the VFX.
+
+use crate::loot::LootItem;
+use crate::rules::SpawnRequest;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpawnKind {
+    /// A ring that hits everything within `radius` of where it goes off.
+    /// See [`Shockwave`].
+    Shockwave,
+    /// A health orb on the ground; a `large` param above 0 makes it a large
+    /// one.
+    Loot,
+}
+
+impl SpawnKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Shockwave => "shockwave",
+            Self::Loot => "loot",
+        }
+    }
+
+    pub fn parse_str(s: &str) -> Option<Self> {
+        match s {
+            "shockwave" => Some(Self::Shockwave),
+            "loot" => Some(Self::Loot),
+            _ => None,
+        }
+    }
+}
+
+/// A spawned shockwave's tunables, from its request's `radius`,
+/// `knockback`, `launch` and `damage` params.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Shockwave {
+    pub radius: f32,
+    pub knockback: f32,
+    pub launch: f32,
+    /// Scales the spawner's attack damage.
+    pub damage_multiplier: f32,
+}
+
+impl Shockwave {
+    pub fn from_request(request: &SpawnRequest) -> Self {
+        Self {
+            radius: request.param("radius", 3.0),
+            knockback: request.param("knockback", 8.0),
+            launch: request.param("launch", 2.0),
+            damage_multiplier: request.param("damage", 0.5),
+        }
+    }
+}
+
+/// The orb a [`SpawnKind::Loot`] request drops.
+pub fn loot_item(request: &SpawnRequest) -> LootItem {
+    if request.param("large", 0.0) > 0.0 {
+        LootItem::LargeHealthOrb
+    } else {
+        LootItem::HealthOrb
+    }
+}
@@ -0,0 +1,40 @@
+//! Cosmetic per-enemy variation derived from the server id, so every client
+//! draws the same enemy the same way without the server storing any of it.
+
+use crate::rng::{hashed_unit, seed_from_id};
+
+/// Tint variants clients provide per archetype.
+pub const TINT_VARIANTS: u32 = 4;
+/// Enemies are drawn up to this fraction larger or smaller than the model.
+pub const SCALE_SPREAD: f32 = 0.1;
+/// Upper bound on how far an enemy's animations are shifted in time.
+pub const MAX_ANIMATION_OFFSET_SECS: f32 = 2.0;
+
+// One salt per channel so tint, scale and offset don't move together
+const TINT_SALT: u64 = 0x7469_6e74;
+const SCALE_SALT: u64 = 0x7363_616c;
+const OFFSET_SALT: u64 = 0x6f66_6673;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EnemyVariation {
+    /// Which tint variant to draw with, below [`TINT_VARIANTS`].
+    pub tint: u32,
+    /// Multiplier on the model's scale, within 1 ± [`SCALE_SPREAD`].
+    pub scale: f32,
+    /// Animation time offset so a pack idles out of step.
+    pub animation_offset: f32,
+}
+
+impl EnemyVariation {
+    pub fn from_id(id: u64) -> Self {
+        let seed = seed_from_id(id);
+        let tint = hashed_unit(seed, TINT_SALT);
+        let scale = hashed_unit(seed, SCALE_SALT);
+        let offset = hashed_unit(seed, OFFSET_SALT);
+        Self {
+            tint: ((tint * TINT_VARIANTS as f32) as u32).min(TINT_VARIANTS - 1),
+            scale: 1.0 + (scale * 2.0 - 1.0) * SCALE_SPREAD,
+            animation_offset: offset * MAX_ANIMATION_OFFSET_SECS,
+        }
+    }
+}
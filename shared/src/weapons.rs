@@ -0,0 +1,81 @@
+//! Weapons: what a player swings with. The weapon-slot item decides it, and
+//! it reshapes the basic attack's reach, arc and pace. The server stores the
+//! id on the player row so every client draws the same moveset.
+
+use crate::items::Item;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Weapon {
+    /// Nothing in the weapon slot.
+    #[default]
+    Fists,
+    Sword,
+    Hammer,
+}
+
+/// How a weapon changes the basic attack.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WeaponStats {
+    /// Added to the wielder's attack range.
+    pub range_bonus: f32,
+    /// Full attack arc in degrees, replacing the default.
+    pub arc: f32,
+    /// Multiplier on attack pace: below 1 swings slower, above 1 faster.
+    pub speed: f32,
+}
+
+impl Weapon {
+    /// Convert to the weapon id stored in `player.weapon`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Fists => "fists",
+            Self::Sword => "sword",
+            Self::Hammer => "hammer",
+        }
+    }
+
+    /// Parse a weapon id. Unknown ids (from a newer server) fall back to fists.
+    pub fn parse_str(s: &str) -> Self {
+        match s {
+            "sword" => Self::Sword,
+            "hammer" => Self::Hammer,
+            _ => Self::Fists,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Fists => "Fists",
+            Self::Sword => "Sword",
+            Self::Hammer => "Hammer",
+        }
+    }
+
+    pub fn stats(self) -> WeaponStats {
+        match self {
+            Self::Fists => WeaponStats {
+                range_bonus: 0.0,
+                arc: 150.0,
+                speed: 1.0,
+            },
+            Self::Sword => WeaponStats {
+                range_bonus: 0.8,
+                arc: 170.0,
+                speed: 0.9,
+            },
+            Self::Hammer => WeaponStats {
+                range_bonus: 0.5,
+                arc: 110.0,
+                speed: 0.7,
+            },
+        }
+    }
+
+    /// The weapon a player wearing `equipped` fights with.
+    pub fn from_equipped(equipped: &[Item]) -> Self {
+        equipped
+            .iter()
+            .find_map(|item| item.weapon())
+            .unwrap_or_default()
+    }
+}
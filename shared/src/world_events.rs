@@ -0,0 +1,73 @@
+//! Timed world events (`world_event` rows): an invasion or a double XP window
+//! that runs in one world between a start and an end time. The server moves
+//! each row through its [`WorldEventPhase`]s from `game_tick`; clients show a
+//! banner as it starts and ends.
+
+/// What an event does while it runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WorldEventKind {
+    /// Waves of enemies spawn at the world's spawn point.
+    Invasion,
+    /// Kills give [`DOUBLE_XP_MULTIPLIER`] times the XP.
+    DoubleXp,
+}
+
+/// XP multiplier while a [`WorldEventKind::DoubleXp`] event runs.
+pub const DOUBLE_XP_MULTIPLIER: u32 = 2;
+
+impl WorldEventKind {
+    pub const ALL: [Self; 2] = [Self::Invasion, Self::DoubleXp];
+
+    /// Convert to the kind stored in `world_event.kind`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Invasion => "invasion",
+            Self::DoubleXp => "double_xp",
+        }
+    }
+
+    pub fn parse_str(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|k| k.as_str() == s)
+    }
+
+    /// Name shown on banners.
+    pub fn title(self) -> &'static str {
+        match self {
+            Self::Invasion => "Invasion",
+            Self::DoubleXp => "Double XP",
+        }
+    }
+}
+
+/// Where an event is in its run, stored in `world_event.phase`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WorldEventPhase {
+    /// Waiting for its start time.
+    Scheduled,
+    Active,
+    /// Over; the row stays for [`ENDED_LINGER_SECS`] so clients see it end.
+    Ended,
+}
+
+/// Seconds an ended event's row is kept before it's deleted.
+pub const ENDED_LINGER_SECS: f32 = 10.0;
+
+impl WorldEventPhase {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Scheduled => "scheduled",
+            Self::Active => "active",
+            Self::Ended => "ended",
+        }
+    }
+
+    /// Unknown phases read as `Ended`, so a row nobody understands isn't
+    /// shown as running.
+    pub fn parse_str(s: &str) -> Self {
+        match s {
+            "scheduled" => Self::Scheduled,
+            "active" => Self::Active,
+            _ => Self::Ended,
+        }
+    }
+}